@@ -0,0 +1,83 @@
+//! A port of `libsamplerate`'s `timewarp-file` example, changing the
+//! playback speed of a WAV file along a hard coded list of breakpoints.
+
+use std::env::args;
+use std::process::exit;
+
+use hound::SampleFormat;
+use hound::WavReader;
+use hound::WavSpec;
+use hound::WavWriter;
+
+use resample::convert_with_schedule;
+use resample::RatioInterpolation;
+use resample::ResampleType;
+
+
+/// The `(frame, ratio)` breakpoints to warp the input along.
+const WARP: [(usize, f64); 9] = [
+    (0, 1.00000001),
+    (20000, 1.01000000),
+    (20200, 1.00000001),
+    (40000, 1.20000000),
+    (40300, 1.00000001),
+    (60000, 1.10000000),
+    (60400, 1.00000001),
+    (80000, 1.50000000),
+    (81000, 1.00000001),
+];
+
+
+fn main() {
+    let args = args().collect::<Vec<_>>();
+    let [_, input, output] = args.as_slice() else {
+        eprintln!("Usage: timewarp <input file> <output file>");
+        exit(1);
+    };
+
+    let mut reader = WavReader::open(input).unwrap();
+    let spec = reader.spec();
+    let samples = match spec.sample_format {
+        SampleFormat::Float => reader
+            .samples::<f32>()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap(),
+        SampleFormat::Int => {
+            let scale = (1u64 << (spec.bits_per_sample - 1)) as f32;
+            reader
+                .samples::<i32>()
+                .map(|sample| sample.map(|sample| sample as f32 / scale))
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap()
+        },
+    };
+
+    let type_ = ResampleType::SincMediumQuality;
+    println!("Input file    : {input}");
+    println!("Output file   : {output}");
+    println!("Converter     : {}", type_.name());
+
+    let channels = u8::try_from(spec.channels).unwrap();
+    let warped = convert_with_schedule(
+        type_,
+        channels,
+        &samples,
+        &WARP,
+        RatioInterpolation::Stepped,
+    )
+    .unwrap();
+
+    let spec = WavSpec {
+        channels: spec.channels,
+        sample_rate: spec.sample_rate,
+        bits_per_sample: 32,
+        sample_format: SampleFormat::Float,
+    };
+    let mut writer = WavWriter::create(output, spec).unwrap();
+    let () = warped
+        .iter()
+        .for_each(|sample| writer.write_sample(sample.clamp(-1.0, 1.0)).unwrap());
+    let () = writer.finalize().unwrap();
+
+    println!("Output frames : {}", warped.len() / usize::from(channels));
+}
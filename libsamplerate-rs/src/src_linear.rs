@@ -196,8 +196,12 @@ unsafe extern "C" fn linear_vari_process(
     }
     input_index = (*state).last_position;
     while input_index < 1.0f64 && (*priv_0).out_gen < (*priv_0).out_count {
+        // NB: Upstream checks for `1.0 + input_index` here, which
+        //     prevents any output from being generated for single frame
+        //     input, causing the loop below to read before the start of
+        //     `data_in`. Only the first input frame is accessed here.
         if (*priv_0).in_used as core::ffi::c_double
-            + (*state).channels as core::ffi::c_double * (1.0f64 + input_index)
+            + (*state).channels as core::ffi::c_double * input_index
             >= (*priv_0).in_count as core::ffi::c_double
         {
             break;
@@ -85,7 +85,12 @@ linear_vari_process (SRC_STATE *state, SRC_DATA *data)
 	/* Calculate samples before first sample in input array. */
 	while (input_index < 1.0 && priv->out_gen < priv->out_count)
 	{
-		if (priv->in_used + state->channels * (1.0 + input_index) >= priv->in_count)
+		/* Only the first input frame is accessed here, so single frame
+		** input is sufficient for generating output. Requiring a second
+		** frame would leave `in_used` at zero, causing the loop below to
+		** read before the start of `data_in`.
+		*/
+		if (priv->in_used + state->channels * input_index >= priv->in_count)
 			break ;
 
 		if (priv->out_count > 0 && fabs (state->last_ratio - data->src_ratio) > SRC_MIN_RATIO_DIFF)
@@ -0,0 +1,407 @@
+use std::f64::consts::PI;
+
+use libsamplerate_rs::SRC_MAX_RATIO;
+
+use crate::error::Error;
+use crate::error::ErrorKind;
+use crate::resample_type::ResampleType;
+use crate::resampler::Processed;
+use crate::resampler::Resampler;
+use crate::sample::Sample;
+
+
+/// The default bandwidth of the control loop, in Hz.
+const DEFAULT_BANDWIDTH: f64 = 0.1;
+/// The default maximum relative deviation of the ratio from its nominal
+/// value.
+const DEFAULT_MAX_DEVIATION: f64 = 0.005;
+/// The default maximum relative change of the ratio per second of
+/// output.
+const DEFAULT_MAX_SLEW: f64 = 0.0005;
+
+
+/// Check that `value` is a valid, non-negative controller parameter.
+fn check_parameter(value: f64) -> Result<(), Error> {
+    if !value.is_finite() || value < 0.0 {
+        return Err(Error::from(ErrorKind::InvalidControllerParameter))
+    }
+    Ok(())
+}
+
+
+/// A samplerate converter continuously adjusting its conversion ratio
+/// to compensate for drift between two clocks.
+///
+/// When bridging two audio devices with independent clocks, say, a USB
+/// audio interface and a network audio sink, their nominal sample rates
+/// never quite match up. Over time, the buffer between the two either
+/// runs dry or overflows. An `AdaptiveResampler` counteracts that by
+/// running a proportional-integral (PI) controller that nudges the
+/// conversion ratio around its nominal `to_rate / from_rate` value,
+/// based on periodically reported errors.
+///
+/// Errors are expressed in output frames, with a positive error meaning
+/// that too much output has been produced. Typically they are derived
+/// from the fill level of the buffer the converter's output is written
+/// to (see [`update_fill_level`][Self::update_fill_level]), but they may
+/// just as well originate from, say, timestamp differences (see
+/// [`update_error`][Self::update_error]).
+///
+/// To keep ratio changes inaudible, the ratio never deviates from its
+/// nominal value by more than a configurable maximum and changes at a
+/// bounded rate only. Within a block, `libsamplerate` transitions
+/// smoothly from the previous ratio to the current one.
+///
+/// # Example
+///
+/// ```
+/// use resample::{AdaptiveResampler, ResampleType};
+///
+/// // Keep the buffer feeding the output device at 1024 frames.
+/// let mut resampler =
+///     AdaptiveResampler::new(ResampleType::SincFastest, 2, 48000, 44100, 1024).unwrap();
+///
+/// let input = [0.0f32; 2 * 480];
+/// let mut output = [0.0f32; 2 * 1024];
+/// let processed = resampler.process(&input, &mut output).unwrap();
+/// assert_eq!(processed.read, input.len());
+///
+/// // The buffer is filling up faster than the output device drains
+/// // it, so slow down.
+/// let ratio = resampler.update_fill_level(1100);
+/// assert!(ratio < resampler.nominal_ratio());
+/// ```
+#[derive(Debug)]
+pub struct AdaptiveResampler {
+    resampler: Resampler,
+    /// The nominal conversion ratio.
+    nominal: f64,
+    /// The ratio currently in use.
+    ratio: f64,
+    /// The fill level to regulate towards, in frames.
+    target_fill: f64,
+    /// The proportional gain.
+    proportional: f64,
+    /// The integral gain.
+    integral: f64,
+    /// The accumulated error, in frames times output frames.
+    accumulated: f64,
+    /// The maximum relative deviation of the ratio from `nominal`.
+    max_deviation: f64,
+    /// The maximum relative change of the ratio per output frame.
+    max_slew: f64,
+    /// The number of output frames produced since the last update.
+    elapsed: usize,
+}
+
+impl AdaptiveResampler {
+    /// Create a new adaptive samplerate converter assuming the given
+    /// channel count and nominal sample rates.
+    ///
+    /// `target_fill` is the fill level, in frames, that
+    /// [`update_fill_level`][Self::update_fill_level] regulates
+    /// towards. The controller's gains default to a critically damped
+    /// loop with a bandwidth of 0.1 Hz, which settles within tens of
+    /// seconds of output.
+    pub fn new(
+        converter_type: ResampleType,
+        channels: u8,
+        from_rate: u32,
+        to_rate: u32,
+        target_fill: usize,
+    ) -> Result<Self, Error> {
+        let resampler = Resampler::new(converter_type, channels, from_rate, to_rate)?;
+        let nominal = resampler.ratio();
+        // The controller works in units of output frames, so convert
+        // the bandwidth accordingly.
+        let omega = 2.0 * PI * DEFAULT_BANDWIDTH / f64::from(to_rate);
+
+        let slf = Self {
+            resampler,
+            nominal,
+            ratio: nominal,
+            target_fill: target_fill as f64,
+            proportional: 2.0 * omega,
+            integral: omega * omega,
+            accumulated: 0.0,
+            max_deviation: DEFAULT_MAX_DEVIATION,
+            max_slew: DEFAULT_MAX_SLEW / f64::from(to_rate),
+            elapsed: 0,
+        };
+        Ok(slf)
+    }
+
+    /// Set the gains of the controller.
+    ///
+    /// The ratio's relative correction is `proportional` times the
+    /// current error, in frames, plus `integral` times the error
+    /// accumulated over the output frames produced.
+    ///
+    /// Negative or non-finite gains are reported as an error of kind
+    /// [`ErrorKind::InvalidControllerParameter`].
+    pub fn set_gains(&mut self, proportional: f64, integral: f64) -> Result<(), Error> {
+        let () = check_parameter(proportional)?;
+        let () = check_parameter(integral)?;
+        self.proportional = proportional;
+        self.integral = integral;
+        Ok(())
+    }
+
+    /// Set the maximum relative deviation of the ratio from its nominal
+    /// value, e.g., `0.001` for 1000 ppm.
+    ///
+    /// Deviations that are negative, not finite, or not below `1.0` are
+    /// reported as an error of kind
+    /// [`ErrorKind::InvalidControllerParameter`].
+    pub fn set_max_deviation(&mut self, max_deviation: f64) -> Result<(), Error> {
+        let () = check_parameter(max_deviation)?;
+        if max_deviation >= 1.0 {
+            return Err(Error::from(ErrorKind::InvalidControllerParameter))
+        }
+        self.max_deviation = max_deviation;
+        Ok(())
+    }
+
+    /// Set the maximum relative change of the ratio per output frame.
+    ///
+    /// Negative or non-finite values are reported as an error of kind
+    /// [`ErrorKind::InvalidControllerParameter`].
+    pub fn set_max_slew(&mut self, max_slew: f64) -> Result<(), Error> {
+        let () = check_parameter(max_slew)?;
+        self.max_slew = max_slew;
+        Ok(())
+    }
+
+    /// Report the current fill level of the buffer the converter's
+    /// output is written to, in frames, and adjust the ratio.
+    ///
+    /// The new ratio is returned.
+    pub fn update_fill_level(&mut self, fill: usize) -> f64 {
+        self.update_error(fill as f64 - self.target_fill)
+    }
+
+    /// Report the current error, in output frames, and adjust the
+    /// ratio.
+    ///
+    /// A positive error indicates that output is produced faster than
+    /// it is consumed, causing the ratio to decrease. Errors that are
+    /// not finite are ignored. The new ratio is returned.
+    ///
+    /// The ratio never leaves the range `[1/256, 256]` supported by
+    /// `libsamplerate`, irrespective of the configured maximum
+    /// deviation.
+    pub fn update_error(&mut self, error: f64) -> f64 {
+        if !error.is_finite() {
+            return self.ratio
+        }
+
+        let elapsed = self.elapsed as f64;
+        self.elapsed = 0;
+
+        self.accumulated += error * elapsed;
+        // Don't let the integral term wind up beyond what we could ever
+        // correct for.
+        if self.integral > 0.0 {
+            let limit = self.max_deviation / self.integral;
+            self.accumulated = self.accumulated.clamp(-limit, limit);
+        }
+
+        let correction = self.proportional * error + self.integral * self.accumulated;
+        let correction = correction.clamp(-self.max_deviation, self.max_deviation);
+        let target = self.nominal * (1.0 - correction);
+        let slew = self.nominal * self.max_slew * elapsed;
+        let max_ratio = f64::from(SRC_MAX_RATIO);
+        self.ratio = (self.ratio + (target - self.ratio).clamp(-slew, slew))
+            .clamp(max_ratio.recip(), max_ratio);
+        self.ratio
+    }
+
+    /// Perform a samplerate conversion on a block of data, using the
+    /// current ratio.
+    ///
+    /// See [`Resampler::process`] for details.
+    pub fn process<S>(&mut self, input: &[S], output: &mut [S]) -> Result<Processed, Error>
+    where
+        S: Sample,
+    {
+        let processed = self
+            .resampler
+            .process_with_ratio(input, output, self.ratio)?;
+        self.elapsed += processed.written / usize::from(self.resampler.output_channels());
+        Ok(processed)
+    }
+
+    /// Reset the internal converter's state as well as that of the
+    /// controller, reverting to the nominal ratio.
+    pub fn reset(&mut self) -> Result<(), Error> {
+        let () = self.resampler.reset()?;
+        let () = self.resampler.set_ratio(self.nominal)?;
+        self.ratio = self.nominal;
+        self.accumulated = 0.0;
+        self.elapsed = 0;
+        Ok(())
+    }
+
+    /// Retrieve the ratio currently in use.
+    #[inline]
+    pub fn ratio(&self) -> f64 {
+        self.ratio
+    }
+
+    /// Retrieve the nominal conversion ratio.
+    #[inline]
+    pub fn nominal_ratio(&self) -> f64 {
+        self.nominal
+    }
+
+    /// Retrieve the number of channels the converter works with.
+    #[inline]
+    pub fn channels(&self) -> u8 {
+        self.resampler.channels()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::error::ErrorKind;
+
+
+    /// Simulate bridging two devices whose clocks drift apart and check
+    /// that the controller keeps the buffer fill level in check.
+    #[test]
+    fn drifting_clock() {
+        const FROM_RATE: u32 = 48000;
+        const TO_RATE: u32 = 44100;
+        const TARGET: usize = 2048;
+        // The input device's clock runs fast by 300 ppm.
+        const DRIFT: f64 = 300e-6;
+
+        let mut resampler =
+            AdaptiveResampler::new(ResampleType::Linear, 1, FROM_RATE, TO_RATE, TARGET).unwrap();
+        let mut fifo = vec![0.0f32; TARGET];
+        let mut output = vec![0.0f32; 1024];
+        // Fractional frames owed by the input and output devices.
+        let mut owed_in = 0.0;
+        let mut owed_out = 0.0;
+        let mut fills = Vec::new();
+        let mut last_ratio = resampler.ratio();
+
+        // Simulate five minutes in steps of 10ms.
+        for _ in 0..30_000 {
+            owed_in += f64::from(FROM_RATE) * (1.0 + DRIFT) / 100.0;
+            let input = vec![0.25f32; owed_in as usize];
+            owed_in -= input.len() as f64;
+
+            let mut remaining = input.as_slice();
+            while !remaining.is_empty() {
+                let processed = resampler.process(remaining, &mut output).unwrap();
+                remaining = &remaining[processed.read..];
+                let () = fifo.extend_from_slice(&output[..processed.written]);
+            }
+
+            owed_out += f64::from(TO_RATE) / 100.0;
+            let consumed = (owed_out as usize).min(fifo.len());
+            owed_out -= consumed as f64;
+            let () = drop(fifo.drain(..consumed));
+
+            let ratio = resampler.update_fill_level(fifo.len());
+            let max_change = resampler.nominal_ratio() * DEFAULT_MAX_SLEW * 0.011;
+            assert!((ratio - last_ratio).abs() <= max_change);
+            last_ratio = ratio;
+            let () = fills.push(fifo.len());
+        }
+
+        // Without correction we would have accumulated 30_000 * 441 *
+        // DRIFT, i.e., roughly 4000 frames of excess output.
+        let settled = &fills[fills.len() / 2..];
+        assert!(
+            settled.iter().all(|fill| fill.abs_diff(TARGET) < 16),
+            "{:?}",
+            settled.iter().max()
+        );
+
+        // The ratio should now account for the drift.
+        let expected = f64::from(TO_RATE) / (f64::from(FROM_RATE) * (1.0 + DRIFT));
+        let ratio = resampler.ratio();
+        assert!(
+            (ratio / expected - 1.0).abs() < 20e-6,
+            "{ratio} vs. {expected}"
+        );
+    }
+
+    /// Check that ratio adjustments are bounded.
+    #[test]
+    fn bounded_adjustment() {
+        let mut resampler =
+            AdaptiveResampler::new(ResampleType::ZeroOrderHold, 1, 44100, 44100, 512).unwrap();
+        let () = resampler.set_max_deviation(0.001).unwrap();
+        // Without any output produced, the ratio must not change.
+        assert_eq!(resampler.update_fill_level(100_000), 1.0);
+
+        let input = [0.0f32; 4410];
+        let mut output = [0.0f32; 4410];
+        for _ in 0..100 {
+            let _processed = resampler.process(&input, &mut output).unwrap();
+            let ratio = resampler.update_fill_level(100_000);
+            assert!(ratio < 1.0);
+            assert!(ratio >= 0.999);
+        }
+        assert_eq!(resampler.ratio(), 0.999);
+
+        let () = resampler.reset().unwrap();
+        assert_eq!(resampler.ratio(), 1.0);
+
+        let err = AdaptiveResampler::new(ResampleType::ZeroOrderHold, 1, 1, 1000, 512).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::BadSrcRatio);
+    }
+
+    /// Make sure that invalid controller parameters are rejected.
+    #[test]
+    fn invalid_parameters() {
+        let mut resampler =
+            AdaptiveResampler::new(ResampleType::Linear, 1, 44100, 48000, 512).unwrap();
+
+        for value in [-1.0, f64::NAN, f64::INFINITY] {
+            let err = resampler.set_gains(value, 0.0).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::InvalidControllerParameter);
+            let err = resampler.set_gains(0.0, value).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::InvalidControllerParameter);
+            let err = resampler.set_max_deviation(value).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::InvalidControllerParameter);
+            let err = resampler.set_max_slew(value).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::InvalidControllerParameter);
+        }
+        let err = resampler.set_max_deviation(1.0).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidControllerParameter);
+
+        let () = resampler.set_gains(0.0, 0.0).unwrap();
+        let () = resampler.set_max_deviation(0.0).unwrap();
+        let () = resampler.set_max_slew(0.0).unwrap();
+
+        // Non-finite errors leave the ratio untouched.
+        let ratio = resampler.ratio();
+        assert_eq!(resampler.update_error(f64::NAN), ratio);
+        assert_eq!(resampler.update_error(f64::NEG_INFINITY), ratio);
+    }
+
+    /// Check that the ratio is kept within the range supported by
+    /// `libsamplerate`, even for large deviations.
+    #[test]
+    fn ratio_limits() {
+        let mut resampler = AdaptiveResampler::new(ResampleType::Linear, 1, 1, 256, 512).unwrap();
+        let () = resampler.set_max_deviation(0.5).unwrap();
+        let () = resampler.set_max_slew(1.0).unwrap();
+
+        let input = [0.0f32; 16];
+        let mut output = [0.0f32; 8192];
+        for _ in 0..10 {
+            let _processed = resampler.process(&input, &mut output).unwrap();
+            let ratio = resampler.update_fill_level(0);
+            assert!(ratio <= 256.0, "{ratio}");
+        }
+        assert_eq!(resampler.ratio(), 256.0);
+    }
+}
@@ -0,0 +1,335 @@
+use crate::convert_impl;
+use crate::dither::Dither;
+use crate::error::Error;
+use crate::error::ErrorKind;
+use crate::mix::ChannelMix;
+use crate::resample_type::ResampleType;
+use crate::resampler::check_frame_aligned;
+use crate::resampler::expected_frames;
+use crate::resampler::OutputLength;
+use crate::resampler::Resampler;
+use crate::sample::Sample;
+
+
+/// A builder for configuring and creating a [`Resampler`].
+///
+/// The channel layout has to be configured, either via
+/// [`channels`][Self::channels] or [`channel_mix`][Self::channel_mix].
+/// All other options have sensible defaults: a conversion ratio of
+/// `1.0`, no delay compensation, the [natural][OutputLength::Natural]
+/// output length, and no dither. Options are only validated, together,
+/// when [`build`][Self::build] is invoked.
+///
+/// # Example
+///
+/// ```
+/// use resample::{OutputLength, Resampler, ResampleType};
+///
+/// let mut resampler = Resampler::builder(ResampleType::SincFastest)
+///     .channels(2)
+///     .rates(44100, 48000)
+///     .output_length(OutputLength::Exact)
+///     .build()
+///     .unwrap();
+///
+/// let input = [0.0f32; 2 * 441];
+/// let mut output = [0.0f32; 2 * 1024];
+/// let processed = resampler.finalize(&input, &mut output).unwrap();
+/// assert_eq!(processed.read, input.len());
+/// assert_eq!(processed.written, 2 * 480);
+/// ```
+#[derive(Clone, Debug)]
+pub struct ResamplerBuilder {
+    converter_type: ResampleType,
+    channels: Option<u8>,
+    ratio: f64,
+    mix: Option<ChannelMix>,
+    delay_compensation: bool,
+    output_length: OutputLength,
+    dither: (Dither, u64),
+}
+
+impl ResamplerBuilder {
+    /// Create a new builder for a converter of type `converter_type`.
+    pub fn new(converter_type: ResampleType) -> Self {
+        Self {
+            converter_type,
+            channels: None,
+            ratio: 1.0,
+            mix: None,
+            delay_compensation: false,
+            output_length: OutputLength::Natural,
+            dither: (Dither::None, 0),
+        }
+    }
+
+    /// Set the converter type.
+    pub fn converter_type(mut self, converter_type: ResampleType) -> Self {
+        self.converter_type = converter_type;
+        self
+    }
+
+    /// Set the number of channels of the input.
+    ///
+    /// There is no default channel count. Unless a [channel
+    /// mix][Self::channel_mix] is configured, which implies the count,
+    /// it has to be set explicitly. With a channel mix configured as
+    /// well, the count has to match the mix's number of inputs.
+    pub fn channels(mut self, channels: u8) -> Self {
+        self.channels = Some(channels);
+        self
+    }
+
+    /// Set the conversion ratio based on the given sample rates.
+    pub fn rates(mut self, from_rate: u32, to_rate: u32) -> Self {
+        self.ratio = f64::from(to_rate) / f64::from(from_rate);
+        self
+    }
+
+    /// Set the conversion ratio, defined as output samplerate divided
+    /// by input samplerate.
+    ///
+    /// See [`Resampler::with_ratio`] for details.
+    pub fn ratio(mut self, ratio: f64) -> Self {
+        self.ratio = ratio;
+        self
+    }
+
+    /// Set a channel mix to apply as part of the conversion.
+    ///
+    /// See [`Resampler::with_channel_mix`] for details.
+    pub fn channel_mix(mut self, mix: ChannelMix) -> Self {
+        self.mix = Some(mix);
+        self
+    }
+
+    /// Enable or disable compensation for the delay introduced by the
+    /// converter.
+    ///
+    /// See [`Resampler::set_delay_compensation`] for details.
+    pub fn delay_compensation(mut self, enable: bool) -> Self {
+        self.delay_compensation = enable;
+        self
+    }
+
+    /// Set the policy determining the overall number of frames the
+    /// conversion produces.
+    ///
+    /// See [`Resampler::set_output_length`] for details. Note that
+    /// [`OutputLength::ExactAlignedToDelay`] implies delay
+    /// compensation.
+    pub fn output_length(mut self, length: OutputLength) -> Self {
+        self.output_length = length;
+        self
+    }
+
+    /// Set the dither to apply when producing integer output, along
+    /// with the seed of the noise generator.
+    ///
+    /// See [`Resampler::set_dither`] for details.
+    pub fn dither(mut self, dither: Dither, seed: u64) -> Self {
+        self.dither = (dither, seed);
+        self
+    }
+
+    /// Validate the configuration and create the [`Resampler`].
+    ///
+    /// Unsupported ratios are reported as an error of kind
+    /// [`ErrorKind::BadSrcRatio`] and invalid or missing channel counts
+    /// as [`ErrorKind::BadChannelCount`]. A channel count not matching
+    /// that of the configured channel mix results in an
+    /// [`ErrorKind::ChannelCountMismatch`] error.
+    pub fn build(&self) -> Result<Resampler, Error> {
+        let channels = match &self.mix {
+            Some(mix) => {
+                if self
+                    .channels
+                    .is_some_and(|channels| channels != mix.inputs())
+                {
+                    return Err(Error::from(ErrorKind::ChannelCountMismatch))
+                }
+                mix.inputs().min(mix.outputs())
+            },
+            None => self
+                .channels
+                .ok_or_else(|| Error::from(ErrorKind::BadChannelCount))?,
+        };
+
+        let mut resampler = Resampler::with_ratio(self.converter_type, channels, self.ratio)?;
+        if let Some(mix) = &self.mix {
+            let () = resampler.set_channel_mix(mix.clone());
+        }
+        if self.delay_compensation {
+            let () = resampler.set_delay_compensation(true)?;
+        }
+        let () = resampler.set_output_length(self.output_length)?;
+        let (dither, seed) = self.dither;
+        let () = resampler.set_dither(dither, seed);
+        Ok(resampler)
+    }
+
+    /// Create the [`Resampler`] and use it to convert a large chunk of
+    /// audio in its entirety.
+    ///
+    /// This method behaves like [`convert`][crate::convert], but honors
+    /// all configured options, allowing, for example, for dithered
+    /// integer output of a given length at an arbitrary ratio. Errors
+    /// are reported as by [`build`][Self::build], and for `input` not
+    /// holding a whole number of frames.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use resample::{Dither, OutputLength, Resampler, ResampleType};
+    ///
+    /// let input = vec![0i16; 2 * 1000];
+    /// let resampled = Resampler::builder(ResampleType::SincFastest)
+    ///     .channels(2)
+    ///     .ratio(0.9995)
+    ///     .output_length(OutputLength::Exact)
+    ///     .dither(Dither::Triangular, 42)
+    ///     .convert(&input)
+    ///     .unwrap();
+    /// assert_eq!(resampled.len(), 2 * 1000);
+    /// ```
+    pub fn convert<S>(&self, input: &[S]) -> Result<Vec<S>, Error>
+    where
+        S: Sample,
+    {
+        let resampler = self.build()?;
+        let channels = resampler.input_channels();
+        let () = check_frame_aligned(input.len(), channels, ErrorKind::InputNotFrameAligned)?;
+
+        let input_frames = input.len() / usize::from(channels);
+        let output_frames = expected_frames(input_frames, self.ratio);
+        convert_impl(resampler, input, output_frames)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::convert;
+
+
+    /// Check that a built converter behaves like one created directly.
+    #[test]
+    fn build_equivalence() {
+        let input = (0..2 * 1000)
+            .map(|i| (i as f32 / 16.0).sin())
+            .collect::<Vec<f32>>();
+        let mut expected = vec![0.0f32; 2 * 1200];
+        let mut output = vec![0.0f32; 2 * 1200];
+
+        let mut resampler = Resampler::new(ResampleType::SincFastest, 2, 44100, 48000).unwrap();
+        let processed = resampler.finalize(&input, &mut expected).unwrap();
+
+        let mut resampler = Resampler::builder(ResampleType::Linear)
+            .converter_type(ResampleType::SincFastest)
+            .channels(2)
+            .rates(44100, 48000)
+            .build()
+            .unwrap();
+        assert_eq!(resampler.finalize(&input, &mut output).unwrap(), processed);
+        assert_eq!(output, expected);
+    }
+
+    /// Check that options are applied to the created converter.
+    #[test]
+    fn options() {
+        let resampler = ResamplerBuilder::new(ResampleType::Linear)
+            .channels(3)
+            .build()
+            .unwrap();
+        assert_eq!(resampler.channels(), 3);
+        assert_eq!(resampler.ratio(), 1.0);
+        assert_eq!(resampler.output_length(), OutputLength::Natural);
+
+        let resampler = ResamplerBuilder::new(ResampleType::Linear)
+            .channel_mix(ChannelMix::surround_to_stereo())
+            .ratio(0.5)
+            .delay_compensation(true)
+            .output_length(OutputLength::Exact)
+            .build()
+            .unwrap();
+        assert_eq!(resampler.input_channels(), 6);
+        assert_eq!(resampler.output_channels(), 2);
+        assert_eq!(resampler.ratio(), 0.5);
+        assert_eq!(resampler.output_length(), OutputLength::Exact);
+
+        // With delay compensation the output lines up with the input.
+        let input = [0.0f32, 1.0, 2.0, 3.0, 4.0, 5.0];
+        let mut output = [0.0f32; 6];
+        let mut resampler = ResamplerBuilder::new(ResampleType::Linear)
+            .channels(1)
+            .delay_compensation(true)
+            .build()
+            .unwrap();
+        let processed = resampler.finalize(&input, &mut output).unwrap();
+        assert_eq!(output[..processed.written], input);
+    }
+
+    /// Check that conversion via the builder honors all options.
+    #[test]
+    fn conversion() {
+        let input = (0..2 * 10_000)
+            .map(|i| (i as f32 / 32.0).sin() * 0.5)
+            .collect::<Vec<f32>>();
+
+        let builder = ResamplerBuilder::new(ResampleType::SincFastest)
+            .channels(2)
+            .rates(44100, 48000);
+        let expected = convert(ResampleType::SincFastest, 2, 44100, 48000, &input).unwrap();
+        assert_eq!(builder.convert(&input).unwrap(), expected);
+
+        for length in [OutputLength::Exact, OutputLength::ExactAlignedToDelay] {
+            let output = builder
+                .clone()
+                .ratio(0.73)
+                .output_length(length)
+                .convert(&input)
+                .unwrap();
+            assert_eq!(output.len(), 2 * 7300, "{length:?}");
+        }
+
+        let output = ResamplerBuilder::new(ResampleType::Linear)
+            .channel_mix(ChannelMix::stereo_to_mono())
+            .rates(2, 1)
+            .output_length(OutputLength::Exact)
+            .convert(&input)
+            .unwrap();
+        assert_eq!(output.len(), 5000);
+
+        let err = builder.convert(&input[..3]).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InputNotFrameAligned);
+    }
+
+    /// Make sure that invalid configurations are rejected.
+    #[test]
+    fn invalid_options() {
+        let builder = ResamplerBuilder::new(ResampleType::Linear);
+
+        let err = builder.clone().build().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::BadChannelCount);
+
+        let err = builder
+            .clone()
+            .channels(1)
+            .rates(0, 44100)
+            .build()
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::BadSrcRatio);
+
+        let err = builder.clone().channels(0).build().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::BadChannelCount);
+
+        let err = builder
+            .channels(2)
+            .channel_mix(ChannelMix::surround_to_stereo())
+            .build()
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ChannelCountMismatch);
+    }
+}
@@ -0,0 +1,393 @@
+use std::any::Any;
+use std::ffi::c_long;
+use std::ffi::c_void;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
+use std::panic::catch_unwind;
+use std::panic::resume_unwind;
+use std::panic::AssertUnwindSafe;
+
+use libsamplerate_rs::src_callback_new;
+use libsamplerate_rs::src_callback_read;
+use libsamplerate_rs::src_delete;
+use libsamplerate_rs::src_error;
+use libsamplerate_rs::src_reset;
+use libsamplerate_rs::SRC_STATE;
+
+use crate::error::Error;
+use crate::error::ErrorKind;
+use crate::resample_type::ResampleType;
+use crate::resampler::check_channels;
+use crate::resampler::check_frame_aligned;
+use crate::resampler::check_ratio;
+
+
+/// The number of frames the input buffer handed to the source closure
+/// can hold.
+const BUFFER_FRAMES: usize = 1024;
+
+
+/// The data shared with the `libsamplerate` callback.
+struct CallbackData<F> {
+    /// The user provided source of input samples.
+    source: F,
+    /// The buffer the source writes input samples into.
+    buffer: Vec<f32>,
+    /// The number of channels per frame.
+    channels: usize,
+    /// The payload of a panic that occurred inside `source`, if any.
+    panic: Option<Box<dyn Any + Send>>,
+    /// The error caused by input provided by `source`, if any.
+    error: Option<Error>,
+}
+
+
+/// The callback invoked by `libsamplerate` whenever it requires more
+/// input data.
+///
+/// # Safety
+/// `cb_data` has to point to a valid `CallbackData<F>` object and
+/// `data` has to be a valid pointer.
+unsafe extern "C" fn callback<F>(cb_data: *mut c_void, data: *mut *mut f32) -> c_long
+where
+    F: FnMut(&mut [f32]) -> usize,
+{
+    // SAFETY: The caller guarantees that `cb_data` points to a valid
+    //         `CallbackData<F>` and nobody else accesses it while the
+    //         callback is running.
+    let cb_data = unsafe { &mut *cb_data.cast::<CallbackData<F>>() };
+    if cb_data.panic.is_some() || cb_data.error.is_some() {
+        // The source already panicked or misbehaved; signal end of
+        // input until we are able to report the problem.
+        return 0
+    }
+
+    let CallbackData {
+        source,
+        buffer,
+        channels,
+        panic,
+        error,
+    } = cb_data;
+
+    // Rust panics must not unwind across the `extern "C"` boundary, so
+    // catch them here and rethrow once `libsamplerate` returned.
+    match catch_unwind(AssertUnwindSafe(|| source(buffer))) {
+        Ok(count) => {
+            let count = count.min(buffer.len());
+            if count % *channels != 0 {
+                *error = Some(Error::from(ErrorKind::InputNotFrameAligned));
+                return 0
+            }
+
+            let frames = count / *channels;
+            // SAFETY: The caller guarantees that `data` is a valid
+            //         pointer.
+            let () = unsafe { data.write(buffer.as_mut_ptr()) };
+            // `frames` is bounded by `BUFFER_FRAMES`, so this conversion
+            // can't truncate.
+            frames as c_long
+        },
+        Err(payload) => {
+            *panic = Some(payload);
+            0
+        },
+    }
+}
+
+
+/// A pull based samplerate converter.
+///
+/// Contrary to [`Resampler`][crate::Resampler], which gets input data
+/// pushed to it, a `CallbackResampler` pulls input data from a
+/// user-provided source on demand. That makes it possible to request
+/// an exact number of output samples, as is common when servicing the
+/// callback of an audio device.
+///
+/// The source is a closure that receives a buffer into which it should
+/// write (interleaved) input samples. It returns the number of samples
+/// written, which has to be a multiple of the channel count. A return
+/// value of `0` signals the end of input.
+///
+/// # Example
+///
+/// ```
+/// # use std::f32::consts::PI;
+/// use resample::{CallbackResampler, ResampleType};
+///
+/// // Generate a 880Hz sine wave for 1 second in 44100Hz with one channel.
+/// let freq = PI * 880_f32 / 44100_f32;
+/// let mut samples = (0..44100).map(|i| (freq * i as f32).sin());
+///
+/// let source = move |buffer: &mut [f32]| {
+///     buffer
+///         .iter_mut()
+///         .zip(samples.by_ref())
+///         .map(|(slot, sample)| *slot = sample)
+///         .count()
+/// };
+/// let mut resampler = CallbackResampler::new(ResampleType::SincFastest, 1, source).unwrap();
+///
+/// // Request exactly 512 samples of output in 48000Hz.
+/// let mut output = vec![0.0; 512];
+/// let written = resampler.read(48000.0 / 44100.0, &mut output).unwrap();
+/// assert_eq!(written, 512);
+/// ```
+pub struct CallbackResampler<F> {
+    state: *mut SRC_STATE,
+    /// The data shared with `libsamplerate`. It is boxed, because
+    /// `libsamplerate` holds on to a pointer to it.
+    data: Box<CallbackData<F>>,
+    channels: u8,
+}
+
+impl<F> CallbackResampler<F>
+where
+    F: FnMut(&mut [f32]) -> usize,
+{
+    /// Create a new pull based samplerate converter assuming the given
+    /// channel count and retrieving input samples from `source`.
+    pub fn new(converter_type: ResampleType, channels: u8, source: F) -> Result<Self, Error> {
+        let () = check_channels(channels)?;
+
+        let mut data = Box::new(CallbackData {
+            source,
+            buffer: vec![0.0; BUFFER_FRAMES * usize::from(channels)],
+            channels: usize::from(channels),
+            panic: None,
+            error: None,
+        });
+
+        let mut error = 0i32;
+        // SAFETY: `error` is a valid pointer coming from a reference
+        //         and `data` is heap allocated and will outlive
+        //         `state`.
+        let state = unsafe {
+            src_callback_new(
+                Some(callback::<F>),
+                converter_type as i32,
+                i32::from(channels),
+                &raw mut error,
+                (&raw mut *data).cast(),
+            )
+        };
+        let () = Error::check_int(error)?;
+
+        let slf = Self {
+            state,
+            data,
+            channels,
+        };
+        Ok(slf)
+    }
+
+    /// Read converted samples into `output`, pulling as much input
+    /// from the source as necessary.
+    ///
+    /// The ratio is defined as output samplerate divided by input
+    /// samplerate. If it differs from the ratio used in a previous
+    /// call, `libsamplerate` transitions smoothly between the two.
+    ///
+    /// If the number of channels used was not `1` (Mono), the samples
+    /// are stored interleaved, in which case `output` needs to provide
+    /// space for a whole number of frames. The function returns the
+    /// number of samples written, which is only less than the size of
+    /// `output` once the source reached the end of its input.
+    ///
+    /// Should the source provide an incomplete frame, an error of kind
+    /// [`ErrorKind::InputNotFrameAligned`] is reported and the output
+    /// of the call is lost. The converter has to be
+    /// [reset][Self::reset] before being used again.
+    ///
+    /// # Panics
+    /// Panics that occur in the source closure are propagated to the
+    /// caller.
+    pub fn read(&mut self, ratio: f64, output: &mut [f32]) -> Result<usize, Error> {
+        let () = check_ratio(ratio)?;
+
+        let () = check_frame_aligned(
+            output.len(),
+            self.channels,
+            ErrorKind::OutputNotFrameAligned,
+        )?;
+
+        let channels = usize::from(self.channels);
+        let frames = c_long::try_from(output.len() / channels).unwrap_or(c_long::MAX);
+        // SAFETY: `state` is valid and guaranteed to be coming from a
+        //         previous `src_callback_new` call and `output` is
+        //         valid for `frames` frames.
+        let read = unsafe { src_callback_read(self.state, ratio, frames, output.as_mut_ptr()) };
+
+        if let Some(payload) = self.data.panic.take() {
+            resume_unwind(payload)
+        }
+
+        if let Some(err) = self.data.error.take() {
+            return Err(err)
+        }
+
+        if read == 0 {
+            // SAFETY: `state` is valid and guaranteed to be coming from a
+            //         previous `src_callback_new` call.
+            let error = unsafe { src_error(self.state) };
+            let () = Error::check_int(error)?;
+        }
+        // `libsamplerate` never reports negative counts.
+        Ok(usize::try_from(read).unwrap_or(0) * channels)
+    }
+
+    /// Reset the internal converter's state.
+    ///
+    /// Note that the source itself is not affected by this operation.
+    pub fn reset(&mut self) -> Result<(), Error> {
+        // SAFETY: `state` is valid and guaranteed to be coming from a
+        //          previous `src_callback_new` call.
+        let error = unsafe { src_reset(self.state) };
+        let () = Error::check_int(error)?;
+        Ok(())
+    }
+}
+
+// SAFETY: The `SRC_STATE` object is exclusively owned by the
+//         `CallbackResampler` and only ever accessed through it, with
+//         the same reasoning as for `Resampler` applying. The shared
+//         callback data can be sent if the source itself can be.
+unsafe impl<F> Send for CallbackResampler<F> where F: Send {}
+
+impl<F> Debug for CallbackResampler<F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("CallbackResampler")
+            .field("state", &self.state)
+            .field("channels", &self.channels)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<F> Drop for CallbackResampler<F> {
+    fn drop(&mut self) {
+        // SAFETY: `state` is valid and guaranteed to be coming from a
+        //          previous `src_callback_new` call.
+        unsafe { src_delete(self.state) };
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::f32::consts::PI;
+
+    use crate::convert;
+
+
+    /// Create a source closure producing the given samples in chunks
+    /// of at most `chunk` samples.
+    fn source(samples: Vec<f32>, chunk: usize) -> impl FnMut(&mut [f32]) -> usize {
+        let mut offset = 0;
+        move |buffer| {
+            let count = buffer.len().min(chunk).min(samples.len() - offset);
+            let () = buffer[..count].copy_from_slice(&samples[offset..offset + count]);
+            offset += count;
+            count
+        }
+    }
+
+    /// Check that pull based conversion produces the same output as
+    /// conversion in one go.
+    #[test]
+    fn callback_conversion() {
+        let channels = 2;
+        let freq = PI * 880f32 / 44100f32;
+        let input = (0..44100 * usize::from(channels))
+            .map(|i| (freq * (i / usize::from(channels)) as f32).sin())
+            .collect::<Vec<f32>>();
+
+        for type_ in [
+            ResampleType::SincFastest,
+            ResampleType::ZeroOrderHold,
+            ResampleType::Linear,
+        ] {
+            let reference = convert(type_, channels, 44100, 48000, &input).unwrap();
+
+            let mut resampler =
+                CallbackResampler::new(type_, channels, source(input.clone(), 300)).unwrap();
+            let mut output = Vec::new();
+            let mut buffer = vec![0.0; 2 * 441];
+            loop {
+                let written = resampler.read(48000.0 / 44100.0, &mut buffer).unwrap();
+                let () = output.extend_from_slice(&buffer[..written]);
+
+                if written < buffer.len() {
+                    break
+                }
+            }
+
+            // The handling of the end of input differs slightly between
+            // the two modes, which may result in an additional frame
+            // being emitted.
+            assert!(
+                output.len().abs_diff(reference.len()) <= 2 * usize::from(channels),
+                "{type_:?}: {} vs. {}",
+                output.len(),
+                reference.len()
+            );
+            let max_diff = output
+                .iter()
+                .zip(&reference)
+                .fold(0f32, |max, (a, b)| max.max((a - b).abs()));
+            assert!(max_diff < 1e-5, "{type_:?}: {max_diff}");
+        }
+    }
+
+    /// Check that a `CallbackResampler` can be sent to other threads
+    /// if its source can be.
+    #[test]
+    fn callback_send() {
+        fn test<T>(_: &T)
+        where
+            T: Send,
+        {
+        }
+
+        let resampler =
+            CallbackResampler::new(ResampleType::Linear, 1, source(vec![0.0; 64], 64)).unwrap();
+        let () = test(&resampler);
+    }
+
+    /// Make sure that we report an invalid ratio as an error.
+    #[test]
+    fn callback_invalid_ratio() {
+        let mut resampler =
+            CallbackResampler::new(ResampleType::Linear, 1, source(vec![0.0; 64], 64)).unwrap();
+        let mut output = [0.0; 16];
+        let err = resampler.read(1024.0, &mut output).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::BadSrcRatio);
+    }
+
+    /// Make sure that we report a source providing an incomplete frame
+    /// as an error.
+    #[test]
+    fn callback_incomplete_frame() {
+        let mut resampler =
+            CallbackResampler::new(ResampleType::Linear, 2, source(vec![0.5; 301], 301)).unwrap();
+        let mut output = [0.0; 2 * 1024];
+        let err = resampler.read(1.0, &mut output).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InputNotFrameAligned);
+
+        let () = resampler.reset().unwrap();
+        let written = resampler.read(1.0, &mut output).unwrap();
+        assert_eq!(written, 0);
+    }
+
+    /// Check that a panic in the source is propagated to the caller.
+    #[test]
+    #[should_panic = "source failure"]
+    fn callback_panic() {
+        let source = |_buffer: &mut [f32]| -> usize { panic!("source failure") };
+        let mut resampler = CallbackResampler::new(ResampleType::Linear, 1, source).unwrap();
+        let mut output = [0.0; 16];
+        let _result = resampler.read(1.0, &mut output);
+    }
+}
@@ -0,0 +1,517 @@
+use std::mem::take;
+
+use crate::dither::Dither;
+use crate::dither::Ditherer;
+use crate::error::Error;
+use crate::error::ErrorKind;
+use crate::resample_type::ResampleType;
+use crate::resampler::check_channels;
+use crate::resampler::check_frame_aligned;
+use crate::resampler::Latency;
+use crate::resampler::Processed;
+use crate::resampler::Resampler;
+use crate::sample::Sample;
+
+
+/// The maximum conversion ratio supported by a single `libsamplerate`
+/// converter.
+const MAX_STAGE_RATIO: f64 = 256.0;
+
+
+/// Split the conversion ratio `ratio` into the ratios of as few stages
+/// as possible, each of which is supported by `libsamplerate`.
+fn stage_ratios(ratio: f64) -> Result<Vec<f64>, Error> {
+    if !ratio.is_finite() || ratio <= 0.0 {
+        return Err(Error::from(ErrorKind::BadSrcRatio))
+    }
+
+    let count = (ratio.ln().abs() / MAX_STAGE_RATIO.ln()).ceil().max(1.0);
+    let stage_ratio = ratio.powf(count.recip());
+    // `count` is bounded by the range of `f64`, so this conversion is
+    // lossless.
+    let mut ratios = vec![stage_ratio; count as usize];
+    // Make sure that the product of all stage ratios matches the
+    // overall ratio as closely as possible, irrespective of rounding
+    // errors.
+    if let Some((last, rest)) = ratios.split_last_mut() {
+        *last = ratio / rest.iter().product::<f64>();
+    }
+    Ok(ratios)
+}
+
+
+/// Select the converter type for stage `stage` of `count` stages, given
+/// the overall conversion ratio `ratio`.
+///
+/// The stage working at the lowest samplerates, i.e., the first one when
+/// upsampling and the last one when downsampling, shapes the band of
+/// interest and uses `converter_type`. All other stages only have to
+/// keep content well below their Nyquist frequency intact and use the
+/// fastest sinc converter, unless `converter_type` is not a sinc
+/// converter to begin with.
+fn default_stage_type(
+    converter_type: ResampleType,
+    stage: usize,
+    count: usize,
+    ratio: f64,
+) -> ResampleType {
+    let quality_stage = if ratio >= 1.0 { 0 } else { count - 1 };
+    if stage == quality_stage {
+        return converter_type
+    }
+
+    match converter_type {
+        ResampleType::SincBestQuality
+        | ResampleType::SincMediumQuality
+        | ResampleType::SincFastest => ResampleType::SincFastest,
+        ResampleType::ZeroOrderHold | ResampleType::Linear => converter_type,
+    }
+}
+
+
+/// A samplerate converter supporting conversion ratios beyond what a
+/// single `libsamplerate` converter supports.
+///
+/// `libsamplerate` is limited to ratios in the range `[1/256, 256]`. A
+/// `CascadedResampler` splits more extreme ratios into multiple stages
+/// with equal ratios, feeding the output of each stage into the next
+/// one. Ratios inside the supported range result in a single stage.
+/// Each stage may use a different converter type, allowing for cheap
+/// converters where quality matters less.
+///
+/// # Example
+///
+/// ```
+/// use resample::{CascadedResampler, ResampleType};
+///
+/// // Convert one second of 1kHz sensor data to 384kHz.
+/// let input = vec![0.0f32; 1000];
+/// let mut output = vec![0.0f32; 4096];
+///
+/// let mut resampler =
+///     CascadedResampler::new(ResampleType::SincFastest, 1, 1000, 384_000).unwrap();
+/// assert_eq!(resampler.stages(), 2);
+///
+/// let mut remaining = input.as_slice();
+/// let mut total = 0;
+/// loop {
+///     let processed = resampler.finalize(remaining, &mut output).unwrap();
+///     remaining = &remaining[processed.read..];
+///     total += processed.written;
+///     if processed.written == 0 && remaining.is_empty() {
+///         break
+///     }
+/// }
+/// assert!(total.abs_diff(384_000) < 40);
+/// ```
+#[derive(Debug)]
+pub struct CascadedResampler {
+    /// The individual conversion stages.
+    stages: Vec<Resampler>,
+    /// The output of each stage but the last, not yet consumed by the
+    /// next one.
+    queues: Vec<Vec<f32>>,
+    channels: u8,
+    /// The dither applied to integer output, if any.
+    dither: Option<Ditherer>,
+    /// Buffers for converting non-`f32` samples.
+    scratch: (Vec<f32>, Vec<f32>),
+}
+
+impl CascadedResampler {
+    /// Create a new cascaded samplerate converter assuming the given
+    /// channel count and sample rates.
+    ///
+    /// The stage working at the lowest samplerates, which is the first
+    /// one when upsampling and the last one when downsampling, uses a
+    /// converter of type `converter_type`. All other stages use
+    /// [`ResampleType::SincFastest`] if `converter_type` is a sinc
+    /// converter and `converter_type` otherwise. Use
+    /// [`with_stage_types`][Self::with_stage_types] for full control.
+    pub fn new(
+        converter_type: ResampleType,
+        channels: u8,
+        from_rate: u32,
+        to_rate: u32,
+    ) -> Result<Self, Error> {
+        let ratio = f64::from(to_rate) / f64::from(from_rate);
+        let count = stage_ratios(ratio)?.len();
+        Self::with_stage_types(channels, from_rate, to_rate, |stage, _ratio| {
+            default_stage_type(converter_type, stage, count, ratio)
+        })
+    }
+
+    /// Create a new cascaded samplerate converter assuming the given
+    /// channel count and sample rates, selecting the converter type of
+    /// each stage using `select`.
+    ///
+    /// `select` is invoked with the index of the stage and the stage's
+    /// conversion ratio. That makes it possible to, for example, use a
+    /// cheap converter for intermediate stages running at high rates
+    /// and a high quality one for the stage closest to the band of
+    /// interest.
+    pub fn with_stage_types<F>(
+        channels: u8,
+        from_rate: u32,
+        to_rate: u32,
+        mut select: F,
+    ) -> Result<Self, Error>
+    where
+        F: FnMut(usize, f64) -> ResampleType,
+    {
+        let () = check_channels(channels)?;
+
+        let ratios = stage_ratios(f64::from(to_rate) / f64::from(from_rate))?;
+        let stages = ratios
+            .into_iter()
+            .enumerate()
+            .map(|(stage, ratio)| Resampler::with_ratio(select(stage, ratio), channels, ratio))
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        let slf = Self {
+            queues: vec![Vec::new(); stages.len() - 1],
+            stages,
+            channels,
+            dither: None,
+            scratch: (Vec::new(), Vec::new()),
+        };
+        Ok(slf)
+    }
+
+    /// Retrieve the number of stages the conversion is split into.
+    #[inline]
+    pub fn stages(&self) -> usize {
+        self.stages.len()
+    }
+
+    /// Retrieve the number of channels the converter works with.
+    #[inline]
+    pub fn channels(&self) -> u8 {
+        self.channels
+    }
+
+    /// Retrieve the combined latency of all stages.
+    pub fn latency(&self) -> Latency {
+        let mut rate = 1.0;
+        let mut input_frames = 0.0;
+        for stage in &self.stages {
+            // The latency of each stage is expressed in terms of its
+            // input rate, which we convert to the overall input rate.
+            input_frames += stage.latency().input_frames as f64 / rate;
+            rate *= stage.ratio();
+        }
+
+        Latency {
+            input_frames: input_frames.ceil() as usize,
+            output_frames: (input_frames * rate).ceil() as usize,
+        }
+    }
+
+    /// Run all stages of the conversion.
+    fn process_impl(
+        &mut self,
+        input: &[f32],
+        output: &mut [f32],
+        finalize: bool,
+    ) -> Result<Processed, Error> {
+        let () = check_frame_aligned(input.len(), self.channels, ErrorKind::InputNotFrameAligned)?;
+        let () = check_frame_aligned(
+            output.len(),
+            self.channels,
+            ErrorKind::OutputNotFrameAligned,
+        )?;
+
+        let (last, stages) = self
+            .stages
+            .split_last_mut()
+            .ok_or_else(|| Error::from(ErrorKind::BadState))?;
+
+        let mut read = 0;
+        for (i, stage) in stages.iter_mut().enumerate() {
+            let (previous, queues) = self.queues.split_at_mut(i);
+            // Can't fail, because there is one queue per stage but the
+            // last.
+            let queue = &mut queues[0];
+            if let Some(previous) = previous.last_mut() {
+                let consumed = stage.process_into_vec(previous, queue, finalize)?;
+                let () = drop(previous.drain(..consumed));
+            } else {
+                read = stage.process_into_vec(input, queue, finalize)?;
+            }
+        }
+
+        let stage_input = self.queues.last().map(Vec::as_slice).unwrap_or(input);
+        let processed = if finalize {
+            last.finalize(stage_input, output)?
+        } else {
+            last.process(stage_input, output)?
+        };
+
+        if let Some(queue) = self.queues.last_mut() {
+            let () = drop(queue.drain(..processed.read));
+        } else {
+            read = processed.read;
+        }
+
+        let processed = Processed {
+            read,
+            written: processed.written,
+        };
+        Ok(processed)
+    }
+
+    /// Invoke `f` with `input` and `output` represented as `f32`
+    /// samples, converting them via scratch buffers if necessary.
+    fn with_float<S, F>(&mut self, input: &[S], output: &mut [S], f: F) -> Result<Processed, Error>
+    where
+        S: Sample,
+        F: FnOnce(&mut Self, &[f32], &mut [f32]) -> Result<Processed, Error>,
+    {
+        if let (Some(input), Some(output)) = (S::as_float(input), S::as_float_mut(output)) {
+            return f(self, input, output)
+        }
+
+        let (mut in_buf, mut out_buf) = take(&mut self.scratch);
+        let () = in_buf.clear();
+        let () = in_buf.resize(input.len(), 0.0);
+        let () = S::to_float(input, &mut in_buf);
+        let () = out_buf.clear();
+        let () = out_buf.resize(output.len(), 0.0);
+
+        let result = f(self, &in_buf, &mut out_buf);
+        if let Ok(processed) = &result {
+            let written = processed.written;
+            if let Some(ditherer) = &mut self.dither {
+                let () = ditherer.apply_for::<S>(&mut out_buf[..written]);
+            }
+            let () = S::from_float(&out_buf[..written], &mut output[..written]);
+        }
+
+        self.scratch = (in_buf, out_buf);
+        result
+    }
+
+    /// Set the dither to apply when producing integer output.
+    ///
+    /// See [`Resampler::set_dither`] for details.
+    pub fn set_dither(&mut self, dither: Dither, seed: u64) {
+        self.dither = (dither != Dither::None).then(|| Ditherer::new(dither, seed, self.channels));
+    }
+
+    /// Perform a samplerate conversion on a block of data.
+    ///
+    /// See [`Resampler::process`] for details. Intermediate results are
+    /// buffered internally, so the output buffer does not limit the
+    /// amount of input consumed.
+    pub fn process<S>(&mut self, input: &[S], output: &mut [S]) -> Result<Processed, Error>
+    where
+        S: Sample,
+    {
+        self.with_float(input, output, |slf, input, output| {
+            slf.process_impl(input, output, false)
+        })
+    }
+
+    /// Perform a samplerate conversion on last block of given input
+    /// data (which may be empty).
+    ///
+    /// See [`Resampler::finalize`] for details.
+    pub fn finalize<S>(&mut self, input: &[S], output: &mut [S]) -> Result<Processed, Error>
+    where
+        S: Sample,
+    {
+        self.with_float(input, output, |slf, input, output| {
+            slf.process_impl(input, output, true)
+        })
+    }
+
+    /// Reset the internal state of all stages.
+    pub fn reset(&mut self) -> Result<(), Error> {
+        for stage in &mut self.stages {
+            let () = stage.reset()?;
+        }
+        let () = self.queues.iter_mut().for_each(Vec::clear);
+        if let Some(ditherer) = &mut self.dither {
+            let () = ditherer.reset();
+        }
+        Ok(())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::f32::consts::PI;
+
+
+    /// Check that we split ratios into the expected stages.
+    #[test]
+    fn stage_splitting() {
+        assert_eq!(stage_ratios(1.0).unwrap(), vec![1.0]);
+        assert_eq!(stage_ratios(256.0).unwrap(), vec![256.0]);
+        assert_eq!(stage_ratios(1.0 / 256.0).unwrap(), vec![1.0 / 256.0]);
+
+        for ratio in [384.0, 1.0 / 1000.0, 65536.0 * 4.0, 1.0e-7] {
+            let ratios = stage_ratios(ratio).unwrap();
+            assert!(ratios.len() > 1);
+            assert!(ratios
+                .iter()
+                .all(|ratio| (1.0 / 256.0..=256.0).contains(ratio)));
+            let product = ratios.iter().product::<f64>();
+            assert!((product / ratio - 1.0).abs() < 1e-12, "{ratio}");
+        }
+
+        assert_eq!(
+            stage_ratios(0.0).unwrap_err().kind(),
+            ErrorKind::BadSrcRatio
+        );
+        assert_eq!(
+            stage_ratios(f64::INFINITY).unwrap_err().kind(),
+            ErrorKind::BadSrcRatio
+        );
+    }
+
+    /// Check that the default converter types of the stages are chosen
+    /// as expected.
+    #[test]
+    fn stage_types() {
+        let best = ResampleType::SincBestQuality;
+        let fastest = ResampleType::SincFastest;
+        let types = |count, ratio| {
+            (0..count)
+                .map(|stage| default_stage_type(best, stage, count, ratio))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(types(1, 2.0), vec![best]);
+        assert_eq!(types(1, 0.5), vec![best]);
+        assert_eq!(types(3, 1000.0), vec![best, fastest, fastest]);
+        assert_eq!(types(3, 1.0 / 1000.0), vec![fastest, fastest, best]);
+
+        let linear = ResampleType::Linear;
+        assert_eq!(default_stage_type(linear, 0, 2, 0.001), linear);
+        assert_eq!(default_stage_type(linear, 1, 2, 0.001), linear);
+    }
+
+    /// Convert `input` in one go.
+    fn convert(resampler: &mut CascadedResampler, input: &[f32]) -> Vec<f32> {
+        let mut output = Vec::new();
+        let mut buffer = vec![0.0f32; 4096];
+        let mut rest = input;
+        loop {
+            let processed = resampler.finalize(rest, &mut buffer).unwrap();
+            rest = &rest[processed.read..];
+            let () = output.extend_from_slice(&buffer[..processed.written]);
+            if processed.written == 0 && rest.is_empty() {
+                break output
+            }
+        }
+    }
+
+    /// Check that upsampling by a factor exceeding what `libsamplerate`
+    /// supports works as expected.
+    #[test]
+    fn extreme_upsampling() {
+        // A 10Hz sine wave sampled at 1kHz, for one second.
+        let freq = 2.0 * PI * 10.0 / 1000.0;
+        let input = (0..1000)
+            .map(|i| (freq * i as f32).sin())
+            .collect::<Vec<_>>();
+
+        let mut resampler =
+            CascadedResampler::new(ResampleType::SincFastest, 1, 1000, 384_000).unwrap();
+        assert_eq!(resampler.stages(), 2);
+        let output = convert(&mut resampler, &input);
+        // Each stage may round its output length, by up to a frame.
+        assert!(output.len().abs_diff(384_000) < 40, "{}", output.len());
+
+        // Check some samples away from the edges against the ideal sine
+        // wave.
+        let freq = 2.0 * PI * 10.0 / 384_000.0;
+        for i in (10_000..370_000).step_by(9973) {
+            let expected = (freq * i as f32).sin();
+            assert!((output[i] - expected).abs() < 1e-2, "{i}");
+        }
+    }
+
+    /// Check that downsampling by a factor exceeding what
+    /// `libsamplerate` supports works as expected.
+    #[test]
+    fn extreme_downsampling() {
+        let mut resampler = CascadedResampler::with_stage_types(2, 2_000_000, 1_000, |stage, _| {
+            if stage == 0 {
+                ResampleType::Linear
+            } else {
+                ResampleType::SincFastest
+            }
+        })
+        .unwrap();
+        assert_eq!(resampler.stages(), 2);
+
+        let input = vec![0.5f32; 2 * 400_000];
+        let output = convert(&mut resampler, &input);
+        assert!(output.len().abs_diff(2 * 200) <= 2 * 2, "{}", output.len());
+        // A DC signal should remain just that.
+        assert!(output[20..380]
+            .iter()
+            .all(|sample| (sample - 0.5).abs() < 1e-2));
+    }
+
+    /// Make sure that a ratio inside the supported range results in a
+    /// single stage behaving like a `Resampler`.
+    #[test]
+    fn single_stage() {
+        let input = (0..4096)
+            .map(|i| (i as f32 / 32.0).sin())
+            .collect::<Vec<_>>();
+        let mut resampler = CascadedResampler::new(ResampleType::Linear, 1, 44100, 48000).unwrap();
+        assert_eq!(resampler.stages(), 1);
+        let output = convert(&mut resampler, &input);
+        let reference = crate::convert(ResampleType::Linear, 1, 44100, 48000, &input).unwrap();
+        assert_eq!(output, reference);
+    }
+
+    /// Check that integer output gets dithered as configured.
+    #[test]
+    fn dithering() {
+        let input = (0..4096)
+            .map(|i| ((i as f32 / 32.0).sin() * 4.0) as i16)
+            .collect::<Vec<_>>();
+        let mut output = vec![0i16; 8192];
+        let mut expected = vec![0i16; 8192];
+
+        let mut resampler = CascadedResampler::new(ResampleType::Linear, 1, 3, 4).unwrap();
+        let () = resampler.set_dither(Dither::NoiseShaped, 5);
+        let mut reference = Resampler::new(ResampleType::Linear, 1, 3, 4).unwrap();
+        let () = reference.set_dither(Dither::NoiseShaped, 5);
+
+        for _ in 0..2 {
+            let processed = resampler.finalize(&input, &mut output).unwrap();
+            let reference_processed = reference.finalize(&input, &mut expected).unwrap();
+            assert_eq!(processed, reference_processed);
+            assert_eq!(output, expected);
+
+            // Resetting restarts the noise sequence.
+            let () = resampler.reset().unwrap();
+            let () = reference.reset().unwrap();
+        }
+
+        let plain = crate::convert(ResampleType::Linear, 1, 3, 4, &input).unwrap();
+        assert_ne!(output[..plain.len()], plain);
+    }
+
+    /// Check that the combined latency accounts for all stages.
+    #[test]
+    fn cascaded_latency() {
+        let resampler =
+            CascadedResampler::new(ResampleType::ZeroOrderHold, 1, 1, 65536 * 4).unwrap();
+        let latency = resampler.latency();
+        // Three stages with a ratio of 64 each, every one of which
+        // delays by one of its input frames.
+        assert_eq!(resampler.stages(), 3);
+        assert_eq!(latency.input_frames, 2);
+        assert_eq!(latency.output_frames, 262144 + 4096 + 64);
+    }
+}
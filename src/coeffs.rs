@@ -0,0 +1,6253 @@
+/// A table of sinc filter coefficients.
+#[derive(Debug)]
+pub(crate) struct Coefficients {
+    /// The number of coefficients between two adjacent input frames.
+    pub increment: i32,
+    /// The right half of the symmetric filter, followed by a final zero
+    /// coefficient.
+    pub coeffs: &'static [f64],
+}
+
+/// The coefficients of `libsamplerate`'s fastest sinc converter, as
+/// defined in `libsamplerate/src/fastest_coeffs.h`.
+#[rustfmt::skip]
+pub(crate) static FASTEST: Coefficients = Coefficients {
+    increment: 128,
+    coeffs: &[
+        0.8314723729548406, 0.8314140055403082, 0.8312389182662239, 0.8309471560364805,
+        0.8305387936754506, 0.8300139359048007, 0.829372717311067, 0.8286153023039675,
+        0.8277418850654906, 0.8267526894897519, 0.8256479691136782, 0.8244280070384999,
+        0.8230931158421088, 0.8216436374822932, 0.820079943190897, 0.8184024333589336,
+        0.8166115374126891, 0.8147077136808542, 0.8126914492527578, 0.810563259827706,
+        0.8083236895555238, 0.8059733108683144, 0.8035127243035178, 0.8009425583183319,
+        0.7982634690955347, 0.7954761403408008, 0.7925812830715608, 0.7895796353974999,
+        0.7864719622927345, 0.7832590553597861, 0.7799417325854009, 0.7765208380883079,
+        0.7729972418590181, 0.7693718394917182, 0.7656455519083907, 0.7618193250752202,
+        0.7578941297114085, 0.75387096099047, 0.7497508382341534, 0.7455348045990282,
+        0.7412239267559091, 0.7368192945621922, 0.7323220207272096, 0.7277332404707382,
+        0.7230541111747668, 0.7182858120286328, 0.7134295436676645, 0.7084865278054423,
+        0.7034580068598046, 0.6983452435727197, 0.6931495206241758, 0.6878721402401823,
+        0.6825144237950476, 0.6770777114080585, 0.6715633615346847, 0.6659727505524748,
+        0.6603072723417421, 0.6545683378612285, 0.6487573747188605, 0.6428758267377449,
+        0.6369251535175622, 0.6309068299914925, 0.6248223459788378, 0.618673205733471,
+        0.6124609274882937, 0.6061870429958176, 0.5998530970650603, 0.5934606470948939,
+        0.587011262603993, 0.5805065247575691, 0.5739480258910253, 0.5673373690306881,
+        0.5606761674118097, 0.5539660439939615, 0.5472086309740107, 0.540405569296826,
+        0.5335585081638802, 0.5266691045399227, 0.519739022657877, 0.5127699335221193,
+        0.5057635144103363, 0.49872144837408156, 0.49164542373824194, 0.48453713359954687,
+        0.4773982753243089, 0.4702305500455456, 0.4630356621596601, 0.45581531882284615,
+        0.44857122944737954, 0.4413051051979601, 0.43401865848828397, 0.426713602477997,
+        0.4193916505702035, 0.4120545159096897, 0.4047039108820342, 0.39734154661376364,
+        0.3899691324737216, 0.38258837557580677, 0.3752009802832578, 0.36780864771462407,
+        0.36041307525160987, 0.35301595604892577, 0.34561897854633084, 0.3382238259830064,
+        0.33083217591442643, 0.32344569973188103, 0.31606606218480376, 0.30869492090606615,
+        0.30133392594037883, 0.29398471927596526, 0.2866489343796444, 0.27932819573548956,
+        0.27202411838718255, 0.26473830748424504, 0.2574723578322598, 0.2502278534472434,
+        0.2430063671143057, 0.23580945995073394, 0.22863868097364773, 0.221495566672346,
+        0.21438164058549813, 0.20729841288329814, 0.20024737995471736, 0.19323002399998696,
+        0.18624781262843065, 0.17930219846177975, 0.1723946187430858, 0.1655264949513563,
+        0.1586992324220288, 0.15191421997340107, 0.14517282953913227, 0.13847641580692122,
+        0.13182631586348045, 0.1252238488459012, 0.1186703155995239, 0.1121669983424119,
+        0.10571516033652745, 0.09931604556570865, 0.09297087842054055, 0.08668086339021538,
+        0.08044718476146778, 0.07427100632467455, 0.0681534710872002, 0.062095700994075964,
+        0.056098796656083555, 0.05016383708532477, 0.044291879438350536, 0.03848395876691715,
+        0.032741087776440074, 0.027064256592210862, 0.021454432533437127, 0.015912559895166958,
+        0.01043955973815518, 0.005036329686723058, -0.0002962562653363852, -0.005557347940758284,
+        -0.010746119156668763, -0.015861767894264547, -0.02090351646027436, -0.02587061164016228,
+        -0.030762324843041484, -0.03557795223826597, -0.04031681488367698, -0.04497825884547271,
+        -0.04956165530968754, -0.05406640068525568, -0.058491916698647464, -0.06283765048006339,
+        -0.06710307464117826, -0.07128768734442695, -0.07539101236382824, -0.07941259913734837,
+        -0.08335202281080083, -0.08720888427329597, -0.09098281018423904, -0.09467345299189553,
+        -0.09828049094353275, -0.10180362808715743, -0.10524259426486772, -0.10859714509784131,
+        -0.11186706196298879, -0.11505215196129615, -0.11815224787789005, -0.12116720813386275,
+        -0.12409691672988547, -0.1269412831816602, -0.12970024244724368, -0.13237375484629538,
+        -0.134961805971292, -0.13746440659076414, -0.13988159254460444, -0.14221342463150774,
+        -0.14445998848859573, -0.1466213944632947, -0.1486977774775248, -0.15068929688426966,
+        -0.15259613631659547, -0.15441850352919073, -0.15615663023250032, -0.15781077191952922,
+        -0.15938120768540143, -0.16086824003974304, -0.16227219471198515, -0.16359342044966663,
+        -0.16483228880982406, -0.16598919394356315, -0.1670645523739011, -0.1680588027669756,
+        -0.16897240569671704, -0.1698058434030868, -0.17055961954397153, -0.17123425894085362,
+        -0.17183030731834426, -0.17234833103770233, -0.17278891682443426, -0.17315267149009808,
+        -0.17344022164840978, -0.17365221342578224, -0.17378931216639795, -0.17385220213194205,
+        -0.17384158619611167, -0.1737581855340211, -0.173602739306629, -0.17337600434030606,
+        -0.17307875480167, -0.1727117818678186, -0.17227589339208005, -0.17177191356541696,
+        -0.17120068257361137, -0.17056305625036014, -0.16985990572641713, -0.16909211707491323,
+        -0.16826059095298915, -0.16736624223987528, -0.1664099996715579, -0.16539280547216664,
+        -0.16431561498222255, -0.16317939628388384, -0.1619851298233312, -0.1607338080304298,
+        -0.15942643493581357, -0.15806402578552742, -0.15664760665337205, -0.15517821405109483,
+        -0.15365689453656647, -0.15208470432008847, -0.15046270886897506, -0.14879198251054884,
+        -0.1470736080336997, -0.14530867628914731, -0.14349828578855098, -0.14164354230261156,
+        -0.13974555845830988, -0.13780545333542232, -0.13582435206246107, -0.13380338541218056,
+        -0.13174368939679199, -0.1296464048630303, -0.12751267708721534, -0.1253436553704524,
+        -0.12314049263410476, -0.12090434501569147, -0.11863637146534192, -0.11633773334294982,
+        -0.11400959401616652, -0.11165311845937272, -0.10926947285376279, -0.10685982418868374,
+        -0.10442533986436033, -0.10196718729614546, -0.09948653352042636, -0.0969845448023236,
+        -0.0944623862453118, -0.09192122140289481, -0.08936221189246712, -0.08678651701148482,
+        -0.0841952933560806, -0.0815896944422444, -0.07897087032969614, -0.07633996724857395,
+        -0.07369812722906105, -0.07104648773407105, -0.06838618129511131, -0.06571833515144229,
+        -0.06304407089265011, -0.06036450410474374, -0.057680744019894814, -0.05499389316992677,
+        -0.052305047043666106, -0.04961529374826099, -0.046925713674577804, -0.04423737916677291,
+        -0.04155135419614956, -0.03886869403939535, -0.036190444961301194, -0.033517643902057324,
+        -0.030851318169222867, -0.02819248513445957, -0.025542151935121302, -0.022901315180788754,
+        -0.02027096066483427, -0.017652063081102502, -0.015045585745788879, -0.012452480324595469,
+        -0.00987368656524285, -0.00731013203541311, -0.004762731866198076, -0.0022323885011229787,
+        0.0002800085491837061, 0.002773582946609769, 0.005247471759402746, 0.007700825690174399,
+        0.010132809298008765, 0.012542601214614067, 0.014929394354466257, 0.017292396118888467,
+        0.01963082859401953, 0.021943928742620973, 0.024230948589679373, 0.02649115540176034,
+        0.028723831860073355, 0.030928276227210335, 0.03310380250752171, 0.03524974060109815,
+        0.03736543645132536, 0.03945025218598582, 0.041503566251881716, 0.043524773542953754,
+        0.04551328552187877, 0.047468530335124444, 0.04938995292144782, 0.05127701511382427,
+        0.05312919573479358, 0.05494599068521946, 0.05672691302645212, 0.058471493055894025,
+        0.06017927837596553, 0.06184983395647356, 0.06348274219038647, 0.06507760294302269,
+        0.06663403359466058, 0.06815166907658146, 0.0696301619005592, 0.07106918218181396,
+        0.07246841765544651, 0.07382757368637408, 0.07514637327279307, 0.07642455704319125,
+        0.07766188324693975, 0.0788581277384927, 0.08001308395522898, 0.0811265628889681,
+        0.08219839305120132, 0.08322842043207034, 0.08421650845314327, 0.08516253791402405,
+        0.0860664069328435, 0.08692803088068182, 0.08774734230996861, 0.0885242908769152,
+        0.08925884325803062, 0.08995098306078032, 0.09060071072844224, 0.09120804343922173,
+        0.09177301499968787, 0.09229567573259266, 0.09277609235914151, 0.0932143478757789,
+        0.09361054142556212, 0.09396478816419132, 0.09427721912077028, 0.0945479810533706,
+        0.09477723629947782, 0.09496516262139514, 0.09511195304668464, 0.09521781570372802,
+        0.09528297365248768, 0.09530766471055312, 0.09529214127455764, 0.09523667013705363,
+        0.09514153229893095, 0.09500702277747357, 0.09483345041013908, 0.09462113765415903,
+        0.09437042038205042, 0.09408164767313096, 0.09375518160113969, 0.09339139701805416,
+        0.09299068133420475, 0.09255343429478492, 0.0920800677528558, 0.0915710054389489,
+        0.09102668272736597, 0.09044754639927832, 0.08983405440273282, 0.08918667560966502,
+        0.08850588957002381, 0.08779218626311908, 0.08704606584628972, 0.0862680384010084,
+        0.08545862367652217, 0.08461835083114291, 0.08374775817129203, 0.08284739288841148,
+        0.08191781079384715, 0.08095957605181801, 0.0799732609105758, 0.07895944543187164,
+        0.07791871721883403, 0.07685167114237249, 0.07575890906621645, 0.07464103957070001,
+        0.07349867767540327, 0.0723324445607602, 0.07114296728874744, 0.06993087852275816,
+        0.06869681624677838, 0.06744142348397161, 0.06616534801478345, 0.06486924209467618,
+        0.063553762171602, 0.06221956860332542, 0.06086732537470225, 0.05949769981502533,
+        0.058111362315542876, 0.0567089860472592, 0.055291246679122066, 0.053858822096705394,
+        0.05241239212149289, 0.05095263823086463, 0.04948024327889576, 0.04799589121806624,
+        0.046500266821988455, 0.04499405540925153, 0.04347794256848534, 0.041952613884744756,
+        0.040418754667312005, 0.038877049679016853, 0.03732818286717149, 0.03577283709621694,
+        0.03421169388217585, 0.03264543312900653, 0.031074732866950623, 0.029500268992967323,
+        0.02792271501334402, 0.026342741788574136, 0.024761017280588233, 0.02317820630242938,
+        0.021594970270453876, 0.020011966959145314, 0.018429850258623242, 0.01684926993492885,
+        0.015270871393167509, 0.013695295443586988, 0.012123178070669184, 0.01055515020531051,
+        0.008991837500165537, 0.007433860108226963, 0.005881832464712737, 0.0043363630723294525,
+        0.002798054289982051, 0.00126750212499337, -0.0002547039710995504, -0.0017679813031102718,
+        -0.0032717541290672547, -0.004765453853318049, -0.006248519215815338, -0.007720396477528744,
+        -0.009180539601927771, -0.010628410432483318, -0.012063478866136672, -0.013485223022687525,
+        -0.014893129410051997, -0.01628669308534763, -0.01766541781175944, -0.019028816211146687,
+        -0.020376409912349576, -0.02170772969515796, -0.023022315629906167, -0.024319717212658836,
+        -0.025599493495956162, -0.026861213215086943, -0.02810445490986145, -0.029328807041857495,
+        -0.03053386810711313, -0.03171924674424522, -0.03288456183797126, -0.03402944261801547,
+        -0.03515352875338182, -0.03625647044197927, -0.037337928495584524, -0.038397574420130996,
+        -0.03943509049131558, -0.040450169825513006, -0.04144251644599386, -0.042411845344441576,
+        -0.043357882537765076, -0.04428036512020848, -0.045179041310758755, -0.04605367049585399,
+        -0.04690402326739855, -0.04772988145609141, -0.04853103816007717, -0.0493072977689298,
+        -0.05005847598298259, -0.0507843998280174, -0.05148490766533034, -0.052159849197191466,
+        -0.052809085467717086, -0.053432488859178236, -0.05402994308376554, -0.054601343170838104,
+        -0.05514659544968109, -0.05566561752779934, -0.056158338264780436, -0.056624697741753896,
+        -0.05706464722648329, -0.057478149134123885, -0.05786517698368296, -0.058225715350219885,
+        -0.05855975981282588, -0.0588673168984242, -0.05914840402143181, -0.05940304941932873,
+        -0.0596312920841784, -0.059833181690145475, -0.06000877851706066, -0.06015815337008105,
+        -0.06028138749549597, -0.060378572492732645, -0.06044981022261194, -0.06049521271191166,
+        -0.06051490205429143, -0.06050901030763769, -0.06047767938788471, -0.060421060959374495,
+        -0.06033931632181249, -0.060232616293883726, -0.060101141093589654, -0.059945080215371635,
+        -0.05976463230408434, -0.05956000502588493, -0.059331414936105976, -0.059079087344177376,
+        -0.058803256175668464, -0.05850416383151732, -0.058182061044519846, -0.057837206733146566,
+        -0.057469867852761716, -0.05708031924431517, -0.0566688434805821, -0.05623573071002165,
+        -0.05578127849833198, -0.055305791667774676, -0.05480958213434539, -0.054292968742864926,
+        -0.053756277100070235, -0.053199839405780734, -0.0526239942822169, -0.05202908660155116,
+        -0.05141546731176708, -0.05078349326090736, -0.05013352701978844, -0.0494659367032618,
+        -0.04878109579010059, -0.04807938294159196, -0.04736118181891402, -0.046626880899379365,
+        -0.04587687329162213, -0.04511155654981135, -0.04433133248697061, -0.04353660698748225,
+        -0.042727789818858185, -0.04190529444285667, -0.04106953782602533, -0.04022094024974987,
+        -0.039359925119888506, -0.03848691877607178, -0.03760235030074677, -0.03670665132804523,
+        -0.03580025585255365, -0.03488360003806403, -0.03395712202638497, -0.03302126174628788,
+        -0.032076460722668225, -0.0311231618859974, -0.03016180938214276, -0.029192848382630022,
+        -0.028216724895425246, -0.02723388557631072, -0.02624477754092855, -0.025249848177565953,
+        -0.024249544960756052, -0.02324431526576479, -0.022234606184038202, -0.021220864339678708,
+        -0.02020353570702217, -0.019183065429384295, -0.01815989763904597, -0.017134475278544784,
+        -0.016107239923339796, -0.015078631605916413, -0.014049088641395795, -0.013019047454713741,
+        -0.011988942409432334, -0.010959205638247127, -0.009930266875250747, -0.00890255329001434,
+        -0.007876489323545621, -0.0068524965261824115, -0.005830993397479086, -0.0048123952281420215,
+        -0.0037971139440693058, -0.002785557952549687, -0.001778131990672277, -0.0007752369760001324,
+        0.00022273014044212665, 0.0012153765188170624, 0.0022023135727110873, 0.003183157108912462,
+        0.0041575274646834855, 0.005125049642483808, 0.006085353442100425, 0.007038073590142452,
+        0.007982849866859612, 0.008919327230245805, 0.009847155937387853, 0.010765991663024036,
+        0.011675495615275625, 0.012575334648517622, 0.013465181373356073, 0.014344714263678778,
+        0.015213617760751178, 0.01607158237432687, 0.016918304780745762, 0.01775348791799362,
+        0.01857684107769816, 0.01938807999403826, 0.02018692692954359, 0.020973110757765177,
+        0.021746367042796304, 0.022506438115626613, 0.023253073147312592, 0.023986028218949094,
+        0.024705066388428818, 0.02540995775397622, 0.026100479514446166, 0.02677641602637648,
+        0.027437558857787484, 0.02808370683872028, 0.02871466610850978, 0.02933025015978691,
+        0.029930279879208717, 0.030514583584913907, 0.031082997060704866, 0.03163536358695606,
+        0.0321715339682534, 0.032691366557767505, 0.03319472727836598, 0.033681489640472656,
+        0.03415153475668076, 0.03460475135312985, 0.03504103577765689, 0.03546029200473409,
+        0.03586243163720602, 0.03624737390484047, 0.0366150456597097, 0.036965381368417906,
+        0.03729832310119407, 0.03761382051786916, 0.037911830850758166, 0.03819231888447003,
+        0.03845525693266617, 0.03870062481179451, 0.038928409811822114, 0.0391386066639944,
+        0.03933121750564763, 0.03950625184210333, 0.03966372650567554, 0.0398036656118203,
+        0.03992610051245978, 0.040031069746514436, 0.040118618987676304, 0.040188800989459164,
+        0.040241675527560895, 0.04027730933957444, 0.04029577606208686, 0.040297156165202686,
+        0.040281536884534, 0.04024901215069469, 0.04019968251634326, 0.04013365508081312,
+        0.04005104341237664, 0.0399519674681838, 0.03983655351192239, 0.0397049340292426,
+        0.039557247640994324, 0.03939363901432266, 0.03921425877166829, 0.03901926339772279,
+        0.03880881514438597, 0.03858308193377406, 0.03834223725933097, 0.03808646008509027,
+        0.03781593474314096, 0.03753085082934683, 0.03723140309737332, 0.03691779135107231,
+        0.03659022033527905, 0.036248899625074035, 0.0358940435135636, 0.03552587089823389,
+        0.03514460516593095, 0.03475047407652395, 0.034343709645304796, 0.03392454802418039,
+        0.03349322938171275, 0.033049997782062766, 0.03259510106289388, 0.03212879071229152,
+        0.031651321744754816, 0.03116295257631711, 0.03066394489885145, 0.030154563553618487,
+        0.0296350764041117, 0.029105754208260358, 0.0285668704900414, 0.028018701410562813,
+        0.02746152563867035, 0.026895624221138177, 0.026321280452496414, 0.025738779744554675,
+        0.025148409495676646, 0.02455045895986179, 0.023945219115690673, 0.02333298253518946,
+        0.02271404325266834, 0.0220886966335909, 0.021457239243527187, 0.020819968717247193,
+        0.020177183628007963, 0.019529183357088496, 0.018876267963626927, 0.01821873805481234,
+        0.01755689465648454, 0.016891039084194585, 0.0162214728147775, 0.015548497358489637,
+        0.01487241413176074, 0.014193524330612408, 0.01351212880479253, 0.012828527932675428,
+        0.012143021496975845, 0.011455908561327487, 0.010767487347771346, 0.010078055115202964,
+        0.009387908038824081, 0.008697341090645601, 0.008006647921086409, 0.007316120741713129,
+        0.006626050209164985, 0.00593672531030636, 0.005248433248650203, 0.004561459332093787,
+        0.0038760868620079892, 0.0031925970237204836, 0.002511268778431767, 0.0018323787566039199,
+        0.0011562011528586855, 0.000483007622422852, -0.00018693282084307003, -0.0008533539047974553,
+        -0.0015159921977167526, -0.0021745872053079256, -0.0028288814660003786, -0.0034786206444867283,
+        -0.004123553623479657, -0.004763432593657182, -0.005398013141763717, -0.006027054336841599,
+        -0.006650318814563988, -0.0072675728596431795, -0.00787858648628855, -0.008483133516690078,
+        -0.00908099165750268, -0.009671942574310047, -0.010255771964044967, -0.010832269625346665,
+        -0.011401229526833942, -0.011962449873276111, -0.012515733169644565, -0.013060886283026065,
+        -0.013597720502384574, -0.014126051596153908, -0.014645699867650156, -0.015156490208288461,
+        -0.015658252148593708, -0.01615081990699439, -0.016634032436388026, -0.017107733468471675,
+        -0.017571771555827523, -0.01802600011175682, -0.018470277447858608, -0.0189044668093441,
+        -0.019328436408086992, -0.019742059453403453, -0.020145214180561435, -0.02053778387701831,
+        -0.020919656906385222, -0.02129072673012154, -0.02165089192695842, -0.022000056210056677,
+        -0.02233812844190122, -0.02266502264693718, -0.022980658021953905, -0.023284958944222296,
+        -0.023577854977394, -0.023859280875170173, -0.024129176582749615, -0.024387487236066163,
+        -0.024634163158826203, -0.024869159857359203, -0.025092438013293285, -0.025303963474069796,
+        -0.02550370724131132, -0.02569164545705934, -0.0258677593878966, -0.026032035406971753,
+        -0.026184464973945325, -0.026325044612873164, -0.026453775888049695, -0.026570665377828956,
+        -0.026675724646445916, -0.02676897021385928, -0.026850423523637944, -0.026920110908915218,
+        -0.026978063556434218, -0.02702431746870879, -0.0270589134243262, -0.027081896936416758,
+        -0.027093318209317648, -0.0270932320934577, -0.02708169803849154, -0.027058780044711454,
+        -0.027024546612766338, -0.026979070691717143, -0.02692242962545906, -0.026854705097541988,
+        -0.026775983074419887, -0.026686353747161197, -0.02658591147165319, -0.026474754707332293,
+        -0.026352985954474557, -0.02622071169007966, -0.026078042302382573, -0.025925092024028495,
+        -0.025761978863944983, -0.02558882453794713, -0.025405754398112476, -0.025212897360960468,
+        -0.025010385834473948, -0.024798355643999754, -0.02457694595706434, -0.02434629920714351,
+        -0.024106561016422213, -0.023857880117584458, -0.02360040827466931, -0.02333430020303317,
+        -0.02305971348845595, -0.02277680850543029, -0.022485748334672578, -0.02218669867989542,
+        -0.02187982778387993, -0.021565306343887664, -0.02124330742645177, -0.020914006381586706,
+        -0.020577580756455657, -0.020234210208536035, -0.019884076418322214, -0.01952736300160475,
+        -0.01916425542136708, -0.018794940899337156, -0.018419608327236225, -0.018038448177761075,
+        -0.01765165241534257, -0.017259414406716772, -0.01686192883134984, -0.01645939159175501,
+        -0.016051999723740204, -0.01563995130662643, -0.015223445373473433, -0.01480268182135311,
+        -0.014377861321707992, -0.013949185230831676, -0.013516855500511548, -0.013081074588868171,
+        -0.012642045371431623, -0.012199971052488705, -0.011755055076740283, -0.011307501041303573,
+        -0.01085751260809529, -0.010405293416632606, -0.009951046997285364, -0.009494976685016523,
+        -0.009037285533643568, -0.00857817623065582, -0.008117851012622143, -0.0076565115812205695,
+        -0.007194359019924887, -0.0067315937113785135, -0.006268415255489421, -0.005805022388276972,
+        -0.005341612901500893, -0.0048783835631049065, -0.004415530038502645, -0.003953246812737984,
+        -0.003491727113546363, -0.003031162835347472, -0.002571744464196632, -0.0021136610037213845,
+        -0.001657099902072138, -0.0012022469799107488, -0.0007492863594652033, -0.00029840039467315076,
+        0.0001502303975592903, 0.0005964274049602602, 0.0010400139863339, 0.0014808155368165395,
+        0.0019186595519271167, 0.002353375690389584, 0.0027847958357057633, 0.003212754156460317,
+        0.0036370871653360554, 0.00405763377682292, 0.0044742353636006696, 0.004886735811578388,
+        0.005294981573574659, 0.005698821721620479, 0.0060981079978713985, 0.006492694864111875,
+        0.006882439549839985, 0.007267202098916773, 0.00764684541476875, 0.0080212353041316,
+        0.00839024051932213, 0.008753732799029908, 0.009111586907616188, 0.009463680672913062,
+        0.009809895022512337, 0.01015011401853687, 0.010484224890887845, 0.010812118068959601,
+        0.011133687211818379, 0.011448829236837571, 0.011757444346786734, 0.01205943605536978,
+        0.01235471121120875, 0.012643180020272314, 0.01292475606674528, 0.013199356332339436,
+        0.013466901214045103, 0.013727314540323072, 0.013980523585738893, 0.014226459084039958,
+        0.01446505523967888, 0.01469624973778536, 0.014919983752590082, 0.015136201954305937,
+        0.015344852514469782, 0.015545887109752299, 0.01573926092424014, 0.015924932650198998,
+        0.01610286448732375, 0.016273022140483956, 0.0164353748159746, 0.016589895216279234,
+        0.01673655953335737, 0.01687534744046547, 0.01700624208252284, 0.01712923006503437,
+        0.017244301441581695, 0.01735144969989619, 0.017450671746526723, 0.017541967890115747,
+        0.01762534182329915, 0.017700800603243177, 0.017768354630835495, 0.017828017628545002,
+        0.01787980661696773, 0.01792374189007491, 0.017959846989181554, 0.017988148675652436,
+        0.0180086769023645, 0.01802146478394398, 0.018026548565797832, 0.018023967591958526,
+        0.01801376427176296, 0.01799598404538539, 0.017970675348245202, 0.017937889574311156,
+        0.01789768103832332, 0.01785010693695468, 0.017795227308934857, 0.01773310499415853,
+        0.017663805591801425, 0.01758739741746707, 0.017503951459388337, 0.017413541333706782,
+        0.017316243238855143, 0.017212135909065965, 0.01710130056703233, 0.016983820875744713,
+        0.01685978288952956, 0.01672927500431473, 0.016592387907147288, 0.016449214524989875,
+        0.016299849972820957, 0.016144391501065457, 0.015982938442381987, 0.015815592157832948,
+        0.0156424559824643, 0.015463635170321158, 0.015279236838926648, 0.015089369913250635,
+        0.014894145069194628, 0.014693674676621348, 0.014488072741954239, 0.014277454850375694,
+        0.014061938107650005, 0.01384164108159884, 0.013616683743256378, 0.013387187407730743,
+        0.013153274674799926, 0.012915069369268525, 0.012672696481112548, 0.01242628210544006,
+        0.012175953382293344, 0.011921838436321275, 0.011664066316346911, 0.011402766934858699,
+        0.01113807100745104, 0.010870109992240551, 0.010599016029285759, 0.010324921880034726,
+        0.010047960866828336, 0.009768266812484076, 0.0094859739799868, 0.009201217012312052,
+        0.008914130872406634, 0.008624850783353006, 0.00833351216874106, 0.008040250593273353,
+        0.007745201703627334, 0.007448501169599685, 0.007150284625556524, 0.006850687612213134,
+        0.006549845518766932, 0.006247893525407362, 0.005944966546224683, 0.005641199172541722,
+        0.005336725616689458, 0.0050316796562501764, 0.0047261945787882105, 0.004420403127091227,
+        0.004114437444942456, 0.0038084290234442187, 0.003502508647914384, 0.0031968063453742417,
+        0.00289145133264915, 0.0025865719650996497, 0.0022822956860032587, 0.0019787489766050627,
+        0.0016760573068546525, 0.0013743450868485777, 0.0010737356189940007, 0.000774351050912206,
+        0.0004763123290969321, 0.00017973915334491383, -0.00011525006802615044, -0.0004085382621574302,
+        -0.0007000097348105189, -0.0009895502126975294, -0.0012770468849652211, -0.0015623884438191423,
+        -0.001845465124275963, -0.0021261687430297765, -0.002404392736421798, -0.0026800321975003947,
+        -0.00295298391216083, -0.003223146394354267, -0.003490419920354526, -0.0037547065620742665,
+        -0.00401591021941966, -0.004273936651675969, -0.004528693507914639, -0.004780090356414084,
+        -0.005028038713087429, -0.005272452068908788, -0.005513245916333078, -0.005750337774701759,
+        -0.00598364721463032, -0.00621309588137129, -0.006438607517148467, -0.006660107982458851,
+        -0.006877525276337167, -0.007090789555581354, -0.007299833152934846, -0.0075045905942244286,
+        -0.00770499861445137, -0.00790099617283428, -0.008092524466803487, -0.008279526944945818,
+        -0.008461949318900212, -0.008639739574204792, -0.008812847980095845, -0.008981227098260904,
+        -0.009144831790546856, -0.009303619225626428, -0.009457548884624958, -0.009606582565711098,
+        -0.009750684387655147, -0.009889820792358728, -0.010023960546360879, -0.010153074741324684,
+        -0.01027713679351085, -0.01039612244224303, -0.010510009747371605, -0.010618779085742531,
+        -0.010722413146677866, -0.010820896926475889, -0.010914217721938126, -0.011002365122931729,
+        -0.011085331003995622, -0.011163109514999488, -0.011235697070864697, -0.011303092340356822,
+        -0.011365296233960211, -0.011422311890844096, -0.011474144664931803, -0.011520802110083645,
+        -0.011562293964404995, -0.011598632133691065, -0.011629830674020701, -0.01165590577351133,
+        -0.011676875733247521, -0.011692760947396333, -0.011703583882522661, -0.011709369056117776,
+        -0.011710143014355159, -0.01170593430908728, -0.01169677347409801, -0.011682693000624838,
+        -0.01166372731216586, -0.01163991273858644, -0.01161128748954097, -0.011577891627224692,
+        -0.011539767038472037, -0.011496957406216448, -0.011449508180328498, -0.011397466547847955,
+        -0.011340881402626625, -0.01127980331439846, -0.011214284497293517, -0.011144378777812738,
+        -0.011070141562280911, -0.010991629803794454, -0.010908901968681693, -0.010822018002493139,
+        -0.010731039295538976, -0.010636028647991598, -0.010537050234571042, -0.010434169568831014,
+        -0.010327453467063244, -0.010216970011838621, -0.01010278851520252, -0.009984979481543088,
+        -0.009863614570150064, -0.00973876655748247, -0.009610509299163652, -0.009478917691721381,
+        -0.009344067634091756, -0.009206035988904694, -0.009064900543569584, -0.008920739971179146,
+        -0.008773633791249683, -0.008623662330315892, -0.008470906682398624, -0.008315448669363063,
+        -0.008157370801186165, -0.007996756236150582, -0.00783368874098352, -0.007668252650957988,
+        -0.007500532829974368, -0.007330614630640181, -0.007158583854364815, -0.006984526711487861,
+        -0.00680852978145715, -0.006630679973074814, -0.006451064484827608, -0.0062697707653189,
+        -0.0060868864738193185, -0.005902499440952033, -0.005716697629530005, -0.005529569095561002,
+        -0.005341201949436966, -0.005151684317323298, -0.004961104302764436, -0.004769549948521031,
+        -0.004577109198654324, -0.004383869860872772, -0.004189919569156639, -0.003995345746674397,
+        -0.003800235569006753, -0.0036046759276915654, -0.00340875339410504, -0.0032125541836919794,
+        -0.0030161641205599258, -0.002819668602450057, -0.0026231525660980926, -0.0024267004529987513,
+        -0.002230396175585759, -0.00203432308384081, -0.0018385639323427753, -0.0016432008477699136,
+        -0.001448315296866559, -0.0012539880548853044, -0.001060299174516722, -0.0008673279553164822,
+        -0.0006751529136415187, -0.0004838517531045453, -0.00029350133555776993, -0.00010417765261523048,
+        8.40442022771479e-05, 0.00027109006121382864, 0.0004568867086362173, 0.0006413619075646119,
+        0.0008244444252469582, 0.001006064058217503, 0.0011861516567560058, 0.0013646391487425749,
+        0.001541459562898259, 0.0017165470514076964, 0.0018898369119146117, 0.002061265608886451,
+        0.0022307707943406314, 0.002398291327928309, 0.0025637672963694106, 0.002727140032235004,
+        0.002888352132072167, 0.0030473474738668526, 0.003204071233841768, 0.003358469902584622,
+        0.0035104913005047007, 0.0036600845926136752, 0.003807200302629363, 0.003951790326398562,
+        0.004093807944639113, 0.004233207834997027, 0.004369946083420042, 0.004503980194844037,
+        0.0046352691031938216, 0.004763773180696146, 0.0048894542465061815, 0.005012275574646748,
+        0.005132201901261443, 0.005249199431182073, 0.005363235843811903, 0.005474280298325711,
+        0.0055823034381889715, 0.005687277394997296, 0.005789175791639706, 0.005887973744786731,
+        0.0059836478667079, 0.006076176266420603, 0.006165538550173851, 0.006251715821271666,
+        0.006334690679238632, 0.00641444721833308, 0.006490971025411749, 0.006564249177151036,
+        0.006634270236629583, 0.006701024249277955, 0.0067645027382004464, 0.0068246986988752525,
+        0.006881606593238715, 0.006935222343160264, 0.006985543323314089, 0.0070325683534550616,
+        0.007076297690104768, 0.007116733017656151, 0.007153877438903049, 0.007187735465002918,
+        0.007218313004880324, 0.007245617354079386, 0.007269657183073181, 0.007290442525038754,
+        0.0073079847631063516, 0.007322296617091443, 0.007333392129718843, 0.007341286652347754,
+        0.007345996830207458, 0.007347540587152582, 0.007345937109948303, 0.007341206832094526,
+        0.007333371417199675, 0.00732245374191355, 0.007308477878430149, 0.00729146907657012,
+        0.007271453745453871, 0.007248459434775655, 0.007222514815689451, 0.007193649661317447,
+        0.007161894826892011, 0.007127282229542319, 0.007089844827736559, 0.007049616600391122,
+        0.007006632525658017, 0.006960928559401773, 0.006912541613377356, 0.006861509533120709,
+        0.006807871075563246, 0.006751665886382153, 0.006692934477098063, 0.006631718201931706,
+        0.006568059234431593, 0.006502000543884108, 0.006433585871518258, 0.006362859706516468,
+        0.006289867261843731, 0.006214654449906435, 0.006137267858053325, 0.006057754723929908,
+        0.005976162910698568, 0.0058925408821359415, 0.005806937677619658, 0.005719402887015878,
+        0.005629986625480022, 0.005538739508181461, 0.0054457126249651015, 0.005350957514960402,
+        0.005254526141150229, 0.005156470864910621, 0.005056844420533395, 0.004955699889742567,
+        0.0048530906762164865, 0.0047490704801264735, 0.004643693272703717, 0.004537013270845156,
+        0.00442908491176952, 0.004319962827734852, 0.004209701820827711, 0.00409835683783573,
+        0.003985982945213113, 0.003872635304151011, 0.003758369145761657, 0.00364323974638825,
+        0.003527302403049288, 0.0034106124090287865, 0.0032932250296212975, 0.0031751954780423314,
+        0.003056578891513386, 0.0029374303075316013, 0.002817804640332968, 0.002697756657558961,
+        0.002577340957135147, 0.0024566119443713446, 0.0023356238092914713, 0.0022144305042027922,
+        0.0020930857215116115, 0.001971642871795542, 0.0018501550621386753, 0.0017286750747394334,
+        0.0016072553457974813, 0.0014859479446884323, 0.001364804553433178, 0.001243876446469433,
+        0.0011232144707327774, 0.00100286902605367, 0.0008828900458778472, 0.0007633269783159986,
+        0.0006442287675297924, 0.0005256438354597824, 0.0004076200639018966, 0.00029020477693750605,
+        0.00017344472372380577, 5.7386061648424466e-05, -5.792566014471298e-05, -0.00017244551685513998,
+        -0.0002861292237446032, -0.0003989331514716838, -0.000510814341036775, -0.0006217305183339872,
+        -0.0007316401083052928, -0.0008405022486937491, -0.0009482768033915554, -0.0010549243753801684,
+        -0.0011604063192586396, -0.0012646847533579317, -0.001367722571437448, -0.001469483453962317,
+        -0.00156993187895821, -0.0016690331324417367, -0.001766753318424874, -0.0018630593684907549,
+        -0.001957919050940559, -0.002051300979508701, -0.002143174621646441, -0.002233510306372054,
+        -0.0023222792316874566, -0.0024094534715601986, -0.0024950059824710175, -0.0025789106095262444,
+        -0.0026611420921357166, -0.0027416760692558, -0.0028204890841986006, -0.0028975585890073843,
+        -0.002972862948399697, -0.0030463814432783384, -0.0031180942738125083, -0.003187982562089304,
+        -0.003256028354338196, -0.0033222146227294934, -0.0033865252667492286, -0.0034489451141522494,
+        -0.003509459921496019, -0.003568056374257548, -0.003624722086536015, -0.0036794456003440184,
+        -0.003732216384490321, -0.003783024833057431, -0.00383186226347698, -0.003878720914206811,
+        -0.003923593942013039, -0.003966475418861113, -0.004007360328419562, -0.004046244562180958,
+        -0.004083124915203688, -0.004117999081479602, -0.0041508656489314755, -0.004181724094045414,
+        -0.004210574776142588, -0.004237418931295567, -0.0042622586658941026, -0.004285096949865588,
+        -0.004305937609555559, -0.004324785320273619, -0.0043416455985102595, -0.004356524793830437,
+        -0.004369430080449404, -0.004380369448496873, -0.0043893516949754866, -0.0043963864144194184,
+        -0.0044014839892596936, -0.004404655579902017, -0.00440591311452398, -0.004405269278597591,
+        -0.004402737504143993, -0.004398331958726873, -0.004392067534191166, -0.004383959835153928,
+        -0.004374025167254108, -0.004362280525168145, -0.004348743580398348, -0.004333432668840994,
+        -0.0043163667781412835, -0.004297565534842268, -0.004277049191334785, -0.004254838612615751,
+        -0.004230955262861972, -0.004205421191826733, -0.004178259021066525, -0.0041494919300052845,
+        -0.0041191436418433385, -0.004087238409318643, -0.004053801000327643, -0.004018856683413084,
+        -0.0039824312131263905, -0.003944550815271873, -0.0039052421720403427, -0.003864532407039497,
+        -0.003822449070228571, -0.0037790201227645833, -0.003734273921767918, -0.0036882392050141153,
+        -0.0036409450755599446, -0.003592420986310465, -0.0035426967245350514, -0.0034918023963392423,
+        -0.003439768411099998, -0.003386625465871527, -0.003332404529769012, -0.0032771368283372646,
+        -0.0032208538279117275, -0.0031635872199786692, -0.0031053689055419087, -0.0030462309795027927,
+        -0.0029862057150607984, -0.0029253255481410975, -0.0028636230618566934, -0.002801130971011062,
+        -0.002737882106648848, -0.002673909400660717, -0.0026092458704490536, -0.002543924603661382,
+        -0.0024779787429974097, -0.002411441471096549, -0.0023443459955118473, -0.0022767255337768242,
+        -0.002208613298571089, -0.0021400424829909974, -0.002071046245931093, -0.0020016576975824363,
+        -0.0019319098850532233, -0.0018618357781179542, -0.0017914682550998877, -0.0017208400888932694,
+        -0.0016499839331296634, -0.0015789323084946282, -0.0015077175891991226, -0.0014363719896112739,
+        -0.0013649275510530488, -0.0012934161287670503, -0.0012218693790577884, -0.001150318746612463,
+        -0.0010787954520053498, -0.0010073304793905974, -0.0009359545643873922, -0.0008646981821617813,
+        -0.0007935915357093359, -0.0007226645443422593, -0.0006519468323851523, -0.000581467718082645,
+        -0.0005112562027231014, -0.0004413409599809767, -0.000371750325482115, -0.0003025122865943121,
+        -0.0002336544724468609, -0.0001652041441815438, -9.718818543829501e-05, -2.963309307783331e-05,
+        3.7435031855788884e-05, 0.00010399049293066552, 0.00017000800687786091, 0.0002354627118957719,
+        0.00030033017570559457, 0.0003645864033564214, 0.0004282078447775518, 0.0004911714020771867,
+        0.0005534544365850295, 0.0006150347756382141, 0.0006758907191086852, 0.0007360010456711585,
+        0.0007953450188107362, 0.000853902392568786, 0.0009116534170269539, 0.0009685788435281079,
+        0.0010246599296340924, 0.0010798784438195452, 0.0011342166699018486, 0.0011876574112066986,
+        0.0012401839944696212, 0.0012917802734731718, 0.0013424306324204115, 0.001392119989044484,
+        0.0014408337974554236, 0.0014885580507239906, 0.0015352792832038996, 0.0015809845725927422,
+        0.001625661541732878, 0.0016692983601530047, 0.0017118837453518588, 0.001753406963824951,
+        0.0017938578318361588, 0.0018332267159349995, 0.0018715045332218735, 0.0019086827513624112,
+        0.0019447533883528863, 0.001979709012038751, 0.0020135427393879577, 0.002046248235521405,
+        0.0020778197125025156, 0.0021082519278884295, 0.0021375401830449263, 0.0021656803212277524,
+        0.0021926687254327365, 0.002218502316017533, 0.0022431785480975124, 0.0022666954087187416,
+        0.0022890514138108552, 0.002310245604923009, 0.0023302775457455685, 0.002349147318421133,
+        0.0023668555196477417, 0.0023834032565777405, 0.002398792142515538, 0.002413024292417808,
+        0.002426102318199491, 0.0024380293238492143, 0.0024488089003577252, 0.0024584451204630936,
+        0.0024669425332161975, 0.002474306158370578, 0.002480541480600296, 0.002485654443549729,
+        0.002489651443719328, 0.0024925393241911923, 0.0024943253681987076, 0.002495017292544062,
+        0.002494623240868025, 0.002493151776775957, 0.0024906118768243703, 0.002487012923372181,
+        0.002482364697301016, 0.002476677370608772, 0.0024699614988808257, 0.0024622280136431854,
+        0.0024534882146020117, 0.0024437537617738514, 0.0024330366675110087, 0.002421349288426526,
+        0.0024087043172231204, 0.00239511477443066, 0.0023805940000565166, 0.0023651556451533594,
+        0.002348813663308834, 0.002331582302061613, 0.002313476094248285, 0.0022945098492856133,
+        0.0022746986443926034, 0.0022540578157568616, 0.0022326029496497297, 0.0022103498734946663,
+        0.0021873146468932895, 0.002163513552613552, 0.0021389630875444376, 0.002113679953621647,
+        0.0020876810487286067, 0.0020609834575771283, 0.002033604442572236, 0.0020055614346652334,
+        0.0019768720241995384, 0.0019475539517533735, 0.0019176250989837015, 0.0018871034794754437,
+        0.0018560072296003093, 0.0018243545993892796, 0.0017921639434229004, 0.0017594537117433442,
+        0.001726242440792422, 0.0016925487443793627, 0.0016583913046824784, 0.001623788863288299,
+        0.0015887602122724956, 0.001553324185325904, 0.001517499648929626, 0.0014813054935830813,
+        0.0014447606250882082, 0.0014078839558938534, 0.001370694396503609, 0.001333210846950737,
+        0.001295452188343445, 0.0012574372744840682, 0.0012191849235653244, 0.0011807139099469979,
+        0.001142042956016064, 0.0011031907241336198, 0.0010641758086714676, 0.0010250167281415357,
+        0.0009857319174207523, 0.000946339720074761, 0.0009068583807826531, 0.0008673060378659402,
+        0.0008277007159241546, 0.0007880603185798258, 0.0007484026213351407, 0.0007087452645429956,
+        0.0006691057464946163, 0.0006295014166261961, 0.0005899494688465774, 0.0005504669349883847,
+        0.0005110706783845695, 0.00047177738757226664, 0.0004326035701261339, 0.0003935655466228401,
+        0.00035467944473864093, 0.0003159611934814612, 0.0002774265175596446, 0.00023909093188828395,
+        0.00020096973623513458, 0.00016307801000722762, 0.000125430607179672, 8.804215136769107e-05,
+        5.09270310433271e-05, 1.409939489770512e-05, -2.2426852649968996e-05, -5.863805579593558e-05,
+        -9.45208115468091e-05, -0.0001300619737653185, -0.00016524865706598019, -0.00020006824056034874,
+        -0.00023450837145076977, -0.0002685569684727674, -0.00030220222518515314, -0.0003354326131077147,
+        -0.000368236884706315, -0.00040060407622497646, -0.00043252351036502274, -0.0004639847988110956,
+        -0.0004949778446042426, -0.0005254928443619638, -0.0005555202903455402, -0.0005850509723747737,
+        -0.0006140759795906102, -0.0006425867020657953, -0.000670574832264203, -0.0006980323663491937,
+        -0.000724951605341828, -0.0007513251561291563, -0.0007771459323238204, -0.0008024071549752993,
+        -0.0008271023531339032, -0.0008512253642682587, -0.0008747703345374318, -0.0008977317189185018,
+        -0.0009201042811908276, -0.0009418830937780502, -0.0009630635374491659, -0.000983641300879761,
+        -0.001003612380074813, -0.0010229730776544913, -0.0010417200020042324, -0.0010598500662906997,
+        -0.0010773604873450242, -0.0010942487844151426, -0.0011105127777884809, -0.0011261505872870844,
+        -0.001141160630636594, -0.0011555416217110075, -0.0011692925686549558, -0.0011824127718854458,
+        -0.0011949018219748694, -0.0012067595974172654, -0.001217986262279823, -0.0012285822637416607,
+        -0.0012385483295218728, -0.0012478854651990106, -0.0012565949514240636, -0.0012646783410291994,
+        -0.0012721374560342841, -0.0012789743845536224, -0.0012851914776049918, -0.0012907913458233627,
+        -0.0012957768560815412, -0.001300151128020151, -0.0013039175304892345, -0.0013070796779038874,
+        -0.001309641426516364, -0.0013116068706070228, -0.00131298033859659, -0.0013137663890821839,
+        -0.0013139698067996172, -0.0013135955985144128, -0.0013126489888440852, -0.0013111354160141953,
+        -0.0013090605275507023, -0.0013064301759111606, -0.0013032504140573148, -0.0012995274909716364,
+        -0.0012952678471203959, -0.0012904781098657865, -0.0012851650888297342, -0.0012793357712119196,
+        -0.0012729973170646012, -0.0012661570545268405, -0.0012588224750206462, -0.0012510012284116714,
+        -0.0012427011181369867, -0.0012339300963025138, -0.0012246962587526566, -0.0012150078401147054,
+        -0.00120487320882053, -0.0011943008621080843, -0.001183299421005293, -0.0011718776252987503,
+        -0.001160044328489811, -0.0011478084927404476, -0.001135179183811475, -0.0011221655659954448,
+        -0.0011087768970467567, -0.0010950225231113283, -0.001080911873658268, -0.0010664544564158544,
+        -0.0010516598523142608, -0.0010365377104372745, -0.0010210977429853851, -0.001005349720252431,
+        -0.0009893034656182059, -0.0009729688505590704, -0.0009563557896789561, -0.0009394742357627572,
+        -0.0009223341748544664, -0.0009049456213619465, -0.0008873186131905981, -0.0008694632069078946,
+        -0.0008513894729408575, -0.0008331074908083917, -0.0008146273443905232, -0.0007959591172364441,
+        -0.0007771128879131822, -0.000758098725396869, -0.0007389266845083352, -0.0007196068013948847,
+        -0.0007001490890599036, -0.0006805635329421295, -0.0006608600865461619, -0.0006410486671259203,
+        -0.0006211391514225145, -0.0006011413714583192, -0.0005810651103884813, -0.0005609200984115642,
+        -0.0005407160087406455, -0.0005204624536363296, -0.000500168980502928, -0.00047984506804925244,
+        -0.00045950012251517975, -0.00043914347396530897, -0.00041878437265073775, -0.0003984319854403702,
+        -0.00037809539232252804, -0.0003577835829782186, -0.0003375054534268407, -0.00031726980274546213,
+        -0.0002970853298625397, -0.0002769606304268579, -0.00025690419375278906, -0.00023692439984234176,
+        -0.0002170295164850202, -0.00019722769643604998, -0.00017752697467372108, -0.00015793526573636482,
+        -0.00013846036113967144, -0.00011910992687478068, -9.989150098770966e-05, -8.081249124046588e-05,
+        -6.188017285437614e-05, -4.310168633589017e-05, -2.448403538526073e-05, -6.034084888228075e-06,
+        1.2241441008794292e-05, 3.0335960740163017e-05, 4.824303707866437e-05, 6.595637880943033e-05,
+        8.346984231996158e-05, 0.00010077743310633378, 0.0001178733071954815, 0.00013475177248367488,
+        0.00015140728999133755, 0.00016783447503422588, 0.00018402809831123983, 0.00019998308690901117,
+        0.00021569452522360896, 0.0002311576557995392, 0.00024636788008645895, 0.0002613207591138905,
+        0.0002760120140844712, 0.00029043752688594085, 0.00030459334052262266, 0.000318475659466672,
+        0.0003320808499297644, 0.0003454054400557288, 0.00035844612003481537, 0.00037119974214014,
+        0.0003836633206870534, 0.00039583403191609083, 0.00040770921380030615, 0.00041928636577766813,
+        0.00043056314840938273, 0.0004415373829649137, 0.0004522070509346569, 0.00046257029347096155,
+        0.00047262541075863523, 0.00048237086131568455, 0.0004918052612253282, 0.0005009273833002945,
+        0.0005097361561803405, 0.000518230663364083, 0.000526410142176161, 0.0005342739826708581,
+        0.0005418217264732157, 0.0005490530655588192, 0.0005559678409733431, 0.0005625660414930846,
+        0.0005688478022275873, 0.0005748134031656061, 0.0005804632676655853, 0.000585797960891954,
+        0.0005908181881983526, 0.0005955247934592056, 0.0005999187573507934, 0.0006040011955831719,
+        0.0006077733570842161, 0.0006112366221371235, 0.0006143925004726712, 0.0006172426293175756,
+        0.0006197887714002969, 0.0006220328129156463, 0.0006239767614495424, 0.0006256227438652827,
+        0.0006269730041527349, 0.000628029901241786, 0.0006287959067814608, 0.0006292736028860901,
+        0.000629465679849936, 0.0006293749338316372, 0.0006290042645099176, 0.000628356672711924,
+        0.0006274352580156027, 0.0006262432163275306, 0.0006247838374375801, 0.0006230605025518316,
+        0.0006210766818051281, 0.0006188359317546633, 0.0006163418928559996, 0.0006135982869228994,
+        0.00061060891457236, 0.0006073776526562198, 0.0006039084516807206, 0.0006002053332153818,
+        0.0005962723872925475, 0.0005921137697989629, 0.0005877336998607163, 0.0005831364572228834,
+        0.0005783263796251961, 0.0005733078601750587, 0.0005680853447191993, 0.0005626633292152647,
+        0.0005570463571046293, 0.0005512390166876918, 0.0005452459385029186, 0.0005390717927108595,
+        0.0005327212864843899, 0.0005261991614063709, 0.00051951019087595, 0.0005126591775246474,
+        0.0005056509506434617, 0.0004984903636220707, 0.0004911822914013257, 0.0004837316279401224,
+        0.0004761432836977768, 0.0004684221831329527, 0.00046057326222024924, 0.0004526014659854642,
+        0.0004445117460605884, 0.00043630905825949875, 0.00042799836017539257, 0.0004195846088008896,
+        0.00041107275817178307, 0.00040246775703533394, 0.00039377454654404966, 0.00038499805797583247,
+        0.0003761432104813137, 0.0003672149088593124, 0.0003582180413611366, 0.00034915747752460434,
+        0.0003400380660385257, 0.0003308646326384275, 0.00032164197803420934, 0.0003123748758705025,
+        0.0003030680707203687, 0.00029372627611304493, 0.0002843541725963241, 0.0002749564058342517,
+        0.0002655375847406861, 0.0002561022796493327, 0.0002466550205207327, 0.0002372002951868516,
+        0.00022774254763362358, 0.00021828617632204634, 0.00020883553254821602, 0.00019939491884276962,
+        0.00018996858741009343, 0.00018056073860773746, 0.0001711755194663687, 0.0001618170222505711,
+        0.00015248928306086065, 0.00014319628047715862, 0.00013394193424402052, 0.00012473010399781812,
+        0.00011556458803614877, 0.00010644912212962402, 9.738737837624447e-05, 8.838296409844376e-05,
+        7.943942078305014e-05, 7.056022306411644e-05, 6.174877774884398e-05, 5.300842288652778e-05,
+        4.434242688070189e-05, 3.575398764435049e-05, 2.7246231798296384e-05, 1.882221391267319e-05,
+        1.0484915791467377e-05, 2.2372458000139825e-06, -5.917961764588419e-06, -1.3977947260302262e-05,
+        -2.1940026244310825e-05, -2.9801589974731278e-05, -3.756010586993882e-05, -4.521311792583468e-05,
+        -5.275824709115795e-05, -6.019319160111698e-05, -6.751572726961551e-05, -7.472370774026868e-05,
+        -8.181506469659783e-05, -8.878780803158132e-05, -9.564002597701741e-05, -0.0001023698851929323,
+        -0.00010897563081744749, -0.00011545558647744523, -0.00012180815426046264, -0.00012803181464816598,
+        -0.0001341251264118674, -0.00014008672647048372, -0.00014591532971144666, -0.00015160972877492537,
+        -0.00015716879380196493, -0.00016259147214691587, -0.00016787678805476233, -0.0001730238423037601,
+        -0.00017803181181402387, -0.00018289994922251953, -0.00018762758242506342, -0.00019221411408586664,
+        -0.0001966590211152343, -0.00020096185411596455, -0.00020512223679906781, -0.00020913986536942464,
+        -0.00021301450788196392, -0.00021674600356901672, -0.00022033426213945517, -0.00022377926305028862,
+        -0.00022708105475132667, -0.0002302397539035982, -0.00023325554457216254, -0.00023612867739402474,
+        -0.0002388594687217591, -0.00024144829974360663, -0.00024389561558066456, -0.000246201924361898,
+        -0.0002483677962776488, -0.00025039386261236225, -0.0002522808147572102, -0.00025402940320333727,
+        -0.0002556404365164249, -0.000257114780293309, -0.0002584533561013219, -0.00025965714040113034,
+        -0.0002607271634537309, -0.0002616645082123734, -0.000262470309200078, -0.00026314575137350877,
+        -0.0002636920689739056, -0.0002641105443657736, -0.0002644025068640847, -0.0002645693315506748,
+        -0.0002646124380805656, -0.0002645332894789168, -0.0002643333909293266, -0.0002640142885541769,
+        -0.00026357756818773045, -0.00026302485414268594, -0.0002623578079708788, -0.00026157812721882683,
+        -0.00026068754417880435, -0.0002596878246361311, -0.00025858076661335516, -0.000257368199111998,
+        -0.0002560519808525352, -0.0002546339990132729, -0.0002531161679687704, -0.0002515004280284672,
+        -0.0002497887441761485, -0.0002479831048108885, -0.000246085520490099, -0.00024409802267530528,
+        -0.00024202266248125664, -0.00023986150942898385, -0.00023761665020339626, -0.00023529018741600267,
+        -0.00023288423837334536, -0.00023040093385170694, -0.00022784241687866076, -0.0002252108415220049,
+        -0.00022250837168664073, -0.00021973717991990662, -0.00021689944622590795, -0.0002139973568893458,
+        -0.00021103310330935254, -0.00020800888084381952, -0.00020492688766470866, -0.00020178932362480744,
+        -0.00019859838913639997, -0.00019535628406228727, -0.0001920652066196105, -0.00018872735229689435,
+        -0.0001853449127847314, -0.00018192007492049976, -0.00017845501964753045, -0.00017495192098907397,
+        -0.0001714129450374616, -0.00016784024895880718, -0.00016423598001359, -0.00016060227459346397,
+        -0.00015694125727460758, -0.00015325503988792698, -0.00014954572060640008, -0.00014581538304986563,
+        -0.00014206609540751117, -0.00013829990957833795, -0.00013451886032983082, -0.00013072496447509325,
+        -0.00012692022006865447, -0.00012310660562117376, -0.00011928607933321913, -0.00011546057834835084,
+        -0.00011163201802563295, -0.00010780229123177983, -0.0001039732676530656, -0.00010014679312714857,
+        -9.632468899492308e-05, -9.250875147253469e-05, -8.870075104364525e-05, -8.490243187205455e-05,
+        -8.111551123473935e-05, -7.734167897540196e-05, -7.358259697856749e-05, -6.98398986642887e-05,
+        -6.611518850347508e-05, -6.241004155388997e-05, -5.872600301682004e-05, -5.50645878143975e-05,
+        -5.142728018761288e-05, -4.781553331493807e-05, -4.4230768951576636e-05, -4.0674377089271515e-05,
+        -3.7147715636632475e-05, -3.365211011990273e-05, -3.0188853404113053e-05, -2.6759205434522494e-05,
+        -2.3364392998266595e-05, -2.0005609506090695e-05, -1.668401479407972e-05, -1.3400734945243966e-05,
+        -1.0156862130843785e-05, -6.953454471288137e-06, -3.791535916502508e-06, -6.720961455532937e-07,
+        2.403909514592796e-06, 5.435560151374561e-06, 8.421969310176401e-06, 1.1362285019311093e-05,
+        1.4255689796623826e-05, 1.7101400637934125e-05, 1.9898668987539083e-05, 2.2646780690979317e-05,
+        2.5345055930313168e-05, 2.7992849142122074e-05, 3.058954891850533e-05, 3.313457789129254e-05,
+        3.562739259974516e-05, 3.806748334199475e-05, 4.045437401050686e-05, 4.2787621911804446e-05,
+        4.506681757077533e-05, 4.729158451980895e-05, 4.946157907307132e-05, 5.157649008620058e-05,
+        5.3636038701733434e-05, 5.563997808054819e-05, 5.758809311964388e-05, 5.94802001565562e-05,
+        6.131614666073665e-05, 6.309581091219654e-05, 6.481910166775049e-05, 6.648595781517153e-05,
+        6.809634801560432e-05, 6.965027033453476e-05, 7.114775186168107e-05, 7.258884832012639e-05,
+        7.397364366501632e-05, 7.530224967219037e-05, 7.657480551705956e-05, 7.779147734408788e-05,
+        7.895245782721208e-05, 8.005796572155411e-05, 8.110824540675841e-05, 8.210356642230978e-05,
+        8.304422299517175e-05, 8.393053356009906e-05, 8.476284027296242e-05, 8.554150851743798e-05,
+        8.626692640540297e-05, 8.69395042713942e-05, 8.755967416145368e-05, 8.812788931673113e-05,
+        8.864462365216615e-05, 8.911037123060266e-05, 8.952564573267175e-05, 8.989097992278759e-05,
+        9.020692511158626e-05, 9.047405061514904e-05, 9.069294321134019e-05, 9.086420659359403e-05,
+        9.098846082247715e-05, 9.1066341775351e-05, 9.109850059446561e-05, 9.108560313379573e-05,
+        9.102832940494566e-05, 9.092737302243101e-05, 9.078344064865671e-05, 9.059725143888847e-05,
+        9.036953648653461e-05, 9.010103826902988e-05, 8.979251009462322e-05, 8.944471555036044e-05,
+        8.905842795155385e-05, 8.863442979302031e-05, 8.817351220237175e-05, 8.767647439563223e-05,
+        8.71441231354561e-05, 8.657727219221165e-05, 8.597674180819556e-05, 8.534335816523483e-05,
+        8.46779528559295e-05, 8.398136235878424e-05, 8.325442751747235e-05, 8.249799302446961e-05,
+        8.171290690929113e-05, 8.090002003155805e-05, 8.006018557911778e-05, 7.919425857143187e-05,
+        7.830309536844697e-05, 7.738755318514904e-05, 7.64484896120067e-05, 7.548676214149437e-05,
+        7.450322770088567e-05, 7.349874219149973e-05, 7.247416003457813e-05, 7.143033372396398e-05,
+        7.036811338574951e-05, 6.928834634504853e-05, 6.819187670005569e-05, 6.707954490352981e-05,
+        6.595218735185457e-05, 6.48106359818019e-05, 6.365571787514035e-05, 6.248825487120001e-05,
+        6.130906318752294e-05, 6.011895304870505e-05, 5.891872832353985e-05, 5.7709186170560026e-05,
+        5.649111669207684e-05, 5.526530259680121e-05, 5.4032518871133194e-05, 5.2793532459191864e-05,
+        5.1549101951661215e-05, 5.0299977283516225e-05, 4.904689944068231e-05, 4.779060017569437e-05,
+        4.6531801732387224e-05, 4.5271216579674544e-05, 4.400954715443852e-05, 4.2747485613576216e-05,
+        4.148571359521144e-05, 4.0224901989105e-05, 3.8965710716269505e-05, 3.770878851780196e-05,
+        3.645477275293213e-05, 3.5204289206290735e-05, 3.395795190438547e-05, 3.2716362941275954e-05,
+        3.1480112313423024e-05, 3.0249777763702374e-05, 2.9025924634538722e-05, 2.7809105730143296e-05,
+        2.6599861187802535e-05, 2.5398718358191985e-05, 2.4206191694651145e-05, 2.3022782651381306e-05,
+        2.1848979590504787e-05, 2.0685257697922205e-05, 1.9532078907909787e-05, 1.8389891836381623e-05,
+        1.7259131722747817e-05, 1.614022038028566e-05, 1.5033566154949253e-05, 1.3939563892526607e-05,
+        1.285859491405932e-05, 1.1791026999426415e-05, 1.0737214379002725e-05, 9.697497733286292e-06,
+        8.672204200395724e-06, 7.661647391323147e-06, 6.666127412846224e-06, 5.6859308979697695e-06,
+        4.721331043794107e-06, 3.7725876566837472e-06, 2.83994720461858e-06, 1.9236428765979563e-06,
+        1.0238946489767718e-06, 1.4090935859878324e-07, -7.251192174009259e-07, -1.5740102752657375e-06,
+        -2.4055958918840118e-06, -3.2197209258043597e-06, -4.016242912132399e-06, -4.7950319514522345e-06,
+        -5.555970592919599e-06, -6.298953711669154e-06, -7.023888380689953e-06, -7.730693737306623e-06,
+        -8.419300844430433e-06, -9.089652546720496e-06, -9.741703321814202e-06, -1.0375419126777015e-05,
+        -1.0990777239930609e-05, -1.1587766098209585e-05, -1.2166385130207182e-05, -1.2726644585063802e-05,
+        -1.3268565357359683e-05, -1.3792178808164656e-05, -1.4297526582407026e-05, -1.4784660422716915e-05,
+        -1.5253641979907778e-05, -1.5704542620245486e-05, -1.613744322967458e-05, -1.655243401515099e-05,
+        -1.6949614303244378e-05, -1.7329092336165128e-05, -1.7690985065376594e-05, -1.8035417942945513e-05,
+        -1.8362524710788885e-05, -1.86724471879743e-05, -1.8965335056225002e-05, -1.9241345643786634e-05,
+        -1.950064370780644e-05, -1.97434012153796e-05, -1.996979712341026e-05, -2.0180017157439467e-05,
+        -2.0374253589586587e-05, -2.0552705015754475e-05, -2.0715576132238387e-05, -2.0863077511889824e-05,
+        -2.0995425379972245e-05, -2.1112841389852976e-05, -2.121555239866905e-05, -2.1303790243106262e-05,
+        -2.1377791515425443e-05, -2.143779733987124e-05, -2.148405314959461e-05, -2.1516808464220414e-05,
+        -2.153631666818629e-05, -2.154283478998039e-05, -2.1536623282400503e-05, -2.1517945803957726e-05,
+        -2.1487069001542283e-05, -2.144426229446941e-05, -2.1389797660021196e-05, -2.1323949420593842e-05,
+        -2.1246994032563604e-05, -2.1159209876976704e-05, -2.1060877052168902e-05, -2.0952277168417356e-05,
+        -2.0833693144724694e-05, -2.0705409007832376e-05, -2.056770969355816e-05, -2.042088085054989e-05,
+        -2.0265208646545086e-05, -2.0100979577222588e-05, -1.992848027773079e-05, -1.9747997336973355e-05,
+        -1.9559817114731306e-05, -1.9364225561696592e-05, -1.9161508042490898e-05, -1.8951949161739218e-05,
+        -1.8735832593265907e-05, -1.851344091247753e-05, -1.8285055431994575e-05, -1.8050956040590425e-05,
+        -1.7811421045494276e-05, -1.7566727018110702e-05, -1.731714864320671e-05, -1.7062958571613484e-05,
+        -1.680442727648817e-05, -1.6541822913176952e-05, -1.6275411182719152e-05, -1.6005455199028454e-05,
+        -1.5732215359784975e-05, -1.5455949221068457e-05, -1.5176911375761739e-05, -1.4895353335748381e-05,
+        -1.4611523417928096e-05, -1.4325666634069267e-05, -1.4038024584516152e-05, -1.374883535576446e-05,
+        -1.3458333421918204e-05, -1.3166749550036128e-05, -1.2874310709375013e-05, -1.2581239984533223e-05,
+        -1.2287756492496718e-05, -1.1994075303585959e-05, -1.1700407366300736e-05, -1.1406959436056016e-05,
+        -1.1113934007802205e-05, -1.0821529252516897e-05, -1.0529938957556848e-05, -1.0239352470853966e-05,
+        -9.949954648937423e-06, -9.661925808762716e-06, -9.37544168332492e-06, -9.090673381032464e-06,
+        -8.807787348814227e-06, -8.526945338932488e-06, -8.24830437947059e-06, -7.972016748463332e-06,
+        -7.698229951634914e-06, -7.427086703709253e-06, -7.158724913253609e-06, -6.893277671016333e-06,
+        -6.630873241715737e-06, -6.371635059238993e-06, -6.1156817252032145e-06, -5.863127010834295e-06,
+        -5.614079862114045e-06, -5.36864440814655e-06, -5.1269199726917185e-06, -4.889001088814442e-06,
+        -4.6549775165945744e-06, -4.424934263843052e-06, -4.198951609766781e-06, -3.977105131525545e-06,
+        -3.759465733621583e-06, -3.5460996800612946e-06, -3.3370686292291023e-06, -3.1324296714105315e-06,
+        -2.9322353689022028e-06, -2.736533798643659e-06, -2.5453685973084184e-06, -2.3587790087861633e-06,
+        -2.1767999339919193e-06, -1.9994619829341063e-06, -1.8267915289745379e-06, -1.6588107652114638e-06,
+        -1.4955377629179191e-06, -1.3369865319654702e-06, -1.183167083164323e-06, -1.0340854924491147e-06,
+        -8.897439668410137e-07, -7.501409121148274e-07, -6.152710021009188e-07, -4.851252495499759e-07,
+        -3.5969107849128393e-07, -2.389523980112168e-07, -1.2288967738246455e-07, 0.0,
+    ],
+};
+
+/// The coefficients of `libsamplerate`'s medium quality sinc converter, as
+/// defined in `libsamplerate/src/mid_qual_coeffs.h`.
+#[rustfmt::skip]
+pub(crate) static MEDIUM_QUALITY: Coefficients = Coefficients {
+    increment: 491,
+    coeffs: &[
+        0.9190632349861385, 0.919057927383162, 0.9190420046848706, 0.9190154672231939,
+        0.9189783155513206, 0.9189305504437105, 0.9188721728960548, 0.9188031841252661,
+        0.9187235855694376, 0.9186333788878118, 0.9185325659607401, 0.9184211488896346,
+        0.9182991299969165, 0.9181665118259577, 0.918023297141012, 0.9178694889271632,
+        0.9177050903902308, 0.9175301049567006, 0.9173445362736408, 0.9171483882086021,
+        0.9169416648495361, 0.9167243705046784, 0.9164965097024516, 0.9162580871913482,
+        0.9160091079398145, 0.9157495771361238, 0.9154795001882499, 0.9151988827237356,
+        0.9149077305895451, 0.9146060498519228, 0.9142938467962469, 0.9139711279268634,
+        0.9136378999669315, 0.9132941698582575, 0.9129399447611106, 0.9125752320540597,
+        0.9122000393337837, 0.9118143744148778, 0.9114182453296666, 0.9110116603280005,
+        0.9105946278770478, 0.9101671566610972, 0.9097292555813196, 0.9092809337555684,
+        0.9088222005181412, 0.9083530654195477, 0.9078735382262776, 0.9073836289205554,
+        0.9068833477000902, 0.9063727049778222, 0.905851711381668, 0.9053203777542538,
+        0.9047787151526441, 0.9042267348480693, 0.9036644483256435, 0.9030918672840802,
+        0.9025090036354035, 0.9019158695046503, 0.9013124772295639, 0.9006988393602999,
+        0.9000749686590991, 0.8994408780999827, 0.8987965808684226, 0.8981420903610176,
+        0.8974774201851591, 0.8968025841586922, 0.8961175963095726, 0.895422470875522,
+        0.894717222303665, 0.8940018652501847, 0.8932764145799431, 0.8925408853661255,
+        0.8917952928898526, 0.8910396526398144, 0.8902739803118754, 0.8894982918086901,
+        0.888712603239308, 0.8879169309187701, 0.8871112913677079, 0.8862957013119301,
+        0.8854701776820082, 0.8846347376128602, 0.8837893984433177, 0.8829341777157025,
+        0.8820690931753858, 0.8811941627703521, 0.8803094046507521, 0.8794148371684513,
+        0.8785104788765753, 0.8775963485290545, 0.8766724650801458, 0.8757388476839771,
+        0.8747955156940627, 0.8738424886628277, 0.8728797863411224, 0.871907428677728,
+        0.8709254358188718, 0.8699338281077154, 0.8689326260838612, 0.8679218504828314,
+        0.8669015222355692, 0.8658716624679086, 0.8648322925000503, 0.8637834338460406,
+        0.8627251082132331, 0.8616573375017506, 0.8605801438039482, 0.8594935494038546,
+        0.8583975767766349, 0.857292248588016, 0.8561775876937461, 0.8550536171390093,
+        0.8539203601578668, 0.8527778401726749, 0.851626080793513, 0.8504651058175859,
+        0.8492949392286508, 0.8481156051964116, 0.8469271280759231, 0.8457295324069899,
+        0.8445228429135612, 0.8433070845031135, 0.8420822822660411, 0.840848461475026,
+        0.8396056475844215, 0.8383538662296192, 0.8370931432264129, 0.8358235045703656,
+        0.8345449764361591, 0.833257585176953, 0.8319613573237311, 0.8306563195846454,
+        0.8293424988443554, 0.8280199221633631, 0.826688616777346, 0.8253486100964876,
+        0.8239999297047862, 0.8226426033593939, 0.8212766589899133, 0.8199021246977173,
+        0.8185190287552487, 0.8171273996053308, 0.8157272658604564, 0.8143186563020841,
+        0.8129015998799333, 0.811476125711259, 0.810042263080144, 0.8086000414367682,
+        0.807149490396689, 0.8056906397400991, 0.804223519411107, 0.8027481595169911,
+        0.8012645903274501, 0.7997728422738686, 0.7982729459485642, 0.7967649321040267,
+        0.7952488316521624, 0.7937246756635402, 0.7921924953666147, 0.7906523221469668,
+        0.7891041875465215, 0.7875481232627762, 0.785984161148017, 0.784412333208542,
+        0.7828326716038602, 0.7812452086459111, 0.7796499767982624, 0.778047008675316,
+        0.7764363370415054, 0.7748179948104802, 0.7731920150443077, 0.7715584309526552,
+        0.7699172758919736, 0.7682685833646747, 0.7666123870183109, 0.7649487206447474,
+        0.7632776181793285, 0.7615991137000482, 0.7599132414267078, 0.7582200357200843,
+        0.7565195310810693, 0.754811762149841, 0.7530967637050012, 0.7513745706627268,
+        0.7496452180759086, 0.7479087411332924, 0.7461651751586177, 0.7444145556097489,
+        0.7426569180778061, 0.7408922982862901, 0.7391207320902085, 0.7373422554751976,
+        0.7355569045566336, 0.7337647155787579, 0.7319657249137791, 0.7301599690609852,
+        0.7283474846458532, 0.7265283084191426, 0.7247024772560039, 0.7228700281550753,
+        0.7210309982375716, 0.7191854247463771, 0.7173333450451372, 0.7154747966173406,
+        0.713609817065405, 0.711738444109754, 0.7098607155879003, 0.7079766694535135,
+        0.7060863437754946, 0.7041897767370496, 0.7022870066347512, 0.7003780718776047,
+        0.6984630109861137, 0.6965418625913292, 0.6946146654339207, 0.6926814583632128,
+        0.6907422803362554, 0.6887971704168564, 0.686846167774644, 0.6848893116840983,
+        0.6829266415235965, 0.6809581967744616, 0.6789840170199873, 0.6770041419444807,
+        0.6750186113322907, 0.6730274650668459, 0.6710307431296763, 0.6690284855994397,
+        0.6670207326509497, 0.6650075245541966, 0.6629889016733657, 0.6609649044658549,
+        0.6589355734812932, 0.6569009493605521, 0.6548610728347569, 0.6528159847243017,
+        0.6507657259378472, 0.648710337471337, 0.6466498604069961, 0.6445843359123345,
+        0.6425138052391473, 0.6404383097225108, 0.6383578907797843, 0.636272589909603,
+        0.6341824486908668, 0.6320875087817382, 0.6299878119186312, 0.6278833999151932,
+        0.6257743146613017, 0.6236605981220408, 0.6215422923366869, 0.6194194394176928,
+        0.6172920815496674, 0.6151602609883522, 0.6130240200596011, 0.6108834011583563,
+        0.6087384467476208, 0.6065891993574345, 0.6044357015838404, 0.6022779960878676,
+        0.600116125594484, 0.5979501328915761, 0.5957800608289129, 0.5936059523171077,
+        0.5914278503265888, 0.5892457978865548, 0.5870598380839475, 0.5848700140623979,
+        0.5826763690212029, 0.5804789462142688, 0.5782777889490804, 0.5760729405856517,
+        0.5738644445354835, 0.571652344260519, 0.5694366832720955, 0.5672175051299008,
+        0.564994853440922, 0.562768771858399, 0.5605393040807753, 0.558306493850645,
+        0.5560703849537082, 0.5538310212177106, 0.5515884465114003, 0.5493427047434678,
+        0.5470938398614946, 0.544841895850902, 0.5425869167338921, 0.5403289465683933,
+        0.5380680294470076, 0.5358042094959521, 0.5335375308740008, 0.5312680377714298,
+        0.5289957744089564, 0.5267207850366883, 0.5244431139330546, 0.5221628054037584,
+        0.5198799037807056, 0.5175944534209612, 0.5153064987056737, 0.5130160840390249,
+        0.5107232538471692, 0.5084280525771708, 0.5061305246959465, 0.5038307146892032,
+        0.5015286670603765, 0.4992244263295749, 0.49691803703251614, 0.4946095437194682,
+        0.492298990954184, 0.4899864233128517, 0.48767188538302336, 0.4853554217625616,
+        0.4830370770585796, 0.4807168958863757, 0.4783949228683817, 0.476071202633097,
+        0.47374577981403476, 0.47141869904866174, 0.46909000497733444, 0.4667597422422512,
+        0.464427955486385, 0.4620946893524337, 0.45975998848175725, 0.4574238975133261,
+        0.4550864610826619, 0.45274772382078304, 0.450407730353153, 0.44806652529861996,
+        0.4457241532683688, 0.4433806588648645, 0.4410360866808033, 0.4386904812980581,
+        0.4363438872866269, 0.43399634920358515, 0.43164791159203614, 0.4292986189800601,
+        0.42694851587966676, 0.4245976467857517, 0.42224605617504635, 0.419893788505073,
+        0.4175408882131029, 0.4151873997151118, 0.41283336740473764, 0.41047883565223764,
+        0.40812384880344993, 0.40576845117875426, 0.4034126870720317, 0.4010566007496312,
+        0.39870023644933006, 0.39634363837930225, 0.3939868507170819, 0.3916299176085331,
+        0.3892728831668187, 0.386915791471369, 0.38455868656685593, 0.382201612462163,
+        0.3798446131293629, 0.37748773250268813, 0.3751310144775132, 0.3727745029093313,
+        0.3704182416127326, 0.36806227436038846, 0.36570664488203164, 0.36335139686344226,
+        0.3609965739454339, 0.35864221972284194, 0.3562883777435116, 0.35393509150728775,
+        0.35158240446501104, 0.34923036001751084, 0.3468790015145981, 0.3445283722540683,
+        0.34217851548069744, 0.33982947438524425, 0.3374812921034552, 0.3351340117150672,
+        0.33278767624281513, 0.3304423286514424, 0.32809801184671017, 0.3257547686744099,
+        0.32341264191937913, 0.3210716743045174, 0.318731908489807, 0.31639338707133136,
+        0.3140561525802988, 0.3117202474820693, 0.30938571417517907, 0.30705259499037124,
+        0.30472093218962937, 0.3023907679652058, 0.30006214443866486, 0.29773510365991557,
+        0.29540968760625397, 0.2930859381814076, 0.2907638972145774, 0.28844360645948963,
+        0.2861251075934409, 0.28380844221635515, 0.28149365184983544, 0.2791807779362223,
+        0.2768698618376534, 0.2745609448351264, 0.2722540681275622, 0.2699492728308742,
+        0.26764659997703594, 0.2653460905131571, 0.26304778530055384, 0.2607517251138324,
+        0.25845795063996263, 0.25616650247736844, 0.253877421135008, 0.2515907470314658,
+        0.2493065204940423, 0.24702478175784792, 0.24474557096490182, 0.24246892816323057,
+        0.24019489330597027, 0.237923506250472, 0.2356548067574134, 0.2333888344899042,
+        0.23112562901260764, 0.22886522979085125, 0.22660767618975114, 0.2243530074733341,
+        0.2221012628036639, 0.21985248123997006, 0.2176067017377827, 0.21536396314806405,
+        0.21312430421635198, 0.2108877635818975, 0.20865437977681198, 0.20642419122521613,
+        0.204197236242389, 0.20197355303392345, 0.19975317969488662, 0.19753615420897705,
+        0.19532251444769042, 0.19311229816949, 0.1909055430189744, 0.18870228652605256,
+        0.18650256610512347, 0.1843064190542558, 0.182113882554372, 0.17992499366843887,
+        0.1777397893406556, 0.17555830639565195, 0.17338058153768537, 0.17120665134984223,
+        0.1690365522932452, 0.16687032070626096, 0.16470799280371345, 0.1625496046760998,
+        0.16039519228881052, 0.15824479148135367, 0.15609843796657938, 0.15395616732991557,
+        0.15181801502859693, 0.1496840163909078, 0.1475542066154204, 0.14542862077024332,
+        0.1433072937922707, 0.1411902604864334, 0.13907755552495848, 0.13696921344662846,
+        0.1348652686560477, 0.13276575542290897, 0.13067070788126747, 0.12858016002881706,
+        0.12649414572616907, 0.12441269869613868, 0.12233585252303111, 0.12026364065193526,
+        0.11819609638801841, 0.11613325289582745, 0.11407514319859194, 0.11202180017753316,
+        0.10997325657117538, 0.107929544974663, 0.1058906978390804, 0.10385674747077539,
+        0.10182772603068972, 0.09980366553368976, 0.09778459784790455, 0.09577055469406584,
+        0.09376156764485409, 0.09175766812424656, 0.08975888740687235, 0.08776525661736866,
+        0.08577680672974314, 0.08379356856674115, 0.08181557279921417, 0.07984284994549636,
+        0.07787543037078318, 0.07591334428651392, 0.07395662174976043, 0.07200529266261788,
+        0.07005938677160263, 0.06811893366705192, 0.06618396278252911, 0.06425450339423315,
+        0.06233058462041313, 0.06041223542078567, 0.05849948459595861, 0.056592360786857136,
+        0.05469089247415613, 0.052795107977716624, 0.050905035456025816, 0.04902070290564225,
+        0.04714213816064586, 0.045269368892091696, 0.04340242260746869, 0.04154132665016377,
+        0.03968610819892822, 0.037836794267351155, 0.03599341170333596, 0.034155987188582176,
+        0.032324547238071565, 0.03049911819955901, 0.02867972625306764, 0.026866397410389327,
+        0.025059157514589075, 0.02325803223951459, 0.021463047089310607, 0.019674227397936884,
+        0.01789159832869266, 0.016115184873743877, 0.014345011853656789, 0.012581103916935428,
+        0.010823485539563424, 0.009072181024551642, 0.007327214501490089, 0.005588609926104383,
+        0.0038563910798167316, 0.0021305815693130454, 0.00041120482611289183, -0.0013017158938537039,
+        -0.0030081575106666748, -0.004708097120829566, -0.006401511997679847, -0.008088379591793347,
+        -0.009768677531385444, -0.011442383622704621, -0.01310947585042377, -0.014769932378025858,
+        -0.016423731548183836, -0.018070851883136604, -0.019711272085060287, -0.021344971036433208,
+        -0.022971927800397463, -0.024592121621115525, -0.026205531924120007, -0.02781213831666087,
+        -0.02941192058804676, -0.031004858709980737, -0.03259093283689189, -0.03417012330626259,
+        -0.0357424106389484, -0.03730777553949572, -0.03886619889645268, -0.04041766178267685,
+        -0.04196214545563502, -0.043499631357701, -0.04503010111644691, -0.04655353654492963,
+        -0.04806991964197219, -0.04957923259244118, -0.05108145776751702, -0.05257657772496109,
+        -0.05406457520937765, -0.05554543315246938, -0.05701913467328958, -0.05848566307848801,
+        -0.05994500186255261, -0.061397134708045094, -0.06284204548583379, -0.0642797182553178,
+        -0.06571013726464908, -0.06713328695094904, -0.06854915194051883, -0.06995771704904533,
+        -0.07135896728180173, -0.07275288783384497, -0.07413946409020418, -0.0755186816260675,
+        -0.07689052620696316, -0.07825498378893407, -0.07961204051870818, -0.0809616827338648,
+        -0.08230389696299373, -0.0836386699258508, -0.0849659885335089, -0.08628583988850112,
+        -0.08759821128496195, -0.08890309020876198, -0.090200464337637, -0.09149032154131312,
+        -0.09277264988162648, -0.09404743761263691, -0.09531467318073758, -0.0965743452247594,
+        -0.09782644257607101, -0.09907095425867107, -0.10030786948927779, -0.10153717767741387,
+        -0.1027588684254842, -0.10397293152884969, -0.10517935697589642, -0.10637813494809811,
+        -0.10756925582007525, -0.10875271015964909, -0.1099284887278886, -0.11109658247915366,
+        -0.1122569825611344, -0.113409680314883, -0.1145546672748422, -0.11569193516886807,
+        -0.11682147591824742, -0.11794328163771074, -0.11905734463544017, -0.12016365741307154,
+        -0.12126221266569225, -0.12235300328183313, -0.12343602234345663, -0.124511263125939,
+        -0.1255787190980464, -0.12663838392190926, -0.1276902514529876, -0.1287343157400339,
+        -0.12977057102505016, -0.13079901174324063, -0.13181963252295748, -0.13283242818564445,
+        -0.13383739374577258, -0.13483452441077273, -0.1358238155809629, -0.13680526284946917,
+        -0.1377788620021448, -0.13874460901747976, -0.13970250006650928, -0.14065253151271576,
+        -0.1415946999119252, -0.14252900201220012, -0.14345543475372624, -0.1443739952686945,
+        -0.14528468088117905, -0.14618748910700802, -0.14708241765363272, -0.14796946441998923,
+        -0.14884862749635502, -0.14971990516420355, -0.15058329589604955, -0.15143879835529395,
+        -0.15228641139606, -0.15312613406302725, -0.1539579655912598, -0.15478190540602846,
+        -0.15559795312262978, -0.1564061085462003, -0.1572063716715235, -0.15799874268283481,
+        -0.15878322195361977, -0.15955981004640946, -0.16032850771256826, -0.16108931589207978,
+        -0.1618422357133251, -0.16258726849285862, -0.16332441573517892, -0.16405367913249175,
+        -0.16477506056447366, -0.16548856209802484, -0.16619418598702354, -0.1668919346720687,
+        -0.16758181078022488, -0.16826381712475688, -0.1689379567048636, -0.16960423270540442,
+        -0.17026264849662315, -0.17091320763386464, -0.17155591385729047, -0.17219077109158556,
+        -0.17281778344566362, -0.17343695521236713, -0.1740482908681613, -0.17465179507282533,
+        -0.1752474726691368, -0.17583532868255508, -0.17641536832089527, -0.17698759697400354,
+        -0.17755202021342129, -0.17810864379205027, -0.17865747364381004, -0.17919851588329266,
+        -0.1797317768054111, -0.1802572628850463, -0.18077498077668433, -0.1812849373140553,
+        -0.18178713950976336, -0.18228159455491544, -0.18276830981874137, -0.18324729284821448,
+        -0.1837185513676646, -0.18418209327838778, -0.18463792665825066, -0.18508605976129291,
+        -0.185526501017322, -0.1859592590315061, -0.1863843425839619, -0.1868017606293377,
+        -0.18721152229639385, -0.18761363688757476, -0.18800811387858413, -0.18839496291794727,
+        -0.18877419382657515, -0.18914581659732208, -0.18950984139454008, -0.18986627855362717,
+        -0.19021513858057484, -0.19055643215150705, -0.19089017011221907, -0.19121636347770957,
+        -0.1915350234317103, -0.19184616132621057, -0.1921497886809775, -0.19244591718307433,
+        -0.19273455868637046, -0.19301572521105317, -0.19328942894312895, -0.19355568223392755,
+        -0.1938144975995957, -0.1940658877205916, -0.19430986544117365, -0.19454644376888275,
+        -0.19477563587402738, -0.1949974550891566, -0.19521191490853673, -0.19541902898761662,
+        -0.19561881114249688, -0.1958112753493901, -0.1959964357440768, -0.1961743066213625,
+        -0.19634490243452524, -0.19650823779476378, -0.19666432747064067, -0.19681318638751935,
+        -0.1969548296270003, -0.19708927242635363, -0.1972165301779451, -0.1973366184286627,
+        -0.1974495528793355, -0.19755534938415106, -0.19765402395007078, -0.19774559273623582,
+        -0.19783007205337735, -0.1979074783632174, -0.1979778282778685, -0.19804113855922975,
+        -0.19809742611837788, -0.19814670801495862, -0.1981890014565697, -0.19822432379814248,
+        -0.19825269254132397, -0.19827412533384778, -0.19828863996890927, -0.19829625438453177,
+        -0.1982969866629326, -0.19829085502988564, -0.19827787785407802, -0.19825807364646755,
+        -0.1982314610596338, -0.19819805888712633, -0.19815788606281085, -0.1981109616602116,
+        -0.19805730489185194, -0.1979969351085877, -0.1979298717989431, -0.19785613458843798,
+        -0.19777574323891653, -0.19768871764787097, -0.1975950778477611, -0.1974948440053329,
+        -0.19738803642093275, -0.19727467552782008, -0.1971547818914749, -0.1970283762089042,
+        -0.19689547930794565, -0.19675611214656533, -0.19661029581215875, -0.19645805152083995,
+        -0.19629940061673895, -0.1961343645712849, -0.19596296498249596, -0.19578522357426043,
+        -0.19560116219561743, -0.1954108028200354, -0.1952141675446856, -0.19501127858971581,
+        -0.19480215829751976, -0.1945868291320026, -0.19436531367784865, -0.19413763463977893,
+        -0.19390381484181377, -0.19366387722652847, -0.1934178448543069, -0.19316574090259336,
+        -0.19290758866514376, -0.19264341155126935, -0.19237323308508267, -0.19209707690473954,
+        -0.19181496676167914, -0.19152692651985942, -0.19123298015499357, -0.1909331517537816,
+        -0.19062746551314194, -0.19031594573943778, -0.18999861684770364, -0.18967550336086889,
+        -0.18934662990897924, -0.18901202122841568, -0.1886717021611102, -0.18832569765376378,
+        -0.1879740327570557, -0.18761673262485698, -0.1872538225134369, -0.18688532778067052,
+        -0.18651127388524383, -0.18613168638585498, -0.18574659094041596, -0.18535601330525095,
+        -0.1849599793342928, -0.18455851497827888, -0.18415164628394198, -0.18373939939320366,
+        -0.1833218005423634, -0.18289887606128358, -0.18247065237257898, -0.1820371559907976,
+        -0.18159841352160566, -0.1811544516609651, -0.1807052971943147, -0.18025097699574746,
+        -0.17979151802718488, -0.17932694733755136, -0.17885729206194748, -0.17838257942081992,
+        -0.17790283671913085, -0.1774180913455263, -0.17692837077150275, -0.1764337025505697,
+        -0.17593411431741743, -0.17542963378707405, -0.17492028875407073, -0.17440610709159704,
+        -0.1738871167506614, -0.17336334575924797, -0.17283482222146898, -0.17230157431672302,
+        -0.17176363029884453, -0.17122101849525626, -0.17067376730612135, -0.17012190520349058,
+        -0.1695654607304506, -0.16900446250027346, -0.168438939195558, -0.16786891956737984,
+        -0.16729443243443176, -0.16671550668216667, -0.16613217126194132, -0.165544455190155,
+        -0.16495238754739136, -0.16435599747755583, -0.16375531418701436, -0.16315036694373083,
+        -0.16254118507640242, -0.16192779797359716, -0.1613102350828858, -0.1606885259099785,
+        -0.16006270001785639, -0.1594327870259048, -0.1587988166090451, -0.15816081849686534,
+        -0.15751882247275104, -0.15687285837301518, -0.15622295608602724, -0.15556914555134216,
+        -0.1549114567588285, -0.1542499197477956, -0.15358456460612124, -0.15291542146937878,
+        -0.15224252051996132, -0.15156589198621057, -0.1508855661415394, -0.15020157330355885,
+        -0.1495139438332021, -0.14882270813384788, -0.14812789665044712, -0.14742953986864316,
+        -0.14672766831389822, -0.14602231255061632, -0.14531350318126482, -0.14460127084549845,
+        -0.14388564621928251, -0.14316666001401454, -0.14244434297564795, -0.14171872588381348,
+        -0.14098983955094202, -0.14025771482138763, -0.13952238257054933, -0.13878387370399298,
+        -0.13804221915657564, -0.13729744989156625, -0.136549596899769, -0.13579869119864654,
+        -0.13504476383144215, -0.13428784586630302, -0.13352796839540432, -0.13276516253407147,
+        -0.13199945941990493, -0.13123089021190382, -0.13045948608958996, -0.12968527825213358,
+        -0.1289082979174763, -0.12812857632145921, -0.12734614471694608, -0.12656103437295077,
+        -0.12577327657376394, -0.12498290261807952, -0.1241899438181227, -0.1233944314987777,
+        -0.12259639699671648, -0.12179587165952827, -0.12099288684484873, -0.12018747391949057,
+        -0.11937966425857409, -0.11856948924466004, -0.11775698026688063, -0.11694216872007314,
+        -0.11612508600391377, -0.11530576352205148, -0.11448423268124465, -0.11366052489049527,
+        -0.11283467156018771, -0.11200670410122537, -0.11117665392416945, -0.1103445524383789,
+        -0.10951043105114973, -0.10867432116685823, -0.10783625418610213, -0.10699626150484316,
+        -0.10615437451355363, -0.10531062459635973, -0.10446504313018895, -0.1036176614839177,
+        -0.1027685110175191, -0.10191762308121399, -0.1010650290146205, -0.10021076014590723,
+        -0.0993548477909455, -0.09849732325246546, -0.0976382178192097, -0.0967775627650921,
+        -0.095915389348355, -0.09505172881072968, -0.09418661237659615, -0.09332007125214692,
+        -0.09245213662454924, -0.09158283966111123, -0.09071221150844797, -0.08984028329164923,
+        -0.08896708611344975, -0.08809265105339895, -0.08721700916703447, -0.08634019148505563,
+        -0.08546222901249866, -0.08458315272791445, -0.08370299358254701, -0.08282178249951348,
+        -0.08193955037298663, -0.08105632806737849, -0.08017214641652515, -0.07928703622287417,
+        -0.07840102825667365, -0.07751415325516219, -0.07662644192176196, -0.07573792492527165,
+        -0.07484863289906409, -0.07395859644028198, -0.07306784610903966, -0.07217641242762286,
+        -0.07128432587969313, -0.07039161690949325, -0.06949831592105389, -0.06860445327740318,
+        -0.06771005929977825, -0.06681516426683823, -0.06591979841387921, -0.06502399193205236,
+        -0.06412777496758289, -0.06323117762099134, -0.06233422994631774, -0.061436961950347056,
+        -0.0605394035918371, -0.05964158478074843, -0.05874353537747726, -0.05784528519208916,
+        -0.05694686398355629, -0.05604830145899593, -0.05514962727291261, -0.054250871026440176,
+        -0.0533520622665892, -0.052453230485493905, -0.051554405119663714, -0.05065561554923501,
+        -0.049756891097227834, -0.04885826102880189, -0.04795975455051825, -0.04706140080960101,
+        -0.04616322889320216, -0.04526526782767004, -0.04436754657781861, -0.04347009404619986,
+        -0.042572939072379176, -0.04167611043221377, -0.04077963683713111, -0.03988354693341314,
+        -0.03898786930148115, -0.03809263245518438, -0.03719786484108953, -0.03630359483777549,
+        -0.03540985075512882, -0.03451666083364201, -0.03362405324371572, -0.03273205608496282,
+        -0.03184069738551512, -0.03095000510133325, -0.030060007115519496, -0.0291707312376323,
+        -0.028282205203005437, -0.02739445667206868, -0.02650751322967108, -0.0256214023844087,
+        -0.02473615156795364, -0.023851788134386747, -0.022968339359533112, -0.022085832440300438,
+        -0.02120429449402067, -0.020323752557793798, -0.019444233587835806, -0.018565764458828914,
+        -0.017688371963274514, -0.016812082810850157, -0.015936923627768804, -0.015062920956141371,
+        -0.014190101253342288, -0.013318490891378607, -0.012448116156261083, -0.01157900324738008,
+        -0.010711178276883393, -0.009844667269056943, -0.008979496159710283, -0.008115690795563606,
+        -0.007253276933638791, -0.006392280240653525, -0.0055327262924187845, -0.0046746405732388955,
+        -0.003818048475316177, -0.0029629752981575816, -0.0021094462479853706, -0.0012574864371503603,
+        -0.00040712088354976256, 0.000441625489952799, 0.001288727856103918, 0.0021341614838326444,
+        0.002977901738819859, 0.0038199240840656565, 0.004660204080450174, 0.005498717387294599,
+        0.006335439762916334, 0.0071703470651806505, 0.008003415252051097, 0.008834620382134322,
+        0.009663938615221291, 0.010491346212827444, 0.011316819538726331, 0.01214033505948173,
+        0.012961869344975358, 0.013781399068931909, 0.01459890100943854, 0.015414352049463873,
+        0.016227729177370847, 0.01703900948742618, 0.017848170180308476, 0.01865518856360981,
+        0.019460042052335622, 0.02026270816940014, 0.021063164546119013, 0.021861388922696203,
+        0.022657359148710476, 0.02345105318359553, 0.024242449097116543, 0.025031525069845538,
+        0.025818259393629764, 0.026602630472058693, 0.02738461682092603, 0.028164197068689164,
+        0.02894134995692274, 0.02971605434077162, 0.030488289189397194, 0.03125803358642075,
+        0.032025266730364495, 0.03278996793508711, 0.03355211663021504, 0.0343116923615728,
+        0.03506867479160614, 0.03582304369980303, 0.0365747789831116, 0.03732386065635162,
+        0.03807026885262537, 0.03881398382372208, 0.039554985940519136, 0.040293255693380686,
+        0.04102877369255064, 0.04176152066854231, 0.04249147747252516, 0.04321862507670611,
+        0.04394294457470728, 0.04466441718194132, 0.04538302423598089, 0.04609874719692401,
+        0.04681156764775807, 0.04752146729471744, 0.048228427967637086, 0.0489324316203043,
+        0.04963346033080404, 0.05033149630186199, 0.05102652186118265, 0.05171851946178442,
+        0.05240747168232848, 0.05309336122744754, 0.05377617092806637, 0.054455883741720654,
+        0.05513248275287205, 0.05580595117321776, 0.05647627234199696, 0.05714342972629323,
+        0.0578074069213333, 0.05846818765077928, 0.05912575576702143, 0.059780095251462446,
+        0.060431190214799146, 0.06107902489730182, 0.0617235836690874, 0.06236485103038833,
+        0.06300281161181928, 0.06363745017463873, 0.06426875161100566, 0.06489670094423362,
+        0.0655212833290397, 0.06614248405178962, 0.06676028853073934, 0.06737468231627117,
+        0.06798565109112746, 0.06859318067063896, 0.06919725700294883, 0.06979786616923443,
+        0.07039499438392284, 0.07098862799490291, 0.07157875348373402, 0.07216535746585004,
+        0.07274842669075808, 0.07332794804223586, 0.073903908538522, 0.07447629533250424,
+        0.07504509571190193, 0.07561029709944664, 0.07617188705305501, 0.07672985326600157,
+        0.077284183567085, 0.07783486592078981, 0.07838188842744559, 0.0789252393233816,
+        0.07946490698107567, 0.08000087990930167, 0.08053314675327025, 0.08106169629476691,
+        0.08158651745228566, 0.08210759928115832, 0.08262493097367957, 0.08313850185922837,
+        0.08364830140438496, 0.08415431921304285, 0.08465654502651848, 0.08515496872365529,
+        0.08564958032092396, 0.08614036997251864, 0.08662732797044913, 0.08711044474462916,
+        0.08758971086295898, 0.08806511703140674, 0.08853665409408212, 0.08900431303330951,
+        0.08946808496969338, 0.0899279611621831, 0.09038393300813062, 0.09083599204334591,
+        0.09128412994214788, 0.09172833851741038, 0.09216860972060437, 0.09260493564183765,
+        0.09303730850988798, 0.09346572069223295, 0.09389016469507694, 0.09431063316337253,
+        0.09472711888083733, 0.0951396147699703, 0.09554811389205821, 0.09595260944718277,
+        0.09635309477422219, 0.09674956335084826, 0.09714200879351845, 0.09753042485746703,
+        0.09791480543668886, 0.09829514456392052, 0.09867143641061782, 0.09904367528692763,
+        0.099411855641658, 0.09977597206224188, 0.10013601927469833, 0.10049199214358956,
+        0.10084388567197242, 0.10119169500134853, 0.10153541541160734, 0.10187504232096832,
+        0.10221057128591579, 0.10254199800113291, 0.1028693182994293, 0.10319252815166625,
+        0.10351162366667678, 0.10382660109118241, 0.10413745680970567, 0.10444418734447915,
+        0.10474678935534923, 0.10504525963967781, 0.10533959513223819, 0.1056297929051086,
+        0.10591585016756029, 0.10619776426594366, 0.10647553268356773, 0.1067491530405779,
+        0.10701862309382995, 0.10728394073675791, 0.1075451039992403, 0.1078021110474614,
+        0.1080549601837692, 0.10830364984652809, 0.10854817860996996, 0.1087885451840396,
+        0.10902474841423716, 0.10925678728145591, 0.1094846609018175, 0.10970836852650212,
+        0.10992790954157564, 0.11014328346781273, 0.11035448996051558, 0.1105615288093303,
+        0.11076439993805773, 0.110963103404462, 0.1111576394000743, 0.11134800824999383,
+        0.11153421041268383, 0.11171624647976551, 0.11189411717580561, 0.11206782335810404,
+        0.11223736601647415, 0.11240274627302166, 0.11256396538191885, 0.1127210247291753,
+        0.11287392583240513, 0.11302267034059069, 0.11316726003384196, 0.11330769682315317,
+        0.11344398275015505, 0.11357611998686443, 0.11370411083542864, 0.11382795772786905,
+        0.1139476632258167, 0.11406323002025016, 0.1141746609312235, 0.1142819589075965,
+        0.1143851270267569, 0.1144841684943424, 0.1145790866439563, 0.11466988493688239,
+        0.11475656696179404, 0.11483913643446084, 0.1149175971974535, 0.11499195321984018,
+        0.11506220859688622, 0.11512836754974504, 0.11519043442514842, 0.11524841369509138,
+        0.11530230995651654, 0.11535212793099274, 0.1153978724643902, 0.11543954852655408,
+        0.1154771612109736, 0.11551071573444813, 0.11554021743674928, 0.11556567178028028,
+        0.11558708434973326, 0.11560446085174086, 0.11561780711452618, 0.11562712908754923,
+        0.11563243284114982, 0.11563372456618756, 0.11563101057367962, 0.11562429729443181,
+        0.11561359127867174, 0.11559889919567397, 0.11558022783338565, 0.115557584098046,
+        0.11553097501380541, 0.11550040772233965, 0.11546588948246093, 0.11542742766972838,
+        0.11538502977605132, 0.11533870340929349, 0.11528845629287171, 0.11523429626535281,
+        0.11517623128004655, 0.1151142694045968, 0.11504841882056895, 0.11497868782303391,
+        0.11490508482015122, 0.1148276183327462, 0.1147462969938866, 0.11466112954845604,
+        0.11457212485272295, 0.11447929187390915, 0.11438263968975323, 0.11428217748807315,
+        0.1141779145663237, 0.11406986033115435, 0.11395802429796069, 0.11384241609043572,
+        0.11372304544011821, 0.11359992218593676, 0.11347305627375237, 0.1133424577558981,
+        0.11320813679071638, 0.113070103642093, 0.11292836867898852, 0.11278294237496757,
+        0.11263383530772524, 0.11248105815861126, 0.11232462171215064, 0.11216453685556231,
+        0.11200081457827643, 0.11183346597144617, 0.11166250222746046, 0.11148793463945188,
+        0.11130977460080282, 0.11112803360464941, 0.11094272324338253, 0.11075385520814712,
+        0.11056144128833773, 0.11036549337109361, 0.11016602344078986, 0.10996304357852699,
+        0.10975656596161712, 0.10954660286307023, 0.10933316665107493, 0.10911626978847999,
+        0.10889592483227102, 0.10867214443304656, 0.108444941334492, 0.10821432837284968,
+        0.1079803184763889, 0.10774292466487208, 0.10750216004901965, 0.10725803782997252,
+        0.10701057129875312, 0.10675977383572244, 0.10650565891003763, 0.10624824007910508,
+        0.10598753098803361, 0.10572354536908336, 0.10545629704111517, 0.10518579990903579,
+        0.10491206796324269, 0.10463511527906572, 0.10435495601620794, 0.104071604418183,
+        0.10378507481175336, 0.10349538160636307, 0.10320253929357151, 0.10290656244648441,
+        0.10260746571918207, 0.10230526384614808, 0.1019999716416931, 0.10169160399938068,
+        0.10138017589144706, 0.10106570236822322, 0.10074819855755299, 0.10042767966420937,
+        0.10010416096931055, 0.09977765782973362, 0.09944818567752557, 0.0991157600193148,
+        0.0987803964357196, 0.09844211058075447, 0.09810091818123778, 0.09775683503619383,
+        0.09740987701625696, 0.09706006006307223, 0.09670740018869518, 0.09635191347499004,
+        0.09599361607302605, 0.0956325242024746, 0.09526865415100129, 0.09490202227365963,
+        0.09453264499228234, 0.0941605387948711, 0.09378572023498491, 0.09340820593112907,
+        0.0930280125661388, 0.09264515688656608, 0.09225965570206304, 0.0918715258847636,
+        0.09148078436866511, 0.09108744814900888, 0.09069153428165842, 0.09029305988247792,
+        0.0898920421267082, 0.08948849824834312, 0.0890824455395034, 0.08867390134981042,
+        0.0882628830857591, 0.08784940821008821, 0.08743349424115217, 0.08701515875228981,
+        0.08659441937119286, 0.08617129377927386, 0.08574579971103251, 0.08531795495342238,
+        0.08488777734521437, 0.08445528477636237, 0.08402049518736558, 0.08358342656863105,
+        0.08314409695983582, 0.08270252444928743, 0.08225872717328427, 0.08181272331547522,
+        0.08136453110621789, 0.08091416882193715, 0.08046165478448213, 0.08000700736048345,
+        0.07955024496070884, 0.07909138603941888, 0.07863044909372223, 0.07816745266292946,
+        0.07770241532790698, 0.07723535571043103, 0.07676629247253963, 0.07629524431588496,
+        0.0758222299810861, 0.07534726824707928, 0.07487037793046977, 0.0743915778848819,
+        0.07391088700030997, 0.07342832420246666, 0.07294390845213464, 0.072457658744514,
+        0.07196959410857183, 0.07147973360639094, 0.07098809633251808, 0.07049470141331173,
+        0.06999956800629074, 0.06950271529948103, 0.06900416251076395, 0.06850392888722358,
+        0.06800203370449309, 0.06749849626610291, 0.0669933359028273, 0.06648657197203077,
+        0.06597822385701585, 0.06546831096636943, 0.06495685273330934, 0.06444386861503183,
+        0.06392937809205777, 0.06341340066757956, 0.0628959558668089, 0.062377063236322465,
+        0.0618567423434103, 0.06133501277542201, 0.06081189413911518, 0.060287406060001804,
+        0.05976156818169716, 0.05923440016526683, 0.058705921688574914, 0.0581761524456335,
+        0.05764511214594967, 0.05711282051387564, 0.056579297287957846, 0.05604456222028585,
+        0.05550863507584275, 0.054971535631855833, 0.05443328367714608, 0.053893899011479916,
+        0.0533534014449211, 0.05281181079718162, 0.05226914689697429, 0.05172542958136654,
+        0.0511806786951324, 0.05063491409010693, 0.050088155624540914, 0.04954042316245494,
+        0.04899173657299553, 0.048442115729791596, 0.04789158051030968, 0.04734015079521311,
+        0.046787846467718525, 0.0462346874129552, 0.045680693517324504, 0.04512588466785955,
+        0.044570280751585895, 0.04401390165488383, 0.04345676726284973, 0.042898897458659276,
+        0.04234031212293235, 0.04178103113309633, 0.041221074362751985, 0.04066046168104093,
+        0.04009921295201132, 0.039537348033987285, 0.03897488677893686, 0.038411849031843276,
+        0.03784825463007454, 0.037284123402756754, 0.036719475170145933, 0.036154329743002235,
+        0.03558870692196562, 0.035022626496930394, 0.03445610824642367, 0.03388917193698297,
+        0.033321837322535144, 0.032754124143777014, 0.03218605212755725, 0.031617640986258154,
+        0.0310489104171799, 0.030479880101926038, 0.02991056970578846, 0.02934099887713631,
+        0.028771187246803307, 0.028201154427478604, 0.027630920013097318, 0.027060503578233766,
+        0.02648992467749444, 0.025919202844913513, 0.025348357593349868, 0.024777408413883626,
+        0.02420637477521705, 0.023635276123073617, 0.023064131879600706, 0.02249296144277369,
+        0.021921784185799653, 0.021350619456524386, 0.020779486576840756, 0.020208404842097058,
+        0.019637393520508344, 0.019066471852569503, 0.018495659050468044, 0.01792497429750028,
+        0.01735443674748887, 0.01678406552420035, 0.01621387972076678, 0.015643898399107063,
+        0.015074140589350303, 0.014504625289261407, 0.013935371463668599, 0.013366398043891024,
+        0.0127977239271696, 0.012229367976099827, 0.011661349018064364, 0.011093685844669344,
+        0.010526397211182202, 0.009959501835969834, 0.009393018399940178, 0.008826965545985473,
+        0.008261361878425896, 0.007696225962456655, 0.007131576323596798, 0.006567431447138397,
+        0.006003809777599971, 0.005440729718179292, 0.004878209630210759, 0.00431626783262193,
+        0.00375492260139494, 0.003194192169027075, 0.002634094723995269, 0.0020746484102224893,
+        0.0015158713265444397, 0.0009577815261809084, 0.0004003970162065618, -0.00015626424297447253,
+        -0.0007121843381519622, -0.0012673454038323452, -0.0018217296227578772, -0.002375319226423679,
+        -0.0029280964955941333, -0.003480043760815641, -0.004031143402927247, -0.004581377853570486,
+        -0.0051307295956957025, -0.005679181164066179, -0.006226715145760803, -0.006773314180674661,
+        -0.0073189609620156705, -0.007863638236801375, -0.008407328806351689, -0.008950015526779515,
+        -0.009491681309480426, -0.010032309121618583, -0.010571881986610293, -0.011110382984606926,
+        -0.011647795252973624, -0.012184101986766081, -0.012719286439206065, -0.013253331922153288,
+        -0.013786221806574794, -0.014317939523013508, -0.014848468562052705, -0.01537779247477825,
+        -0.015905894873239908, -0.016432759430907787, -0.016958369883128856, -0.017482710027578507,
+        -0.018005763724712166, -0.018527514898211604, -0.019047947535431584, -0.0195670456878419,
+        -0.020084793471467337, -0.020601175067326182, -0.02111617472186435, -0.02162977674738865,
+        -0.022141965522496622, -0.022652725492503047, -0.023162041169866105, -0.02366989713460843,
+        -0.024176278034736643, -0.024681168586658933, -0.025184553575598807, -0.02568641785600619,
+        -0.02618674635196735, -0.026685524057610346, -0.027182736037508762, -0.02767836742708236,
+        -0.028172403432995697, -0.028664829333552917, -0.0291556304790909, -0.02964479229236912,
+        -0.030132300268956278, -0.030618139977615637, -0.03110229706068614, -0.03158475723446103,
+        -0.03206550628956489, -0.03254453009132616, -0.03302181458014774, -0.03349734577187516,
+        -0.03397110975816116, -0.03444309270682747, -0.0349132808622246, -0.035381660545588105,
+        -0.03584821815539138, -0.03631294016769773, -0.03677581313650651, -0.03723682369409983,
+        -0.03769595855138266, -0.038153204498223875, -0.038608548403790854, -0.0390619772168841,
+        -0.039513477966267016, -0.039963037760993185, -0.04041064379073169, -0.040856283326087865,
+        -0.04129994371892273, -0.04174161240266846, -0.04218127689264064, -0.042618924786349044,
+        -0.043054543763803695, -0.04348812158781887, -0.04391964610431411, -0.0443491052426124,
+        -0.04477648701573414, -0.04520177952068981, -0.045624970938768446, -0.04604604953582378,
+        -0.04646500366255648, -0.046881821754794856, -0.047296492333770423, -0.0477090040063928,
+        -0.04811934546551955, -0.04852750549022394, -0.04893347294605967, -0.04933723678532259,
+        -0.049738786047308144, -0.05013810985856801, -0.050535197433161406, -0.05093003807290446,
+        -0.051322621167616815, -0.05171293619536404, -0.05210097272269753, -0.05248672040489194,
+        -0.052870168986178114, -0.053251308299974064, -0.053630128269112703, -0.05400661890606531,
+        -0.054380770313163906, -0.05475257268281817, -0.05512201629773157, -0.05548909153111246,
+        -0.055853788846882774, -0.05621609879988398, -0.05657601203607913, -0.05693351929275266,
+        -0.05728861139870544, -0.05764127927444903, -0.05799151393239466, -0.05833930647704012,
+        -0.05868464810515328, -0.0590275301059521, -0.05936794386128208, -0.05970588084579041,
+        -0.06004133262709647, -0.060374290865959176, -0.060704747316442334, -0.06103269382607497,
+        -0.061358122336010075, -0.06168102488117884, -0.06200139359044339, -0.06231922068674429,
+        -0.06263449848724656, -0.0629472194034817, -0.06325737594148625, -0.06356496070193826,
+        -0.06386996638028937, -0.06417238576689431, -0.0644722117471372, -0.06476943730155461,
+        -0.0650640555059552, -0.06535605953153605, -0.06564544264499701, -0.06593219820864986,
+        -0.06621631968052585, -0.06649780061447973, -0.06677663466028946, -0.06705281556375506,
+        -0.06732633716679146, -0.06759719340752013, -0.06786537832035697, -0.06813088603609684,
+        -0.06839371078199487, -0.06865384688184512, -0.06891128875605453, -0.06916603092171629,
+        -0.06941806799267744, -0.06966739467960427, -0.06991400579004571, -0.07015789622849052,
+        -0.0703990609964252, -0.07063749519238513, -0.07087319401200508, -0.07110615274806442,
+        -0.07133636679053179, -0.07156383162660372, -0.07178854284074179, -0.07201049611470679,
+        -0.07222968722758806, -0.07244611205583207, -0.07265976657326483, -0.0728706468511147,
+        -0.07307874905802826, -0.0732840694600864, -0.07348660442081482, -0.07368635040119259,
+        -0.07388330395965721, -0.07407746175210653, -0.07426882053189751, -0.07445737714984203,
+        -0.07464312855419916, -0.07482607179066478, -0.07500620400235745, -0.07518352242980172,
+        -0.07535802441090819, -0.07552970738095023, -0.07569856887253762, -0.07586460651558714,
+        -0.07602781803729013, -0.07618820126207686, -0.07634575411157773, -0.07650047460458174,
+        -0.07665236085699106, -0.07680141108177294, -0.07694762358890946, -0.07709099678534202,
+        -0.07723152917491574, -0.0773692193583175, -0.07750406603301284, -0.07763606799318044,
+        -0.07776522412964197, -0.07789153342978869, -0.07801499497750736, -0.0781356079531003,
+        -0.0782533716332041, -0.07836828539070508, -0.07848034869465122, -0.07858956111016091,
+        -0.07869592229833053, -0.07879943201613611, -0.07890009011633403, -0.07899789654735856,
+        -0.07909285135321562, -0.079184954673374, -0.07927420674265373, -0.07936060789111153,
+        -0.07944415854392209, -0.07952485922125903, -0.07960271053816963, -0.07967771320444943,
+        -0.07974986802451213, -0.07981917589725705, -0.07988563781593472, -0.07994925486800705,
+        -0.08001002823500755, -0.08006795919239662, -0.08012304910941423, -0.08017529944893108,
+        -0.08022471176729469, -0.08027128771417458, -0.0803150290324034, -0.08035593755781577,
+        -0.08039401521908397, -0.08042926403755103, -0.08046168612706085, -0.08049128369378536,
+        -0.08051805903604918, -0.08054201454415137, -0.08056315270018434, -0.08058147607784948,
+        -0.08059698734227135, -0.08060968924980749, -0.08061958464785648, -0.08062667647466325,
+        -0.08063096775912063, -0.0806324616205699, -0.08063116126859672, -0.08062707000282592,
+        -0.08062019121271238, -0.08061052837732993, -0.08059808506515788, -0.08058286493386305,
+        -0.08056487173008191, -0.08054410928919756, -0.08052058153511557, -0.08049429248003638,
+        -0.08046524622422575, -0.08043344695578176, -0.08039889895039974, -0.08036160657113503,
+        -0.08032157426816183, -0.08027880657853072, -0.08023330812592333, -0.08018508362040357,
+        -0.08013413785816748, -0.08008047572129054, -0.08002410217747083, -0.07996502227977152,
+        -0.07990324116636024, -0.0798387640602455, -0.07977159626901147, -0.079701743184549,
+        -0.07962921028278552, -0.07955400312341211, -0.07947612734960739, -0.07939558868776043,
+        -0.07931239294718864, -0.07922654601985744, -0.07913805388009254, -0.07904692258429427,
+        -0.07895315827064632, -0.07885676715882442, -0.07875775554970083, -0.07865612982504785,
+        -0.07855189644723831, -0.07844506195894384, -0.07833563298283097, -0.07822361622125473,
+        -0.0781090184559503, -0.07799184654772251, -0.07787210743613204, -0.07774980813918052,
+        -0.07762495575299307, -0.07749755745149849, -0.0773676204861072, -0.0772351521853873,
+        -0.07710015995473768, -0.07696265127606051, -0.07682263370742957, -0.07668011488275799,
+        -0.07653510251146331, -0.07638760437813052, -0.07623762834217214, -0.07608518233748814,
+        -0.07593027437212183, -0.07577291252791458, -0.07561310496015874, -0.07545085989724747,
+        -0.07528618564032441, -0.07511909056292874, -0.07494958311064057, -0.07477767180072352,
+        -0.07460336522176439, -0.07442667203331282, -0.07424760096551691, -0.07406616081875875,
+        -0.07388236046328676, -0.07369620883884638, -0.07350771495430936, -0.07331688788730084,
+        -0.07312373678382457, -0.07292827085788614, -0.0727304993911145, -0.07253043173238219,
+        -0.07232807729742262, -0.07212344556844652, -0.07191654609375608, -0.07170738848735803,
+        -0.0714959824285739, -0.0712823376616492, -0.07106646399536136, -0.07084837130262474,
+        -0.07062806952009439, -0.0704055686477698, -0.0701808787485935, -0.06995400994805141,
+        -0.06972497243377006, -0.06949377645511187, -0.06926043232276914, -0.06902495040835739,
+        -0.0687873411440055, -0.0685476150219453, -0.06830578259409988, -0.06806185447166897,
+        -0.06781584132471455, -0.0675677538817438, -0.06731760292929084, -0.06706539931149698,
+        -0.06681115392968989, -0.06655487774196078, -0.06629658176274042, -0.06603627706237351,
+        -0.06577397476669244, -0.06550968605658826, -0.06524342216758196, -0.06497519438939264,
+        -0.06470501406550616, -0.06443289259274068, -0.06415884142081216, -0.06388287205189887,
+        -0.06360499604020235, -0.06332522499150992, -0.06304357056275446, -0.06276004446157292,
+        -0.06247465844586397, -0.06218742432334468, -0.061898353951105406, -0.06160745923516383,
+        -0.06131475213001792, -0.06102024463819786, -0.06072394880981624, -0.060425876742117815,
+        -0.06012604057902817, -0.05982445251070026, -0.059521124773061804, -0.059216069647359586,
+        -0.05890929945970377, -0.058600826580612345, -0.058290663424551165, -0.05797882244947704,
+        -0.057665316156377135, -0.05735015708880846, -0.057033357832436245, -0.056714931014571354,
+        -0.05639488930370699, -0.0560732454090539, -0.055750012080076274, -0.055425202106024286,
+        -0.05509882831546801, -0.05477090357582996, -0.05444144079291572, -0.054110452910445556,
+        -0.05377795290958352, -0.05344395380846743, -0.05310846866173627, -0.052771510560059194,
+        -0.052433092629661, -0.052093228031850146, -0.05175192996254259, -0.051409211651788016,
+        -0.051065086363293655, -0.05071956739394814, -0.05037266807334418, -0.05002440176330155,
+        -0.04967478185738847, -0.04932382178044337, -0.04897153498809566, -0.048617934966285255,
+        -0.04826303523078317, -0.047906849326710466, -0.04754939082805645, -0.04719067333719809,
+        -0.046830710484416366, -0.04646951592741498, -0.0461071033508363, -0.045743486465778756,
+        -0.0453786790093122, -0.0450126947439942, -0.0446455474573856, -0.04427725096156498,
+        -0.04390781909264398, -0.04353726571028162, -0.04316560469719835, -0.04279284995868991,
+        -0.04241901542214146, -0.04204411503654014, -0.04166816277198869, -0.04129117261921873,
+        -0.04091315858910295, -0.04053413471216761, -0.040154115038105655, -0.0397731136352883,
+        -0.03939114459027746, -0.039008222007337834, -0.03862436000794841, -0.03823957273031457,
+        -0.03785387432888023, -0.037467278973838745, -0.03707980085064529, -0.036691454159528195,
+        -0.03630225311500043, -0.035912211945371746, -0.03552134489225986, -0.03512966621010249,
+        -0.034737190165669264, -0.03434393103757315, -0.033949903115782575, -0.033555120701134096,
+        -0.03315959810484352, -0.03276334964801893, -0.032366389661173375, -0.031968732483736564,
+        -0.03157039246356882, -0.031171383956473342, -0.030771721325709996, -0.030371418941508074,
+        -0.02997049118058096, -0.029568952425639387, -0.029166817064905967, -0.028764099491630103,
+        -0.028360814103602288, -0.02795697530266949, -0.027552597494251294, -0.027147695086854856,
+        -0.02674228249159179, -0.026336374121694914, -0.025929984392035, -0.02552312771863811,
+        -0.02511581851820446, -0.024708071207625536, -0.02429990020350381, -0.023891319921671813,
+        -0.023482344776712093, -0.02307298918147697, -0.022663267546610334, -0.022253194280068216,
+        -0.02184278378664095, -0.021432050467476264, -0.021021008719601348, -0.020609672935447753,
+        -0.020198057502374536, -0.019786176802194057, -0.01937404521069751, -0.01896167709718066,
+        -0.018549086823971075, -0.01813628874595626, -0.017723297210111164, -0.01731012655502744,
+        -0.01689679111044399, -0.016483305196776418, -0.016069683124648917, -0.015655939194426486,
+        -0.015242087695747308, -0.014828142907057052, -0.014414119095142617, -0.014000030514668426,
+        -0.013585891407711569, -0.013171716003299855, -0.012757518516949019, -0.012343313150201755,
+        -0.011929114090167958, -0.011514935509064532, -0.011100791563757519, -0.010686696395304857,
+        -0.010272664128499287, -0.009858708871413104, -0.009444844714944152, -0.009031085732361489,
+        -0.008617445978853281, -0.008203939491075741, -0.0077905802867019106, -0.0073773823639732205,
+        -0.0069643597012503215, -0.00655152625656686, -0.006138895967182456, -0.005726482749138683,
+        -0.005314300496814471, -0.004902363082483721, -0.004490684355874208, -0.004079278143726319,
+        -0.0036681582493549455, -0.0032573384522106064, -0.0028468325074430707, -0.002436654145466199,
+        -0.002026817071522901, -0.001617334965252489, -0.0012082214802592317, -0.0007994902436811284,
+        -0.00039115485576105295, 1.677111058077926e-05, 0.0004242741101741629, 0.0008313406260238005,
+        0.0012379571697328632, 0.0016441102819264406, 0.0020497865326718815, 0.0024549725218988927,
+        0.002859654879818814, 0.0032638202673402434, 0.0036674553764855806, 0.004070546930804782,
+        0.004473081685789086, 0.00487504642928087, 0.005276427981884175, 0.005677213197373868,
+        0.006077388963101055, 0.0064769422003991195, 0.006875859864988406, 0.0072741289473770565,
+        0.007671736473262572, 0.008068669503931742, 0.008464915136656972, 0.008860460505093223,
+        0.009255292779672637, 0.00964939916799734, 0.01004276691523034, 0.010435383304486134,
+        0.01082723565721787, 0.011218311333604588, 0.011608597732935788, 0.011998082293994189,
+        0.012386752495437438, 0.012774595856178683, 0.013161599935763331, 0.013547752334745988,
+        0.013933040695066009, 0.014317452700419023, 0.014700976076628628, 0.01508359859201691,
+        0.015465308057770433, 0.015846092328307103, 0.016225939301640895, 0.016604836919742787,
+        0.016982773168902298, 0.017359736080086296, 0.017735713729295347, 0.018110694237919292,
+        0.018484665773090516, 0.018857616548034892, 0.019229534822421516, 0.019600408902711305,
+        0.01997022714250126, 0.020338977942869037, 0.02070664975271588, 0.02107323106910503,
+        0.02143871043760092, 0.021803076452605957, 0.022166317757693288, 0.022528423045940313,
+        0.022889381060259333, 0.023249180593724807, 0.02360781048990076, 0.023965259643165288,
+        0.024321516999032657, 0.024676571554473725, 0.025030412358235235, 0.025383028511155186,
+        0.025734409166478137, 0.026084543530167408, 0.026433420861215216, 0.02678103047195084,
+        0.02712736172834808, 0.027472404050327838, 0.02781614691206099, 0.028158579842268938,
+        0.02849969242452053, 0.028839474297528277, 0.029177915155443, 0.029515004748143753,
+        0.029850732881528442, 0.030185089417801472, 0.030518064275757862, 0.030849647431067034,
+        0.03117982891655383, 0.031508598822476995, 0.031835947296805715, 0.03216186454549446,
+        0.03248634083275446, 0.032809366481324556, 0.03313093187273905, 0.033451027447592346,
+        0.033769643705803425, 0.034086771206876657, 0.03440240057016115, 0.034716522475106884,
+        0.03502912766151942, 0.03534020692981313, 0.03564975114125977, 0.03595775121823712,
+        0.03626419814447477, 0.036569082965296464, 0.03687239678786175, 0.037174130781404954,
+        0.03747427617747068, 0.0377728242701485, 0.03806976641630492, 0.03836509403581284,
+        0.03865879861177836, 0.03895087169076629, 0.03924130488302231, 0.03953008986269311,
+        0.03981721836804547, 0.040102682201680125, 0.04038647323074604, 0.04066858338715205,
+        0.04094900466777353, 0.04122772913466016, 0.041504748915239635, 0.041780056202518276,
+        0.04205364325528091, 0.042325502398287876, 0.04259562602246853, 0.042864006585113724,
+        0.043130636610065824, 0.04339550868790523, 0.043658615476135414, 0.04391994969936587,
+        0.04417950414949153, 0.0444372716858705, 0.044693245235499876, 0.04494741779318757,
+        0.04519978242172354, 0.04545033225204764, 0.0456990604834146, 0.045945960383557735,
+        0.04619102528884997, 0.046434248604460986, 0.04667562380451385, 0.0469151444322387,
+        0.0471528041001229, 0.04738859649005979, 0.04762251535349542, 0.047854554511571244,
+        0.0480847078552657, 0.048312969345533176, 0.04853933301343993, 0.048763792960297585,
+        0.048986343357795455, 0.04920697844812826, 0.049425692544123205, 0.04964248002936417,
+        0.04985733535831241, 0.05007025305642578, 0.05028122772027596, 0.050490254017661544,
+        0.05069732668771989, 0.05090244054103713, 0.0511055904597531, 0.051306771397666755,
+        0.051505978380337665, 0.05170320650518459, 0.05189845094158275, 0.05209170693095782,
+        0.052282969786877825, 0.05247223489514173, 0.05265949771386699, 0.05284475377357324,
+        0.053027998677264515, 0.05320922810050816, 0.0533884377915117, 0.053565623571197166,
+        0.05374078133327278, 0.0539139070443027, 0.054084996743773234, 0.05425404654415744,
+        0.05442105263097727, 0.05458601126286231, 0.05474891877160698, 0.05490977156222513,
+        0.05506856611300162, 0.055225298975541265, 0.05537996677481686, 0.05553256620921263,
+        0.055683094050566505, 0.05583154714420941, 0.055977922409002734, 0.05612221683737194,
+        0.05626442749533909, 0.056404551522552464, 0.056542586132313236, 0.05667852861160009,
+        0.05681237632109142, 0.05694412669518482, 0.057073777242014206, 0.05720132554346434,
+        0.05732676925518302, 0.05745010610659129, 0.057571333900889594, 0.057690450515063364,
+        0.05780745389988526, 0.0579223420799146, 0.05803511315349475, 0.05814576529274893,
+        0.05825429674357098, 0.05836070582561688, 0.05846499093229174, 0.0585671505307346,
+        0.05866718316180139, 0.05876508744004535, 0.058860862053694285, 0.05895450576462613,
+        0.059046017408341936, 0.059135395893936134, 0.05922264020406479, 0.05930774939491101,
+        0.05939072259614806, 0.05947155901090029, 0.05955025791570163, 0.05962681866045101,
+        0.0597012406683663, 0.05977352343593546, 0.05984366653286507, 0.059911669602026374,
+        0.05997753235939982, 0.06004125459401647, 0.06010283616789632, 0.06016227701598629,
+        0.06021957714609379, 0.060274736638818986, 0.06032775564748448, 0.0603786343980625,
+        0.06042737318909979, 0.06047397239164051, 0.06051843244914587, 0.060560753877412256,
+        0.060600937264487044, 0.06063898327058153, 0.06067489262798181, 0.06070866614095681,
+        0.06074030468566573, 0.06076980921006057, 0.06079718073378871, 0.06082242034809136,
+        0.060845529215701556, 0.06086650857073752, 0.06088535971859639, 0.06090208403584315,
+        0.06091668297009912, 0.06092915803992764, 0.06093951083471679, 0.060947743014560594,
+        0.06095385631013818, 0.06095785252258952, 0.0609597335233904, 0.06095950125422446,
+        0.06095715772685217, 0.060952705022979094, 0.06094614529412143, 0.06093748076146855,
+        0.06092671371574378, 0.06091384651706437, 0.06089888159479673, 0.060881821447411125,
+        0.060862668642334125, 0.06084142581579845, 0.06081809567269077, 0.060792680986397535,
+        0.06076518459864803, 0.06073560941935629, 0.06070395842646031, 0.06067023466575852,
+        0.060634441250745436, 0.06059658136244441, 0.06055665824923798, 0.06051467522669694,
+        0.06047063567740685, 0.06042454305079234, 0.060376400862939425, 0.06032621269641617,
+        0.06027398220009076, 0.060219713088947496, 0.060163409143901316, 0.060105074211609606,
+        0.060044712204282494, 0.059982327099490786, 0.05991792293997202, 0.05985150383343446,
+        0.05978307395235913, 0.05971263753380015, 0.059640198879182156, 0.05956576235409706,
+        0.05948933238809775, 0.059410913474490235, 0.05933051017012428, 0.05924812709518094,
+        0.05916376893295987, 0.05907744042966268, 0.058989146394176184, 0.058898891697852736,
+        0.058806681274288695, 0.058712520119102156, 0.05861641328970636, 0.058518365905084,
+        0.05841838314555813, 0.0583164702525612, 0.05821263252840275, 0.058106875336035056,
+        0.05799920409881726, 0.05788962430027689, 0.057778141483870546, 0.05766476125274204,
+        0.05754948926947907, 0.0574323312558684, 0.05731329299264863, 0.05719238031926159,
+        0.05706959913360234, 0.056944955391766416, 0.05681845510779613, 0.05669010435342563,
+        0.05655990925782288, 0.056427876007330645, 0.056294010845206695, 0.056158320071360596,
+        0.05602081004208959, 0.05588148716981398, 0.055740357922808785, 0.055597428824935194,
+        0.055452706455369906, 0.055306197448333025, 0.05515790849281448, 0.05500784633229773,
+        0.054856017764484254, 0.05470242964101336, 0.05454708886718352, 0.05439000240166987,
+        0.05423117725624114, 0.054070620495474966, 0.05390833923647164, 0.05374434064856669,
+        0.05357863195304078, 0.053411220422829676, 0.053242113382232094, 0.05307131820661516,
+        0.05289884232212019, 0.05272469320536576, 0.05254887838314916, 0.05237140543214789,
+        0.052192281978618305, 0.05201151569809316, 0.05182911431507823, 0.051645085602747345,
+        0.0514594373826359, 0.051272177524333065, 0.05108331394517288, 0.0508928546099235,
+        0.05070080753047588, 0.05050718076553058, 0.05031198242028283, 0.05011522064610828,
+        0.0499169036402448, 0.0497170396454746, 0.049515636949805555, 0.04931270388614967,
+        0.04910824883200156, 0.048902280209115474, 0.04869480648318099, 0.04848583616349691,
+        0.04827537780264567, 0.04806343999616458, 0.047850031382217564, 0.047635160641264364,
+        0.04741883649572963, 0.04720106770967029, 0.04698186308844284, 0.046761231478367575,
+        0.04653918176639376, 0.046315722879762454, 0.04609086378566934, 0.04586461349092483,
+        0.045636981041614975, 0.04540797552276073, 0.045177606057975, 0.044945881809120805,
+        0.044712811975966965, 0.0444784057958428, 0.04424267254329306, 0.04400562152973086,
+        0.043767262103089295, 0.04352760364747376, 0.043286655582812, 0.04304442736450319,
+        0.042800928483067525, 0.042556168463793266, 0.04231015686638364, 0.042062903284603236,
+        0.04181441734592326, 0.04156470871116545, 0.041313787074146284, 0.041061662161319346,
+        0.040808343731417285, 0.04055384157509319, 0.040298165514561494, 0.040041325403236364,
+        0.039783331125372356, 0.03952419259570156, 0.03926391975907112, 0.03900252259008071,
+        0.03874001109271837, 0.03847639529999571, 0.03821168527358312, 0.03794589110344371,
+        0.03767902290746693, 0.037411090831101425, 0.03714210504698767, 0.036872075754589076,
+        0.03660101317982328, 0.03632892757469323, 0.03605582921691673, 0.03578172840955532,
+        0.035506635480644505, 0.035230560782821226, 0.034953514692951604, 0.034675507611758985,
+        0.03439654996345064, 0.03411665219534356, 0.03383582477749137, 0.03355407820230968,
+        0.03327142298420014, 0.032987869659176765, 0.032703428784489154, 0.032418110938246265,
+        0.032131926719040985, 0.03184488674557201, 0.0315570016562673, 0.03126828210890679,
+        0.030978738780244097, 0.030688382365627844, 0.030397223578624566, 0.030105273150638726,
+        0.029812541830533817, 0.029519040384253566, 0.029224779594442283, 0.028929770260064304,
+        0.028634023196024743, 0.028337549232789458, 0.028040359216003338, 0.027742464006111193,
+        0.027443874477976072, 0.02714460152049846, 0.026844656036235127, 0.026544048941018417,
+        0.026242791163573958, 0.025940893645140052, 0.025638367339085654, 0.025335223210528723,
+        0.025031472235954786, 0.02472712540283523, 0.024422193709244495, 0.024116688163479727,
+        0.023810619783678086, 0.02350399959743439, 0.023196838641420386, 0.022889147961002405,
+        0.022580938609858766, 0.022272221649599324, 0.02196300814938329, 0.02165330918553663,
+        0.021343135841172124, 0.021032499205806764, 0.02072141037498076, 0.020409880449876187,
+        0.02009792053693617, 0.0197855417474835, 0.019472755197340288, 0.01915957200644721,
+        0.018846003298482496, 0.018532060200482606, 0.018217753842461826, 0.017903095357031874,
+        0.017588095879023363, 0.017272766545105955, 0.016957118493408734, 0.016641162863142454,
+        0.01632491079422065, 0.016008373426880593, 0.015691561901306696, 0.015374487357252282,
+        0.01505716093366208, 0.014739593768295605, 0.014421796997350954, 0.014103781755087757,
+        0.013785559173452385, 0.013467140381701721, 0.013148536506028473, 0.012829758669186871,
+        0.012510817990118158, 0.012191725583577158, 0.011872492559758398, 0.011553130023924319,
+        0.011233649076032538, 0.010914060810363398, 0.010594376315149812, 0.010274606672206033,
+        0.009954762956556637, 0.00963485623606807, 0.009314897571078968, 0.00899489801403082,
+        0.0086748686091013, 0.008354820391836078, 0.008034764388781564, 0.007714711617119714,
+        0.007394673084301857, 0.0070746597876834945, 0.006754682714160666, 0.006434752839805615,
+        0.006114881129504146, 0.005795078536593415, 0.005475356002500293, 0.0051557244563797755,
+        0.004836194814755938, 0.004516777981161898, 0.0041974848457805095, 0.003878326285087576,
+        0.003559313161494015, 0.003240456322988873, 0.0029217666027848265, 0.0026032548189627515,
+        0.002284931774117097, 0.001966808255003779, 0.0016488950321871447, 0.0013312028596883085,
+        0.0010137424746346152, 0.0006965245969101002, 0.0003795599288058421, 6.285915467254162e-05,
+        -0.00025356705942685884, -0.0005697080660641302, -0.0008855532367899753, -0.0012010919624790427,
+        -0.001516313653674007, -0.0018312077409269592, -0.002145763675141579, -0.0024599709279143336,
+        -0.002773818991872944, -0.0030872973810156332, -0.003400395631049312, -0.0037131032997250904,
+        -0.0040254099671744314, -0.004337305236244289, -0.00464877873282952, -0.004959820106206004,
+        -0.005270419029362214, -0.005580565199328918, -0.0058902483375090665, -0.006199458190005678,
+        -0.006508184527948633, -0.006816417147820782, -0.007124145871783337, -0.007431360547998411,
+        -0.0077380510509521445, -0.008044207281776546, -0.008349819168568685, -0.008654876666710175,
+        -0.008959369759185553, -0.009263288456897925, -0.009566622798984828, -0.009869362853133048,
+        -0.010171498715890542, -0.010473020512978834, -0.010773918399603602, -0.011074182560763769,
+        -0.011373803211559242, -0.011672770597498295, -0.011971074994802405, -0.012268706710710557,
+        -0.01256565608378274, -0.012861913484200516, -0.013157469314067837, -0.01345231400770978,
+        -0.013746438031970691, -0.014039831886509433, -0.014332486104094773, -0.014624391250899498,
+        -0.014915537926791473, -0.01520591676562496, -0.015495518435530289, -0.015784333639201564,
+        -0.016072353114182588, -0.016359567633153882, -0.016645968004214102, -0.016931545071164202,
+        -0.01721628971378746, -0.01750019284812929, -0.017783245426774348, -0.018065438439124996,
+        -0.018346762911673826, -0.01862720990827931, -0.01890677053043694, -0.01918543591755003,
+        -0.01946319724719821, -0.019740045735406716, -0.020015972636910052, -0.02029096924541853,
+        -0.020565026893880456, -0.020838136954743222, -0.02111029084021401, -0.02138148000251775,
+        -0.021651695934154072, -0.021920930168151803, -0.02218917427832411, -0.022456419879518866,
+        -0.02272265862787032, -0.022987882221047484, -0.023252082398501666, -0.02351525094171103,
+        -0.023777379674426925, -0.02403846046291356, -0.024298485216190675, -0.024557445886271952,
+        -0.024815334468402752, -0.02507214300129515, -0.025327863567363284, -0.025582488292955063,
+        -0.025836009348583166, -0.02608841894915432, -0.026339709354196154, -0.02658987286808375,
+        -0.026838901840263385, -0.027086788665474724, -0.027333525783971194, -0.027579105681740195,
+        -0.02782352089071824, -0.028066763989008207, -0.028308827601092254, -0.02854970439804405,
+        -0.02878938709773831, -0.029027868465060842, -0.029265141312113385, -0.029501198498419878,
+        -0.02973603293112927, -0.029969637565216926, -0.030202005403683896, -0.03043312949775547,
+        -0.03066300294707679, -0.030891618899907138, -0.031118970553312652, -0.03134505115335642,
+        -0.03156985399528785, -0.031793372423729635, -0.0320155998328629, -0.032236529666610024,
+        -0.032456155418817544, -0.03267447063343415, -0.03289146890469003, -0.033107143877272024,
+        -0.03332148924649832, -0.03353449875849, -0.03374616621034269, -0.03395648545029412,
+        -0.03416545037789159, -0.034373054944156, -0.0345792931517461, -0.03478415905511813,
+        -0.03498764676068633, -0.03518975042697975, -0.035390464264797285, -0.03558978253736215,
+        -0.035787699560473014, -0.03598420970265375, -0.03617930738530115, -0.036372987082832,
+        -0.03656524332282515, -0.03675607068616556, -0.03694546380718333, -0.037133417373792735,
+        -0.037319926127627606, -0.03750498486417734, -0.037688588432917565, -0.03787073173744228,
+        -0.03805140973559152, -0.03823061743957868, -0.038408349916114574, -0.038584602286531176,
+        -0.03875936972690166, -0.03893264746815981, -0.03910443079621696, -0.03927471505207657,
+        -0.03944349563194799, -0.03961076798735698, -0.0397765276252551, -0.03994077010812676,
+        -0.040103491054095046, -0.04026468613702372, -0.040424351086619646, -0.040582481688531735,
+        -0.0407390737844482, -0.04089412327219156, -0.04104762610581318, -0.04119957829568306,
+        -0.04134997590858076, -0.04149881506778189, -0.04164609195314414, -0.041791802801190166,
+        -0.04193594390518967, -0.04207851161523871, -0.04221950233833711, -0.042358912538464015,
+        -0.042496738736651614, -0.04263297751105664, -0.04276762549702979, -0.04290067938718338,
+        -0.04303213593145702, -0.04316199193718127, -0.043290244269138724, -0.04341688984962443,
+        -0.04354192565850284, -0.04366534873326371, -0.043787156169075594, -0.043907345118837704,
+        -0.04402591279322923, -0.04414285646075716, -0.044258173447801905, -0.044371861138661095,
+        -0.04448391697559061, -0.0445943384588452, -0.044703123146715276, -0.04481026865556296,
+        -0.04491577265985597, -0.04501963289219866, -0.04512184714336231, -0.04522241326231256,
+        -0.04532132915623512, -0.045418592790559306, -0.04551420218898044, -0.04560815543347857,
+        -0.04570045066433677, -0.045791086080157, -0.045880059937873766, -0.045967370552765355,
+        -0.04605301629846447, -0.04613699560696566, -0.046219306968630997, -0.04629994893219394,
+        -0.046378920104761655, -0.04645621915181401, -0.046531844797202626, -0.04660579582314586,
+        -0.046678071070223134, -0.04674866943736686, -0.04681758988185266, -0.046884831419287114,
+        -0.046950393123593975, -0.0470142741269984, -0.04707647362000887, -0.04713699085139761,
+        -0.047195825128178596, -0.04725297581558411, -0.047308442337038945, -0.04736222417413284,
+        -0.047414320866590434, -0.04746473201224087, -0.04751345726698293, -0.047560496344750895,
+        -0.04760584901747635, -0.04764951511504922, -0.04769149452527686, -0.04773178719384045,
+        -0.047770393124250216, -0.04780731237779798, -0.04784254507350924, -0.047876091388091416,
+        -0.047907951555881484, -0.04793812586879122, -0.04796661467625111, -0.047993418385150956,
+        -0.04801853745978074, -0.048041972421767054, -0.048063723850010204, -0.04808379238061719,
+        -0.048102178706834406, -0.04811888357897738, -0.04813390780435975, -0.0481472522472189,
+        -0.04815891782864113, -0.048168905526484056, -0.04817721637529769, -0.04818385146624309,
+        -0.04818881194700976, -0.04819209902173104, -0.048193713950897166, -0.048193658051267,
+        -0.04819193269577822, -0.048188539313454314, -0.048183479389311626, -0.04817675446426313,
+        -0.04816836613502096, -0.04815831605399712, -0.0481466059292021, -0.048133237524142385,
+        -0.04811821265771498, -0.04810153320410152, -0.04808320109265934, -0.04806321830781184,
+        -0.04804158688893611, -0.04801830893024961, -0.04799338658069447, -0.04796682204382023,
+        -0.04793861757766497, -0.04790877549463467, -0.047877298161380165, -0.0478441879986738,
+        -0.04780944748128269, -0.04777307913784129, -0.047735085550722, -0.0476954693559042,
+        -0.04765423324284078, -0.04761137995432415, -0.04756691228634986, -0.04752083308797827,
+        -0.047473145261195296, -0.04742385176077098, -0.04737295559411616, -0.04732045982113838,
+        -0.04726636755409471, -0.047210681957444364, -0.04715340624769885, -0.047094543693270534,
+        -0.047034097614319606, -0.04697207138259974, -0.046908468421301794, -0.04684329220489559,
+        -0.046776546258971076, -0.046708234160076495, -0.046638359535556645, -0.04656692606338757,
+        -0.04649393747201127, -0.046419397540168494, -0.046343310096729026, -0.04626567902052155,
+        -0.04618650824016166, -0.04610580173387752, -0.046023563529335246, -0.04593979770346184,
+        -0.045854508382266866, -0.04576769974066238, -0.04567937600228236, -0.04558954143929873,
+        -0.045498200372237826, -0.045405357169794026, -0.04531101624864268, -0.045215182073251085,
+        -0.04511785915568828, -0.04501905205543317, -0.04491876537918114, -0.044817003780650046,
+        -0.04471377196038265, -0.044609074665550856, -0.044502916689755256, -0.04439530287282547,
+        -0.04428623810061762, -0.04417572730481239, -0.044063775462708565, -0.043950387597018636,
+        -0.04383556877566044, -0.04371932411154888, -0.04360165876238526, -0.043482577930446506,
+        -0.04336208686237141, -0.04324019084894727, -0.04311689522489405, -0.04299220536864728,
+        -0.04286612670214035, -0.04273866469058475, -0.04260982484224947, -0.04247961270823868,
+        -0.04234803388226898, -0.0422150940004439, -0.04208079874102885, -0.04194515382422375,
+        -0.04180816501193435, -0.04166983810754284, -0.04153017895567748, -0.0413891934419795,
+        -0.04124688749287076, -0.041103267075318986, -0.040958338196601767, -0.04081210690407021,
+        -0.0406645792849107, -0.04051576146590553, -0.04036565961319269, -0.040214279932024734,
+        -0.04006162866652517, -0.03990771209944582, -0.03975253655192109, -0.03959610838322231,
+        -0.039438433990510474, -0.03927951980858846, -0.039119372309650784, -0.03895799800303442,
+        -0.03879540343496619, -0.03863159518831147, -0.03846657988231934, -0.0383003641723691,
+        -0.038132954749714114, -0.03796435834122511, -0.037794581709132795, -0.03762363165076928,
+        -0.03745151499830794, -0.03727823861850332, -0.03710380941242928, -0.03692823431521627,
+        -0.03675152029578851, -0.036573674356598605, -0.0363947035333628, -0.03621461489479419,
+        -0.03603341554233651, -0.0358511126098941, -0.035667713263565035, -0.03548322470136976,
+        -0.035297654152980605, -0.035111008879450435, -0.034923296172940224, -0.03473452335644472,
+        -0.034544697783519526, -0.03435382683800536, -0.03416191793375215, -0.033968978514342746,
+        -0.033775016052815444, -0.03358003805138541, -0.03338405204116586, -0.03318706558188834,
+        -0.03298908626162218, -0.03279012169649338, -0.0325901795304022, -0.0323892674347405,
+        -0.03218739310810864, -0.03198456427603126, -0.03178078869067161, -0.03157607413054729,
+        -0.03137042840024307, -0.03116385933012425, -0.030956374776049327, -0.03074798261908188,
+        -0.030538690765200743, -0.030328507145012278, -0.030117439713458764, -0.029905496449528658,
+        -0.029692685355964598, -0.029479014458972594, -0.029264491807928526, -0.029049125475085702,
+        -0.028832923555281702, -0.028615894165643026, -0.02839804544529225, -0.02817938555505131,
+        -0.027959922677146903, -0.027739665014913602, -0.027518620792498318, -0.027296798254561435,
+        -0.027074205665981305, -0.02685085131155456, -0.026626743495698442, -0.02640189054215153,
+        -0.026176300793674948, -0.025949982611751767, -0.025722944376287474, -0.025495194485309076,
+        -0.0252667413546642, -0.025037593417720203, -0.024807759125061626, -0.024577246944188773,
+        -0.024346065359215022, -0.024114222870564764, -0.023881727994668804, -0.023648589263663006,
+        -0.02341481522508297, -0.023180414441560996, -0.022945395490521416, -0.022709766963876634,
+        -0.02247353746772122, -0.022236715622028447, -0.02199931006034351, -0.021761329429479653,
+        -0.02152278238921036, -0.021283677611965812, -0.021044023782524904, -0.020803829597709943,
+        -0.020563103766079678, -0.02032185500762339, -0.020080092053453076, -0.0198378236454973,
+        -0.019595058536193838, -0.019351805488182303, -0.01910807327399762, -0.01886387067576158,
+        -0.01861920648487594, -0.018374089501714593, -0.018128528535316733, -0.01788253240307728,
+        -0.017636109930441506, -0.01738926995059542, -0.017142021304158553, -0.01689437283887624,
+        -0.016646333409312077, -0.016397911876538624, -0.016149117107831602, -0.0158999579763606,
+        -0.015650443360881793, -0.015400582145430022, -0.015150383219011724, -0.014899855475296472,
+        -0.014649007812309642, -0.014397849132125915, -0.014146388340559932, -0.013894634346861352,
+        -0.013642596063405957, -0.013390282405389461, -0.013137702290520248, -0.012884864638713509,
+        -0.012631778371782922, -0.012378452413136393, -0.01212489568746821, -0.011871117120453691,
+        -0.011617125638443129, -0.011362930168156853, -0.011108539636378182, -0.01085396296965039,
+        -0.010599209093970082, -0.010344286934483084, -0.010089205415180538, -0.009833973458593744,
+        -0.009578599985490972, -0.009323093914573564, -0.00906746416217331, -0.008811719641947928,
+        -0.00855586926458042, -0.008299921937475202, -0.008043886564456754, -0.007787772045467711,
+        -0.007531587276268428, -0.007275341148134298, -0.00701904254755744, -0.006762700355944791,
+        -0.006506323449319266, -0.006249920698019991, -0.005993500966404121, -0.005737073112547369,
+        -0.005480645987946627, -0.005224228437222215, -0.004967829297820585, -0.004711457399718278,
+        -0.004455121565124858, -0.004198830608187783, -0.003942593334696934, -0.003686418541790675,
+        -0.0034303150176599995, -0.003174291541257059, -0.002918356882000325, -0.002662519799482811,
+        -0.002406789043179777, -0.0021511733521580208, -0.0018956814547833383, -0.0016403220684323813,
+        -0.0013851038992011412, -0.0011300356416173616, -0.0008751259783502363, -0.0006203835799247027,
+        -0.0003658171044325204, -0.00011143519724645004, 0.00014275350926598796, 0.0003967403960271835,
+        0.0006505168575349716, 0.0009040743021459497, 0.0011574041523588947, 0.001410497845097457,
+        0.0016633468319914133, 0.0019159425796587235, 0.00216827656998534, 0.002420340300405178,
+        0.002672125284178332, 0.00292362305067095, 0.003174825145630515, 0.0034257231314642053,
+        0.003676308587514074, 0.003926573110332396, 0.004176508313955306, 0.004426105830177933,
+        0.004675357308825033, 0.004924254418024512, 0.005172788844477904, 0.005420952293730155,
+        0.005668736490439921, 0.0059161331786474584, 0.0061631341220426085, 0.006409731104230886,
+        0.006655915929000999, 0.006901680420587838, 0.007147016423938206, 0.007391915804973368,
+        0.007636370450851612, 0.007880372270228933, 0.008123913193521218, 0.008366985173161569,
+        0.008609580183860635, 0.008851690222863483, 0.009093307310206583, 0.009334423488972936,
+        0.00957503082554766, 0.00981512140987119, 0.010054687355692203, 0.010293720800819476,
+        0.010532213907372122, 0.010770158862030066, 0.011007547876282399, 0.011244373186675085,
+        0.011480627055057164, 0.011716301768827712, 0.011951389641178405, 0.01218588301133871,
+        0.012419774244817479, 0.012653055733644543, 0.012885719896610323, 0.013117759179506543,
+        0.01334916605536229, 0.013579933024682508, 0.013810052615683148, 0.014039517384526228,
+        0.01426831991555275, 0.014496452821516187, 0.014723908743813005, 0.01495068035271317,
+        0.01517676034758918, 0.015402141457143479, 0.015626816439635885, 0.015850778083108516,
+        0.016074019205610485, 0.016296532655420335, 0.016518311311269344, 0.0167393480825605,
+        0.0169596359095896, 0.01717916776376257, 0.017397936647813136, 0.01761593559601793,
+        0.017833157674412112, 0.018049595981001955, 0.01826524364597753, 0.018480093831922673,
+        0.018694139734026265, 0.018907374580288495, 0.01911979163172957, 0.019331384182594877,
+        0.019542145560559557, 0.019752069126931313, 0.01996114827685322, 0.02016937643950367,
+        0.020376747078295548, 0.020583253691075238, 0.02078888981031777, 0.02099364900332347,
+        0.021197524872411364, 0.02140051105511209, 0.021602601224358524, 0.02180378908867732,
+        0.022004068392375665, 0.02220343291572989, 0.02240187647517083, 0.0225993929234685,
+        0.02279597614991486, 0.022991620080506623, 0.02318631867812414, 0.0233800659427117,
+        0.023572855911454704, 0.02376468265895527, 0.02395554029740803, 0.02414542297677285,
+        0.024334324884947096, 0.024522240247935638, 0.024709163330021156, 0.024895088433930197,
+        0.025080009901000865, 0.025263922111346857, 0.025446819484021152, 0.025628696477177325,
+        0.025809547588231396, 0.025989367354019112, 0.026168150350954673, 0.02634589119518641,
+        0.026522584542751648, 0.026698225089729098, 0.026872807572391556, 0.02704632676735547,
+        0.02721877749172963, 0.027390154603262648, 0.027560453000488074, 0.027729667622869415,
+        0.027897793450942215, 0.028064825506455605, 0.028230758852511507, 0.028395588593703693,
+        0.028559309876253235, 0.028721917888144603, 0.028883407859259014, 0.029043775061506374,
+        0.029203014808955523, 0.029361122457964364, 0.02951809340730592, 0.029673923098295217,
+        0.02982860701491338, 0.029982140683930632, 0.03013451967502715, 0.03028573960091326,
+        0.030435796117447494, 0.030584684923753144, 0.030732401762333605, 0.03087894241918509,
+        0.031024302723909575, 0.03116847854982461, 0.0313114658140721, 0.03145326047772544,
+        0.03159385854589586, 0.0317332560678353, 0.03187144913703984, 0.03200843389135022,
+        0.03214420651305129, 0.032278763228969325, 0.03241210031056863, 0.03254421407404577,
+        0.03267510088042289, 0.032804757135638074, 0.03293317929063656, 0.03306036384145739,
+        0.03318630732932101, 0.033311006340713524, 0.03343445750747046, 0.033556657506858045,
+        0.03367760306165378, 0.033797290940224496, 0.033915717956603336, 0.03403288097056535,
+        0.03414877688770054, 0.03426340265948639, 0.03437675528335787, 0.034488831802776676,
+        0.03459962930729764, 0.03470914493263538, 0.0348173758607271, 0.03492431931979551,
+        0.03502997258440931, 0.035134332975542346, 0.03523739786063058, 0.03533916465362867,
+        0.03543963081506344, 0.03553879385208651, 0.03563665131852564, 0.03573320081493307,
+        0.03582843998863431, 0.03592236653377335, 0.036014978191357135, 0.03610627274929844,
+        0.03619624804245727, 0.03628490195267933, 0.0363722324088352, 0.0364582373868554,
+        0.03654291490976566, 0.03662626304771939, 0.03670827991802962, 0.036788963685197906,
+        0.03686831256094274, 0.03694632480422612, 0.03702299872127819, 0.037098332665619974,
+        0.037172325038085624, 0.03724497428684172, 0.03731627890740589, 0.03738623744266307,
+        0.03745484848288077, 0.03752211066572235, 0.03758802267625871, 0.0376525832469785,
+        0.03771579115779634, 0.03777764523606016, 0.03783814435655579, 0.03789728744151123,
+        0.03795507346059799, 0.03801150143093195, 0.03806657041707172, 0.038120279531016266,
+        0.03817262793219978, 0.03822361482748582, 0.038273239471160035, 0.03832150116491998,
+        0.03836839925786486, 0.03841393314648247, 0.03845810227463543, 0.038500906133545156,
+        0.03854234426177444, 0.038582416245208695, 0.03862112171703495, 0.03865846035772016,
+        0.03869443189498693, 0.038729036103788396, 0.03876227280628137, 0.0387941418717972,
+        0.03882464321681215, 0.03885377680491545, 0.038881542646776034, 0.03890794080010711,
+        0.0389329713696306, 0.03895663450703811, 0.03897893041095179, 0.03899985932688298,
+        0.03901942154718917, 0.0390376174110301, 0.039054447304321324, 0.03906991165968676,
+        0.03908401095641005, 0.03909674572038327, 0.039108116524055436, 0.03911812398637816,
+        0.03912676877275058, 0.039134051594962665, 0.03913997321113659, 0.03914453442566697,
+        0.03914773608915943, 0.039149579098367346, 0.039150064396127594, 0.03914919297129441,
+        0.03914696585867178, 0.039143384138944325, 0.03913844893860665, 0.03913216142989147,
+        0.03912452283069554, 0.03911553440450482, 0.03910519746031777, 0.039093513352566986,
+        0.03908048348103985, 0.039066109290797194, 0.039050392272090916, 0.039033333960279366,
+        0.039014935935742096, 0.03899519982379302, 0.03897412729459116, 0.03895172006305154,
+        0.0389279798887528, 0.03890290857584489, 0.03887650797295454, 0.03884877997308909,
+        0.038819726513539626, 0.03878934957578209, 0.038757651185376885, 0.03872463341186761,
+        0.038690298368677656, 0.03865464821300632, 0.038617685145721996, 0.03857941141125585,
+        0.03853982929749251, 0.03849894113565995, 0.03845674930021829, 0.038413256208746624,
+        0.03836846432182876, 0.03832237614293747, 0.03827499421831773, 0.038226321136867435,
+        0.0381763595300187, 0.03812511207161575, 0.03807258147779274, 0.03801877050684966,
+        0.03796368195912738, 0.03790731867688071, 0.037849683544150324, 0.037790779486633945,
+        0.03773060947155543, 0.03766917650753256, 0.037606483644444216, 0.037542533973295655,
+        0.037477330626082245, 0.03741087677565299, 0.037343175635571095, 0.03727423045997497,
+        0.037204044543436836, 0.0371326212208202, 0.03705996386713648, 0.03698607589740001,
+        0.03691096076648173, 0.03683462196896205, 0.03675706303898171, 0.03667828755009259,
+        0.03659829911510561, 0.03651710138593901, 0.03643469805346448, 0.03635109284735238,
+        0.036266289535915486, 0.036180291925952034, 0.03609310386258689, 0.03600472922911215,
+        0.03591517194682606, 0.035824435974871144, 0.035732525310070956, 0.03563944398676543,
+        0.035545196076645504, 0.03544978568858653, 0.03535321696848029, 0.035255494099065476,
+        0.03515662129975866, 0.03505660282648188, 0.034955442971490606, 0.03485314606320048,
+        0.034749716466012326, 0.03464515858013641, 0.03453947684141584, 0.034432675721148304,
+        0.03432475972590728, 0.03421573339736154, 0.03410560131209469, 0.033994368081422074,
+        0.033882038351207934, 0.03376861680168111, 0.03365410814724928, 0.03353851713631294,
+        0.03342184855107771, 0.03330410720736565, 0.03318529795442622, 0.03306542567474548,
+        0.032944495283854264, 0.03282251173013661, 0.03269947999463518, 0.032575405090857455,
+        0.03245029206458004, 0.032324145993652444, 0.03219697198779937, 0.032068775188422854,
+        0.031939560768402604, 0.03180933393189598, 0.0316780999141368, 0.03154586398123367,
+        0.031412631429966274, 0.031278407587582165, 0.03114319781159199, 0.03100700748956309,
+        0.030869842038914214, 0.030731706906706926, 0.03059260756943812, 0.03045254953283037,
+        0.03031153833162274, 0.03016957952935862, 0.030026678718175628, 0.02988284151859191,
+        0.029738073579293475, 0.029592380576919767, 0.029445768215849318, 0.029298242227982895,
+        0.029149808372528254, 0.029000472435782042, 0.028850240230912025, 0.028699117597738303,
+        0.028547110402513672, 0.0283942245377032, 0.028240465921763182, 0.028085840498919646,
+        0.02793035423894523, 0.027774013136936764, 0.02761682321309035, 0.02745879051247763,
+        0.027299921104819713, 0.02714022108426199, 0.026979696569145988, 0.02681835370178397,
+        0.026656198648229136, 0.026493237598048256, 0.02632947676409114, 0.026164922382261892,
+        0.025999580711287478, 0.025833458032486885, 0.02566656064953919, 0.025498894888251397,
+        0.025330467096325006, 0.025161283643122916, 0.024991350919434826, 0.024820675337242644,
+        0.024649263329485815, 0.024477121349824202, 0.02430425587240298, 0.024130673391614784,
+        0.023956380421863255, 0.02378138349732371, 0.023605689171706204, 0.023429304018015434,
+        0.023252234628311864, 0.023074487613471393, 0.022896069602945615, 0.022716987244519864,
+        0.022537247204073147, 0.022356856165335347, 0.022175820829645462, 0.021994147915708747,
+        0.021811844159354183, 0.021628916313290062, 0.02144537114686094, 0.021261215445802718,
+        0.021076456011998344, 0.020891099663232854, 0.020705153232947453, 0.020518623569994076,
+        0.020331517538388976, 0.02014384201706699, 0.01995560389963324, 0.01976681009411763,
+        0.019577467522726273, 0.01938758312159393, 0.01919716384053601, 0.019006216642800562,
+        0.018814748504818568, 0.018622766415956293, 0.018430277378265008, 0.018237288406232094,
+        0.01804380652653101, 0.01784983877777182, 0.01765539221025033, 0.01746047388569797,
+        0.017265090877031753, 0.017069250268101854, 0.01687295915344256, 0.016676224638019512,
+        0.016479053836979052, 0.016281453875396232, 0.016083431888023778, 0.01588499501903887,
+        0.0156861504217928, 0.01548690525855737, 0.015287266700273243, 0.0150872419262974,
+        0.014886838124150804, 0.014686062489265336, 0.01448492222473133, 0.014283424541044804,
+        0.01408157665585427, 0.013879385793708452, 0.013676859185802338, 0.013474004069724724,
+        0.013270827689204892, 0.013067337293859999, 0.012863540138940517, 0.01265944348507901,
+        0.01245505459803523, 0.012250380748443892, 0.01204542921156105, 0.011840207267011536,
+        0.011634722198535032, 0.011428981293733494, 0.011222991843817871, 0.011016761143355713,
+        0.010810296490016913, 0.01060360518432261, 0.01039669452939096, 0.01018957183068508,
+        0.009982244395760355, 0.009774719534012271, 0.00956700455642336, 0.00935910677531151,
+        0.009151033504077637, 0.008942792056953613, 0.008734389748750952, 0.008525833894608396,
+        0.00831713180974084, 0.008108290809187832, 0.00789931820756297, 0.007690221318801669,
+        0.0074810074559120135, 0.007271683930722934, 0.007062258053634428, 0.006852737133367259,
+        0.006643128476713626, 0.006433439388286208, 0.006223677170270485, 0.0060138491221742325,
+        0.005803962540579294, 0.005594024718892735, 0.005384042947099002, 0.005174024511511204,
+        0.004963976694523695, 0.004753906774365251, 0.004543822024850515, 0.0043337297151350125,
+        0.004123637109467315, 0.003913551466943596, 0.003703480041261589, 0.0034934300804758517,
+        0.003283408826751328, 0.0030734235161204476, 0.0028634813782374884, 0.002653589636135308,
+        0.0024437555059815693, 0.002233986196836339, 0.002024288910407986, 0.001814670840812705,
+        0.0016051391743313141, 0.0013957010891683774, 0.0011863637552116215, 0.0009771343337907146,
+        0.0007680199774377314, 0.0005590278296473556, 0.000350165024638415, 0.00014143868711391088,
+        -6.714406797532524e-05, -0.0002755761356696215, -0.0004838504212369299, -0.0006919598404096565,
+        -0.0008998973196200926, -0.0011076557962373139, -0.001315228218800422, -0.0015226075472543476,
+        -0.00172978675318312, -0.0019367588200434377, -0.0021435167433969246, -0.0023500535311430475,
+        -0.00255636220375023, -0.0027624357944869477, -0.00296826734965229, -0.003173849928805214,
+        -0.003379176604994518, -0.0035842404649868613, -0.0037890346094949737, -0.003993552153404299,
+        -0.004197786226001022, -0.004401729971196341, -0.004605376547753155, -0.004808719129510105,
+        -0.005011750905605868, -0.005214465080701933, -0.005416854875205989, -0.005618913525493402,
+        -0.005820634284128644, -0.0060220104200852595, -0.006223035218966968, -0.006423701983225125,
+        -0.006624004032378331, -0.006823934703229525, -0.007023487350083052, -0.007222655344960342,
+        -0.00742143207781597, -0.007619810956751992, -0.007817785408231142, -0.008015348877291375,
+        -0.008212494827756387, -0.008409216742448383, -0.008605508123398269, -0.00880136249205564,
+        -0.008996773389497358, -0.009191734376637086, -0.009386239034430996, -0.009580280964085745,
+        -0.009773853787263611, -0.00996695114628763, -0.010159566704345126, -0.010351694145692111,
+        -0.010543327175854099, -0.010734459521828792, -0.010925084932286238, -0.01111519717776824,
+        -0.011304790050887817, -0.011493857366526668, -0.0116823929620325, -0.011870390697414612,
+        -0.012057844455540201, -0.012244748142327235, -0.012431095686938998, -0.01261688104197601,
+        -0.012802098183667662, -0.012986741112062326, -0.013170803851218148, -0.013354280449390084,
+        -0.013537164979218998, -0.01371945153791763, -0.013901134247456716, -0.01408220725474928,
+        -0.01426266473183509, -0.014442500876063117, -0.01462170991027329, -0.014800286082977555,
+        -0.014978223668539264, -0.015155516967352548, -0.015332160306019705, -0.015508148037528145,
+        -0.01568347454142572, -0.01585813422399644, -0.016032121518432703, -0.016205430885009215,
+        -0.016378056811253802, -0.016549993812118295, -0.016721236430147585, -0.016891779235648826,
+        -0.01706161682685768, -0.017230743830105505, -0.017399154899984087, -0.01756684471950997,
+        -0.017733808000286976, -0.01790003948266882, -0.018065533935919528, -0.018230286158373066,
+        -0.018394290977592435, -0.018557543250526352, -0.01872003786366662, -0.018881769733202526,
+        -0.019042733805175745, -0.019202925055632324, -0.019362338490775986, -0.019520969147117352,
+        -0.01967881209162467, -0.019835862421871645, -0.019992115266185072, -0.020147565783790504,
+        -0.020302209164957823, -0.020456040631144604, -0.02060905543513916, -0.02076124886120123,
+        -0.020912616225203514, -0.021063152874769443, -0.02121285418941206, -0.02136171558067035,
+        -0.021509732492245023, -0.021656900400132295, -0.021803214812757786, -0.021948671271108187,
+        -0.02209326534886145, -0.022236992652517506, -0.022379848821525147, -0.022521829528410354,
+        -0.02266293047890132, -0.022803147412053696, -0.02294247610037328, -0.023080912349939295,
+        -0.02321845200052403, -0.023355090925713643, -0.02349082503302601, -0.023625650264028276,
+        -0.023759562594452253, -0.02389255803431026, -0.024024632628007185, -0.02415578245445384,
+        -0.02428600362717741, -0.02441529229443087, -0.024543644639302146, -0.024671056879820602,
+        -0.02479752526906327, -0.024923046095259076, -0.025047615681892833, -0.025171230387806505,
+        -0.025293886607300402, -0.025415580770232628, -0.02553630934211735, -0.025656068824221147,
+        -0.02577485575365961, -0.025892666703490365, -0.026009498282807325, -0.026125347136831567,
+        -0.026240209947002206, -0.02635408343106486, -0.02646696434316043, -0.0265788494739107,
+        -0.026689735650504007, -0.026799619736778857, -0.0269084986333064, -0.027016369277472176,
+        -0.027123228643555302, -0.0272290737428077, -0.027333901623530717, -0.027437709371151954,
+        -0.027540494108298917, -0.027642252994872787, -0.02774298322812056, -0.02784268204270538,
+        -0.02794134671077565, -0.028038974542033652, -0.028135562883801507, -0.02823110912108677,
+        -0.028325610676646427, -0.028419065011049415, -0.02851146962273781, -0.028602822048086875,
+        -0.02869311986146369, -0.028782360675284415, -0.02887054214006989, -0.028957661944500498,
+        -0.02904371781546938, -0.02912870751813412, -0.02921262885596733, -0.02929547967080572,
+        -0.029377257842898238, -0.02945796129095208, -0.029537587972178193, -0.029616135882334478,
+        -0.029693603055769145, -0.02976998756546063, -0.029845287523058248, -0.02991950107891983,
+        -0.02999262642214932, -0.030064661780631677, -0.03013560542106804, -0.030205455649007795,
+        -0.03027421080888069, -0.030341869284026997, -0.03040842949672599, -0.030473889908223785,
+        -0.030538249018759525, -0.030601505367589953, -0.03066365753301263, -0.030724704132388862,
+        -0.0307846438221634, -0.030843475297884324, -0.030901197294221052, -0.030957808584980904,
+        -0.03101330798312433, -0.031067694340778957, -0.031120966549252158, -0.031173123539042148,
+        -0.031224164279848112, -0.031274087780578615, -0.0313228930893585, -0.031370579293535344,
+        -0.03141714551968324, -0.03146259093360639, -0.0315069147403407, -0.03155011618415423,
+        -0.031592194548546365, -0.03163314915624526, -0.03167297936920464, -0.03171168458859846,
+        -0.031749264254815, -0.03178571784744875, -0.031821044885291784, -0.03185524492632346,
+        -0.03188831756769851, -0.03192026244573402, -0.03195107923589566, -0.03198076765278124,
+        -0.032009327450104166, -0.032036758420675146, -0.03206306039638224, -0.032088233248170096,
+        -0.03211227688601763, -0.0321351912589141, -0.03215697635483471, -0.03217763220071371,
+        -0.0321971588624171, -0.03221555644471374, -0.03223282509124499, -0.03224896498449319,
+        -0.03226397634574884, -0.03227785943507631, -0.032290614551278525, -0.03230224203185997,
+        -0.03231274225298886, -0.0323221156294574, -0.03233036261464143, -0.0323374837004581,
+        -0.0323434794173229, -0.032348350334105, -0.03235209705808109, -0.03235472023488865,
+        -0.03235622054847709, -0.0323565987210584, -0.03235585551305591, -0.03235399172305213,
+        -0.032351008187735, -0.032346905781843245, -0.032341685418110284, -0.03233534804720667,
+        -0.03232789465768165, -0.03231932627590313, -0.0323096439659966, -0.03229884882978276,
+        -0.03228694200671386, -0.0322739246738086, -0.032259798045586076, -0.032244563373998725,
+        -0.03222822194836302, -0.03221077509529027, -0.03219222417861492, -0.032172570599322686,
+        -0.03215181579547668, -0.032129961242142874, -0.03210700845131375, -0.032082958971831436,
+        -0.03205781438930891, -0.03203157632605083, -0.0320042464409721, -0.03197582642951619,
+        -0.03194631802357194, -0.03191572299138863, -0.031884043137490915, -0.03185128030259157,
+        -0.03181743636350382, -0.031782513233051736, -0.03174651285998011, -0.031709437228863026,
+        -0.03167128836001075, -0.03163206830937624, -0.031591779168459955, -0.03155042306421347,
+        -0.031508002158942526, -0.03146451865020817, -0.03141997477072722, -0.031374372788271467,
+        -0.0313277150055659, -0.031280003760185436, -0.031231241424450936, -0.031181430405323667,
+        -0.031130573144298988, -0.031078672117299037, -0.031025729834563573, -0.03097174884054077,
+        -0.030916731713776045, -0.030860681066800105, -0.030803599546015983, -0.030745489831585,
+        -0.030686354637311182, -0.030626196710525605, -0.03056501883196832, -0.030502823815670422,
+        -0.030439614508834267, -0.030375393791713168, -0.030310164577489494, -0.030243929812152258,
+        -0.030176692474373105, -0.030108455575382092, -0.030039222158841243, -0.02996899530071837,
+        -0.029897778109158717, -0.029825573724356427, -0.029752385318424623, -0.029678216095264328,
+        -0.029603069290432934, -0.02952694817101107, -0.02944985603546876, -0.029371796213530386,
+        -0.029292772066039547, -0.02921278698482116, -0.029131844392544657, -0.02904994774258464,
+        -0.02896710051888122, -0.02888330623579959, -0.02879856843798816, -0.02871289070023605,
+        -0.028626276627329632, -0.02853872985390795, -0.02845025404431734, -0.02836085289246543,
+        -0.0282705301216735, -0.028179289484528566, -0.02808713476273431, -0.027994069766961272,
+        -0.027900098336695548, -0.02780522434008784, -0.027709451673800074, -0.027612784262852585,
+        -0.027515226060468887, -0.027416781047921644, -0.02731745323437564, -0.027217246656730993,
+        -0.027116165379465786, -0.027014213494477173, -0.02691139512092184, -0.026807714405055784,
+        -0.02670317552007336, -0.026597782665945063, -0.026491540069255134, -0.02638445198303774,
+        -0.02627652268661263, -0.026167756485420097, -0.02605815771085522, -0.02594773072010065,
+        -0.02583647989595979, -0.025724409646687877, -0.025611524405823545, -0.025497828632018597,
+        -0.025383326808867998, -0.02526802344473786, -0.025151923072594378, -0.025035030249830278,
+        -0.024917349558091856, -0.02479888560310459, -0.024679643014498553, -0.02455962644563236,
+        -0.02443884057341726, -0.024317290098139994, -0.024194979743285168, -0.024071914255357086,
+        -0.023948098403700466, -0.023823536980320865, -0.023698234799704353, -0.02357219669863688,
+        -0.02344542753602173, -0.023317932192698395, -0.02318971557125883, -0.023060782595864266,
+        -0.022931138212060952, -0.02280078738659579, -0.022669735107230072, -0.02253798638255474,
+        -0.022405546241802687, -0.022272419734662388, -0.02213861193108979, -0.02200412792112038,
+        -0.021868972814679852, -0.021733151741395094, -0.021596669850404208, -0.02145953231016554,
+        -0.021321744308267464, -0.021183311051236064, -0.021044237764343415, -0.02090452969141478,
+        -0.02076419209463602, -0.020623230254358815, -0.020481649468908042, -0.02033945505438609,
+        -0.020196652344478056, -0.020053246690256325, -0.019909243459984696, -0.01976464803892111,
+        -0.019619465829121892, -0.019473702249243382, -0.019327362734344485, -0.01918045273568862,
+        -0.019032977720544596, -0.018884943171987873, -0.018736354588700814, -0.018587217484773242,
+        -0.01843753738950107, -0.018287319847187112, -0.018136570416938672, -0.017985294672466828,
+        -0.017833498201884252, -0.01768118660750349, -0.017528365505633806, -0.017375040526378723,
+        -0.017221217313432558, -0.017066901523877475, -0.01691209882797835, -0.01675681490898014,
+        -0.016601055462902106, -0.016444826198333658, -0.01628813283622897, -0.016130981109702028,
+        -0.01597337676382027, -0.015815325555399197, -0.01565683325279593, -0.01549790563570262,
+        -0.015338548494940237, -0.015178767632250926, -0.01501856886009116, -0.014857958001424237,
+        -0.014696940889512938, -0.014535523367710721, -0.014373711289254859, -0.014211510517057184,
+        -0.014048926923496146, -0.013885966390207746, -0.013722634807877325, -0.013558938076029505,
+        -0.0133948821028202, -0.013230472804826402, -0.01306571610683699, -0.01290061794164303,
+        -0.012735184249828318, -0.01256942097955895, -0.01240333408637364, -0.012236929532973484,
+        -0.012070213289011679, -0.011903191330883526, -0.011735869641515352, -0.011568254210154538,
+        -0.011400351032158576, -0.011232166108784967, -0.011063705446979382, -0.010894975059166267,
+        -0.01072598096303694, -0.010556729181339175, -0.010387225741666093, -0.010217476676245807,
+        -0.010047488021729212, -0.009877265818980635, -0.00970681611286551, -0.00953614495204011,
+        -0.009365258388740322, -0.009194162478571055, -0.009022863280294793, -0.008851366855620985,
+        -0.008679679268995585, -0.008507806587389125, -0.008335754880087448, -0.008163530218479834,
+        -0.007991138675848901, -0.007818586327159883, -0.007645879248850672, -0.007473023518620262,
+        -0.007300025215219972, -0.0071268904182420715, -0.006953625207910333, -0.006780235664869734,
+        -0.0066067278699771595, -0.006433107904091067, -0.00625938184786228, -0.006085555781524559,
+        -0.005911635784685204, -0.005737627936116368, -0.005563538313545551, -0.005389372993447198,
+        -0.005215138050833963, -0.00504083955904885, -0.004866483589555838, -0.004692076211733416,
+        -0.004517623492665616, -0.004343131496934971, -0.004168606286414993, -0.003994053920063511,
+        -0.003819480453715101, -0.0036448919398749564, -0.003470294427512249, -0.003295693961854607,
+        -0.003121096584181023, -0.002946508331617845, -0.002771935236932228, -0.0025973833283277304,
+        -0.0024228586292393355, -0.0022483671581294607, -0.0020739149282832307, -0.0018995079476050785,
+        -0.0017251522184151644, -0.001550853737246129, -0.0013766184946406822, -0.0012024524749484954,
+        -0.001028361656124487, -0.0008543520095268402, -0.000680429499716105, -0.0005066000842529871,
+        -0.0003328697134991459, -0.00015924433041574078, 1.4270129635957133e-05, 0.0001876677390942901,
+        0.00036094257839580705, 0.000534088736175021, 0.0007071003094617645, 0.0008799714038791712,
+        0.0010526961338416473, 0.0012252686227515782, 0.0013976830031955927, 0.00156993341714122,
+        0.0017420140161327699, 0.0019139189614851004, 0.00208564242448047, 0.002257178586561334,
+        0.0024285216395243973, 0.0025996657857143162, 0.0027706052382167777, 0.00294133422104934,
+        0.0031118469693555725, 0.0032821377295938897, 0.0034522007597306364, 0.0036220303294289056,
+        0.0037916207202385453, 0.00396096622578572, 0.004130061151961898, 0.004298899817110463,
+        0.004467476552216402, 0.004635785701091861, 0.004803821620562829, 0.004971578680655552,
+        0.00513905126478151, 0.005306233769921847, 0.005473120606812044, 0.005639706200125558,
+        0.0058059849886557945, 0.005971951425499592, 0.006137599978238397, 0.006302925129119172,
+        0.006467921375235354, 0.006632583228706884, 0.0067969052168580625, 0.006960881882398098,
+        0.00712450778359764, 0.007287777494466331, 0.007450685604929751, 0.007613226721005293,
+        0.007775395464976893, 0.007937186475570675, 0.00809859440812803, 0.008259613934778883,
+        0.00842023974461481, 0.00858046654386057, 0.008740289056044948, 0.008899702022171708,
+        0.009058700200889377, 0.009217278368659456, 0.009375431319925636, 0.009533153867280932,
+        0.009690440841634273, 0.00984728709237703, 0.010003687487548572, 0.010159636913999492,
+        0.01031513027755733, 0.010470162503188342, 0.010624728535159991, 0.010778823337202937,
+        0.010932441892671485, 0.011085579204703261, 0.011238230296379251, 0.011390390210881451,
+        0.011542054011650413, 0.011693216782542698, 0.011843873627986444, 0.011994019673136362,
+        0.012143650064028486, 0.012292759967734034, 0.012441344572510681, 0.012589399087956398,
+        0.012736918745159238, 0.012883898796847655, 0.013030334517540328, 0.013176221203694827,
+        0.013321554173854, 0.01346632876879472, 0.01361054035167237, 0.013754184308166354,
+        0.013897256046624572, 0.014039750998206613, 0.01418166461702592, 0.014322992380291718,
+        0.014463729788450027, 0.014603872365322486, 0.014743415658246092, 0.014882355238211098,
+        0.015020686699997579, 0.015158405662312266, 0.015295507767923842, 0.015431988683796251,
+        0.01556784410122367, 0.015703069735961975, 0.015837661328360477, 0.015971614643492865,
+        0.0161049254712871, 0.016237589626653018, 0.016369602949611897, 0.016500961305422043,
+        0.01663166058470503, 0.01676169670357096, 0.01689106560374239, 0.01701976325267692,
+        0.017147785643689878, 0.017275128796075415, 0.017401788755226015, 0.017527761592752315,
+        0.017653043406601086, 0.01777763032117215, 0.017901518487435052, 0.018024704083044272,
+        0.0181471833124526, 0.01826895240702566, 0.018390007625153314, 0.018510345252360807,
+        0.01862996160141954, 0.01874885301245589, 0.01886701585305927, 0.018984446518389996,
+        0.019101141431284635, 0.01921709704236146, 0.019332309830124808, 0.0194467763010678,
+        0.019560492989774365, 0.019673456459020695, 0.019785663299875026, 0.019897110131796045,
+        0.020007793602731715, 0.02011771038921537, 0.020226857196461855, 0.02033523075846228,
+        0.020442827838077823, 0.02054964522713178, 0.020655679746502138, 0.02076092824621123,
+        0.020865387605515495, 0.020969054732994156, 0.021071926566636222, 0.021174000073926665,
+        0.021275272251932036, 0.02137574012738435, 0.0214754007567638, 0.021574251226381243,
+        0.02167228865245898, 0.02176951018121016, 0.021865912988917904, 0.02196149428201303,
+        0.02205625129714974, 0.022150181301282316, 0.022243281591738637, 0.022335549496293457,
+        0.022426982373240794, 0.02251757761146538, 0.022607332630511442, 0.022696244880652933,
+        0.022784311842960467, 0.022871531029368015, 0.02295789998273887, 0.023043416276929806,
+        0.023128077516854105, 0.023211881338544564, 0.023294825409213935, 0.023376907427314998,
+        0.02345812512259981, 0.02353847625617705, 0.023617958620568837, 0.023696570039766347,
+        0.023774308369284226, 0.023851171496213373, 0.023927157339273968, 0.024002263848865817,
+        0.02407648900711837, 0.024149830827939953, 0.024222287357064807, 0.024293856672100014,
+        0.024364536882570998, 0.024434326129965274, 0.024503222587776236, 0.02457122446154478,
+        0.024638329988900167, 0.02470453743960009, 0.024769845115568966, 0.024834251350935588,
+        0.02489775451206959, 0.02496035299761625, 0.025022045238531077, 0.02508282969811246,
+        0.02514270487203359, 0.025201669288373085, 0.025259721507644477, 0.02531686012282504,
+        0.025373083759382152, 0.025428391075300486, 0.02548278076110628, 0.025536251539891485,
+        0.025588802167336427, 0.025640431431731493, 0.025691138153997154, 0.02574092118770401,
+        0.025789779419090247, 0.025837711767079156, 0.025884717183294704, 0.025930794652076535,
+        0.02597594319049341, 0.026020161848355847, 0.026063449708227455, 0.026105805885435006,
+        0.026147229528077548, 0.026187719817034535, 0.02622727596597244, 0.02626589722135055,
+        0.026303582862425477, 0.026340332201254476, 0.026376144582697863, 0.026411019384420104,
+        0.026444956016889776, 0.02647795392337858, 0.02651001257995891, 0.02654113149550076,
+        0.026571310211666838, 0.02660054830290738, 0.026628845376453065, 0.026656201072307126,
+        0.026682615063236704, 0.026708087054762433, 0.026732616785147014, 0.026756204025383392,
+        0.026778848579180746, 0.026800550282950232, 0.026821309005789013, 0.026841124649463626,
+        0.026859997148391816, 0.026877926469623722, 0.026894912612821422, 0.026910955610237937,
+        0.026926055526694533, 0.026940212459557453, 0.026953426538713098, 0.02696569792654265,
+        0.02697702681789478, 0.026987413440058082, 0.026996858052732, 0.027005360947996524,
+        0.027012922450281203, 0.027019542916332726, 0.027025222735181438, 0.027029962328107032,
+        0.027033762148602914, 0.02703662268233946, 0.027038544447126146, 0.027039527992873125,
+        0.027039573901550755, 0.02703868278714928, 0.027036855295636025, 0.027034092104912717,
+        0.027030393924771235, 0.02702576149684808, 0.02702019559457826, 0.027013697023147785,
+        0.027006266619445055, 0.02699790525201161, 0.026988613820991204, 0.02697839325807824,
+        0.026967244526465147, 0.026955168620788627, 0.02694216656707465, 0.02692823942268271,
+        0.02691338827624906, 0.026897614247628356, 0.026880918487835116, 0.02686330217898349,
+        0.026844766534226246, 0.02682531279769256, 0.0268049422444251, 0.026783656180315737,
+        0.026761455942040525, 0.026738342896993457, 0.026714318443219125, 0.026689384009344782,
+        0.026663541054510947, 0.026636791068301253, 0.02660913557067119, 0.0265805761118759,
+        0.02655111427239684, 0.026520751662867856, 0.026489489923999632, 0.026457330726503667,
+        0.026424275771015058, 0.02639032678801446, 0.02635548553774865, 0.02631975381015072,
+        0.026283133424758836, 0.026245626230634058, 0.026207234106277743, 0.026167958959547093,
+        0.02612780272757034, 0.026086767376661147, 0.02604485490223137, 0.026002067328703537,
+        0.025958406709421896, 0.025913875126562975, 0.025868474691044738, 0.025822207542435385,
+        0.025775075848860456, 0.02572708180690965, 0.025678227641542713, 0.02562851560599366,
+        0.025577947981675273, 0.025526527078081656, 0.025474255232690313, 0.025421134810863298,
+        0.025367168205747758, 0.02531235783817459, 0.025256706156557705, 0.025200215636791135,
+        0.025142888782145753, 0.025084728123165107, 0.02502573621756072, 0.024965915650105457,
+        0.02490526903252735, 0.02484379900340185, 0.02478150822804298, 0.024718399398394326,
+        0.0246544752329187, 0.024589738476487204, 0.024524191900266952, 0.024457838301608994,
+        0.024390680503934222, 0.024322721356619212, 0.02425396373488107, 0.02418441053966127,
+        0.024114064697508836, 0.02404292916046315, 0.02397100690593456, 0.023898300936586396,
+        0.023824814280213758, 0.023750549989623407, 0.023675511142512075, 0.023599700841343926,
+        0.02352312221322725, 0.023445778409791233, 0.023367672607060325, 0.023288808005329464,
+        0.023209187829037553, 0.02312881532664081, 0.023047693770484597, 0.022965826456675432,
+        0.02288321670495141, 0.022799867858552533, 0.022715783284090303, 0.022630966371415723,
+        0.02254542053348768, 0.02245914920624014, 0.022372155848448552, 0.02228444394159532,
+        0.022196016989735917, 0.02210687851936197, 0.022017032079265933, 0.0219264812404039,
+        0.021835229595757837, 0.021743280760197253, 0.02165063837034061, 0.021557306084415202,
+        0.02146328758211713, 0.021368586564470566, 0.021273206753685634, 0.02117715189301673,
+        0.021080425746619447, 0.020983032099407068, 0.02088497475690638, 0.02078625754511328,
+        0.020686884310347233, 0.020586858919105132, 0.020486185257915214, 0.02038486723318957,
+        0.020282908771076393, 0.020180313817312112, 0.02007708633707185, 0.01997323031482039,
+        0.019868749754162203, 0.019763648677690437, 0.01965793112683599, 0.019551601161716198,
+        0.01944466286098166, 0.01933712032166414, 0.019228977659023264, 0.01912023900639222,
+        0.019010908515023635, 0.01890099035393467, 0.01879048870975164, 0.01867940778655364,
+        0.018567751805716983, 0.018455525005757336, 0.018342731642172976, 0.018229375987286616,
+        0.01811546233008704, 0.01800099497607003, 0.01788597824707992, 0.017770416481148407,
+        0.017654314032335566, 0.01753767527056865, 0.017420504581480836, 0.017302806366249868,
+        0.017184585041436204, 0.01706584503882006, 0.016946590805239047, 0.01682682680242485,
+        0.016706557506839376, 0.016585787409510642, 0.01646452101586876, 0.016342762845580488,
+        0.01622051743238398, 0.016097789323924194, 0.01597458308158537, 0.01585090328032567,
+        0.015726754508510295, 0.015602141367744255, 0.015477068472704626, 0.015351540450973849,
+        0.015225561942870187, 0.015099137601280243, 0.014972272091490072, 0.014844970091015802,
+        0.014717236289434252, 0.014589075388213774, 0.014460492100543286, 0.014331491151162728,
+        0.014202077276192401, 0.01407225522296166, 0.013942029749838054, 0.01381140562605605,
+        0.01368038763154506, 0.013548980556757165, 0.013417189202496207, 0.013285018379743567,
+        0.013152472909486826, 0.013019557622546397, 0.012886277359402292, 0.0127526369700204,
+        0.012618641313680212, 0.012484295258799192, 0.012349603682760092, 0.012214571471736498,
+        0.012079203520517955, 0.01194350473233585, 0.01180748001868865, 0.011671134299166754,
+        0.01153447250127716, 0.011397499560269062, 0.011260220418957509, 0.011122640027548293,
+        0.01098476334346237, 0.010846595331159654, 0.010708140961962901, 0.010569405213882749,
+        0.010430393071439822, 0.010291109525489589, 0.010151559573045818, 0.010011748217103562,
+        0.0098716804664625, 0.009731361335551407, 0.00959079584424958, 0.00944998901771118,
+        0.009308945886188217, 0.009167671484852981, 0.00902617085362135, 0.008884449036975935,
+        0.008742511083788422, 0.008600362047142311, 0.008458006984156623, 0.008315450955807572,
+        0.008172699026751789, 0.008029756265149228, 0.007886627742485497, 0.0077433185333943815,
+        0.007599833715481738, 0.007456178369146525, 0.00731235757740479, 0.007168376425712182,
+        0.007024240001786365, 0.006879953395430117, 0.0067355216983545204, 0.006590950004001026,
+        0.006446243407365245, 0.006301407004819813, 0.006156445893937156, 0.006011365173312809,
+        0.005866169942389057, 0.005720865301277692, 0.005575456350583458, 0.005429948191228325,
+        0.005284345924274187, 0.005138654650746986, 0.004992879471460831, 0.004847025486841466,
+        0.004701097796750217, 0.004555101500309457, 0.004409041695724983, 0.004262923480112671,
+        0.004116751949321148, 0.003970532197757901, 0.0038242693182139677, 0.0036779684016887957,
+        0.003531634537215376, 0.003385272811686904, 0.003238888309680766, 0.003092486113285572,
+        0.002946071301927081, 0.0027996489521940173, 0.0026532241376648, 0.0025068019287345037,
+        0.002360387392441326, 0.0022139855922936325, 0.0020676015880981037, 0.001921240435786485,
+        0.0017749071872437709, 0.0016286068901365755, 0.001482344587741035, 0.0013361253187712517,
+        0.0011899541172094112, 0.00104383601213318, 0.000897776027546351, 0.000751779182208406,
+        0.0006058504894640586, 0.0004599949570738438, 0.00031421758704504863, 0.0001685233754617562,
+        2.2917312316849704e-05, -0.00012259561865653274, -0.00026801044015357814, -0.0004133221814653443,
+        -0.0005585258786459684, -0.0007036165746802448, -0.0008485893196504932, -0.0009934391709022197,
+        -0.0011381611932110615, -0.0012827504589481618, -0.0014272020482452505, -0.0015715110491600754,
+        -0.0017156725578411538, -0.0018596816786907754, -0.0020035335245305857, -0.0021472232167637677,
+        -0.0022907458855382637, -0.0024340966699098396, -0.00257727071800402, -0.002720263187177549,
+        -0.002863069244180644, -0.0030056840653171243, -0.003148102836605065, -0.0032903207539372735,
+        -0.00343233302324067, -0.003574134860635236, -0.003715721492593152, -0.0038570881560972764,
+        -0.003998230098797716, -0.0041391425791709275, -0.00427982086667536, -0.004420260241908031,
+        -0.0045604559967607795, -0.004700403434575827, -0.004840097870299543, -0.004979534630638557,
+        -0.005118709054212478, -0.0052576164917075285, -0.00539625230602975, -0.005534611872457288,
+        -0.005672690578791776, -0.005810483825510545, -0.005947987025916704, -0.006085195606289406,
+        -0.006222105006034032, -0.0063587106778310665, -0.0064950080877844525, -0.006630992715570114,
+        -0.006766660054583568, -0.006902005612085633, -0.007037024909350551, -0.0071717134818105105,
+        -0.007306066879201034, -0.007440080665705905, -0.0075737504201013476, -0.007707071735898307,
+        -0.007840040221486905, -0.0079726515002775, -0.008104901210842444, -0.00823678500705748,
+        -0.00836829855824197, -0.008499437549298224, -0.008630197680851192, -0.00876057466938695,
+        -0.008890564247389897, -0.00902016216348067, -0.009149364182552525, -0.009278166085906966,
+        -0.00940656367138939, -0.009534552753523905, -0.009662129163646185, -0.009789288750038257,
+        -0.009916027378060134, -0.010042340930281838, -0.010168225306615021, -0.010293676424443655,
+        -0.010418690218752932, -0.010543262642259925, -0.010667389665541108, -0.01079106727716027,
+        -0.010914291483796064, -0.011037058310368117, -0.011159363800162546, -0.011281204014957379,
+        -0.011402575035146785, -0.011523472959864027, -0.011643893907104979, -0.011763834013849951,
+        -0.011883289436184882, -0.012002256349422185, -0.01212073094822093, -0.012238709446704852,
+        -0.012356188078582263, -0.012473163097262647, -0.012589630775973656, -0.012705587407877751,
+        -0.012821029306187068, -0.012935952804278074, -0.013050354255805821, -0.013164230034816529,
+        -0.01327757653585986, -0.013390390174100882, -0.013502667385430387, -0.013614404626574822,
+        -0.013725598375205712, -0.013836245130048096, -0.013946341410987077, -0.014055883759176284,
+        -0.01416486873714232, -0.014273292928890577, -0.014381152940009604, -0.014488445397774701,
+        -0.01459516695124993, -0.01470131427139116, -0.014806884051146291, -0.014911873005555604,
+        -0.015016277871851646, -0.015120095409557444, -0.015223322400584228, -0.015325955649328727,
+        -0.015427991982769078, -0.015529428250559758, -0.015630261325126578, -0.015730488101759938,
+        -0.01583010549870742, -0.01592911045726591, -0.01602749994187279, -0.016125270940195172,
+        -0.016222420463220536, -0.0163189455453441, -0.016414843244456818, -0.016510110642032116,
+        -0.016604744843211547, -0.016698742976889874, -0.01679210219579946, -0.01688481967659249,
+        -0.016976892619924633, -0.01706831825053562, -0.017159093817329933, -0.017249216593456684,
+        -0.017338683876388428, -0.017427492987998724, -0.017515641274639733, -0.017603126107217846,
+        -0.01768994488126899, -0.01777609501703314, -0.01786157395952775, -0.017946379178619958,
+        -0.018030508169098524, -0.018113958450744712, -0.018196727568401422, -0.018278813092042788,
+        -0.018360212616841853, -0.01844092376323755, -0.018520944177001137, -0.018600271529301375,
+        -0.01867890351676846, -0.018756837861558125, -0.01883407231141354, -0.018910604639727026,
+        -0.01898643264560097, -0.019061554153907114, -0.01913596701534553, -0.019209669106502687,
+        -0.019282658329908053, -0.019354932614090065, -0.01942648991363145, -0.019497328209223373,
+        -0.01956744550771814, -0.019636839842182036, -0.019705509271946427, -0.019773451882657764,
+        -0.019840665786327677, -0.019907149121381123, -0.019972900052703708, -0.020037916771688615,
+        -0.02010219749628246, -0.020165740471029227, -0.02022854396711498, -0.020290606282409945,
+        -0.020351925741510814, -0.02041250069578154, -0.020472329523393125, -0.020531410629362944,
+        -0.020589742445592727, -0.020647323430905553, -0.020704152071081925, -0.020760226878895432,
+        -0.020815546394146546, -0.020870109183696056, -0.020923913841497502, -0.020976958988628627,
+        -0.02102924327332133, -0.021080765370991926, -0.021131523984268912, -0.021181517843020924,
+        -0.021230745704383384, -0.021279206352783943, -0.021326898599967108, -0.021373821285018278,
+        -0.021419973274386356, -0.02146535346190535, -0.02150996076881553, -0.021553794143783153,
+        -0.021596852562919464, -0.02163913502979846, -0.021680640575474364, -0.02172136825849707,
+        -0.021761317164927678, -0.021800486408352526, -0.02183887512989617, -0.021876482498233714,
+        -0.021913307709602377, -0.021949349987811038, -0.021984608584250397, -0.022019082777900847,
+        -0.022052771875339845, -0.022085675210748775, -0.022117792145918153, -0.022149122070252054,
+        -0.022179664400772146, -0.022209418582119826, -0.022238384086558247, -0.022266560413972857,
+        -0.02229394709187123, -0.02232054367538186, -0.02234634974725193, -0.022371364917844583,
+        -0.022395588825134295, -0.022419021134702344, -0.022441661539730748, -0.02246350976099516,
+        -0.02248456554685739, -0.02250482867325626, -0.022524298943698045, -0.022542976189245768,
+        -0.02256086026850768, -0.022577951067624332, -0.022594248500255463, -0.022609752507565247,
+        -0.02262446305820709, -0.022638380148307154, -0.022651503801447165, -0.02266383406864609,
+        -0.022675371028341138, -0.022686114786367517, -0.022696065475937523, -0.022705223257618534,
+        -0.022713588319310047, -0.022721160876220087, -0.02272794117084022, -0.022733929472920026,
+        -0.02273912607944044, -0.022743531314586285, -0.022747145529717763, -0.022749969103341143,
+        -0.022752002441078507, -0.02275324597563636, -0.022753700166773853, -0.022753365501269437,
+        -0.02275224249288709, -0.02275033168234133, -0.022747633637261722, -0.02274414895215585,
+        -0.02273987824837205, -0.022734822174060643, -0.022728981404134877, -0.022722356640230357,
+        -0.02271494861066414, -0.022706758070392317, -0.022697785800967363, -0.02268803261049419,
+        -0.022677499333585285, -0.02266618683131521, -0.022654095991174024, -0.022641227727019844,
+        -0.022627582979030596, -0.022613162713654912, -0.02259796792356204, -0.022581999627590773,
+        -0.022565258870697987, -0.02254774672390567, -0.022529464284247604, -0.02251041267471492,
+        -0.022490593044200726, -0.022470006567444224, -0.02244865444497352, -0.022426537903047952,
+        -0.02240365819359938, -0.02238001659417253, -0.022355614407864804, -0.022330452963265048,
+        -0.02230453361439124, -0.022277857740627963, -0.022250426746662368, -0.022222242062419736,
+        -0.0221933051429981, -0.022163617468601966, -0.02213318054447509, -0.02210199590083307,
+        -0.022070065092794095, -0.022037389700309714, -0.022003971328094597, -0.021969811605555136,
+        -0.021934912186717587, -0.021899274750155535, -0.021862900998915958, -0.02182579266044512,
+        -0.021787951486513402, -0.021749379253139273, -0.02171007776051251, -0.021670048832916934,
+        -0.021629294318651793, -0.02158781608995276, -0.02154561604291232, -0.02150269609739863,
+        -0.021459058196974586, -0.02141470430881545, -0.021369636423625923, -0.021323856555556263,
+        -0.021277366742118275, -0.02123016904409963, -0.0211822655454781, -0.021133658353334982,
+        -0.02108434959776729, -0.0210343414317999, -0.020983636031296486, -0.020932235594869827,
+        -0.020880142343791312, -0.020827358521900183, -0.02077388639551124, -0.0207197282533226,
+        -0.020664886406322327, -0.020609363187694324, -0.020553160952723675, -0.020496282078701612,
+        -0.020438728964828803, -0.02038050403211885, -0.02032160972330106, -0.020262048502721758,
+        -0.02020182285624563, -0.020140935291156542, -0.020079388336056634, -0.020017184540765894,
+        -0.019954326476220537, -0.019890816734370598, -0.019826657928077206, -0.019761852691008885,
+        -0.019696403677537642, -0.01963031356263347, -0.019563585041759663, -0.019496220830766072,
+        -0.019428223665782552, -0.019359596303111543, -0.019290341519120062, -0.019220462110130614,
+        -0.019149960892312815, -0.019078840701572404, -0.019007104393441405, -0.01893475484296692,
+        -0.01886179494459914, -0.018788227612079142, -0.018714055778326185, -0.018639282395323635,
+        -0.01856391043400537, -0.01848794288414094, -0.018411382754220124, -0.018334233071337275,
+        -0.01825649688107484, -0.018178177247386344, -0.018099277252478684, -0.01801979999669455,
+        -0.01793974859839315, -0.017859126193831246, -0.017777935937043726, -0.017696180999722765,
+        -0.017613864571097145, -0.017530989857811534, -0.017447560083803448, -0.0173635784901819,
+        -0.01727904833510399, -0.01719397289365155, -0.01710835545770711, -0.017022199335829827,
+        -0.016935507853130024, -0.016848284351144046, -0.016760532187708607, -0.016672254736833748,
+        -0.016583455388576456, -0.016494137548913268, -0.01640430463961215, -0.016313960098104235,
+        -0.016223107377355772, -0.016131749945737763, -0.016039891286897087, -0.015947534899626383,
+        -0.01585468429773311, -0.0157613430099086, -0.015667514579597357, -0.015573202564864046,
+        -0.015478410538262061, -0.015383142086700764, -0.015287400811311925, -0.015191190327316664,
+        -0.015094514263891457, -0.014997376264033552, -0.014899779984426525, -0.014801729095305253,
+        -0.014703227280320145, -0.014604278236401484, -0.014504885673623286, -0.014405053315066491,
+        -0.014304784896681785, -0.014204084167153264, -0.014102954887759459, -0.01400140083223609,
+        -0.013899425786637718, -0.013797033549198742, -0.013694227930194185, -0.013591012751801279,
+        -0.013487391847958382, -0.013383369064226065, -0.013278948257646424, -0.013174133296602242,
+        -0.013068928060676274, -0.012963336440510098, -0.012857362337662376, -0.012751009664466866,
+        -0.012644282343890954, -0.012537184309392609, -0.012429719504777998, -0.012321891884058694,
+        -0.012213705411308265, -0.01210516406051867, -0.011996271815457347, -0.011887032669522174,
+        -0.011777450625597978, -0.011667529695912142, -0.011557273901889507, -0.011446687274007793,
+        -0.011335773851652608, -0.011224537682971611, -0.011112982824729452, -0.011001113342161997,
+        -0.010888933308830106, -0.010776446806473577, -0.010663657924865145, -0.010550570761663505,
+        -0.010437189422266626, -0.010323518019665431, -0.010209560674296084, -0.010095321513893096,
+        -0.009980804673342069, -0.009866014294531775, -0.00975095452620698, -0.009635629523820216,
+        -0.009520043449383654, -0.009404200471322068, -0.009288104764323131, -0.009171760509190054,
+        -0.009055171892693024, -0.008938343107420298, -0.008821278351629405, -0.008703981829099322,
+        -0.008586457748980203, -0.008468710325645341, -0.008350743778541956, -0.008232562332041785,
+        -0.008114170215291913, -0.007995571662065859, -0.007876770910613678, -0.007757772203512596,
+        -0.007638579787518151, -0.007519197913413849, -0.007399630835861967, -0.007279882813254147,
+        -0.007159958107561305, -0.007039860984183865, -0.006919595711803035, -0.006799166562229661,
+        -0.006678577810255434, -0.006557833733503069, -0.006436938612276165, -0.006315896729409391,
+        -0.006194712370119692, -0.006073389821855109, -0.005951933374146112, -0.005830347318455689,
+        -0.005708635948029338, -0.005586803557745649, -0.005464854443966911, -0.005342792904389175,
+        -0.005220623237892694, -0.005098349744393057, -0.004975976724691116, -0.004853508480323891,
+        -0.004730949313415543, -0.0046083035265277895, -0.004485575422510628, -0.004362769304354343,
+        -0.004239889475039072, -0.004116940237386972, -0.003993925893913328, -0.0038708507466774854,
+        -0.0037477190971346, -0.003624535245987408, -0.0035013034930372455, -0.003378028137036476,
+        -0.0032547134755403816, -0.003131363804758847, -0.003007983419408794, -0.0028845766125667575,
+        -0.0027611476755210317, -0.002637700897624236, -0.0025142405661468053, -0.0023907709661292343,
+        -0.0022672963802354983, -0.00214382108860664, -0.0020203493687138635, -0.0018968854952120839,
+        -0.001773433739794857, -0.0016499983710469293, -0.0015265836542995774, -0.001403193851484911,
+        -0.0012798332209901853, -0.0011565060175129168, -0.0010332164919162937, -0.0009099688910837541,
+        -0.0007867674577752378, -0.0006636164304828861, -0.0005405200432867125, -0.0004174825257110998,
+        -0.00029450810258154105, -0.00017160099388104698, -4.8765414607025244e-05, 7.399442537038659e-05,
+        0.00019667432145224707, 0.0003192700744519398, 0.00044177749073721835, 0.0005641923823721127,
+        0.0006865105672584078, 0.0008087278692756437, 0.000930840118423333, 0.0010528431509603821,
+        0.0011747328095453602, 0.0012965049433767124, 0.0014181554083320521, 0.0015396800671071324,
+        0.0016610747893550974, 0.001782335451825101, 0.0019034579384998608, 0.002024438140734281,
+        0.002145271957392683, 0.0022659552949857932, 0.0023864840678079527, 0.00250685419807374,
+        0.0026270616160530866, 0.0027471022602084992, 0.0028669720773294477, 0.0029866670226675342,
+        0.003106183060071406, 0.0032255161621212217, 0.003344662310261458, 0.0034636174949358857,
+        0.003582377715719659, 0.0037009389814521313, 0.0038192973103695453, 0.003937448730236663,
+        0.004055389278478045, 0.004173115002309503, 0.0042906219588687445, 0.00440790621534501,
+        0.004524963849109519, 0.004641790947844548, 0.0047583836096721805, 0.004874737943283089,
+        0.004990850068064677, 0.005106716114227769, 0.005222332222935021, 0.005337694546426706,
+        0.005452799248146997, 0.005567642502870083, 0.005682220496825196, 0.0057965294278211855,
+        0.00591056550537144, 0.006024324950817198, 0.006137803997450884, 0.006250998890639327,
+        0.0063639058879459455, 0.00647652125925239, 0.006588841286880223, 0.006700862265711889,
+        0.006812580503310373, 0.006923992320039829, 0.007035094049184498, 0.007145882037067386,
+        0.007256352643168841, 0.007366502240244394, 0.0074763272144410895, 0.007585823965415459,
+        0.007694988906448703, 0.0078038184645624715, 0.00791230908063415, 0.008020457209511236,
+        0.008128259320124913, 0.008235711895604219, 0.008342811433388119, 0.008449554445337935,
+        0.008555937457849267, 0.008661957011963007, 0.00876760966347558, 0.008872891983049285,
+        0.008977800556321715, 0.00908233198401374, 0.009186482882038946, 0.0092902498816103,
+        0.009393629629347328, 0.0094966187873829, 0.009599214033468504, 0.009701412061079844,
+        0.009803209579521523, 0.009904603314030183, 0.010005590005879089, 0.010106166412479968,
+        0.010206329307485343, 0.010306075480890136, 0.010405401739132755, 0.01050430490519447,
+        0.01060278181870018, 0.010700829336016515, 0.010798444330350324, 0.010895623691846676,
+        0.010992364327685597, 0.011088663162178358, 0.011184517136863534, 0.011279923210601896,
+        0.011374878359670623, 0.01146937957785748, 0.01156342387655382, 0.011657008284846197,
+        0.011750129849609442, 0.011842785635596934, 0.011934972725530991, 0.012026688220192656,
+        0.012117929238511299, 0.012208692917651904, 0.012298976413103952, 0.012388776898767346,
+        0.01247809156703926, 0.012566917628899908, 0.012655252313996907, 0.012743092870729757,
+        0.012830436566333538, 0.012917280686961559, 0.013003622537767207, 0.013089459442985556,
+        0.013174788746014302, 0.013259607809493259, 0.013343914015383653, 0.013427704765047286,
+        0.013510977479323324, 0.013593729598606446, 0.01367595858292232, 0.013757661912003631,
+        0.013838837085365183, 0.013919481622377949, 0.013999593062341872, 0.014079168964559663,
+        0.014158206908408197, 0.01423670449340957, 0.014314659339301794, 0.01439206908610891,
+        0.014468931394209308, 0.01454524394440433, 0.014621004437986043, 0.014696210596803344,
+        0.014770860163328682, 0.014844950900722725, 0.014918480592899056, 0.014991447044588135,
+        0.015063848081400147, 0.015135681549886612, 0.01520694531760289, 0.015277637273168276,
+        0.015347755326325764, 0.015417297408001476, 0.01548626147036315, 0.015554645486877308,
+        0.015622447452366756, 0.0156896653830658, 0.015756297316676215, 0.01582234131242161,
+        0.015887795451100938, 0.015952657835141514, 0.016016926588651382, 0.016080599857470748,
+        0.01614367580922199, 0.016206152633360126, 0.016268028541221682, 0.016329301766072758,
+        0.01638997056315655, 0.01645003320974021, 0.01650948800516042, 0.0165683332708688,
+        0.01662656735047579, 0.016684188609794495, 0.016741195436883415, 0.0167975862420879,
+        0.016853359458081724, 0.016908513539907247, 0.016963046965014925, 0.01701695823330186,
+        0.01707024586714987, 0.017122908411462738, 0.017174944433702186, 0.01722635252392349,
+        0.017277131294810528, 0.01732727938170896, 0.017376795442660086, 0.01742567815843261,
+        0.01747392623255423, 0.017521538391342437, 0.01756851338393441, 0.01761484998231563,
+        0.01766054698134867, 0.017705603198800532, 0.017750017475368886, 0.017793788674708242,
+        0.017836915683454906, 0.01787939741125115, 0.01792123279076842, 0.017962420777730283,
+        0.018002960350933753, 0.018042850512270656, 0.01808209028674747, 0.01812067872250464,
+        0.018158614890835378, 0.018195897886203193, 0.01823252682625851, 0.01826850085185518,
+        0.01830381912706566, 0.01833848083919515, 0.018372485198795488, 0.01840583143967802,
+        0.018438518818925366, 0.01847054661690292, 0.018501914137268885, 0.01853262070698418,
+        0.018562665676320977, 0.018592048418870796, 0.01862076833155142, 0.01864882483461351,
+        0.018676217371645945, 0.018702945409580483, 0.01872900843869577, 0.01875440597262032,
+        0.018779137548334886, 0.018803202726173786, 0.018826601089825826, 0.018849332246333686,
+        0.018871395826093408, 0.01889279148285238, 0.0189135188937068, 0.018933577759098378,
+        0.0189529678028099, 0.01897168877196059, 0.01898974043700002, 0.019007122591701585,
+        0.01902383505315527, 0.01903987766175903, 0.019055250281210225, 0.019069952798495594,
+        0.019083985123880553, 0.019097347190898013, 0.019110038956335937, 0.01912206040022465,
+        0.019133411525822604, 0.019144092359602113, 0.019154102951233872, 0.019163443373570666,
+        0.019172113722630616, 0.019180114117579226, 0.019187444700710817, 0.019194105637429276,
+        0.01920009711622792, 0.019205419348668425, 0.0192100725693593, 0.019214057035933426,
+        0.019217373029024466, 0.01922002085224316, 0.019222000832152333, 0.019223313318241297,
+        0.019223958682899386, 0.019223937321388936, 0.019223249651817233, 0.019221896115107898,
+        0.019219877174971197, 0.019217193317874005, 0.019213845053008664, 0.019209832912261207,
+        0.019205157450178825, 0.019199819243936437, 0.019193818893302815, 0.019187157020605448,
+        0.01917983427069525, 0.019171851310909885, 0.01916320883103691, 0.019153907543275752,
+        0.019143948182199094, 0.019133331504713542, 0.019122058290019547, 0.01911012933957029,
+        0.01909754547703048, 0.01908430754823358, 0.019070416421138887, 0.019055872985787828,
+        0.019040678154259107, 0.019024832860623578, 0.01900833806089816, 0.018991194732999088,
+        0.01897340387669428, 0.018954966513555244, 0.01893588368690819, 0.018916156461784052,
+        0.01889578592486839, 0.018874773184450116, 0.018853119370369754, 0.018830825633966795,
+        0.018807893148026455, 0.018784323106725852, 0.018760116725579153, 0.01873527524138219,
+        0.01870979991215654, 0.01868369201709267, 0.01865695285649236, 0.018629583751710643,
+        0.018601586045096874, 0.018572961099935316, 0.01854371030038461, 0.018513835051417214,
+        0.018483336778757343, 0.018452216928819203, 0.018420476968643622, 0.018388118385834457,
+        0.018355142688494423, 0.018321551405160136, 0.018287346084736183, 0.01825252829642902,
+        0.018217099629680083, 0.01818106169409788, 0.018144416119389887, 0.018107164555293563,
+        0.018069308671506676, 0.018030850157617188, 0.017991790723032315, 0.017952132096906864,
+        0.017911876028071306, 0.017871024284958747, 0.017829578655531583, 0.017787540947207362,
+        0.01774491298678437, 0.017701696620365876, 0.017657893713284377, 0.017613506150025077,
+        0.01756853583414881, 0.01752298468821389, 0.017476854653698165, 0.017430147690919502,
+        0.01738286577895673, 0.01733501091556901, 0.01728658511711513, 0.01723759041847225,
+        0.017188028872953804, 0.017137902552226802, 0.017087213546228733, 0.017035963963084176,
+        0.016984155929019893, 0.016931791588280228, 0.016878873103041758, 0.016825402653327125,
+        0.016771382436918246, 0.016716814669269543, 0.016661701583419855, 0.016606045429904445,
+        0.016549848476666112, 0.016493113008965655, 0.016435841329291913, 0.016378035757272,
+        0.016319698629579306, 0.01626083229984251, 0.01620143913855358, 0.01614152153297517,
+        0.016081081887047054, 0.01602012262129319, 0.015958646172727197, 0.0158966549947575,
+        0.015834151557092765, 0.015771138345645534, 0.015707617862436695, 0.0156435926254984,
+        0.01557906516877693, 0.015514038042035083, 0.015448513810754528, 0.015382495056036201,
+        0.015315984374501893, 0.015248984378194627, 0.015181497694478435, 0.0151135269659378,
+        0.01504507485027691, 0.014976144020217871, 0.014906737163399164, 0.014836856982273168,
+        0.014766506194003043, 0.01469568753035999, 0.014624403737619278, 0.014552657576456077,
+        0.014480451821840861, 0.014407789262934774, 0.014334672702983881, 0.01426110495921315,
+        0.01418708886272073, 0.014112627258370971, 0.014037723004687062, 0.01396237897374421,
+        0.01388659805106094, 0.013810383135491579, 0.01373373713911713, 0.013656662987136036,
+        0.013579163617755168, 0.013501241982079873, 0.013422901044003309, 0.013344143780096254,
+        0.013264973179496192, 0.01318539224379587, 0.013105403986931226, 0.013025011435069955,
+        0.012944217626498819, 0.012863025611510594, 0.012781438452291538, 0.012699459222807382,
+        0.01261709100869, 0.012534336907123082, 0.012451200026727451, 0.0123676834874468,
+        0.012283790420432282, 0.012199523967926906, 0.012114887283150374, 0.012029883530182386,
+        0.0119445158838469, 0.011858787529595178, 0.011772701663388707, 0.011686261491582377,
+        0.011599470230806807, 0.011512331107850252, 0.011424847359540871, 0.011337022232628405,
+        0.011248858983665488, 0.011160360878888375, 0.01107153119409851, 0.010982373214542677,
+        0.010892890234793125, 0.010803085558628396, 0.010712962498912291, 0.010622524377474254,
+        0.010531774524988394, 0.010440716280852486, 0.010349352993066857, 0.010257688018113997,
+        0.01016572472083562, 0.010073466474311695, 0.009980916659738694, 0.009888078666307098,
+        0.00979495589107882, 0.0097015517388653, 0.009607869622104002, 0.009513912960736174,
+        0.009419685182083434, 0.009325189720724136, 0.009230430018370516, 0.009135409523744803,
+        0.009040131692455123, 0.008944599986871669, 0.008848817876003326, 0.00875278883537211,
+        0.008656516346889437, 0.008560003898731726, 0.008463254985215442, 0.008366273106671917,
+        0.008269061769323145, 0.008171624485155786, 0.0080739647717967, 0.007976086152387346,
+        0.007877992155457847, 0.007779686314802222, 0.007681172169352325, 0.007582453263051854,
+        0.0074835331447306854, 0.007384415367979183, 0.007285103491021882, 0.007185601076591062,
+        0.007085911691801207, 0.006986038908022328, 0.006885986300753386, 0.006785757449496574,
+        0.006685355937629942, 0.006584785352281751, 0.006484049284203445, 0.006383151327642865,
+        0.006282095080217612, 0.006180884142789267, 0.006079522119335118, 0.005978012616822295,
+        0.005876359245081049, 0.005774565616677745, 0.005672635346787597, 0.005570572053068606,
+        0.005468379355534292, 0.005366060876426499, 0.005263620240089391, 0.005161061072841651,
+        0.0050583870028504275, 0.00495560166000418, 0.004852708675785661, 0.004749711683145209,
+        0.004646614316374859, 0.004543420210980151, 0.004440133003554319, 0.004336756331651734,
+        0.004233293833661082, 0.004129749148678432, 0.004026125916381597, 0.003922427776902742,
+        0.00381865837070281, 0.0037148213384448855, 0.0036109203208675583, 0.00350695895865954,
+        0.0034029408923331663, 0.003298869762098177, 0.0031947492077360217, 0.0030905828684743303,
+        0.00298637438286092, 0.002882127388637794, 0.0027778455226163917, 0.0026735324205518093,
+        0.0025691917170171104, 0.002464827045279072, 0.0023604420371720835, 0.0022560403229741487,
+        0.0021516255312816813, 0.0020472012888845, 0.0019427712206420537, 0.0018383389493586695,
+        0.0017339080956590884, 0.0016294822778646335, 0.0015250651118695648, 0.0014206602110170437,
+        0.0013162711859752067, 0.001211901644614463, 0.0011075551918838904, 0.0010032354296877913,
+        0.0008989459567636706, 0.0007946903685585428, 0.0006904722571072911, 0.0005862952109099782,
+        0.00048216281480947153, 0.0003780786498696534, 0.0002740462932545904, 0.00017006931810552073,
+        6.615129342045395e-05, -3.770421606677581e-05, -0.00014149365000760405, -0.00024521345255973067,
+        -0.0003488600725066302, -0.00045242996337870565, -0.0005559195835723291, -0.0006593253964698814,
+        -0.0007626438705595622, -0.000865871479553803, -0.0009690047025085712, -0.0010720400239422688,
+        -0.001174973933954017, -0.0012778029283409072, -0.0013805235087173565, -0.0014831321826318264,
+        -0.001585625463684, -0.0016879998716420725, -0.001790251932559835, -0.0018923781788922883,
+        -0.001994375149612764, -0.002096239390327925, -0.002197967453393708, -0.00229955589803092,
+        -0.0024010012904394497, -0.002502300203913286, -0.0026034492189550475, -0.002704444923389747,
+        -0.002805283912478201, -0.0029059627890306397, -0.0030064781635200594, -0.0031068266541941668,
+        -0.0032070048871880906, -0.003307009496636715, -0.003406837124785506, -0.0035064844221027644,
+        -0.0036059480473897036, -0.003705224667891401, -0.0038043109594074155, -0.0039032036064008888,
+        -0.004001899302108397, -0.004100394748649458, -0.004198686657134403, -0.004296771747773732,
+        -0.004394646749985233, -0.00449230840250193, -0.0045897534534796915, -0.004686978660603295,
+        -0.004783980791193223, -0.004880756622311885, -0.004977302940869038, -0.005073616543726759,
+        -0.005169694237804412, -0.005265532840183511, -0.005361129178210854, -0.005456480089602535,
+        -0.005551582422547319, -0.005646433035808625, -0.0057410287988276755, -0.00583536659182451,
+        -0.0059294433058997495, -0.006023255843135807, -0.006116801116697256, -0.00621007605093015,
+        -0.0063030775814629314, -0.0063958026553049135, -0.006488248230944783, -0.0065804112784491504,
+        -0.0066722887795606384, -0.006763877727794474, -0.0068551751285361945, -0.006946177999137276,
+        -0.007036883369011399, -0.00712728827973018, -0.007217389785117512, -0.007307184951344373,
+        -0.00739667085702298, -0.00748584459330021, -0.007574703263949854, -0.0076632439854664594,
+        -0.007751463887156488, -0.007839360111229864, -0.00792692981289107, -0.008014170160430056,
+        -0.008101078335311441, -0.008187651532264893, -0.008273886959373276, -0.00835978183816152,
+        -0.008445333403684772, -0.00853053890461525, -0.008615395603329398, -0.008699900775994435,
+        -0.00878405171265398, -0.008867845717313184, -0.008951280108023566, -0.009034352216967686,
+        -0.009117059390542068, -0.009199398989440702, -0.009281368388737946, -0.009362964977969755,
+        -0.009444186161216075, -0.009525029357180982, -0.009605491999273423, -0.009685571535687233,
+        -0.009765265429479869, -0.00984457115865135, -0.009923486216222546, -0.01000200811031261,
+        -0.010080134364215643, -0.010157862516477462, -0.010235190120971448, -0.010312114746973538,
+        -0.010388633979236941, -0.010464745418066643, -0.010540446679392135, -0.01061573539484119,
+        -0.010690609211811494, -0.010765065793542614, -0.01083910281918742, -0.01091271798388242,
+        -0.010985908998817212, -0.011058673591304802, -0.011131009504849718, -0.011202914499216243,
+        -0.011274386350495859, -0.01134542285117466, -0.011416021810199052, -0.011486181053041939,
+        -0.011555898421767496, -0.01162517177509573, -0.011693998988466698, -0.011762377954103164,
+        -0.011830306581073558, -0.011897782795354063, -0.011964804539889995, -0.012031369774655904,
+        -0.012097476476716777, -0.012163122640286898, -0.012228306276788545, -0.012293025414910601,
+        -0.01235727810066626, -0.012421062397449363, -0.012484376386091562, -0.012547218164917223,
+        -0.0126095858497989, -0.01267147757421184, -0.012732891489287364, -0.012793825763866186,
+        -0.012854278584550963, -0.012914248155758128, -0.012973732699768713, -0.01303273045677921,
+        -0.0130912396849515, -0.01314925866046177, -0.013206785677549134, -0.013263819048563934,
+        -0.013320357104014333, -0.01337639819261356, -0.013431940681325324, -0.013486982955409267,
+        -0.013541523418465788, -0.0135955604924798, -0.013649092617863663, -0.01370211825350049,
+        -0.013754635876785694, -0.013806643983668201, -0.013858141088691177, -0.013909125725032145,
+        -0.01395959644454196, -0.014009551817783496, -0.01405899043406979, -0.014107910901500915,
+        -0.014156311847000991, -0.014204191916353687, -0.014251549774237695, -0.014298384104261258,
+        -0.014344693608996182, -0.01439047701001039, -0.014435733047901425, -0.01448046048232757,
+        -0.014524658092039226, -0.014568324674909384, -0.014611459047963555, -0.01465406004740853,
+        -0.014696126528661356, -0.01473765736637651, -0.014778651454473324, -0.014819107706162364,
+        -0.014859025053970885, -0.014898402449768092, -0.014937238864789381, -0.01497553328965996,
+        -0.015013284734417766, -0.015050492228535769, -0.015087154820943678, -0.015123271580048623,
+        -0.015158841593755426, -0.015193863969486129, -0.01522833783419901, -0.015262262334406406,
+        -0.015295636636192225, -0.01532845992522891, -0.015360731406793456, -0.01539245030578256,
+        -0.015423615866727462, -0.015454227353808156, -0.015484284050866288, -0.015513785261418202,
+        -0.015542730308666547, -0.01557111853551163, -0.015598949304562076, -0.01562622199814463,
+        -0.015652936018313177, -0.01567909078685747, -0.015704685745310823, -0.015729720354957184,
+        -0.015754194096837593, -0.015778106471755905, -0.015801457000283903, -0.015824245222765446,
+        -0.015846470699320464, -0.015868133009847584, -0.015889231754026692, -0.015909766551320433,
+        -0.01592973704097503, -0.015949142882020734, -0.015967983753271242, -0.015986259353322436,
+        -0.01600396940055088, -0.016021113633111005, -0.016037691808932077, -0.016053703705714222,
+        -0.016069149120924015, -0.01608402787178913, -0.016098339795292366, -0.0161120847481652,
+        -0.01612526260688037, -0.016137873267643977, -0.01614991664638688, -0.016161392678755156,
+        -0.016172301320100464, -0.016182642545469066, -0.01619241634959065, -0.01620162274686613,
+        -0.01621026177135519, -0.016218333476762686, -0.016225837936424667, -0.01623277524329374,
+        -0.016239145509923638, -0.016244948868453107, -0.016250185470589137, -0.01625485548758975,
+        -0.01625895911024583, -0.016262496548862363, -0.016265468033239024, -0.016267873812650296,
+        -0.01626971415582451, -0.016270989350922662, -0.01627169970551624, -0.016271845546564658,
+        -0.01627142722039168, -0.0162704450926617, -0.016268899548354785, -0.016266790991741564,
+        -0.016264119846357127, -0.016260886554974454, -0.01625709157957712, -0.016252735401331292,
+        -0.016247818520557396, -0.01624234145670051, -0.01623630474830085, -0.016229708952963103,
+        -0.016222554647325298, -0.016214842427026952, -0.01620657290667674, -0.01619774671981934,
+        -0.01618836451890188, -0.016178426975239368, -0.016167934778979886, -0.016156888639068903,
+        -0.016145289283213137, -0.01613313745784364, -0.016120433928078236, -0.01610717947768357,
+        -0.016093374909036187, -0.016079021043083435, -0.01606411871930312, -0.016048668795663294,
+        -0.016032672148580798, -0.01601612967287957, -0.015999042281748088, -0.015981410906696487,
+        -0.015963236497512766, -0.0159445200222186, -0.015925262467024363, -0.01590546483628391,
+        -0.01588512815244834, -0.015864253456019183, -0.015842841805501377, -0.015820894277355474,
+        -0.015798411965948788, -0.01577539598350684, -0.01575184746006334, -0.015727767543410195,
+        -0.015703157399046694, -0.015678018210127955, -0.015652351177413096, -0.015626157519212796,
+        -0.015599438471335958, -0.015572195287036053, -0.015544429236957029, -0.01551614160907834,
+        -0.015487333708659567, -0.015458006858184374, -0.015428162397304213, -0.015397801682781037,
+        -0.015366926088429614, -0.015335537005059541, -0.015303635840416384, -0.015271224019122223,
+        -0.015238302982616114, -0.015204874189093344, -0.015170939113444774, -0.015136499247195246,
+        -0.015101556098441453, -0.015066111191789562, -0.015030166068292106, -0.014993722285384212,
+        -0.014956781416819484, -0.014919345052605488, -0.014881414798938506, -0.01484299227813753,
+        -0.01480407912857848, -0.014764677004627117, -0.014724787576571764, -0.014684412530555906,
+        -0.014643553568509288, -0.014602212408079785, -0.014560390782563663, -0.014518090440835863,
+        -0.014475313147279716, -0.014432060681716536, -0.014388334839333638, -0.014344137430613047,
+        -0.014299470281259283, -0.014254335232126408, -0.014208734139144828, -0.014162668873247826,
+        -0.01411614132029707, -0.014069153381008297, -0.01402170697087605, -0.013973804020097971,
+        -0.013925446473499045, -0.013876636290455003, -0.013827375444815205, -0.013777665924825355,
+        -0.013727509733049932, -0.01367690888629324, -0.013625865415521327, -0.013574381365782253,
+        -0.013522458796126824, -0.013470099779528451, -0.01341730640280248, -0.013364080766525208,
+        -0.013310424984953207, -0.013256341185940556, -0.013201831510857175, -0.013146898114506258,
+        -0.013091543165041134, -0.013035768843881575, -0.012979577345630452, -0.012922970877988943,
+        -0.012865951661672197, -0.012808521930324317, -0.012750683930432495, -0.012692439921241675,
+        -0.01263379217466807, -0.012574742975212393, -0.012515294619873015, -0.012455449418058703,
+        -0.012395209691500687, -0.012334577774164425, -0.01227355601216133, -0.012212146763659887,
+        -0.01215035239879602, -0.012088175299584189, -0.012025617859826705, -0.011962682485023971,
+        -0.011899371592283646, -0.01183568761022944, -0.011771632978910138, -0.011707210149707723,
+        -0.011642421585245285, -0.011577269759294661, -0.011511757156684045, -0.011445886273204814,
+        -0.011379659615517835, -0.011313079701060486, -0.011246149057952255, -0.011178870224900378,
+        -0.011111245751105732, -0.011043278196167319, -0.010974970129987606, -0.010906324132676897,
+        -0.010837342794457212, -0.0107680287155665, -0.010698384506162608, -0.01062841278622587,
+        -0.010558116185462613, -0.01048749734320806, -0.010416558908328719, -0.010345303539124343,
+        -0.010273733903230511, -0.010201852677519708, -0.01012966254800327, -0.01005716620973243,
+        -0.00998436636669903, -0.009911265731736608, -0.009837867026420765, -0.009764172980969044,
+        -0.009690186334141154, -0.009615909833139142, -0.009541346233506057, -0.00946649829902564,
+        -0.009391368801621659, -0.009315960521256491, -0.009240276245829444, -0.00916431877107586,
+        -0.009088090900464562, -0.009011595445096542, -0.008934835223602375, -0.008857813062039695,
+        -0.008780531793790996, -0.008702994259460695, -0.0086252033067719, -0.008547161790463494,
+        -0.008468872572186927, -0.008390338520402541, -0.008311562510275667, -0.008232547423573251,
+        -0.00815329614855958, -0.008073811579891892, -0.00799409661851656, -0.007914154171564033,
+        -0.007833987152244704, -0.0077535984797438996, -0.007672991079116804, -0.007592167881183428,
+        -0.007511131822424127, -0.0074298858448730715, -0.007348432896013513, -0.007266775928672271,
+        -0.007184917900913851, -0.007102861775934335, -0.0070206105219559915, -0.00693816711212105,
+        -0.006855534524385129, -0.006772715741411793, -0.006689713750465358, -0.006606531543305181,
+        -0.006523172116078787, -0.006439638469215129, -0.006355933607317944, -0.006272060539059686,
+        -0.006188022277073483, -0.006103821837846915, -0.006019462241615115, -0.005934946512253571,
+        -0.00585027767717063, -0.0057654587672011206, -0.005680492816498222, -0.005595382862427049,
+        -0.005510131945456874, -0.0054247431090535874, -0.00533921939957286, -0.005253563866152425,
+        -0.005167779560604407, -0.005081869537308089, -0.0049958368531025235, -0.004909684567178898,
+        -0.004823415740972634, -0.0047370334380564556, -0.004650540724032582, -0.004563940666424742,
+        -0.004477236334571447, -0.004390430799517563, -0.004303527133907454, -0.004216528411877204,
+        -0.0041294377089467005, -0.004042258101912808, -0.003954992668741562, -0.0038676444884604808,
+        -0.0037802166410513638, -0.0036927122073431157, -0.003605134268904144, -0.0035174859079347663,
+        -0.0034297702071605565, -0.0033419902497247808, -0.003254149119080951, -0.0031662498988864027,
+        -0.0030782956728944316, -0.002990289524848023, -0.0029022345383725823, -0.0028141337968689236,
+        -0.0027259903834065246, -0.002637807380617602, -0.0025495878705892247, -0.002461334934757474,
+        -0.0023730516538011366, -0.0022847411075351074, -0.0021964063748038283, -0.0021080505333758498,
+        -0.0020196766598369253, -0.0019312878294848066, -0.0018428871162230716, -0.001754477592455085,
+        -0.0016660623289790066, -0.001577644394882002, -0.001489226857434645, -0.001400812781985788,
+        -0.0013124052318582147, -0.0012240072682422546, -0.0011356219500922634, -0.0010472523340209381,
+        -0.0009589014741955454, -0.000870572422233193, -0.0007822682270963814, -0.0006939919349890169,
+        -0.0006057465892532378, -0.0005175352302643749, -0.0004293608953280971, -0.0003412266185770983,
+        -0.00025313543086763355, -0.00016509035967612464, -7.709442899701688e-05, 1.0849340760781745e-05,
+        9.873793287567348e-05, 0.00018656833441601958, 0.00027433753634258936, 0.00036204253360986,
+        0.0004496803252680676, 0.0005372479145649476, 0.0006247423090468418, 0.0007121605206595957,
+        0.0007994995658496585, 0.0008867564656650857, 0.0009739282458553427, 0.001061011936971861,
+        0.0011480045744683431, 0.0012349031987998027, 0.0013217048555229205, 0.0014084065953946256,
+        0.0014950054744714428, 0.00158149855420861, 0.001667882901558025, 0.0017541555890668518,
+        0.001840313694975783, 0.0019263543033166289, 0.002012274504009623, 0.002098071392960927,
+        0.002183742072159912, 0.002269283649775279, 0.0023546932402518143, 0.002439967964406884,
+        0.002525104949525617, 0.002610101329457302, 0.0026949542447100394, 0.0027796608425460527,
+        0.0028642182770766476, 0.0029486237093565583, 0.0030328743074773666, 0.003116967246662576,
+        0.0032008997093604503, 0.0032846688853371206, 0.0033682719717697124, 0.0034517061733392706,
+        0.0035349687023223954, 0.003618056778683586, 0.0037009676301670946, 0.003783698492387608,
+        0.003866246608922008, 0.003948609231399312, 0.004030783619591324, 0.004112767041502787,
+        0.004194556773460918, 0.004276150100204034, 0.0043575443149716725, 0.004438736719592482,
+        0.004519724624572392, 0.004600505349182682, 0.004681076221547825, 0.004761434578731931,
+        0.004841577766826405, 0.004921503141035618, 0.005001208065763321, 0.0050806899146986165,
+        0.005159946070900697, 0.005238973926884093, 0.0053177708847033985, 0.005396334356037289,
+        0.005474661762272099, 0.005552750534585432, 0.0056305981140294635, 0.005708201951612938,
+        0.005785559508383787, 0.005862668255511091, 0.005939525674366003, 0.006016129256603352,
+        0.006092476504241728, 0.0061685649297439, 0.006244392056096888, 0.006319955416890803,
+        0.006395252556398078, 0.006470281029652157, 0.006545038402525453, 0.006619522251806896,
+        0.006693730165279314, 0.006767659741796533, 0.00684130859135933, 0.006914674335191609,
+        0.006987754605816168, 0.007060547047129257, 0.007133049314475884, 0.007205259074723412,
+        0.007277174006335595, 0.007348791799446125, 0.007420110155931268, 0.007491126789481819,
+        0.0075618394256760394, 0.0076322458020505555, 0.007702343668171307, 0.007772130785704298,
+        0.007841604928486037, 0.007910763882592488, 0.00797960544640902, 0.008048127430698517,
+        0.008116327658669794, 0.008184203966045732, 0.008251754201130035, 0.008318976224874201,
+        0.008385867910944212, 0.008452427145786078, 0.008518651828690842, 0.008584539871860284,
+        0.008650089200470825, 0.008715297752737366, 0.008780163479976795, 0.008844684346671194,
+        0.008908858330529675, 0.008972683422551014, 0.009036157627084374, 0.009099278961890665,
+        0.009162045458202995, 0.009224455160786283, 0.009286506127996851, 0.009348196431841406,
+        0.00940952415803539, 0.009470487406060615, 0.00953108428922289, 0.009591312934708976,
+        0.009651171483642562, 0.009710658091140243, 0.009769770926367126, 0.00982850817259095,
+        0.00988686802723705, 0.009944848701941561, 0.010002448422604756, 0.0100596654294439,
+        0.010116497977045393, 0.01017294433441579, 0.010229002785033742, 0.010284671626900109,
+        0.010339949172587874, 0.010394833749291683, 0.010449323698877138, 0.010503417377928643,
+        0.010557113157797561, 0.010610409424649659, 0.010663304579511442, 0.010715797038316996,
+        0.010767885231952986, 0.010819567606304257, 0.010870842622298423, 0.010921708755949548,
+        0.010972164498401955, 0.011022208355973135, 0.011071838850195897, 0.011121054517860532,
+        0.01116985391105575, 0.011218235597209567, 0.011266198159129489, 0.011313740195042212,
+        0.011360860318632116, 0.011407557159080515, 0.011453829361103096, 0.011499675584987323,
+        0.011545094506629284, 0.01159008481757004, 0.011634645225031, 0.011678774451949375,
+        0.011722471237012345, 0.011765734334691262, 0.011808562515275029, 0.011850954564902809,
+        0.011892909285596238, 0.01193442549529142, 0.011975502027869745, 0.012016137733188294,
+        0.012056331477110275, 0.012096082141534133, 0.012135388624422233, 0.012174249839829317,
+        0.012212664717930153, 0.012250632205046443, 0.01228815126367364, 0.012325220872506589,
+        0.012361840026464949, 0.012398007736718196, 0.012433723030709446, 0.012468984952179385,
+        0.01250379256118914, 0.012538144934142861, 0.012572041163809467, 0.012605480359343911,
+        0.012638461646308186, 0.01267098416669107, 0.012703047078927873, 0.012734649557919519,
+        0.012765790795050611, 0.012796469998207572, 0.012826686391795562, 0.012856439216755342,
+        0.012885727730579178, 0.0129145512073265, 0.012942908937638307, 0.01297080022875211,
+        0.012998224404515236, 0.013025180805397978, 0.013051668788506195, 0.013077687727593375,
+        0.013103237013071806, 0.013128316052023425, 0.013152924268210152, 0.013177061102083333,
+        0.013200726010792857, 0.01322391846819554, 0.013246637964863098, 0.013268884008089229,
+        0.013290656121896659, 0.013311953847042673, 0.013332776741025211, 0.013353124378087585,
+        0.013372996349222675, 0.013392392262176886, 0.013411311741453382, 0.013429754428314414,
+        0.013447719980783692, 0.01346520807364752, 0.01348221839845579, 0.0134987506635223,
+        0.013514804593924358, 0.01353037993150186, 0.013545476434855899, 0.01356009387934675,
+        0.013574232057091006, 0.013587890776958635, 0.013601069864569071, 0.013613769162286815,
+        0.013625988529216503, 0.01363772784119743, 0.01364898699079735, 0.013659765887305924,
+        0.013670064456727261, 0.013679882641772343, 0.013689220401850343, 0.013698077713059925,
+        0.013706454568179371, 0.013714350976656752, 0.013721766964599069, 0.013728702574760972,
+        0.013735157866532939, 0.013741132915928916, 0.013746627815573287, 0.013751642674687278,
+        0.013756177619074885, 0.01376023279110827, 0.013763808349712385, 0.01376690447034917,
+        0.013769521345001179, 0.013771659182154748, 0.013773318206782367, 0.013774498660324604,
+        0.013775200800671569, 0.013775424902143651, 0.013775171255471797, 0.013774440167777268,
+        0.013773231962550666, 0.013771546979630607, 0.013769385575181719, 0.013766748121672162,
+        0.013763635007850474, 0.013760046638722073, 0.01375598343552493, 0.013751445835704968,
+        0.013746434292890802, 0.013740949276867788, 0.013734991273551874, 0.013728560784962469,
+        0.01372165832919518, 0.013714284440393743, 0.013706439668721542, 0.01369812458033247,
+        0.01368933975734143, 0.013680085797794122, 0.013670363315636463, 0.013660172940683207,
+        0.01364951531858647, 0.013638391110803238, 0.013626800994562725, 0.013614745662832944,
+        0.013602225824286975, 0.013589242203268457, 0.013575795539756885, 0.013561886589332047,
+        0.013547516123138087, 0.013532684927847212, 0.013517393805622557, 0.013501643574080792,
+        0.013485435066254142, 0.013468769130551755, 0.013451646630720938, 0.013434068445807378,
+        0.013416035470115268, 0.013397548613166681, 0.01337860879966069, 0.013359216969431563,
+        0.013339374077406978, 0.013319081093565457, 0.013298339002893175, 0.013277148805340611,
+        0.013255511515778397, 0.013233428163952884, 0.013210899794441242, 0.013187927466605704,
+        0.013164512254547891, 0.013140655247062233, 0.01311635754758897, 0.013091620274166887,
+        0.013066444559385352, 0.013040831550335903, 0.013014782408563536, 0.012988298310017271,
+        0.012961380445000456, 0.012934030018120584, 0.012906248248238356, 0.012878036368416687,
+        0.012849395625869196, 0.012820327281907778, 0.012790832611890294, 0.012760912905167517,
+        0.01273056946502972, 0.012699803608652572, 0.012668616667043076, 0.012637009984984631,
+        0.012604984920981582, 0.012572542847203985, 0.012539685149430942, 0.01250641322699452,
+        0.012472728492722539, 0.012438632372881012, 0.012404126307116427, 0.01236921174839764,
+        0.01233389016295677, 0.01229816303023019, 0.012262031842799226, 0.01222549810632985,
+        0.01218856333951233, 0.01215122907400067, 0.012113496854350978, 0.012075368237960177,
+        0.012036844795003856, 0.011997928108373699, 0.011958619773614771, 0.011918921398862274,
+        0.011878834604777826, 0.011838361024485345, 0.01179750230350684, 0.011756260099697467,
+        0.011714636083180179, 0.011672631936280327, 0.01163024935345964, 0.011587490041249702,
+        0.011544355718185434, 0.01150084811473781, 0.011456968973246471, 0.011412720047851863,
+        0.011368103104426857, 0.01132311992050839, 0.011277772285228431, 0.01123206199924451,
+        0.011185990874670213, 0.011139560735005155, 0.011092773415064679, 0.01104563076090879,
+        0.010998134629771492, 0.010950286889989207, 0.010902089420928874, 0.010853544112916173,
+        0.010804652867162753, 0.010755417595693746, 0.010705840221274594, 0.010655922677337523,
+        0.010605666907907752, 0.010555074867529931, 0.010504148521193055, 0.010452889844256122,
+        0.010401300822373193, 0.01034938345141797, 0.010297139737407963, 0.010244571696429,
+        0.010191681354558477, 0.01013847074778923, 0.01008494192195255, 0.010031096932640865,
+        0.009976937845130688, 0.009922466734304658, 0.009867685684573566, 0.009812596789797963,
+        0.009757202153210224, 0.009701503887334787, 0.009645504113909624, 0.009589204963806856,
+        0.009532608576953022, 0.009475717102248964, 0.009418532697490073, 0.00936105752928543,
+        0.009303293772977457, 0.009245243612560884, 0.009186909240601313, 0.009128292858154217,
+        0.009069396674683, 0.009010222907977032, 0.008950773784069523, 0.008891051537155342,
+        0.008831058409508223, 0.008770796651397756, 0.008710268521006622, 0.008649476284347155,
+        0.008588422215177612, 0.008527108594918894, 0.008465537712570033, 0.008403711864624566,
+        0.008341633354985856, 0.008279304494882492, 0.008216727602783474, 0.008153905004313804,
+        0.008090839032168435, 0.008027532026027387, 0.007963986332470308, 0.007900204304890558,
+        0.007836188303409077, 0.007771940694788673, 0.00770746385234764, 0.007642760155872944,
+        0.007577831991534225, 0.007512681751796334, 0.007447311835332892, 0.007381724646939077,
+        0.007315922597444, 0.007249908103623525, 0.007183683588113071, 0.007117251479319095,
+        0.007050614211331648, 0.006983774223836458, 0.00691673396202667, 0.0068494958765142324,
+        0.006782062423242073, 0.006714436063394874, 0.006646619263310957, 0.006578614494393243,
+        0.0065104242330200355, 0.006442050960456525, 0.006373497162765303, 0.006304765330717024,
+        0.006235857959701087, 0.0061667775496363295, 0.006097526604881391, 0.0060281076341446,
+        0.005958523150394829, 0.005888775670771299, 0.0058188677164934125, 0.005748801812771253,
+        0.005678580488714787, 0.005608206277244286, 0.005537681714999734, 0.005467009342250257,
+        0.005396191702804155, 0.005325231343918205, 0.0052541308162069155, 0.005182892673552051,
+        0.0051115194730121626, 0.005040013774731693, 0.004968378141849875, 0.004896615140410511,
+        0.004824727339270695, 0.0047527173100100776, 0.0046805876268396895, 0.004608340866511101,
+        0.00453597960822558, 0.00446350643354291, 0.004390923926289986, 0.004318234672470317,
+        0.004245441260172636, 0.0041725462794794665, 0.0040995523223766595, 0.004026461982661498,
+        0.003953277855852197, 0.003880002539096428, 0.0038066386310799767, 0.003733188731936025,
+        0.0036596554431538165, 0.003586041367487296, 0.0035123491088642008, 0.0034385812722950845,
+        0.0033647404637820815, 0.0032908292902275775, 0.0032168503593436757, 0.0031428062795609353,
+        0.003068699659937129, 0.002994533110066896, 0.0029203092399901626, 0.002846030660101849,
+        0.002771699981060819, 0.002697319813698906, 0.0026228927689302827, 0.0025484214576614282,
+        0.0024739084906994263, 0.0023993564786620393, 0.002324768031887312, 0.0022501457603429646,
+        0.0021754922735357737, 0.0021008101804219064, 0.0020261020893160083, 0.0019513706078016935,
+        0.001876618342641226, 0.0018018478996852988, 0.001727061883783686, 0.0016522628986951847,
+        0.0015774535469977682, 0.001502636429999059, 0.0014278141476474708, 0.0013529892984417435,
+        0.0012781644793422866, 0.0012033422856820854, 0.0011285253110774767, 0.0010537161473389066,
+        0.00097891738438275, 0.0009041316101418683, 0.0008293614104776662, 0.000754609369091354,
+        0.0006798780674353603, 0.0006051700846256635, 0.0005304879973534835, 0.00045583437979716346,
+        0.0003812118035345843, 0.0003066228374557106, 0.0002320700476749261, 0.0001575559974434306,
+        8.308324706260158e-05, 8.654353796701388e-06, -6.572812821425377e-05, -0.00014006164804056536,
+        -0.00021434365805010505, -0.0002885716139940516, -0.00036274297509335495, -0.00043685520412493635,
+        -0.0005109057675074391, -0.0005848921353862669, -0.0006588117817201385, -0.0007326621843657555,
+        -0.0008064408251628124, -0.0008801451900191028, -0.0009537727689954962, -0.0010273210563899297,
+        -0.001100787550821999, -0.001174169755317344, -0.0012474651773909465, -0.0013206713291315413,
+        -0.0013937857272844935, -0.0014668058933353465, -0.001539729353593066, -0.001612553639272792,
+        -0.0016852762865778566, -0.001757894836783225, -0.0018304068363170626, -0.0019028098368425905,
+        -0.001975101395339953, -0.0020472790741879734, -0.0021193404412447707, -0.0021912830699294403,
+        -0.0022631045393022068, -0.0023348024341451837, -0.002406374345042894, -0.002477817868461733,
+        -0.002549130606830017, -0.002620310168617608, -0.0026913541684149877, -0.0027622602270120675,
+        -0.0028330259714770554, -0.00290364903523515, -0.0029741270581462092, -0.003044457686582881,
+        -0.0031146385735085053, -0.0031846673785538597, -0.003254541768094878, -0.0033242594153288893,
+        -0.0033938180003513117, -0.003463215210232162, -0.003532448739091453, -0.003601516288175061,
+        -0.0036704155659302595, -0.0037391442880805744, -0.003807700177700332, -0.0038760809652892817,
+        -0.003944284388847004, -0.00401230819394617, -0.004080150133806381, -0.00414780796936755,
+        -0.004215279469362278, -0.004282562410389019, -0.0043496545769838415, -0.004416553761692518,
+        -0.004483257765142387, -0.004549764396113498, -0.004616071471609033, -0.00468217681692687,
+        -0.0047480782657293675, -0.004813773660113207, -0.004879260850679186, -0.004944537696601749,
+        -0.00500960206569739, -0.005074451834493866, -0.0051390848882979095, -0.0052034991212633495,
+        -0.005267692436458998, -0.005331662745935269, -0.005395407970791326, -0.005458926041241761,
+        -0.0055222148966826, -0.00558527248575657, -0.005648096766419458, -0.005710685706004608,
+        -0.005773037281287514, -0.005835149478550334, -0.0058970202936460105, -0.005958647732061412,
+        -0.00602002980898101, -0.006081164549349276, -0.006142049987933318, -0.006202684169385174,
+        -0.006263065148303073, -0.006323190989292906, -0.006383059767029362, -0.0064426695663162255,
+        -0.006502018482146417, -0.006561104619761932, -0.006619926094713288, -0.006678481032918179,
+        -0.006736767570720247, -0.006794783854947093, -0.006852528042968321, -0.006909998302752708,
+        -0.006967192812925016, -0.007024109762822706, -0.007080747352552266, -0.007137103793044574,
+        -0.007193177306110322, -0.007248966124495315, -0.007304468491934278, -0.007359682663205658,
+        -0.007414606904184672, -0.0074692394918969025, -0.007523578714571177, -0.007577622871691659,
+        -0.007631370274049992, -0.0076848192437970085, -0.0077379681144937006, -0.007790815231161806,
+        -0.007843358950334164, -0.007895597640104826, -0.007947529680178006, -0.007999153461917272,
+        -0.008050467388394243, -0.008101469874436308, -0.008152159346674714, -0.00820253424359136,
+        -0.008252593015565858, -0.008302334124921859, -0.00835175604597295, -0.008400857265067676,
+        -0.008449636280635275, -0.008498091603229741, -0.008546221755573999, -0.008594025272603523,
+        -0.008641500701509838, -0.008688646601782823, -0.008735461545253422, -0.008781944116135159,
+        -0.008828092911065632, -0.00887390653914767, -0.008919383621989399, -0.008964522793744475,
+        -0.009009322701151672, -0.009053782003574004, -0.009097899373036913, -0.0091416734942671,
+        -0.00918510306472979, -0.009228186794666, -0.009270923407129287, -0.009313311638022357,
+        -0.009355350236132367, -0.009397037963166772, -0.009438373593787838, -0.009479355915647228,
+        -0.009519983729420088, -0.00956025584883813, -0.009600171100722884, -0.009639728325018256,
+        -0.009678926374822502, -0.009717764116419666, -0.009756240429310727, -0.009794354206244436,
+        -0.009832104353247115, -0.009869489789652422, -0.009906509448130688, -0.009943162274717273,
+        -0.009979447228841138, -0.01001536328335225, -0.010050909424548884, -0.010086084652204692,
+        -0.010120887979594453, -0.010155318433520232, -0.01018937505433655, -0.010223056895975283,
+        -0.010256363025969802, -0.010289292525478912, -0.010321844489310318, -0.010354018025943245,
+        -0.010385812257550963, -0.010417226320022713, -0.010448259362984871, -0.010478910549822149,
+        -0.010509179057697738, -0.010539064077573301, -0.010568564814228557, -0.010597680486280096,
+        -0.010626410326199555, -0.010654753580332082, -0.010682709508913492, -0.010710277386087133,
+        -0.010737456499920571, -0.010764246152421495, -0.010790645659553112, -0.010816654351249238,
+        -0.010842271571428766, -0.010867496678009536, -0.010892329042922068, -0.010916768052122385,
+        -0.010940813105604484, -0.01096446361741262, -0.01098771901565256, -0.011010578742502589,
+        -0.011033042254224312, -0.011055109021172422, -0.011076778527804346, -0.011098050272689203,
+        -0.011118923768516545, -0.011139398542104115, -0.011159474134405713, -0.011179150100517938,
+        -0.011198426009686986, -0.01121730144531466, -0.011235776004963883, -0.011253849300363748,
+        -0.011271520957414214, -0.01128879061619016, -0.011305657930944877, -0.011322122570113197,
+        -0.011338184216314282, -0.011353842566353356, -0.011369097331223686, -0.011383948236107473,
+        -0.011398395020376581, -0.011412437437592649, -0.01142607525550666, -0.011439308256058166,
+        -0.011452136235373899, -0.011464559003766018, -0.011476576385729576, -0.011488188219939936,
+        -0.011499394359249347, -0.01151019467068308, -0.01152058903543525, -0.011530577348863988,
+        -0.011540159520486105, -0.011549335473971455, -0.01155810514713661, -0.011566468491938084,
+        -0.011574425474465234, -0.01158197607493248, -0.01158912028767102, -0.01159585812112032,
+        -0.011602189597818894, -0.01160811475439455, -0.011613633641554356, -0.011618746324074074,
+        -0.01162345288078693, -0.011627753404572118, -0.011631648002342733, -0.011635136795033219,
+        -0.011638219917586365, -0.011640897518939779, -0.01164316976201196, -0.011645036823687784,
+        -0.0116464988948037, -0.011647556180132107, -0.01164820889836573, -0.01164845728210112,
+        -0.011648301577821795, -0.0116477420458811, -0.011646778960484327, -0.011645412609670468,
+        -0.011643643295293602, -0.01164147133300362, -0.011638897052226628, -0.011635920796144908,
+        -0.011632542921676174, -0.01162876379945277, -0.011624583813799928, -0.011620003362713951,
+        -0.011615022857839798, -0.011609642724448165, -0.01160386340141206, -0.01159768534118316,
+        -0.01159110900976746, -0.011584134886700553, -0.011576763465022526, -0.011568995251252285,
+        -0.011560830765361494, -0.011552270540748073, -0.011543315124209187, -0.011533965075913883,
+        -0.011524220969375155, -0.011514083391421674, -0.011503552942169025, -0.01149263023499047,
+        -0.011481315896487333, -0.011469610566458914, -0.011457514897871949, -0.011445029556829644,
+        -0.011432155222540328, -0.011418892587285498, -0.011405242356387641, -0.011391205248177596,
+        -0.011376781993961206, -0.011361973337985946, -0.01134678003740684, -0.011331202862252088,
+        -0.011315242595388188, -0.011298900032484663, -0.011282175981978391, -0.011265071265037467,
+        -0.01124758671552467, -0.011229723179960557, -0.011211481517486025, -0.011192862599824596,
+        -0.011173867311244153, -0.011154496548518326, -0.011134751220887668, -0.011114632250019903,
+        -0.011094140569970339, -0.011073277127141524, -0.011052042880242701, -0.011030438800248591,
+        -0.01100846587035811, -0.010986125085952423, -0.010963417454552803, -0.010940343995777887,
+        -0.010916905741300673, -0.010893103734805181, -0.010868939031942568, -0.010844412700286899,
+        -0.010819525819290595, -0.010794279480239515, -0.010768674786207584, -0.010742712852010906,
+        -0.010716394804161943, -0.010689721780822727, -0.010662694931758109, -0.010635315418288672,
+        -0.010607584413242834, -0.010579503100909068, -0.01055107267698755, -0.010522294348541223,
+        -0.010493169333947056, -0.01046369886284637, -0.01043388417609505, -0.010403726525713409,
+        -0.010373227174835757, -0.010342387397659499, -0.010311208479393666, -0.010279691716207793,
+        -0.010247838415179612, -0.010215649894242902, -0.010183127482135006, -0.010150272518343606,
+        -0.010117086353053693, -0.010083570347093876, -0.0100497258718822, -0.010015554309372033,
+        -0.009981057051997576, -0.00994623550261851, -0.009911091074464966, -0.009875625191081896,
+        -0.009839839286273121, -0.009803734804044888, -0.009767313198549616, -0.009730575934028608,
+        -0.009693524484755217, -0.009656160334977106, -0.009618484978858326, -0.009580499920421452,
+        -0.009542206673488941, -0.009503606761624307, -0.009464701718073173, -0.009425493085704157,
+        -0.009385982416948668, -0.009346171273741351, -0.009306061227459833, -0.009265653858864083,
+        -0.00922495075803555, -0.00918395352431631, -0.009142663766247292, -0.009101083101506962,
+        -0.009059213156849322, -0.009017055568041327, -0.008974611979800953, -0.008931884045734069,
+        -0.008888873428271344, -0.008845581798605149, -0.008802010836625953, -0.008758162230858475,
+        -0.008714037678397412, -0.008669638884843397, -0.008624967564238252, -0.008580025438999923,
+        -0.00853481423985785, -0.008489335705787096, -0.008443591583943122, -0.008397583629595851,
+        -0.008351313606063358, -0.008304783284645905, -0.008257994444559246, -0.008210948872867633,
+        -0.008163648364417013, -0.008116094721767851, -0.008068289755127522, -0.008020235282282465,
+        -0.00797193312853061, -0.007923385126613067, -0.007874593116645588, -0.007825558946050427,
+        -0.007776284469487054, -0.00772677154878368, -0.007677022052867776, -0.00762703785769663,
+        -0.007576820846187795, -0.0075263729081496925, -0.007475695940210969, -0.007424791845750682,
+        -0.007373662534827988, -0.007322309924111516, -0.007270735936808391, -0.007218942502593746,
+        -0.007166931557539163, -0.0071147050440417475, -0.00706226491075257, -0.007009613112504796,
+        -0.006956751610242266, -0.006903682370947367, -0.006850407367568659, -0.006796928578949034,
+        -0.006743247989753014, -0.006689367590393995, -0.006635289376961861, -0.006581015351149665,
+        -0.006526547520180898, -0.006471887896736185, -0.00641703849887975, -0.0063620013499859915,
+        -0.00630677847866631, -0.006251371918694582, -0.006195783708933684, -0.0061400158932615485,
+        -0.006084070520496858, -0.006027949644324581, -0.005971655323222, -0.005915189620383732,
+        -0.005858554603647452, -0.005801752345418986, -0.005744784922597273, -0.005687654416499738,
+        -0.005630362912787032, -0.005572912501387698, -0.005515305276423064, -0.0054575433361319075,
+        -0.0053996287827949374, -0.005341563722658956, -0.0052833502658615845, -0.005224990526355404,
+        -0.0051664866218317715, -0.005107840673645543, -0.005049054806738369, -0.004990131149563237,
+        -0.004931071834008021, -0.0048718789953190985, -0.004812554772025473, -0.004753101305862226,
+        -0.0046935207416938995, -0.004633815227438242, -0.004573986913989757, -0.004514037955142987,
+        -0.00445397050751567, -0.004393786730472448, -0.004333488786047921, -0.004273078838869687,
+        -0.004212559056081939, -0.004151931607268115, -0.004091198664374426, -0.004030362401632754,
+        -0.003969424995483511, -0.003908388624498747, -0.003847255469305609, -0.003786027712508435,
+        -0.003724707538612243, -0.003663297133945641, -0.0036017986865836678, -0.0035402143862704235,
+        -0.00347854642434248, -0.003416796993651161, -0.0033549682884859568, -0.003293062504497141,
+        -0.0032310818386184635, -0.0031690284889904163, -0.003106904654882968, -0.00304471253661824,
+        -0.0029824543354935455, -0.0029201322537047935, -0.0028577484942685374, -0.0027953052609454862,
+        -0.0027328047581635286, -0.002670249190940592, -0.0026076407648074887, -0.002544981685731459,
+        -0.002482274160038703, -0.0024195203943380393, -0.002356722595443828, -0.0022938829702990084,
+        -0.00223100372589876, -0.002168087069213614, -0.002105135207112652, -0.002042150346287067,
+        -0.0019791346931737706, -0.0019160904538787582, -0.0018530198341004875, -0.001789925039053933,
+        -0.0017268082733941053, -0.001663671741139576, -0.0016005176455968584, -0.0015373481892837278,
+        -0.0014741655738537251, -0.001410972000020032, -0.0013477696674794374, -0.0012845607748366774,
+        -0.0012213475195292673, -0.0011581320977510122, -0.00109491670437704, -0.0010317035328884661,
+        -0.0009684947752969288, -0.0009052926220691659, -0.0008420992620523414, -0.0007789168823988289,
+        -0.0007157476684910744, -0.0006525938038673398, -0.0005894574701464156, -0.0005263408469535914,
+        -0.0004632461118459745, -0.0004001754402380084, -0.00033713100532734654, -0.0002741149780212912,
+        -0.00021112952686192903, -0.00014817681795283497, -8.525901488544691e-05, -2.2378278665353642e-05,
+        4.0463232361362155e-05, 0.00010326336258087921, 0.0001660199591853928, 0.00022873087224558537,
+        0.00029139395478371285, 0.0003540070628465379, 0.0004165680555774341, 0.00047907479528901657,
+        0.0005415251475355384, 0.0006039169811848338, 0.0006662481684900864, 0.0007285165851617564,
+        0.0007907201104393998, 0.0008528566271626482, 0.000914924021842694, 0.0009769201847336139,
+        0.0010388430099027449, 0.0011006903953020297, 0.0011624602428380378, 0.0012241504584425812,
+        0.0012857589521431104, 0.0013472836381322798, 0.0014087224348379823, 0.00147007326499312,
+        0.0015313340557048904, 0.0015925027385238603, 0.0016535772495131578, 0.001714555529317514,
+        0.0017754355232314782, 0.0018362151812680346, 0.001896892458227066, 0.001957465313762833,
+        0.0020179317124523538, 0.0020782896238625012, 0.0021385370226175617, 0.002198671888466552,
+        0.0022586922063500474, 0.002318595966466396, 0.0023783811643390846, 0.0024380458008824496,
+        0.002497587882467646, 0.002557005420988599, 0.002616296433927745, 0.002675458944420889,
+        0.0027344909813228242, 0.0027933905792717085, 0.0028521557787538655, 0.0029107846261683646,
+        0.0029692751738906818, 0.0030276254803368245, 0.003085833610027121, 0.0031438976336491584,
+        0.003201815628121102, 0.003259585676654786, 0.0033172058688177757, 0.0033746743005962677,
+        0.003431989074456693, 0.0034891482994076884, 0.0035461500910618183, 0.0036029925716967214,
+        0.003659673870315689, 0.0037161921227091507, 0.0037725454715147057, 0.0038287320662771795,
+        0.0038847500635086785, 0.003940597626748425, 0.00399627292662157, 0.004051774140898864,
+        0.004107099454554862, 0.004162247059826648, 0.004217215156272244, 0.004272001950828038,
+        0.00432660565786665, 0.004381024499254372, 0.004435256704408035, 0.0044893005103513765,
+        0.004543154161772123, 0.004596815911077757, 0.004650284018451155, 0.004703556751906286,
+        0.004756632387343562, 0.004809509208604298, 0.004862185507525776, 0.004914659583995068,
+        0.004966929746003166, 0.005018994309698832, 0.005070851599441469, 0.0051224999478543636,
+        0.005173937695877474, 0.0052251631928196315, 0.0052761747964104565, 0.005326970872852159,
+        0.005377549796871144, 0.005427909951768682, 0.005478049729471707, 0.005527967530583553,
+        0.005577661764433403, 0.005627130849126638, 0.005676373211593644, 0.005725387287639173,
+        0.005774171521991049, 0.00582272436834852, 0.005871044289429851, 0.0059191297570207165,
+        0.005966979252021086, 0.006014591264492144, 0.006061964293703055, 0.0061090968481774385,
+        0.0061559874457388815, 0.006202634613556772, 0.006249036888191631, 0.0062951928156396225,
+        0.006341100951377482, 0.006386759860406318, 0.0064321681172955485, 0.006477324306226421,
+        0.006522227021035089, 0.006566874865254905, 0.006611266452159409, 0.006655400404803977,
+        0.006699275356067287, 0.006742889948692776, 0.006786242835329624, 0.0068293326785728685,
+        0.006872158151004048, 0.0069147179352304685, 0.006957010723924822, 0.006999035219864431,
+        0.007040790135969373, 0.00708227419534111, 0.007123486131300334, 0.007164424687424418,
+        0.007205088617584422, 0.00724547668598195, 0.0072855876671856605, 0.007325420346166926,
+        0.007364973518335539, 0.007404245989575152, 0.00744323657627771, 0.007481944105378206,
+        0.007520367414388478, 0.007558505351430971, 0.007596356775272164, 0.0076339205553550595,
+        0.007671195571831972, 0.007708180715596559, 0.007744874888315486, 0.007781277002459633,
+        0.007817385981335063, 0.007853200759113724, 0.007888720280863177, 0.007923943502576584,
+        0.007958869391201984, 0.007993496924671108, 0.008027825091928033, 0.008061852892957109,
+        0.008095579338810777, 0.008129003451636967, 0.008162124264705925, 0.00819494082243647,
+        0.008227452180422529, 0.00825965740545862, 0.008291555575565027, 0.00832314578001278,
+        0.008354427119348242, 0.008385398705416955, 0.008416059661387509, 0.008446409121774589,
+        0.008476446232461911, 0.008506170150724796, 0.00853558004525187, 0.008564675096166865,
+        0.008593454495049906, 0.00862191744495818, 0.008650063160446137, 0.008677890867585785,
+        0.008705399803986064, 0.008732589218811971, 0.008759458372803241, 0.008786006538292831,
+        0.008812232999224578, 0.008838137051170872, 0.008863718001349585, 0.00888897516864074,
+        0.008913907883602856, 0.00893851548848857, 0.008962797337260086, 0.008986752795604354,
+        0.009010381240947355, 0.009033682062468375, 0.009056654661113675, 0.009079298449609953,
+        0.009101612852477025, 0.009123597306040348, 0.009145251258443286, 0.009166574169658423,
+        0.009187565511498998, 0.009208224767629607, 0.00922855143357661, 0.009248545016738122,
+        0.00926820503639358, 0.009287531023712589, 0.009306522521764062, 0.009325179085524226,
+        0.009343500281884498, 0.009361485689658987, 0.009379134899591515, 0.009396447514362218,
+        0.009413423148593713, 0.009430061428856891, 0.00944636199367624, 0.009462324493534815,
+        0.009477948590878683, 0.009493233960121064, 0.009508180287646042, 0.009522787271811698,
+        0.009537054622953036, 0.009550982063384415, 0.009564569327401478, 0.009577816161282813,
+        0.00959072232329104, 0.009603287583673558, 0.009615511724663009, 0.009627394540477074,
+        0.009638935837317831, 0.009650135433371227, 0.009660993158805331, 0.009671508855768837,
+        0.009681682378388757, 0.00969151359276795, 0.009701002376982053, 0.009710148621076114,
+        0.009718952227060654, 0.009727413108907594, 0.009735531192545503, 0.00974330641585453,
+        0.009750738728660962, 0.009757828092731314, 0.009764574481766047, 0.009770977881392806,
+        0.009777038289159387, 0.00978275571452613, 0.009788130178857973, 0.00979316171541614,
+        0.009797850369349404, 0.009802196197684855, 0.00980619926931833, 0.009809859665004533,
+        0.009813177477346528, 0.009816152810785005, 0.009818785781587068, 0.009821076517834646,
+        0.009823025159412488, 0.009824631857995753, 0.009825896777037183, 0.009826820091753953,
+        0.009827401989113969, 0.009827642667821915, 0.009827542338304901, 0.009827101222697502,
+        0.009826319554826693, 0.009825197580196144, 0.009823735555970358, 0.0098219337509581,
+        0.009819792445595747, 0.009817311931930053, 0.009814492513600587, 0.009811334505821693,
+        0.009807838235364335, 0.009804004040537082, 0.009799832271167158, 0.009795323288580855,
+        0.009790477465583657, 0.009785295186439854, 0.009779776846851962, 0.009773922853939583,
+        0.009767733626218, 0.00976120959357631, 0.00975435119725519, 0.009747158889824342,
+        0.009739633135159513, 0.009731774408419144, 0.009723583196020597, 0.009715059995616034,
+        0.009706205316068056, 0.009697019677424713, 0.009687503610894285, 0.009677657658819753,
+        0.009667482374652814, 0.009656978322927438, 0.00964614607923326, 0.00963498623018848,
+        0.009623499373412362, 0.009611686117497537, 0.009599547081981661, 0.00958708289731904,
+        0.009574294204851655, 0.009561181656779886, 0.009547745916132935, 0.009533987656738855,
+        0.009519907563194185, 0.009505506330833231, 0.009490784665697171, 0.00947574328450247,
+        0.009460382914609284, 0.009444704293989345, 0.009428708171193354, 0.009412395305318469,
+        0.009395766465974962, 0.009378822433252786, 0.009361563997687764, 0.009343991960227473,
+        0.009326107132196691, 0.009307910335262485, 0.009289402401399192, 0.00927058417285277,
+        0.009251456502104984, 0.009232020251837258, 0.0092122762948941, 0.009192225514246288,
+        0.009171868802953717, 0.00915120706412787, 0.009130241210893906, 0.009108972166352787,
+        0.009087400863542454, 0.009065528245399223, 0.009043355264718663, 0.009020882884116212,
+        0.008998112075987214, 0.0089750438224671, 0.008951679115390963, 0.008928018956252675,
+        0.00890406435616419, 0.008879816335813966, 0.008855275925425544, 0.008830444164715588,
+        0.008805322102851556, 0.008779910798409278, 0.008754211319330211, 0.008728224742878115,
+        0.008701952155595789, 0.008675394653261337, 0.008648553340844115, 0.008621429332460467,
+        0.008594023751329151, 0.00856633772972642, 0.008538372408940925, 0.008510128939228263,
+        0.008481608479765104, 0.008452812198603455, 0.008423741272624158, 0.008394396887490344,
+        0.008364780237600632, 0.008334892526042121, 0.008304734964542846, 0.008274308773424093,
+        0.008243615181552677, 0.008212655426292643, 0.008181430753456629, 0.008149942417257557,
+        0.008118191680259155, 0.008086179813327196, 0.008053908095579692, 0.008021377814337169,
+        0.007988590265072874, 0.00795554675136228, 0.007922248584832663, 0.007888697085112343,
+        0.007854893579779788, 0.007820839404312257, 0.007786535902034232, 0.007751984424065929,
+        0.007717186329271188, 0.007682142984205298, 0.007646855763062512, 0.007611326047623397,
+        0.007575555227202083, 0.0075395446985930545, 0.007503295866017596, 0.007466810141070587,
+        0.007430088942666458, 0.0073931336969851845, 0.0073559458374182, 0.007318526804513745,
+        0.007280878045922417, 0.0072430010163422025, 0.007204897177463267, 0.007166567997912754,
+        0.0071280149531995785, 0.007089239525658174, 0.007050243204392904, 0.0070110274852220236,
+        0.006971593870621277, 0.00693194386966725, 0.006892078997980994, 0.006852000777670949,
+        0.0068117107372757685, 0.006771210411707394, 0.006730501342193257, 0.006689585076218893,
+        0.0066484631674700815, 0.006607137175774768, 0.006565608667044845, 0.006523879213218322,
+        0.006481950392200105, 0.006439823787803673, 0.006397500989692335, 0.006354983593320105,
+        0.006312273199872343, 0.0062693714162067975, 0.00622627985479374, 0.00618300013365657,
+        0.00613953387631196, 0.006095882711709672, 0.006052048274172814, 0.006008032203337359,
+        0.005963836144091688, 0.005919461746516141, 0.005874910665822385, 0.005830184562292559,
+        0.005785285101218067, 0.005740213952838857, 0.005694972792281972, 0.005649563299500068,
+        0.005603987159210296, 0.005558246060832188, 0.005512341698426369, 0.005466275770632519,
+        0.005420049980607139, 0.005373666035961877, 0.0053271256487011105, 0.005280430535159485,
+        0.005233582415939605, 0.005186583015849574, 0.005139434063840279, 0.005092137292942376,
+        0.005044694440203852, 0.004997107246626865, 0.004949377457104472, 0.004901506820357946,
+        0.004853497088873032, 0.004805350018836998, 0.004757067370075092, 0.0047086509059868905,
+        0.004660102393482724, 0.004611423602920475, 0.004562616308041093, 0.00451368228590516,
+        0.004464623316829089, 0.004415441184320982, 0.0043661376750164325, 0.004316714578614818,
+        0.0042671736878146516, 0.004217516798249744, 0.004167745708424761, 0.004117862219650606,
+        0.004067868135980432, 0.004017765264144985, 0.003967555413487885, 0.003917240395901217,
+        0.003866822025761264, 0.0038163021198631296, 0.0037656824973564826, 0.0037149649796808255,
+        0.0036641513905006206, 0.0036132435556402746, 0.0035622433030197144, 0.003511152462588991,
+        0.00345997286626384, 0.0034087063478604996, 0.0033573547430305866, 0.0033059198891964986,
+        0.0032544036254861827, 0.003202807792668004, 0.0031511342330858335, 0.0030993847905941042,
+        0.0030475613104926453, 0.0029956656394613977, 0.002943699625495718, 0.002891665117841119,
+        0.0028395639669279897, 0.0027873980243069306, 0.0027351691425832043, 0.002682879175352089,
+        0.0026305299771336027, 0.0025781234033073483, 0.0025256613100474996, 0.002473145554258229,
+        0.0024205779935079423, 0.002367960485964699, 0.002315294890331318, 0.0022625830657802843,
+        0.0022098268718886583, 0.0021570281685735107, 0.0021041888160269174, 0.0020513106746508757,
+        0.001998395604992993, 0.0019454454676811881, 0.0018924621233594036, 0.0018394474326227444,
+        0.0017864032559527022, 0.001733331453652612, 0.001680233885783556, 0.0016271124120991116,
+        0.0015739688919813157, 0.0015208051843763754, 0.0014676231477301746, 0.0014144246399238197,
+        0.00136121151820986, 0.0013079856391476346, 0.0012547488585396226, 0.0012015030313672374,
+        0.0011482500117266725, 0.0010949916527654244, 0.0010417298066182795, 0.00098846632434347,
+        0.0009352030558591477, 0.0008819418498799381, 0.000828684553853334, 0.0007754330138960991,
+        0.0007221890747313393, 0.0006689545796250819, 0.0006157313703229569, 0.0005625212869875975,
+        0.0005093261681351905, 0.00045614785057307585, 0.00040298816933680734, 0.00034984895762731836,
+        0.0002967320467487644, 0.00024363926604589663, 0.00019057244284161195, 0.00013753340237486255,
+        8.452396773868434e-05, 3.1545959818079634e-05, -2.139880277204428e-05, -7.430850374824365e-05,
+        -0.00012718132922042523, -0.00018001546775322578, -0.00023280911042758713, -0.0002855604509018841,
+        -0.0003382676854729228, -0.0003909290131370649, -0.0004435426356512795, -0.0004961067575934902,
+        -0.0005486195864233519, -0.0006010793325428957, -0.0006534842093563904, -0.0007058324333310175,
+        -0.0007581222240564445, -0.0008103518043048941, -0.0008625194000910487, -0.0009146232407312528,
+        -0.0009666615589031129, -0.0010186325907048932, -0.001070534575714492, -0.0011223657570482647,
+        -0.0011741243814199405, -0.00122580869919942, -0.0012774169644708768, -0.001328947435091216,
+        -0.0013803983727483924, -0.0014317680430189425, -0.0014830547154262206, -0.0015342566634976127,
+        -0.0015853721648221182, -0.001636399501107736, -0.0016873369582384762, -0.0017381828263307978,
+        -0.0017889353997910787, -0.001839592977371667, -0.0018901538622271823, -0.001940616361970752,
+        -0.001990978788730163, -0.002041239459203183, -0.0020913966947135963, -0.002141448821266162,
+        -0.002191394169601947, -0.0022412310752534734, -0.0022909578785991333, -0.0023405729249179013,
+        -0.0023900745644438288, -0.002439461152420124, -0.0024887310491526625, -0.0025378826200644377,
+        -0.0025869142357486568, -0.00263582427202196, -0.0026846111099776515, -0.0027332731360387064,
+        -0.0027818087420100443, -0.0028302163251313684, -0.0028784942881290147, -0.0029266410392680578,
+        -0.0029746549924042423, -0.003022534567035188, -0.0030702781883517986, -0.003117884287289504,
+        -0.003165351300578908, -0.0032126776707963197, -0.003259861846414184, -0.0033069022818514038,
+        -0.003353797437522858, -0.0034005457798892192, -0.0034471457815065188, -0.0034935959210749125,
+        -0.0035398946834880223, -0.0035860405598812047, -0.003632032047680157, -0.003677867650649139,
+        -0.0037235458789389012, -0.003769065249133878, -0.00381442428430029, -0.0038596215140329184,
+        -0.0039046554745018913, -0.003949524708499478, -0.003994227765486567, -0.004038763201638483,
+        -0.0040831295798909985, -0.004127325469986025, -0.0041713494485166236, -0.004215200098972382,
+        -0.004258876011783915, -0.0043023757843674605, -0.004345698021169248, -0.004388841333709396,
+        -0.004431804340625227, -0.004474585667715319, -0.004517183947982253, -0.004559597821675419,
+        -0.004601825936333728, -0.004643866946827995, -0.004685719515402646, -0.004727382311717819,
+        -0.004768854012890457, -0.004810133303535645, -0.004851218875807574, -0.004892109429439858,
+        -0.004932803671785957, -0.004973300317859286, -0.005013598090372812, -0.005053695719778361,
+        -0.005093591944305869, -0.0051332855100022735, -0.005172775170769824, -0.005212059688404417,
+        -0.005251137832633667, -0.005290008381154214, -0.005328670119669383, -0.0053671218419259386,
+        -0.005405362349750883, -0.005443390453088061, -0.0054812049700339515, -0.005518804726873621,
+        -0.0055561885581162755, -0.005593355306530422, -0.005630303823178582, -0.005667032967451949,
+        -0.005703541607104829, -0.005739828618288236, -0.0057758928855836745, -0.005811733302036616,
+        -0.005847348769189117, -0.005882738197112882, -0.0059179005044413206, -0.005952834618401647,
+        -0.005987539474846804, -0.006022014018286686, -0.006056257201919004, -0.006090267987660512,
+        -0.006124045346177131, -0.006157588256914046, -0.00619089570812553, -0.006223966696904554,
+        -0.006256800229211594, -0.006289395319903802, -0.006321750992763126, -0.006353866280524659,
+        -0.006385740224904491, -0.006417371876627007, -0.006448760295452133, -0.006479904550202233,
+        -0.0065108037187885425, -0.006541456888237117, -0.006571863154715021, -0.006602021623555608,
+        -0.006631931409283598, -0.006661591635639951, -0.006691001435606536, -0.00672015995142993,
+        -0.006749066334645477, -0.0067777197461006584, -0.006806119355978152, -0.006834264343818763,
+        -0.006862153898543739, -0.0068897872184768925, -0.00691716351136641, -0.006944281994406319,
+        -0.006971141894257327, -0.006997742447067828, -0.00702408289849409, -0.007050162503720495,
+        -0.007075980527479032, -0.007101536244068723, -0.007126828937374782, -0.007151857900887071,
+        -0.007176622437718472, -0.0072011218606228816, -0.0072253554920129375, -0.007249322663977079,
+        -0.007273022718296585, -0.007296455006462264, -0.007319618889690427, -0.007342513738939052,
+        -0.00736513893492311, -0.0073874938681297705, -0.00740957793883342, -0.007431390557109924,
+        -0.007452931142850769, -0.007474199125777058, -0.007495193945452767, -0.007515915051297784,
+        -0.007536361902600756, -0.007556533968531464, -0.00757643072815272, -0.007596051670432154,
+        -0.007615396294253567, -0.007634464108427719, -0.007653254631703156, -0.007671767392776334,
+        -0.007690001930301546, -0.00770795779290055, -0.007725634539171729, -0.0077430317376988664,
+        -0.007760148967059764, -0.0077769858158343716, -0.007793541882612487, -0.007809816776001235,
+        -0.007825810114632204, -0.00784152152716813, -0.007856950652309269, -0.007872097138799451,
+        -0.007886960645431665, -0.007901540841053547, -0.007915837404572128, -0.00792985002495863,
+        -0.007943578401252592, -0.00795702224256592, -0.007970181268086183, -0.00798305520708013,
+        -0.00799564379889628, -0.00800794679296759, -0.008019963948813434, -0.008031695036041526,
+        -0.008043139834349258, -0.0080542981335249, -0.008065169733448195, -0.008075754444090784,
+        -0.008086052085516339, -0.008096062487880076, -0.008105785491428156, -0.008115220946496686,
+        -0.008124368713510364, -0.008133228662980647, -0.008141800675503708, -0.008150084641758138,
+        -0.008158080462501988, -0.008165788048569768, -0.008173207320868962, -0.008180338210376118,
+        -0.008187180658132816, -0.008193734615241068, -0.008200000042858433, -0.008205976912192877,
+        -0.008211665204497207, -0.008217064911063065, -0.008222176033214883, -0.008226998582303078,
+        -0.008231532579697262, -0.008235778056778852, -0.008239735054933582, -0.008243403625543369,
+        -0.008246783829978148, -0.008249875739587145, -0.00825267943568987, -0.008255195009566842,
+        -0.008257422562449867, -0.008259362205512025, -0.008261014059857326, -0.00826237825651005,
+        -0.008263454936403623, -0.008264244250369314, -0.008264746359124563, -0.008264961433260812,
+        -0.008264889653231255, -0.008264531209338087, -0.008263886301719387, -0.008262955140335808,
+        -0.008261737944956878, -0.00826023494514684, -0.008258446380250422, -0.008256372499378014,
+        -0.008254013561390643, -0.00825136983488463, -0.00824844159817594, -0.008245229139284018,
+        -0.008241732755915571, -0.008237952755447777, -0.008233889454911365, -0.008229543180973237,
+        -0.00822491426991884, -0.008220003067634115, -0.008214809929587301, -0.00820933522081024,
+        -0.008203579315879462, -0.008197542598896925, -0.008191225463470361, -0.008184628312693521,
+        -0.008177751559125795, -0.008170595624771782, -0.008163160941060439, -0.008155447948823846,
+        -0.008147457098275801, -0.008139188848989988, -0.00813064366987793, -0.00812182203916647,
+        -0.008112724444375169, -0.008103351382293192, -0.008093703358956044, -0.008083780889621884,
+        -0.008073584498747502, -0.00806311471996421, -0.008052372096053216, -0.008041357178920738,
+        -0.008030070529572872, -0.008018512718090131, -0.008006684323601673, -0.007994585934259205,
+        -0.007982218147210797, -0.007969581568573969, -0.007956676813408933, -0.007943504505691304,
+        -0.007930065278284559, -0.00791635977291221, -0.007902388640129696, -0.00788815253929599,
+        -0.007873652138544853, -0.007858888114756001, -0.007843861153525639, -0.007828571949137034,
+        -0.007813021204530745, -0.007797209631274335, -0.007781137949532133, -0.007764806888034529,
+        -0.007748217184046875, -0.00773136958333853, -0.007714264840151144, -0.007696903717166927,
+        -0.007679286985476664, -0.007661415424547342, -0.007643289822189526, -0.007624910974524576,
+        -0.007606279685951427, -0.007587396769113329, -0.007568263044864044, -0.007548879342233975,
+        -0.007529246498396003, -0.007509365358631042, -0.007489236776293269, -0.0074688616127751755,
+        -0.007448240737472515, -0.007427375027748503, -0.007406265368898326, -0.00738491265411314,
+        -0.007363317784443742, -0.007341481668764099, -0.007319405223734733, -0.007297089373765536,
+        -0.007274535050978741, -0.007251743195171366, -0.007228714753777399, -0.007205450681830075,
+        -0.007181951941923503, -0.007158219504174272, -0.007134254346182732, -0.007110057452994259,
+        -0.007085629817059972, -0.007060972438197343, -0.007036086323550707, -0.0070109724875513915,
+        -0.006985631951877554, -0.006960065745414089, -0.006934274904211937, -0.006908260471447485,
+        -0.006882023497381561, -0.006855565039318265, -0.006828886161563469, -0.006801987935383554,
+        -0.006774871438963152, -0.006747537757363346, -0.0067199879824794435, -0.006692223212998457,
+        -0.006664244554356384, -0.006636053118695479, -0.0066076500248210485, -0.0065790363981581826,
+        -0.006550213370708371, -0.006521182081005665, -0.006491943674072945, -0.0064624993013777665,
+        -0.006432850120788079, -0.0064029972965276355, -0.006372941999131759, -0.006342685405401934,
+        -0.006312228698361075, -0.00628157306720835, -0.006250719707273597, -0.0062196698199717595,
+        -0.00618842461275723, -0.006156985299077714, -0.006125353098328261, -0.006093529235804865,
+        -0.006061514942657885, -0.0060293114558456215, -0.005996920018087273, -0.005964341877815992,
+        -0.005931578289131685, -0.0058986305117537975, -0.00586549981097372, -0.0058321874576069905,
+        -0.005798694727945759, -0.005765022903710618, -0.005731173272002381, -0.0056971471252540255,
+        -0.005662945761181952, -0.005628570482737662, -0.005594022598058856, -0.005559303420420428,
+        -0.0055244142681856465, -0.0054893564647568276, -0.005454131338525939, -0.005418740222825205,
+        -0.005383184455877519, -0.005347465380746665, -0.005311584345287255, -0.005275542702094979,
+        -0.0052393418084563, -0.0052029830262980475, -0.005166467722137317, -0.00512979726703051,
+        -0.005092973036523066, -0.005055996410598455, -0.0050188687736272405, -0.004981591514316033,
+        -0.004944166025656675, -0.004906593704874445, -0.00486887595337699, -0.004831014176702883,
+        -0.004793009784469877, -0.004754864190323154, -0.004716578811883733, -0.004678155070696266,
+        -0.004639594392177255, -0.004600898205562836, -0.0045620679438564465, -0.00452310504377671,
+        -0.004484010945704936, -0.004444787093632503, -0.004405434935108289, -0.004365955921186368,
+        -0.004326351506372581, -0.004286623148572107, -0.004246772309036463, -0.004206800452310468,
+        -0.004166709046178955, -0.004126499561613913, -0.004086173472720864, -0.00404573225668584,
+        -0.004005177393721882, -0.0039645103670153566, -0.003923732662672738, -0.0038828457696668517,
+        -0.003841851179783077, -0.003800750387565728, -0.0037595448902643463, -0.003718236187779754,
+        -0.0036768257826099516, -0.0036353151797964968, -0.0035937058868702985, -0.0035519994137974097,
+        -0.0035101972729252424, -0.003468300978927988, -0.0034263120487527904, -0.003384232001565296,
+        -0.003342062358695205, -0.003299804643582264, -0.0032574603817217013, -0.003215031100609726,
+        -0.003172518329689179, -0.003129923600295168, -0.003087248445600417, -0.003044494400560634,
+        -0.0030016630018601807, -0.002958755787857403, -0.0029157742985298153, -0.0028727200754198414,
+        -0.0028295946615797675, -0.00278639960151741, -0.0027431364411412964, -0.0026998067277058816,
+        -0.00265641200975685, -0.0026129538370768166, -0.0025694337606299417, -0.0025258533325075816,
+        -0.0024822141058736345, -0.00243851763490966, -0.002394765474760032, -0.0023509591814775506,
+        -0.002307100311968326, -0.0022631904239374017, -0.0022192310758339, -0.002175223826796167,
+        -0.002131170236597403, -0.0020870718655908223, -0.002042930274654849, -0.0019987470251387825,
+        -0.0019545236788080364, -0.001910261797789365, -0.0018659629445166603, -0.0018216286816759967,
+        -0.0017772605721514529, -0.0017328601789704738, -0.0016884290652493118, -0.0016439687941386283,
+        -0.0015994809287694805, -0.0015549670321983347, -0.0015104286673531002, -0.001465867396978913,
+        -0.0014212847835837962, -0.0013766823893842893, -0.0013320617762516915, -0.001287424505657525,
+        -0.0012427721386198596, -0.0011981062356491556, -0.0011534283566941512, -0.001108740061088302,
+        -0.0010640429074957778, -0.0010193384538575827, -0.0009746282573379494, -0.0009299138742707951,
+        -0.0008851968601060222, -0.0008404787693558402, -0.0007957611555416283, -0.0007510455711403963,
+        -0.0007063335675313077, -0.0006616266949428157, -0.0006169265023990716, -0.000572234537667212,
+        -0.0005275523472041916, -0.0004828814761036937, -0.00043822346804360285, -0.0003935798652330907,
+        -0.0003489522083598248, -0.00030434203653749583, -0.00025975088725342134, -0.00021518029631603557,
+        -0.0001706317978024115, -0.00012610692400636615, -8.160720538617621e-05, -3.713417051239321e-05,
+        7.310653983705089e-06, 5.172574346236637e-05, 9.610957532670666e-05, 0.0001404606290744317,
+        0.0001847773863497182, 0.00022905833099402196, 0.0002733019490974734, 0.0003175067290506944,
+        0.000361671161595003, 0.00040579373987378994, 0.00044987295948344965, 0.0004939073185237723,
+        0.0005378953176486955, 0.0005818354601169214, 0.0006257262518419497, 0.000669566201442401,
+        0.0007133538202925206, 0.0007570876225713017, 0.0008007661253129486, 0.0008443878484566739,
+        0.000887951314895739, 0.0009314550505269921, 0.0009748975843007781, 0.0010182774482692656,
+        0.0010615931776358633, 0.0011048433108042084, 0.0011480263894265617, 0.00119114095845256,
+        0.0012341855661777998, 0.00127715876429181, 0.0013200591079262885, 0.00136288515570351,
+        0.0014056354697833077, 0.0014483086159114986, 0.0014909031634669765, 0.0015334176855095237,
+        0.0015758507588264669, 0.0016182009639798187, 0.0016604668853537558, 0.0017026471112005297,
+        0.0017447402336874124, 0.0017867448489431947, 0.0018286595571040743, 0.0018704829623598647,
+        0.0019122136730000156, 0.0019538503014590006, 0.001995391464361957, 0.0020368357825704154,
+        0.0020781818812266877, 0.002119428389799438, 0.0021605739421286115, 0.0022016171764695537,
+        0.002242556735537552, 0.002283391266552715, 0.0023241194212832274, 0.002364739856089651,
+        0.002405251231968705, 0.0024456522145965, 0.0024859414743719755, 0.002526117686460203,
+        0.0025661795308349854, 0.0026061256923217216, 0.002645954860640293, 0.0026856657304466696,
+        0.00272525700137559, 0.0027647273780826106, 0.002804075570285304, 0.002843300292805138,
+        0.002882400265608877, 0.002921374213849412, 0.002960220867906848, 0.0029989389634292895,
+        0.00303752724137311, 0.0030759844480433315, 0.00311430933513383, 0.0031525006597669276,
+        0.0031905571845331103, 0.00322847767753078, 0.0032662609124049537, 0.003303905668386299,
+        0.003341410730330489, 0.00337877488875601, 0.0034159969398828093, 0.0034530756856705346,
+        0.003490009933856074, 0.0035267984979913685, 0.0035634401974809563, 0.003599933857618892,
+        0.0036362783096258295, 0.003672472390685894, 0.003708514943982909, 0.0037444048187367128,
+        0.003780140870239561, 0.0038157219598913663, 0.0038511469552353814, 0.003886414729994041,
+        0.003921524164103317, 0.003956474143747935, 0.003991263561396041, 0.004025891315833314,
+        0.004060356312197196, 0.004094657462010889, 0.004128793683216757, 0.004162763900209756,
+        0.004196567043870916, 0.004230202051599536, 0.004263667867346369, 0.0042969634416460635,
+        0.00433008773164883, 0.004363039701152633, 0.004395818320634908, 0.004428422567283674,
+        0.004460851425028818, 0.004493103884573029, 0.004525178943422228, 0.00455707560591599,
+        0.004588792883257804, 0.004620329793544648, 0.004651685361796646, 0.004682858619986702,
+        0.004713848607068966, 0.004744654369008006, 0.004775274958807516, 0.004805709436538106,
+        0.0048359568693655515, 0.004866016331578676, 0.004895886904616515, 0.0049255676770956726,
+        0.0049550577448373824, 0.004984356210893889, 0.005013462185575011, 0.0050423747864743775,
+        0.005071093138495034, 0.0050996163738750875, 0.005127943632213308, 0.005156074060493741,
+        0.005184006813110581, 0.005211741051893023, 0.005239275946128976, 0.0052666106725892435,
+        0.005293744415551264, 0.005320676366822277, 0.005347405725762565, 0.005373931699308359,
+        0.005400253501994164, 0.0054263703559751745, 0.005452281491049349, 0.005477986144678898,
+        0.005503483562011805, 0.005528772995903174, 0.005553853706935773, 0.005578724963440631,
+        0.005603386041517751, 0.0056278362250556276, 0.005652074805751184, 0.005676101083129289,
+        0.005699914364561757, 0.0057235139652863, 0.005746899208425206, 0.005770069425003399,
+        0.00579302395396661, 0.005815762142199262, 0.005838283344541455, 0.005860586923806479,
+        0.0058826722507976815, 0.005904538704324801, 0.005926185671220368, 0.005947612546355769,
+        0.005968818732656783, 0.005989803641119088, 0.006010566690823426, 0.006031107308950281,
+        0.006051424930794471, 0.006071518999779509, 0.006091388967471353, 0.0061110342935921085,
+        0.006130454446033634, 0.006149648900870147, 0.006168617142371372, 0.006187358663014969,
+        0.00620587296349856, 0.0062241595527516794, 0.006242217947947528, 0.006260047674514048,
+        0.006277648266145029, 0.006295019264810898, 0.0063121602207689975, 0.006329070692573737,
+        0.006345750247086559, 0.006362198459485234, 0.00637841491327324, 0.006394399200288775,
+        0.006410150920713221, 0.006425669683079452, 0.0064409551042802415, 0.006456006809575557,
+        0.0064708244326003, 0.006485407615371428, 0.006499756008294696, 0.006513869270171348,
+        0.006527747068204361, 0.006541389078004332, 0.006554794983595226, 0.006567964477419839,
+        0.006580897260344646, 0.006593593041664775, 0.006606051539108487, 0.006618272478841293,
+        0.006630255595469773, 0.006642000632045462, 0.006653507340067834, 0.006664775479487444,
+        0.006675804818708705, 0.00668659513459215, 0.006697146212456646, 0.006707457846081225,
+        0.006717529837706531, 0.00672736199803606, 0.00673695414623727, 0.0067463061099419,
+        0.0067554177252465465, 0.0067642888367127185, 0.006772919297366427, 0.00678130896869777,
+        0.006789457720660086, 0.006797365431668761, 0.006805031988599865, 0.006812457286788423,
+        0.006819641230026336, 0.006826583730560173, 0.006833284709088562, 0.006839744094759198,
+        0.0068459618251657545, 0.006851937846344421, 0.006857672112770065, 0.0068631645873521675,
+        0.0068684152414306, 0.006873424054770853, 0.006878191015559133, 0.006882716120397189,
+        0.006886999374296766, 0.006891040790673803, 0.006894840391342384, 0.006898398206508321,
+        0.006901714274762464, 0.006904788643073838, 0.0069076213667823355, 0.00691021250959119,
+        0.006912562143559208, 0.006914670349092657, 0.006916537214936805, 0.006918162838167386,
+        0.006919547324181552, 0.00692069078668865, 0.006921593347700764, 0.0069222551375228,
+        0.006922676294742526, 0.006922856966220089, 0.00692279730707747, 0.006922497480687495,
+        0.006921957658662647, 0.006921178020843555, 0.006920158755287243, 0.006918900058255136,
+        0.006917402134200632, 0.006915665195756572, 0.006913689463722363, 0.006911475167050803,
+        0.006909022542834677, 0.006906331836293051, 0.006903403300757206, 0.006900237197656595,
+        0.0068968337965041124, 0.006893193374881396, 0.006889316218423775, 0.006885202620804892,
+        0.006880852883721139, 0.00687626731687573, 0.006871446237962625, 0.006866389972650063,
+        0.006861098854563938, 0.00685557322527081, 0.006849813434260681, 0.006843819838929652,
+        0.006837592804562023, 0.006831132704312368, 0.0068244399191873025, 0.006817514838026906,
+        0.006810357857486005, 0.006802969382015039, 0.00679534982384084, 0.006787499602947032,
+        0.006779419147054295, 0.006771108891600119, 0.006762569279718645, 0.006753800762220059,
+        0.006744803797569659, 0.0067355788518668296, 0.006726126398823781, 0.006716446919743778,
+        0.006706540903499474, 0.006696408846510722, 0.006686051252722231, 0.0066754686335810925,
+        0.006664661508013802, 0.006653630402403253, 0.0066423758505654365, 0.006630898393725917,
+        0.006619198580495819, 0.0066072769668480275, 0.006595134116092807, 0.00658277059885319,
+        0.006570186993040347, 0.006557383883828532, 0.006544361863629765, 0.006531121532068494,
+        0.006517663495955828, 0.006503988369263496, 0.006490096773097869, 0.006475989335673465,
+        0.006461666692286146, 0.006447129485286573, 0.006432378364052904, 0.006417413984963502,
+        0.0064022370113694434, 0.006386848113566726, 0.006371247968768246, 0.00635543726107563,
+        0.006339416681450815, 0.006323186927687332, 0.00630674870438146, 0.006290102722903216,
+        0.006273249701366843, 0.00625619036460154, 0.006238925444121603, 0.006221455678096378,
+        0.006203781811320301, 0.006185904595182402, 0.0061678247876357385, 0.006149543153166621,
+        0.0061310604627636545, 0.006112377493886431, 0.0060934950304342515, 0.006074413862714447,
+        0.006055134787410531, 0.006035658607550299, 0.006015986132473565, 0.005996118177799612,
+        0.005976055565394831, 0.005955799123339872, 0.005935349685896336, 0.005914708093474064,
+        0.0058938751925974135, 0.005872851835871784, 0.005851638881949887, 0.005830237195497769,
+        0.005808647647160704, 0.005786871113528757, 0.00576490847710248, 0.005742760626258068,
+        0.005720428455212476, 0.005697912863988505, 0.00567521475837952, 0.005652335049913827,
+        0.0056292746558195255, 0.005606034498988435, 0.005582615507940102, 0.0055590186167860075,
+        0.0055352447651930755, 0.005511294898347203, 0.005487169966916853, 0.005462870927016083,
+        0.0054383987401676525, 0.005413754373265986, 0.0053889387985398786, 0.005363952993514992,
+        0.005338797940976386, 0.005313474628930733, 0.005287984050568285, 0.00526232720422502,
+        0.005236505093344459, 0.005210518726438944, 0.005184369117051559, 0.005158057283717254,
+        0.005131584249923998, 0.005104951044073938, 0.005078158699444293, 0.005051208254147947,
+        0.00502410075109427, 0.004996837237949562, 0.0049694187670970506, 0.004941846395597708,
+        0.004914121185149826, 0.004886244202048923, 0.004858216517147877, 0.004830039205816461,
+        0.004801713347900549, 0.004773240027682, 0.004744620333837661, 0.004715855359398497,
+        0.004686946201708766, 0.004657893962384831, 0.004628699747273873, 0.004599364666412681,
+        0.004569889833986226, 0.00454027636828573, 0.004510525391667554, 0.004480638030510999,
+        0.004450615415176298, 0.004420458679962984, 0.004390168963067502, 0.0043597474065409135,
+        0.004329195156246744, 0.004298513361818441, 0.004267703176616688, 0.004236765757687,
+        0.004205702265716817, 0.004174513864992585, 0.004143201723356988, 0.004111767012165847,
+        0.0040802109062447476, 0.004048534583846149, 0.004016739226606063, 0.00398482601950016,
+        0.0039527961508008585, 0.00392065081203351, 0.003888391197932562, 0.003856018506398098,
+        0.0038235339384519004, 0.0037909386981933857, 0.003758233992755888, 0.0037254210322624696,
+        0.0036925010297816406, 0.0036594752012834345, 0.0036263447655950133, 0.0035931109443560457,
+        0.0035597749619747524, 0.0035263380455832845, 0.0034928014249927634, 0.003459166332649254,
+        0.0034254340035888026, 0.003391605675392603, 0.003357682588142465, 0.003323665984375805,
+        0.003289557109040659, 0.00325535720945097, 0.003221067535241439, 0.0031866893383222096,
+        0.003152223872834366, 0.0031176723951042893, 0.0030830361635983904, 0.003048316438878283,
+        0.0030135144835552533, 0.0029786315622447913, 0.0029436689415215087, 0.0029086278898735915,
+        0.0028735096776572365, 0.002838315577051416, 0.0028030468620122368, 0.0027677048082273064,
+        0.0027322906930703685, 0.002696805795555646, 0.002661251396291865, 0.002625628777437285,
+        0.0025899392226535287, 0.0025541840170597786, 0.0025183644471875456, 0.002482481800934629,
+        0.0024465373675192927, 0.0024105324374347316, 0.0023744683024031887, 0.0023383462553300313,
+        0.002302167590258196, 0.002265933602322245, 0.0022296455877024563, 0.0021933048435792105,
+        0.0021569126680871143, 0.0021204703602688488, 0.0020839792200298326, 0.0020474405480922957,
+        0.0020108556459489243, 0.001974225815817753, 0.0019375523605959983, 0.0019008365838141447,
+        0.001864079789590424, 0.001827283282584888, 0.0017904483679535002, 0.001753576351302637,
+        0.0017166685386432452, 0.00167972623634477, 0.0016427507510901288, 0.001605743389829516,
+        0.0015687054597346062, 0.0015316382681533826, 0.001494543122564398, 0.001457421330530674,
+        0.001420274199654806, 0.001383103037533094, 0.001345909151709943, 0.0013086938496326332,
+        0.0012714584386057696, 0.0012342042257457225, 0.0011969325179355416, 0.0011596446217795149,
+        0.0011223418435575409, 0.0010850254891805909, 0.0010476968641449566, 0.0010103572734870164,
+        0.000973008021738604, 0.0009356504128816295, 0.0008982857503029849, 0.0008609153367498366,
+        0.0008235404742845857, 0.0007861624642398757, 0.0007487826071740569, 0.0007114022028263115,
+        0.0006740225500718344, 0.0006366449468774721, 0.0005992706902570754, 0.0005619010762266813,
+        0.0005245373997605742, 0.00048718095474682796, 0.00044983303394246637, 0.0004124949289299474,
+        0.00037516793007264887, 0.00033785332647068625, 0.00030055240591717123, 0.0002632664548541371,
+        0.00022599675832854098, 0.0001887445999487516, 0.00015151126184071085, 0.0001142980246041755,
+        7.710616726944777e-05, 3.993696725382957e-05, 2.791700317938687e-06, -3.432835947708024e-05,
+        -7.142193981283993e-05, -0.00010848777015735261, -0.00014552458180732553, -0.0001825311079314393,
+        -0.0002195060836132627, -0.00025644824589371505, -0.0002933563338138313, -0.00033022908845743146,
+        -0.00036706525299328634, -0.00040386357271753856, -0.0004406227950962912, -0.00047734166980701773,
+        -0.0005140189487811165, -0.000550653386245915, -0.0005872437387659845, -0.0006237887652851423,
+        -0.00066028722716813, -0.0006967378882418102, -0.0007331395148366648, -0.0007694908758281666,
+        -0.0008057907426776332, -0.0008420378894733751, -0.0008782310929717124, -0.0009143691326374758,
+        -0.0009504507906847409, -0.0009864748521177, -0.0010224401047703592, -0.001058345339347337,
+        -0.0010941893494640833, -0.0011299709316864336, -0.0011656888855707777, -0.0012013420137039106,
+        -0.0012369291217424055, -0.0012724490184522017, -0.0013079005157481118, -0.001343282428732766,
+        -0.0013785935757358208, -0.001413832778353033, -0.0014489988614847878, -0.0014840906533748553,
+        -0.0015191069856491952, -0.0015540466933538638, -0.0015889086149933143, -0.0016236915925689987,
+        -0.001658394471616609, -0.0016930161012442065, -0.0017275553341699256, -0.0017620110267592155,
+        -0.0017963820390622912, -0.0018306672348514159, -0.0018648654816576962, -0.0018989756508080498,
+        -0.001932996617462059, -0.0019669272606482237, -0.0020007664633004928, -0.0020345131122947277,
+        -0.0020681660984843368, -0.0021017243167362667, -0.0021351866659672083, -0.0021685520491785473,
+        -0.00220181937349205, -0.0022349875501852332, -0.0022680554947261513, -0.0023010221268084277,
+        -0.002333886370386118, -0.002366647153708011, -0.0023993034093520867, -0.002431854074260057,
+        -0.0024642980897707596, -0.002496634401654481, -0.002528861960146736, -0.0025609797199812965,
+        -0.0025929866404238435, -0.0026248816853051316, -0.002656663823053749, -0.002688332026728998,
+        -0.0027198852740536027, -0.0027513225474458947, -0.0027826428340521334, -0.002813845125778675,
+        -0.0028449284193235993, -0.002875891716208408, -0.0029067340228098168, -0.0029374543503904575,
+        -0.0029680517151303826, -0.002998525138158034, -0.0030288736455805778, -0.0030590962685145924,
+        -0.0030891920431165417, -0.0031191600106126225, -0.0031489992173287826, -0.0031787087147205884,
+        -0.003208287559402499, -0.0032377348131773136, -0.00326704954306538, -0.003296230821333318,
+        -0.0033252777255228017, -0.003354189338479315, -0.0033829647483800784, -0.003411603048762217,
+        -0.0034401033385510938, -0.003468464722087412, -0.0034966863091550194, -0.0035247672150082116,
+        -0.003552706560398618, -0.0035805034716021368, -0.0036081570804457194, -0.003635666524333564,
+        -0.00366303094627341, -0.003690249494902832, -0.0037173213245144947, -0.0037442455950821144,
+        -0.003771021472285898, -0.003797648127537382, -0.0038241247380043867, -0.003850450486636162,
+        -0.003876624562187338, -0.003902646159242463, -0.00392851447824013, -0.003954228725496631,
+        -0.003979788113229632, -0.0040051918595817, -0.00403043918864326, -0.0040555293304756065,
+        -0.004080461521133899, -0.004105235002689133, -0.004129849023250809, -0.004154302836989021,
+        -0.004178595704155966, -0.004202726891107742, -0.0042266956703257705, -0.004250501320437747,
+        -0.004274143126238697, -0.0042976203787116534, -0.004320932375048024, -0.004344078418667846,
+        -0.004367057819239882, -0.0043898698927012005, -0.004412513961276767, -0.004434989353498959,
+        -0.004457295404226265, -0.00447943145466219, -0.004501396852374239, -0.00452319095131175,
+        -0.0045448131118243544, -0.00456626270067962, -0.004587539091081087, -0.004608641662685281,
+        -0.004629569801619067, -0.004650322900496665, -0.004670900358436224, -0.004691301581076316,
+        -0.0047115259805922795, -0.0047315729757120475, -0.004751441991731961, -0.004771132460532456,
+        -0.004790643820592951, -0.0048099755170071725, -0.004829127001498006, -0.00484809773243176,
+        -0.004866887174832646, -0.004885494800396903, -0.004903920087506457, -0.004922162521242616,
+        -0.004940221593399418, -0.00495809680249663, -0.004975787653792668, -0.004993293659297261,
+        -0.005010614337783664, -0.005027749214800855, -0.005044697822685554, -0.0050614597005736225,
+        -0.005078034394411444, -0.0050944214569673945, -0.005110620447842357, -0.0051266309334805395,
+        -0.0051424524871799444, -0.005158084689102357, -0.005173527126283365, -0.005188779392642089,
+        -0.005203841088990435, -0.0052187118230423435, -0.0052333912094228215, -0.00524787886967643,
+        -0.0052621744322758155, -0.005276277532629996, -0.005290187813092074, -0.00530390492296701,
+        -0.005317428518519276, -0.005330758262979719, -0.00534389382655267, -0.005356834886422721,
+        -0.005369581126761002, -0.005382132238731497, -0.0053944879204969945, -0.005406647877224745,
+        -0.0054186118210919195, -0.005430379471290962, -0.005441950554034281, -0.005453324802559211,
+        -0.005464501957132409, -0.005475481765054008, -0.005486263980661622, -0.005496848365334135,
+        -0.0055072346874950875, -0.005517422722615908, -0.005527412253218988, -0.005537203068880281,
+        -0.0055467949662318765, -0.005556187748964225, -0.005565381227828091, -0.005574375220636284,
+        -0.005583169552265267, -0.005591764054656234, -0.005600158566816202, -0.0056083529348188105,
+        -0.005616347011804708, -0.0056241406579818805, -0.005631733740625694, -0.005639126134078593,
+        -0.005646317719749615, -0.005653308386113765, -0.005660098028710945, -0.005666686550144725,
+        -0.005673073860081011, -0.005679259875246168, -0.005685244519425159, -0.005691027723459397,
+        -0.0056966094252441845, -0.005701989569726048, -0.005707168108899937, -0.0057121450018058845,
+        -0.005716920214525669, -0.005721493720179173, -0.005725865498920421, -0.005730035537933483,
+        -0.005734003831428074, -0.005737770380634884, -0.005741335193800763, -0.005744698286183617,
+        -0.005747859680046955, -0.005750819404654401, -0.005753577496263847, -0.005756133998121296,
+        -0.00575848896045463, -0.005760642440467061, -0.0057625945023302766, -0.005764345217177457,
+        -0.005765894663096021, -0.005767242925120048, -0.005768390095222627, -0.0057693362723078255,
+        -0.005770081562202493, -0.005770626077647792, -0.005770969938290536, -0.005771113270674273,
+        -0.005771056208230093, -0.0057707988912672785, -0.005770341466963654, -0.005769684089355788,
+        -0.005768826919328848, -0.005767770124606283, -0.005766513879739305, -0.005765058366096107,
+        -0.005763403771850841, -0.00576155029197236, -0.0057594981282128, -0.005757247489095821,
+        -0.005754798589904737, -0.005752151652670305, -0.005749306906158418, -0.005746264585857417,
+        -0.005743024933965339, -0.005739588199376802, -0.005735954637669732, -0.0057321245110918875,
+        -0.005728098088547114, -0.005723875645581376, -0.005719457464368606, -0.00571484383369632,
+        -0.005710035048950971, -0.005705031412103126, -0.005699833231692422, -0.005694440822812321,
+        -0.0056888545070945115, -0.005683074612693329, -0.005677101474269804, -0.005670935432975418,
+        -0.0056645768364358695, -0.005658026038734486, -0.005651283400395358, -0.005644349288366429,
+        -0.0056372240760022806, -0.0056299081430466235, -0.005622401875614798, -0.005614705666175857,
+        -0.005606819913534461, -0.005598745022812777, -0.005590481405431859, -0.005582029479093037,
+        -0.005573389667759066, -0.005564562401635037, -0.005555548117148958, -0.005546347256932469,
+        -0.005536960269801019, -0.005527387610733962, -0.005517629740854539, -0.005507687127409509,
+        -0.005497560243748651, -0.005487249569304123, -0.005476755589569555, -0.0054660787960788505,
+        -0.005455219686385071, -0.005444178764038858, -0.0054329565385666965, -0.005421553525449183,
+        -0.005409970246098882, -0.0053982072278380595, -0.005386265003876284, -0.005374144113287763,
+        -0.005361845100988516, -0.005349368517713386, -0.005336714919992844, -0.005323884870129527,
+        -0.005310878936174783, -0.005297697691904898, -0.005284341716797014, -0.0052708115960052,
+        -0.005257107920336114, -0.005243231286224356, -0.005229182295708025, -0.005214961556403791,
+        -0.005200569681481813, -0.005186007289640676, -0.005171275005081935, -0.005156373457484577,
+        -0.00514130328197935, -0.005126065119122861, -0.005110659614871465, -0.005095087420555122,
+        -0.005079349192850967, -0.0050634455937566495, -0.005047377290563757, -0.005031144955830875,
+        -0.00501474926735638, -0.004998190908151411, -0.004981470566412373, -0.004964588935493342,
+        -0.00494754671387846, -0.004930344605154042, -0.0049129833179804095, -0.004895463566063884,
+        -0.004877786068128373, -0.004859951547886717, -0.00484196073401234, -0.004823814360110226,
+        -0.0048055131646878935, -0.004787057891126509, -0.004768449287651493, -0.0047496881073030685,
+        -0.004730775107906835, -0.004711711052043955, -0.004692496707021307, -0.004673132844841568,
+        -0.004653620242173054, -0.004633959680319325, -0.004614151945188955, -0.0045941978272648985,
+        -0.004574098121573604, -0.004553853627654557, -0.00453346514952905, -0.004512933495668993,
+        -0.0044922594789659695, -0.004471443916699682, -0.00445048763050639, -0.004429391446347472,
+        -0.004408156194477506, -0.004386782709412402, -0.004365271829897467, -0.004343624398875207,
+        -0.00432184126345301, -0.004299923274870869, -0.004277871288468871, -0.004255686163654328,
+        -0.004233368763869364, -0.0042109199565579835, -0.004188340613132745, -0.0041656316089421245,
+        -0.004142793823237044, -0.004119828139137547, -0.0040967354435994625, -0.0040735166273808176,
+        -0.004050172585008088, -0.004026704214742579, -0.004003112418546495, -0.003979398102048788,
+        -0.003955562174511449, -0.003931605548795044, -0.003907529141324322, -0.0038833338720541595,
+        -0.0038590206644349705, -0.003834590445377801, -0.0038100441452201084, -0.0037853826976907293,
+        -0.0037606070398749775, -0.003735718112179841, -0.00371071685829884, -0.003685604225176778,
+        -0.0036603811629747083, -0.00363504862503453, -0.0036096075678433517, -0.0035840589509984646,
+        -0.003558403737171414, -0.0035326428920722766, -0.003506777384414233, -0.003480808185877567,
+        -0.0034547362710736535, -0.003428562617509221, -0.003402288205550159, -0.0033759140183853343,
+        -0.003349441041990513, -0.003322870265092075, -0.003296202679130519, -0.0032694392782242356,
+        -0.0032425810591329846, -0.0032156290212211366, -0.003188584166421351, -0.003161447499197881,
+        -0.003134220026509443, -0.0031069027577728986, -0.0030794967048261798, -0.0030520028818912335,
+        -0.0030244223055373256, -0.002996755994643816, -0.002969004970363028, -0.0029411702560832903,
+        -0.0029132528773916228, -0.002885253862036405, -0.002857174239890284, -0.0028290150429127396,
+        -0.002800777305112484, -0.002772462062510372, -0.0027440703531018615, -0.0027156032168190927,
+        -0.002687061695493841, -0.002658446832819667, -0.0026297596743141754, -0.0026010012672815597,
+        -0.0025721726607747623, -0.0025432749055576405, -0.0025143090540673653, -0.0024852761603765395,
+        -0.002456177280155086, -0.0024270134706328916, -0.00239778579056152, -0.002368495300176185,
+        -0.0023391430611581767, -0.002309730136596645, -0.0022802575909505016, -0.002250726490010643,
+        -0.0022211379008617487, -0.002191492891844143, -0.0021617925325159147, -0.002132037893614716,
+        -0.002102230047019523, -0.002072370065712754, -0.0020424590237420256, -0.0020124979961817463,
+        -0.0019824880590955055, -0.0019524302894974237, -0.001922325765314249, -0.0018921755653468798,
+        -0.0018619807692325401, -0.0018317424574065652, -0.0018014617110637496, -0.0017711396121207485,
+        -0.0017407772431776087, -0.0017103756874795144, -0.0016799360288788094, -0.0016494593517967391,
+        -0.0016189467411851523, -0.0015883992824885797, -0.0015578180616060066, -0.0015272041648524397,
+        -0.0014965586789213608, -0.0014658826908461864, -0.0014351772879620962, -0.001404443557868347,
+        -0.0013736825883899448, -0.0013428954675395494, -0.0013120832834796448, -0.0012812471244844292,
+        -0.0012503880789017383, -0.001219507235115286, -0.001188605681506628, -0.0011576845064171556,
+        -0.0011267447981104205, -0.0010957876447342232, -0.0010648141342824956, -0.0010338253545581005,
+        -0.0010028223931346373, -0.0009718063373186458, -0.0009407782741123306, -0.000909739290175649,
+        -0.0008786904717886333, -0.0008476329048140351, -0.0008165676746596979, -0.0007854958662409396,
+        -0.0007544185639433437, -0.0007233368515852502, -0.0006922518123802774, -0.0006611645289002518,
+        -0.000630076083037866, -0.0005989875559692128, -0.0005679000281170405, -0.0005368145791135661,
+        -0.0005057322877629801, -0.00047465423200504384, -0.00044358148887785293, -0.0004125151344808724,
+        -0.0003814562439383415, -0.0003504058913623886, -0.00031936514981622063, -0.0002883350912777006,
+        -0.000257316786602654, -0.00022631130548824666, -0.00019531971643675685, -0.00016434308671911814,
+        -0.0001333824823383447, -0.0001024389679936998, -7.15136070444458e-05, -4.0607461473294696e-05,
+        -9.721591850978507e-06, 2.114294270000423e-05, 5.198508454134075e-05, 8.280377755448202e-05,
+        0.0001135979671764772, 0.00014436660043573113, 0.00017510862598734322, 0.00020582299414866525,
+        0.00023650865693499766, 0.00026716456809431076, 0.000297789683142922, 0.0003283829594007117,
+        0.00035894335602577594, 0.00038946983404965094, 0.0004199613564122668, 0.0004504168879965115,
+        0.00048083539566304095, 0.0005112158482849899, 0.0005415572167822562, 0.0005718584741560284,
+        0.0006021185955232084, 0.0006323365581504118, 0.0006625113414881567, 0.0006926419272051805,
+        0.000722727299221769, 0.000752766443744014, 0.0007827583492975939, 0.000812702006760984,
+        0.0008425964093992187, 0.0008724405528973475, 0.0009022334353935168, 0.0009319740575122575,
+        0.0009616614223976476, 0.000991294535746079, 0.0010208724058391892, 0.001050394043576714,
+        0.001079858462508892, 0.0011092646788690322, 0.0011386117116061595, 0.0011678985824168876,
+        0.0011971243157776414, 0.0012262879389771239, 0.0012553884821476463, 0.0012844249782972144,
+        0.0013133964633412617, 0.0013423019761339835, 0.0013711405584998567, 0.0013999112552650454,
+        0.00142861311428836, 0.0014572451864923576, 0.001485806525894544, 0.0015142961896375828,
+        0.0015427132380203853, 0.0015710567345286798, 0.0015993257458650643, 0.0016275193419793227,
+        0.0016556365960989595, 0.0016836765847586365, 0.0017116383878302934, 0.0017395210885529288,
+        0.001767323773561954, 0.0017950455329187548, 0.0018226854601400499, 0.0018502426522268738,
+        0.0018777162096936387, 0.0019051052365972685, 0.0019324088405653851, 0.001959626132825301,
+        0.001986756228232504, 0.0020137982452985833, 0.0020407513062196026, 0.00206761453690416,
+        0.002094387067001018, 0.0021210680299269115, 0.00214765656289418, 0.0021741518069379588,
+        0.002200552906943515, 0.002226859011673433, 0.002253069273794341, 0.0022791828499037544,
+        0.002305198900556929, 0.0023311165902929454, 0.0023569350876611076, 0.0023826535652474463,
+        0.002408271199700206, 0.0024337871717559374, 0.0024592006662652066, 0.0024845108722179278,
+        0.0025097169827687812, 0.0025348181952625105, 0.0025598137112587335, 0.002584702736556888,
+        0.002609484481221011, 0.002634158159604098, 0.0026587229903724677, 0.0026831781965302027,
+        0.002707523005442829, 0.0027317566488611884, 0.0027558783629455406, 0.002779887388288521,
+        0.0028037829699387733, 0.0028275643574241444, 0.0028512308047745063, 0.002874781570544672,
+        0.0028982159178371153, 0.0029215331143242766, 0.002944732432270913, 0.002967813148556457,
+        0.0029907745446964853, 0.0030136159068648535, 0.003036336525915313, 0.003058935697402621,
+        0.0030814127216039827, 0.003103766903540138, 0.0031259975529960974, 0.0031481039845418653,
+        0.0031700855175530746, 0.003191941476231148, 0.0032136711896234974, 0.0032352739916435835,
+        0.003256749221090552, 0.003278096221668838, 0.0032993143420078064, 0.003320402935680578,
+        0.0033413613612233383, 0.0033621889821542827, 0.0033828851669919498, 0.003403449289273917,
+        0.003423880727575133, 0.003444178865525837, 0.0034643430918295864, 0.0034843728002810163,
+        0.003504267389783244, 0.003524026264365286, 0.003543648833199253, 0.0035631345106172047,
+        0.0035824827161279223, 0.0036016928744336857, 0.003620764415446357, 0.003639696774303643,
+        0.003658489391385379, 0.0036771417123289208, 0.0036956531880449612, 0.003714023274732976,
+        0.003732251433896327, 0.003750337132357291, 0.0037682798422720323, 0.0037860790411450313,
+        0.0038037342118435787, 0.003821244842612193, 0.0038386104270862996, 0.0038558304643063483,
+        0.0038729044587314343, 0.003889831920252616, 0.0039066123642061135, 0.003923245311386668,
+        0.003939730288060007, 0.003956066825975686, 0.003972254462379548, 0.003988292740025842,
+        0.004004181207189359, 0.004019919417677316, 0.004035506930840823, 0.004050943311586463,
+        0.004066228130387595, 0.004081360963295127, 0.004096341391948555, 0.004111169003586641,
+        0.004125843391057634, 0.0041403641528296046, 0.004154730893000544, 0.0041689432213080115,
+        0.004183000753138838, 0.004196903109538613, 0.004210649917220717, 0.004224240808575442,
+        0.004237675421678794, 0.0042509534003010085, 0.0042640743939149515, 0.004277038057704423,
+        0.0042898440525719955, 0.004302492045146745, 0.004314981707792067, 0.004327312718612767,
+        0.004339484761462323, 0.00435149752594993, 0.004363350707447044, 0.0043750440070941055,
+        0.004386577131806783, 0.004397949794282121, 0.004409161713004425, 0.004420212612251063,
+        0.0044311022220979, 0.004441830278424586, 0.004452396522919783, 0.0044628007030859075,
+        0.004473042572243797, 0.0044831218895374295, 0.004493038419937903, 0.004502791934247657,
+        0.004512382209104332, 0.00452180902698437, 0.004531072176206488, 0.004540171450934925,
+        0.004549106651182469, 0.00455787758281323, 0.004566484057545462, 0.004574925892953677,
+        0.0045832029124710585, 0.004591314945391482, 0.0045992618268712185, 0.004607043397930501,
+        0.004614659505455063, 0.004622110002197181, 0.004629394746776698, 0.004636513603681826,
+        0.004643466443269639, 0.004650253141766505, 0.0046568735812682035, 0.004663327649739881,
+        0.0046696152410157805, 0.004675736254798894, 0.004681690596660054, 0.0046874781780373385,
+        0.004693098916234864, 0.004698552734421496, 0.004703839561629406, 0.004708959332752409,
+        0.004713911988544039, 0.004718697475615456, 0.004723315746433216, 0.004727766759316704,
+        0.004732050478435455, 0.004736166873806321, 0.004740115921290259, 0.004743897602589097,
+        0.004747511905242072, 0.0047509588226220265, 0.004754238353931534, 0.004757350504198844,
+        0.004760295284273535, 0.004763072710821988, 0.004765682806322733, 0.004768125599061496,
+        0.004770401123126112, 0.0047725094184012246, 0.004774450530562803, 0.0047762245110723675,
+        0.004777831417171191, 0.0047792713118741436, 0.0047805442639633966, 0.004781650347981975,
+        0.004782589644227002, 0.004783362238742912, 0.004783968223314291, 0.004784407695458635,
+        0.00478468075841889, 0.0047847875211557865, 0.0047847280983399675, 0.004784502610343958,
+        0.004784111183233914, 0.004783553948761199, 0.0047828310443537225, 0.004781942613107168,
+        0.004780888803775932, 0.0047796697707639555, 0.004778285674115348, 0.004776736679504748,
+        0.004775022958227587, 0.004773144687190138, 0.004771102048899315, 0.004768895231452422,
+        0.0047665244285265305, 0.004763989839367814, 0.004761291668780665, 0.004758430127116573,
+        0.004755405430262868, 0.004752217799631249, 0.004748867462146191, 0.004745354650233048,
+        0.004741679601806093, 0.004737842560256323, 0.004733843774439056, 0.0047296834986613944,
+        0.004725361992669505, 0.004720879521635662, 0.004716236356145177, 0.004711432772183105,
+        0.004706469051120798, 0.004701345479702259, 0.004696062350030363, 0.004690619959552789,
+        0.004685018611047946, 0.0046792586126106, 0.004673340277637262, 0.004667263924811666,
+        0.004661029878089802, 0.0046546384666848375, 0.004648090025051992, 0.004641384892873112,
+        0.004634523415041101, 0.00462750594164423, 0.00462033282795023, 0.004613004434390162,
+        0.004605521126542302, 0.0045978832751156365, 0.004590091255933311, 0.0045821454499159605,
+        0.004574046243064704, 0.004565794026444139, 0.004557389196165105, 0.004548832153367306,
+        0.004540123304201661, 0.004531263059812683, 0.004522251836320559, 0.00451309005480308,
+        0.004503778141277484, 0.004494316526682071, 0.004484705646857626, 0.004474945942528844,
+        0.004465037859285439, 0.004454981847563084, 0.004444778362624401, 0.004434427864539562,
+        0.004423930818166788, 0.004413287693132869, 0.004402498963813366, 0.0043915651093125296,
+        0.004380486613443476, 0.004369263964707846, 0.004357897656275384, 0.004346388185963572,
+        0.0043347360562168554, 0.004322941774085857, 0.0043110058512064505, 0.004298928803778674,
+        0.004286711152545372, 0.004274353422770974, 0.0042618561442198995, 0.004249219851134773,
+        0.004236445082214794, 0.004223532380593728, 0.004210482293817703, 0.004197295373823124,
+        0.004183972176914278, 0.0041705132637407365, 0.004156919199274843, 0.004143190552788878,
+        0.0041293278978321215, 0.00411533181220788, 0.004101202877950318, 0.004086941681300994,
+        0.004072548812685719, 0.0040580248666907594, 0.004043370442039175, 0.0040285861415670825,
+        0.004013672572199715, 0.003998630344927217, 0.003983460074780617, 0.0039681623808074315,
+        0.003952737886047156, 0.0039371872175068415, 0.003921511006136332, 0.0039057098868034213,
+        0.0038897844982690305, 0.0038737354831621246, 0.003857563487954449, 0.00384126916293541,
+        0.0038248531621867315, 0.0038083161435566244, 0.0037916587686345643, 0.003774881702725335,
+        0.0037579856148232003, 0.003740971177586059, 0.003723839067309297, 0.003706589963899609,
+        0.003689224550848806, 0.0036717435152073597, 0.0036541475475579187, 0.0036364373419887613,
+        0.003618613596067114, 0.0036006770108121893, 0.003582628290668521, 0.003564468143478904,
+        0.003546197280457083, 0.003527816416160854, 0.0035093262684646152, 0.0034907275585319597,
+        0.0034720210107882688, 0.003453207352893112, 0.0034342873157124833, 0.0034152616332911835,
+        0.003396131042824906, 0.003376896284632139, 0.003357558102126441, 0.0033381172417881,
+        0.0033185744531358534, 0.003298930488698834, 0.003279186103988066, 0.0032593420574679025,
+        0.003239399110527661, 0.003219358027452897, 0.003199219575396655, 0.0031789845243507925,
+        0.0031586536471170453, 0.0031382277192780433, 0.0031177075191683866, 0.00309709382784553,
+        0.0030763874290604225, 0.0030555891092286245, 0.003034699657400737, 0.0030137198652329463,
+        0.002992650526957825, 0.002971492439354675, 0.00295024640171982, 0.0029289132158371767,
+        0.002907493685948391, 0.0028859886187229855, 0.002864398823228692, 0.002842725110901414,
+        0.00282096829551521, 0.0027991291931523817, 0.0027772086221733447, 0.002755207403186302,
+        0.0027331263590172944, 0.002710966314679887, 0.00268872809734457, 0.002666412536308748,
+        0.002644020462966144, 0.0026215527107762548, 0.0025990101152339818, 0.002576393513838966,
+        0.0025537037460648974, 0.0025309416533289965, 0.002508108078961209, 0.0024852038681733683,
+        0.002462229868028545, 0.002439186927410159, 0.002416075896990873, 0.0023928976292019854,
+        0.0023696529782023883, 0.002346342799847235, 0.0023229679516573467, 0.0022995292927878875,
+        0.002276027683997214, 0.0022524639876158755, 0.002228839067515365, 0.002205153789076811,
+        0.002181409019159898, 0.002157605626071548, 0.002133744479534392, 0.002109826450655885,
+        0.0020858524118966145, 0.0020618232370388824, 0.0020377398011555805, 0.0020136029805786076,
+        0.001989413652867337, 0.0019651726967773258, 0.0019408809922287537, 0.0019165394202748248,
+        0.0018921488630704183, 0.0018677102038404754, 0.0018432243268483523, 0.0018186921173644233,
+        0.0017941144616344718, 0.00176949224684783, 0.0017448263611062808, 0.0017201176933920818,
+        0.0016953671335362944, 0.0016705755721874768, 0.0016457439007798661, 0.0016208730115016676,
+        0.001595963797263605, 0.0015710171516671912, 0.001546033968972988, 0.001521015144069165,
+        0.0014959615724397409, 0.0014708741501328819, 0.001445753773729432, 0.0014206013403112124,
+        0.001395417747429216, 0.0013702038930722765, 0.001344960675635437, 0.0013196889938879536,
+        0.0012943897469421986, 0.001269063834221833, 0.0012437121554301695, 0.001218335610518808,
+        0.00119293509965598, 0.0011675115231949461, 0.0011420657816426565, 0.0011165987756281992,
+        0.0010911114058710643, 0.0010656045731501933, 0.001040079178272161, 0.0010145361220397055,
+        0.0009889763052206375, 0.000963400628516409, 0.0009378099925303987, 0.0009122052977370963,
+        0.0008865874444505454, 0.0008609573327930381, 0.0008353158626640459, 0.000809663933708945,
+        0.0007840024452877272, 0.0007583322964440632, 0.0007326543858741269, 0.0007069696118952726,
+        0.0006812788724154956, 0.0006555830649020619, 0.0006298830863504854, 0.0006041798332539348,
+        0.000578474201572144, 0.0005527670867004986, 0.0005270593834394299, 0.0005013519859635498,
+        0.0004756457877908558, 0.0004499416817522415, 0.00042424055996078097, 0.00039854331378105936,
+        0.00037285083379883286, 0.0003471640097904833, 0.0003214837306923775, 0.0002958108845708255,
+        0.0002701463585916985, 0.00024449103898978135, 0.00021884581103905044, 0.00019321155902226494,
+        0.00016758916620079865, 0.00014197951478477563, 0.00011638348590298832, 9.080195957287256e-05,
+        6.523581467082281e-05, 3.96859289022898e-05, 1.4153178771941013e-05, -1.136156044583798e-05,
+        -3.6857414736624814e-05, -6.233351137572971e-05, -8.778897895734495e-05, -0.00011322294742402627,
+        -0.00013863454809641545, -0.0001640229137020295, -0.00018938717840472096, -0.00021472647783388,
+        -0.00024003994911331983, -0.00026532673089036513, -0.00029058596336486515, -0.00031581678831785973,
+        -0.000341018349140408, -0.0003661897908625303, -0.0003913302601813334, -0.0004164389054899176,
+        -0.0004415148769058908, -0.0004665573262994131, -0.0004915654073217061, -0.0005165382754333232,
+        -0.0005414750879321004, -0.0005663750039812891, -0.0005912371846376074, -0.000616060792878935,
+        -0.0006408449936321838, -0.0006655889538010888, -0.0006902918422936414, -0.0007149528300496598,
+        -0.0007395710900684641, -0.000764145797435733, -0.0007886761293511002, -0.0008131612651553744,
+        -0.0008376003863572644, -0.0008619926766605366, -0.0008863373219909643, -0.0009106335105228923,
+        -0.0009348804327060133, -0.0009590772812920185, -0.0009832232513608962, -0.0010073175403473938,
+        -0.0010313593480673713, -0.0010553478767437983, -0.001079282331032873, -0.0011031619180501932,
+        -0.001126985847396293, -0.001150753331182445, -0.0011744635840566647, -0.0011981158232287732,
+        -0.001221709268496098, -0.0012452431422687064, -0.0012687166695947267, -0.0012921290781852854,
+        -0.0013154795984395764, -0.001338767463469832, -0.0013619919091259188, -0.0013851521740200642,
+        -0.0014082474995515005, -0.001431277129930694, -0.0014542403122037306, -0.0014771362962767215,
+        -0.001499964334939427, -0.001522723683889552, -0.0015454136017566387, -0.0015680333501254793,
+        -0.0015905821935598954, -0.001613059399626287, -0.0016354642389168111, -0.0016577959850726967,
+        -0.0016800539148074368, -0.0017022373079296057, -0.0017243454473658089, -0.001746377619183489,
+        -0.001768333112613377, -0.001790211220072027, -0.0018120112371843595, -0.0018337324628055938,
+        -0.0018553741990433878, -0.0018769357512801398, -0.0018984164281943942, -0.0019198155417827656,
+        -0.001941132407381546, -0.001962366343688018, -0.0019835166727818305, -0.002004582720146246,
+        -0.0020255638146890343, -0.002046459288763445, -0.002067268478189149, -0.002087990722272514,
+        -0.0021086253638273648, -0.0021291717491954175, -0.0021496292282662537, -0.002169997154497448,
+        -0.0021902748849348275, -0.002210461780231851, -0.0022305572046695116, -0.0022505605261758884,
+        -0.0022704711163453874, -0.0022902883504580616, -0.0023100116074987772, -0.002329640270176031,
+        -0.0023491737249408105, -0.002368611362005448, -0.002387952575361774, -0.002407196762799759,
+        -0.002426343325925833, -0.002445391670180653, -0.0024643412048572727, -0.002483191343118929,
+        -0.0025019415020165682, -0.002520591102506413, -0.0025391395694673666, -0.0025575863317180833,
+        -0.00257593082203407, -0.0025941724771646555, -0.002612310737849619, -0.00263034504883579,
+        -0.0026482748588937117, -0.0026660996208335273, -0.00268381879152143, -0.0027014318318956594,
+        -0.002718938206982107, -0.002736337385910116, -0.002753628841928083, -0.0027708120524186732,
+        -0.002787886498914085, -0.0028048516671111954, -0.002821707046886315, -0.0028384521323099776,
+        -0.002855086421661627, -0.00287160941744387, -0.00288802062639683, -0.002904319559512375,
+        -0.0029205057320478432, -0.0029365786635399036, -0.0029525378778183934, -0.0029683829030195093,
+        -0.0029841132715992358, -0.002999728520346588, -0.003015228190396442, -0.003030611827242449,
+        -0.0030458789807497628, -0.00306102920516742, -0.003076062059140732, -0.003090977105723611,
+        -0.0031057739123902953, -0.0031204520510474468, -0.003135011098045851, -0.003149450634191777,
+        -0.003163770244758401, -0.003177969519497182, -0.00319204805264865, -0.0032060054429534158,
+        -0.0032198412936628824, -0.003233555212549738, -0.0032471468119183336, -0.0032606157086149706,
+        -0.003273961524037848, -0.003287183884146955, -0.003300282419473946, -0.003313256765131388,
+        -0.0033261065608223748, -0.0033388314508497417, -0.0033514310841249364, -0.003363905114177034,
+        -0.0033762531991614517, -0.003388475001868395, -0.0034005701897312957, -0.0034125384348350734,
+        -0.0034243794139240348, -0.0034360928084098447, -0.00344767830437922, -0.003459135592601428,
+        -0.0034704643685356056, -0.003481664332338145, -0.003492735188869478, -0.0035036766477009834,
+        -0.0035144884231218935, -0.0035251702341454983, -0.0035357218045156684, -0.0035461428627130407,
+        -0.0035564331419609355, -0.00356659238023124, -0.0035766203202501276, -0.0035865167095034227,
+        -0.003596281300242057, -0.0036059138494871725, -0.003615414119035089, -0.0036247818754621304,
+        -0.0036340168901293434, -0.0036431189391868475, -0.003652087803578167, -0.0036609232690445184,
+        -0.003669625126128548, -0.0036781931701782393, -0.003686627201350598, -0.0036949270246149574,
+        -0.00370309244975634, -0.0037111232913786298, -0.003719019368907363, -0.0037267805065926024,
+        -0.0037344065335115726, -0.0037418972835709407, -0.003749252595509212, -0.0037564723128987927,
+        -0.00376355628414785, -0.0037705043625020805, -0.0037773164060463673, -0.0037839922777060555,
+        -0.0037905318452482913, -0.0037969349812830627, -0.003803201563264069, -0.0038093314734894807,
+        -0.003815324599102492, -0.0038211808320916902, -0.0038269000692912904, -0.003832482212381239,
+        -0.003837927167886933, -0.003843234847179126, -0.0038484051664733664, -0.003853438046829378,
+        -0.0038583334141502595, -0.0038630911991815787, -0.0038677113375101724, -0.003872193769562859,
+        -0.003876538440605043, -0.003880745300738961, -0.0038848143049019703, -0.0038887454128645506,
+        -0.003892538589228146, -0.0038961938034228934, -0.003899711029705122, -0.003903090247154752,
+        -0.003906331439672413, -0.003909434595976585, -0.003912399709600357, -0.003915226778888167,
+        -0.003917915806992373, -0.003920466801869527, -0.003922879776276664, -0.003925154747767281,
+        -0.003927291738687244, -0.003929290776170455, -0.0039311518921344695, -0.003932875123275766,
+        -0.003934460511065055, -0.003935908101742302, -0.00393721794631161, -0.0039383901005359346,
+        -0.003939424624931701, -0.003940321584763134, -0.0039410810500365556, -0.003941703095494456,
+        -0.003942187800609389, -0.003942535249577734, -0.0039427455313133354, -0.003942818739440888,
+        -0.003942754972289229, -0.003942554332884494, -0.003942216928943031, -0.0039417428728642035,
+        -0.003941132281723077, -0.003940385277262858, -0.003939501985887264, -0.003938482538652625,
+        -0.003937327071259985, -0.003936035724046918, -0.0039346086419791895, -0.003933045974642385,
+        -0.0039313478762332465, -0.003929514505550905, -0.00392754602598801, -0.0039254426055216115,
+        -0.003923204416703933, -0.0039208316366530615, -0.003918324447043317, -0.003915683034095645,
+        -0.0039129075885677616, -0.00390999830574415, -0.0039069553854259404, -0.0039037790319206493,
+        -0.0039004694540316753, -0.0038970268650477917, -0.0038934514827323502, -0.00388974352931243,
+        -0.003885903231467805, -0.003881930820319771, -0.0038778265314197893, -0.0038735906047380514,
+        -0.0038692232846518335, -0.0038647248199337987, -0.0038600954637399744, -0.0038553354735978078,
+        -0.0038504451113939193, -0.003845424643361781, -0.003840274340069216, -0.003834994476405804,
+        -0.003829585331570091, -0.003824047189056697, -0.0038183803366432776, -0.0038125850663772837,
+        -0.0038066616745627353, -0.0038006104617466577, -0.0037944317327055326, -0.0037881257964315564,
+        -0.003781692966118731, -0.0037751335591488765, -0.003768447897077481, -0.003761636305619407,
+        -0.0037546991146344528, -0.0037476366581128454, -0.003740449274160519, -0.003733137304984278,
+        -0.003725701096876887, -0.0037181410002019724, -0.0037104573693787484, -0.0037026505628668036,
+        -0.003694720943150496, -0.003686668876723368, -0.0036784947340724856, -0.0036701988896625276,
+        -0.003661781721919767, -0.0036532436132160158, -0.003644584949852377, -0.0036358061220427918,
+        -0.0036269075238976787, -0.0036178895534072384, -0.0036087526124247093, -0.0035994971066495487,
+        -0.0035901234456104567, -0.003580632042648162, -0.003571023314898367, -0.0035612976832743153,
+        -0.003551455572449259, -0.0035414974108390162, -0.0035314236305842048, -0.00352123466753237,
+        -0.0035109309612201627, -0.003500512954855233, -0.003489981095298013, -0.003479335833043572,
+        -0.00346857762220311, -0.0034577069204854757, -0.0034467241891786154, -0.0034356298931307854,
+        -0.0034244245007316754, -0.003413108483893568, -0.0034016823180322677, -0.0033901464820477786,
+        -0.003378501458305261, -0.0033667477326154924, -0.003354885794215432, -0.0033429161357486563,
+        -0.0033308392532456215, -0.0033186556461038694, -0.0033063658170681643, -0.0032939702722104927,
+        -0.0032814695209098096, -0.0032688640758320965, -0.0032561544529098657, -0.003243341171321727,
+        -0.003230424753472059, -0.0032174057249702752, -0.003204284614610131, -0.003191061954349007,
+        -0.0031777382792869554, -0.0031643141276457157, -0.003150790040747691, -0.00313716656299475,
+        -0.003123444241846908, -0.0031096236278010886, -0.0030957052743695998, -0.0030816897380586596,
+        -0.0030675775783466615, -0.003053369357662616, -0.003039065641364304, -0.00302466699771628,
+        -0.003010173997868069, -0.002995587215832107, -0.002980907228461415, -0.0029661346154276043,
+        -0.0029512699591984896, -0.002936313845015648, -0.002921266860872064, -0.002906129597489543,
+        -0.0028909026482960365, -0.002875586609403051, -0.002860182079582837, -0.0028446896602453923,
+        -0.0028291099554158304, -0.002813443571711165, -0.002797691118317198, -0.0027818532069655674,
+        -0.002765930451910405, -0.00274992346990503, -0.002733832880178656, -0.002717659304412961,
+        -0.0027014033667184935, -0.002685065693611249, -0.002668646913988959, -0.002652147659107355,
+        -0.0026355685625565283, -0.0026189102602370336, -0.0026021733903358927, -0.002585358593302913,
+        -0.002568466511826472, -0.002551497790809392, -0.002534453077345015, -0.0025173330206929067,
+        -0.002500138272254545, -0.0024828694855491304, -0.0024655273161891837, -0.0024481124218560532,
+        -0.002430625462275587, -0.0024130670991935155, -0.002395437996350861, -0.0023777388194593883,
+        -0.0023599702361769156, -0.002342132916082428, -0.0023242275306515356, -0.0023062547532316084,
+        -0.002288215259016647, -0.0022701097250227183, -0.0022519388300628026, -0.0022337032547217577,
+        -0.0022154036813314014, -0.002197040793945345, -0.002178615278313802, -0.002160127821858527,
+        -0.0021415791136475864, -0.0021229698443699114, -0.002104300706310353, -0.0020855723933241115,
+        -0.0020667856008113167, -0.0020479410256918308, -0.00202903936637981, -0.002010081322757942,
+        -0.001991067596152308, -0.0019719988893066847, -0.0019528759063569313, -0.001933699352805523,
+        -0.0019144699354958783, -0.0018951883625866468, -0.0018758553435261542, -0.0018564715890266657,
+        -0.0018370378110384824, -0.0018175547227245498, -0.001798023038434416, -0.0017784434736783845,
+        -0.0017588167451019557, -0.0017391435704598261, -0.0017194246685899855, -0.0016996607593879841,
+        -0.0016798525637809598, -0.0016600008037016734, -0.0016401062020627048, -0.001620169482730468,
+        -0.0016001913704991563, -0.001580172591064934, -0.0015601138709999025, -0.0015400159377259486,
+        -0.0015198795194890004, -0.0014997053453329604, -0.0014794941450734177, -0.0014592466492719845,
+        -0.001438963589210078, -0.0014186456968628377, -0.0013982937048732174, -0.0013779083465258618,
+        -0.0013574903557209935, -0.0013370404669484882, -0.0013165594152617615, -0.0012960479362516288,
+        -0.001275506766020409, -0.0012549366411558067, -0.0012343382987047, -0.0012137124761473612,
+        -0.0011930599113713564, -0.0011723813426452255, -0.0011516775085928322, -0.0011309491481671457,
+        -0.001110197000624172, -0.0010894218054970932, -0.0010686243025701994, -0.001047805231852811,
+        -0.0010269653335534716, -0.0010061053480539153, -0.0009852260158829242, -0.0009643280776907885,
+        -0.0009434122742230832, -0.0009224793462947114, -0.000901530034764292, -0.0008805650805081132,
+        -0.0008595852243942221, -0.0008385912072567521, -0.0008175837698700232, -0.0007965636529226811,
+        -0.0007755315969920812, -0.0007544883425184541, -0.000733434629779122, -0.0007123711988629379,
+        -0.0006912987896445759, -0.0006702181417586752, -0.0006491299945746222, -0.0006280350871706721,
+        -0.0006069341583083195, -0.0005858279464070613, -0.0005647171895187094, -0.0005436026253018774,
+        -0.000522484990996696, -0.0005013650233993344, -0.00048024345883656015, -0.00045912103314055356,
+        -0.00043799848162354274, -0.00041687653905246374, -0.0003957559396238956, -0.00037463741693882053,
+        -0.0003535217039773129, -0.00033240953307371097, -0.0003113016358915037, -0.00029019874339801093,
+        -0.00026910158583981237, -0.00024801089271761143, -0.00022692739276129737, -0.00020585181390525635,
+        -0.0001847848832634995, -0.00016372732710483688, -0.00014267987082833034, -0.00012164323893859118,
+        -0.00010061815502097364, -7.960534171743082e-05, -5.860552070170776e-05, -3.76194126548443e-05,
+        -1.6647737241032252e-05, 4.308786916777418e-06, 2.5249442261465224e-05, 4.6173512325570145e-05,
+        6.708028175566784e-05, 8.796903633653995e-05, 0.00010883906301508388, 0.00012968964992439037,
+        0.00015052008640776334, 0.00017132966304245093, 0.00019211767166351786, 0.0002128834053878085,
+        0.00023362615863724002, 0.0002543452271627408, 0.0002750399080678705, 0.000295709499832049,
+        0.00031635330233417333, 0.00033697061687603975, 0.0003575607462055036, 0.0003781229945397871,
+        0.0003986566675887307, 0.0004191610725777394, 0.00043963551827088916, 0.0004600793149939641,
+        0.0004804917746571881, 0.0005008722107780962, 0.0005212199385044726, 0.0005415342746366215,
+        0.000561814537650261, 0.0005820600477190839, 0.0006022701267369347, 0.0006224440983403473,
+        0.0006425812879308758, 0.0006626810226971589, 0.0006827426316371288, 0.0007027654455801275,
+        0.0007227487972087355, 0.0007426920210807277, 0.0007625944536509634, 0.0007824554332929544,
+        0.000802274300320557, 0.0008220503970097084, 0.0008417830676196175, 0.0008614716584142138,
+        0.0008811155176837288, 0.0009007139957655305, 0.0009202664450654405, 0.0009397722200788233,
+        0.0009592306774113869, 0.000978641175800122, 0.0009980030761341432, 0.0010173157414752215,
+        0.0010365785370784267, 0.0010557908304128102, 0.0010749519911814295, 0.0010940613913419475,
+        0.0011131184051268843, 0.0011321224090635267, 0.0011510727819939762, 0.0011699689050953738,
+        0.0011888101618993674, 0.001207595938312021, 0.0012263256226335104, 0.0012449986055775254,
+        0.0012636142802907706, 0.0012821720423723977, 0.001300671289893093, 0.001319111423414271,
+        0.0013374918460072997, 0.0013558119632720732, 0.0013740711833561167, 0.0013922689169733702,
+        0.001410404577422573, 0.0014284775806059323, 0.0014464873450476048, 0.0014644332919118656,
+        0.0014823148450213925, 0.0015001314308754246, 0.0015178824786676384, 0.0015355674203040928,
+        0.0015531856904210664, 0.0015707367264026272, 0.0015882199683981996, 0.0016056348593401929,
+        0.0016229808449610991, 0.0016402573738107664, 0.0016574638972737766, 0.001674599869586109,
+        0.0016916647478521997, 0.0017086579920617894, 0.001725579065106436, 0.0017424274327961533,
+        0.0017592025638758998, 0.0017759039300417985, 0.0017925310059573906, 0.0018090832692698022,
+        0.0018255602006255988, 0.0018419612836866814, 0.0018582860051461815, 0.0018745338547438613,
+        0.0018907043252816562, 0.001906796912639322, 0.0019228111157893724, 0.0019387464368123798,
+        0.001954602380912082, 0.001970378456430132, 0.0019860741748609734, 0.0020016890508665654,
+        0.0020172226022908074, 0.0020326743501739987, 0.0020480438187673033, 0.0020633305355466243,
+        0.002078534031226883, 0.00209365383977599, 0.0021086894984284482, 0.0021236405476991738,
+        0.002138506531397075, 0.0021532869966383954, 0.0021679814938600677, 0.002182589576832948,
+        0.0021971108026747656, 0.002211544731863113, 0.0022258909282482915, 0.002240148959065886,
+        0.0022543183949493345, 0.002268398809942501, 0.0022823897815116975, 0.0022962908905580113,
+        0.002310101721429425, 0.002323821861932481, 0.0023374509033442115, 0.0023509884404237934,
+        0.0023644340714239795, 0.0023777873981024977, 0.0023910480257333822, 0.002404215563117993,
+        0.0024172896225960264, 0.00243026982005644, 0.002443155774948088, 0.002455947110290321,
+        0.0024686434526835655, 0.0024812444323194936, 0.0024937496829912083, 0.0025061588421035324,
+        0.0025184715506826544, 0.0025306874533860555, 0.002542806198512199, 0.002554827438009953,
+        0.002566750827488028, 0.002578576026224283, 0.00259030269717474, 0.002601930506982593,
+        0.0026134591259872088, 0.0026248882282325393, 0.0026362174914759296, 0.0026474465971965673,
+        0.0026585752306036168, 0.0026696030806444573, 0.0026805298400128545, 0.002691355205156608,
+        0.0027020788762854653, 0.0027127005573787523, 0.002723219956192732, 0.0027336367842680513,
+        0.0027439507569369014, 0.0027541615933301252, 0.0027642690163840918, 0.002774272752847488,
+        0.0027841725332880727, 0.002793968092099039, 0.0028036591675054717, 0.0028132455015706524,
+        0.002822726840202, 0.002832102933157104, 0.002841373534049637, 0.002850538400354874,
+        0.0028595972934153295, 0.002868549978446169, 0.0028773962245404157, 0.002886135804674087,
+        0.0028947684957112483, 0.0029032940784087563, 0.0029117123374209986, 0.0029200230613045633,
+        0.0029282260425224486, 0.002936321077448524, 0.002944307966371677, 0.0029521865134996867,
+        0.0029599565269631724, 0.002967617818819378, 0.0029751702050556357, 0.002982613505592886,
+        0.0029899475442889843, 0.002997172148941821, 0.0030042871512923845, 0.0030112923870276673,
+        0.003018187695783343, 0.0030249729211464134, 0.00303164791065773, 0.003038212515814172,
+        0.003044666592070965, 0.003051009998843695, 0.003057242599510207, 0.0030633642614122897,
+        0.0030693748558574658, 0.0030752742581203587, 0.0030810623474440114, 0.0030867390070412736,
+        0.0030923041240956276, 0.003097757589762248, 0.003103099299168879, 0.0031083291514162384,
+        0.003113447049578765, 0.0031184529007048534, 0.0031233466158171203, 0.003128128109912505,
+        0.0031327973019622387, 0.003137354114911584, 0.003141798475679603, 0.0031461303151586566,
+        0.003150349568213765, 0.003154456173681933, 0.003158450074371231, 0.0031623312170597963,
+        0.0031660995524946402, 0.0031697550353904282, 0.0031732976244280035, 0.003176727282252778,
+        0.003180043975473134, 0.003183247674658492, 0.003186338354337373, 0.0031893159929952675,
+        0.003192180573072401, 0.0031949320809613003, 0.003197570507004362, 0.0032000958454910743,
+        0.003202508094655288, 0.003204807256672302, 0.0032069933376557332, 0.0032090663476543567,
+        0.003211026300648766, 0.003212873214547867, 0.0032146071111853135, 0.0032162280163157402,
+        0.00321773595961087, 0.00321913097465554, 0.003220413098943549, 0.0032215823738733474,
+        0.003222638844743665, 0.003223582560748949, 0.0032244135749747004, 0.0032251319443926236,
+        0.0032257377298557603, 0.0032262309960933407, 0.0032266118117056184, 0.0032268802491585403,
+        0.0032270363847782596, 0.003227080298745538, 0.0032270120750900323, 0.0032268318016844297,
+        0.0032265395702384585, 0.003226135476292775, 0.0032256196192127053, 0.003224992102181877,
+        0.0032242530321957355, 0.0032234025200548505, 0.0032224406803582394, 0.0032213676314964096,
+        0.0032201834956443916, 0.0032188883987545394, 0.0032174824705493337, 0.0032159658445139287,
+        0.0032143386578886626, 0.0032126010516613985, 0.0032107531705597558, 0.0032087951630432113,
+        0.0032067271812950856, 0.003204549381214389, 0.003202261922407569, 0.0031998649681800957,
+        0.003197358685527955, 0.003194743245129035, 0.003192018821334329, 0.0031891855921590785,
+        0.003186243739273776, 0.0031831934479950212, 0.0031800349072762858, 0.003176768309698568,
+        0.003173393851460875, 0.0031699117323706325, 0.003166322155834025, 0.0031626253288460117,
+        0.0031588214619805363, 0.003154910769380358, 0.0031508934687468366, 0.003146769781329705,
+        0.003142539931916596, 0.0031382041488224764, 0.003133762663879073, 0.003129215712424018,
+        0.003124563533290001, 0.003119806368793799, 0.003114944464725089, 0.003109978070335288,
+        0.0031049074383261996, 0.003099732824838568, 0.0030944544894404816, 0.0030890726951157362,
+        0.0030835877082520703, 0.003077999798629238, 0.003072309239406976, 0.003066516307113004,
+        0.0030606212816306905, 0.003054624446186762, 0.0030485260873389236, 0.003042326494963238,
+        0.003036025962241496, 0.0030296247856485365, 0.00302312326493925, 0.0030165217031357496,
+        0.0030098204065142626, 0.0030030196845918576, 0.0029961198501133467, 0.0029891212190377614,
+        0.002982024110524919, 0.0029748288469219103, 0.002967535753749286, 0.0029601451596873614,
+        0.0029526573965623425, 0.0029450727993322935, 0.002937391706073044, 0.0029296144579641172,
+        0.002921741399274326, 0.0029137728773474406, 0.002905709242587781, 0.0028975508484455565,
+        0.0028892980514022594, 0.0028809512109558718, 0.00287251068960609, 0.002863976852839279,
+        0.0028553500691134836, 0.0028466307098433483, 0.0028378191493847937, 0.0028289157650197645,
+        0.0028199209369408544, 0.002810835048235748, 0.0028016584848716383, 0.002792391635679681,
+        0.0027830348923389868, 0.002773588649361064, 0.002764053304073732, 0.0027544292566049934,
+        0.002744716909867203, 0.0027349166695406386, 0.002725028944057296, 0.002715054144584646,
+        0.0027049926850089925, 0.0026948449819190687, 0.0026846114545894797, 0.002674292524963852,
+        0.002663888617638196, 0.0026534001598440752, 0.0026428275814315448, 0.0026321713148522727,
+        0.002621431795142368, 0.0026106094599053506, 0.002599704749294743, 0.002588718105996835,
+        0.0025776499752133953, 0.0025665008046440235, 0.0025552710444687, 0.002543961147330264,
+        0.0025325715683165665, 0.0025211027649427935, 0.0025095551971337257, 0.0024979293272056136,
+        0.0024862256198484676, 0.0024744445421079277, 0.002462586563366986, 0.0024506521553281395,
+        0.002438641791994881, 0.0024265559496534856, 0.00241439510685473, 0.0024021597443952825,
+        0.002389850345299295, 0.0023774673947999127, 0.0023650113803204767, 0.0023524827914559376,
+        0.0023398821199541587, 0.0023272098596969536, 0.0023144665066813044, 0.0023016525590004886,
+        0.0022887685168249436, 0.0022758148823833154, 0.0022627921599433017, 0.0022497008557926033,
+        0.00223654147821953, 0.002223314537493816, 0.0022100205458473786, 0.0021966600174547607,
+        0.0021832334684137714, 0.0021697414167261104, 0.002156184382277633, 0.002142562886818841,
+        0.0021288774539453955, 0.002115128609078062, 0.0021013168794433786, 0.0020874427940536936,
+        0.002073506883687126, 0.002059509680868123, 0.002045451719847148, 0.0020313335365808706,
+        0.002017155668712223, 0.002002918655550167, 0.0019886230380496746, 0.0019742693587916863,
+        0.0019598581619627363, 0.0019453899933348055, 0.0019308654002451841, 0.001916284931575938,
+        0.0019016491377337268, 0.0018869585706293261, 0.0018722137836573581, 0.001857415331675654,
+        0.001842563770984821, 0.001827659659307862, 0.0018127035557693966, 0.0017976960208751665,
+        0.0017826376164915168, 0.0017675289058245427, 0.0017523704533995033, 0.0017371628250402225,
+        0.0017219065878479956, 0.0017066023101812414, 0.0016912505616344944, 0.0016758519130173762,
+        0.0016604069363341713, 0.001644916204762586, 0.0016293802926329564, 0.0016137997754074416,
+        0.0015981752296588404, 0.0015825072330497318, 0.0015667963643115788, 0.0015510432032234817,
+        0.0015352483305912604, 0.0015194123282265006, 0.0015035357789252336, 0.0014876192664470163,
+        0.0014716633754937084, 0.0014556686916885144, 0.001439635801554599, 0.0014235652924940397,
+        0.0014074577527667489, 0.0013913137714690789, 0.001375133938512707, 0.001358918844603545,
+        0.0013426690812202871, 0.001326385240593262, 0.0013100679156833263, 0.0012937177001603624,
+        0.001277335188382117, 0.0012609209753731087, 0.0012444756568028852, 0.0012279998289652764,
+        0.0012114940887568383, 0.0011949590336553385, 0.0011783952616989553, 0.0011618033714645443,
+        0.0011451839620464878, 0.0011285376330355038, 0.001111864984497099, 0.0010951666169503797,
+        0.001078443131346856, 0.0010616951290488969, 0.0010449232118085467, 0.0010281279817463435,
+        0.0010113100413297665, 0.000994469993352113, 0.0009776084409111254, 0.000960725987387863,
+        0.0009438232364251844, 0.0009269007919065959, 0.0009099592579351171, 0.0008929992388117662,
+        0.0008760213390144558, 0.0008590261631768711, 0.0008420143160670028, 0.0008249864025660497,
+        0.00080794302764736, 0.0007908847963549792, 0.0007738123137826106, 0.0007567261850525818,
+        0.0007396270152944453, 0.0007225154096240275, 0.0007053919731222283, 0.0006882573108140978,
+        0.0006711120276475124, 0.0006539567284722411, 0.0006367920180190405, 0.0006196185008783824,
+        0.0006024367814795906, 0.00058524746406999, 0.0005680511526937052, 0.0005508484511708671,
+        0.0005336399630768638, 0.0005164262917210168, 0.000499208040126264, 0.00048198581100808,
+        0.0004647602067534357, 0.000447531829400559, 0.00043030128061773804, 0.00041306916168277086,
+        0.0003958360734624043, 0.00037860261639143173, 0.00036136939045219136, 0.00034413699515409936,
+        0.000326906029512844, 0.000309677092029979, 0.0002924507806725581, 0.00027522769285242773,
+        0.00025800842540592576, 0.00024079357457362354, 0.00022358373597972342, 0.00020637950461190995,
+        0.00018918147480099628, 0.000171990240200849, 0.0001548063937679436, 0.00013763052774133197,
+        0.00012046323362264948, 0.00010330510215579073, 8.615672330700058e-05, 6.901868624500785e-05,
+        5.189157932082954e-05, 3.47759900479914e-05, 1.76725050828347e-05, 5.817102042483012e-07,
+        -1.6495809705561657e-05, -3.355947068104062e-05, -5.060868969320614e-05, -6.76428846687377e-05,
+        -8.466147451000655e-05, -0.00010166387911443891, -0.00011864951939386985, -0.0001356178172942186,
+        -0.00015256819581473873, -0.00016950007902722167, -0.00018641289209551397, -0.00020330606129461171,
+        -0.00022017901402970435, -0.00023703117885552917, -0.00025386198549526555, -0.0002706708648596152,
+        -0.0002874572490655806, -0.00030422057145560285, -0.00032096026661626207, -0.00033767577039692926,
+        -0.00035436651992872556, -0.00037103195364305034, -0.0003876715112900554, -0.0004042846339574174,
+        -0.0004208707640886938, -0.00043742934550156684, -0.0004539598234066372, -0.0004704616444251859,
+        -0.0004869342566076349, -0.0005033771094519476, -0.000519789653921199, -0.000536171342462045,
+        -0.000552521629022521, -0.0005688399690698213, -0.0005851258196083648, -0.0006013786391974347,
+        -0.0006175978879687543, -0.0006337830276443471, -0.0006499335215539754, -0.0006660488346525004,
+        -0.0006821284335375427, -0.000698171786466657, -0.0007141783633746936, -0.0007301476358908313,
+        -0.0007460790773559497, -0.0007619721628395594, -0.0007778263691566633, -0.0007936411748849068,
+        -0.0008094160603812845, -0.0008251505077987783, -0.000840844001103278, -0.0008564960260900684,
+        -0.0008721060704002297, -0.0008876736235373332, -0.000903198176883694, -0.000918679223716504,
+        -0.0009341162592244756, -0.0009495087805234736, -0.0009648562866728153, -0.0009801582786914472,
+        -0.000995414259573373, -0.0010106237343038644, -0.0010257862098750471, -0.0010409011953014407,
+        -0.0010559682016357415, -0.0010709867419841945, -0.001085956331521887, -0.0011008764875082792,
+        -0.0011157467293023168, -0.001130566578377481, -0.0011453355583370527, -0.0011600531949289435,
+        -0.001174719016060654, -0.0011893325518139253, -0.0012038933344596789, -0.001218400898472508,
+        -0.0012328547805451244, -0.0012472545196030127, -0.0012615996568186804, -0.0012758897356258177,
+        -0.0012901243017336846, -0.0013043029031410932, -0.0013184250901502614, -0.0013324904153811038,
+        -0.0013464984337845847, -0.0013604487026566536, -0.001374340781652072, -0.0013881742327975033,
+        -0.001401948620505333, -0.0014156635115868737, -0.0014293184752655129, -0.0014429130831900823,
+        -0.0014564469094478177, -0.0014699195305772092, -0.0014833305255811016, -0.001496679475939346,
+        -0.001509965965621383, -0.0015231895810990338, -0.001536349911358842, -0.0015494465479145268,
+        -0.001562479084819148, -0.0015754471186774795, -0.0015883502486580211, -0.0016011880765048742,
+        -0.0016139602065498439, -0.001626666245724122, -0.0016393058035699007, -0.001651878492252147,
+        -0.0016643839265699945, -0.001676821723968053, -0.0016891915045478718, -0.0017014928910790528,
+        -0.001713725509010191, -0.0017258889864802127, -0.001737982954328848, -0.0017500070461076113,
+        -0.0017619608980906257, -0.0017738441492848288, -0.0017856564414407618, -0.0017973974190628147,
+        -0.00180906672941939, -0.0018206640225532505, -0.001832188951291455, -0.0018436411712552442,
+        -0.0018550203408700249, -0.0018663261213750439, -0.00187755817683291, -0.001888716174139296,
+        -0.001899799783032231, -0.0019108086761014792, -0.001921742528797606, -0.0019326010194412576,
+        -0.001943383829232062, -0.0019540906422574177, -0.001964721145501425, -0.0019752750288534885,
+        -0.0019857519851167594, -0.0019961517100167687, -0.0020064739022096615, -0.0020167182632903386,
+        -0.0020268844978008544, -0.0020369723132380806, -0.0020469814200617825, -0.0020569115317025303,
+        -0.00206676236456908, -0.002076533638056202, -0.0020862250745520366, -0.002095836399445396,
+        -0.002105367341133114, -0.0021148176310271214, -0.0021241870035613917, -0.0021334751961989756,
+        -0.0021426819494387347, -0.0021518070068219457, -0.002160850114939026, -0.0021698110234358815,
+        -0.0021786894850203066, -0.0021874852554681396, -0.002196198093629493, -0.002204827761434733,
+        -0.0022133740239002843, -0.0022218366491345573, -0.0022302154083435532, -0.0022385100758363615,
+        -0.002246720429030737, -0.0022548462484583776, -0.002262887317770027, -0.002270843423740868,
+        -0.0022787143562751984, -0.0022864999084114715, -0.0022941998763271547, -0.0023018140593431703,
+        -0.0023093422599285455, -0.0023167842837049425, -0.0023241399394507587, -0.0023314090391054892,
+        -0.002338591397773779, -0.0023456868337293087, -0.00235269516841879, -0.0023596162264656255,
+        -0.0023664498356735153, -0.002373195827030067, -0.0023798540347101348, -0.0023864242960790643,
+        -0.0023929064516959916, -0.002399300345316785, -0.0024056058238970733, -0.0024118227375949904,
+        -0.002417950939773976, -0.0024239902870053613, -0.0024299406390707883, -0.0024358018589646833,
+        -0.0024415738128964726, -0.0024472563702926838, -0.0024528494037990654, -0.002458352789282455,
+        -0.0024637664058325443, -0.0024690901357637034, -0.00247432386461638, -0.002479467481158642,
+        -0.002484520877387641, -0.002489483948530601, -0.002494356593046149, -0.002499138712625235,
+        -0.002503830212191988, -0.002508430999904584, -0.002512940987155855, -0.002517360088573824,
+        -0.0025216882220221917, -0.0025259253086006377, -0.0025300712726449943, -0.002534126041727407,
+        -0.0025380895466562805, -0.002541961721476166, -0.002545742503467477, -0.002549431833146199,
+        -0.0025530296542633783, -0.0025565359138045294, -0.0025599505619889828, -0.0025632735522690725,
+        -0.0025665048413291546, -0.0025696443890846623, -0.0025726921586809166, -0.002575648116491853,
+        -0.0025785122321187264, -0.0025812844783885355, -0.0025839648313524945, -0.0025865532702843436,
+        -0.0025890497776784693, -0.0025914543392480506, -0.002593766943922975, -0.002595987583847707,
+        -0.0025981162543790293, -0.0026001529540837, -0.0026020976847359154, -0.0026039504513147736,
+        -0.0026057112620015786, -0.0026073801281769646, -0.0026089570644180645, -0.0026104420884954316,
+        -0.0026118352213699058, -0.0026131364871893725, -0.0026143459132854266, -0.002615463530169874,
+        -0.0026164893715311683, -0.0026174234742307677, -0.0026182658782992986, -0.0026190166269326764,
+        -0.0026196757664880927, -0.002620243346479944, -0.002620719419575551, -0.0026211040415908653,
+        -0.0026213972714860547, -0.0026215991713609364, -0.002621709806450329, -0.002621729245119329,
+        -0.002621657558858414, -0.0026214948222785367, -0.0026212411131059875, -0.002620896512177284,
+        -0.0026204611034338575, -0.002619934973916677, -0.0026193182137607884, -0.002618610916189677,
+        -0.002617813177509622, -0.002616925097103872, -0.0026159467774267446, -0.0026148783239976427,
+        -0.0026137198453949372, -0.0026124714532497558, -0.002611133262239682, -0.0026097053900823335,
+        -0.002608187957528868, -0.002606581088357366, -0.002604884909366093, -0.002603099550366718,
+        -0.002601225144177378, -0.002599261826615687, -0.002597209736491588, -0.002595069015600169,
+        -0.002592839808714354, -0.0025905222635774806, -0.002588116530895784, -0.0025856227643308144,
+        -0.0025830411204917254, -0.002580371758927478, -0.002577614842118927, -0.0025747705354708583,
+        -0.002571839007303887, -0.0025688204288462414, -0.0025657149742255615, -0.0025625228204604416,
+        -0.0025592441474519994, -0.0025558791379753504, -0.0025524279776708604, -0.0025488908550354793,
+        -0.0025452679614138747, -0.0025415594909894606, -0.002537765640775423, -0.0025338866106055864,
+        -0.0025299226031251475, -0.0025258738237814594, -0.0025217404808146092, -0.0025175227852478627,
+        -0.0025132209508782195, -0.0025088351942666605, -0.0025043657347283933, -0.0024998127943230966,
+        -0.0024951765978448895, -0.0024904573728123714, -0.0024856553494585327, -0.002480770760720511,
+        -0.00247580384222936, -0.0024707548322996722, -0.0024656239719191325, -0.002460411504737971,
+        -0.002455117677058339, -0.002449742737823678, -0.002444286938607829, -0.0024387505336042055,
+        -0.002433133779614897, -0.002427436936039533, -0.0024216602648642178, -0.0024158040306503626,
+        -0.0024098685005233306, -0.0024038539441611174, -0.0023977606337829416, -0.00239158884413762,
+        -0.0023853388524920894, -0.0023790109386196885, -0.002372605384788282, -0.0023661224757486367,
+        -0.002359562498722322, -0.0023529257433898085, -0.0023462125018784107, -0.002339423068750081,
+        -0.00233255774098923, -0.0023256168179904666, -0.0023186006015461454, -0.002311509395833971,
+        -0.002304343507404537, -0.0022971032451686185, -0.002289788920384601, -0.002282400846645692,
+        -0.0022749393398671966, -0.002267404718273537, -0.0022597973023853407, -0.0022521174150065084,
+        -0.002244365381210991, -0.002236541528329696, -0.002228646185937323, -0.002220679685838953,
+        -0.0022126423620567643, -0.002204534550816645, -0.002196356590534531, -0.0021881088218030625,
+        -0.0021797915873778594, -0.0021714052321637287, -0.002162950103201106, -0.0021544265496521563,
+        -0.0021458349227867777, -0.0021371755759688736, -0.002128448864642175, -0.002119655146316226,
+        -0.0021107947805523207, -0.0021018681289491988, -0.0020928755551288553, -0.0020838174247222964,
+        -0.0020746941053550357, -0.0020655059666327396, -0.002056253380126815, -0.0020469367193597238,
+        -0.0020375563597904893, -0.002028112678799986, -0.0020186060556763217, -0.0020090368715999334,
+        -0.0019994055096288644, -0.0019897123546839123, -0.0019799577935336126, -0.001970142214779297,
+        -0.0019602660088401413, -0.0019503295679379588, -0.0019403332860821081, -0.001930277559054421,
+        -0.0019201627843937136, -0.0019099893613807935, -0.0018997576910229886, -0.0018894681760386488,
+        -0.0018791212208419858, -0.0018687172315273789, -0.0018582566158539032, -0.0018477397832298806,
+        -0.001837167144697081, -0.0018265391129151753, -0.0018158561021460733, -0.0018051185282380504,
+        -0.0017943268086100248, -0.0017834813622358136, -0.0017725826096280965, -0.0017616309728226043,
+        -0.0017506268753621308, -0.0017395707422806046, -0.0017284630000869482, -0.0017173040767490427,
+        -0.0017060944016777171, -0.0016948344057104326, -0.0016835245210951872, -0.0016721651814743583,
+        -0.0016607568218682904, -0.0016492998786590987, -0.001637794789574415, -0.0016262419936707696,
+        -0.0016146419313174988, -0.0016029950441802073, -0.0015913017752041052, -0.0015795625685978915,
+        -0.001567777869816915, -0.0015559481255467357, -0.0015440737836866386, -0.0015321552933328254,
+        -0.001520193104761888, -0.0015081876694142005, -0.0014961394398770647, -0.0014840488698680593,
+        -0.0014719164142183978, -0.0014597425288559532, -0.0014475276707885676, -0.001435272298087297,
+        -0.0014229768698694025, -0.0014106418462815824, -0.0013982676884830285, -0.0013858548586286263,
+        -0.001373403819851821, -0.0013609150362477909, -0.00134838897285651, -0.0013358260956456047,
+        -0.0013232268714934033, -0.001310591768172013, -0.001297921254330073, -0.0012852157994757691,
+        -0.0012724758739598822, -0.0012597019489583514, -0.0012468944964555359, -0.001234053989226921,
+        -0.0012211809008217734, -0.001208275705546387, -0.0011953388784465614, -0.0011823708952905677,
+        -0.0011693722325520125, -0.0011563433673924645, -0.0011432847776443288, -0.0011301969417937022,
+        -0.0011170803389629633, -0.0011039354488936197, -0.0010907627519291384, -0.0010775627289975071,
+        -0.0010643358615940932, -0.0010510826317643065, -0.0010378035220864436, -0.0010244990156542224,
+        -0.0010111695960595864, -0.000997815747375511, -0.0009844379541385116, -0.0009710367013314586,
+        -0.0009576124743663554, -0.0009441657590668646, -0.0009306970416510967, -0.0009172068087144402,
+        -0.0009036955472119039, -0.0008901637444412497, -0.0008766118880254891, -0.0008630404658954084,
+        -0.0008494499662726914, -0.0008358408776522747, -0.0008222136887852058, -0.0008085688886614483,
+        -0.0007949069664924277, -0.0007812284116938512, -0.0007675337138685592, -0.0007538233627890643,
+        -0.0007400978483804155, -0.0007263576607030541, -0.0007126032899353896, -0.0006988352263567237,
+        -0.0006850539603299628, -0.0006712599822845674, -0.0006574537826991594, -0.0006436358520844571,
+        -0.0006298066809662091, -0.0006159667598678488, -0.0006021165792934639, -0.0005882566297107763,
+        -0.0005743874015338355, -0.0005605093851060413, -0.0005466230706831594, -0.0005327289484160722,
+        -0.000518827508333841, -0.0005049192403268193, -0.000491004634129287, -0.0004770841793028953,
+        -0.00046315836521951096, -0.00044922768104407163, -0.00043529261571809456, -0.00042135365794241427,
+        -0.00040741129616043623, -0.00039346601854138076, -0.0003795183129632496, -0.00036556866699612,
+        -0.00035161756788545744, -0.00033766550253515744, -0.00032371295749090946, -0.00030976041892359136,
+        -0.00029580837261238447, -0.00028185730392825447, -0.0002679076978172545, -0.00025396003878406484,
+        -0.00024001481087521735, -0.0002260724976626563, -0.00021213358222733, -0.0001981985471425053,
+        -0.00018426787445742339, -0.00017034204568097731, -0.000156421541765129, -0.00014250684308865364,
+        -0.00012859842944095575, -0.00011469678000541226, -0.00010080237334355762, -8.691568737866479e-05,
+        -7.303719937936183e-05, -5.916738594392185e-05, -4.530672298378503e-05, -3.145568570761954e-05,
+        -1.7614748605386957e-05, -3.784385432144545e-06, 1.0034930807813206e-05, 2.3842727876773773e-05,
+        3.763853431968684e-05, 5.142187947990368e-05, 6.519229351487652e-05, 7.8949307412116e-05,
+        9.269245300477468e-05, 0.00010642126298738666, 0.00012013527093136652, 0.0001338340113007963,
+        0.00014751701946787046, 0.00016118383172829528, 0.0001748339853169438, 0.00018846701842316682,
+        0.0002020824702060563, 0.00021567988080996168, 0.00022925879137966252, 0.0002428187440754937,
+        0.0002563592820887157, 0.0002698799496565498, 0.00028338029207711815, 0.00029685985572484055,
+        0.00031031818806498217, 0.0003237548376687865, 0.00033716935422854446, 0.00035056128857199957,
+        0.0003639301926774864, 0.0003772756196885172, 0.0003905971239283597, 0.00040389426091485176,
+        0.00041716658737486414, 0.0004304136612587165, 0.00044363504175482914, 0.0004568302893040244,
+        0.0004699989656137737, 0.0004831406336726886, 0.0004962548577646155, 0.0005093412034828837,
+        0.0005223992377442921, 0.0005354285288033688, 0.0005484286462662718, 0.0005613991611046417,
+        0.0005743396456696796, 0.0005872496737058782, 0.0006001288203646896, 0.0006129766622184251,
+        0.0006257927772738086, 0.0006385767449854253, 0.0006513281462696002, 0.0006640465635174451,
+        0.0006767315806084485, 0.0006893827829239956, 0.0007019997573602329, 0.0007145820923416266,
+        0.0007271293778339722, 0.0007396412053573896, 0.0007521171679995204, 0.000764556860428383,
+        0.0007769598789051636, 0.000789325821297223, 0.0008016542870907485, 0.0008139448774033519,
+        0.0008261971949968674, 0.0008384108442897719, 0.0008505854313697414, 0.0008627205640059568,
+        0.0008748158516614875, 0.0008868709055057746, 0.0008988853384264395, 0.0009108587650417573,
+        0.0009227908017125893, 0.0009346810665543011, 0.000946529179448851, 0.000958334762056562,
+        0.0009700974378278032, 0.0009818168320148812, 0.000993492571683581, 0.0010051242857246434,
+        0.0010167116048654245, 0.0010282541616812308, 0.001039751590606563, 0.0010512035279465633,
+        0.0010626096118880907, 0.0010739694825108944, 0.0010852827817985414, 0.0010965491536495556,
+        0.0011077682438882241, 0.0011189397002753232, 0.0011300631725190257, 0.001141138312285481,
+        0.0011521647732093123, 0.001163142210904289, 0.001174070282973681, 0.0011849486490204857,
+        0.0011957769706580075, 0.001206554911519707, 0.001217282137269454, 0.0012279583156117578,
+        0.0012385831163013365, 0.0012491562111533276, 0.001259677274052941, 0.0012701459809651126,
+        0.0012805620099443041, 0.0012909250411439624, 0.001301234756825923, 0.0013114908413699524,
+        0.0013216929812829951, 0.0013318408652082962, 0.0013419341839347285, 0.0013519726304057381,
+        0.001361955899728376, 0.0013718836891820994, 0.0013817556982277355, 0.0013915716285161342,
+        0.0014013311838967381, 0.0014110340704263054, 0.001420679996377294, 0.0014302686722462066,
+        0.0014397998107620275, 0.0014492731268943835, 0.0014586883378615858, 0.0014680451631389443,
+        0.0014773433244664339, 0.001486582545856709, 0.0014957625536030526, 0.0015048830762867762,
+        0.0015139438447851084, 0.001522944592278623, 0.001531885054258646, 0.0015407649685347327,
+        0.0015495840752418975, 0.0015583421168477263, 0.0015670388381596038, 0.001575673986331685,
+        0.0015842473108717444, 0.001592758563648128, 0.0016012074988964568, 0.0016095938732262965,
+        0.0016179174456276613, 0.0016261779774776309, 0.00163437523254668, 0.001642508977004914,
+        0.0016505789794284199, 0.0016585850108053505, 0.0016665268445418587, 0.0016744042564682217,
+        0.0016822170248446046, 0.0016899649303667866, 0.0016976477561719608, 0.001705265287844256,
+        0.001712817313420175, 0.0017203036233942033, 0.0017277240107238852, 0.0017350782708351767,
+        0.0017423662016276824, 0.0017495876034794668, 0.001756742279252218, 0.0017638300342960344,
+        0.0017708506764541544, 0.001777804016067739, 0.0017846898659804131, 0.001791508041542726,
+        0.001798258360616641, 0.0018049406435798358, 0.0018115547133298437, 0.0018181003952883127,
+        0.001824577517404985, 0.001830985910161663, 0.0018373254065760165, 0.0018435958422054455,
+        0.0018497970551507135, 0.0018559288860594708, 0.0018619911781298584, 0.001867983777113846,
+        0.0018739065313205059, 0.0018797592916193068, 0.0018855419114432042, 0.0018912542467916082,
+        0.0018968961562334775, 0.001902467500909994, 0.0019079681445373761, 0.0019133979534096553,
+        0.0019187567964010438, 0.0019240445449685646, 0.001929261073154401, 0.001934406257588115,
+        0.0019394799774889594, 0.0019444821146679253, 0.0019494125535297285, 0.0019542711810747993,
+        0.0019590578869010822, 0.001963772563205741, 0.001968415104786856, 0.001972985409044957,
+        0.0019774833759844904, 0.001981908908215129, 0.0019862619109531554, 0.001990542292022571,
+        0.001994749961856176, 0.001998884833496626, 0.0020029468225973134, 0.0020069358474231417,
+        0.002010851828851323, 0.0020146946903719545, 0.0020184643580885607, 0.002022160760718571,
+        0.00202578382959366, 0.002029333498659963, 0.0020328097044783606, 0.0020362123862244326,
+        0.0020395414856885035, 0.0020427969472755865, 0.0020459787180050824, 0.002049086747510584,
+        0.0020521209880394685, 0.002055081394452392, 0.0020579679242227937, 0.002060780537436215,
+        0.002063519196789533, 0.00206618386759018, 0.0020687745177551836, 0.0020712911178101504,
+        0.002073733640888188, 0.002076102062728693, 0.0020783963616760734, 0.0020806165186783437,
+        0.002082762517285721, 0.00208483434364902, 0.0020868319865180148, 0.0020887554372397196,
+        0.0020906046897565603, 0.002092379740604448, 0.002094080588910797, 0.0020957072363924287,
+        0.0020972596873533576, 0.002098737948682602, 0.002100142029851722, 0.0021014719429124384,
+        0.002102727702494117, 0.002103909325801078, 0.0021050168326099293, 0.0021060502452667803,
+        0.002107009588684302, 0.0021078948903388095, 0.0021087061802671715, 0.002109443491063663,
+        0.0021101068578767463, 0.0021106963184057263, 0.0021112119128973554, 0.0021116536841423402,
+        0.0021120216774717596, 0.002112315940753387, 0.00211253652438795, 0.002112683481305283,
+        0.0021127568669604127, 0.0021127567393295333, 0.0021126831589059394, 0.0021125361886958145,
+        0.0021123158942139876, 0.002112022343479582, 0.0021116556070115925, 0.0021112157578243355,
+        0.002110702871422897, 0.0021101170257984163, 0.002109458301423333, 0.002108726781246521,
+        0.002107922550688401, 0.0021070456976358728, 0.0021060963124372406, 0.0021050744878970467,
+        0.0021039803192707965, 0.0021028139042596253, 0.002101575343004864, 0.0021002647380825453,
+        0.0020988821944978157, 0.0020974278196792743, 0.0020959017234732174, 0.0020943040181378267,
+        0.002092634818337245, 0.002090894241135625, 0.0020890824059910298, 0.0020871994347492983,
+        0.002085245451637859, 0.0020832205832593876, 0.0020811249585854326, 0.0020789587089499973,
+        0.0020767219680429844, 0.002074414871903567, 0.0020720375589135458, 0.0020695901697905454,
+        0.002067072847581198, 0.0020644857376542433, 0.002061828987693474, 0.0020591027476907497,
+        0.0020563071699388376, 0.002053442409024125, 0.002050508621819432, 0.002047505967476581,
+        0.0020444346074189775, 0.002041294705334111, 0.002038086427165959, 0.0020348099411073147,
+        0.0020314654175921016, 0.0020280530292875174, 0.0020245729510862025, 0.0020210253600982807,
+        0.0020174104356433455, 0.002013728359242358, 0.0020099793146095123, 0.002006163487644011,
+        0.0020022810664217404, 0.0019983322411869183, 0.0019943172043436824, 0.001990236150447548,
+        0.0019860892761968487, 0.0019818767804241234, 0.0019775988640873677, 0.001973255730261268,
+        0.0019688475841284033, 0.0019643746329702496, 0.001959837086158296, 0.001955235155144985,
+        0.001950569053454505, 0.0019458389966737967, 0.0019410452024431594, 0.0019361878904470208,
+        0.0019312672824046064, 0.001926283602060424, 0.0019212370751748352, 0.001916127929514515,
+        0.0019109563948427686, 0.0019057227029099127, 0.0019004270874435243, 0.001895069784138626,
+        0.0018896510306478176, 0.0018841710665714036, 0.0018786301334473491, 0.0018730284747412683,
+        0.0018673663358363183, 0.0018616439640230767, 0.0018558616084892517, 0.0018500195203094438,
+        0.0018441179524348581, 0.00183815715968282, 0.001832137398726381, 0.0018260589280838552,
+        0.001819922008108162, 0.0018137269009762841, 0.0018074738706786156, 0.0018011631830080993,
+        0.001794795105549639, 0.0017883699076691687, 0.0017818878605026884, 0.001775349236945506,
+        0.0017687543116410836, 0.0017621033609700617, 0.0017553966630391988, 0.0017486344976701278,
+        0.0017418171463882302, 0.001734944892411401, 0.0017280180206386724, 0.0017210368176389197,
+        0.0017140015716394941, 0.0017069125725147, 0.001699770111774344, 0.0016925744825521797,
+        0.0016853259795943723, 0.0016780248992477706, 0.0016706715394482698, 0.001663266199709131,
+        0.0016558091811091139, 0.0016483007862807028, 0.001640741319398282, 0.0016331310861661648,
+        0.0016254703938066396, 0.0016177595510480777, 0.0016099988681127064, 0.0016021886567047408,
+        0.001594329229998151, 0.0015864209026244188, 0.0015784639906605388, 0.0015704588116165897,
+        0.0015624056844235155, 0.001554304929420854, 0.0015461568683442766, 0.0015379618243132128,
+        0.0015297201218185026, 0.001521432086709751, 0.0015130980461829833, 0.0015047183287679607,
+        0.0014962932643156337, 0.0014878231839856124, 0.0014793084202332708, 0.0014707493067973388,
+        0.0014621461786870128, 0.0014534993721691104, 0.0014448092247554946, 0.0014360760751901131,
+        0.0014273002634360454, 0.0014184821306628505, 0.0014096220192334078, 0.001400720272691062,
+        0.0013917772357466965, 0.0013827932542655704, 0.0013737686752543562, 0.0013647038468481071,
+        0.0013555991182970465, 0.0013464548399534694, 0.001337271363258676, 0.0013280490407296297,
+        0.0013187882259458406, 0.0013094892735360777, 0.0013001525391651868, 0.001290778379520668,
+        0.0012813671522994231, 0.001271919216194482, 0.0012624349308815001, 0.0012529146570054426,
+        0.0012433587561672273, 0.0012337675909101784, 0.0012241415247066063, 0.0012144809219444461,
+        0.0012047861479135072, 0.0011950575687922314, 0.0011852955516340345, 0.0011755004643536158,
+        0.0011656726757136525, 0.0011558125553109845, 0.0011459204735630706, 0.001135996801694429,
+        0.0011260419117228647, 0.0011160561764458584, 0.0011060399694269598, 0.001095993664981966,
+        0.0010859176381652635, 0.001075812264756172, 0.0010656779212450864, 0.00105551498481979,
+        0.0010453238333516632, 0.0010351048453819908, 0.0010248584001080283, 0.0010145848773692725,
+        0.001004284657633723, 0.0009939581219839152, 0.0009836056521031515, 0.0009732276302617417,
+        0.0009628244393029965, 0.0009523964626294467, 0.0009419440841890659, 0.000931467688461126,
+        0.0009209676604426418, 0.0009104443856343187, 0.0008998982500265212, 0.0008893296400856627,
+        0.0008787389427401446, 0.0008681265453663105, 0.000857492835774838, 0.0008468382021965544,
+        0.0008361630332686125, 0.0008254677180206434, 0.0008147526458606704, 0.0008040182065612654,
+        0.0007932647902456877, 0.0007824927873738035, 0.0007717025887282345, 0.000760894585400497,
+        0.0007500691687769247, 0.0007392267305248401, 0.0007283676625785744, 0.0007174923571256488,
+        0.000706601206592698, 0.0006956946036316236, 0.0006847729411057637, 0.0006738366120758258,
+        0.0006628860097860485, 0.0006519215276503943, 0.0006409435592384841, 0.0006299524982617935,
+        0.0006189487385598709, 0.0006079326740861809, 0.0005969046988945632, 0.0005858652071252304,
+        0.0005748145929907501, 0.0005637532507625434, 0.0005526815747567658, 0.0005415999593205906,
+        0.0005305087988184719, 0.0005194084876181842, 0.0005082994200771125, 0.0004971819905285537,
+        0.0004860565932677927, 0.00047492362253842075, 0.00046378347251868743, 0.0004526365373076032,
+        0.00044148321091133945, 0.00043032388722947525, 0.00041915896004142563, 0.00040798882299260877,
+        0.0003968138695808784, 0.00038563449314297046, 0.0003744510868407238, 0.00036326404364756345,
+        0.00035207375633499436, 0.0003408806174588812, 0.00032968501934598415, 0.0003184873540805376,
+        0.00030728801349045475, 0.00029608738913418624, 0.0002848858722870988, 0.00027368385392786634,
+        0.00026248172472540226, 0.00025127987502516054, 0.0002400786948358676, 0.00022887857381624844,
+        0.0002176799012615347, 0.0002064830660902371, 0.0001952884568309465, 0.0001840964616089102,
+        0.00017290746813288394, 0.00016172186368200323, 0.00015054003509244157, 0.00013936236874433674,
+        0.00012818925054874415, 0.00011702106593437373, 0.00010585819983462786, 9.470103667450129e-05,
+        8.354996035767432e-05, 7.240535425336543e-05, 6.126760118345451e-05, 5.013708340963751e-05,
+        3.901418262036877e-05, 2.7899279918076235e-05, 1.6792755806407804e-05, 5.694990177268111e-06,
+        -5.393637701871444e-06, -1.6472749200603172e-05, -2.7541966338582446e-05, -3.8600911797851146e-05,
+        -4.96492089356356e-05, -6.068648179711078e-05, -7.171235512762116e-05, -8.272645438550835e-05,
+        -9.372840575450275e-05, -0.00010471783615610542, -0.00011569437326217541, -0.00012665764550723695,
+        -0.00013760728210075415, -0.00014854291303960525, -0.00015946416912027776, -0.00017037068195103022,
+        -0.00018126208396424993, -0.00019213800842850842, -0.0002029980894607347, -0.00021384196203819278,
+        -0.0002246692620106818, -0.0002354796261124546, -0.00024627269197410195, -0.000257048098134627,
+        -0.0002678054840532422, -0.0002785444901211269, -0.000289264757673376, -0.00029996592900066743,
+        -0.00031064764736086684, -0.00032130955699097465, -0.00033195130311840546, -0.00034257253197271574,
+        -0.0003531728907972934, -0.0003637520278604978, -0.00037430959246738486, -0.00038484523497099755,
+        -0.0003953586067836319, -0.0004058493603882894, -0.0004163171493498559, -0.00042676162832622753,
+        -0.00043718245307961906, -0.000447579280487599, -0.00045795176855407553, -0.0004682995764204541,
+        -0.0004786223643765085, -0.0004889197938713349, -0.0004991915275241225, -0.000509437229135115,
+        -0.0005196565636962964, -0.0005298491974020335, -0.0005400147976598789, -0.0005501530331011165,
+        -0.000560263573591236, -0.0005703460902405903, -0.0005804002554147807, -0.0005904257427449708,
+        -0.0006004222271383954, -0.0006103893847885736, -0.0006203268931854536, -0.0006302344311258663,
+        -0.0006401116787233424, -0.0006499583174183375, -0.0006597740299883954, -0.0006695585005578065,
+        -0.0006793114146077801, -0.0006890324589862117, -0.0006987213219173989, -0.0007083776930119406,
+        -0.0007180012632763466, -0.0007275917251225926, -0.0007371487723778411, -0.0007466721002938821,
+        -0.0007561614055565216, -0.0007656163862951137, -0.0007750367420918178, -0.0007844221739909176,
+        -0.0007937723845079566, -0.0008030870776390365, -0.0008123659588698567, -0.0008216087351846765,
+        -0.0008308151150754514, -0.0008399848085506821, -0.0008491175271442006, -0.0008582129839241234,
+        -0.0008672708935015087, -0.000876290972038959, -0.0008852729372594744, -0.0008942165084547236,
+        -0.0009031214064936712, -0.0009119873538311361, -0.0009208140745158811, -0.0009296012941991519,
+        -0.0009383487401428387, -0.000947056141227587, -0.0009557232279610387, -0.0009643497324858288,
+        -0.0009729353885874975, -0.0009814799317025525, -0.0009899830989262516, -0.000998444629020347,
+        -0.0010068642624209282, -0.0010152417412460112, -0.0010235768093031705, -0.001031869212096995,
+        -0.0010401186968366795, -0.001048325012443359, -0.0010564879095573865, -0.0010646071405457267,
+        -0.001072682459509082, -0.0010807136222889847, -0.0010887003864749787, -0.0010966425114115564,
+        -0.0011045397582050414, -0.0011123918897305587, -0.0011201986706387936, -0.0011279598673626253,
+        -0.0011356752481240144, -0.0011433445829403372, -0.001150967643631036, -0.0011585442038241807,
+        -0.0011660740389626142, -0.0011735569263104807, -0.0011809926449594017, -0.0011883809758345837,
+        -0.0011957217017010708, -0.001203014607169705, -0.0012102594787030492, -0.0012174561046214096,
+        -0.0012246042751085978, -0.0012317037822176435, -0.0012387544198765995, -0.0012457559838940794,
+        -0.0012527082719648568, -0.00125961108367526, -0.0012664642205086917, -0.0012732674858509172,
+        -0.0012800206849952764, -0.0012867236251479764, -0.0012933761154331697, -0.0012999779668979613,
+        -0.001306528992517468, -0.0013130290071996975, -0.0013194778277903177, -0.0013258752730775907,
+        -0.0013322211637968552, -0.0013385153226352618, -0.0013447575742363918, -0.0013509477452045373,
+        -0.0013570856641092867, -0.0013631711614897814, -0.0013692040698589233, -0.0013751842237076655,
+        -0.001381111459509077, -0.0013869856157223252, -0.0013928065327967413, -0.0013985740531756723,
+        -0.0014042880213002435, -0.0014099482836132018, -0.0014155546885625445, -0.0014211070866051217,
+        -0.0014266053302101234, -0.001432049273862616, -0.0014374387740668712, -0.0014427736893496501,
+        -0.0014480538802634898, -0.001453279209389848, -0.0014584495413421323, -0.0014635647427688001,
+        -0.001468624682356247, -0.00147362923083162, -0.0014785782609657637, -0.0014834716475757472,
+        -0.001488309267527606, -0.0014930909997389545, -0.0014978167251813608, -0.0015024863268828226,
+        -0.0015070996899301885, -0.0015116567014712785, -0.0015161572507172156, -0.0015206012289444447,
+        -0.0015249885294968736, -0.0015293190477877968, -0.0015335926813017662, -0.0015378093295965007,
+        -0.0015419688943045795, -0.0015460712791351024, -0.0015501163898753632, -0.0015541041343923323,
+        -0.001558034422634075, -0.0015619071666312585, -0.0015657222804983054, -0.001569479680434706,
+        -0.0015731792847262275, -0.0015768210137458698, -0.0015804047899549904, -0.0015839305379041903,
+        -0.0015873981842341614, -0.001590807657676524, -0.0015941588890545145, -0.0015974518112836038,
+        -0.0016006863593721085, -0.0016038624704216682, -0.0016069800836276263, -0.0016100391402794401,
+        -0.001613039583760916, -0.0016159813595504177, -0.0016188644152209594, -0.0016216887004403165,
+        -0.001624454166970961, -0.001627160768669964, -0.0016298084614888547, -0.0016323972034733637,
+        -0.0016349269547630883, -0.0016373976775911473, -0.0016398093362836816, -0.0016421618972593172,
+        -0.0016444553290286196, -0.001646689602193324, -0.0016488646894456337, -0.0016509805655674206,
+        -0.0016530372074292522, -0.0016550345939894864, -0.001656972706293212, -0.0016588515274711046,
+        -0.001660671042738278, -0.0016624312393930221, -0.0016641321068154276, -0.0016657736364660515,
+        -0.0016673558218843817, -0.001668878658687322, -0.001670342144567574, -0.0016717462792919413,
+        -0.001673091064699572, -0.0016743765047001152, -0.0016756026052718422, -0.001676769374459655,
+        -0.0016778768223730499, -0.0016789249611839996, -0.001679913805124775, -0.0016808433704856732,
+        -0.0016817136756127184, -0.0016825247409052442, -0.001683276588813428, -0.001683969243835777,
+        -0.0016846027325164996, -0.0016851770834428426, -0.0016856923272423518, -0.0016861484965800505,
+        -0.0016865456261555426, -0.0016868837527001129, -0.0016871629149736464, -0.0016873831537615794,
+        -0.001687544511871736, -0.0016876470341310866, -0.0016876907673824843, -0.0016876757604812756,
+        -0.0016876020642918967, -0.001687469731684361, -0.001687278817530703, -0.0016870293787013496,
+        -0.001686721474061426, -0.001686355164466979, -0.001685930512761157, -0.0016854475837703177,
+        -0.0016849064443000637, -0.0016843071631311968, -0.00168364981101565, -0.0016829344606723053,
+        -0.0016821611867827823, -0.00168133006598714, -0.0016804411768795316, -0.0016794946000037619,
+        -0.0016784904178488388, -0.001677428714844379, -0.0016763095773560316, -0.0016751330936807924,
+        -0.0016738993540422367, -0.0016726084505857585, -0.0016712604773736774, -0.001669855530380292,
+        -0.0016683937074869297, -0.0016668751084768544, -0.0016652998350301545, -0.0016636679907185803,
+        -0.0016619796810002842, -0.0016602350132145169, -0.0016584340965762807, -0.0016565770421708731,
+        -0.0016546639629484198, -0.0016526949737183296, -0.0016506701911436746, -0.0016485897337355172,
+        -0.001646453721847202, -0.0016442622776685525, -0.0016420155252200202, -0.0016397135903467798,
+        -0.0016373566007127887, -0.001634944685794713, -0.0016324779768758888, -0.0016299566070401695,
+        -0.0016273807111657028, -0.0016247504259187151, -0.0016220658897471705, -0.0016193272428743958,
+        -0.0016165346272926716, -0.0016136881867567607, -0.001610788066777304, -0.0016078344146143078,
+        -0.0016048273792704283, -0.0016017671114842919, -0.0015986537637237464, -0.0015954874901790072,
+        -0.0015922684467558081, -0.001588996791068483, -0.0015856726824329702, -0.001582296281859766,
+        -0.001578867752046893, -0.001575387257372691, -0.001571854963888673, -0.001568271039312246,
+        -0.0015646356530194644, -0.0015609489760376096, -0.0015572111810378386, -0.0015534224423277422,
+        -0.0015495829358437882, -0.0015456928391438113, -0.0015417523313994079, -0.0015377615933882599,
+        -0.0015337208074864275, -0.0015296301576606557, -0.0015254898294604561, -0.0015213000100103892,
+        -0.0015170608880020913, -0.0015127726536862888, -0.001508435498864911, -0.0015040496168829483,
+        -0.0014996152026204052, -0.0014951324524841803, -0.0014906015643998331, -0.0014860227378033876,
+        -0.0014813961736330665, -0.0014767220743209307, -0.001472000643784532, -0.001467232087418538,
+        -0.0014624166120862083, -0.001457554426110944, -0.0014526457392677122, -0.0014476907627745052,
+        -0.00144268970928365, -0.001437642792873162, -0.0014325502290380603, -0.0014274122346815403,
+        -0.0014222290281062282, -0.0014170008290053339, -0.0014117278584537406, -0.0014064103388990873,
+        -0.001401048494152861, -0.0013956425493812682, -0.0013901927310963316, -0.0013846992671467508,
+        -0.0013791623867086989, -0.0013735823202768005, -0.0013679592996548658, -0.0013622935579465907,
+        -0.001356585329546417, -0.0013508348501301082, -0.001345042356645434, -0.001339208087302825,
+        -0.0013333322815658802, -0.0013274151801419421, -0.0013214570249726436, -0.0013154580592243032,
+        -0.0013094185272783997, -0.0013033386747220085, -0.0012972187483381052, -0.0012910589960959352,
+        -0.0012848596671413046, -0.00127862101178689, -0.0012723432815024012, -0.001266026728904824,
+        -0.0012596716077486355, -0.0012532781729158561, -0.0012468466804061984, -0.0012403773873272083,
+        -0.0012338705518842052, -0.0012273264333703562, -0.0012207452921567166, -0.0012141273896820392,
+        -0.0012074729884428842, -0.0012007823519834457, -0.0011940557448853228, -0.0011872934327575775,
+        -0.0011804956822263814, -0.0011736627609248652, -0.0011667949374829362, -0.0011598924815169286,
+        -0.0011529556636193578, -0.001145984755348659, -0.0011389800292187658, -0.0011319417586887859,
+        -0.001124870218152663, -0.0011177656829286903, -0.001110628429249122, -0.0011034587342496985,
+        -0.0010962568759592293, -0.0010890231332890015, -0.0010817577860222972, -0.0010744611148039089,
+        -0.0010671334011294702, -0.0010597749273349281, -0.0010523859765859801, -0.0010449668328673502,
+        -0.0010375177809722014, -0.0010300391064915284, -0.001022531095803296, -0.0010149940360619611,
+        -0.0010074282151876425, -0.0009998339218552925, -0.0009922114454841576, -0.0009845610762268218,
+        -0.000976883104958488, -0.0009691778232662328, -0.000961445523438071, -0.0009536864984521882,
+        -0.0009459010419661369, -0.0009380894483058658, -0.0009302520124549044, -0.000922389030043513,
+        -0.0009145007973376844, -0.0009065876112282719, -0.0008986497692201181, -0.0008906875694210047,
+        -0.00088270131053077, -0.0008746912918303076, -0.0008666578131706673, -0.000858601174961955,
+        -0.0008505216781623952, -0.0008424196242673797, -0.0008342953152983454, -0.0008261490537917942,
+        -0.0008179811427883309, -0.0008097918858215022, -0.0008015815869068039, -0.0007933505505307068,
+        -0.0007850990816393778, -0.000776827485627867, -0.0007685360683289115, -0.0007602251360017355,
+        -0.0007518949953212293, -0.0007435459533666264, -0.0007351783176104986, -0.0007267923959077086,
+        -0.0007183884964841891, -0.0007099669279258967, -0.0007015279991677578, -0.0006930720194824362,
+        -0.0006845992984692817, -0.0006761101460432679, -0.0006676048724237572, -0.0006590837881234633,
+        -0.0006505472039372839, -0.0006419954309312617, -0.0006334287804313537, -0.000624847564012359,
+        -0.000616252093486866, -0.0006076426808940157, -0.0005990196384884407, -0.0005903832787292165,
+        -0.0005817339142686289, -0.0005730718579411211, -0.0005643974227522755, -0.0005557109218674858,
+        -0.0005470126686011248, -0.0005383029764053268, -0.0005295821588587785, -0.000520850529655901,
+        -0.0005121084025955465, -0.0005033560915700089, -0.0004945939105540206, -0.0004858221735935695,
+        -0.0004770411947949066, -0.0004682512883135698, -0.00045945276834321756, -0.0004506459491046668,
+        -0.0004418311448349313, -0.0004330086697760941, -0.0004241788381643801, -0.0004153419642191291,
+        -0.00040649836213189954, -0.000397648346055364, -0.0003887922300924205, -0.00037993032828530546,
+        -0.00037106295460452647, -0.0003621904229380033, -0.0003533130470802179, -0.0003444311407211905,
+        -0.00033554501743565267, -0.00032665499067223804, -0.00031776137374249746, -0.00030886447981011764,
+        -0.0002999646218801749, -0.00029106211278808386, -0.00028215726518907654, -0.00027325039154726976,
+        -0.00026434180412487055, -0.00025543181497149326, -0.0002465207359133793, -0.00023760887854276454,
+        -0.00022869655420705172, -0.00021978407399818962, -0.00021087174874207657, -0.0002019598889877818,
+        -0.00019304880499698755, -0.00018413880673344245, -0.0001752302038522455, -0.00016632330568934486,
+        -0.00015741842125107318, -0.00014851585920338715, -0.00013961592786164117, -0.0001307189351799714,
+        -0.00012182518874070795, -0.00011293499574421236, -0.00010404866299822332, -9.516649690754837e-05,
+        -8.628880346375612e-05, -7.74158882346997e-05, -6.854805635425641e-05, -5.968561251209189e-05,
+        -5.0828860943255844e-05, -4.1978105417996235e-05, -3.3133649231597054e-05, -2.4295795194050734e-05,
+        -1.546484561996956e-05, -6.641102318394704e-06, 2.175133417239182e-06, 1.0983560819326567e-05,
+        1.9783879656094e-05, 2.8575790241575895e-05, 3.735899344575398e-05, 4.613319070447661e-05,
+        5.48980840293504e-05, 6.365337601779318e-05, 7.23987698628676e-05, 8.113396936306344e-05,
+        8.985867893236808e-05, 9.857260360979877e-05, 0.00010727544906930696, 0.00011596692162964987,
+        0.00012464672826383196, 0.000133314576609021, 0.0001419701749761143, 0.0001506132323592958,
+        0.00015924345844575092, 0.0001678605636251562, 0.00017646425899913993, 0.00018505425639089545,
+        0.00019363026835457383, 0.00020219200818464138, 0.00021073918992539365, 0.00021927152838024312,
+        0.0002277887391209756, 0.00023629053849715654, 0.0002447766436452976, 0.0002532467724981062,
+        0.0002617006437935804, 0.0002701379770842724, 0.00027855849274632745, 0.0002869619119884904,
+        0.00029534795686125587, 0.0003037163502657987, 0.0003120668159628677, 0.000320399078581821,
+        0.00032871286362944616, 0.0003370078974987136, 0.0003452839074778039, 0.0003535406217586018,
+        0.00036177776944554247, 0.0003699950805644151, 0.0003781922860707376, 0.0003863691178585886,
+        0.00039452530876908073, 0.0004026605925988249, 0.0004107747041085314, 0.00041886737903138783,
+        0.00042693835408139563, 0.00043498736696184515, 0.00044301415637357103, 0.000451018462023162,
+        0.0004590000246313103, 0.0004669585859409161, 0.00047489388872527555, 0.0004828056767960925,
+        0.0004906936950116624, 0.0004985576892848198, 0.0005063974065908501, 0.0005142125949755299,
+        0.0005220030035629505, 0.0005297683825632914, 0.0005375084832807307, 0.0005452230581211315,
+        0.0005529118605996566, 0.0005605746453486456, 0.0005682111681249677, 0.0005758211858176949,
+        0.0005834044564557391, 0.0005909607392150701, 0.000598489794426348, 0.0006059913835822169,
+        0.0006134652693445844, 0.0006209112155520156, 0.0006283289872268974, 0.0006357183505825826,
+        0.0006430790730306304, 0.000650410923187841, 0.0006577136708832384, 0.000664987087165181,
+        0.0006722309443082359, 0.000679445015820026, 0.000686629076448188, 0.000693782902187083,
+        0.0007009062702845845, 0.0007079989592487035, 0.0007150607488543382, 0.0007220914201498112,
+        0.000729090755463371, 0.0007360585384097854, 0.0007429945538967281, 0.000749898588131129,
+        0.0007567704286256091, 0.0007636098642047235, 0.0007704166850111277, 0.0007771906825119582,
+        0.0007839316495047333, 0.0007906393801235558, 0.0007973136698452215, 0.0008039543154949979,
+        0.0008105611152527245, 0.0008171338686586189, 0.0008236723766190513, 0.0008301764414124033,
+        0.000836645866694735, 0.0008430804575053986, 0.0008494800202727394, 0.0008558443628196019,
+        0.0008621732943687791, 0.0008684666255485545, 0.0008747241683980335, 0.0008809457363725043,
+        0.0008871311443486599, 0.0008932802086299247, 0.0008993927469515809, 0.0009054685784858288,
+        0.0009115075238469615, 0.0009175094050963155, 0.0009234740457471753, 0.0009294012707697772,
+        0.0009352909065960941, 0.0009411427811245662, 0.0009469567237250022, 0.0009527325652430538,
+        0.0009584701380049278, 0.0009641692758220412, 0.0009698298139953369, 0.0009754515893198953,
+        0.0009810344400892658, 0.0009865782060997817, 0.0009920827286549036, 0.000997547850569419,
+        0.0010029734161735647, 0.0010083592713172214, 0.0010137052633739315, 0.0010190112412448507,
+        0.0010242770553627741, 0.00102950255769597, 0.0010346876017520274, 0.0010398320425815703,
+        0.0010449357367820554, 0.0010499985425013883, 0.0010550203194414729, 0.0010600009288618348,
+        0.0010649402335830642, 0.0010698380979901956, 0.001074694388036153, 0.0010795089712450213,
+        0.0010842817167152612, 0.0010890124951229655, 0.0010937011787249633, 0.0010983476413618444,
+        0.0011029517584611114, 0.0011075134070399782, 0.0011120324657083434, 0.0011165088146717081,
+        0.0011209423357337945, 0.0011253329122994176, 0.0011296804293770828, 0.0011339847735815814,
+        0.0011382458331365846, 0.0011424634978771271, 0.0011466376592519799, 0.0011507682103261082,
+        0.0011548550457829404, 0.0011588980619265967, 0.001162897156684147, 0.0011668522296077082,
+        0.0011707631818765494, 0.0011746299162990596, 0.0011784523373147698, 0.0011822303509962393,
+        0.0011859638650508508, 0.0011896527888226674, 0.0011932970332941186, 0.001196896511087643,
+        0.0012004511364673482, 0.0012039608253405418, 0.001207425495259162, 0.0012108450654213304,
+        0.0012142194566725894, 0.0012175485915072817, 0.0012208323940698432, 0.0012240707901558913,
+        0.0012272637072134533, 0.0012304110743440017, 0.0012335128223034592, 0.001236568883503198,
+        0.0012395791920109156, 0.0012425436835514466, 0.001245462295507601, 0.0012483349669208616,
+        0.0012511616384920132, 0.001253942252581809, 0.001256676753211479, 0.001259365086063228,
+        0.0012620071984806428, 0.0012646030394691027, 0.0012671525596960741, 0.0012696557114913285,
+        0.0012721124488471955, 0.0012745227274186665, 0.001276886504523458, 0.0012792037391420692,
+        0.0012814743919177213, 0.001283698425156232, 0.0012858758028259378, 0.0012880064905573894,
+        0.0012900904556431196, 0.0012921276670373606, 0.001294118095355558, 0.001296061712873988,
+        0.0012979584935292675, 0.0012998084129177373, 0.0013016114482948813, 0.0013033675785746482,
+        0.0013050767843286863, 0.0013067390477855955, 0.0013083543528300432, 0.0013099226850018676,
+        0.0013114440314951207, 0.0013129183811570377, 0.0013143457244869552, 0.0013157260536351973,
+        0.0013170593624018526, 0.0013183456462355525, 0.0013195849022321375, 0.001320777129133318,
+        0.0013219223273252517, 0.0013230204988370533, 0.0013240716473392775, 0.001325075778142325,
+        0.0013260328981947922, 0.0013269430160817851, 0.0013278061420231553, 0.0013286222878716742,
+        0.0013293914671112033, 0.0013301136948547234, 0.001330788987842384, 0.0013314173644394787,
+        0.0013319988446343178, 0.0013325334500361272, 0.0013330212038728199, 0.0013334621309887527,
+        0.0013338562578424145, 0.0013342036125040687, 0.001334504224653327, 0.0013347581255766792,
+        0.0013349653481649726, 0.0013351259269108258, 0.0013352398979059954, 0.0013353072988386934,
+        0.0013353281689908322, 0.0013353025492352417, 0.0013352304820328186, 0.0013351120114296225,
+        0.0013349471830539142, 0.00133473604411317, 0.001334478643390998, 0.0013341750312440281,
+        0.0013338252595987614, 0.0013334293819483385, 0.001332987453349282, 0.0013324995304181632,
+        0.001331965671328227, 0.0013313859358059792, 0.0013307603851277082, 0.0013300890821159397,
+        0.0013293720911358843, 0.0013286094780917866, 0.00132780131042325, 0.0013269476571015164,
+        0.0013260485886256634, 0.0013251041770187951, 0.001324114495824146, 0.0013230796201011514,
+        0.0013219996264214713, 0.0013208745928649493, 0.0013197045990155437, 0.0013184897259571873,
+        0.0013172300562696224, 0.0013159256740241576, 0.0013145766647794017, 0.001313183115576944,
+        0.001311745114936967, 0.0013102627528538374, 0.00130873612079164, 0.0013071653116796527,
+        0.0013055504199077838, 0.0013038915413219783, 0.0013021887732195402, 0.0013004422143444302,
+        0.0012986519648825411, 0.0012968181264568631, 0.0012949408021226618, 0.0012930200963625965,
+        0.0012910561150817647, 0.001289048965602742, 0.0012869987566605282, 0.001284905598397524,
+        0.0012827696023583644, 0.0012805908814847852, 0.0012783695501104228, 0.0012761057239555527,
+        0.0012737995201217943, 0.0012714510570867956, 0.0012690604546988261, 0.0012666278341713635,
+        0.0012641533180776578, 0.0012616370303451536, 0.0012590790962500112, 0.0012564796424114778,
+        0.0012538387967862231, 0.0012511566886627364, 0.0012484334486555341, 0.0012456692086994293,
+        0.001242864102043739, 0.001240018263246424, 0.001237131828168194, 0.0012342049339666293,
+        0.0012312377190901553, 0.0012282303232720795, 0.0012251828875245513, 0.0012220955541324347,
+        0.0012189684666472297, 0.0012158017698808857, 0.001212595609899621, 0.0012093501340176584,
+        0.0012060654907909566, 0.0012027418300109194, 0.001199379302698001, 0.0011959780610953387,
+        0.0011925382586623367, 0.001189060050068173, 0.0011855435911853065, 0.001181989039082974,
+        0.00117839655202056, 0.0011747662894410085, 0.0011710984119642244, 0.00116739308138029,
+        0.0011636504606428615, 0.001159870713862365, 0.0011560540062991644, 0.0011522005043568513,
+        0.001148310375575299, 0.0011443837886238035, 0.0011404209132941901, 0.0011364219204938175,
+        0.001132386982238607, 0.0011283162716460493, 0.001124209962928106, 0.0011200682313841459,
+        0.0011158912533938596, 0.0011116792064100583, 0.0011074322689515292, 0.0011031506205958085,
+        0.0010988344419719828, 0.0010944839147533584, 0.0010900992216501789, 0.0010856805464023475,
+        0.001081228073771984, 0.0010767419895360783, 0.0010722224804791023, 0.0010676697343854965,
+        0.0010630839400322218, 0.0010584652871813123, 0.0010538139665722149, 0.0010491301699143678,
+        0.0010444140898795606, 0.001039665920094249, 0.0010348858551320552, 0.0010300740905059835,
+        0.0010252308226607722, 0.0010203562489652068, 0.001015450567704311, 0.0010105139780716135,
+        0.001005546680161382, 0.0010005488749607419, 0.0009955207643418669, 0.0009904625510541419,
+        0.0009853744387162107, 0.000980256631808105, 0.0009751093356632819, 0.0009699327564607153,
+        0.0009647271012168377, 0.0009594925777775743, 0.0009542293948103555, 0.0009489377617959875,
+        0.0009436178890206283, 0.0009382699875677297, 0.0009328942693098522, 0.0009274909469005709,
+        0.0009220602337663724, 0.0009166023440983521, 0.0009111174928441763, 0.0009056058956998054,
+        0.0009000677691011815, 0.0008945033302161382, 0.0008889127969360583, 0.000883296387867511,
+        0.0008776543223241177, 0.0008719868203180868, 0.0008662941025519473, 0.0008605763904102082,
+        0.000854833905950934, 0.0008490668718973875, 0.0008432755116296691, 0.0008374600491762256,
+        0.0008316207092054559, 0.000825757717017302, 0.0008198712985347127, 0.000813961680295216,
+        0.0008080290894424101, 0.0008020737537175063, 0.00079609590145074, 0.0007900957615528757,
+        0.000784073563506709, 0.0007780295373584305, 0.0007719639137090983, 0.000765876923706103,
+        0.0007597687990344968, 0.0007536397719084299, 0.0007474900750625995, 0.0007413199417434725,
+        0.0007351296057008325, 0.0007289193011790577, 0.0007226892629083808, 0.0007164397260964158,
+        0.0007101709264193303, 0.0007038831000132227, 0.0006975764834654804, 0.0006912513138059972,
+        0.0006849078284985137, 0.0006785462654319569, 0.0006721668629116213, 0.0006657698596505079,
+        0.0006593554947606233, 0.0006529240077441653, 0.0006464756384848308, 0.0006400106272390439,
+        0.0006335292146272634, 0.0006270316416251298, 0.0006205181495547576, 0.0006139889800760048,
+        0.0006074443751776165, 0.0006008845771684934, 0.0005943098286689637, 0.0005877203726019105,
+        0.000581116452184029, 0.0005744983109171132, 0.0005678661925790868, 0.000561220341215426,
+        0.000554561001130266, 0.0005478884168775232, 0.0005412028332523002, 0.0005345044952819251,
+        0.0005277936482172164, 0.0005210705375237396, 0.0005143354088729196, 0.0005075885081332942,
+        0.0005008300813617724, 0.0004940603747947466, 0.0004872796348393572, 0.000480488108064749,
+        0.0004736860411931917, 0.0004668736810913507, 0.0004600512747615535, 0.00045321906933291895,
+        0.0004463773120526465, 0.0004395262502772245, 0.00043266613146371796, 0.0004257972031609244,
+        0.0004189197130006591, 0.0004120339086890592, 0.00040514003799773583, 0.0003982383487550915,
+        0.0003913290888376292, 0.0003844125061611314, 0.0003774888486719866, 0.0003705583643385449,
+        0.0003636213011422264, 0.0003566779070690375, 0.0003497284301007848, 0.00034277311820628677,
+        0.0003358122193329187, 0.00032884598139776066, 0.0003218746522790085, 0.000314898479807375,
+        0.0003079177117573507, 0.0003009325958386286, 0.00029394337968752785, 0.00028695031085829354,
+        0.00027995363681454375, 0.0002729536049207351, 0.00026595046243348564, 0.0002589444564930813,
+        0.0002519358341148839, 0.0002449248421808643, 0.00023791172743097154, 0.00023089673645466932,
+        0.00022388011568248326, 0.0002168621113774124, 0.00020984296962650624, 0.00020282293633245066,
+        0.0001958022572050234, 0.00018878117775271037, 0.00018175994327435536, 0.00017473879885057257,
+        0.0001677179893355776, 0.0001606977593487147, 0.00015367835326599952, 0.00014666001521199834,
+        0.00013964298905131856, 0.0001326275183803705, 0.00012561384651912677, 0.00011860221650274898,
+        0.00011159287107338318, 0.00010458605267197307, 9.758200342993907e-05, 9.05809651610283e-05,
+        8.358317935318292e-05, 7.658888716027496e-05, 6.959832939401282e-05, 6.261174651586647e-05,
+        5.562937862886169e-05, 4.865146546956139e-05, 4.167824639996745e-05, 3.470996039954273e-05,
+        2.7746846057086608e-05, 2.0789141562782564e-05, 1.3837084700266562e-05, 6.89091283856648e-06,
+        -4.9137075785927725e-08, -6.982828526621039e-06, -1.3909925436125906e-05, -2.0830192172665397e-05,
+        -2.774339355856376e-05, -3.464929487811433e-05, -4.1547661885163906e-05, -4.8438260810992384e-05,
+        -5.532085837216674e-05, -6.219522177805544e-05, -6.906111873871814e-05, -7.591831747252026e-05,
+        -8.276658671374058e-05, -8.960569572030234e-05, -9.643541428133037e-05, -0.00010325551272468121,
+        -0.00011006576192459884, -0.00011686593330919441, -0.00012365579886789685, -0.0001304351311590309,
+        -0.00013720370331719926, -0.00014396128906073817, -0.00015070766269904669, -0.00015744259914005307,
+        -0.00016416587389750484, -0.0001708772630982331, -0.00017757654348953195, -0.0001842634924463679,
+        -0.00019093788797855567, -0.0001975995087380539, -0.00020424813402608575, -0.00021088354380021274,
+        -0.00021750551868162329, -0.00022411383996200296, -0.00023070828961068007, -0.0002372886502817431,
+        -0.00024385470532081987, -0.00025040623877221733, -0.00025694303538577795, -0.00026346488062373494,
+        -0.0002699715606676703, -0.00027646286242530147, -0.00028293857353723707, -0.0002893984823838406,
+        -0.00029584237809192234, -0.00030227005054139815, -0.0003086812903720562, -0.0003150758889901331,
+        -0.0003214536385749574, -0.00032781433208545603, -0.00033415776326679294, -0.00034048372665682774,
+        -0.0003467920175925418, -0.0003530824322165747, -0.00035935476748357825, -0.0003656088211665444,
+        -0.00037184439186323357, -0.0003780612790024309, -0.0003842592828501668, -0.000390438204516039,
+        -0.00039659784595936457, -0.00040273800999527894, -0.00040885850030103005, -0.0004149591214218715,
+        -0.0004210396787772009, -0.00042709997866667386, -0.00043313982827598656, -0.0004391590356829824,
+        -0.0004451574098635048, -0.0004511347606972195, -0.0004570908989735369, -0.0004630256363973646,
+        -0.0004689387855948217, -0.00047483016011904864, -0.0004806995744558444, -0.0004865468440292691,
+        -0.0004923717852073392, -0.0004981742153075394, -0.0005039539526023775, -0.0005097108163248212,
+        -0.000515444626673841, -0.0005211552048197732, -0.0005268423729096599, -0.0005325059540726688,
+        -0.0005381457724253406, -0.0005437616530768128, -0.0005493534221341172, -0.0005549209067073139,
+        -0.0005604639349145882, -0.0005659823358874331, -0.0005714759397756634, -0.0005769445777523928,
+        -0.0005823880820190882, -0.0005878062858104545, -0.000593199023399358, -0.0005985661301016204,
+        -0.0006039074422809192, -0.0006092227973535133, -0.000614512033792935, -0.0006197749911347635,
+        -0.0006250115099812223, -0.0006302214320057561, -0.0006354045999576604, -0.0006405608576665803,
+        -0.0006456900500469259, -0.0006507920231024542, -0.0006558666239304962, -0.0006609137007263934,
+        -0.000665933102787874, -0.0006709246805191643, -0.0006758882854353463, -0.0006808237701664932,
+        -0.0006857309884617665, -0.0006906097951935906, -0.0006954600463616634, -0.0007002815990969359,
+        -0.000705074311665641, -0.0007098380434731915, -0.0007145726550680096, -0.0007192780081454444,
+        -0.0007239539655515226, -0.0007286003912866587, -0.000733217150509431, -0.0007378041095401981,
+        -0.0007423611358647298, -0.0007468880981377335, -0.0007513848661864549, -0.0007558513110141167,
+        -0.0007602873048033331, -0.0007646927209195589, -0.0007690674339144149, -0.0007734113195289489,
+        -0.000777724254696968, -0.0007820061175482146, -0.0007862567874114939, -0.0007904761448179204,
+        -0.000794664071503844, -0.0007988204504139659, -0.0008029451657043792, -0.0008070381027453752,
+        -0.0008110991481244584, -0.0008151281896491629, -0.0008191251163498158, -0.0008230898184823724,
+        -0.0008270221875310949, -0.0008309221162111916, -0.0008347894984715086, -0.0008386242294970662,
+        -0.0008424262057115749, -0.0008461953247799756, -0.0008499314856108412, -0.0008536345883587959,
+        -0.0008573045344268171, -0.0008609412264686017, -0.0008645445683908026, -0.000868114465355194,
+        -0.0008716508237809085, -0.0008751535513465195, -0.0008786225569920905, -0.0008820577509212573,
+        -0.0008854590446031706, -0.0008888263507744094, -0.0008921595834409556, -0.0008954586578799269,
+        -0.0008987234906414228, -0.0009019539995503203, -0.0009051501037078724, -0.0009083117234934525,
+        -0.0009114387805661304, -0.0009145311978662116, -0.0009175888996168073, -0.0009206118113252717,
+        -0.000923599859784607, -0.0009265529730748902, -0.0009294710805645714, -0.0009323541129117489,
+        -0.000935202002065437, -0.000938014681266752, -0.000940792085050013, -0.000943534149243908,
+        -0.0009462408109724991, -0.0009489120086562513, -0.0009515476820129578, -0.0009541477720587016,
+        -0.000956712221108692, -0.0009592409727780685, -0.0009617339719827182, -0.0009641911649399729,
+        -0.0009666124991692829, -0.0009689979234928709, -0.0009713473880363282, -0.0009736608442291016,
+        -0.0009759382448050748, -0.0009781795438029359, -0.0009803846965666058, -0.00098255365974565,
+        -0.000984686391295484, -0.0009867828504777339, -0.000988842997860407, -0.0009908667953180512,
+        -0.0009928542060319244, -0.0009948051944900465, -0.0009967197264872257, -0.0009985977691250706,
+        -0.0010004392908119209, -0.0010022442612627283, -0.0010040126514989339, -0.0010057444338482536,
+        -0.0010074395819444572, -0.00100909807072704, -0.0010107198764409437, -0.0010123049766361475,
+        -0.0010138533501672371, -0.0010153649771929582, -0.0010168398391757036, -0.0010182779188809164,
+        -0.0010196792003765358, -0.0010210436690323153, -0.0010223713115191267, -0.001023662115808248,
+        -0.0010249160711705398, -0.0010261331681756377, -0.0010273133986910842, -0.0010284567558813888,
+        -0.001029563234207077, -0.0010306328294236791, -0.0010316655385806666, -0.001032661360020376,
+        -0.0010336202933768457, -0.0010345423395746274, -0.001035427500827582, -0.0010362757806375784,
+        -0.0010370871837931755, -0.0010378617163682756, -0.0010385993857207007, -0.001039300200490756,
+        -0.0010399641705997182, -0.0010405913072483132, -0.0010411816229151296, -0.0010417351313549807,
+        -0.0010422518475972596, -0.0010427317879442108, -0.0010431749699691736, -0.0010435814125147977,
+        -0.0010439511356911957, -0.0010442841608740508, -0.0010445805107027032, -0.0010448402090781775,
+        -0.001045063281161166, -0.00104524975336998, -0.0010453996533784623, -0.0010455130101138168,
+        -0.0010455898537544788, -0.0010456302157278406, -0.0010456341287080298, -0.0010456016266135704,
+        -0.0010455327446050604, -0.001045427519082771, -0.0010452859876842178, -0.0010451081892816894,
+        -0.0010448941639797373, -0.0010446439531126225, -0.0010443575992417165, -0.0010440351461528745,
+        -0.0010436766388537517, -0.0010432821235710963, -0.0010428516477479811, -0.0010423852600410215,
+        -0.0010418830103175248, -0.0010413449496526291, -0.0010407711303263786, -0.0010401616058207587,
+        -0.001039516430816723, -0.0010388356611911447, -0.0010381193540137334, -0.0010373675675439532,
+        -0.0010365803612278322, -0.0010357577956948093, -0.001034899932754482, -0.0010340068353933334,
+        -0.0010330785677714538, -0.001032115195219178, -0.0010311167842336944, -0.001030083402475655,
+        -0.0010290151187656927, -0.0010279120030809233, -0.0010267741265514415, -0.001025601561456726,
+        -0.0010243943812220345, -0.001023152660414777, -0.00102187647474082, -0.001020565901040775,
+        -0.0010192210172862387, -0.0010178419025760265, -0.0010164286371323122, -0.0010149813022967916,
+        -0.0010134999805267821, -0.0010119847553912839, -0.0010104357115670063, -0.0010088529348343896,
+        -0.0010072365120735321, -0.001005586531260142, -0.0010039030814614244, -0.0010021862528319296,
+        -0.0010004361366093846, -0.000998652825110491, -0.0009968364117266457, -0.00099498699091971,
+        -0.0009931046582176682, -0.000991189510210266, -0.0009892416445446827, -0.0009872611599210668,
+        -0.000985248156088119, -0.0009832027338386147, -0.000981124995004877, -0.000979015042454247,
+        -0.0009768729800845135, -0.0009746989128192941, -0.0009724929466033988, -0.0009702551883981759,
+        -0.0009679857461767853, -0.0009656847289194843, -0.0009633522466088544, -0.0009609884102250295,
+        -0.0009585933317408364, -0.0009561671241169577, -0.0009537099012970577, -0.0009512217782028455,
+        -0.0009487028707291379, -0.0009461532957389023, -0.0009435731710582193, -0.000940962615471272,
+        -0.0009383217487152996, -0.0009356506914754483, -0.0009329495653797185, -0.0009302184929937971,
+        -0.0009274575978158341, -0.0009246670042713208, -0.0009218468377077908, -0.0009189972243895847,
+        -0.0009161182914925853, -0.0009132101670988634, -0.0009102729801913593, -0.0009073068606485362,
+        -0.0009043119392389386, -0.0009012883476158069, -0.0008982362183116377, -0.0008951556847326868,
+        -0.0008920468811534831, -0.0008889099427113011, -0.0008857450054006482, -0.0008825522060676369,
+        -0.0008793316824044129, -0.0008760835729435632, -0.0008728080170524128, -0.0008695051549273915,
+        -0.0008661751275883468, -0.0008628180768727989, -0.0008594341454302085, -0.0008560234767162485,
+        -0.0008525862149869356, -0.0008491225052929162, -0.0008456324934735878, -0.0008421163261512045,
+        -0.0008385741507250964, -0.0008350061153656845, -0.0008314123690086002, -0.0008277930613487568,
+        -0.0008241483428343486, -0.0008204783646608864, -0.0008167832787652279, -0.0008130632378194886,
+        -0.0008093183952250334, -0.0008055489051064374, -0.0008017549223053491, -0.00079793660237441,
+        -0.0007940941015711599, -0.0007902275768518468, -0.0007863371858652951, -0.0007824230869467139,
+        -0.0007784854391115291, -0.0007745244020491239, -0.0007705401361166204, -0.0007665328023326657,
+        -0.0007625025623711081, -0.0007584495785547312, -0.0007543740138489843, -0.0007502760318556131,
+        -0.0007461557968063408, -0.0007420134735565646, -0.000737849227578879, -0.0007336632249568144,
+        -0.0007294556323783818, -0.0007252266171296104, -0.000720976347088226, -0.0007167049907171195,
+        -0.0007124127170579132, -0.0007080996957245257, -0.0007037660968966224, -0.0006994120913131502,
+        -0.0006950378502658434, -0.0006906435455926471, -0.0006862293496711999, -0.0006817954354123171,
+        -0.0006773419762533486, -0.0006728691461516918, -0.0006683771195780883, -0.0006638660715101388,
+        -0.00065933617742565, -0.0006547876132959252, -0.0006502205555792787, -0.0006456351812142635,
+        -0.0006410316676130698, -0.0006364101926548731, -0.000631770934679099, -0.000627114072478772,
+        -0.0006224397852938417, -0.0006177482528044181, -0.0006130396551240857, -0.0006083141727932063,
+        -0.0006035719867721292, -0.0005988132784344823, -0.0005940382295604082, -0.0005892470223298478,
+        -0.0005844398393157048, -0.0005796168634771107, -0.0005747782781526721, -0.0005699242670536213,
+        -0.0005650550142570497, -0.0005601707041991507, -0.0005552715216683342, -0.0005503576517984547,
+        -0.0005454292800620229, -0.0005404865922633175, -0.0005355297745315844, -0.0005305590133142541,
+        -0.0005255744953699737, -0.000520576407761913, -0.0005155649378508469, -0.000510540273288235,
+        -0.0005055026020095174, -0.0005004521122271285, -0.0004953889924236791, -0.0004903134313451271,
+        -0.00048522561799383817, -0.00048012574162175346, -0.0004750139917235497, -0.00046989055802969156,
+        -0.00046475563049959634, -0.0004596093993147826, -0.0004544520548719285, -0.0004492837877760317,
+        -0.00044410478883350624, -0.00043891524904535255, -0.0004337153596001923, -0.0004285053118674352,
+        -0.0004232852973904221, -0.00041805550787947705, -0.0004128161352050584, -0.000407567371390917,
+        -0.00040230940860714275, -0.00039704243916332204, -0.0003917666555017126, -0.0003864822501902347,
+        -0.00038118941591575283, -0.00037588834547713757, -0.0003705792317783218, -0.00036526226782159567,
+        -0.0003599376467006086, -0.00035460556159356193, -0.00034926620575639086, -0.00034391977251583563,
+        -0.0003385664552626356, -0.0003332064474447201, -0.00032783994256029903, -0.00032246713415106087,
+        -0.00031708821579538556, -0.0003117033811014453, -0.0003063128237004334, -0.0003009167372397281,
+        -0.0002955153153761376, -0.0002901087517690214, -0.0002846972400735334, -0.00027928097393387416,
+        -0.00027386014697643293, -0.0002684349528030499, -0.0002630055849842917, -0.0002575722370526098,
+        -0.000252135102495633, -0.00024669437474947304, -0.0002412502471918467, -0.00023580291313551763,
+        -0.0002303525658215004, -0.0002248993984122741, -0.0002194436039852369, -0.0002139853755259361,
+        -0.0002085249059213123, -0.00020306238795319363, -0.00019759801429149255, -0.00019213197748760606,
+        -0.00018666446996781384, -0.0001811956840265719, -0.00017572581181992924, -0.0001702550453589623,
+        -0.00016478357650309683, -0.00015931159695356422, -0.0001538392982468665, -0.00014836687174813677,
+        -0.00014289450864464254, -0.0001374223999392225, -0.00013195073644381637, -0.00012647970877287327,
+        -0.00012100950733689485, -0.0001155403223359897, -0.00011007234375332298, -0.00010460576134869931,
+        -9.91407646521589e-05, -9.367754295746941e-05, -8.82162853157505e-05, -8.275718052912718e-05,
+        -7.730041714419936e-05, -7.184618344584267e-05, -6.639466745077479e-05, -6.094605690113776e-05,
+        -5.550053925834409e-05, -5.005830169662454e-05, -4.4619531096789574e-05, -3.918441403999253e-05,
+        -3.375313680139213e-05, -2.8325885343949497e-05, -2.290284531224216e-05, -1.7484202026177065e-05,
+        -1.2070140474838946e-05, -6.660845310356077e-06, -1.256500841665997e-06, 4.142708971569871e-06,
+        9.536600525112154e-06, 1.4924990576192746e-05, 2.0307696249676e-05, 2.5684535044083545e-05,
+        3.1055324837601e-05, 3.641988389418169e-05, 4.177803086951489e-05, 4.7129584816974045e-05,
+        5.2474365193662684e-05, 5.781219186632302e-05, 6.314288511721192e-05, 6.84662656501514e-05,
+        7.37821545962468e-05, 7.90903735198314e-05, 8.439074442438941e-05, 8.968308975821264e-05,
+        9.496723242034604e-05, 0.00010024299576631845, 0.0001055102036138642, 0.00011076868024873929,
+        0.00011601825043039937, 0.00012125873939765695, 0.00012648997287442984, 0.00013171177707535338,
+        0.00013692397871136884, 0.000142126404995406, 0.00014731888364792506, 0.00015250124290243792,
+        0.0001576733115111194, 0.00016283491875026694, 0.00016798589442581583, 0.0001731260688787516,
+        0.00017825527299062578, 0.00018337333818893418, 0.00018848009645247089, 0.00019357538031677024,
+        0.00019865902287941175, 0.00020373085780530016, 0.0002087907193320324, 0.000213838442275128,
+        0.0002188738620332202, 0.00022389681459341061, 0.00022890713653629684, 0.00023390466504121364,
+        0.0002388892378914448, 0.000243860693479175, 0.00024881887081071335, 0.00025376360951150393,
+        0.00025869474983112093, 0.00026361213264835046, 0.00026851559947613327, 0.0002734049924664817,
+        0.0002782801544154755, 0.00028314092876812656, 0.0002879871596232136, 0.00029281869173819393,
+        0.0002976353705339744, 0.000302437042099721, 0.00030722355319757095, 0.00031199475126743596,
+        0.00031675048443166663, 0.0003214906014996939, 0.00032621495197274485, 0.0003309233860484241,
+        0.0003356157546252652, 0.0003402919093073621, 0.00034495170240886233, 0.0003495949869584226,
+        0.00035422161670380537, 0.00035883144611616934, 0.00036342433039455465, 0.0003680001254703289,
+        0.0003725586880113943, 0.00037709987542663205, 0.0003816235458701504, 0.0003861295582455114,
+        0.00039061777221003024, 0.00039508804817894264, 0.0003995402473295383, 0.0004039742316053697,
+        0.00040838986372032665, 0.0004127870071626777, 0.0004171655261991859, 0.0004215252858790879,
+        0.0004258661520380436, 0.00043018799130215967, 0.00043449067109185743, 0.0004387740596257891,
+        0.0004430380259246506, 0.0004472824398150707, 0.0004515071719333703, 0.0004557120937292937,
+        0.0004598970774697981, 0.0004640619962427209, 0.00046820672396041216, 0.0004723311353634275,
+        0.0004764351060240935, 0.0004805185123500354, 0.0004845812315878258, 0.000488623141826346,
+        0.0004926441220003197, 0.0004966440518938045, 0.0005006228121434661, 0.0005045802842420518,
+        0.0005085163505416884, 0.000512430894257155, 0.0005163237994692042, 0.000520194951127774,
+        0.0005240442350551527, 0.0005278715379492099, 0.0005316767473864942, 0.0005354597518253069,
+        0.0005392204406088238, 0.0005429587039680883, 0.0005466744330250292, 0.0005503675197953787,
+        0.0005540378571916648, 0.0005576853390260723, 0.0005613098600132775, 0.0005649113157733261,
+        0.0005684896028344019, 0.0005720446186355427, 0.0005755762615294335, 0.0005790844307850487,
+        0.0005825690265902808, 0.000586029950054648, 0.000589467103211765, 0.0005928803890219377,
+        0.0005962697113747352, 0.0005996349750913461, 0.0006029760859271178, 0.000606292950573933,
+        0.0006095854766625462, 0.0006128535727649765, 0.0006160971483967861, 0.0006193161140193128,
+        0.0006225103810419527, 0.0006256798618243304, 0.0006288244696784329, 0.0006319441188707794,
+        0.0006350387246244833, 0.0006381082031213075, 0.0006411524715036643, 0.0006441714478766291,
+        0.0006471650513098705, 0.0006501332018395311, 0.0006530758204701514, 0.0006559928291764805,
+        0.000658884150905254, 0.0006617497095770068, 0.0006645894300877821, 0.0006674032383107945,
+        0.0006701910610981424, 0.0006729528262823973, 0.0006756884626781701, 0.000678397900083732,
+        0.0006810810692824369, 0.000683737902044241, 0.0006863683311271893, 0.0006889722902787231,
+        0.0006915497142371327, 0.0006941005387328574, 0.0006966247004897616, 0.0006991221372264366,
+        0.0007015927876574045, 0.0007040365914942878, 0.0007064534894469953, 0.0007088434232248261,
+        0.0007112063355375188, 0.0007135421700963403, 0.0007158508716150684, 0.0007181323858109666,
+        0.0007203866594057008, 0.0007226136401262719, 0.0007248132767058608, 0.0007269855188846296,
+        0.0007291303174105547, 0.0007312476240401568, 0.0007333373915392042, 0.0007353995736834247,
+        0.0007374341252591369, 0.0007394410020638286, 0.0007414201609067993, 0.0007433715596096182,
+        0.0007452951570066621, 0.0007471909129456056, 0.0007490587882877829, 0.0007508987449086383,
+        0.0007527107456980606, 0.0007544947545606843, 0.0007562507364162083, 0.0007579786571996227,
+        0.0007596784838614114, 0.0007613501843677586, 0.0007629937277006504, 0.0007646090838580185,
+        0.0007661962238537797, 0.0007677551197178619, 0.0007692857444962512, 0.0007707880722508854,
+        0.0007722620780596121, 0.0007737077380161092, 0.0007751250292296649, 0.0007765139298250677,
+        0.0007778744189423481, 0.0007792064767365317, 0.0007805100843773641, 0.0007817852240489829,
+        0.000783031878949542, 0.0007842500332908374, 0.0007854396722978802, 0.0007866007822084016,
+        0.000787733350272391, 0.000788837364751535, 0.0007899128149186554, 0.0007909596910571058,
+        0.0007919779844601336, 0.0007929676874301979, 0.000793928793278254, 0.0007948612963230296,
+        0.0007957651918902327, 0.0007966404763117262, 0.0007974871469247019, 0.0007983052020707871,
+        0.0007990946410951119, 0.0007998554643453876, 0.0008005876731708947, 0.0008012912699214731,
+        0.0008019662579464678, 0.0008026126415936326, 0.0008032304262080093, 0.000803819618130785,
+        0.0008043802246980748, 0.0008049122542397164, 0.0008054157160780108, 0.0008058906205264146,
+        0.0008063369788882314, 0.0008067548034552442, 0.000807144107506318, 0.000807504905305985,
+        0.0008078372121029805, 0.0008081410441287377, 0.0008084164185958819, 0.0008086633536966562,
+        0.0008088818686013411, 0.0008090719834566153, 0.0008092337193839121, 0.0008093670984777242,
+        0.0008094721438038737, 0.0008095488793977691, 0.0008095973302626121, 0.0008096175223675689,
+        0.0008096094826459342, 0.000809573238993234, 0.0008095088202653166, 0.0008094162562763975,
+        0.0008092955777970859, 0.0008091468165523678, 0.0008089700052195641, 0.0008087651774262583,
+        0.0008085323677481872, 0.0008082716117071006, 0.0008079829457685974, 0.0008076664073399225,
+        0.0008073220347677394, 0.0008069498673358594, 0.0008065499452629619, 0.0008061223097002578,
+        0.000805667002729148, 0.0008051840673588278, 0.0008046735475238796, 0.0008041354880818229,
+        0.0008035699348106477, 0.0008029769344062965, 0.0008023565344801447, 0.0008017087835564226,
+        0.0008010337310696299, 0.0008003314273619051, 0.0007996019236803823, 0.0007988452721744976,
+        0.0007980615258932865, 0.0007972507387826368, 0.0007964129656825282, 0.0007955482623242177,
+        0.0007946566853274374, 0.0007937382921975123, 0.0007927931413224973, 0.0007918212919702607,
+        0.0007908228042855318, 0.000789797739286949, 0.0007887461588640586, 0.000787668125774284,
+        0.0007865637036398867, 0.000785432956944879, 0.0007842759510319228, 0.0007830927520991913,
+        0.0007818834271972156, 0.0007806480442256898, 0.0007793866719302669, 0.0007780993798993107,
+        0.0007767862385606292, 0.000775447319178184, 0.0007740826938487735, 0.0007726924354986823,
+        0.0007712766178803085, 0.0007698353155687751, 0.0007683686039584928, 0.0007668765592597292,
+        0.0007653592584951235, 0.0007638167794961862, 0.0007622492008997814, 0.0007606566021445831,
+        0.00075903906346748, 0.0007573966658999979, 0.0007557294912646853, 0.0007540376221714234,
+        0.0007523211420138069, 0.0007505801349654177, 0.0007488146859761091, 0.0007470248807682772,
+        0.0007452108058330804, 0.000743372548426652, 0.0007415101965663034, 0.0007396238390266588,
+        0.0007377135653358223, 0.0007357794657714921, 0.0007338216313570455, 0.0007318401538576178,
+        0.0007298351257761545, 0.0007278066403494537, 0.0007257547915441483, 0.0007236796740526988,
+        0.000721581383289381, 0.0007194600153861955, 0.0007173156671888024, 0.0007151484362524374,
+        0.0007129584208377604, 0.0007107457199067275, 0.0007085104331184506, 0.0007062526608249721,
+        0.0007039725040670914, 0.0007016700645701502, 0.0006993454447397393, 0.000696998747657499,
+        0.0006946300770768123, 0.0006922395374184636, 0.000689827233766386, 0.0006873932718632742,
+        0.0006849377581062315, 0.0006824607995424156, 0.0006799625038646102, 0.0006774429794068227,
+        0.0006749023351398709, 0.0006723406806668945, 0.00066975812621891, 0.0006671547826503297,
+        0.0006645307614344314, 0.0006618861746588576, 0.0006592211350210665, 0.0006565357558237894,
+        0.0006538301509704409, 0.0006511044349605317, 0.0006483587228850828, 0.0006455931304219723,
+        0.0006428077738313162, 0.0006400027699508202, 0.0006371782361910901, 0.0006343342905309514,
+        0.0006314710515127771, 0.0006285886382377062, 0.0006256871703609873, 0.0006227667680871986,
+        0.0006198275521654534, 0.0006168696438846962, 0.0006138931650688516, 0.0006108982380720438,
+        0.0006078849857738001, 0.000604853531574174, 0.0006018039993889274, 0.0005987365136446873,
+        0.0005956511992740373, 0.0005925481817106481, 0.0005894275868844089, 0.0005862895412164642,
+        0.0005831341716143296, 0.0005799616054669383, 0.0005767719706397206, 0.0005735653954696081,
+        0.0005703420087600791, 0.0005671019397761963, 0.0005638453182395781, 0.0005605722743234096,
+        0.0005572829386474482, 0.0005539774422729586, 0.0005506559166976908, 0.0005473184938508689,
+        0.0005439653060880462, 0.0005405964861861394, 0.0005372121673383002, 0.0005338124831487896,
+        0.0005303975676279691, 0.0005269675551871458, 0.0005235225806334174, 0.000520062779164643,
+        0.0005165882863642303, 0.0005130992381960214, 0.0005095957709991694, 0.0005060780214829312,
+        0.0005025461267215298, 0.0004990002241489997, 0.0004954404515539628, 0.0004918669470744596,
+        0.0004882798491927809, 0.00048467929673021756, 0.000481065428841884, 0.0004774383850114785,
+        0.00047379830504610425, 0.00047014532907098784, 0.0004664795975242651, 0.0004628012511517679,
+        0.0004591104310017355, 0.0004554072784195751, 0.00045169193504263907, 0.00044796454279491196,
+        0.0004442252438817633, 0.0004404741807847181, 0.00043671149625607914, 0.00043293733331376335,
+        0.0004291518352359646, 0.0004253551455558111, 0.0004215474080561873, 0.00041772876676434415,
+        0.0004138993659466307, 0.0004100593501032242, 0.00040620886396276545, 0.000402348052477078,
+        0.000398477060815892, 0.00039459603436146494, 0.00039070511870330324, 0.00038680445963286565,
+        0.0003828942031381955, 0.00037897449539862904, 0.00037504548277945976, 0.0003711073118266491,
+        0.0003671601292614462, 0.0003632040819750936, 0.0003592393170235192, 0.0003552659816219669,
+        0.000351284223139686, 0.0003472941890946343, 0.0003432960271480986, 0.00033928988509939097,
+        0.00033527591088056163, 0.0003312542525509679, 0.00032722505829207196, 0.0003231884764020604,
+        0.00031914465529046246, 0.00031509374347295097, 0.0003110358895659198, 0.0003069712422812003,
+        0.00030289995042077645, 0.0002988221628714085, 0.0002947380285993509, 0.00029064769664507333,
+        0.00028655131611789175, 0.0002824490361906951, 0.0002783410060946717, 0.0002742273751139538,
+        0.0002701082925803509, 0.0002659839078680852, 0.00026185437038845103, 0.0002577198295845656,
+        0.000253580434926069, 0.00024943633590389277, 0.0002452876820249253, 0.00024113462280677834,
+        0.00023697730777255284, 0.00023281588644552483, 0.00022865050834392249, 0.00022448132297571275,
+        0.00022030847983329837, 0.00021613212838831475, 0.00021195241808644175, 0.00020776949834206944,
+        0.00020358351853321424, 0.0001993946279962469, 0.00019520297602063118, 0.00019100871184385724,
+        0.00018681198464614042, 0.00018261294354528153, 0.0001784117375915205, 0.00017420851576230954,
+        0.00017000342695718367, 0.00016579661999263848, 0.00016158824359692716, 0.00015737844640495354,
+        0.0001531673769531767, 0.0001489551836744313, 0.0001447420148928568, 0.00014052801881877955,
+        0.00013631334354365763, 0.00013209813703493934, 0.00012788254713102135, 0.00012366672153621267,
+        0.00011945080781562193, 0.00011523495339014362, 0.00011101930553145398, 0.00010680401135692728,
+        0.00010258921782465179, 9.837507172846837e-05, 9.416171969286832e-05, 8.994930816814144e-05,
+        8.573798342534792e-05, 8.152789155129718e-05, 7.731917844373349e-05, 7.311198980628809e-05,
+        6.890647114359637e-05, 6.47027677564156e-05, 6.050102473665496e-05, 5.6301386962567805e-05,
+        5.210399909380643e-05, 4.790900556658911e-05, 4.371655058888034e-05, 3.952677813549304e-05,
+        3.533983194329481e-05, 3.1155855506426865e-05, 2.6974992071446e-05, 2.2797384632570828e-05,
+        1.8623175926950977e-05, 1.4452508429800094e-05, 1.0285524349783559e-05, 6.122365624229528e-06,
+        1.9631739143541395e-06, -2.1919093993078055e-06, -6.342743221698879e-06, -1.0489186747869568e-05,
+        -1.4631099467604081e-05, -1.876834117012135e-05, -2.290077194867067e-05, -2.7028252205112107e-05,
+        -3.1150642654571504e-05, -3.526780432998958e-05, -3.9379598586655615e-05, -4.34858871068153e-05,
+        -4.758653190416223e-05, -5.168139532837312e-05, -5.577034006956732e-05, -5.985322916284904e-05,
+        -6.392992599274387e-05, -6.800029429761757e-05, -7.206419817416877e-05, -7.612150208181388e-05,
+        -8.017207084705627e-05, -8.421576966792509e-05, -8.825246411831134e-05, -9.228202015227184e-05,
+        -9.630430410846712e-05, -0.000100319182714343, -0.00010432652309048243, -0.00010832619275493826,
+        -0.00011231805962736123, -0.00011630199203334569, -0.00012027785870860883, -0.0001242455288031627,
+        -0.0001282048718855528, -0.0001321557579469926, -0.0001360980574054779, -0.00014003164110996824,
+        -0.00014395638034446444, -0.00014787214683206415, -0.0001517788127390869, -0.00015567625067908108,
+        -0.00015956433371687003, -0.00016344293537252027, -0.0001673119296253876, -0.0001711711909180512,
+        -0.00017502059416023546, -0.00017886001473279024, -0.00018268932849156737, -0.00018650841177127989,
+        -0.00019031714138942072, -0.00019411539465007672, -0.0001979030493477157, -0.00020167998377108775,
+        -0.00020544607670688596, -0.0002092012074435605, -0.00021294525577510978, -0.0002166781020046869,
+        -0.00022039962694834784, -0.0002241097119387794, -0.00022780823882882946, -0.00023149508999523722,
+        -0.00023517014834220174, -0.00023883329730493881, -0.00024248442085329826, -0.00024612340349527544,
+        -0.00024975013028050055, -0.0002533644868037883, -0.00025696635920858054, -0.0002605556341903701,
+        -0.0002641321990001795, -0.00026769594144792963, -0.0002712467499058334, -0.0002747845133117211,
+        -0.0002783091211724258, -0.0002818204635670651, -0.00028531843115030215, -0.00028880291515566344,
+        -0.0002922738073987491, -0.0002957310002804216, -0.0002991743867900526, -0.00030260386050866327,
+        -0.0003060193156120325, -0.00030942064687391324, -0.00031280774966901503, -0.00031618051997612645,
+        -0.000319538854381215, -0.00032288265008033465, -0.0003262118048827233, -0.00032952621721373746,
+        -0.0003328257861177762, -0.00033611041126125815, -0.00033937999293549067, -0.0003426344320595245,
+        -0.0003458736301830481, -0.0003490974894891895, -0.00035230591279728895, -0.0003554988035657232,
+        -0.0003586760658946207, -0.00036183760452859894, -0.00036498332485943393, -0.0003681131329287763,
+        -0.0003712269354307747, -0.0003743246397146759, -0.0003774061537874641, -0.00038047138631640737,
+        -0.0003835202466315781, -0.0003865526447284191, -0.0003895684912702074, -0.00039256769759049456,
+        -0.0003955501756956266, -0.00039851583826706053, -0.00040146459866379565, -0.00040439637092478005,
+        -0.0004073110697711498, -0.000410208610608615, -0.0004130889095297163, -0.0004159518833160565,
+        -0.00041879744944057183, -0.0004216255260697148, -0.00042443603206560466, -0.00042722888698822466,
+        -0.000430004011097518, -0.00043276132535546277, -0.00043550075142818564, -0.0004382222116879797,
+        -0.0004409256292152973, -0.0004436109278007842, -0.0004462780319472062, -0.00044892686687140234,
+        -0.00045155735850615917, -0.000454169433502137, -0.00045676301922969456, -0.000459338043780699,
+        -0.00046189443597036416, -0.00046443212533899567, -0.00046695104215371987, -0.00046945111741023915,
+        -0.00047193228283449367, -0.00047439447088430567, -0.00047683761475107787, -0.00047926164836131855,
+        -0.00048166650637825726, -0.0004840521242034328, -0.0004864184379781393, -0.0004887653845849875,
+        -0.0004910929016493441, -0.0004934009275407649, -0.0004956894013744314, -0.0004979582630125267,
+        -0.0005002074530655732, -0.0005024369128937963, -0.0005046465846084096, -0.0005068364110728711,
+        -0.0005090063359041649, -0.0005111563034739979, -0.0005132862589100021, -0.0005153961480968691,
+        -0.000517485917677529, -0.0005195555150542333, -0.0005216048883896219, -0.0005236339866078035,
+        -0.0005256427593953671, -0.0005276311572023593, -0.0005295991312432845, -0.0005315466334980311,
+        -0.000533473616712759, -0.0005353800344008441, -0.0005372658408436644, -0.0005391309910914613,
+        -0.0005409754409641724, -0.0005427991470521339, -0.0005446020667168816, -0.0005463841580918508,
+        -0.0005481453800830461, -0.0005498856923697329, -0.0005516050554050555, -0.0005533034304166339,
+        -0.0005549807794071563, -0.000556637065154924, -0.0005582722512143592, -0.0005598863019165213,
+        -0.000561479182369557, -0.0005630508584591427, -0.0005646012968488869, -0.000566130464980727,
+        -0.0005676383310752781, -0.0005691248641321425, -0.000570590033930236, -0.0005720338110280472,
+        -0.0005734561667638652, -0.0005748570732560229, -0.0005762365034030695, -0.0005775944308839211,
+        -0.0005789308301580148, -0.0005802456764654073, -0.0005815389458268271, -0.0005828106150437761,
+        -0.0005840606616984941, -0.0005852890641539828, -0.0005864958015539906, -0.0005876808538229068,
+        -0.0005888442016657117, -0.000589985826567848, -0.0005911057107950715, -0.000592203837393292,
+        -0.0005932801901883751, -0.0005943347537859078, -0.000595367513570956, -0.0005963784557077862,
+        -0.0005973675671395503, -0.0005983348355879608, -0.0005992802495529256, -0.0006002037983121684,
+        -0.0006011054719207968, -0.0006019852612108831, -0.0006028431577909789, -0.0006036791540456271,
+        -0.0006044932431348391, -0.0006052854189935475, -0.000606055676331021, -0.0006068040106302798,
+        -0.0006075304181474491, -0.0006082348959111084, -0.0006089174417216231, -0.0006095780541504153,
+        -0.000610216732539231, -0.0006108334769994055, -0.0006114282884110375, -0.0006120011684221978,
+        -0.0006125521194480911, -0.0006130811446701708, -0.0006135882480352661, -0.000614073434254656,
+        -0.0006145367088031177, -0.000614978077917963, -0.0006153975485980499, -0.0006157951286027385,
+        -0.0006161708264508632, -0.0006165246514196488, -0.0006168566135436185, -0.0006171667236134547,
+        -0.0006174549931748643, -0.0006177214345273963, -0.0006179660607232365, -0.0006181888855659877,
+        -0.0006183899236094178, -0.000618569190156173, -0.0006187267012564868, -0.0006188624737068503,
+        -0.0006189765250486558, -0.0006190688735668238, -0.0006191395382884032, -0.0006191885389811366,
+        -0.0006192158961520189, -0.0006192216310458152, -0.0006192057656435625, -0.0006191683226610497,
+        -0.0006191093255472568, -0.0006190287984827981, -0.0006189267663783119, -0.0006188032548728388,
+        -0.0006186582903321893, -0.0006184918998472627, -0.0006183041112323546, -0.0006180949530234443,
+        -0.0006178644544764535, -0.0006176126455654801, -0.0006173395569810077, -0.0006170452201281006,
+        -0.000616729667124569, -0.0006163929307991049, -0.0006160350446894052, -0.000615656043040269,
+        -0.0006152559608016694, -0.0006148348336267999, -0.0006143926978701053, -0.0006139295905852935,
+        -0.0006134455495232994, -0.0006129406131302639, -0.0006124148205454583, -0.0006118682115992003,
+        -0.0006113008268107554, -0.0006107127073861998, -0.0006101038952162645, -0.000609474432874176,
+        -0.000608824363613447, -0.0006081537313656636, -0.000607462580738253, -0.000606750957012214,
+        -0.000606018906139841, -0.0006052664747424183, -0.0006044937101078983, -0.000603700660188551,
+        -0.0006028873735986068, -0.0006020538996118608, -0.0006012002881592651, -0.0006003265898265066,
+        -0.0005994328558515527, -0.0005985191381221832, -0.0005975854891734927, -0.000596631962185397,
+        -0.000595658610980083, -0.0005946654900194719, -0.0005936526544026431, -0.0005926201598632436,
+        -0.0005915680627668748, -0.0005904964201084772, -0.0005894052895096541, -0.0005882947292160319,
+        -0.000587164798094559, -0.0005860155556307927, -0.000584847061926182, -0.0005836593776953232,
+        -0.0005824525642631896, -0.0005812266835623526, -0.0005799817981301793, -0.0005787179711060221,
+        -0.0005774352662283634, -0.0005761337478319709, -0.0005748134808450266, -0.000573474530786227,
+        -0.0005721169637618717, -0.0005707408464629464, -0.0005693462461621697, -0.0005679332307110253,
+        -0.0005665018685368019, -0.0005650522286395599, -0.0005635843805891619, -0.0005620983945222121,
+        -0.0005605943411390015, -0.0005590722917004733, -0.0005575323180251128, -0.00055597449248586,
+        -0.0005543988880070025, -0.0005528055780610287, -0.0005511946366654886, -0.0005495661383798437,
+        -0.0005479201583022638, -0.0005462567720664433, -0.0005445760558384051, -0.0005428780863132523,
+        -0.0005411629407119379, -0.0005394306967780089, -0.000537681432774342, -0.0005359152274798394,
+        -0.0005341321601861402, -0.0005323323106943112, -0.0005305157593114988, -0.0005286825868475931,
+        -0.0005268328746118827, -0.0005249667044096563, -0.000523084158538829, -0.0005211853197865536,
+        -0.0005192702714257683, -0.000517339097211815, -0.0005153918813789747, -0.0005134287086369906,
+        -0.0005114496641676511, -0.0005094548336212589, -0.0005074443031131625, -0.0005054181592202514,
+        -0.0005033764889774197, -0.0005013193798740397, -0.0004992469198504351, -0.0004971591972942946,
+        -0.0004950563010371178, -0.0004929383203506471, -0.0004908053449432455, -0.0004886574649563142,
+        -0.0004864947709606652, -0.0004843173539529118, -0.00048212530535180224, -0.0004799187169945834,
+        -0.0004776976811333544, -0.0004754622904313673, -0.00047321263795935687, -0.00047094881719186263,
+        -0.00046867092200349804, -0.00046637904666524654, -0.00046407328584075706, -0.0004617537345825792,
+        -0.000459420488328433, -0.00045707364289747723, -0.00045471329448648677, -0.0004523395396661518,
+        -0.00044995247537726, -0.0004475521989268784, -0.00044513880798461783, -0.0004427124005787705,
+        -0.0004402730750925058, -0.0004378209302600714, -0.00043535606516291334, -0.00043287857922586105,
+        -0.0004303885722132851, -0.00042788614422520723, -0.00042537139569345236, -0.0004228444273777877,
+        -0.00042030534036200713, -0.0004177542360500659, -0.0004151912161621689, -0.00041261638273089633,
+        -0.0004100298380972493, -0.0004074316849067566, -0.0004048220261055626, -0.0004022009649364658,
+        -0.0003995686049349942, -0.0003969250499254838, -0.0003942704040170865, -0.00039160477159984124,
+        -0.0003889282573407288, -0.0003862409661796424, -0.00038354300332548997, -0.0003808344742521839,
+        -0.0003781154846946168, -0.00037538614064475546, -0.0003726465483475793, -0.000369896814297105,
+        -0.00036713704523240297, -0.00036436734813355474, -0.00036158783021765506, -0.00035879859893482216,
+        -0.0003559997619641304, -0.0003531914272096156, -0.00035037370279625697, -0.00034754669706591346,
+        -0.0003447105185733122, -0.0003418652760819986, -0.00033901107856031896, -0.00033614803517733834,
+        -0.0003332762552988153, -0.00033039584848316517, -0.0003275069244773739, -0.0003246095932129608,
+        -0.0003217039648019403, -0.0003187901495327224, -0.00031586825786607096, -0.0003129384004310637,
+        -0.00031000068802098193, -0.00030705523158926624, -0.00030410214224547505, -0.00030114153125114,
+        -0.000298173510015786, -0.0002951981900928157, -0.0002922156831753968, -0.00028922610109247896,
+        -0.0002862295558046435, -0.0002832261594000569, -0.00028021602409041696, -0.00027719926220683713,
+        -0.0002741759861957921, -0.0002711463086150643, -0.000268110342129629, -0.0002650681995075991,
+        -0.00026201999361617583, -0.0002589658374175321, -0.0002559058439647701, -0.0002528401263978372,
+        -0.00024976879793948417, -0.00024669197189115376, -0.00024360976162893585, -0.0002405222805995268,
+        -0.00023742964231611793, -0.00023433196035436036, -0.00023122934834832358, -0.00022812191998639904,
+        -0.0002250097890072606, -0.00022189306919584903, -0.0002187718743792345, -0.0002156463184226692,
+        -0.0002125165152254975, -0.00020938257871707034, -0.0002062446228528014, -0.0002031027616100539,
+        -0.0001999571089841372, -0.00019680777898429912, -0.00019365488562966045, -0.00019049854294521525,
+        -0.00018733886495783604, -0.00018417596569221686, -0.0001810099591668891, -0.00017784095939023836,
+        -0.00017466908035646125, -0.00017149443604160013, -0.000168317140399539, -0.00016513730735804853,
+        -0.00016195505081475977, -0.00015877048463321558, -0.00015558372263891945, -0.00015239487861532996,
+        -0.00014920406629992443, -0.00014601139938026698, -0.0001428169914900206, -0.00013962095620502518,
+        -0.0001364234070393964, -0.00013322445744151217, -0.00013002422079019048, -0.00012682281039072971,
+        -0.0001236203394709517, -0.00012041692117739366, -0.00011721266857136648, -0.0001140076946250221,
+        -0.00011080211221757112, -0.0001075960341313266, -0.00010438957304786808, -0.0001011828415442065,
+        -9.79759520888879e-05, -9.476901703817362e-05, -9.156214863222876e-05, -8.835545899124883e-05,
+        -8.514906011166596e-05, -8.194306386236005e-05, -7.873758198081212e-05, -7.553272606934207e-05,
+        -7.232860759130854e-05, -6.912533786736448e-05, -6.592302807164487e-05, -6.272178922803207e-05,
+        -5.95217322064308e-05, -5.632296771898315e-05, -5.312560631636256e-05, -4.992975838407697e-05,
+        -4.6735534138712446e-05, -4.3543043624256605e-05, -4.0352396708438326e-05, -3.716370307896371e-05,
+        -3.3977072239945434e-05, -3.079261350819724e-05, -2.7610436009538673e-05, -2.4430648675255656e-05,
+        -2.1253360238388583e-05, -1.8078679230145428e-05, -1.4906713976318213e-05, -1.1737572593641968e-05,
+        -8.571362986233175e-06, -5.4081928420398404e-06, -2.248169629233925e-06, 9.085994073158901e-07,
+        4.062007249559964e-06, 7.21194710984036e-06, 1.0358312434375388e-05, 1.350099690677805e-05,
+        1.6639894451516743e-05, 1.9774899237439886e-05, 2.290590568121985e-05, 2.603280845078441e-05,
+        2.915550246880447e-05, 3.2273882916100414e-05, 3.5387845235035966e-05, 3.849728513296748e-05,
+        4.160209858561236e-05, 4.470218184039641e-05, 4.779743141990192e-05, 5.0887744125120743e-05,
+        5.397301703883864e-05, 5.705314752900417e-05, 6.012803325194276e-05, 6.319757215573732e-05,
+        6.626166248348102e-05, 6.932020277652611e-05, 7.237309187778388e-05, 7.542022893494506e-05,
+        7.846151340368422e-05, 8.149684505092017e-05, 8.452612395799064e-05, 8.754925052381621e-05,
+        9.056612546811527e-05, 9.357664983453636e-05, 9.658072499377798e-05, 9.957825264675863e-05,
+        0.0001025691348276993, 0.00010555327390723246, 0.00010853057259545592, 0.00011150093394504135,
+        0.00011446426135426364, 0.00011742045857001331, 0.0001203694296908609, 0.00012331107917003928,
+        0.00012624531181840913, 0.0001291720328074766, 0.00013209114767232747, 0.00013500256231453964,
+        0.0001379061830051878, 0.00014080191638765735, 0.00014368966948058275, 0.00014656934968076312,
+        0.0001494408647659332, 0.00015230412289768224, 0.00015515903262425503, 0.00015800550288333923,
+        0.00016084344300490306, 0.00016367276271395017, 0.0001664933721332582, 0.00016930518178616646,
+        0.00017210810259927722, 0.00017490204590514852, 0.00017768692344502336, 0.00018046264737148101,
+        0.00018322913025110377, 0.00018598628506709283, 0.0001887340252219308, 0.000191472264539965,
+        0.0001942009172699764, 0.00019691989808778913, 0.00019962912209880074, 0.00020232850484049912,
+        0.0002050179622850149, 0.00020769741084160316, 0.00021036676735909434, 0.00021302594912843203,
+        0.0002156748738850263, 0.00021831345981122388, 0.00022094162553875144, 0.00022355929015102017,
+        0.00022616637318556869, 0.00022876279463638773, 0.00023134847495623383, 0.0002339233350589865,
+        0.00023648729632191854, 0.00023904028058795802, 0.00024158221016798537, 0.00024411300784305234,
+        0.0002466325968665816, 0.0002491409009666123, 0.00025163784434794993, 0.0002541233516943481,
+        0.00025659734817062266, 0.0002590597594248183, 0.0002615105115902947, 0.00026394953128779005,
+        0.00026637674562753283, 0.0002687920822112624, 0.0002711954691342454, 0.0002735868349873154,
+        0.00027596610885884755, 0.0002783332203367077, 0.0002806880995102372, 0.00028303067697216765,
+        0.00028536088382050877, 0.00028767865166047827, 0.0002899839126063509, 0.00029227659928329124,
+        0.0002945566448292233, 0.0002968239828966126, 0.00029907854765426925, 0.00030132027378909675,
+        0.0003035490965078737, 0.00030576495153896913, 0.00030796777513403197, 0.0003101575040697167,
+        0.0003123340756493349, 0.0003144974277044852, 0.0003166474985967179, 0.0003187842272191221,
+        0.00032090755299789617, 0.00032301741589397083, 0.0003251137564044838, 0.00032719651556434396,
+        0.00032926563494776345, 0.00033132105666967176, 0.00033336272338724423, 0.0003353905783013172,
+        0.0003374045651578, 0.000339404628249106, 0.0003413907124155172, 0.00034336276304653025,
+        0.0003453207260822255, 0.0003472645480145729, 0.0003491941758887092, 0.00035110955730425196,
+        0.00035301064041652895, 0.000354897373937827, 0.0003567697071385853, 0.00035862758984861703,
+        0.00036047097245826576, 0.0003622998059195475, 0.0003641140417472996, 0.00036591363202028636,
+        0.00036769852938226433, 0.00036946868704308087, 0.00037122405877970454, 0.000372964598937233,
+        0.00037469026242994453, 0.0003764010047422216, 0.0003780967819295399, 0.00037977755061943544,
+        0.00038144326801236623, 0.00038309389188264287, 0.00038472938057932434, 0.0003863496930270197,
+        0.00038795478872677164, 0.0003895446277568475, 0.00039111917077352026, 0.00039267837901186354,
+        0.0003942222142864888, 0.00039575063899226096, 0.000397263616105025, 0.00039876110918228656,
+        0.0004002430823638576, 0.000401709500372525, 0.0004031603285146562, 0.0004045955326808081,
+        0.00040601507934628656, 0.0004074189355717365, 0.00040880706900365453, 0.0004101794478748994,
+        0.0004115360410052106, 0.0004128768178016642, 0.000414201748259118, 0.00041551080296065945,
+        0.00041680395307801016, 0.000418081170371891, 0.0004193424271924331, 0.0004205876964794782,
+        0.0004218169517629214, 0.00042303016716303306, 0.0004242273173907019, 0.0004254083777477293,
+        0.00042657332412705513, 0.0004277221330129674, 0.0004288547814813199, 0.0004299712471996981,
+        0.0004310715084275624, 0.0004321555440164084, 0.00043322333340986227, 0.0004342748566437679,
+        0.000435310094346275, 0.00043632902773788144, 0.0004373316386314608, 0.0004383179094322645,
+        0.0004392878231379251, 0.0004402413633384143, 0.0004411785142159785, 0.000442099260545083,
+        0.0004430035876923051, 0.00044389148161620956, 0.00044476292886722914, 0.0004456179165874963,
+        0.0004464564325106519, 0.0004472784649616788, 0.00044808400285664126, 0.00044887303570246743,
+        0.00044964555359669424, 0.0004504015472271598, 0.00045114100787172276, 0.00045186392739793177,
+        0.00045257029826267244, 0.00045326011351182085, 0.0004539333667798478, 0.000454590052289411,
+        0.0004552301648509384, 0.000455853699862182, 0.00045646065330773864, 0.00045705102175858293,
+        0.0004576248023715507, 0.0004581819928888074, 0.0004587225916373143, 0.0004592465975282552,
+        0.00045975401005644937, 0.00046024482929974265, 0.00046071905591838787, 0.0004611766911543958,
+        0.00046161773683086146, 0.0004620421953512902, 0.0004624500696988885, 0.0004628413634358271,
+        0.00046321608070251175, 0.00046357422621681076, 0.00046391580527326665, 0.0004642408237423051,
+        0.0004645492880693974, 0.00046484120527422336, 0.0004651165829498147, 0.00046537542926166356,
+        0.0004656177529468282, 0.00046584356331301295, 0.00046605287023762513, 0.00046624568416682075,
+        0.00046642201611452836, 0.000466581877661445, 0.0004667252809540294, 0.00046685223870346603,
+        0.0004669627641846072, 0.0004670568712349088, 0.0004671345742533333, 0.0004671958881992458,
+        0.0004672408285912818, 0.00046726941150620517, 0.00046728165357773994, 0.0004672775719953897,
+        0.0004672571845032351, 0.0004672205093987145, 0.0004671675655313847, 0.0004670983723016659,
+        0.0004670129496595691, 0.00046691131810339923, 0.0004667934986784483, 0.0004666595129756698,
+        0.000466509383130325, 0.0004663431318206253, 0.00046616078226634943, 0.00046596235822744407,
+        0.0004657478840026049, 0.00046551738442784707, 0.00046527088487504845, 0.00046500841125048434,
+        0.0004647299899933399, 0.0004644356480742053, 0.00046412541299355723, 0.00046379931278021946,
+        0.00046345737598981054, 0.0004630996317031664, 0.00046272610952476057, 0.0004623368395810891,
+        0.0004619318525190578, 0.00046151117950433355, 0.0004610748522197001, 0.0004606229028633768,
+        0.0004601553641473366, 0.0004596722692955975, 0.0004591736520425064, 0.0004586595466309942,
+        0.0004581299878108344, 0.0004575850108368652, 0.0004570246514672038, 0.0004564489459614547,
+        0.00045585793107888115, 0.0004552516440765833, 0.00045463012270764686, 0.0004539934052192735,
+        0.0004533415303509126, 0.0004526745373323599, 0.0004519924658818471, 0.00045129535620412674,
+        0.000450583248988518, 0.0004498561854069647, 0.0004491142071120569, 0.0004483573562350537,
+        0.00044758567538387336, 0.0004467992076410936, 0.0004459979965619086, 0.00044518208617209444,
+        0.0004443515209659479, 0.0004435063459042188, 0.00044264660641201955, 0.0004417723483767224,
+        0.00044088361814585594, 0.0004399804625249632, 0.00043906292877546587, 0.0004381310646125141,
+        0.0004371849182028056, 0.00043622453816240454, 0.0004352499735545582, 0.00043426127388746145,
+        0.00043325848911205924, 0.00043224166961979726, 0.0004312108662403607, 0.00043016613023943924,
+        0.0004291075133164245, 0.0004280350676021318, 0.00042694884565650616, 0.00042584890046629093,
+        0.00042473528544271203, 0.0004236080544191418, 0.0004224672616487354, 0.00042131296180207236,
+        0.00042014520996478766, 0.00041896406163517333, 0.00041776957272177627, 0.0004165617995409935,
+        0.0004153407988146542, 0.0004141066276675647, 0.000412859343625072, 0.00041159900461061247,
+        0.00041032566894322635, 0.00040903939533508215, 0.00040774024288899366, 0.00040642827109589814,
+        0.00040510353983235165, 0.0004037661093580081, 0.0004024160403130726, 0.000401053393715751,
+        0.0003996782309597163, 0.0003982906138114989, 0.0003968906044079513, 0.00039547826525363747,
+        0.0003940536592182181, 0.0003926168495338786, 0.0003911678997926778, 0.0003897068739439287,
+        0.00038823383629157205, 0.00038674885149150607, 0.0003852519845489372, 0.00038374330081572425,
+        0.00038222286598768097, 0.00038069074610189705, 0.0003791470075340565, 0.00037759171699570953,
+        0.000376024941531575, 0.0003744467485168103, 0.00037285720565429127, 0.0003712563809718577,
+        0.00036964434281956877, 0.0003680211598669621, 0.00036638690110026636, 0.0003647416358196356,
+        0.0003630854336363811, 0.0003614183644701632, 0.00035974049854619954, 0.00035805190639248024,
+        0.00035635265883690907, 0.000354642827004538, 0.00035292248231471126, 0.00035119169647821266,
+        0.00034945054149446923, 0.00034769908964866217, 0.00034593741350888424, 0.0003441655859232906,
+        0.0003423836800172042, 0.00034059176919025024, 0.0003387899271134844, 0.00033697822772648273,
+        0.00033515674523445393, 0.0003333255541053511, 0.000331484729066938, 0.0003296343451038909,
+        0.0003277744774548733, 0.00032590520160962096, 0.00032402659330598826, 0.0003221387285270225,
+        0.0003202416834980368, 0.00031833553468362967, 0.0003164203587847512, 0.0003144962327357512,
+        0.0003125632337013957, 0.0003106214390739068, 0.00030867092647000725, 0.00030671177372789706,
+        0.00030474405890432415, 0.00030276786027157317, 0.00030078325631444536, 0.00029879032572731844,
+        0.0002967891474111177, 0.0002947798004702863, 0.0002927623642098343, 0.00029073691813227956,
+        0.00028870354193464746, 0.00028666231550546846, 0.00028461331892172504, 0.000282556632445841,
+        0.00028049233652266805, 0.00027842051177642157, 0.00027634123900766136, 0.0002742545991902678,
+        0.0002721606734683706, 0.0002700595431533261, 0.00026795128972065886, 0.0002658359948070293,
+        0.00026371374020717556, 0.00026158460787082755, 0.00025944867989971244, 0.00025730603854444515,
+        0.0002551567662014869, 0.00025300094541009957, 0.00025083865884925047, 0.0002486699893345649,
+        0.0002464950198152724, 0.0002443138333711105, 0.00024212651320927066, 0.00023993314266134127,
+        0.00023773380518020906, 0.00023552858433700469, 0.00023331756381801935, 0.0002311008274216529,
+        0.00022887845905530894, 0.00022665054273233342, 0.0002244171625689555, 0.00022217840278118174,
+        0.00021993434768173567, 0.00021768508167699605, 0.00021543068926389194, 0.00021317125502684077,
+        0.0002109068636346947, 0.00020863759983760945, 0.0002063635484640345, 0.00020408479441761057,
+        0.00020180142267406297, 0.00019951351827819942, 0.00019722116634077864, 0.00019492445203546154,
+        0.0001926234605957581, 0.00019031827731192684, 0.00018800898752792386, 0.00018569567663835422,
+        0.0001833784300853743, 0.00018105733335564743, 0.00017873247197729866, 0.0001764039315168247,
+        0.00017407179757605756, 0.00017173615578910023, 0.0001693970918192953, 0.00016705469135614375,
+        0.00016470904011227187, 0.00016236022382040417, 0.00016000832823028757, 0.00015765343910566633,
+        0.0001552956422212641, 0.0001529350233597161, 0.00015057166830855417, 0.00014820566285719518,
+        0.000145837092793885, 0.00014346604390269148, 0.0001410926019605109, 0.00013871685273399102,
+        0.0001363388819765941, 0.0001339587754255551, 0.00013157661879884677, 0.00012919249779225293,
+        0.00012680649807631053, 0.0001244187052933433, 0.00012202920505449017, 0.00011963808293669124,
+        0.0001172454244797248, 0.00011485131518325363, 0.00011245584050382006, 0.00011005908585190147,
+        0.00010766113658896653, 0.0001052620780244889, 0.00010286199541302015, 0.00010046097395123552,
+        9.80590987750175e-05, 9.565645495648902e-05, 9.325312750110331e-05, 9.084920134473885e-05,
+        8.844476135074807e-05, 8.603989230706531e-05, 8.363467892331827e-05, 8.122920582789541e-05,
+        7.88235575650697e-05, 7.641781859213564e-05, 7.401207327646574e-05, 7.160640589271114e-05,
+        6.9200900619899e-05, 6.679564153853753e-05, 6.439071262783645e-05, 6.198619776279526e-05,
+        5.9582180711386095e-05, 5.7178745131736044e-05, 5.477597456926659e-05, 5.237395245389007e-05,
+        4.997276209721476e-05, 4.7572486689704276e-05, 4.517320929789675e-05, 4.277501286163185e-05,
+        4.037798019123245e-05, 3.79821939647526e-05, 3.5587736725197595e-05, 3.319469087778752e-05,
+        3.080313868717026e-05, 2.8413162274694686e-05, 2.602484361569176e-05, 2.3638264536712363e-05,
+        2.1253506712824567e-05, 1.8870651664919774e-05, 1.6489780756975243e-05, 1.4110975193376962e-05,
+        1.1734316016256782e-05, 9.3598841027523e-06, 6.987760162412991e-06, 4.618024734506339e-06,
+        2.250758185332994e-06, -1.1395929434609973e-07, -2.4760476919764757e-06, -4.83542717586758e-06,
+        -7.192018097732476e-06, -9.545740995355305e-06, -1.1896516595163442e-05, -1.4244265814796302e-05,
+        -1.658890976571526e-05, -1.893036975575311e-05, -2.126856729165356e-05, -2.3603424081652035e-05,
+        -2.593486203799535e-05, -2.8262803279451942e-05, -3.058717013386214e-05, -3.290788514062223e-05,
+        -3.522487105319239e-05, -3.753805084155973e-05, -3.984734769474821e-05, -4.215268502326643e-05,
+        -4.4453986461545956e-05, -4.675117587041872e-05, -4.904417733953356e-05, -5.13329151897618e-05,
+        -5.361731397564327e-05, -5.589729848776916e-05, -5.8172793755151e-05, -6.044372504765936e-05,
+        -6.271001787831995e-05, -6.497159800570334e-05, -6.722839143630305e-05, -6.948032442679744e-05,
+        -7.172732348643322e-05, -7.396931537931443e-05, -7.620622712668445e-05, -7.843798600924763e-05,
+        -8.066451956942798e-05, -8.288575561361804e-05, -8.510162221446331e-05, -8.73120477130869e-05,
+        -8.951696072130161e-05, -9.171629012385886e-05, -9.390996508063228e-05, -9.609791502882e-05,
+        -9.828006968510332e-05, -0.0001004563590478474, -0.00010262671339924005, -0.00010479106330741883,
+        -0.00010694933962863416, -0.00010910147350935043, -0.00011124739638833627, -0.00011338703999878909,
+        -0.0001155203363703991, -0.00011764721783139533, -0.00011976761701065926, -0.00012188146683969518,
+        -0.00012398870055469412, -0.00012608925169857763, -0.0001281830541229343, -0.00013027004199006514,
+        -0.0001323501497749413, -0.0001344233122671482, -0.00013648946457286946, -0.00013854854211680498,
+        -0.0001406004806440811, -0.0001426452162221888, -0.00014468268524286615, -0.00014671282442396352,
+        -0.00014873557081134385, -0.00015075086178072353, -0.00015275863503949686, -0.00015475882862859517,
+        -0.00015675138092428092, -0.00015873623063995666, -0.00016071331682793123, -0.00016268257888122343,
+        -0.00016464395653530264, -0.00016659738986982042, -0.00016854281931036918, -0.0001704801856301821,
+        -0.00017240942995182314, -0.0001743304937489001, -0.0001762433188477263, -0.00017814784742895763,
+        -0.00018004402202929153, -0.00018193178554303397, -0.00018381108122374467, -0.00018568185268586534,
+        -0.00018754404390624528, -0.00018939759922576574, -0.00019124246335087841, -0.00019307858135513266,
+        -0.00019490589868073415, -0.0001967243611400427, -0.00019853391491705572, -0.0002003345065689232,
+        -0.00020212608302739863, -0.00020390859160028193, -0.00020568197997288698, -0.00020744619620944387,
+        -0.0002092011887545203, -0.00021094690643439772, -0.0002126832984584741, -0.00021441031442062013,
+        -0.00021612790430050962, -0.00021783601846498248, -0.00021953460766935067, -0.00022122362305868683,
+        -0.00022290301616913937, -0.00022457273892919296, -0.00022623274366090877, -0.00022788298308121328,
+        -0.0002295234103030713, -0.00023115397883672167, -0.00023277464259089994, -0.00023438535587396388,
+        -0.00023598607339510514, -0.00023757675026548313, -0.0002391573419993467, -0.00024072780451517677,
+        -0.00024228809413677922, -0.00024383816759435985, -0.00024537798202562013, -0.00024690749497680383,
+        -0.00024842666440372274, -0.0002499354486728099, -0.0002514338065621145, -0.0002529216972622859,
+        -0.00025439908037757486, -0.00025586591592678004, -0.000257322164344204, -0.00025876778648056755,
+        -0.0002602027436039488, -0.00026162699740067284, -0.0002630405099761821, -0.00026444324385592753,
+        -0.00026583516198621043, -0.00026721622773500693, -0.000268586404892811, -0.0002699456576734279,
+        -0.00027129395071474926, -0.00027263124907956585, -0.0002739575182562755, -0.0002752727241596491,
+        -0.00027657683313157615, -0.0002778698119417266, -0.00027915162778828457, -0.0002804222482986128,
+        -0.00028168164152990005, -0.0002829297759698325, -0.0002841666205372087, -0.0002853921445825465,
+        -0.00028660631788869883, -0.00028780911067142757, -0.00028900049357995645, -0.00029018043769754527,
+        -0.0002913489145420057, -0.0002925058960662294, -0.0002936513546586778, -0.0002947852631438808,
+        -0.0002959075947829078, -0.0002970183232738041, -0.0002981174227520508, -0.000299204867790977,
+        -0.0003002806334021593, -0.0003013446950358265, -0.00030239702858122873, -0.0003034376103669809,
+        -0.0003044664171614393, -0.0003054834261729901, -0.0003064886150503766, -0.00030748196188301304,
+        -0.0003084634452012224, -0.0003094330439765361, -0.00031039073762192476, -0.0003113365059920235,
+        -0.0003122703293833624, -0.0003131921885345585, -0.0003141020646264928, -0.00031499993928249043,
+        -0.00031588579456846583, -0.0003167596129930534, -0.0003176213775077398, -0.0003184710715069595,
+        -0.0003193086788281888, -0.0003201341837520082, -0.00032094757100217504, -0.00032174882574565605,
+        -0.00032253793359264614, -0.0003233148805965935, -0.0003240796532541876, -0.00032483223850532687,
+        -0.0003255726237331009, -0.00032630079676372435, -0.0003270167458664689, -0.00032772045975358756,
+        -0.00032841192758021053, -0.00032909113894422, -0.0003297580838861459, -0.00033041275288898903,
+        -0.00033105513687807546, -0.0003316852272208877, -0.00033230301572684945, -0.0003329084946471374,
+        -0.0003335016566744514, -0.0003340824949427685, -0.00033465100302710227, -0.0003352071749432267,
+        -0.0003357510051473852, -0.0003362824885360034, -0.00033680162044536703, -0.00033730839665128677,
+        -0.0003378028133687669, -0.000338284867251625, -0.0003387545553921413, -0.0003392118753206416,
+        -0.00033965682500510435, -0.0003400894028507488, -0.0003405096076995765, -0.0003409174388299389,
+        -0.0003413128959560681, -0.00034169597922758643, -0.0003420666892290225, -0.0003424250269792968,
+        -0.00034277099393119093, -0.0003431045919708171, -0.00034342582341705797, -0.0003437346910209959,
+        -0.0003440311979653319, -0.00034431534786378757, -0.00034458714476049096, -0.0003448465931293432,
+        -0.00034509369787338585, -0.00034532846432413995, -0.0003455508982409303, -0.0003457610058102086,
+        -0.00034595879364484743, -0.0003461442687834251, -0.0003463174386895036, -0.0003464783112508804,
+        -0.0003466268947788323, -0.0003467631980073503, -0.0003468872300923437, -0.00034699900061084863,
+        -0.00034709851956021793, -0.0003471857973572835, -0.0003472608448375258, -0.0003473236732542125,
+        -0.00034737429427753334, -0.00034741271999371456, -0.0003474389629041272, -0.0003474530359243715,
+        -0.000347454952383359, -0.000347444726022374, -0.0003474223709941187, -0.00034738790186175417,
+        -0.0003473413335979243, -0.00034728268158375615, -0.00034721196160786603, -0.00034712918986533423,
+        -0.0003470343829566809, -0.00034692755788681543, -0.00034680873206398527, -0.0003466779232987,
+        -0.0003465351498026553, -0.00034638043018762833, -0.0003462137834643778, -0.00034603522904151467,
+        -0.0003458447867243708, -0.00034564247671384956, -0.0003454283196052703, -0.0003452023363871888,
+        -0.00034496454844021594, -0.00034471497753582034, -0.0003444536458351145, -0.0003441805758876353,
+        -0.0003438957906301077, -0.00034359931338519457, -0.0003432911678602425, -0.00034297137814600325,
+        -0.00034263996871535625, -0.0003422969644220062, -0.0003419423904991795, -0.00034157627255830076,
+        -0.00034119863658766504, -0.0003408095089510885, -0.0003404089163865571, -0.0003399968860048563,
+        -0.00033957344528819626, -0.0003391386220888159, -0.0003386924446275822, -0.00033823494149258303,
+        -0.00033776614163769027, -0.00033728607438113527, -0.0003367947694040543, -0.0003362922567490313,
+        -0.00033577856681862774, -0.0003352537303739061, -0.0003347177785329265, -0.00033417074276925843,
+        -0.0003336126549104582, -0.0003330435471365414, -0.0003324634519784572, -0.000331872402316534,
+        -0.00033127043137892503, -0.00033065757274004494, -0.00033003386031898703, -0.00032939932837793516,
+        -0.00032875401152057194, -0.00032809794469046004, -0.00032743116316943065, -0.0003267537025759532,
+        -0.00032606559886349146, -0.00032536688831886026, -0.00032465760756056675, -0.0003239377935371342,
+        -0.00032320748352542895, -0.00032246671512897116, -0.0003217155262762385, -0.00032095395521895283,
+        -0.0003201820405303667, -0.00031939982110354203, -0.00031860733614960175, -0.00031780462519599283,
+        -0.0003169917280847345, -0.00031616868497064436, -0.000315335536319576, -0.00031449232290663447,
+        -0.0003136390858143763, -0.0003127758664310251, -0.0003119027064486582, -0.0003110196478613769,
+        -0.00031012673296350194, -0.00030922400434772447, -0.0003083115049032667, -0.00030738927781403803,
+        -0.0003064573665567625, -0.00030551581489912064, -0.0003045646668978766, -0.00030360396689698186,
+        -0.0003026337595256923, -0.00030165408969667196, -0.0003006650026040732, -0.000299666543721632,
+        -0.0002986587588007352, -0.00029764169386850374, -0.0002966153952258385, -0.00029557990944548124,
+        -0.000294535283370068, -0.000293481564110154, -0.0002924187990422546, -0.00029134703580687457,
+        -0.0002902663223065145, -0.0002891767067036859, -0.00028807823741892695, -0.00028697096312877107,
+        -0.00028585493276377013, -0.0002847301955064624, -0.00028359680078933485, -0.000282454798292824,
+        -0.00028130423794325604, -0.00028014516991081234, -0.0002789776446074848, -0.00027780171268501196,
+        -0.0002766174250328198, -0.0002754248327759652, -0.0002742239872730473, -0.000273014940114133,
+        -0.0002717977431186828, -0.00027057244833344433, -0.00026933910803036303, -0.00026809777470447935,
+        -0.00026684850107182767, -0.0002655913400673114, -0.0002643263448425882, -0.000263053568763959,
+        -0.00026177306541021964, -0.0002604848885705368, -0.00025918909224231714, -0.0002578857306290454,
+        -0.00025657485813814765, -0.00025525652937884326, -0.00025393079915996984, -0.0002525977224878306,
+        -0.0002512573545640386, -0.0002499097507833111, -0.00024855496673133393, -0.0002471930581825615,
+        -0.0002458240810980175, -0.0002444480916231409, -0.00024306514608556323, -0.0002416753009929237,
+        -0.0002402786130306762, -0.00023887513905986962, -0.0002374649361149466, -0.00023604806140154088,
+        -0.00023462457229424648, -0.00023319452633440538, -0.0002317579812278978, -0.00023031499484289455,
+        -0.0002288656252076453, -0.0002274099305082379, -0.00022594796908637722, -0.0002244797994371287,
+        -0.00022300548020668688, -0.00022152507019014282, -0.0002200386283292193, -0.00021854621371002986,
+        -0.00021704788556083898, -0.00021554370324978816, -0.0002140337262826513, -0.00021251801430058803,
+        -0.00021099662707784765, -0.0002094696245195502, -0.00020793706665940098, -0.0002063990136574039,
+        -0.00020485552579763752, -0.000203306663485947, -0.00020175248724768645, -0.000200193057725454,
+        -0.00019862843567679971, -0.0001970586819719535, -0.0001954838575915635, -0.00019390402362439184,
+        -0.0001923192412650471, -0.00019072957181171063, -0.00018913507666383614, -0.00018753581731987743,
+        -0.00018593185537499676, -0.00018432325251879644, -0.0001827100705330079, -0.00018109237128921763,
+        -0.0001794702167465911, -0.00017784366894956166, -0.00017621279002555436, -0.00017457764218270863,
+        -0.00017293828770756638, -0.00017129478896279546, -0.0001696472083849151, -0.00016799560848196487,
+        -0.000166340051831267, -0.00016468060107711294, -0.00016301731892845296, -0.00016135026815665388,
+        -0.00015967951159318836, -0.00015800511212732383, -0.00015632713270388837, -0.00015464563632093924,
+        -0.00015296068602749563, -0.0001512723449212648, -0.0001495806761463345, -0.0001478857428909037,
+        -0.00014618760838501233, -0.00014448633589823754, -0.00014278198873742565, -0.0001410746302444267,
+        -0.00013936432379379544, -0.00013765113279052963, -0.00013593512066779103, -0.0001342163508846486,
+        -0.00013249488692378448, -0.00013077079228923777, -0.00012904413050414984, -0.00012731496510847557,
+        -0.00012558335965673378, -0.00012384937771575734, -0.00012211308286241214, -0.00012037453868135202,
+        -0.00011863380876278214, -0.00011689095670016173, -0.00011514604608800994, -0.00011339914051963299,
+        -0.00011165030358485728, -0.00010989959886784176, -0.00010814708994479329, -0.00010639284038174945,
+        -0.00010463691373235679, -0.0001028793735356177, -0.00010112028331367591, -9.935970656960645e-05,
+        -9.759770678517044e-05, -9.583434741861027e-05, -9.406969190244984e-05, -9.230380364125933e-05,
+        -9.053674600946567e-05, -8.876858234914139e-05, -8.69993759678225e-05, -8.522919013628891e-05,
+        -8.345808808638403e-05, -8.168613300884086e-05, -7.991338805107396e-05, -7.813991631501133e-05,
+        -7.636578085493227e-05, -7.459104467527134e-05, -7.281577072846353e-05, -7.104002191279907e-05,
+        -6.926386107021869e-05, -6.748735098421496e-05, -6.571055437765772e-05, -6.393353391062475e-05,
+        -6.215635217831653e-05, -6.0379071708874826e-05, -5.860175496126966e-05, -5.6824464323186816e-05,
+        -5.50472621088818e-05, -5.3270210557077704e-05, -5.149337182886794e-05, -4.971680800558536e-05,
+        -4.794058108671611e-05, -4.616475298781709e-05, -4.438938553840276e-05, -4.2614540479873336e-05,
+        -4.08402794634499e-05, -3.906666404807706e-05, -3.7293755698372724e-05, -3.552161578255872e-05,
+        -3.3750305570422935e-05, -3.1979886231244105e-05, -3.0210418831761615e-05, -2.844196433415065e-05,
+        -2.6674583593965324e-05, -2.4908337358126422e-05, -2.314328626291511e-05, -2.1379490831934605e-05,
+        -1.9617011474116564e-05, -1.785590848173807e-05, -1.6096242028381277e-05, -1.433807216700149e-05,
+        -1.258145882792124e-05, -1.0826461816830973e-05, -9.073140812876971e-06, -7.321555366654213e-06,
+        -5.571764898269951e-06, -3.823828695409499e-06, -2.077805911371171e-06, -3.337555631462109e-07,
+        1.4082634704942814e-06, 3.148192450947391e-06, 4.885972781656096e-06, 6.621546010042845e-06,
+        8.35485382939378e-06, 1.0085838080731369e-05, 1.1814440754742649e-05, 1.3540603993599199e-05,
+        1.52642700928494e-05, 1.6985381503302874e-05, 1.8703880832827982e-05, 2.041971084824173e-05,
+        2.2132814477129344e-05, 2.384313480966037e-05, 2.5550615100434086e-05, 2.7255198770279793e-05,
+        2.8956829408048794e-05, 3.0655450772436295e-05, 3.2351006793757006e-05, 3.404344157571324e-05,
+        3.5732699397192216e-05, 3.741872471401272e-05, 3.910146216068954e-05, 4.07808565521638e-05,
+        4.2456852885566826e-05, 4.412939634193748e-05, 4.5798432287932255e-05, 4.7463906277562696e-05,
+        4.912576405388786e-05, 5.078395155069789e-05, 5.2438414894225606e-05, 5.4089100404812675e-05,
+        5.573595459856728e-05, 5.737892418904864e-05, 5.901795608890705e-05, 6.0652997411510075e-05,
+        6.228399547262131e-05, 6.391089779197212e-05, 6.553365209490185e-05, 6.715220631398754e-05,
+        6.876650859059009e-05, 7.03765072764853e-05, 7.198215093542718e-05, 7.358338834470491e-05,
+        7.518016849672696e-05, 7.677244060055889e-05, 7.836015408345386e-05, 7.994325859240619e-05,
+        8.152170399566257e-05, 8.309544038422207e-05, 8.466441807336242e-05, 8.622858760411756e-05,
+        8.778789974476801e-05, 8.934230549229964e-05, 9.089175607388921e-05, 9.243620294834814e-05,
+        9.397559780755341e-05, 9.550989257790557e-05, 9.703903942174033e-05, 9.856299073873128e-05,
+        0.00010008169916731724, 0.00010159511758608318, 0.00010310319911513023, 0.00010460589711748951,
+        0.00010610316520043724, 0.00010759495721687006, 0.00010908122726666894, 0.0001105619296979858,
+        0.00011203701910860317, 0.00011350645034723378, 0.00011497017851480902, 0.00011642815896579276,
+        0.00011788034730944993, 0.00011932669941110554, 0.00012076717139342626, 0.000122201719637657,
+        0.0001236303007848491, 0.0001250528717371095, 0.0001264693896588018, 0.0001278798119777601,
+        0.00012928409638646973, 0.00013068220084327362, 0.00013207408357353433, 0.0001334597030707871,
+        0.0001348390180979125, 0.0001362119876882666, 0.00013757857114680112, 0.0001389387280512034,
+        0.0001402924182529941, 0.00014163960187861028, 0.0001429802393305299, 0.00014431429128830298,
+        0.00014564171870963357, 0.00014696248283145675, 0.00014827654517093626, 0.0001495838675265184,
+        0.00015088441197896917, 0.00015217814089234132, 0.00015346501691500624, 0.0001547450029806271,
+        0.00015601806230912434, 0.00015728415840765661, 0.00015854325507156603, 0.0001597953163853056,
+        0.00016104030672339111, 0.00016227819075130691, 0.00016350893342640418, 0.00016473249999881414,
+        0.00016594885601232062, 0.00016715796730523724, 0.00016835980001125712, 0.00016955432056031794,
+        0.0001707414956794346, 0.00017192129239351279, 0.00017309367802618384, 0.00017425862020059902,
+        0.00017541608684021047, 0.00017656604616957198, 0.00017770846671509607, 0.00017884331730580057,
+        0.00017997056707408552, 0.0001810901854564288, 0.00018220214219412633, 0.0001833064073340197,
+        0.00018440295122915657, 0.00018549174453951078, 0.0001865727582326444, 0.00018764596358436365,
+        0.00018871133217938978, 0.00018976883591198716, 0.00019081844698658654, 0.00019186013791841743,
+        0.00019289388153410404, 0.00019391965097225204, 0.00019493741968404444, 0.00019594716143380285,
+        0.00019694885029954894, 0.00019794246067354117, 0.00019892796726282423, 0.00019990534508974503,
+        0.00020087456949245406, 0.0002018356161254215, 0.00020278846095991642, 0.0002037330802844741,
+        0.0002046694507053758, 0.00020559754914709186, 0.00020651735285271257, 0.00020742883938440343,
+        0.00020833198662379042, 0.000209226772772378, 0.00021011317635195653, 0.00021099117620495354,
+        0.0002118607514948268, 0.000212721881706414, 0.00021357454664627074, 0.000214418726443018,
+        0.00021525440154765673, 0.00021608155273387307, 0.0002169001610983474, 0.0002177102080610353,
+        0.00021851167536543654, 0.00021930454507887063, 0.00022008879959271987, 0.00022086442162266433,
+        0.00022163139420892176, 0.00022238970071645342, 0.00022313932483517392, 0.00022388025058013426,
+        0.0002246124622917153, 0.00022533594463578914, 0.00022605068260387253, 0.00022675666151328124,
+        0.00022745386700725906, 0.0002281422850550943, 0.00022882190195224012, 0.00022949270432040888,
+        0.0002301546791076483, 0.0002308078135884433, 0.0002314520953637461, 0.00023208751236104917,
+        0.0002327140528344267, 0.00023333170536454746, 0.00023394045885870646, 0.00023454030255082444,
+        0.00023513122600143864, 0.00023571321909769062, 0.00023628627205329595, 0.0002368503754084961,
+        0.00023740552003001557, 0.00023795169711099488, 0.0002384888981709089, 0.0002390171150554883,
+        0.00023953633993661666, 0.0002400465653122224, 0.0002405477840061527, 0.00024103998916804825,
+        0.00024152317427319489, 0.00024199733312236485, 0.0002424624598416572, 0.00024291854888231553,
+        0.0002433655950205342, 0.00024380359335726668, 0.0002442325393180082, 0.0002446524286525664,
+        0.00024506325743484493, 0.00024546502206257436, 0.0002458577192570689, 0.00024624134606296183,
+        0.00024661589984791216, 0.0002469813783023259, 0.0002473377794390516, 0.00024768510159306164,
+        0.00024802334342113885, 0.00024835250390153364, 0.0002486725823336195, 0.00024898357833754055,
+        0.00024928549185383947, 0.0002495783231430795, 0.000249862072785457, 0.0002501367416804009,
+        0.0002504023310461557, 0.0002506588424193689, 0.0002509062776546507, 0.0002511446389241341,
+        0.0002513739287170174, 0.0002515941498391026, 0.00025180530541231856, 0.00025200739887423236,
+        0.0002522004339775543, 0.00025238441478963203, 0.0002525593456919276, 0.00025272523137949383,
+        0.0002528820768604318, 0.00025302988745534033, 0.0002531686687967629, 0.00025329842682860675,
+        0.00025341916780556817, 0.0002535308982925447, 0.00025363362516402435, 0.0002537273556034817,
+        0.0002538120971027522, 0.00025388785746139996, 0.0002539546447860788, 0.0002540124674898753,
+        0.0002540613342916484, 0.00025410125421535706, 0.0002541322365893779, 0.0002541542910458099,
+        0.0002541674275197747, 0.00025417165624870303, 0.00025416698777161256, 0.00025415343292837513,
+        0.0002541310028589738, 0.0002540997090027559, 0.00025405956309766426, 0.0002540105771794701,
+        0.00025395276358099433, 0.00025388613493131096, 0.0002538107041549537, 0.0002537264844711016,
+        0.00025363348939276103, 0.0002535317327259405, 0.0002534212285688066, 0.0002533019913108424,
+        0.0002531740356319878, 0.00025303737650177606, 0.0002528920291784548, 0.00025273800920810516,
+        0.0002525753324237469, 0.00025240401494443546, 0.00025222407317435216, 0.00025203552380188084,
+        0.00025183838379867985, 0.0002516326704187435, 0.00025141840119745275, 0.00025119559395062177,
+        0.00025096426677352954, 0.0002507244380399485, 0.00025047612640116137, 0.00025021935078496665,
+        0.0002499541303946834, 0.0002496804847081407, 0.0002493984334766581, 0.0002491079967240239,
+        0.0002488091947454601, 0.00024850204810657753, 0.0002481865776423269, 0.00024786280445594197,
+        0.0002475307499178667, 0.00024719043566468385, 0.00024684188359803315, 0.00024648511588351135,
+        0.00024612015494958155, 0.000245747023486461, 0.0002453657444450026, 0.00024497634103557727,
+        0.0002445788367269378, 0.00024417325524507983, 0.00024375962057209837, 0.00024333795694502592,
+        0.00024290828885467614, 0.00024247064104447185, 0.00024202503850926513, 0.00024157150649415382,
+        0.00024111007049329065, 0.00024064075624867832, 0.00024016358974896537, 0.00023967859722822982,
+        0.0002391858051647607, 0.00023868524027982125, 0.0002381769295364193, 0.00023766090013806331,
+        0.00023713717952750663, 0.00023660579538549315, 0.00023606677562949517, 0.00023552014841243777,
+        0.00023496594212141987, 0.00023440418537643844, 0.00023383490702908095, 0.00023325813616124116,
+        0.0002326739020838107, 0.00023208223433535715, 0.00023148316268082171, 0.00023087671711018358,
+        0.0002302629278371346, 0.0002296418252977361, 0.00022901344014908108, 0.0002283778032679432,
+        0.00022773494574941742, 0.00022708489890555897, 0.00022642769426402048, 0.00022576336356666985,
+        0.00022509193876821298, 0.0002244134520348129, 0.00022372793574268517, 0.0002230354224767161,
+        0.00022233594502904882, 0.00022162953639767163, 0.00022091622978501693, 0.00022019605859652464,
+        0.00021946905643922642, 0.00021873525712031501, 0.00021799469464569944, 0.00021724740321857005,
+        0.0002164934172379515, 0.00021573277129724471, 0.0002149655001827733, 0.00021419163887232272,
+        0.00021341122253366618, 0.00021262428652309636, 0.00021183086638395133, 0.0002110309978451222,
+        0.000210224716819573, 0.00020941205940284654, 0.00020859306187157132, 0.00020776776068195268,
+        0.00020693619246826977, 0.00020609839404137352, 0.00020525440238715692, 0.00020440425466504458,
+        0.00020354798820647202, 0.00020268564051334842, 0.00020181724925652956, 0.00020094285227428748,
+        0.0002000624875707537, 0.00019917619331439272, 0.0001982840078364488, 0.00019738596962937712,
+        0.00019648211734531624, 0.00019557248979450403, 0.0001946571259437248, 0.00019373606491474346,
+        0.00019280934598272755, 0.00019187700857467455, 0.00019093909226784294, 0.0001899956367881556,
+        0.00018904668200862414, 0.0001880922679477649, 0.00018713243476799532, 0.0001861672227740478,
+        0.000185196672411368, 0.00018422082426452306, 0.00018323971905558268, 0.00018225339764252167,
+        0.00018126190101761487, 0.00018026527030581225, 0.0001792635467631297, 0.00017825677177503807,
+        0.0001772449868548298, 0.0001762282336420009, 0.0001752065539006359, 0.00017417998951775357,
+        0.00017314858250170573, 0.00017211237498053199, 0.00017107140920030983, 0.0001700257275235467,
+        0.00016897537242751537, 0.0001679203865026223, 0.00016686081245076962, 0.00016579669308369643,
+        0.00016472807132133675, 0.00016365499019017936, 0.00016257749282160022, 0.00016149562245021794,
+        0.00016040942241224586, 0.00015931893614382097, 0.000158224207179356, 0.00015712527914987586,
+        0.0001560221957813655, 0.000154915000893094, 0.00015380373839595868, 0.00015268845229082656,
+        0.0001515691866668554, 0.00015044598569983295, 0.0001493188936505161, 0.0001481879548629491,
+        0.00014705321376279787, 0.00014591471485568978, 0.00014477250272552453, 0.00014362662203281073,
+        0.00014247711751300184, 0.00014132403397479592, 0.00014016741629849266, 0.00013900730943430489,
+        0.00013784375840066634, 0.0001366768082825909, 0.0001355065042299668, 0.0001343328914558912,
+        0.00013315601523500187, 0.00013197592090178457, 0.00013079265384890372, 0.000129606259525534,
+        0.00012841678343566707, 0.00012722427113644378, 0.00012602876823648499, 0.00012483032039419868,
+        0.0001236289733161154, 0.00012242477275520744, 0.0001212177645092229, 0.00012000799441899481,
+        0.00011879550836677578, 0.00011758035227457206, 0.00011636257210245179, 0.00011514221384688438,
+        0.00011391932353907339, 0.00011269394724327052, 0.00011146613105511352, 0.00011023592109996781,
+        0.00010900336353122736, 0.00010776850452868398, 0.00010653139029684474, 0.00010529206706324833,
+        0.00010405058107683966, 0.00010280697860627488, 0.00010156130593827196, 0.0001003136093759594,
+        9.90639352372e-05, 9.78123298529432e-05, 9.655883956557732e-05, 9.530351072725913e-05,
+        9.404638969827013e-05, 9.278752284537425e-05, 9.152695654015217e-05, 9.026473715736678e-05,
+        8.900091107331023e-05, 8.773552466417486e-05, 8.646862430439303e-05, 8.520025636500785e-05,
+        8.393046721204431e-05, 8.265930320485759e-05, 8.138681069450904e-05, 8.011303602214467e-05,
+        7.883802551734986e-05, 7.756182549653232e-05, 7.628448226130869e-05, 7.50060420968652e-05,
+        7.37265512703489e-05, 7.244605602926513e-05, 7.116460259983033e-05, 6.988223718540193e-05,
+        6.859900596485299e-05, 6.731495509094954e-05, 6.603013068878831e-05, 6.474457885416511e-05,
+        6.345834565199078e-05, 6.217147711470825e-05, 6.0884019240684446e-05, 5.9596017992633184e-05,
+        5.830751929604122e-05, 5.70185690375701e-05, 5.572921306348852e-05, 5.443949717810833e-05,
+        5.3149467142196496e-05, 5.185916867142081e-05, 5.056864743478076e-05, 4.927794905306088e-05,
+        4.7987119097255996e-05, 4.66962030870295e-05, 4.540524648917313e-05, 4.4114294716044366e-05,
+        4.2823393124035305e-05, 4.153258701204435e-05, 4.0241921619925466e-05, 3.895144212696798e-05,
+        3.7661193650384076e-05, 3.637122124375398e-05, 3.508156989554929e-05, 3.379228452760169e-05,
+        3.2503409993576365e-05, 3.121499107750769e-05, 2.9927072492265432e-05, 2.8639698878071806e-05,
+        2.7352914801019507e-05, 2.6066764751566582e-05, 2.4781293143063648e-05, 2.3496544310285477e-05,
+        2.2212562507939615e-05, 2.0929391909207282e-05, 1.9647076604288857e-05, 1.8365660598926626e-05,
+        1.708518781296297e-05, 1.580570207888476e-05, 1.452724714039144e-05, 1.3249866650936972e-05,
+        1.1973604172304478e-05, 1.069850317318607e-05, 9.424607027739796e-06, 8.151959014179596e-06,
+        6.880602313370171e-06, 5.610580007399638e-06, 4.341935078184899e-06, 3.0747104060853007e-06,
+        1.8089487684758083e-06, 5.446928383989097e-07, -7.180148168362076e-07, -1.97913173704893e-06,
+        -3.2386155705221197e-06, -4.496424075386409e-06, -5.752515120998873e-06, -7.006846689258187e-06,
+        -8.259376875987297e-06, -9.510063892264603e-06, -1.0758866065752632e-05, -1.2005741842048063e-05,
+        -1.325064978599879e-05, -1.4493548583014926e-05, -1.573439704040153e-05, -1.6973154088657644e-05,
+        -1.8209778782769834e-05, -1.944423030352652e-05, -2.0676467958796472e-05, -2.19064511848188e-05,
+        -2.3134139547469126e-05, -2.4359492743549642e-05, -2.5582470602045505e-05, -2.680303308537578e-05,
+        -2.8021140290664073e-05, -2.9236752450976733e-05, -3.0449829936554406e-05, -3.166033325606376e-05,
+        -3.286822305781628e-05, -3.4073460130978e-05, -3.527600540681619e-05, -3.6475819959869384e-05,
+        -3.7672865009166686e-05, -3.886710191944031e-05, -4.005849220227566e-05, -4.1246997517325486e-05,
+        -4.2432579673472685e-05, -4.36152006299901e-05, -4.479482249771946e-05, -4.59714075402168e-05,
+        -4.71449181748918e-05, -4.8315316974166364e-05, -4.948256666659908e-05, -5.064663013800464e-05,
+        -5.1807470432590005e-05, -5.2965050754057246e-05, -5.411933446671402e-05, -5.527028509656193e-05,
+        -5.641786633240519e-05, -5.756204202692676e-05, -5.8702776197757464e-05, -5.984003302856356e-05,
+        -6.097377687010093e-05, -6.210397224126284e-05, -6.323058383014574e-05, -6.43535764950815e-05,
+        -6.547291526566068e-05, -6.658856534378977e-05, -6.770049210467434e-05, -6.880866109784741e-05,
+        -6.991303804819054e-05, -7.101358885689488e-05, -7.211027960248054e-05, -7.320307654176842e-05,
+        -7.429194611084509e-05, -7.537685492604744e-05, -7.645776978491244e-05, -7.753465766712103e-05,
+        -7.860748573545867e-05, -7.967622133674323e-05, -8.074083200274497e-05, -8.180128545112449e-05,
+        -8.285754958633706e-05, -8.390959250053052e-05, -8.495738247445779e-05, -8.600088797835758e-05,
+        -8.704007767284e-05, -8.807492040975106e-05, -8.910538523305463e-05, -9.013144137968245e-05,
+        -9.115305828037637e-05, -9.217020556054804e-05, -9.318285304110298e-05, -9.419097073926185e-05,
+        -9.519452886939276e-05, -9.619349784381526e-05, -9.718784827359122e-05, -9.817755096934836e-05,
+        -9.916257694203322e-05, -0.00010014289740370436, -0.00010111848376831724, -0.00010208930765245575,
+        -0.00010305534087611002, -0.00010401655546341489, -0.00010497292364337914, -0.00010592441785062956,
+        -0.00010687101072612471, -0.00010781267511785999, -0.00010874938408158809, -0.00010968111088150704,
+        -0.00011060782899094138, -0.0001115295120930364, -0.00011244613408142032, -0.00011335766906087196,
+        -0.00011426409134796609, -0.00011516537547173473, -0.00011606149617429757, -0.00011695242841148518,
+        -0.00011783814735347415, -0.00011871862838539383, -0.00011959384710792324, -0.00012046377933790195,
+        -0.00012132840110891045, -0.00012218768867184015, -0.00012304161849549056, -0.000123890167267101,
+        -0.00012473331189292127, -0.0001255710294987587, -0.0001264032974305103, -0.00012723009325469302,
+        -0.00012805139475895782, -0.00012886717995261247, -0.00012967742706711848, -0.00013048211455658052,
+        -0.0001312812210982428, -0.00013207472559296316, -0.00013286260716567335, -0.00013364484516585306,
+        -0.00013442141916797485, -0.0001351923089719428, -0.0001359574946035366, -0.00013671695631483572,
+        -0.00013747067458462516, -0.00013821863011882792, -0.0001389608038508802, -0.00013969717694213427,
+        -0.00014042773078225417, -0.00014115244698956314, -0.00014187130741143454, -0.00014258429412463753,
+        -0.00014329138943568347, -0.00014399257588117569, -0.00014468783622813587, -0.0001453771534743216,
+        -0.00014606051084855285, -0.00014673789181101076, -0.00014740928005352967, -0.00014807465949989807,
+        -0.0001487340143061296, -0.00014938732886073908, -0.00015003458778499736, -0.0001506757759331979,
+        -0.000151310878392895, -0.00015193988048513657, -0.00015256276776470302, -0.0001531795260203213,
+        -0.00015379014127487247, -0.00015439459978560185, -0.00015499288804431095, -0.0001555849927775345,
+        -0.00015617090094673642, -0.000156750599748455, -0.00015732407661447464, -0.00015789131921198596,
+        -0.00015845231544370746, -0.0001590070534480366, -0.0001595555215991698, -0.0001600977085072144,
+        -0.00016063360301830576, -0.00016116319421470454, -0.00016168647141488022, -0.00016220342417360694,
+        -0.00016271404228203053, -0.0001632183157677306, -0.00016371623489478697, -0.00016420779016382378,
+        -0.00016469297231205024, -0.00016517177231328918, -0.00016564418137800856, -0.00016611019095333156,
+        -0.00016656979272303957, -0.0001670229786075785, -0.00016746974076404503, -0.00016791007158616227,
+        -0.00016834396370426344, -0.0001687714099852501, -0.00016919240353254495, -0.00016960693768605203,
+        -0.00017001500602208084, -0.00017041660235328634, -0.0001708117207285958, -0.00017120035543311254,
+        -0.00017158250098802698, -0.00017195815215052297, -0.00017232730391365063, -0.00017268995150622322,
+        -0.00017304609039268346, -0.0001733957162729666, -0.00017373882508236313, -0.00017407541299136435,
+        -0.00017440547640549945, -0.00017472901196517482, -0.00017504601654549435, -0.00017535648725607223,
+        -0.00017566042144084767, -0.00017595781667788062, -0.00017624867077914455, -0.00017653298179030725,
+        -0.00017681074799051201, -0.00017708196789214137, -0.0001773466402405757, -0.0001776047640139496,
+        -0.000177856338422891, -0.0001781013629102586, -0.00017833983715086907, -0.0001785717610512195,
+        -0.00017879713474919345, -0.0001790159586137734, -0.0001792282332447294, -0.00017943395947230936,
+        -0.0001796331383569258, -0.00017982577118881826, -0.00018001185948772675, -0.00018019140500254638,
+        -0.00018036440971097375, -0.00018053087581915538, -0.00018069080576131645, -0.00018084420219938817,
+        -0.00018099106802262996, -0.0001811314063472383, -0.00018126522051594785, -0.00018139251409763377,
+        -0.0001815132908868963, -0.00018162755490364182, -0.00018173531039265663, -0.0001818365618231755,
+        -0.00018193131388843833, -0.0001820195715052398, -0.00018210133981347762, -0.00018217662417568638,
+        -0.0001822454301765652, -0.00018230776362250303, -0.00018236363054109205, -0.0001824130371806332,
+        -0.00018245599000963939, -0.00018249249571632582, -0.0001825225612080965, -0.00018254619361102444,
+        -0.00018256340026931987, -0.00018257418874479737, -0.00018257856681633166, -0.0001825765424793094,
+        -0.00018256812394507063, -0.00018255331964034505, -0.00018253213820668376, -0.00018250458849987828,
+        -0.00018247067958937843, -0.00018243042075769952, -0.0001823838214998262, -0.00018233089152260473,
+        -0.00018227164074413206, -0.0001822060792931381, -0.00018213421750836053, -0.00018205606593791113,
+        -0.00018197163533863924, -0.000181880936675484, -0.0001817839811208264, -0.00018168078005382628,
+        -0.00018157134505976066, -0.00018145568792935106, -0.00018133382065808608, -0.00018120575544553684,
+        -0.0001810715046946656, -0.00018093108101113008, -0.00018078449720257894, -0.00018063176627794184,
+        -0.00018047290144671587, -0.00018030791611823834, -0.0001801368239009649, -0.00017995963860172894,
+        -0.00017977637422500394, -0.00017958704497215728, -0.00017939166524069485, -0.0001791902496235038,
+        -0.00017898281290808742, -0.00017876937007579424, -0.00017854993630104076, -0.0001783245269505291,
+        -0.00017809315758245855, -0.00017785584394573102, -0.00017761260197915018, -0.0001773634478106176,
+        -0.00017710839775631967, -0.00017684746831990857, -0.00017658067619168383, -0.0001763080382477592,
+        -0.00017602957154923122, -0.00017574529334133909, -0.00017545522105261947, -0.00017515937229405407,
+        -0.00017485776485821913, -0.00017455041671841492, -0.00017423734602780826, -0.00017391857111855662,
+        -0.00017359411050092748, -0.00017326398286242236, -0.00017292820706688464, -0.00017258680215360647,
+        -0.00017223978733643512, -0.00017188718200286448, -0.00017152900571312888, -0.00017116527819929398,
+        -0.000170796019364332, -0.0001704212492812016, -0.00017004098819192357, -0.00016965525650664248,
+        -0.00016926407480269146, -0.0001688674638236499, -0.00016846544447839937, -0.0001680580378401654,
+        -0.00016764526514556597, -0.00016722714779365187, -0.0001668037073449352, -0.00016637496552042428,
+        -0.00016594094420064904, -0.00016550166542467963, -0.0001650571513891427, -0.00016460742444723874,
+        -0.0001641525071077431, -0.0001636924220340139, -0.0001632271920429935, -0.00016275684010419437,
+        -0.00016228138933870306, -0.000161800863018159, -0.00016131528456373524, -0.00016082467754512691,
+        -0.00016032906567951604, -0.00015982847283054752, -0.000159322923007298, -0.00015881244036323325,
+        -0.00015829704919517084, -0.00015777677394223757, -0.00015725163918481753, -0.00015672166964350037,
+        -0.00015618689017803035, -0.00015564732578624154, -0.0001551030016029979, -0.00015455394289912584,
+        -0.00015400017508034733, -0.0001534417236862029, -0.0001528786143889751, -0.0001523108729926141,
+        -0.0001517385254316474, -0.00015116159777009476, -0.00015058011620038502, -0.00014999410704225367,
+        -0.00014940359674165077, -0.00014880861186964743, -0.00014820917912131961, -0.00014760532531465844,
+        -0.00014699707738945436, -0.0001463844624061801, -0.00014576750754489106, -0.00014514624010409518,
+        -0.00014452068749963822, -0.00014389087726358597, -0.00014325683704309055, -0.00014261859459926578,
+        -0.00014197617780606234, -0.00014132961464912542, -0.00014067893322466387, -0.00014002416173831603,
+        -0.0001393653285040008, -0.0001387024619427812, -0.0001380355905817142, -0.0001373647430527111,
+        -0.00013668994809137793, -0.000136011234535868, -0.00013532863132573208, -0.0001346421675007523,
+        -0.00013395187219979006, -0.00013325777465962748, -0.00013255990421379754, -0.00013185829029142374,
+        -0.00013115296241605797, -0.00013044395020450354, -0.0001297312833656485, -0.0001290149916993015,
+        -0.00012829510509499936, -0.00012757165353085315, -0.0001268446670723618, -0.00012611417587122327,
+        -0.00012538021016417512, -0.0001246428002717957, -0.0001239019765973267, -0.00012315776962549461,
+        -0.00012241020992131301, -0.00012165932812890183, -0.00012090515497030302, -0.00012014772124428072,
+        -0.00011938705782513452, -0.00011862319566151305, -0.00011785616577520989, -0.00011708599925997669,
+        -0.00011631272728032252, -0.00011553638107032635, -0.00011475699193242717, -0.00011397459123623241,
+        -0.00011318921041732297, -0.00011240088097604282, -0.00011160963447630348, -0.00011081550254438858,
+        -0.00011001851686773918, -0.00010921870919375785, -0.00010841611132861168, -0.00010761075513600834,
+        -0.00010680267253601527, -0.00010599189550384348, -0.00010517845606863223, -0.00010436238631226516,
+        -0.00010354371836814577, -0.0001027224844199967, -0.00010189871670066022, -0.00010107244749087904,
+        -0.00010024370911809494, -9.94125339552484e-05, -9.857895441955838e-05, -9.774300297132164e-05,
+        -9.690471211271143e-05, -9.60641143865578e-05, -9.522124237514915e-05, -9.437612869902159e-05,
+        -9.352880601575852e-05, -9.26793070187742e-05, -9.182766443611142e-05, -9.097391102924314e-05,
+        -9.011807959185397e-05, -8.926020294864152e-05, -8.840031395411717e-05, -8.753844549139025e-05,
+        -8.667463047096956e-05, -8.580890182956689e-05, -8.494129252888212e-05, -8.407183555440742e-05,
+        -8.320056391423181e-05, -8.232751063782899e-05, -8.145270877486338e-05, -8.057619139399704e-05,
+        -7.969799158168016e-05, -7.881814244096163e-05, -7.793667709028862e-05, -7.705362866232014e-05,
+        -7.616903030272013e-05, -7.528291516897049e-05, -7.439531642918523e-05, -7.350626726090824e-05,
+        -7.26158008499284e-05, -7.172395038909806e-05, -7.083074907713424e-05, -6.99362301174375e-05,
+        -6.904042671691703e-05, -6.814337208478444e-05, -6.724509943139869e-05, -6.634564196707427e-05,
+        -6.544503290088958e-05, -6.454330543953721e-05, -6.364049278612536e-05, -6.273662813901245e-05,
+        -6.183174469064137e-05, -6.092587562635655e-05, -6.001905412324112e-05, -5.9111313348957455e-05,
+        -5.820268646056821e-05, -5.729320660338047e-05, -5.638290690979031e-05, -5.547182049811131e-05,
+        -5.4559980471425536e-05, -5.3647419916423786e-05, -5.2734171902261726e-05, -5.182026947939526e-05,
+        -5.0905745678438445e-05, -4.999063350902295e-05, -4.907496595864112e-05, -4.815877599150988e-05,
+        -4.724209654743733e-05, -4.632496054067239e-05, -4.540740085877575e-05, -4.448945036149628e-05,
+        -4.35711418796162e-05, -4.265250821385204e-05, -4.173358213371595e-05, -4.081439637638023e-05,
+        -3.9894983645584914e-05, -3.897537661049698e-05, -3.805560790460379e-05, -3.713571012460757e-05,
+        -3.6215715829303085e-05, -3.5295657538477585e-05, -3.437556773181348e-05, -3.345547884777429e-05,
+        -3.253542328251335e-05, -3.161543338878513e-05, -3.069554147484004e-05, -2.9775779803343984e-05,
+        -2.8856180590287854e-05, -2.7936776003913483e-05, -2.70175981636203e-05, -2.6098679138895088e-05,
+        -2.5180050948245087e-05, -2.4261745558114422e-05, -2.3343794881823367e-05, -2.2426230778511096e-05,
+        -2.1509085052061938e-05, -2.0592389450054588e-05, -1.967617566271487e-05, -1.876047532185217e-05,
+        -1.7845319999818865e-05, -1.6930741208475455e-05, -1.6016770398126688e-05, -1.510343895651326e-05,
+        -1.4190778207765804e-05, -1.3278819411362394e-05, -1.2367593761131356e-05, -1.1457132384205175e-05,
+        -1.0547466340010755e-05, -9.638626619261631e-06, -8.73064414293377e-06, -7.82354976126522e-06,
+        -6.917374252759404e-06, -6.012148323172716e-06, -5.10790260452551e-06, -4.204667654117173e-06,
+        -3.302473953525396e-06, -2.4013519076312735e-06, -1.5013318436353606e-06, -6.024440100907753e-07,
+        2.952814240813489e-07, 1.1918143705505606e-06, 2.0871248225447188e-06, 2.981182855800449e-06,
+        3.873958629533237e-06, 4.765422387370818e-06, 5.6555444583126725e-06, 6.54429525767433e-06,
+        7.431645288005861e-06, 8.317565140049265e-06, 9.202025493650286e-06, 1.0084997118689886e-05,
+        1.096645087600671e-05, 1.1846357718317402e-05, 1.2724688691108773e-05, 1.360141493356853e-05,
+        1.4476507679468392e-05, 1.534993825807919e-05, 1.6221678095045124e-05, 1.709169871329599e-05,
+        1.7959971733912024e-05, 1.8826468877007887e-05, 1.9691161962609684e-05, 2.05540229115312e-05,
+        2.1415023746222657e-05, 2.2274136591654488e-05, 2.3131333676154367e-05, 2.3986587332260257e-05,
+        2.4839869997579424e-05, 2.5691154215613216e-05, 2.654041263660583e-05, 2.7387618018378434e-05,
+        2.823274322713896e-05, 2.9075761238316586e-05, 2.9916645137382643e-05, 3.075536812064811e-05,
+        3.1591903496082586e-05, 3.2426224684118415e-05, 3.325830521842884e-05, 3.408811874674233e-05,
+        3.49156390316126e-05, 3.574083995120636e-05, 3.6563695500081926e-05, 3.738417978996398e-05,
+        3.820226705049148e-05, 3.901793163000071e-05, 3.983114799626284e-05, 4.0641890737250004e-05,
+        4.145013456186271e-05, 4.225585430069115e-05, 4.305902490673211e-05, 4.3859621456121645e-05,
+        4.465761914886132e-05, 4.5452993309542235e-05, 4.624571938804248e-05, 4.703577296025655e-05,
+        4.782312972877953e-05, 4.8607765523605996e-05, 4.938965630283344e-05, 5.01687781533319e-05,
+        5.094510729143668e-05, 5.171862006362477e-05, 5.2489292947168935e-05, 5.325710255081278e-05,
+        5.4022025615433256e-05, 5.478403901467975e-05, 5.554311975563655e-05, 5.629924497944693e-05,
+        5.705239196197055e-05, 5.780253811439589e-05, 5.854966098386712e-05, 5.9293738254114446e-05,
+        6.003474774605411e-05, 6.077266741839937e-05, 6.150747536827406e-05, 6.22391498317937e-05,
+        6.296766918465727e-05, 6.36930119427435e-05, 6.441515676267492e-05, 6.513408244240156e-05,
+        6.584976792177056e-05, 6.656219228307079e-05, 6.727133475160919e-05, 6.797717469624548e-05,
+        6.867969162994177e-05, 6.937886521030273e-05, 7.007467524011138e-05, 7.076710166783914e-05,
+        7.145612458818645e-05, 7.214172424258097e-05, 7.282388101970076e-05, 7.350257545596115e-05,
+        7.41777882360309e-05, 7.484950019330742e-05, 7.551769231040494e-05, 7.618234571963618e-05,
+        7.684344170348963e-05, 7.750096169508325e-05, 7.815488727864462e-05, 7.880520018995008e-05,
+        7.945188231677633e-05, 8.009491569935305e-05, 8.073428253078868e-05, 8.136996515750436e-05,
+        8.200194607966916e-05, 8.26302079516058e-05, 8.325473358220521e-05, 8.387550593534296e-05,
+        8.449250813026791e-05, 8.510572344200524e-05, 8.571513530174808e-05, 8.632072729722485e-05,
+        8.692248317309331e-05, 8.752038683129901e-05, 8.811442233144304e-05, 8.870457389114338e-05,
+        8.929082588638862e-05, 8.987316285187107e-05, 9.045156948134251e-05, 9.102603062793539e-05,
+        9.159653130450138e-05, 9.216305668392023e-05, 9.272559209943155e-05, 9.328412304492929e-05,
+        9.38386351752697e-05, 9.43891143065681e-05, 9.493554641649538e-05, 9.547791764454982e-05,
+        9.601621429235263e-05, 9.655042282390629e-05, 9.70805298658629e-05, 9.760652220779223e-05,
+        9.812838680242495e-05, 9.86461107659079e-05, 9.915968137804797e-05, 9.966908608253591e-05,
+        0.00010017431248718365, 0.00010067534836415055, 0.00010117218165015093, 0.00010166480044667229,
+        0.00010215319302018186, 0.00010263734780231254, 0.00010311725339007102, 0.00010359289854601027,
+        0.00010406427219842111, 0.00010453136344149442, 0.00010499416153550392, 0.0001054526559069499,
+        0.00010590683614873036, 0.00010635669202027752, 0.00010680221344771298, 0.00010724339052397193,
+        0.00010768021350894816, 0.00010811267282960778, 0.00010854075908010875, 0.00010896446302192157,
+        0.00010938377558392555, 0.00010979868786251277, 0.00011020919112168933, 0.00011061527679315239,
+        0.00011101693647637589, 0.0001114141619386907, 0.00011180694511534705, 0.00011219527810958395,
+        0.00011257915319268817, 0.00011295856280403688, 0.0001133334995511501, 0.00011370395620973108,
+        0.00011406992572369377, 0.00011443140120519483, 0.00011478837593465693, 0.00011514084336077402,
+        0.00011548879710053231, 0.0001158322309392038, 0.00011617113883035204, 0.00011650551489581355,
+        0.00011683535342569556, 0.00011716064887834077, 0.00011748139588031271, 0.0001177975892263542,
+        0.00011810922387935569, 0.000118416294970303, 0.0001187187977982363, 0.0001190167278301852,
+        0.00011931008070110729, 0.0001195988522138255, 0.00011988303833894724, 0.00012016263521478545,
+        0.00012043763914727928, 0.00012070804660989316, 0.00012097385424352151, 0.00012123505885638965,
+        0.00012149165742393733, 0.00012174364708870868, 0.00012199102516023161, 0.00012223378911488462,
+        0.00012247193659577105, 0.00012270546541257588, 0.00012293437354142343, 0.00012315865912472748,
+        0.000123378320471038, 0.0001235933560548721, 0.0001238037645165556, 0.00012400954466204323,
+        0.00012421069546274618, 0.00012440721605534103, 0.0001245991057415881, 0.00012478636398812867,
+        0.0001249689904262876, 0.0001251469848518638, 0.00012532034722492275, 0.00012548907766957206,
+        0.0001256531764737451, 0.00012581264408896654, 0.00012596748113011784, 0.00012611768837520205,
+        0.0001262632667650916, 0.0001264042174032826, 0.00012654054155563678, 0.00012667224065011695,
+        0.00012679931627652204, 0.00012692177018621415, 0.00012703960429183897, 0.00012715282066704328,
+        0.00012726142154618624, 0.00012736540932404243, 0.00012746478655550528, 0.00012755955595527923,
+        0.00012764972039757186, 0.00012773528291577603, 0.00012781624670215198, 0.00012789261510749692,
+        0.00012796439164081724, 0.0001280315799689897, 0.0001280941839164218, 0.00012815220746470164,
+        0.0001282056547522502, 0.00012825453007396062, 0.0001282988378808359, 0.00012833858277962328,
+        0.00012837376953244147, 0.00012840440305640115, 0.0001284304884232256, 0.00012845203085886032,
+        0.0001284690357430806, 0.00012848150860909636, 0.00012848945514314637, 0.0001284928811840933,
+        0.00012849179272301164, 0.00012848619590276737, 0.00012847609701759896, 0.00012846150251268927,
+        0.0001284424189837336, 0.00012841885317650345, 0.00012839081198640559, 0.00012835830245803504,
+        0.00012832133178472505, 0.00012827990730809074, 0.00012823403651757, 0.00012818372704995776,
+        0.00012812898668893667, 0.0001280698233646027, 0.00012800624515298763, 0.00012793826027557368,
+        0.00012786587709880782, 0.0001277891041336076, 0.0001277079500348652, 0.00012762242360094597,
+        0.00012753253377318277, 0.0001274382896353649, 0.00012733970041322486, 0.00012723677547391756,
+        0.00012712952432549846, 0.000127017956616395, 0.00012690208213487467, 0.0001267819108085094,
+        0.00012665745270363393, 0.00012652871802480196, 0.000126395717114236, 0.0001262584604512751,
+        0.00012611695865181763, 0.00012597122246775887, 0.00012582126278642698, 0.00012566709063001135,
+        0.00012550871715498961, 0.00012534615365155128, 0.0001251794115430137, 0.00012500850238523838,
+        0.00012483343786603976, 0.0001246542298045934, 0.00012447089015083518, 0.00012428343098486413,
+        0.000124091864516334, 0.00012389620308384623, 0.00012369645915433547, 0.0001234926453224551,
+        0.0001232847743099539, 0.00012307285896505474, 0.00012285691226182656, 0.00012263694729955097,
+        0.0001224129773020897, 0.00012218501561724534, 0.00012195307571611738, 0.00012171717119245848,
+        0.00012147731576202624, 0.00012123352326192647, 0.000120985807649962, 0.00012073418300396954,
+        0.00012047866352115658, 0.00012021926351743922, 0.0001199559974267663, 0.00011968887980045209,
+        0.00011941792530649699, 0.00011914314872890767, 0.00011886456496701411, 0.00011858218903478722,
+        0.00011829603606014468, 0.00011800612128426236, 0.00011771246006087605, 0.00011741506785558632,
+        0.00011711396024515234, 0.00011680915291679053, 0.00011650066166746794, 0.00011618850240318923,
+        0.00011587269113828246, 0.00011555324399468686, 0.00011523017720122764, 0.00011490350709289789,
+        0.00011457325011013501, 0.00011423942279808746, 0.00011390204180588988, 0.000113561123885926,
+        0.00011321668589309241, 0.00011286874478406437, 0.00011251731761655119, 0.00011216242154855065,
+        0.00011180407383760785, 0.00011144229184006083, 0.00011107709301028973, 0.0001107084948999682,
+        0.00011033651515729922, 0.00010996117152626327, 0.00010958248184585012, 0.00010920046404930296,
+        0.00010881513616334248, 0.00010842651630740889, 0.00010803462269288355, 0.0001076394736223217,
+        0.00010724108748867182, 0.00010683948277450535, 0.00010643467805122808, 0.00010602669197830642,
+        0.00010561554330248189, 0.00010520125085698479, 0.00010478383356074367, 0.00010436331041760281,
+        0.00010393970051552084, 0.00010351302302578452, 0.00010308329720221321, 0.00010265054238035564,
+        0.00010221477797669756, 0.00010177602348785577, 0.00010133429848977462, 0.00010088962263692771,
+        0.000100442015661507, 9.999149737261256e-05, 9.953808765544944e-05, 9.908180647051076e-05,
+        9.862267385276454e-05, 9.816070991084662e-05, 9.769593482623487e-05, 9.722836885244083e-05,
+        9.675803231418239e-05, 9.628494560657163e-05, 9.580912919428302e-05, 9.533060361074271e-05,
+        9.484938945729213e-05, 9.43655074023721e-05, 9.387897818068666e-05, 9.338982259238483e-05,
+        9.289806150221877e-05, 9.240371583872346e-05, 9.190680659338319e-05, 9.140735481979174e-05,
+        9.090538163282674e-05, 9.040090820781176e-05, 8.989395577967375e-05, 8.938454564211443e-05,
+        8.887269914677177e-05, 8.835843770237281e-05, 8.78417827739043e-05, 8.73227558817615e-05,
+        8.680137860091757e-05, 8.62776725600676e-05, 8.575165944079697e-05, 8.522336097673385e-05,
+        8.46927989527037e-05, 8.415999520388011e-05, 8.362497161494899e-05, 8.308775011924756e-05,
+        8.25483526979287e-05, 8.200680137911156e-05, 8.14631182370263e-05, 8.091732539117421e-05,
+        8.036944500547171e-05, 7.981949928739913e-05, 7.926751048716019e-05, 7.871350089682433e-05,
+        7.815749284947356e-05, 7.759950871835902e-05, 7.703957091604318e-05, 7.647770189354595e-05,
+        7.591392413950464e-05, 7.534826017930777e-05, 7.478073257425247e-05, 7.421136392068941e-05,
+        7.364017684916771e-05, 7.30671940235806e-05, 7.249243814032419e-05, 7.191593192743058e-05,
+        7.133769814372726e-05, 7.075775957797367e-05, 7.017613904802144e-05, 6.959285939994649e-05,
+        6.900794350720967e-05, 6.842141426980164e-05, 6.783329461339004e-05, 6.724360748846381e-05,
+        6.665237586949324e-05, 6.605962275406315e-05, 6.54653711620354e-05, 6.486964413469693e-05,
+        6.427246473390175e-05, 6.367385604123234e-05, 6.30738411571427e-05, 6.247244320010873e-05,
+        6.186968530579037e-05, 6.12655906261767e-05, 6.0660182328736146e-05, 6.0053483595579715e-05,
+        5.944551762260717e-05, 5.8836307618660766e-05, 5.822587680469341e-05, 5.761424841290975e-05,
+        5.7001445685935815e-05, 5.63874918759662e-05, 5.5772410243935e-05, 5.515622405865875e-05,
+        5.4538956596016875e-05, 5.3920631138095886e-05, 5.330127097236402e-05, 5.268089939082399e-05,
+        5.205953968918941e-05, 5.1437215166034535e-05, 5.0813949121973224e-05, 5.018976485882553e-05,
+        4.956468567877652e-05, 4.893873488355665e-05, 4.831193577360925e-05, 4.768431164725406e-05,
+        4.7055885799871654e-05, 4.642668152307624e-05, 4.579672210388313e-05, 4.516603082389662e-05,
+        4.453463095847968e-05, 4.390254577593237e-05, 4.326979853668558e-05, 4.263641249246715e-05,
+        4.200241088549817e-05, 4.136781694767276e-05, 4.073265389974402e-05, 4.009694495050921e-05,
+        3.946071329601255e-05, 3.882398211872059e-05, 3.818677458672821e-05, 3.7549113852941854e-05,
+        3.691102305428816e-05, 3.627252531089499e-05, 3.5633643725311204e-05, 3.499440138169013e-05,
+        3.435482134500644e-05, 3.371492666024863e-05, 3.307474035163948e-05, 3.2434285421827627e-05,
+        3.179358485111165e-05, 3.115266159665086e-05, 3.051153859167018e-05, 2.9870238744686855e-05,
+        2.9228784938725568e-05, 2.858720003052916e-05, 2.794550684979286e-05, 2.7303728198386772e-05,
+        2.666188684957304e-05, 2.602000554724588e-05, 2.537810700515307e-05, 2.47362139061269e-05,
+        2.4094348901332132e-05, 2.3452534609486283e-05, 2.2810793616110864e-05, 2.216914847276788e-05,
+        2.1527621696301884e-05, 2.088623576808265e-05, 2.0245013133267142e-05, 1.9603976200032846e-05,
+        1.896314733884459e-05, 1.83225488816972e-05, 1.7682203121386997e-05, 1.704213231075474e-05,
+        1.6402358661962126e-05, 1.576290434575326e-05, 1.5123791490720653e-05, 1.4485042182570624e-05,
+        1.3846678463407753e-05, 1.3208722330993331e-05, 1.2571195738036457e-05, 1.1934120591472837e-05,
+        1.129751875173838e-05, 1.0661412032066867e-05, 1.002582219776904e-05, 9.390770965521807e-06,
+        8.756280002674018e-06, 8.122370926533654e-06, 7.489065303663869e-06, 6.8563846491953536e-06,
+        6.224350426121987e-06, 5.592984044606706e-06, 4.962306861305528e-06, 4.332340178664008e-06,
+        3.7031052442469822e-06, 3.074623250045389e-06, 2.4469153318118083e-06, 1.8200025683671665e-06,
+        1.193905980949734e-06, 5.686465325277691e-07, -5.575487285304644e-08, -6.792773907448402e-07,
+        -1.301900236944086e-06, -1.923602688165211e-06, -2.544364082680986e-06, -3.1641638209763856e-06,
+        -3.7829813663978132e-06, -4.400796245802158e-06, -5.017588050186236e-06, -5.633336435341475e-06,
+        -6.2480211224754365e-06, -6.8616218988451645e-06, -7.474118618394713e-06, -8.085491202368466e-06,
+        -8.695719639942215e-06, -9.304783988843305e-06, -9.912664375954183e-06, -1.0519340997933458e-05,
+        -1.112479412182762e-05, -1.1729004085666068e-05, -1.2331951299071959e-05, -1.2933616243862464e-05,
+        -1.3533979474630501e-05, -1.4133021619352882e-05, -1.4730723379965349e-05, -1.5327065532959163e-05,
+        -1.5922028929949468e-05, -1.651559449827052e-05, -1.7107743241529805e-05, -1.7698456240196375e-05,
+        -1.8287714652154996e-05, -1.8875499713281556e-05, -1.9461792737990346e-05, -2.0046575119806336e-05,
+        -2.0629828331905476e-05, -2.1211533927665153e-05, -2.1791673541218976e-05, -2.237022888798842e-05,
+        -2.2947181765224517e-05, -2.3522514052550912e-05, -2.4096207712483604e-05, -2.4668244790959873e-05,
+        -2.52386074178702e-05, -2.5807277807567e-05, -2.6374238259389335e-05, -2.693947115817652e-05,
+        -2.750295897476431e-05, -2.8064684266505014e-05, -2.862462967775756e-05, -2.9182777940388777e-05,
+        -2.97391118742681e-05, -3.0293614387760295e-05, -3.0846268478198185e-05, -3.1397057232380176e-05,
+        -3.19459638270359e-05, -3.2492971529310715e-05, -3.3038063697224346e-05, -3.358122378015166e-05,
+        -3.412243531927313e-05, -3.46616819480357e-05, -3.519894739260904e-05, -3.573421547233996e-05,
+        -3.6267470100188076e-05, -3.6798695283183575e-05, -3.732787512285344e-05, -3.785499381565763e-05,
+        -3.838003565342775e-05, -3.890298502378413e-05, -3.9423826410560344e-05, -3.9942544394235486e-05,
+        -4.0459123652325555e-05, -4.0973548959808237e-05, -4.148580518953054e-05, -4.199587731260209e-05,
+        -4.25037503988014e-05, -4.300940961697082e-05, -4.3512840235395325e-05, -4.4014027622202684e-05,
+        -4.4512957245735006e-05, -4.500961467493005e-05, -4.550398557969644e-05, -4.599605573128579e-05,
+        -4.648581100264606e-05, -4.697323736879701e-05, -4.745832090717593e-05, -4.7941047798000164e-05,
+        -4.842140432460515e-05, -4.889937687380197e-05, -4.937495193620666e-05, -4.984811610657908e-05,
+        -5.03188560841557e-05, -5.0787158672981215e-05, -5.125301078222226e-05, -5.1716399426499584e-05,
+        -5.217731172619311e-05, -5.263573490775318e-05, -5.309165630401458e-05, -5.3545063354489443e-05,
+        -5.399594360567233e-05, -5.444428471133557e-05, -5.489007443280881e-05, -5.533330063927233e-05,
+        -5.577395130803952e-05, -5.621201452482594e-05, -5.664747848402928e-05, -5.708033148899782e-05,
+        -5.7510561952284846e-05, -5.793815839592067e-05, -5.836310945165795e-05, -5.878540386123149e-05,
+        -5.920503047659557e-05, -5.9621978260179186e-05, -6.0036236285109425e-05, -6.044779373545826e-05,
+        -6.085663990646246e-05, -6.126276420475775e-05, -6.166615614858963e-05, -6.206680536804276e-05,
+        -6.246470160524302e-05, -6.285983471456669e-05, -6.325219466285137e-05, -6.364177152958735e-05,
+        -6.402855550711508e-05, -6.44125369008221e-05, -6.479370612932166e-05, -6.517205372463561e-05,
+        -6.554757033237868e-05, -6.592024671192343e-05, -6.629007373657662e-05, -6.665704239374387e-05,
+        -6.702114378508195e-05, -6.738236912666611e-05, -6.77407097491336e-05, -6.809615709783453e-05,
+        -6.844870273297711e-05, -6.879833832976685e-05, -6.914505567853355e-05, -6.948884668487257e-05,
+        -6.982970336976174e-05, -7.016761786969063e-05, -7.050258243676986e-05, -7.08345894388557e-05,
+        -7.116363135964506e-05, -7.148970079879194e-05, -7.181279047199881e-05, -7.213289321112e-05,
+        -7.245000196424562e-05, -7.276410979579797e-05, -7.30752098866074e-05, -7.338329553399098e-05,
+        -7.368836015183327e-05, -7.399039727064959e-05, -7.428940053765422e-05, -7.458536371682649e-05,
+        -7.487828068896247e-05, -7.516814545172741e-05, -7.545495211971085e-05, -7.573869492446411e-05,
+        -7.601936821454512e-05, -7.629696645555583e-05, -7.65714842301667e-05, -7.684291623815294e-05,
+        -7.711125729641124e-05, -7.737650233898161e-05, -7.763864641706329e-05, -7.789768469902625e-05,
+        -7.815361247041097e-05, -7.840642513393935e-05, -7.865611820950585e-05, -7.890268733417692e-05,
+        -7.914612826217439e-05, -7.938643686486866e-05, -7.96236091307539e-05, -7.985764116542592e-05,
+        -8.00885291915568e-05, -8.031626954886464e-05, -8.054085869407389e-05, -8.076229320088036e-05,
+        -8.098056975990718e-05, -8.119568517865204e-05, -8.140763638144178e-05, -8.161642040937131e-05,
+        -8.182203442024758e-05, -8.202447568852058e-05, -8.222374160522194e-05, -8.241982967788378e-05,
+        -8.261273753047048e-05, -8.280246290329137e-05, -8.298900365292211e-05, -8.317235775211005e-05,
+        -8.335252328968763e-05, -8.352949847047153e-05, -8.370328161516192e-05, -8.387387116023904e-05,
+        -8.404126565785607e-05, -8.420546377572122e-05, -8.43664642969879e-05, -8.452426612012838e-05,
+        -8.467886825881031e-05, -8.483026984177054e-05, -8.497847011267901e-05, -8.512346843000542e-05,
+        -8.526526426687826e-05, -8.540385721093718e-05, -8.55392469641882e-05, -8.567143334285144e-05,
+        -8.580041627720268e-05, -8.59261958114153e-05, -8.604877210339833e-05, -8.616814542462326e-05,
+        -8.628431615995866e-05, -8.639728480749045e-05, -8.65070519783463e-05, -8.661361839650911e-05,
+        -8.67169848986353e-05, -8.681715243385848e-05, -8.691412206360002e-05, -8.70078949613681e-05,
+        -8.709847241255837e-05, -8.718585581424549e-05, -8.727004667497822e-05, -8.735104661456319e-05,
+        -8.742885736384824e-05, -8.750348076450508e-05, -8.757491876880263e-05, -8.76431734393804e-05,
+        -8.770824694901855e-05, -8.777014158040175e-05, -8.782885972588043e-05, -8.788440388723046e-05,
+        -8.793677667540565e-05, -8.798598081029018e-05, -8.803201912044573e-05, -8.807489454285378e-05,
+        -8.811461012265841e-05, -8.815116901290021e-05, -8.818457447425141e-05, -8.821482987474517e-05,
+        -8.824193868950264e-05, -8.826590450045339e-05, -8.828673099605781e-05, -8.830442197102115e-05,
+        -8.831898132600695e-05, -8.83304130673453e-05, -8.833872130674032e-05, -8.834391026097092e-05,
+        -8.834598425159075e-05, -8.834494770462316e-05, -8.834080515025571e-05, -8.833356122252654e-05,
+        -8.832322065901228e-05, -8.830978830050965e-05, -8.829326909071538e-05, -8.827366807590174e-05,
+        -8.825099040459032e-05, -8.822524132722051e-05, -8.819642619581737e-05, -8.816455046365367e-05,
+        -8.812961968491067e-05, -8.809163951433589e-05, -8.805061570689538e-05, -8.800655411742645e-05,
+        -8.795946070028372e-05, -8.790934150898471e-05, -8.785620269585142e-05, -8.780005051164864e-05,
+        -8.774089130521977e-05, -8.767873152311892e-05, -8.76135777092413e-05, -8.75454365044493e-05,
+        -8.747431464619596e-05, -8.740021896814629e-05, -8.732315639979468e-05, -8.724313396607997e-05,
+        -8.716015878699776e-05, -8.707423807720902e-05, -8.698537914564708e-05, -8.689358939512098e-05,
+        -8.679887632191602e-05, -8.670124751539183e-05, -8.660071065757759e-05, -8.649727352276471e-05,
+        -8.639094397709623e-05, -8.628172997815374e-05, -8.61696395745422e-05, -8.605468090547118e-05,
+        -8.593686220033351e-05, -8.581619177828221e-05, -8.569267804780515e-05, -8.556632950629352e-05,
+        -8.543715473961319e-05, -8.530516242166929e-05, -8.517036131396898e-05, -8.503276026518465e-05,
+        -8.489236821071021e-05, -8.474919417221875e-05, -8.460324725721427e-05, -8.445453665858462e-05,
+        -8.430307165414773e-05, -8.414886160620122e-05, -8.399191596106253e-05, -8.383224424861418e-05,
+        -8.366985608183924e-05, -8.350476115636146e-05, -8.333696924997655e-05, -8.316649022218722e-05,
+        -8.299333401373213e-05, -8.281751064611291e-05, -8.263903022112106e-05, -8.245790292036086e-05,
+        -8.227413900476844e-05, -8.208774881413384e-05, -8.189874276661667e-05, -8.170713135825959e-05,
+        -8.151292516250348e-05, -8.131613482969532e-05, -8.111677108659742e-05, -8.091484473589571e-05,
+        -8.071036665570125e-05, -8.050334779905507e-05, -8.02937991934276e-05, -8.008173194021727e-05,
+        -7.986715721424519e-05, -7.965008626325439e-05, -7.943053040739764e-05, -7.920850103873198e-05,
+        -7.89840096207046e-05, -7.875706768764412e-05, -7.852768684424014e-05, -7.829587876503349e-05,
+        -7.806165519389264e-05, -7.78250279434975e-05, -7.758600889481487e-05, -7.734460999657815e-05,
+        -7.710084326475897e-05, -7.685472078204254e-05, -7.660625469730053e-05, -7.635545722505783e-05,
+        -7.610234064496387e-05, -7.584691730125948e-05, -7.55891996022384e-05, -7.532920001971574e-05,
+        -7.50669310884896e-05, -7.480240540579839e-05, -7.453563563078506e-05, -7.426663448395131e-05,
+        -7.399541474661395e-05, -7.372198926036469e-05, -7.344637092651743e-05, -7.316857270556582e-05,
+        -7.288860761663184e-05, -7.260648873691578e-05, -7.232222920114266e-05, -7.203584220101475e-05,
+        -7.174734098465177e-05, -7.145673885603995e-05, -7.116404917447122e-05, -7.086928535399056e-05,
+        -7.057246086283092e-05, -7.02735892228583e-05, -6.99726840090092e-05, -6.966975884872874e-05,
+        -6.936482742140423e-05, -6.905790345780648e-05, -6.874900073951732e-05, -6.843813309836921e-05,
+        -6.812531441587643e-05, -6.78105586226638e-05, -6.749387969790195e-05, -6.71752916687334e-05,
+        -6.685480860970045e-05, -6.653244464217917e-05, -6.620821393380185e-05, -6.58821306978821e-05,
+        -6.555420919284556e-05, -6.52244637216499e-05, -6.489290863120848e-05, -6.455955831181955e-05,
+        -6.422442719658089e-05, -6.388752976081866e-05, -6.354888052150155e-05, -6.320849403666928e-05,
+        -6.286638490484205e-05, -6.252256776445089e-05, -6.217705729324737e-05, -6.182986820772794e-05,
+        -6.148101526254557e-05, -6.113051324993373e-05, -6.077837699911397e-05, -6.042462137571918e-05,
+        -6.006926128120685e-05, -5.971231165227443e-05, -5.935378746027074e-05, -5.8993703710616406e-05,
+        -5.863207544221014e-05, -5.8268917726847856e-05, -5.790424566863611e-05, -5.753807440339951e-05,
+        -5.717041909810035e-05, -5.680129495024547e-05, -5.6430717187297756e-05, -5.6058701066094627e-05,
+        -5.5685261872254336e-05, -5.531041491958634e-05, -5.4934175549507615e-05, -5.455655913044965e-05,
+        -5.4177581057267914e-05, -5.379725675066097e-05, -5.3415601656571673e-05, -5.303263124560544e-05,
+        -5.264836101243478e-05, -5.2262806475217285e-05, -5.187598317499646e-05, -5.148790667512405e-05,
+        -5.10985925606613e-05, -5.070805643779715e-05, -5.031631393325231e-05, -4.992338069369869e-05,
+        -4.95292723851606e-05, -4.913400469243442e-05, -4.873759331849923e-05, -4.834005398392349e-05,
+        -4.794140242628299e-05, -4.754165439957169e-05, -4.7140825673608944e-05, -4.6738932033459036e-05,
+        -4.633598927884416e-05, -4.593201322355217e-05, -4.552701969485745e-05, -4.5121024532929904e-05,
+        -4.471404359024868e-05, -4.430609273102518e-05, -4.3897187830608736e-05, -4.3487344774909474e-05,
+        -4.307657945981226e-05, -4.266490779059237e-05, -4.2252345681331183e-05, -4.183890905434221e-05,
+        -4.142461383957948e-05, -4.100947597406484e-05, -4.059351140130023e-05, -4.017673607069634e-05,
+        -3.9759165936982874e-05, -3.9340816959638746e-05, -3.892170510231243e-05, -3.850184633224355e-05,
+        -3.808125661968412e-05, -3.76599519373302e-05, -3.7237948259736334e-05, -3.681526156275023e-05,
+        -3.639190782293836e-05, -3.596790301700766e-05, -3.5543263121240455e-05, -3.511800411092171e-05,
+        -3.4692141959763456e-05, -3.4265692639343e-05, -3.3838672118533935e-05, -3.341109636293316e-05,
+        -3.298298133430213e-05, -3.255434298999571e-05, -3.212519728239682e-05, -3.169556015836204e-05,
+        -3.1265447558648054e-05, -3.083487541735846e-05, -3.040385966138062e-05, -2.9972416209825296e-05,
+        -2.954056097346665e-05, -2.910830985419445e-05, -2.8675678744446836e-05, -2.8242683526664948e-05,
+        -2.7809340072731268e-05, -2.7375664243426526e-05, -2.6941671887867183e-05, -2.6507378842964925e-05,
+        -2.607280093287594e-05, -2.5637953968452348e-05, -2.520285374669323e-05, -2.4767516050207807e-05,
+        -2.4331956646661056e-05, -2.3896191288240273e-05, -2.3460235711113607e-05, -2.3024105634884424e-05,
+        -2.258781676206125e-05, -2.215138477751522e-05, -2.1714825347943682e-05, -2.1278154121344652e-05,
+        -2.084138672647828e-05, -2.0404538772334092e-05, -1.9967625847608533e-05, -1.953066352017127e-05,
+        -1.9093667336537438e-05, -1.8656652821352442e-05, -1.8219635476857215e-05, -1.7782630782375704e-05,
+        -1.7345654193786918e-05, -1.6908721143015323e-05, -1.6471847037501793e-05, -1.6035047259702826e-05,
+        -1.5598337166564605e-05, -1.5161732089020373e-05, -1.4725247331471935e-05, -1.4288898171289599e-05,
+        -1.3852699858298935e-05, -1.3416667614274903e-05, -1.2980816632447707e-05, -1.2545162076990064e-05,
+        -1.2109719082525584e-05, -1.1674502753627822e-05, -1.1239528164323024e-05, -1.0804810357593823e-05,
+        -1.0370364344896037e-05, -9.936205105656713e-06, -9.502347586794675e-06, -9.068806702225443e-06,
+        -8.635597332385602e-06, -8.202734323737344e-06, -7.770232488301851e-06, -7.338106603167762e-06,
+        -6.906371410023311e-06, -6.475041614672838e-06, -6.044131886572841e-06, -5.613656858349519e-06,
+        -5.183631125338718e-06, -4.754069245117851e-06, -4.324985737034575e-06, -3.896395081750928e-06,
+        -3.46831172078032e-06, -3.0407500560224644e-06, -2.6137244493148126e-06, -2.18724922197686e-06,
+        -1.761338654351428e-06, -1.336006985362487e-06, -9.112684120607396e-07, -4.871370891765282e-07,
+        -6.362712868514868e-08, 3.592474006461222e-07, 7.814724736893391e-07, 1.203034109396239e-06,
+        1.6239183712343127e-06, 2.044111367621654e-06, 2.4635992523483197e-06, 2.882368225015843e-06,
+        3.300404531454057e-06, 3.7176944641529486e-06, 4.1342243626749e-06, 4.549980614084982e-06,
+        4.964949653358522e-06, 5.379117963797626e-06, 5.792472077444215e-06, 6.204998575492762e-06,
+        6.616684088689541e-06, 7.027515297748718e-06, 7.4374789337457575e-06, 7.846561778518596e-06,
+        8.25475066507146e-06, 8.662032477962061e-06, 9.068394153700423e-06, 9.47382268114041e-06,
+        9.878305101859544e-06, 1.0281828510550563e-05, 1.0684380055406296e-05, 1.1085946938493362e-05,
+        1.1486516416135931e-05, 1.1886075799292273e-05, 1.2284612453918855e-05, 1.2682113801351573e-05,
+        1.3078567318664759e-05, 1.3473960539044494e-05, 1.3868281052142371e-05, 1.426151650444757e-05,
+        1.4653654599630774e-05, 1.5044683098910887e-05, 1.543458982139872e-05, 1.5823362644454614e-05,
+        1.6210989504026902e-05, 1.6597458395007198e-05, 1.698275737156352e-05, 1.7366874547481694e-05,
+        1.7749798096502933e-05, 1.81315162526607e-05, 1.8512017310604415e-05, 1.889128962593865e-05,
+        1.9269321615540448e-05, 1.9646101757883764e-05, 2.0021618593365466e-05, 2.0395860724615964e-05,
+        2.0768816816819875e-05, 2.1140475598029527e-05, 2.151082585946703e-05, 2.1879856455837082e-05,
+        2.2247556305632997e-05, 2.2613914391431623e-05, 2.2978919760197955e-05, 2.3342561523581665e-05,
+        2.3704828858202334e-05, 2.4065711005949826e-05, 2.442519727426382e-05, 2.4783277036425917e-05,
+        2.5139939731833695e-05, 2.549517486629057e-05, 2.584897201226993e-05, 2.620132080919957e-05,
+        2.6552210963724137e-05, 2.6901632249980762e-05, 2.724957450985604e-05, 2.759602765325791e-05,
+        2.7940981658367682e-05, 2.828442657189735e-05, 2.8626352509348656e-05, 2.896674965525786e-05,
+        2.9305608263445994e-05, 2.9642918657268846e-05, 2.9978671229852635e-05, 3.0312856444334377e-05,
+        3.064546483410374e-05, 3.0976487003029723e-05, 3.1305913625696825e-05, 3.163373544763383e-05,
+        3.1959943285531e-05, 3.2284528027472005e-05, 3.2607480633145996e-05, 3.2928792134067034e-05,
+        3.3248453633787575e-05, 3.356645630811148e-05, 3.3882791405293734e-05, 3.41974502462543e-05,
+        3.451042422477252e-05, 3.48217048076927e-05, 3.513128353511247e-05, 3.54391520205847e-05,
+        3.574530195130047e-05, 3.6049725088277594e-05, 3.635241326654589e-05, 3.6653358395330574e-05,
+        3.6952552458224283e-05, 3.724998751337125e-05, 3.7545655693632366e-05, 3.7839549206755745e-05,
+        3.813166033554748e-05, 3.8421981438030485e-05, 3.871050494760574e-05, 3.8997223373215445e-05,
+        3.9282129299490806e-05, 3.95652153869051e-05, 3.9846474371926754e-05, 4.012589906715901e-05,
+        4.0403482361487585e-05, 4.067921722022118e-05, 4.095309668522154e-05, 4.122511387504578e-05,
+        4.1495261985071456e-05, 4.176353428762668e-05, 4.202992413211622e-05, 4.229442494514452e-05,
+        4.255703023062905e-05, 4.281773356992375e-05, 4.307652862192619e-05, 4.333340912319348e-05,
+        4.358836888804294e-05, 4.384140180866454e-05, 4.4092501855215585e-05, 4.434166307592095e-05,
+        4.458887959716879e-05, 4.483414562360521e-05, 4.507745543821877e-05, 4.531880340243355e-05,
+        4.555818395618759e-05, 4.579559161801409e-05, 4.603102098512265e-05, 4.626446673346891e-05,
+        4.649592361783086e-05, 4.672538647187733e-05, 4.6952850208229456e-05, 4.717830981852738e-05,
+        4.740176037349155e-05, 4.7623197022975186e-05, 4.784261499602224e-05, 4.80600096009193e-05,
+        4.827537622523838e-05, 4.8488710335888865e-05, 4.870000747915457e-05, 4.890926328073801e-05,
+        4.9116473445791505e-05, 4.932163375895805e-05, 4.952474008439376e-05, 4.972578836580295e-05,
+        4.9924774626457323e-05, 5.012169496922321e-05, 5.0316545576574915e-05, 5.050932271061777e-05,
+        5.070002271309582e-05, 5.0888642005403244e-05, 5.1075177088594875e-05, 5.125962454338732e-05,
+        5.144198103016188e-05, 5.1622243288966245e-05, 5.180040813950654e-05, 5.1976472481141036e-05,
+        5.215043329287367e-05, 5.2322287633337183e-05, 5.249203264078074e-05, 5.265966553305267e-05,
+        5.282518360757452e-05, 5.298858424132104e-05, 5.31498648907937e-05, 5.330902309198813e-05,
+        5.3466056460364134e-05, 5.362096269081196e-05, 5.377373955760846e-05, 5.392438491438284e-05,
+        5.4072896694068004e-05, 5.4219272908858215e-05, 5.436351165015556e-05, 5.450561108852343e-05,
+        5.464556947362574e-05, 5.4783385134174024e-05, 5.491905647786408e-05, 5.5052581991315554e-05,
+        5.518396024000298e-05, 5.5313189868191384e-05, 5.544026959886114e-05, 5.5565198233635155e-05,
+        5.5687974652703594e-05, 5.5808597814742145e-05, 5.5927066756830656e-05, 5.6043380594370376e-05,
+        5.6157538520993116e-05, 5.626953980847225e-05, 5.6379383806630985e-05, 5.648706994324422e-05,
+        5.659259772394274e-05, 5.669596673211208e-05, 5.679717662878609e-05, 5.689622715254451e-05,
+        5.699311811940129e-05, 5.7087849422694106e-05, 5.718042103297065e-05, 5.727083299787234e-05,
+        5.735908544201247e-05, 5.744517856685725e-05, 5.75291126505988e-05, 5.761088804803107e-05,
+        5.7690505190416015e-05, 5.776796458535619e-05, 5.784326681665611e-05, 5.7916412544186433e-05,
+        5.798740250374358e-05, 5.805623750690911e-05, 5.812291844090195e-05, 5.81874462684338e-05,
+        5.824982202755745e-05, 5.831004683151426e-05, 5.8368121868581044e-05, 5.8424048401910126e-05,
+        5.8477827769372106e-05, 5.852946138339277e-05, 5.857895073078677e-05, 5.862629737259224e-05,
+        5.8671502943901466e-05, 5.871456915368717e-05, 5.875549778462953e-05, 5.879429069294002e-05,
+        5.88309498081797e-05, 5.8865477133080346e-05, 5.889787474335898e-05, 5.892814478753169e-05,
+        5.895628948672607e-05, 5.898231113449014e-05, 5.90062120965974e-05, 5.90279948108533e-05,
+        5.9047661786896034e-05, 5.906521560599746e-05, 5.908065892085936e-05, 5.909399445541036e-05,
+        5.9105225004597843e-05, 5.911435343417868e-05, 5.912138268050813e-05, 5.9126315750326685e-05,
+        5.912915572054259e-05, 5.91299057380162e-05, 5.912856901933724e-05, 5.912514885060393e-05,
+        5.911964858719772e-05, 5.911207165355637e-05, 5.910242154294564e-05, 5.9090701817228015e-05,
+        5.907691610662865e-05, 5.906106810950152e-05, 5.904316159209094e-05, 5.902320038829237e-05,
+        5.900118839941094e-05, 5.897712959391812e-05, 5.895102800720509e-05, 5.892288774133601e-05,
+        5.889271296479792e-05, 5.88605079122488e-05, 5.88262768842641e-05, 5.8790024247080544e-05,
+        5.875175443233901e-05, 5.871147193682425e-05, 5.8669181322203465e-05, 5.8624887214763e-05,
+        5.85785943051422e-05, 5.853030734806641e-05, 5.848003116207735e-05, 5.842777062926228e-05,
+        5.837353069498028e-05, 5.831731636758804e-05, 5.825913271816212e-05, 5.819898488022094e-05,
+        5.813687804944394e-05, 5.8072817483389354e-05, 5.800680850121014e-05, 5.793885648336808e-05,
+        5.786896687134603e-05, 5.7797145167358144e-05, 5.772339693405923e-05, 5.764772779425157e-05,
+        5.757014343059022e-05, 5.749064958528657e-05, 5.740925205981057e-05, 5.7325956714590434e-05,
+        5.7240769468712076e-05, 5.7153696299615066e-05, 5.7064743242788564e-05, 5.697391639146484e-05,
+        5.6881221896311004e-05, 5.6786665965120366e-05, 5.6690254862499765e-05, 5.659199490955803e-05,
+        5.649189248359164e-05, 5.638995401776813e-05, 5.6286186000809735e-05, 5.618059497667359e-05,
+        5.6073187544231606e-05, 5.596397035694954e-05, 5.5852950122561546e-05, 5.574013360274778e-05,
+        5.562552761280664e-05, 5.550913902132735e-05, 5.539097474986009e-05, 5.52710417725878e-05,
+        5.514934711599149e-05, 5.502589785851901e-05, 5.490070113024869e-05, 5.477376411255516e-05,
+        5.464509403776988e-05, 5.451469818884427e-05, 5.438258389900919e-05, 5.4248758551433356e-05,
+        5.411322957888049e-05, 5.3976004463367135e-05, 5.383709073581504e-05, 5.369649597570648e-05,
+        5.3554227810737826e-05, 5.341029391646771e-05, 5.326470201597023e-05, 5.311745987948206e-05,
+        5.2968575324049576e-05, 5.281805621317799e-05, 5.266591045647481e-05, 5.251214600929434e-05,
+        5.235677087238187e-05, 5.2199793091514824e-05, 5.204122075714321e-05, 5.188106200403242e-05,
+        5.171932501089898e-05, 5.1556018000051054e-05, 5.139114923702278e-05, 5.1224727030213365e-05,
+        5.105675973051785e-05, 5.088725573096578e-05, 5.07162234663502e-05, 5.054367141286251e-05,
+        5.036960808772142e-05, 5.019404204880569e-05, 5.0016981894279924e-05, 4.9838436262225956e-05,
+        4.965841383027035e-05, 4.947692331520852e-05, 4.929397347263366e-05, 4.91095730965607e-05,
+        4.892373101904846e-05, 4.873645610982597e-05, 4.854775727591486e-05, 4.8357643461249e-05,
+        4.81661236462984e-05, 4.797320684768809e-05, 4.777890211781633e-05, 4.7583218544477696e-05,
+        4.7386165250478425e-05, 4.7187751393253506e-05, 4.698798616448652e-05, 4.6786878789723095e-05,
+        4.6584438527987056e-05, 4.638067467139804e-05, 4.617559654478235e-05, 4.5969213505290236e-05,
+        4.576153494200598e-05, 4.555257027556411e-05, 4.534232895775703e-05, 4.513082047115257e-05,
+        4.4918054328699646e-05, 4.470404007334332e-05, 4.448878727763114e-05, 4.427230554332725e-05,
+        4.405460450101624e-05, 4.383569380971692e-05, 4.36155831564893e-05, 4.3394282256040445e-05,
+        4.317180085033535e-05, 4.2948148708203056e-05, 4.272333562494094e-05, 4.249737142192482e-05,
+        4.227026594621512e-05, 4.2042029070159306e-05, 4.1812670691001806e-05, 4.158220073048628e-05,
+        4.1350629134460134e-05, 4.111796587248371e-05, 4.088422093742938e-05, 4.064940434509026e-05,
+        4.041352613378188e-05, 4.017659636394659e-05, 3.9938625117755577e-05, 3.969962249871726e-05,
+        3.945959863127494e-05, 3.921856366041493e-05, 3.8976527751265324e-05, 3.873350108870357e-05,
+        3.8489493876953884e-05, 3.824451633919472e-05, 3.7998578717160515e-05, 3.775169127074362e-05,
+        3.7503864277595967e-05, 3.725510803273531e-05, 3.7005432848142395e-05, 3.675484905236827e-05,
+        3.650336699013659e-05, 3.625099702194274e-05, 3.5997749523661196e-05, 3.574363488614524e-05,
+        3.548866351482875e-05, 3.523284582933405e-05, 3.497619226307146e-05, 3.47187132628414e-05,
+        3.446041928844179e-05, 3.4201320812268e-05, 3.394142831891606e-05, 3.36807523047913e-05,
+        3.3419303277706645e-05, 3.315709175649081e-05, 3.289412827059142e-05, 3.263042335967892e-05,
+        3.236598757325052e-05, 3.210083147024036e-05, 3.183496561861872e-05, 3.156840059500297e-05,
+        3.130114698425891e-05, 3.1033215379112466e-05, 3.076461637974982e-05, 3.0495360593430046e-05,
+        3.022545863409092e-05, 2.995492112195629e-05, 2.968375868314241e-05, 2.94119819492715e-05,
+        2.9139601557073536e-05, 2.8866628148001812e-05, 2.8593072367841604e-05, 2.831894486631692e-05,
+        2.8044256296706376e-05, 2.7769017315450466e-05, 2.7493238581762805e-05, 2.7216930757247193e-05,
+        2.6940104505506943e-05, 2.666277049175654e-05, 2.6384939382440087e-05, 2.6106621844841737e-05,
+        2.5827828546700347e-05, 2.5548570155830395e-05, 2.5268857339731214e-05, 2.4988700765209836e-05,
+        2.4708111097992674e-05, 2.4427099002349726e-05, 2.4145675140705562e-05, 2.386385017326737e-05,
+        2.3581634757637498e-05, 2.329903954843998e-05, 2.301607519693658e-05, 2.2732752350654946e-05,
+        2.2449081653004133e-05, 2.2165073742904245e-05, 2.1880739254409966e-05, 2.159608881633185e-05,
+        2.1311133051866785e-05, 2.1025882578223307e-05, 2.0740348006245204e-05, 2.0454539940045017e-05,
+        2.016846897663247e-05, 1.988214570554065e-05, 1.959558070846185e-05, 1.930878455887556e-05,
+        1.902176782168012e-05, 1.8734541052832258e-05, 1.8447114798974036e-05, 1.8159499597073482e-05,
+        1.7871705974058793e-05, 1.7583744446454735e-05, 1.7295625520019408e-05, 1.7007359689389125e-05,
+        1.6718957437711214e-05, 1.6430429236290958e-05, 1.6141785544228166e-05, 1.5853036808066136e-05,
+        1.556419346142846e-05, 1.5275265924669758e-05, 1.4986264604520617e-05, 1.4697199893733985e-05,
+        1.4408082170731142e-05, 1.4118921799256603e-05, 1.382972912802073e-05, 1.3540514490357468e-05,
+        1.3251288203875943e-05, 1.296206057011025e-05, 1.2672841874178826e-05, 1.238364238443893e-05,
+        1.2094472352139863e-05, 1.1805342011086386e-05, 1.151626157729744e-05, 1.1227241248662712e-05,
+        1.0938291204609775e-05, 1.0649421605762928e-05, 1.0360642593606754e-05, 1.0071964290157527e-05,
+        9.783396797622447e-06, 9.494950198072856e-06, 9.206634553111385e-06, 8.918459903541568e-06,
+        8.630436269038206e-06, 8.342573647826393e-06, 8.05488201634824e-06, 7.767371328944549e-06,
+        7.4800515175262395e-06, 7.192932491258549e-06, 6.906024136233124e-06, 6.619336315154956e-06,
+        6.3328788670232e-06, 6.04666160681407e-06, 5.760694325163667e-06, 5.474986788059784e-06,
+        5.189548736522046e-06, 4.904389886297173e-06, 4.619519927548758e-06, 4.334948524545082e-06,
+        4.050685315357956e-06, 3.766739911553317e-06, 3.4831218978868107e-06, 3.1998408320068937e-06,
+        2.91690624414984e-06, 2.6343276368390118e-06, 2.352114484591656e-06, 2.070276233618403e-06,
+        1.7888223015276238e-06, 1.5077620770380853e-06, 1.2271049196795633e-06, 9.468601595085163e-07,
+        6.670370968137836e-07, 3.87645001835316e-07, 1.0869311447026068e-07, -1.6980935600185215e-07,
+        -4.478532312098953e-07, -7.254293639662186e-07, -1.002528638551577e-06, -1.2791419709870688e-06,
+        -1.555260309317894e-06, -1.8308746338820911e-06, -2.1059759575850717e-06, -2.3805553261718537e-06,
+        -2.654603818498989e-06, -2.9281125467975704e-06, -3.201072656947215e-06, -3.4734753287351265e-06,
+        -3.745311776120159e-06, -4.016573247498492e-06, -4.2872510259584535e-06, -4.557336429542851e-06,
+        -4.826820811506357e-06, -5.095695560565299e-06, -5.36395210115491e-06, -5.631581893682354e-06,
+        -5.898576434772019e-06, -6.164927257517768e-06, -6.430625931730005e-06, -6.695664064174695e-06,
+        -6.960033298823574e-06, -7.223725317089609e-06, -7.48673183807182e-06, -7.749044618787265e-06,
+        -8.010655454414946e-06, -8.271556178521037e-06, -8.53173866329927e-06, -8.79119481979591e-06,
+        -9.049916598143935e-06, -9.307895987784455e-06, -9.56512501769931e-06, -9.82159575662893e-06,
+        -1.0077300313294803e-05, -1.0332230836623517e-05, -1.0586379515960398e-05, -1.0839738581287307e-05,
+        -1.1092300303441082e-05, -1.1344056994321255e-05, -1.1595001007101693e-05, -1.1845124736443487e-05,
+        -1.2094420618697312e-05, -1.234288113211256e-05, -1.2590498797041654e-05, -1.2837266176136122e-05,
+        -1.3083175874553507e-05, -1.3328220540150332e-05, -1.3572392863680149e-05, -1.3815685578988615e-05,
+        -1.4058091463206996e-05, -1.4299603336937319e-05, -1.4540214064447815e-05, -1.4779916553854144e-05,
+        -1.5018703757309282e-05, -1.5256568671181113e-05, -1.5493504336240043e-05, -1.572950383783307e-05,
+        -1.5964560306062047e-05, -1.6198666915959886e-05, -1.6431816887665854e-05, -1.666400348659236e-05,
+        -1.68952200236012e-05, -1.712545985516603e-05, -1.735471638353873e-05, -1.7582983056916888e-05,
+        -1.7810253369601752e-05, -1.8036520862158822e-05, -1.8261779121582355e-05, -1.848602178144123e-05,
+        -1.8709242522039384e-05, -1.8931435070568926e-05, -1.9152593201256195e-05, -1.9372710735513974e-05,
+        -1.959178154208821e-05, -1.9809799537197638e-05, -2.0026758684682472e-05, -2.0242652996140535e-05,
+        -2.0457476531067536e-05, -2.0671223396995627e-05, -2.0883887749623477e-05, -2.109546379295527e-05,
+        -2.1305945779426598e-05, -2.1515328010033566e-05, -2.172360483446337e-05, -2.1930770651214395e-05,
+        -2.2136819907722865e-05, -2.2341747100483886e-05, -2.25455467751666e-05, -2.2748213526734e-05,
+        -2.294974199955927e-05, -2.3150126887534988e-05, -2.3349362934187616e-05, -2.354744493278682e-05,
+        -2.3744367726448133e-05, -2.3940126208243717e-05, -2.41347153213009e-05, -2.4328130058907378e-05,
+        -2.4520365464605982e-05, -2.4711416632297924e-05, -2.4901278706330876e-05, -2.5089946881598296e-05,
+        -2.527741640362635e-05, -2.5463682568666956e-05, -2.5648740723780695e-05, -2.5832586266927674e-05,
+        -2.6015214647046293e-05, -2.6196621364134985e-05, -2.6376801969334934e-05, -2.6555752065003578e-05,
+        -2.6733467304791268e-05, -2.6909943393717447e-05, -2.708517608823831e-05, -2.7259161196316624e-05,
+        -2.7431894577492245e-05, -2.7603372142943743e-05, -2.777358985555467e-05, -2.7942543729975643e-05,
+        -2.8110229832679564e-05, -2.827664428202468e-05, -2.8441783248305922e-05, -2.8605642953810457e-05,
+        -2.8768219672869565e-05, -2.8929509731909165e-05, -2.9089509509493633e-05, -2.9248215436376304e-05,
+        -2.940562399553942e-05, -2.9561731722239295e-05, -2.9716535204042564e-05, -2.9870031080868406e-05,
+        -3.0022216045020827e-05, -3.0173086841223466e-05, -3.032264026665204e-05, -3.0470873170965628e-05,
+        -3.061778245633137e-05, -3.076336507745542e-05, -3.0907618041603004e-05, -3.105053840862156e-05,
+        -3.119212329096316e-05, -3.13323698537002e-05, -3.147127531454255e-05, -3.1608836943854424e-05,
+        -3.174505206466401e-05, -3.187991805267413e-05, -3.2013432336273376e-05, -3.214559239654002e-05,
+        -3.227639576724888e-05, -3.240584003487468e-05, -3.253392283858987e-05, -3.266064187026752e-05,
+        -3.27859948744757e-05, -3.2909979648473894e-05, -3.303259404220663e-05, -3.315383595829574e-05,
+        -3.327370335202653e-05, -3.3392194231339706e-05, -3.3509306656813665e-05, -3.362503874165126e-05,
+        -3.373938865165872e-05, -3.3852354605229614e-05, -3.396393487331992e-05, -3.407412777942496e-05,
+        -3.41829316995548e-05, -3.4290345062207166e-05, -3.4396366348336e-05, -3.450099409132392e-05,
+        -3.460422687694624e-05, -3.470606334333661e-05, -3.480650218095257e-05, -3.4905542132534e-05,
+        -3.50031819930651e-05, -3.509942060973237e-05, -3.519425688187839e-05, -3.528768976095759e-05,
+        -3.53797182504896e-05, -3.547034140600743e-05, -3.555955833500824e-05, -3.5647368196900445e-05,
+        -3.5733770202946205e-05, -3.581876361620835e-05, -3.590234775148909e-05, -3.598452197527196e-05,
+        -3.606528570565787e-05, -3.614463841230404e-05, -3.622257961635504e-05, -3.629910889037861e-05,
+        -3.637422585829442e-05, -3.644793019530503e-05, -3.652022162782148e-05, -3.659109993339133e-05,
+        -3.666056494062072e-05, -3.6728616529096715e-05, -3.679525462930946e-05, -3.686047922256895e-05,
+        -3.692429034092316e-05, -3.698668806707445e-05, -3.70476725342912e-05, -3.7107243926320686e-05,
+        -3.716540247730002e-05, -3.7222148471663475e-05, -3.7277482244050596e-05, -3.7331404179211386e-05,
+        -3.738391471190891e-05, -3.7435014326821776e-05, -3.748470355844504e-05, -3.753298299098769e-05,
+        -3.7579853258270483e-05, -3.7625315043621676e-05, -3.766936907976919e-05, -3.7712016148734416e-05,
+        -3.775325708172119e-05, -3.7793092759006546e-05, -3.783152410982569e-05, -3.786855211226036e-05,
+        -3.7904177793120126e-05, -3.793840222782756e-05, -3.7971226540296734e-05, -3.800265190281483e-05,
+        -3.803267953591799e-05, -3.806131070826954e-05, -3.808854673653297e-05, -3.8114388985245905e-05,
+        -3.813883886669213e-05, -3.816189784077085e-05, -3.8183567414865565e-05, -3.820384914371199e-05,
+        -3.82227446292627e-05, -3.824025552055112e-05, -3.825638351355523e-05, -3.827113035105732e-05,
+        -3.828449782250458e-05, -3.8296487763867525e-05, -3.8307102057495394e-05, -3.831634263197275e-05,
+        -3.832421146197271e-05, -3.8330710568109364e-05, -3.833584201678908e-05, -3.8339607920059826e-05,
+        -3.834201043545896e-05, -3.834305176586069e-05, -3.8342734159320725e-05, -3.834105990892076e-05,
+        -3.833803135261071e-05, -3.833365087305009e-05, -3.8327920897447844e-05, -3.832084389740052e-05,
+        -3.8312422388729694e-05, -3.83026589313178e-05, -3.8291556128942366e-05, -3.8279116629109464e-05,
+        -3.826534312288504e-05, -3.8250238344725834e-05, -3.8233805072308465e-05, -3.821604612635726e-05,
+        -3.819696437047086e-05, -3.8176562710948125e-05, -3.8154844096611355e-05, -3.8131811518629725e-05,
+        -3.8107468010340774e-05, -3.808181664707049e-05, -3.805486054595297e-05, -3.802660286574763e-05,
+        -3.799704680665662e-05, -3.796619561013969e-05, -3.793405255872869e-05, -3.790062097584093e-05,
+        -3.786590422559055e-05, -3.782990571259976e-05, -3.779262888180826e-05, -3.775407721828165e-05,
+        -3.771425424701849e-05, -3.767316353275689e-05, -3.76308086797794e-05, -3.75871933317166e-05,
+        -3.75423211713502e-05, -3.749619592041502e-05, -3.744882133939889e-05, -3.740020122734272e-05,
+        -3.735033942163889e-05, -3.7299239797828455e-05, -3.724690626939757e-05, -3.719334278757299e-05,
+        -3.7138553341116114e-05, -3.7082541956116515e-05, -3.702531269578359e-05, -3.6966869660238115e-05,
+        -3.690721698630285e-05, -3.6846358847291196e-05, -3.678429945279569e-05, -3.672104304847539e-05,
+        -3.665659391584188e-05, -3.6590956372044737e-05, -3.6524134769656424e-05, -3.6456133496455065e-05,
+        -3.63869569752076e-05, -3.6316609663451074e-05, -3.6245096053273966e-05, -3.617242067109475e-05,
+        -3.609858807744277e-05, -3.6023602866734416e-05, -3.594746966705207e-05, -3.5870193139918976e-05,
+        -3.579177798007637e-05, -3.57122289152563e-05, -3.563155070595698e-05, -3.5549748145215634e-05,
+        -3.546682605837994e-05, -3.5382789302880245e-05, -3.529764276800041e-05, -3.521139137464643e-05,
+        -3.5124040075116746e-05, -3.5035593852870605e-05, -3.494605772229447e-05, -3.485543672847071e-05,
+        -3.4763735946941984e-05, -3.467096048347729e-05, -3.45771154738374e-05, -3.448220608353756e-05,
+        -3.4386237507611843e-05, -3.428921497037548e-05, -3.419114372518656e-05, -3.409202905420671e-05,
+        -3.399187626816404e-05, -3.389069070611017e-05, -3.378847773518212e-05, -3.3685242750359046e-05,
+        -3.358099117422232e-05, -3.3475728456710535e-05, -3.336946007487949e-05, -3.32621915326559e-05,
+        -3.3153928360594954e-05, -3.304467611563423e-05, -3.293444038084975e-05, -3.2823226765208235e-05,
+        -3.271104090332221e-05, -3.2597888455203396e-05, -3.248377510601325e-05, -3.236870656581706e-05,
+        -3.225268856933484e-05, -3.21357268756912e-05, -3.2017827268167606e-05, -3.189899555395217e-05,
+        -3.177923756388846e-05, -3.165855915222595e-05, -3.153696619636776e-05, -3.1414464596618794e-05,
+        -3.129106027593559e-05, -3.116675917967164e-05, -3.104156727532612e-05, -3.091549055229014e-05,
+        -3.078853502159271e-05, -3.066070671564654e-05, -3.053201168799576e-05, -3.0402456013058374e-05,
+        -3.0272045785873988e-05, -3.0140787121845997e-05, -3.0008686156488474e-05, -2.9875749045167303e-05,
+        -2.9741981962846267e-05, -2.9607391103830077e-05, -2.9471982681507187e-05, -2.933576292809199e-05,
+        -2.9198738094369793e-05, -2.9060914449435946e-05, -2.892229828044059e-05, -2.8782895892330583e-05,
+        -2.8642713607589298e-05, -2.8501757765980467e-05, -2.8360034724287993e-05, -2.821755085605664e-05,
+        -2.807431255133526e-05, -2.793032621641609e-05, -2.7785598273574686e-05, -2.7640135160812423e-05,
+        -2.7493943331595153e-05, -2.7347029254593313e-05, -2.7199399413424415e-05, -2.7051060306389933e-05,
+        -2.690201844621789e-05, -2.6752280359800216e-05, -2.6601852587935397e-05, -2.6450741685064215e-05,
+        -2.6298954219013885e-05, -2.6146496770733805e-05, -2.599337593403773e-05, -2.5839598315341022e-05,
+        -2.5685170533402834e-05, -2.553009921906197e-05, -2.537439101497928e-05, -2.5218052575376563e-05,
+        -2.506109056577553e-05, -2.4903511662736477e-05, -2.474532255360028e-05, -2.458652993622449e-05,
+        -2.442714051872582e-05, -2.42671610192197e-05, -2.4106598165557755e-05, -2.3945458695070508e-05,
+        -2.3783749354305304e-05, -2.362147689876586e-05, -2.3458648092655363e-05, -2.3295269708614457e-05,
+        -2.313134852746119e-05, -2.296689133793369e-05, -2.2801904936428276e-05, -2.263639612674342e-05,
+        -2.247037171981685e-05, -2.2303838533469735e-05, -2.2136803392148146e-05, -2.196927312666208e-05,
+        -2.1801254573929754e-05, -2.1632754576719067e-05, -2.1463779983387564e-05, -2.1294337647627617e-05,
+        -2.1124434428208847e-05, -2.095407718871895e-05, -2.0783272797309563e-05, -2.06120281264377e-05,
+        -2.0440350052608913e-05, -2.026824545612482e-05, -2.009572122082318e-05, -1.992278423382544e-05,
+        -1.9749441385280793e-05, -1.9575699568110595e-05, -1.9401565677753482e-05, -1.9227046611914548e-05,
+        -1.905214927030745e-05, -1.8876880554404373e-05, -1.8701247367180046e-05, -1.8525256612862378e-05,
+        -1.8348915196675972e-05, -1.8172230024593772e-05, -1.7995208003084716e-05, -1.781785603886231e-05,
+        -1.7640181038632616e-05, -1.7462189908847433e-05, -1.7283889555449894e-05, -1.710528688362865e-05,
+        -1.6926388797568654e-05, -1.6747202200200205e-05, -1.6567733992954235e-05, -1.638799107551218e-05,
+        -1.6207980345558834e-05, -1.6027708698538623e-05, -1.584718302740742e-05, -1.566641022238606e-05,
+        -1.5485397170718136e-05, -1.5304150756423015e-05, -1.512267786005165e-05, -1.4940985358446424e-05,
+        -1.4759080124494098e-05, -1.4576969026886603e-05, -1.439465892987799e-05, -1.4212156693042963e-05,
+        -1.4029469171035195e-05, -1.3846603213351276e-05, -1.3663565664086475e-05, -1.348036336169978e-05,
+        -1.3297003138772238e-05, -1.3113491821773204e-05, -1.2929836230818666e-05, -1.2746043179438642e-05,
+        -1.2562119474340761e-05, -1.2378071915174696e-05, -1.2193907294296396e-05, -1.2009632396537898e-05,
+        -1.1825253998969509e-05, -1.1640778870671196e-05, -1.145621377250058e-05, -1.1271565456859224e-05,
+        -1.1086840667465811e-05, -1.0902046139123948e-05, -1.0717188597492965e-05, -1.0532274758862975e-05,
+        -1.0347311329924962e-05, -1.0162305007543353e-05, -9.977262478532866e-06, -9.792190419430814e-06,
+        -9.607095496272283e-06, -9.421984364370238e-06, -9.23686366808791e-06, -9.051740040620555e-06,
+        -8.866620103770735e-06, -8.68151046773149e-06, -8.496417730861525e-06, -8.311348479472294e-06,
+        -8.126309287604659e-06, -7.941306716815482e-06, -7.756347315957696e-06, -7.571437620968535e-06,
+        -7.386584154649925e-06, -7.201793426458471e-06, -7.017071932291849e-06, -6.832426154274008e-06,
+        -6.647862560546742e-06, -6.463387605058422e-06, -6.279007727351578e-06, -6.09472935235775e-06,
+        -5.910558890189115e-06, -5.726502735928916e-06, -5.5425672694288865e-06, -5.358758855101453e-06,
+        -5.175083841715077e-06, -4.9915485621948e-06, -4.808159333415089e-06, -4.62492245600181e-06,
+        -4.441844214130422e-06, -4.2589308753259166e-06, -4.076188690263356e-06, -3.893623892574035e-06,
+        -3.7112426986440007e-06, -3.529051307422225e-06, -3.3470559002222994e-06, -3.1652626405325555e-06,
+        -2.9836776738180095e-06, -2.8023071273346313e-06, -2.6211571099332416e-06, -2.4402337118735975e-06,
+        -2.2595430046320774e-06, -2.079091040717727e-06, -1.8988838534806905e-06, -1.7189274569303042e-06,
+        -1.539227845549883e-06, -1.3597909941103758e-06, -1.1806228574906855e-06, -1.0017293704951178e-06,
+        -8.231164476701935e-07, -6.447899831284926e-07, -4.6675585036971624e-07, -2.8901990210063174e-07,
+        -1.1158797006211599e-07, 6.553413514875661e-08, 2.42340624253392e-07, 4.1882572946101067e-07,
+        5.949837046454449e-07, 7.708088255127391e-07, 9.462953897723444e-07, 1.1214377173063709e-06,
+        1.2962301503381598e-06, 1.4706670535950751e-06, 1.6447428144786916e-06, 1.8184518432253672e-06,
+        1.9917885730729347e-06, 2.1647474604190153e-06, 2.3373229849867426e-06, 2.5095096499808278e-06,
+        2.6813019822472153e-06, 2.8526945324311003e-06, 3.0236818751347396e-06, 3.1942586090693743e-06,
+        3.3644193572140903e-06, 3.5341587669650828e-06, 3.7034715102879637e-06, 3.872352283871004e-06,
+        4.040795809271372e-06, 4.208796833066042e-06, 4.376350126999427e-06, 4.543450488126117e-06,
+        4.710092738958259e-06, 4.876271727610091e-06, 5.0419823279375555e-06, 5.207219439682119e-06,
+        5.371977988611341e-06, 5.536252926654062e-06, 5.700039232042755e-06, 5.863331909446322e-06,
+        6.026125990108751e-06, 6.18841653197961e-06, 6.350198619851843e-06, 6.511467365487804e-06,
+        6.672217907754644e-06, 6.832445412749992e-06, 6.99214507393324e-06, 7.151312112248778e-06,
+        7.309941776256053e-06, 7.468029342250372e-06, 7.625570114386875e-06, 7.782559424802885e-06,
+        7.938992633739788e-06, 8.094865129659387e-06, 8.250172329366438e-06, 8.404909678122115e-06,
+        8.559072649760172e-06, 8.712656746803803e-06, 8.865657500575736e-06, 9.018070471312619e-06,
+        9.16989124827618e-06, 9.321115449859551e-06, 9.471738723698001e-06, 9.62175674677654e-06,
+        9.771165225533018e-06, 9.919959895964939e-06, 1.0068136523733007e-05, 1.0215690904259649e-05,
+        1.0362618862833895e-05, 1.0508916254707487e-05, 1.0654578965195778e-05, 1.0799602909771505e-05,
+        1.0943984034164737e-05, 1.1087718314452035e-05, 1.1230801757153983e-05, 1.1373230399323544e-05,
+        1.1515000308639754e-05, 1.1656107583493368e-05, 1.1796548353078975e-05, 1.1936318777478405e-05,
+        1.2075415047746457e-05, 1.221383338599715e-05, 1.2351570045484133e-05, 1.2488621310682781e-05,
+        1.2624983497372756e-05, 1.2760652952714046e-05, 1.2895626055325082e-05, 1.3029899215361152e-05,
+        1.3163468874586795e-05, 1.3296331506451736e-05, 1.3428483616163704e-05, 1.3559921740756663e-05,
+        1.3690642449164415e-05, 1.3820642342286737e-05, 1.3949918053057606e-05, 1.407846624651175e-05,
+        1.4206283619850139e-05, 1.4333366902500325e-05, 1.4459712856182546e-05, 1.4585318274967513e-05,
+        1.4710179985338799e-05, 1.4834294846248113e-05, 1.4957659749176237e-05, 1.508027161818547e-05,
+        1.5202127409975077e-05, 1.5323224113934685e-05, 1.5443558752197144e-05, 1.556312837968662e-05,
+        1.568193008417141e-05, 1.5799960986309154e-05, 1.591721823969386e-05, 1.6033699030903227e-05,
+        1.6149400579540723e-05, 1.6264320138278682e-05, 1.637845499290347e-05, 1.6491802462349972e-05,
+        1.6604359898744408e-05, 1.671612468744201e-05, 1.6827094247061554e-05, 1.693726602952235e-05,
+        1.7046637520078387e-05, 1.7155206237348276e-05, 1.7262969733349998e-05, 1.7369925593528396e-05,
+        1.747607143678501e-05, 1.75814049155058e-05, 1.7685923715588036e-05, 1.7789625556462596e-05,
+        1.7892508191120835e-05, 1.7994569406134545e-05, 1.8095807021679262e-05, 1.8196218891551576e-05,
+        1.8295802903191012e-05, 1.8394556977694716e-05, 1.8492479069834206e-05, 1.858956716807036e-05,
+        1.8685819294568096e-05, 1.878123350520603e-05, 1.8875807889590992e-05, 1.896954057106507e-05,
+        1.9062429706714623e-05, 1.9154473487378987e-05, 1.924567013765473e-05, 1.9336017915901648e-05,
+        1.942551511424682e-05, 1.9514160058584737e-05, 1.9601951108580014e-05, 1.9688886657667648e-05,
+        1.9774965133049825e-05, 1.9860184995694978e-05, 1.9944544740333856e-05, 2.002804289545245e-05,
+        2.011067802328814e-05, 2.019244871982018e-05, 2.0273353614762716e-05, 2.035339137155255e-05,
+        2.0432560687341082e-05, 2.0510860292978147e-05, 2.058828895300143e-05, 2.0664845465619563e-05,
+        2.0740528662697716e-05, 2.081533740973833e-05, 2.0889270605865338e-05, 2.096232718380204e-05,
+        2.10345061098509e-05, 2.1105806383872476e-05, 2.1176227039260807e-05, 2.1245767142919407e-05,
+        2.1314425795236685e-05, 2.138220213005791e-05, 2.1449095314656786e-05, 2.1515104549708107e-05,
+        2.1580229069255396e-05, 2.16444681406808e-05, 2.1707821064672602e-05, 2.1770287175189563e-05,
+        2.183186583942755e-05, 2.189255645778324e-05, 2.1952358463815826e-05, 2.2011271324209526e-05,
+        2.2069294538734275e-05, 2.2126427640203086e-05, 2.2182670194432515e-05, 2.223802180019767e-05,
+        2.2292482089189613e-05, 2.234605072596853e-05, 2.2398727407919263e-05, 2.2450511865201524e-05,
+        2.2501403860703127e-05, 2.2551403189988935e-05, 2.2600509681250862e-05, 2.2648723195255028e-05,
+        2.2696043625289773e-05, 2.2742470897110574e-05, 2.2788004968884604e-05, 2.283264583113537e-05,
+        2.287639350668439e-05, 2.291924805059266e-05, 2.296120955010146e-05, 2.300227812457197e-05,
+        2.3042453925422055e-05, 2.308173713606505e-05, 2.312012797184468e-05, 2.315762667997103e-05,
+        2.3194233539453494e-05, 2.3229948861034972e-05, 2.3264772987122786e-05, 2.329870629171964e-05,
+        2.33317491803536e-05, 2.336390209000734e-05, 2.3395165489044755e-05, 2.342553987713908e-05,
+        2.345502578519764e-05, 2.3483623775286417e-05, 2.3511334440554625e-05, 2.3538158405156414e-05,
+        2.3564096324173356e-05, 2.3589148883534957e-05, 2.3613316799937695e-05, 2.3636600820764263e-05,
+        2.3659001724001592e-05, 2.3680520318156772e-05, 2.370115744217361e-05, 2.3720913965347262e-05,
+        2.3739790787237417e-05, 2.3757788837582155e-05, 2.3774909076209034e-05, 2.379115249294684e-05,
+        2.380652010753468e-05, 2.3821012969532537e-05, 2.3834632158227646e-05, 2.3847378782543245e-05,
+        2.3859253980943867e-05, 2.387025892134153e-05, 2.3880394800999417e-05, 2.388966284643639e-05,
+        2.389806431332902e-05, 2.3905600486413322e-05, 2.391227267938589e-05, 2.391808223480409e-05,
+        2.392303052398457e-05, 2.392711894690223e-05, 2.3930348932087193e-05, 2.3932721936521083e-05,
+        2.3934239445533237e-05, 2.3934902972694997e-05, 2.3934714059713957e-05, 2.393367427632713e-05,
+        2.3931785220192685e-05, 2.392904851678198e-05, 2.392546581926993e-05, 2.392103880842454e-05,
+        2.391576919249628e-05, 2.390965870710607e-05, 2.3902709115132417e-05, 2.3894922206598194e-05,
+        2.388629979855643e-05, 2.387684373497508e-05, 2.386655588662117e-05, 2.385543815094455e-05,
+        2.3843492451960048e-05, 2.3830720740129668e-05, 2.381712499224362e-05, 2.3802707211300552e-05,
+        2.378746942638718e-05, 2.377141369255731e-05, 2.37545420907097e-05, 2.3736856727465606e-05,
+        2.3718359735045404e-05, 2.3699053271144553e-05, 2.3678939518808756e-05, 2.3658020686308524e-05,
+        2.3636299007012918e-05, 2.361377673926278e-05, 2.359045616624309e-05, 2.3566339595854653e-05,
+        2.354142936058545e-05, 2.3515727817380526e-05, 2.348923734751219e-05, 2.346196035644875e-05,
+        2.3433899273723132e-05, 2.340505655280062e-05, 2.3375434670945913e-05, 2.3345036129089454e-05,
+        2.3313863451693613e-05, 2.328191918661772e-05, 2.3249205904982613e-05, 2.32157262010348e-05,
+        2.3181482692009766e-05, 2.3146478017994753e-05, 2.3110714841790945e-05, 2.3074195848775145e-05,
+        2.303692374676078e-05, 2.299890126585828e-05, 2.2960131158335136e-05, 2.2920616198474855e-05,
+        2.2880359182436284e-05, 2.2839362928111364e-05, 2.2797630274982896e-05, 2.2755164083981867e-05,
+        2.2711967237344004e-05, 2.2668042638465204e-05, 2.262339321175832e-05, 2.2578021902507267e-05,
+        2.2531931676721664e-05, 2.248512552099132e-05, 2.24376064423393e-05, 2.2389377468074964e-05,
+        2.234044164564716e-05, 2.229080204249561e-05, 2.2240461745902965e-05, 2.2189423862845803e-05,
+        2.2137691519845325e-05, 2.2085267862817508e-05, 2.203215605692341e-05, 2.1978359286417788e-05,
+        2.1923880754499054e-05, 2.1868723683156564e-05, 2.1812891313019987e-05, 2.175638690320577e-05,
+        2.1699213731165372e-05, 2.164137509253184e-05, 2.158287430096642e-05, 2.1523714688004097e-05,
+        2.1463899602900393e-05, 2.1403432412475426e-05, 2.1342316500959867e-05, 2.1280555269839534e-05,
+        2.121815213769893e-05, 2.11551105400662e-05, 2.1091433929255875e-05, 2.1027125774212077e-05,
+        2.0962189560352377e-05, 2.0896628789409657e-05, 2.0830446979274298e-05, 2.076364766383669e-05,
+        2.0696234392828643e-05, 2.0628210731664167e-05, 2.055958026128189e-05, 2.0490346577984575e-05,
+        2.0420513293280595e-05, 2.0350084033723278e-05, 2.0279062440752062e-05, 2.0207452170530603e-05,
+        2.013525689378814e-05, 2.0062480295657103e-05, 1.998912607551332e-05, 1.991519794681377e-05,
+        1.984069963693618e-05, 1.9765634887016154e-05, 1.9690007451786102e-05, 1.96138210994133e-05,
+        1.9537079611336616e-05, 1.9459786782105047e-05, 1.9381946419214726e-05, 1.930356234294522e-05,
+        1.9224638386197685e-05, 1.9145178394331358e-05, 1.9065186224999435e-05, 1.898466574798667e-05,
+        1.8903620845044884e-05, 1.882205540972878e-05, 1.8739973347233696e-05, 1.8657378574229485e-05,
+        1.8574275018697698e-05, 1.849066661976682e-05, 1.8406557327547597e-05, 1.8321951102968115e-05,
+        1.8236851917610688e-05, 1.815126375354541e-05, 1.8065190603166602e-05, 1.7978636469026652e-05,
+        1.7891605363672617e-05, 1.7804101309479063e-05, 1.7716128338485297e-05, 1.7627690492228372e-05,
+        1.753879182157906e-05, 1.7449436386575494e-05, 1.735962825625933e-05, 1.726937150850847e-05,
+        1.7178670229873337e-05, 1.7087528515411304e-05, 1.699595046852034e-05, 1.6903940200774664e-05,
+        1.6811501831759215e-05, 1.671863948890304e-05, 1.6625357307315443e-05, 1.6531659429620434e-05,
+        1.6437550005790263e-05, 1.6343033192981806e-05, 1.6248113155369868e-05, 1.6152794063981978e-05,
+        1.6057080096534923e-05, 1.5960975437267454e-05, 1.586448427677683e-05, 1.576761081185334e-05,
+        1.567035924531504e-05, 1.557273378584254e-05, 1.54747386478162e-05, 1.537637805114907e-05,
+        1.5277656221124352e-05, 1.517857738822913e-05, 1.5079145787992055e-05, 1.497936566081667e-05,
+        1.4879241251819267e-05, 1.4778776810664438e-05, 1.4677976591401058e-05, 1.4576844852297934e-05,
+        1.4475385855681871e-05, 1.437360386777206e-05, 1.4271503158518474e-05, 1.4169088001438831e-05,
+        1.40663626734542e-05, 1.396333145472803e-05, 1.3859998628502207e-05, 1.3756368480934547e-05,
+        1.3652445300938241e-05, 1.3548233380018495e-05, 1.3443737012110392e-05, 1.3338960493418784e-05,
+        1.3233908122255517e-05, 1.3128584198878072e-05, 1.3022993025330716e-05, 1.2917138905281137e-05,
+        1.2811026143861894e-05, 1.2704659047509424e-05, 1.2598041923803901e-05, 1.24911790813089e-05,
+        1.2384074829414067e-05, 1.2276733478173403e-05, 1.2169159338148241e-05, 1.20613567202468e-05,
+        1.1953329935567854e-05, 1.1845083295240037e-05, 1.1736621110266086e-05, 1.1627947691364949e-05,
+        1.1519067348814125e-05, 1.1409984392292195e-05, 1.1300703130723969e-05, 1.1191227872121629e-05,
+        1.1081562923430872e-05, 1.097171259037501e-05, 1.0861681177298253e-05, 1.0751472987012516e-05,
+        1.0641092320641475e-05, 1.0530543477465896e-05, 1.0419830754771712e-05, 1.0308958447694901e-05,
+        1.0197930849067828e-05, 1.0086752249268115e-05, 9.975426936064559e-06, 9.863959194464877e-06,
+        9.75235330656617e-06, 9.64061355140084e-06, 9.528744204787877e-06, 9.416749539180357e-06,
+        9.304633823517479e-06, 9.192401323071813e-06, 9.08005629930352e-06, 8.9676030097084e-06,
+        8.855045707671638e-06, 8.742388642317744e-06, 8.629636058365222e-06, 8.516792195976455e-06,
+        8.403861290613387e-06, 8.290847572890794e-06, 8.177755268428995e-06, 8.064588597710186e-06,
+        7.951351775932936e-06, 7.838049012866245e-06, 7.724684512707476e-06, 7.611262473938567e-06,
+        7.497787089181416e-06, 7.3842625450573845e-06, 7.270693022043546e-06, 7.157082694330919e-06,
+        7.043435729685558e-06, 6.929756289305139e-06, 6.816048527680887e-06, 6.70231659245718e-06,
+        6.588564624292187e-06, 6.474796756718737e-06, 6.3610171160086325e-06, 6.247229821032179e-06,
+        6.133438983123529e-06, 6.019648705942143e-06, 5.905863085339135e-06, 5.792086209219025e-06,
+        5.67832215740719e-06, 5.56457500151509e-06, 5.450848804806159e-06, 5.337147622061866e-06,
+        5.223475499451136e-06, 5.109836474395393e-06, 4.996234575439403e-06, 4.882673822120103e-06,
+        4.769158224834579e-06, 4.655691784712204e-06, 4.5422784934848395e-06, 4.428922333356678e-06,
+        4.3156272768783444e-06, 4.202397286819125e-06, 4.089236316038654e-06, 3.976148307362719e-06,
+        3.8631371934560655e-06, 3.750206896697139e-06, 3.6373613290560455e-06, 3.5246043919678677e-06,
+        3.4119399762115842e-06, 3.299371961786786e-06, 3.1869042177914374e-06, 3.0745406023000886e-06,
+        2.9622849622455975e-06, 2.8501411332961986e-06, 2.7381129397385125e-06, 2.6262041943567014e-06,
+        2.5144186983167296e-06, 2.402760241046049e-06, 2.291232600119179e-06, 2.179839541141053e-06,
+        2.0685848176312736e-06, 1.9574721709085114e-06, 1.8465053299782358e-06, 1.7356880114176165e-06,
+        1.625023919262481e-06, 1.51451674489755e-06, 1.4041701669420853e-06, 1.2939878511414938e-06,
+        1.1839734502551152e-06, 1.074130603949209e-06, 9.644629386850627e-07, 8.549740676146555e-07,
+        7.456675904701764e-07, 6.36547093459751e-07, 5.27616149159408e-07, 4.188783164102115e-07,
+        3.103371402108453e-07, 2.0199615161615458e-07, 9.385886763377096e-08, -1.407120887985109e-08,
+        -1.2179058931928947e-07, -2.2929579943164325e-07, -3.365833794183395e-07, -4.4364988403257184e-07,
+        -5.50491882678361e-07, -6.571059595101859e-07, -7.634887135282474e-07, -8.696367586766381e-07,
+        -9.755467239394675e-07, -1.0812152534336903e-06, -1.1866390065063356e-06, -1.2918146578261144e-06,
+        -1.3967388974771085e-06, -1.5014084310512058e-06, -1.605819979740031e-06, -1.7099702804233366e-06,
+        -1.813856085761725e-06, -1.9174741642835436e-06, -2.020821300475336e-06, -2.123894294867226e-06,
+        -2.2266899641228564e-06, -2.329205141121999e-06, -2.4314366750489834e-06, -2.5333814314750185e-06,
+        -2.6350362924440898e-06, -2.7363981565537697e-06, -2.8374639390403036e-06, -2.9382305718579043e-06,
+        -3.0386950037597657e-06, -3.1388542003795953e-06, -3.2387051443090565e-06, -3.3382448351767446e-06,
+        -3.4374702897273504e-06, -3.5363785418965396e-06, -3.6349666428873465e-06, -3.7332316612469818e-06,
+        -3.831170682939381e-06, -3.928780811420458e-06, -4.026059167711331e-06, -4.123002890468282e-06,
+        -4.21960913605685e-06, -4.315875078620501e-06, -4.4117979101511735e-06, -4.5073748405585204e-06,
+        -4.6026030977386385e-06, -4.697479927639312e-06, -4.792002594329336e-06, -4.88616838006216e-06,
+        -4.97997458534287e-06, -5.0734185289902974e-06, -5.166497548203036e-06, -5.259208998619992e-06,
+        -5.35155025438269e-06, -5.443518708196553e-06, -5.535111771391842e-06, -5.626326873981242e-06,
+        -5.717161464721027e-06, -5.807613011166786e-06, -5.897678999730726e-06, -5.987356935739235e-06,
+        -6.076644343486446e-06, -6.165538766290217e-06, -6.254037766546213e-06, -6.342138925778936e-06,
+        -6.429839844695382e-06, -6.5171381432368065e-06, -6.604031460627694e-06, -6.690517455426899e-06,
+        -6.776593805576725e-06, -6.862258208449133e-06, -6.947508380895432e-06, -7.032342059290812e-06,
+        -7.116756999581771e-06, -7.200750977329073e-06, -7.28432178775437e-06, -7.367467245780486e-06,
+        -7.450185186076384e-06, -7.53247346309687e-06, -7.614329951125164e-06, -7.695752544310967e-06,
+        -7.776739156711944e-06, -7.857287722330266e-06, -7.937396195150591e-06, -8.017062549176968e-06,
+        -8.09628477846948e-06, -8.175060897177658e-06, -8.25338893957712e-06, -8.331266960101213e-06,
+        -8.408693033373873e-06, -8.485665254242845e-06, -8.562181737809005e-06, -8.638240619457924e-06,
+        -8.713840054889521e-06, -8.78897822014506e-06, -8.86365331163621e-06, -8.937863546172552e-06,
+        -9.011607160986285e-06, -9.084882413758906e-06, -9.157687582645997e-06, -9.230020966299256e-06,
+        -9.301880883891684e-06, -9.37326567513793e-06, -9.444173700317411e-06, -9.514603340292949e-06,
+        -9.58455299653299e-06, -9.654021091127875e-06, -9.72300606681029e-06, -9.791506386970972e-06,
+        -9.859520535676754e-06, -9.927047017684756e-06, -9.99408435845928e-06, -1.0060631104184405e-05,
+        -1.0126685821777559e-05, -1.019224709890322e-05, -1.0257313543983422e-05, -1.032188378620919e-05,
+        -1.0385956475551744e-05, -1.044953028277051e-05, -1.0512603899422048e-05, -1.057517603786882e-05,
+        -1.063724543128493e-05, -1.0698810833663427e-05, -1.0759871019822015e-05, -1.0820424785406118e-05,
+        -1.0880470946894769e-05, -1.0940008341602366e-05, -1.0999035827681546e-05, -1.1057552284125003e-05,
+        -1.1115556610766492e-05, -1.1173047728279585e-05, -1.1230024578178525e-05, -1.1286486122815418e-05,
+        -1.134243134537915e-05, -1.139785924989094e-05, -1.1452768861202254e-05, -1.1507159224988893e-05,
+        -1.1561029407745805e-05, -1.1614378496781229e-05, -1.1667205600210305e-05, -1.1719509846946175e-05,
+        -1.1771290386693257e-05, -1.1822546389936828e-05, -1.1873277047933217e-05, -1.1923481572700051e-05,
+        -1.1973159197003792e-05, -1.2022309174347998e-05, -1.2070930778961187e-05, -1.2119023305782087e-05,
+        -1.2166586070445375e-05, -1.2213618409267532e-05, -1.2260119679229644e-05, -1.230608925796179e-05,
+        -1.2351526543725814e-05, -1.2396430955396036e-05, -1.2440801932441852e-05, -1.248463893490737e-05,
+        -1.2527941443391256e-05, -1.257070895902612e-05, -1.2612941003456936e-05, -1.2654637118817572e-05,
+        -1.269579686770897e-05, -1.2736419833174142e-05, -1.2776505618674795e-05, -1.2816053848064661e-05,
+        -1.2855064165565054e-05, -1.2893536235736985e-05, -1.293146974345408e-05, -1.2968864393874519e-05,
+        -1.3005719912412622e-05, -1.3042036044708533e-05, -1.3077812556599104e-05, -1.3113049234086083e-05,
+        -1.3147745883304642e-05, -1.3181902330491797e-05, -1.321551842195255e-05, -1.3248594024027032e-05,
+        -1.32811290230561e-05, -1.3313123325345625e-05, -1.3344576857131471e-05, -1.3375489564543352e-05,
+        -1.3405861413567092e-05, -1.3435692390007539e-05, -1.3464982499450538e-05, -1.3493731767222695e-05,
+        -1.3521940238353087e-05, -1.3549607977531997e-05, -1.357673506907069e-05, -1.3603321616858979e-05,
+        -1.362936774432407e-05, -1.3654873594386487e-05, -1.3679839329417396e-05, -1.3704265131193778e-05,
+        -1.3728151200854291e-05, -1.3751497758853015e-05, -1.3774305044914271e-05, -1.3796573317985319e-05,
+        -1.3818302856188862e-05, -1.3839493956775767e-05, -1.3860146936075835e-05, -1.3880262129448816e-05,
+        -1.3899839891235082e-05, -1.3918880594704514e-05, -1.3937384632005733e-05, -1.3955352414114744e-05,
+        -1.3972784370782182e-05, -1.398968095048101e-05, -1.4006042620353108e-05, -1.4021869866154664e-05,
+        -1.4037163192202047e-05, -1.4051923121316886e-05, -1.4066150194769638e-05, -1.4079844972223738e-05,
+        -1.4093008031678814e-05, -1.4105639969412509e-05, -1.4117741399923205e-05, -1.412931295587089e-05,
+        -1.4140355288018402e-05, -1.4150869065171114e-05, -1.4160854974117413e-05, -1.417031371956709e-05,
+        -1.417924602409053e-05, -1.4187652628056318e-05, -1.419553428956931e-05, -1.4202891784406892e-05,
+        -1.4209725905956204e-05, -1.4216037465149529e-05, -1.4221827290399725e-05, -1.4227096227535398e-05,
+        -1.4231845139734876e-05, -1.4236074907460257e-05, -1.4239786428390778e-05, -1.4242980617355428e-05,
+        -1.4245658406265174e-05, -1.4247820744045107e-05, -1.4249468596565287e-05, -1.4250602946571959e-05,
+        -1.4251224793617617e-05, -1.4251335153990703e-05, -1.4250935060645184e-05, -1.4250025563129212e-05,
+        -1.4248607727513546e-05, -1.4246682636319408e-05, -1.424425138844614e-05, -1.4241315099097616e-05,
+        -1.423787489970923e-05, -1.4233931937873613e-05, -1.4229487377266397e-05, -1.4224542397571117e-05,
+        -1.4219098194404075e-05, -1.4213155979238492e-05, -1.42067169793281e-05, -1.4199782437630706e-05,
+        -1.4192353612730993e-05, -1.4184431778762936e-05, -1.4176018225331974e-05, -1.4167114257436533e-05,
+        -1.4157721195389177e-05, -1.4147840374737474e-05, -1.4137473146184284e-05, -1.4126620875507823e-05,
+        -1.41152849434811e-05, -1.4103466745791114e-05, -1.4091167692957674e-05, -1.4078389210251611e-05,
+        -1.4065132737612854e-05, -1.4051399729568044e-05, -1.4037191655147608e-05, -1.4022509997802704e-05,
+        -1.400735625532159e-05, -1.3991731939745816e-05, -1.3975638577285804e-05, -1.395907770823627e-05,
+        -1.3942050886891161e-05, -1.3924559681458408e-05, -1.3906605673974052e-05, -1.388819046021641e-05,
+        -1.3869315649619377e-05, -1.3849982865185922e-05, -1.383019374340088e-05, -1.3809949934143608e-05,
+        -1.378925310060022e-05, -1.3768104919175537e-05, -1.3746507079404703e-05, -1.372446128386454e-05,
+        -1.3701969248084324e-05, -1.3679032700456769e-05, -1.3655653382148274e-05, -1.3631833047008898e-05,
+        -1.3607573461482318e-05, -1.3582876404515335e-05, -1.3557743667466772e-05, -1.353217705401681e-05,
+        -1.3506178380075362e-05, -1.3479749473690497e-05, -1.3452892174956591e-05, -1.3425608335922128e-05,
+        -1.3397899820497084e-05, -1.3369768504360596e-05, -1.3341216274867622e-05, -1.3312245030956041e-05,
+        -1.3282856683052928e-05, -1.3253053152981128e-05, -1.3222836373865029e-05, -1.3192208290036682e-05,
+        -1.3161170856941073e-05, -1.3129726041041852e-05, -1.3097875819726078e-05, -1.3065622181209525e-05,
+        -1.3032967124441063e-05, -1.2999912659007273e-05, -1.2966460805036851e-05, -1.293261359310446e-05,
+        -1.2898373064134827e-05, -1.2863741269306313e-05, -1.2828720269954379e-05, -1.2793312137474787e-05,
+        -1.2757518953227152e-05, -1.2721342808437301e-05, -1.268478580410057e-05, -1.2647850050883911e-05,
+        -1.2610537669028797e-05, -1.2572850788252914e-05, -1.25347915476528e-05, -1.2496362095605533e-05,
+        -1.245756458967061e-05, -1.2418401196491322e-05, -1.2378874091696793e-05, -1.23389854598026e-05,
+        -1.2298737494112573e-05, -1.2258132396619975e-05, -1.2217172377907779e-05, -1.21758596570502e-05,
+        -1.2134196461513227e-05, -1.2092185027054756e-05, -1.2049827597625639e-05, -1.2007126425270184e-05,
+        -1.1964083770025682e-05, -1.192070189982339e-05, -1.1876983090388128e-05, -1.183292962513815e-05,
+        -1.1788543795085748e-05, -1.1743827898736194e-05, -1.1698784241988211e-05, -1.1653415138033228e-05,
+        -1.1607722907254998e-05, -1.1561709877128891e-05, -1.1515378382122134e-05, -1.146873076359225e-05,
+        -1.142176936968712e-05, -1.1374496555243743e-05, -1.1326914681687994e-05, -1.127902611693305e-05,
+        -1.1230833235279228e-05, -1.1182338417312933e-05, -1.113354404980563e-05, -1.1084452525612705e-05,
+        -1.1035066243573081e-05, -1.098538760840726e-05, -1.0935419030617138e-05, -1.0885162926384894e-05,
+        -1.0834621717471286e-05, -1.078379783111546e-05, -1.0732693699933325e-05, -1.0681311761816272e-05,
+        -1.0629654459830997e-05, -1.0577724242117854e-05, -1.0525523561789634e-05, -1.0473054876831211e-05,
+        -1.0420320649997948e-05, -1.0367323348714714e-05, -1.031406544497572e-05, -1.0260549415242532e-05,
+        -1.020677774034416e-05, -1.0152752905375329e-05, -1.0098477399596641e-05, -1.0043953716332616e-05,
+        -9.989184352872385e-06, -9.934171810367786e-06, -9.87891859373377e-06, -9.823427211546985e-06,
+        -9.767700175946281e-06, -9.711740002531117e-06, -9.655549210262265e-06, -9.599130321361359e-06,
+        -9.542485861210113e-06, -9.485618358251032e-06, -9.428530343887142e-06, -9.371224352381417e-06,
+        -9.313702920757855e-06, -9.25596858870173e-06, -9.198023898459098e-06, -9.139871394738386e-06,
+        -9.081513624610205e-06, -9.022953137407909e-06, -8.964192484629384e-06, -8.90523421983735e-06,
+        -8.846080898560023e-06, -8.786735078193282e-06, -8.727199317901136e-06, -8.667476178517132e-06,
+        -8.607568222447025e-06, -8.547478013569069e-06, -8.487208117137132e-06, -8.426761099681565e-06,
+        -8.366139528912554e-06, -8.305345973620928e-06, -8.244383003582359e-06, -8.183253189458509e-06,
+        -8.1219591027009e-06, -8.060503315452956e-06, -7.998888400454153e-06, -7.937116930942007e-06,
+        -7.87519148055662e-06, -7.813114623244291e-06, -7.750888933160514e-06, -7.68851698457496e-06,
+        -7.626001351775381e-06, -7.563344608971258e-06, -7.5005493301994284e-06, -7.437618089228823e-06,
+        -7.37455345946455e-06, -7.311358013854283e-06, -7.2480343247928875e-06, -7.184584964027791e-06,
+        -7.121012502566129e-06, -7.057319510579317e-06, -6.993508557310411e-06, -6.929582210980174e-06,
+        -6.865543038693728e-06, -6.801393606347233e-06, -6.737136478536295e-06, -6.672774218461891e-06,
+        -6.608309387839307e-06, -6.543744546804963e-06, -6.479082253825756e-06, -6.414325065606069e-06,
+        -6.349475536997549e-06, -6.2845362209078205e-06, -6.21950966820953e-06, -6.154398427649328e-06,
+        -6.089205045758714e-06, -6.023932066762442e-06, -5.958582032490061e-06, -5.893157482286229e-06,
+        -5.827660952920658e-06, -5.762094978500356e-06, -5.696462090380049e-06, -5.630764817073704e-06,
+        -5.565005684167559e-06, -5.4991872142315096e-06, -5.433311926731223e-06, -5.367382337942021e-06,
+        -5.301400960861038e-06, -5.235370305120476e-06, -5.169292876902587e-06, -5.103171178852094e-06,
+        -5.037007709991599e-06, -4.970804965635774e-06, -4.904565437306152e-06, -4.838291612646055e-06,
+        -4.771985975337446e-06, -4.705651005015213e-06, -4.639289177184709e-06, -4.572902963137026e-06,
+        -4.506494829867235e-06, -4.440067239989918e-06, -4.373622651658006e-06, -4.307163518480361e-06,
+        -4.24069228943977e-06, -4.174211408811093e-06, -4.107723316081253e-06, -4.041230445866844e-06,
+        -3.974735227835053e-06, -3.908240086623403e-06, -3.841747441759117e-06, -3.77525970758098e-06,
+        -3.708779293159354e-06, -3.6423086022173977e-06, -3.57585003305397e-06, -3.509405978464785e-06,
+        -3.4429788256645326e-06, -3.3765709562107254e-06, -3.310184745926e-06, -3.2438225648214338e-06,
+        -3.1774867770218766e-06, -3.111179740688544e-06, -3.0449038079450704e-06, -2.9786613248013404e-06,
+        -2.9124546310802732e-06, -2.8462860603418354e-06, -2.7801579398113712e-06, -2.714072590304354e-06,
+        -2.64803232615476e-06, -2.5820394551412284e-06, -2.516096278416181e-06, -2.450205090432365e-06,
+        -2.384368178872735e-06, -2.318587824579321e-06, -2.2528663014816455e-06, -2.187205876527603e-06,
+        -2.1216088096133207e-06, -2.0560773535128264e-06, -1.9906137538103025e-06, -1.9252202488313167e-06,
+        -1.8598990695737442e-06, -1.7946524396412364e-06, -1.7294825751749645e-06, -1.6643916847865351e-06,
+        -1.5993819694928743e-06, -1.534455622648482e-06, -1.4696148298809636e-06, -1.404861769025376e-06,
+        -1.3401986100592562e-06, -1.2756275150379497e-06, -1.2111506380320363e-06, -1.146770125062153e-06,
+        -1.082488114037271e-06, -1.0183067346908438e-06, -9.54228108519971e-07, -8.902543487218015e-07,
+        -8.263875601343362e-07, -7.626298391737099e-07, -6.989832737751243e-07, -6.354499433316156e-07,
+        -5.720319186358713e-07, -5.087312618194472e-07, -4.455500262954725e-07, -3.824902567002777e-07,
+        -3.1955398883472486e-07, -2.5674324960795524e-07, -1.940600569802213e-07, -1.3150641990557835e-07,
+        -6.908433827714174e-08, -6.795802871439583e-09, 5.53572047075268e-08, 1.173727120816574e-07,
+        1.7924875615377368e-07, 2.4098338316142327e-07, 3.0257464872872607e-07, 3.640206179207551e-07,
+        4.2531936529460317e-07, 4.864689749516357e-07, 5.474675405889768e-07, 6.083131655506687e-07,
+        6.690039628767349e-07, 7.295380553547482e-07, 7.899135755679504e-07, 8.50128665945429e-07,
+        9.101814788092999e-07, 9.70070176424382e-07, 1.029792931044428e-06, 1.08934792495952e-06,
+        1.1487333505428407e-06, 1.2079474102972496e-06, 1.266988316899775e-06, 1.3258542932484256e-06,
+        1.3845435725055632e-06, 1.4430543981422956e-06, 1.5013850239830926e-06, 1.5595337142478956e-06,
+        1.6174987435957744e-06, 1.6752783971673716e-06, 1.7328709706255873e-06, 1.790274770197763e-06,
+        1.8474881127168365e-06, 1.9045093256607056e-06, 1.961336747192978e-06, 2.0179687262025042e-06,
+        2.0744036223410186e-06, 2.1306398060631397e-06, 2.1866756586630114e-06, 2.2425095723129098e-06,
+        2.298139950098928e-06, 2.353565206059147e-06, 2.4087837652176553e-06, 2.4637940636217452e-06,
+        2.5185945483756414e-06, 2.5731836776761463e-06, 2.627559920845437e-06, 2.6817217583660974e-06,
+        2.7356676819129587e-06, 2.789396194385899e-06, 2.842905809942093e-06, 2.8961950540279546e-06,
+        2.949262463409156e-06, 3.002106586202714e-06, 3.0547259819058563e-06, 3.1071192214257963e-06,
+        3.1592848871096106e-06, 3.2112215727718653e-06, 3.262927883723475e-06, 3.314402436799514e-06,
+        3.3656438603852986e-06, 3.4166507944438303e-06, 3.4674218905422303e-06, 3.517955811876506e-06,
+        3.568251233297474e-06, 3.6183068413356104e-06, 3.6681213342240607e-06, 3.71769342192377e-06,
+        3.767021826145547e-06, 3.816105280373763e-06, 3.864942529887502e-06, 3.9135323317837515e-06,
+        3.961873454996911e-06, 4.009964680321029e-06, 4.057804800428972e-06, 4.1053926198931676e-06,
+        4.152726955203748e-06, 4.199806634788782e-06, 4.246630499031347e-06, 4.293197400287546e-06,
+        4.3395062029045295e-06, 4.385555783236483e-06, 4.431345029661199e-06, 4.476872842596713e-06,
+        4.52213813451561e-06, 4.567139829960088e-06, 4.611876865557067e-06, 4.65634819003112e-06,
+        4.700552764218612e-06, 4.744489561080753e-06, 4.7881575657150104e-06, 4.831555775368315e-06,
+        4.874683199447635e-06, 4.91753885953133e-06, 4.960121789379774e-06, 5.0024310349455965e-06,
+        5.044465654382218e-06, 5.086224718054219e-06, 5.127707308544807e-06, 5.16891252066492e-06,
+        5.2098394614599e-06, 5.250487250217349e-06, 5.2908550184738985e-06, 5.330941910020543e-06,
+        5.370747080908983e-06, 5.410269699457119e-06, 5.449508946253051e-06, 5.488464014160028e-06,
+        5.527134108320382e-06, 5.56551844615789e-06, 5.603616257381896e-06, 5.641426783988787e-06,
+        5.678949280264292e-06, 5.716183012785084e-06, 5.753127260419931e-06, 5.78978131432935e-06,
+        5.826144477966822e-06, 5.862216067077408e-06, 5.897995409697789e-06, 5.933481846153966e-06,
+        5.968674729060631e-06, 6.003573423318009e-06, 6.038177306109331e-06, 6.0724857668978305e-06,
+        6.106498207423364e-06, 6.140214041697551e-06, 6.1736326960003664e-06, 6.206753608874222e-06,
+        6.239576231118649e-06, 6.2721000257849895e-06, 6.304324468169304e-06, 6.33624904580611e-06,
+        6.367873258461242e-06, 6.3991966181234354e-06, 6.430218648996748e-06, 6.460938887492115e-06,
+        6.4913568822178055e-06, 6.521472193970364e-06, 6.551284395724859e-06, 6.580793072623731e-06,
+        6.609997821966973e-06, 6.638898253200128e-06, 6.667493987903297e-06, 6.695784659778339e-06,
+        6.723769914637296e-06, 6.751449410388255e-06, 6.778822817023022e-06, 6.8058898166025045e-06,
+        6.8326501032431575e-06, 6.859103383101531e-06, 6.88524937436017e-06, 6.911087807211247e-06,
+        6.936618423840737e-06, 6.961840978412597e-06, 6.986755237051362e-06, 7.011360977825116e-06,
+        7.03565799072842e-06, 7.059646077663543e-06, 7.08332505242218e-06, 7.106694740667073e-06,
+        7.129754979912064e-06, 7.152505619502967e-06, 7.174946520597496e-06, 7.197077556144201e-06,
+        7.218898610862311e-06, 7.240409581219901e-06, 7.261610375412445e-06, 7.282500913340773e-06,
+        7.303081126588752e-06, 7.3233509583997735e-06, 7.343310363654294e-06, 7.362959308845503e-06,
+        7.38229777205581e-06, 7.401325742931883e-06, 7.4200432226604904e-06, 7.438450223942713e-06,
+        7.4565467709685505e-06, 7.47433289939091e-06, 7.491808656299556e-06, 7.508974100193797e-06,
+        7.525829300956126e-06, 7.5423743398241615e-06, 7.558609309362869e-06, 7.5745343134367695e-06,
+        7.59014946718082e-06, 7.605454896971666e-06, 7.620450740398671e-06, 7.635137146233752e-06,
+        7.649514274401409e-06, 7.663582295948651e-06, 7.677341393013908e-06, 7.690791758796076e-06,
+        7.70393359752324e-06, 7.716767124420317e-06, 7.729292565677532e-06, 7.74151015841743e-06,
+        7.753420150662288e-06, 7.765022801300835e-06, 7.776318380054874e-06, 7.787307167445022e-06,
+        7.797989454756928e-06, 7.80836554400638e-06, 7.818435747904722e-06, 7.828200389823384e-06,
+        7.837659803758624e-06, 7.846814334295414e-06, 7.855664336571286e-06, 7.864210176239873e-06,
+        7.87245222943419e-06, 7.880390882729138e-06, 7.888026533104565e-06, 7.89535958790698e-06,
+        7.902390464811627e-06, 7.909119591784223e-06, 7.915547407041982e-06, 7.921674359014806e-06,
+        7.927500906306058e-06, 7.933027517652516e-06, 7.938254671884785e-06, 7.943182857887076e-06,
+        7.947812574556493e-06, 7.952144330762393e-06, 7.95617864530529e-06, 7.959916046875313e-06,
+        7.96335707401068e-06, 7.966502275055611e-06, 7.96935220811825e-06, 7.971907441027914e-06,
+        7.974168551292654e-06, 7.976136126055694e-06, 7.977810762052584e-06, 7.979193065567171e-06,
+        7.980283652388002e-06, 7.981083147763917e-06, 7.981592186359952e-06, 7.98181141221235e-06,
+        7.981741478683835e-06, 7.981383048418229e-06, 7.980736793295217e-06, 7.979803394384428e-06,
+        7.978583541899703e-06, 7.977077935152725e-06, 7.975287282506548e-06, 7.973212301329142e-06,
+        7.97085371794616e-06, 7.968212267593958e-06, 7.965288694372211e-06, 7.962083751196089e-06,
+        7.958598199748533e-06, 7.954832810432113e-06, 7.95078836232074e-06, 7.946465643111005e-06,
+        7.941865449073526e-06, 7.936988585003824e-06, 7.93183586417323e-06, 7.926408108279326e-06,
+        7.920706147396479e-06, 7.914730819925778e-06, 7.90848297254512e-06, 7.90196346015886e-06,
+        7.895173145847394e-06, 7.888112900816437e-06, 7.880783604346183e-06, 7.873186143740222e-06,
+        7.86532141427428e-06, 7.857190319144738e-06, 7.848793769417027e-06, 7.84013268397377e-06,
+        7.831207989462741e-06, 7.822020620244714e-06, 7.812571518341108e-06, 7.802861633381318e-06,
+        7.792891922550071e-06, 7.782663350534515e-06, 7.772176889471106e-06, 7.7614335188924e-06,
+        7.750434225673647e-06, 7.7391800039792e-06, 7.72767185520876e-06, 7.715910787943554e-06,
+        7.703897817892232e-06, 7.691633967836682e-06, 7.67912026757765e-06, 7.666357753880381e-06,
+        7.653347470419756e-06, 7.64009046772567e-06, 7.6265878031280035e-06, 7.612840540701673e-06,
+        7.598849751211249e-06, 7.584616512055741e-06, 7.570141907213181e-06, 7.555427027184871e-06,
+        7.540472968939712e-06, 7.525280835858424e-06, 7.5098517376774515e-06, 7.494186790432927e-06,
+        7.478287116404581e-06, 7.462153844059151e-06, 7.445788107994191e-06, 7.42919104888145e-06,
+        7.412363813410006e-06, 7.395307554229899e-06, 7.3780234298949755e-06, 7.360512604806011e-06,
+        7.342776249153693e-06, 7.324815538861361e-06, 7.306631655527773e-06, 7.288225786369924e-06,
+        7.269599124165389e-06, 7.250752867194973e-06, 7.2316882191851075e-06, 7.212406389250042e-06,
+        7.1929085918341035e-06, 7.173196046654075e-06, 7.1532699786410065e-06, 7.1331316178824476e-06,
+        7.112782199564273e-06, 7.092222963912678e-06, 7.071455156135775e-06, 7.050480026365593e-06,
+        7.029298829599728e-06, 7.007912825642927e-06, 6.986323279048589e-06, 6.964531459060604e-06,
+        6.942538639554406e-06, 6.920346098978727e-06, 6.897955120296966e-06, 6.8753669909283474e-06,
+        6.852583002689468e-06, 6.829604451735378e-06, 6.806432638500797e-06, 6.783068867641537e-06,
+        6.759514447975525e-06, 6.73577069242379e-06, 6.711838917951936e-06, 6.68772044551089e-06,
+        6.663416599978057e-06, 6.638928710098573e-06, 6.614258108426014e-06, 6.589406131263696e-06,
+        6.564374118605419e-06, 6.539163414076766e-06, 6.513775364875602e-06, 6.4882113217137005e-06,
+        6.462472638757092e-06, 6.436560673567516e-06, 6.410476787043007e-06, 6.384222343359265e-06,
+        6.357798709910186e-06, 6.331207257249174e-06, 6.30444935903022e-06, 6.2775263919485085e-06,
+        6.250439735681843e-06, 6.223190772831579e-06, 6.195780888863405e-06, 6.168211472048784e-06,
+        6.14048391340609e-06, 6.112599606641336e-06, 6.0845599480898026e-06, 6.056366336656928e-06,
+        6.02802017375944e-06, 5.999522863267126e-06, 5.9708758114435e-06, 5.942080426887649e-06,
+        5.913138120475335e-06, 5.884050305300427e-06, 5.854818396616258e-06, 5.825443811777592e-06,
+        5.795927970181632e-06, 5.766272293210054e-06, 5.736478204170355e-06, 5.706547128237926e-06,
+        5.676480492397269e-06, 5.646279725384609e-06, 5.615946257629076e-06, 5.58548152119524e-06,
+        5.554886949724689e-06, 5.524163978378602e-06, 5.4933140437793345e-06, 5.462338583953167e-06,
+        5.431239038272594e-06, 5.400016847398361e-06, 5.368673453222341e-06, 5.337210298810053e-06,
+        5.305628828342854e-06, 5.273930487061188e-06, 5.242116721207377e-06, 5.210188977968123e-06,
+        5.178148705418016e-06, 5.145997352462254e-06, 5.113736368779791e-06, 5.081367204767138e-06,
+        5.04889131148111e-06, 5.01631014058278e-06, 4.983625144280901e-06, 4.950837775275528e-06,
+        4.91794948670172e-06, 4.884961732073998e-06, 4.851875965229655e-06, 4.818693640273514e-06,
+        4.785416211521631e-06, 4.752045133446261e-06, 4.7185818606196095e-06, 4.685027847659086e-06,
+        4.651384549171832e-06, 4.617653419699547e-06, 4.583835913663274e-06, 4.5499334853090345e-06,
+        4.515947588652335e-06, 4.481879677424123e-06, 4.447731205016297e-06, 4.413503624426844e-06,
+        4.379198388206215e-06, 4.344816948402853e-06, 4.3103607565091115e-06, 4.275831263408076e-06,
+        4.241229919319491e-06, 4.2065581737460635e-06, 4.1718174754206226e-06, 4.137009272252486e-06,
+        4.102135011274343e-06, 4.067196138589957e-06, 4.03219409932064e-06, 3.997130337553349e-06,
+        3.962006296287599e-06, 3.926823417383734e-06, 3.891583141510678e-06, 3.856286908093507e-06,
+        3.820936155262291e-06, 3.7855323198003623e-06, 3.7500768370924736e-06, 3.714571141074038e-06,
+        3.6790166641796355e-06, 3.643414837291966e-06, 3.607767089691694e-06, 3.5720748490064063e-06,
+        3.536339541160087e-06, 3.5005625903233765e-06, 3.464745418863073e-06, 3.4288894472922127e-06,
+        3.3929960942210536e-06, 3.357066776306756e-06, 3.321102908204743e-06, 3.285105902519e-06,
+        3.2490771697538018e-06, 3.2130181182640446e-06, 3.1769301542078207e-06, 3.140814681497106e-06,
+        3.1046731017503118e-06, 3.0685068142437056e-06, 3.0323172158643595e-06, 2.9961057010617227e-06,
+        2.959873661800953e-06, 2.923622487515716e-06, 2.887353565060752e-06, 2.8510682786656557e-06,
+        2.8147680098882356e-06, 2.778454137567655e-06, 2.7421280377789755e-06, 2.705791083787163e-06,
+        2.6694446460009095e-06, 2.633090091927805e-06, 2.5967287861285653e-06, 2.5603620901719346e-06,
+        2.5239913625905396e-06, 2.4876179588354072e-06, 2.4512432312322168e-06, 2.4148685289368834e-06,
+        2.3784951978915153e-06, 2.342124580780538e-06, 2.3057580169879294e-06, 2.2693968425530253e-06,
+        2.233042390128218e-06, 2.1966959889354885e-06, 2.160358964724541e-06, 2.1240326397295463e-06,
+        2.0877183326277695e-06, 2.051417358497474e-06, 2.01513102877621e-06, 1.9788606512191324e-06,
+        1.942607529858467e-06, 1.906372964961661e-06, 1.8701582529914034e-06, 1.833964686565078e-06,
+        1.797793554414029e-06, 1.7616461413441486e-06, 1.72552372819604e-06, 1.6894275918046213e-06,
+        1.653359004961335e-06, 1.6173192363742283e-06, 1.5813095506291146e-06, 1.5453312081516153e-06,
+        1.5093854651683931e-06, 1.4734735736690613e-06, 1.4375967813691379e-06, 1.4017563316716249e-06,
+        1.3659534636304074e-06, 1.3301894119129709e-06, 1.2944654067635692e-06, 1.2587826739665521e-06,
+        1.2231424348108574e-06, 1.1875459060531004e-06, 1.1519942998825839e-06, 1.1164888238851692e-06,
+        1.0810306810087784e-06, 1.045621069527534e-06, 1.0102611830078088e-06, 9.74952210273612e-07,
+        9.396953353723328e-07, 9.044917375405782e-07, 8.693425911711895e-07, 8.342490657789357e-07,
+        7.992123259681408e-07, 7.642335313997488e-07, 7.29313836758232e-07, 6.944543917199338e-07,
+        6.596563409205194e-07, 6.249208239231025e-07, 5.90248975187389e-07, 5.556419240379346e-07,
+        5.211007946330053e-07, 4.866267059344408e-07, 4.52220771676729e-07, 4.178841003367634e-07,
+        3.836177951046833e-07, 3.494229538533585e-07, 3.1530066910978094e-07, 2.812520280253408e-07,
+        2.4727811234776505e-07, 2.1337999839161866e-07, 1.7955875701120237e-07, 1.458154535716043e-07,
+        1.1215114792174086e-07, 7.856689436629407e-08, 4.506374163930165e-08, 1.164273287640902e-08,
+        -2.169509441098403e-08, -5.4948708362107496e-08, -8.811708279332676e-08, -1.2119919722332217e-07,
+        -1.5419403689871425e-07, -1.8710059281972702e-07, -2.199178617644482e-07, -2.5264484631353366e-07,
+        -2.8528055487502296e-07, -3.178240017077621e-07, -3.502742069456567e-07, -3.826301966212442e-07,
+        -4.14891002688193e-07, -4.4705566304509876e-07, -4.791232215575048e-07, -5.110927280804921e-07,
+        -5.429632384808429e-07, -5.7473381465899e-07, -6.064035245698203e-07, -6.379714422447647e-07,
+        -6.694366478120402e-07, -7.007982275179242e-07, -7.320552737464307e-07, -7.632068850403327e-07,
+        -7.942521661198764e-07, -8.251902279032252e-07, -8.560201875250046e-07, -8.867411683558653e-07,
+        -9.17352300020444e-07, -9.478527184165916e-07, -9.782415657327726e-07, -1.0085179904659443e-06,
+        -1.0386811474395381e-06, -1.068730197820152e-06, -1.0986643091346666e-06, -1.1284826552873423e-06,
+        -1.1581844165755976e-06, -1.187768779706177e-06, -1.2172349378114076e-06, -1.2465820904641091e-06,
+        -1.275809443693296e-06, -1.3049162099991669e-06, -1.333901608367123e-06, -1.3627648642829523e-06,
+        -1.3915052097463219e-06, -1.4201218832848172e-06, -1.4486141299675212e-06, -1.4769812014184136e-06,
+        -1.505222355828639e-06, -1.5333368579699971e-06, -1.5613239792066477e-06, -1.5891829975078077e-06,
+        -1.6169131974588922e-06, -1.644513870273843e-06, -1.671984313805742e-06, -1.6993238325578714e-06,
+        -1.7265317376945028e-06, -1.75360734705148e-06, -1.7805499851457702e-06, -1.8073589831860586e-06,
+        -1.834033679081681e-06, -1.8605734174519199e-06, -1.8869775496354342e-06, -1.9132454336983796e-06,
+        -1.9393764344432577e-06, -1.9653699234171096e-06, -1.9912252789188098e-06, -2.016941886007085e-06,
+        -2.0425191365079075e-06, -2.0679564290210456e-06, -2.093253168927218e-06, -2.118408768394593e-06,
+        -2.143422646384379e-06, -2.1682942286574414e-06, -2.1930229477793558e-06, -2.217608243126234e-06,
+        -2.2420495608892373e-06, -2.2663463540801336e-06, -2.2904980825348932e-06, -2.314504212918715e-06,
+        -2.3383642187294185e-06, -2.3620775803016125e-06, -2.385643784809561e-06, -2.4090623262710127e-06,
+        -2.4323327055494905e-06, -2.455454430357023e-06, -2.4784270152565097e-06, -2.501249981664001e-06,
+        -2.5239228578499174e-06, -2.5464451789412803e-06, -2.568816486922377e-06, -2.5910363306357996e-06,
+        -2.6131042657835077e-06, -2.635019854926754e-06, -2.656782667486659e-06, -2.6783922797441097e-06,
+        -2.699848274838938e-06, -2.7211502427696404e-06, -2.742297780392589e-06, -2.7632904914204483e-06,
+        -2.7841279864211315e-06, -2.804809882816107e-06, -2.8253358048779587e-06, -2.845705383728794e-06,
+        -2.8659182573372386e-06, -2.885974070516174e-06, -2.9058724749191865e-06, -2.9256131290380602e-06,
+        -2.9451956981983773e-06, -2.9646198545564835e-06, -2.9838852770949196e-06, -3.002991651618602e-06,
+        -3.0219386707497573e-06, -3.0407260339237426e-06, -3.0593534473835133e-06, -3.0778206241743387e-06,
+        -3.096127284138583e-06, -3.1142731539095367e-06, -3.1322579669054416e-06, -3.150081463323504e-06,
+        -3.1677433901329374e-06, -3.1852435010681656e-06, -3.202581556622107e-06, -3.2197573240384564e-06,
+        -3.2367705773044448e-06, -3.253621097143099e-06, -3.270308671004736e-06, -3.2868330930592806e-06,
+        -3.3031941641872896e-06, -3.31939169197137e-06, -3.3354254906872205e-06, -3.3512953812944863e-06,
+        -3.3670011914268384e-06, -3.382542755382781e-06, -3.3979199141153025e-06, -3.4131325152220055e-06,
+        -3.4281804129342944e-06, -3.4430634681072256e-06, -3.457781548208193e-06, -3.4723345273058862e-06,
+        -3.486722286059021e-06, -3.500944711704834e-06, -3.515001698046943e-06, -3.5288931454437926e-06,
+        -3.542618960795974e-06, -3.5561790575337854e-06, -3.569573355604762e-06, -3.582801781460468e-06,
+        -3.5958642680433445e-06, -3.608760754773637e-06, -3.6214911875354217e-06, -3.6340555186628146e-06,
+        -3.6464537069261926e-06, -3.658685717517571e-06, -3.6707515220364074e-06, -3.682651098474895e-06,
+        -3.694384431202714e-06, -3.7059515109523444e-06, -3.717352334803401e-06, -3.728586906167205e-06,
+        -3.739655234771105e-06, -3.750557336642507e-06, -3.76129323409241e-06, -3.7718629556994224e-06,
+        -3.782266536292832e-06, -3.792504016936138e-06, -3.8025754449096677e-06, -3.812480873693866e-06,
+        -3.8222203629514725e-06, -3.831793978510052e-06, -3.841201792344223e-06, -3.8504438825577654e-06,
+        -3.859520333365148e-06, -3.8684312350735165e-06, -3.877176684063824e-06, -3.885756782772063e-06,
+        -3.894171639670522e-06, -3.902421369248349e-06, -3.910506091992419e-06, -3.91842593436781e-06,
+        -3.926181028797874e-06, -3.9337715136444615e-06, -3.941197533187918e-06, -3.948459237606613e-06,
+        -3.9555567829566475e-06, -3.962490331151247e-06, -3.969260049939682e-06, -3.975866112886626e-06,
+        -3.982308699350665e-06, -3.9885879944632335e-06, -3.994704189106806e-06, -4.000657479893559e-06,
+        -4.0064480691431054e-06, -4.012076164860786e-06, -4.017541980715252e-06, -4.022845736016247e-06,
+        -4.027987655691876e-06, -4.0329679702661604e-06, -4.0377869158359425e-06, -4.042444734047876e-06,
+        -4.0469416720754144e-06, -4.0512779825952105e-06, -4.055453923763709e-06, -4.0594697591936e-06,
+        -4.0633257579297955e-06, -4.067022194425481e-06, -4.070559348518077e-06, -4.073937505404852e-06,
+        -4.077156955618562e-06, -4.080217995002713e-06, -4.083120924687068e-06, -4.085866051062458e-06,
+        -4.088453685756021e-06, -4.090884145605789e-06, -4.09315775263561e-06, -4.095274834029422e-06,
+        -4.097235722105901e-06, -4.09904075429255e-06, -4.100690273099827e-06, -4.102184626095227e-06,
+        -4.103524165877049e-06, -4.1047092500480955e-06, -4.105740241189357e-06, -4.106617506833363e-06,
+        -4.107341419437491e-06, -4.107912356357269e-06, -4.10833069981929e-06, -4.108596836894308e-06,
+        -4.108711159469973e-06, -4.1086740642234635e-06, -4.108485952594178e-06, -4.108147230756099e-06,
+        -4.107658309590144e-06, -4.107019604656399e-06, -4.106231536166277e-06, -4.1052945289543155e-06,
+        -4.104209012450241e-06, -4.102975420650602e-06, -4.101594192090508e-06, -4.100065769815053e-06,
+        -4.098390601350927e-06, -4.096569138677556e-06, -4.094601838198404e-06, -4.092489160712084e-06,
+        -4.0902315713834e-06, -4.087829539714188e-06, -4.08528353951421e-06, -4.082594048871844e-06,
+        -4.07976155012465e-06, -4.076786529829977e-06, -4.073669478735326e-06, -4.070410891748737e-06,
+        -4.067011267909015e-06, -4.0634711103558815e-06, -4.05979092630007e-06, -4.055971226993279e-06,
+        -4.0520125276980735e-06, -4.047915347657715e-06, -4.043680210065899e-06, -4.039307642036354e-06,
+        -4.0347981745724954e-06, -4.0301523425368335e-06, -4.025370684620433e-06, -4.020453743312245e-06,
+        -4.015402064868366e-06, -4.010216199281237e-06, -4.004896700248743e-06, -3.999444125143327e-06,
+        -3.993859034980879e-06, -3.988141994389725e-06, -3.982293571579428e-06, -3.976314338309609e-06,
+        -3.970204869858648e-06, -3.9639657449923464e-06, -3.957597545932555e-06, -3.951100858325663e-06,
+        -3.944476271211105e-06, -3.937724376989811e-06, -3.9308457713925505e-06, -3.923841053448249e-06,
+        -3.916710825452308e-06, -3.9094556929347595e-06, -3.902076264628437e-06, -3.89457315243714e-06,
+        -3.8869469714036995e-06, -3.8791983396779585e-06, -3.871327878484808e-06, -3.863336212092142e-06,
+        -3.85522396777867e-06, -3.846991775801908e-06, -3.8386402693658855e-06, -3.8301700845890365e-06,
+        -3.821581860471858e-06, -3.812876238864705e-06, -3.804053864435431e-06, -3.7951153846370825e-06,
+        -3.786061449675466e-06, -3.776892712476814e-06, -3.767609828655296e-06, -3.7582134564806154e-06,
+        -3.7487042568454888e-06, -3.7390828932331386e-06, -3.7293500316848362e-06, -3.719506340767251e-06,
+        -3.7095524915399756e-06, -3.699489157522939e-06, -3.6893170146637004e-06, -3.6790367413049844e-06,
+        -3.668649018151977e-06, -3.658154528239666e-06, -3.6475539569002693e-06, -3.636847991730502e-06,
+        -3.6260373225588856e-06, -3.615122641413206e-06, -3.6041046424877034e-06, -3.5929840221104506e-06,
+        -3.5817614787106513e-06, -3.5704377127859455e-06, -3.559013426869655e-06, -3.5474893254982596e-06,
+        -3.53586611517856e-06, -3.5241445043550783e-06, -3.512325203377294e-06, -3.500408924467106e-06,
+        -3.4883963816859894e-06, -3.476288290902484e-06, -3.464085369759509e-06, -3.4517883376417554e-06,
+        -3.4393979156429397e-06, -3.426914826533382e-06, -3.41433979472721e-06, -3.401673546249939e-06,
+        -3.388916808705897e-06, -3.376070311245557e-06, -3.363134784533203e-06, -3.3501109607143138e-06,
+        -3.3369995733830194e-06, -3.3238013575497993e-06, -3.310517049609024e-06, -3.297147387306433e-06,
+        -3.2836931097069653e-06, -3.2701549571622174e-06, -3.256533671278162e-06, -3.2428299948830376e-06,
+        -3.2290446719948625e-06, -3.2151784477893924e-06, -3.201232068567858e-06, -3.1872062817248548e-06,
+        -3.1731018357160924e-06, -3.158919480026606e-06, -3.144659965138442e-06, -3.1303240424988826e-06,
+        -3.1159124644883103e-06, -3.101425984388505e-06, -3.086865356350565e-06, -3.072231335363243e-06,
+        -3.0575246772211705e-06, -3.0427461384931116e-06, -3.0278964764902103e-06, -3.0129764492345606e-06,
+        -2.997986815427399e-06, -2.982928334417757e-06, -2.9678017661710143e-06, -2.952607871237328e-06,
+        -2.9373474107204795e-06, -2.922021146246434e-06, -2.906629839932048e-06, -2.8911742543541677e-06,
+        -2.8756551525182666e-06, -2.860073297827402e-06, -2.8444294540513714e-06, -2.8287243852956462e-06,
+        -2.8129588559704596e-06, -2.7971336307602503e-06, -2.781249474592671e-06, -2.7653071526081143e-06,
+        -2.749307430128918e-06, -2.7332510726291067e-06, -2.7171388457035765e-06, -2.7009715150381124e-06,
+        -2.684749846378776e-06, -2.6684746055018637e-06, -2.6521465581835636e-06, -2.63576647017012e-06,
+        -2.619335107147505e-06, -2.6028532347117542e-06, -2.586321618339092e-06, -2.5697410233560062e-06,
+        -2.5531122149097293e-06, -2.536435957938606e-06, -2.5197130171423776e-06, -2.502944156952997e-06,
+        -2.486130141505247e-06, -2.469271734607293e-06, -2.4523696997117447e-06, -2.4354247998863906e-06,
+        -2.4184377977851544e-06, -2.4014094556194535e-06, -2.384340535129185e-06, -2.3672317975539214e-06,
+        -2.3500840036045438e-06, -2.3328979134344556e-06, -2.3156742866111294e-06, -2.298413882088063e-06,
+        -2.281117458176151e-06, -2.263785772515834e-06, -2.246419582048768e-06, -2.2290196429901947e-06,
+        -2.211586710800637e-06, -2.1941215401586595e-06, -2.1766248849327744e-06, -2.1590974981542238e-06,
+        -2.1415401319892347e-06, -2.123953537712024e-06, -2.1063384656771242e-06, -2.0886956652926125e-06,
+        -2.071025884993029e-06, -2.053329872212257e-06, -2.0356083733569632e-06, -2.0178621337798174e-06,
+        -2.000091897752725e-06, -1.982298408440582e-06, -1.964482407874919e-06, -1.9466446369274175e-06,
+        -1.9287858352840534e-06, -1.9109067414188684e-06, -1.8930080925680106e-06, -1.8750906247042932e-06,
+        -1.8571550725110817e-06, -1.8392021693570486e-06, -1.821232647270618e-06, -1.803247236914603e-06,
+        -1.7852466675609222e-06, -1.7672316670658776e-06, -1.749202961844743e-06, -1.7311612768472845e-06,
+        -1.7131073355327352e-06, -1.6950418598455417e-06, -1.6769655701904757e-06, -1.6588791854086135e-06,
+        -1.6407834227530742e-06, -1.6226789978648402e-06, -1.6045666247487038e-06, -1.5864470157497168e-06,
+        -1.5683208815290584e-06, -1.5501889310407888e-06, -1.5320518715083554e-06, -1.5139104084010463e-06,
+        -1.495765245411074e-06, -1.4776170844302978e-06, -1.4594666255272392e-06, -1.4413145669245902e-06,
+        -1.4231616049763103e-06, -1.4050084341450235e-06, -1.3868557469798583e-06, -1.368704234093968e-06,
+        -1.3505545841423469e-06, -1.3324074838001866e-06, -1.3142636177405824e-06, -1.2961236686131158e-06,
+        -1.2779883170221643e-06, -1.2598582415053844e-06, -1.2417341185123385e-06, -1.2236166223836697e-06,
+        -1.205506425329634e-06, -1.1874041974095607e-06, -1.1693106065107733e-06, -1.151226318328356e-06,
+        -1.1331519963442091e-06, -1.115088301807099e-06, -1.0970358937123857e-06, -1.078995428781934e-06,
+        -1.060967561444087e-06, -1.042952943814242e-06, -1.024952225674773e-06, -1.0069660544559339e-06,
+        -9.889950752164876e-07, -9.710399306242886e-07, -9.53101260937562e-07, -9.351797039857771e-07,
+        -9.172758951508739e-07, -8.993904673489715e-07, -8.815240510116896e-07, -8.636772740677657e-07,
+        -8.458507619251851e-07, -8.280451374529335e-07, -8.102610209630995e-07, -7.924990301935441e-07,
+        -7.747597802898812e-07, -7.570438837884477e-07, -7.393519505987189e-07, -7.216845879865677e-07,
+        -7.040424005568231e-07, -6.864259902370403e-07, -6.68835956260347e-07, -6.51272895149321e-07,
+        -6.337374006993094e-07, -6.16230063962601e-07, -5.987514732319361e-07, -5.813022140249769e-07,
+        -5.638828690685371e-07, -5.464940182827448e-07, -5.291362387658999e-07, -5.118101047791143e-07,
+        -4.945161877309505e-07, -4.772550561627736e-07, -4.600272757338931e-07, -4.428334092066732e-07,
+        -4.2567401643233545e-07, -4.085496543363809e-07, -3.914608769043499e-07, -3.744082351681478e-07,
+        -3.573922771917194e-07, -3.4041354805764877e-07, -3.234725898534597e-07, -3.0656994165816855e-07,
+        -2.897061395289459e-07, -2.728817164883717e-07, -2.5609720251105594e-07, -2.3935312451120565e-07,
+        -2.226500063296816e-07, -2.059883687218757e-07, -1.893687293449629e-07, -1.7279160274609742e-07,
+        -1.562575003503316e-07, -1.3976693044873203e-07, -1.2332039818657527e-07, -1.0691840555213437e-07,
+        -9.056145136487337e-08, -7.425003126458912e-08, -5.7984637700315094e-08, -4.176575991918964e-08,
+        -2.5593883955989392e-08, -9.469492622430668e-09, 6.606934503293872e-09, 2.263492108685764e-08,
+        3.861399404755874e-08, 5.4543683568696164e-08, 7.042352310701286e-08, 8.625304940263784e-08,
+        1.0203180248821549e-07, 1.1775932569864493e-07, 1.3343516567989346e-07, 1.4905887239807478e-07,
+        1.6462999914833088e-07, 1.8014810256363153e-07, 1.9561274262299542e-07, 2.110234826602817e-07,
+        2.263798893720542e-07, 2.4168153282567795e-07, 2.569279864674402e-07, 2.721188271299872e-07,
+        2.8725363504013225e-07, 3.023319938263175e-07, 3.17353490525577e-07, 3.3231771559087374e-07,
+        3.4722426289812477e-07, 3.6207272975273633e-07, 3.7686271689646484e-07, 3.915938285139325e-07,
+        4.062656722386272e-07, 4.208778591594653e-07, 4.35430003826482e-07, 4.4992172425697535e-07,
+        4.643526419408785e-07, 4.787223818467419e-07, 4.930305724266091e-07, 5.072768456216917e-07,
+        5.21460836867112e-07, 5.355821850971195e-07, 5.49640532749533e-07, 5.636355257707482e-07,
+        5.775668136198691e-07, 5.914340492730586e-07, 6.052368892278866e-07, 6.189749935070866e-07,
+        6.326480256624668e-07, 6.462556527788077e-07, 6.597975454771181e-07, 6.732733779180476e-07,
+        6.866828278053389e-07, 7.000255763886075e-07, 7.133013084664884e-07, 7.265097123894374e-07,
+        7.396504800620789e-07, 7.527233069458815e-07, 7.657278920615415e-07, 7.786639379909243e-07,
+        7.91531150879269e-07, 8.043292404371007e-07, 8.17057919941638e-07, 8.297169062387358e-07,
+        8.423059197439962e-07, 8.548246844443194e-07, 8.672729278987031e-07, 8.796503812396297e-07,
+        8.919567791734229e-07, 9.041918599813189e-07, 9.163553655197048e-07, 9.28447041220755e-07,
+        9.40466636092393e-07, 9.524139027187147e-07, 9.642885972596557e-07, 9.760904794508199e-07,
+        9.87819312603328e-07, 9.99474863603101e-07, 1.0110569029102844e-06, 1.0225652045586768e-06,
+        1.0339995461545316e-06, 1.0453597088755377e-06, 1.0566454774697974e-06, 1.0678566402542249e-06,
+        1.0789929891132371e-06, 1.0900543194971689e-06, 1.101040430420195e-06, 1.1119511244587694e-06,
+        1.122786207749324e-06, 1.1335454899861636e-06, 1.144228784419161e-06, 1.1548359078513444e-06,
+        1.1653666806360304e-06, 1.175820926674378e-06, 1.186198473412256e-06, 1.196499151837448e-06,
+        1.206722796476234e-06, 1.2168692453904099e-06, 1.2269383401735956e-06, 1.2369299259477458e-06,
+        1.246843851359447e-06, 1.2566799685761947e-06, 1.2664381332821882e-06, 1.2761182046745418e-06,
+        1.2857200454587986e-06, 1.2952435218446067e-06, 1.3046885035413674e-06, 1.3140548637534027e-06,
+        1.3233424791753595e-06, 1.3325512299873505e-06, 1.3416809998497172e-06, 1.3507316758980454e-06,
+        1.3597031487379659e-06, 1.3685953124395335e-06, 1.3774080645318869e-06, 1.3861413059976002e-06,
+        1.3947949412666798e-06, 1.4033688782109433e-06, 1.4118630281377709e-06, 1.4202773057840522e-06,
+        1.4286116293099032e-06, 1.4368659202923371e-06, 1.4450401037185002e-06, 1.4531341079793076e-06,
+        1.4611478648624488e-06, 1.469081309545711e-06, 1.4769343805897263e-06, 1.4847070199311278e-06,
+        1.4923991728750764e-06, 1.500010788087952e-06, 1.5075418175898906e-06, 1.5149922167472447e-06,
+        1.5223619442647125e-06, 1.529650962177717e-06, 1.5368592358442776e-06, 1.5439867339369641e-06,
+        1.5510334284348593e-06, 1.557999294615084e-06, 1.5648843110445296e-06, 1.5716884595713833e-06,
+        1.5784117253162917e-06, 1.5850540966637829e-06, 1.5916155652534498e-06, 1.5980961259708535e-06,
+        1.6044957769385718e-06, 1.6108145195070716e-06, 1.6170523582452047e-06, 1.623209300931077e-06,
+        1.6292853585423559e-06, 1.6352805452468393e-06, 1.641194878392567e-06, 1.6470283784982497e-06,
+        1.6527810692430975e-06, 1.6584529774570131e-06, 1.6640441331103125e-06, 1.669554569303669e-06,
+        1.6749843222576091e-06, 1.6803334313023159e-06, 1.6856019388669158e-06, 1.6907898904689277e-06,
+        1.6958973347036049e-06, 1.7009243232330212e-06, 1.7058709107752037e-06, 1.7107371550932227e-06,
+        1.715523116983952e-06, 1.7202288602669079e-06, 1.7248544517730394e-06, 1.7293999613332262e-06,
+        1.733865461766905e-06, 1.7382510288705192e-06, 1.7425567414057154e-06, 1.7467826810877692e-06,
+        1.7509289325736488e-06, 1.754995583450096e-06, 1.7589827242216639e-06, 1.7628904482986341e-06,
+        1.7667188519846926e-06, 1.7704680344648327e-06, 1.774138097792848e-06, 1.777729146879046e-06,
+        1.7812412894775374e-06, 1.7846746361738382e-06, 1.7880293003720159e-06, 1.7913053982819747e-06,
+        1.7945030489065887e-06, 1.7976223740288559e-06, 1.8006634981987285e-06, 1.8036265487202068e-06,
+        1.806511655638037e-06, 1.80931895172449e-06, 1.8120485724661186e-06, 1.8147006560502615e-06,
+        1.8172753433516338e-06, 1.8197727779188177e-06, 1.8221931059605682e-06, 1.8245364763321333e-06,
+        1.826803040521572e-06, 1.8289929526358232e-06, 1.8311063693868722e-06, 1.8331434500778168e-06,
+        1.8351043565886842e-06, 1.8369892533624835e-06, 1.838798307390927e-06, 1.8405316882002345e-06,
+        1.8421895678368225e-06, 1.843772120852997e-06, 1.845279524292366e-06, 1.8467119576755032e-06,
+        1.8480696029852789e-06, 1.8493526446523282e-06, 1.8505612695402746e-06, 1.8516956669310937e-06,
+        1.8527560285102533e-06, 1.8537425483518311e-06, 1.8546554229036369e-06, 1.8554948509722609e-06,
+        1.8562610337079678e-06, 1.856954174589718e-06, 1.857574479409932e-06, 1.8581221562593445e-06,
+        1.858597415511762e-06, 1.859000469808723e-06, 1.859331534044195e-06, 1.859590825349184e-06,
+        1.859778563076204e-06, 1.859894968783865e-06, 1.85994026622129e-06, 1.8599146813124963e-06,
+        1.8598184421407919e-06, 1.859651778933095e-06, 1.8594149240441303e-06, 1.8591081119407322e-06,
+        1.8587315791859798e-06, 1.8582855644233473e-06, 1.8577703083607669e-06, 1.8571860537547507e-06,
+        1.8565330453943113e-06, 1.8558115300850103e-06, 1.855021756632828e-06, 1.8541639758281363e-06,
+        1.8532384404294582e-06, 1.852245405147375e-06, 1.8511851266282598e-06, 1.8500578634380306e-06,
+        1.8488638760458883e-06, 1.8476034268079894e-06, 1.846276779951067e-06, 1.8448842015560963e-06,
+        1.8434259595418407e-06, 1.8419023236484117e-06, 1.8403135654208142e-06, 1.8386599581924098e-06,
+        1.8369417770684294e-06, 1.8351592989093784e-06, 1.8333128023144505e-06, 1.8314025676049273e-06,
+        1.8294288768075453e-06, 1.8273920136378222e-06, 1.8252922634833684e-06, 1.8231299133872208e-06,
+        1.8209052520310412e-06, 1.8186185697184224e-06, 1.8162701583580879e-06, 1.8138603114471187e-06,
+        1.8113893240541165e-06, 1.8088574928024245e-06, 1.8062651158532152e-06, 1.803612492888676e-06,
+        1.800899925095107e-06, 1.7981277151460423e-06, 1.7952961671853231e-06, 1.7924055868101876e-06,
+        1.7894562810543466e-06, 1.7864485583710054e-06, 1.7833827286159272e-06, 1.7802591030304554e-06,
+        1.777077994224539e-06, 1.7738397161597366e-06, 1.7705445841322131e-06, 1.767192914755773e-06,
+        1.7637850259447881e-06, 1.7603212368972204e-06, 1.7568018680775872e-06, 1.7532272411999246e-06,
+        1.7495976792107605e-06, 1.7459135062720932e-06, 1.7421750477443226e-06, 1.7383826301692281e-06,
+        1.7345365812529208e-06, 1.7306372298487946e-06, 1.726684905940504e-06, 1.7226799406248906e-06,
+        1.7186226660949935e-06, 1.714513415622949e-06, 1.7103525235430038e-06, 1.706140325234456e-06,
+        1.7018771571046473e-06, 1.6975633565719418e-06, 1.6931992620486934e-06, 1.6887852129242662e-06,
+        1.6843215495480124e-06, 1.6798086132122694e-06, 1.675246746135401e-06, 1.6706362914448204e-06,
+        1.6659775931600002e-06, 1.661270996175567e-06, 1.6565168462443157e-06, 1.651715489960264e-06,
+        1.6468672747418036e-06, 1.6419725488147524e-06, 1.6370316611954509e-06, 1.6320449616739286e-06,
+        1.6270128007970316e-06, 1.621935529851539e-06, 1.6168135008474107e-06, 1.6116470665009156e-06,
+        1.606436580217884e-06, 1.6011823960769243e-06, 1.595884868812654e-06, 1.5905443537989864e-06,
+        1.5851612070324368e-06, 1.57973578511539e-06, 1.574268445239487e-06, 1.5687595451689316e-06,
+        1.5632094432239176e-06, 1.5576184982639947e-06, 1.5519870696715084e-06, 1.546315517335078e-06,
+        1.5406042016330587e-06, 1.5348534834170257e-06, 1.529063723995368e-06, 1.5232352851167875e-06,
+        1.5173685289539226e-06, 1.5114638180869866e-06, 1.5055215154873671e-06, 1.4995419845013663e-06,
+        1.4935255888338517e-06, 1.48747269253207e-06, 1.481383659969319e-06, 1.4752588558288978e-06,
+        1.4690986450878071e-06, 1.4629033930007337e-06, 1.4566734650838778e-06, 1.4504092270989822e-06,
+        1.4441110450372093e-06, 1.437779285103261e-06, 1.4314143136993965e-06, 1.4250164974094815e-06,
+        1.4185862029831897e-06, 1.4121237973201459e-06, 1.4056296474540774e-06, 1.399104120537141e-06,
+        1.3925475838242149e-06, 1.385960404657141e-06, 1.3793429504491973e-06, 1.3726955886694473e-06,
+        1.3660186868271801e-06, 1.3593126124565076e-06, 1.3525777331007825e-06, 1.3458144162972979e-06,
+        1.3390230295618467e-06, 1.3322039403734164e-06, 1.3253575161588806e-06, 1.3184841242778664e-06,
+        1.311584132007449e-06, 1.304657906527129e-06, 1.2977058149036302e-06, 1.2907282240759775e-06,
+        1.2837255008403768e-06, 1.2766980118353734e-06, 1.2696461235269453e-06, 1.2625702021936352e-06,
+        1.2554706139117548e-06, 1.2483477245407034e-06, 1.241201899708172e-06, 1.2340335047956127e-06,
+        1.2268429049236446e-06, 1.219630464937454e-06, 1.212396549392398e-06, 1.2051415225395822e-06,
+        1.1978657483113918e-06, 1.1905695903072973e-06, 1.1832534117795882e-06, 1.1759175756190957e-06,
+        1.1685624443411491e-06, 1.1611883800714375e-06, 1.153795744531934e-06, 1.1463848990270683e-06,
+        1.138956204429668e-06, 1.13151002116721e-06, 1.1240467092079918e-06, 1.1165666280473755e-06,
+        1.109070136694116e-06, 1.1015575936568323e-06, 1.0940293569303359e-06, 1.0864857839822525e-06,
+        1.0789272317394883e-06, 1.0713540565749876e-06, 1.0637666142942754e-06, 1.0561652601223557e-06,
+        1.0485503486904867e-06, 1.0409222340230654e-06, 1.03328126952454e-06, 1.0256278079665327e-06,
+        1.017962201474751e-06, 1.0102848015163614e-06, 1.0025959588869495e-06, 9.948960236980156e-07,
+        9.871853453641985e-07, 9.794642725907486e-07, 9.717331533608867e-07, 9.6399233492355e-07,
+        9.56242163780793e-07, 9.48482985675643e-07, 9.407151455797571e-07, 9.329389876812929e-07,
+        9.251548553727074e-07, 9.173630912387782e-07, 9.09564037044683e-07, 9.017580337240286e-07,
+        8.939454213670243e-07, 8.861265392087655e-07, 8.783017256174526e-07, 8.70471318082834e-07,
+        8.626356532047147e-07, 8.547950666814085e-07, 8.469498932983733e-07, 8.391004669168073e-07,
+        8.312471204624477e-07, 8.23390185914452e-07, 8.155299942941949e-07, 8.076668756542601e-07,
+        7.998011590675244e-07, 7.919331726161568e-07, 7.840632433808785e-07, 7.761916974302901e-07,
+        7.683188598101073e-07, 7.604450545326437e-07, 7.525706045661643e-07, 7.446958318246866e-07,
+        7.368210571573468e-07, 7.289466003383488e-07, 7.210727800566313e-07, 7.131999139057979e-07,
+        7.053283183739326e-07, 6.974583088338631e-07, 6.895901995329825e-07, 6.817243035836685e-07,
+        6.738609329534398e-07, 6.660003984551939e-07, 6.581430097378136e-07, 6.502890752765584e-07,
+        6.424389023635504e-07, 6.345927970985985e-07, 6.26751064379842e-07, 6.189140078944566e-07,
+        6.110819301097353e-07, 6.0325513226396e-07, 5.954339143573428e-07, 5.876185751433931e-07,
+        5.798094121198454e-07, 5.720067215201856e-07, 5.642107983048828e-07, 5.564219361529011e-07,
+        5.486404274530961e-07, 5.408665632960588e-07, 5.331006334655316e-07, 5.253429264304156e-07,
+        5.175937293364891e-07, 5.098533279984055e-07, 5.021220068916116e-07, 4.944000491445033e-07,
+        4.866877365306806e-07, 4.789853494610943e-07, 4.7129316697642864e-07, 4.6361146673955625e-07,
+        4.5594052502794414e-07, 4.482806167263072e-07, 4.4063201531934317e-07, 4.3299499288439257e-07,
+        4.2536982008432884e-07, 4.1775676616038497e-07, 4.101560989252162e-07, 4.0256808475605025e-07,
+        3.9499298858775135e-07, 3.8743107390610894e-07, 3.798826027411925e-07, 3.723478356606713e-07,
+        3.648270317633792e-07, 3.5732044867295927e-07, 3.4982834253142834e-07, 3.4235096799298885e-07,
+        3.3488857821773287e-07, 3.274414248657767e-07, 3.200097580909998e-07, 3.1259382653535376e-07,
+        3.051938773228941e-07, 2.978101560540913e-07, 2.904429068000295e-07, 2.8309237209705357e-07,
+        2.7575879294099557e-07, 2.6844240878198996e-07, 2.611434575190375e-07, 2.538621754946526e-07,
+        2.4659879748989006e-07, 2.393535567191647e-07, 2.3212668482514285e-07, 2.2491841187402393e-07,
+        2.1772896635060495e-07, 2.105585751534286e-07, 2.0340746359031092e-07, 1.9627585537365443e-07,
+        1.8916397261585165e-07, 1.8207203582508755e-07, 1.750002639007453e-07, 1.6794887412937998e-07,
+        1.6091808218041743e-07, 1.5390810210213113e-07, 1.4691914631750736e-07, 1.39951425620553e-07,
+        1.3300514917219301e-07, 1.2608052449674355e-07, 1.1917775747810803e-07, 1.1229705235625099e-07,
+        1.054386117235923e-07, 9.860263652164808e-08, 9.178932603774777e-08, 8.499887790167888e-08,
+        7.823148808253934e-08, 7.148735088567252e-08, 6.476665894955667e-08, 5.806960324293555e-08,
+        5.139637306203215e-08, 4.474715602768161e-08, 3.812213808267681e-08, 3.152150348919308e-08,
+        2.4945434826168592e-08, 1.839411298692411e-08, 1.1867717176863171e-08, 5.366424911096249e-09,
+        -1.1095879876974659e-09, -7.560147391483507e-09, -1.3985080868848595e-08, -2.0384217686897294e-08,
+        -2.675738881322391e-08, -3.310442691767998e-08, -3.942516637406091e-08, -4.571944326183737e-08,
+        -5.198709536765165e-08, -5.822796218673527e-08, -6.444188492440101e-08, -7.062870649730131e-08,
+        -7.678827153479307e-08, -8.292042637988419e-08, -8.90250190905621e-08, -9.51018994405854e-08,
+        -1.0115091892053202e-07, -1.0717193073858707e-07, -1.131647898214326e-07, -1.1912935281473211e-07,
+        -1.2506547808398186e-07, -1.3097302571484036e-07, -1.3685185751367863e-07, -1.4270183700805125e-07,
+        -1.4852282944682378e-07, -1.5431470180049435e-07, -1.6007732276143062e-07, -1.658105627437722e-07,
+        -1.7151429388351962e-07, -1.7718839003854606e-07, -1.8283272678827175e-07, -1.8844718143352487e-07,
+        -1.9403163299633006e-07, -1.9958596221932456e-07, -2.0511005156554176e-07, -2.1060378521768332e-07,
+        -2.1606704907763466e-07, -2.2149973076573435e-07, -2.2690171962013886e-07, -2.322729066958079e-07,
+        -2.376131847638299e-07, -2.4292244831026144e-07, -2.4820059353519967e-07, -2.534475183516132e-07,
+        -2.586631223842403e-07, -2.638473069682806e-07, -2.6899997514800477e-07, -2.74121031675442e-07,
+        -2.792103830088809e-07, -2.8426793731129903e-07, -2.892936044488331e-07, -2.942872959890387e-07,
+        -2.9924892519908964e-07, -3.0417840704402714e-07, -3.0907565818481966e-07, -3.1394059697647907e-07,
+        -3.1877314346597575e-07, -3.235732193900873e-07, -3.28340748173308e-07, -3.3307565492559226e-07,
+        -3.37777866440015e-07, -3.424473111904856e-07, -3.470839193292531e-07, -3.5168762268435153e-07,
+        -3.562583547571017e-07, -3.607960507194287e-07, -3.6530064741124867e-07, -3.697720833375272e-07,
+        -3.7421029866564453e-07, -3.786152352223179e-07, -3.8298683649073656e-07, -3.873250476074806e-07,
+        -3.916298153595314e-07, -3.9590108818093123e-07, -4.0013881614976514e-07, -4.0434295098470215e-07,
+        -4.085134460417229e-07, -4.1265025631077984e-07, -4.167533384121799e-07, -4.208226505931295e-07,
+        -4.248581527241985e-07, -4.2885980629552903e-07, -4.328275744131875e-07, -4.3676142179545496e-07,
+        -4.406613147688479e-07, -4.4452722126429853e-07, -4.4835911081325245e-07, -4.5215695454350055e-07,
+        -4.5592072517528384e-07, -4.596503970169999e-07, -4.633459459611019e-07, -4.670073494798025e-07,
+        -4.7063458662086375e-07, -4.742276380030642e-07, -4.77786485811964e-07, -4.81311113795272e-07,
+        -4.848015072584005e-07, -4.882576530598332e-07, -4.916795396065432e-07, -4.950671568492623e-07,
+        -4.984204962776691e-07, -5.017395509156569e-07, -5.050243153164489e-07, -5.082747855576638e-07,
+        -5.114909592364077e-07, -5.146728354642226e-07, -5.17820414861959e-07, -5.20933699554724e-07,
+        -5.24012693166663e-07, -5.270574008157925e-07, -5.300678291086839e-07, -5.330439861350935e-07,
+        -5.359858814626553e-07, -5.388935261314193e-07, -5.417669326483656e-07, -5.446061149819549e-07,
+        -5.4741108855642e-07, -5.501818702462989e-07, -5.529184783706282e-07, -5.556209326873166e-07,
+        -5.582892543873255e-07, -5.60923466088947e-07, -5.63523591831814e-07, -5.660896570711497e-07,
+        -5.686216886716872e-07, -5.71119714901748e-07, -5.735837654272402e-07, -5.760138713054845e-07,
+        -5.784100649791429e-07, -5.807723802701021e-07, -5.831008523731615e-07, -5.853955178498309e-07,
+        -5.876564146220991e-07, -5.898835819659789e-07, -5.920770605051929e-07, -5.942368922048048e-07,
+        -5.96363120364643e-07, -5.984557896129336e-07, -6.005149458996604e-07, -6.025406364900334e-07,
+        -6.045329099578568e-07, -6.064918161789325e-07, -6.084174063242608e-07, -6.103097328534343e-07,
+        -6.121688495077602e-07, -6.139948113035335e-07, -6.157876745251457e-07, -6.175474967182699e-07,
+        -6.192743366829144e-07, -6.209682544664494e-07, -6.226293113566702e-07, -6.242575698747648e-07,
+        -6.258530937682646e-07, -6.274159480039851e-07, -6.289461987608962e-07, -6.304439134229436e-07,
+        -6.319091605719113e-07, -6.333420099801796e-07, -6.347425326035377e-07, -6.361108005738603e-07,
+        -6.374468871917946e-07, -6.387508669194587e-07, -6.400228153730693e-07, -6.412628093155327e-07,
+        -6.424709266490606e-07, -6.436472464077067e-07, -6.447918487498439e-07, -6.45904814950711e-07,
+        -6.469862273948526e-07, -6.480361695686055e-07, -6.490547260524331e-07, -6.500419825134039e-07,
+        -6.509980256974859e-07, -6.519229434219116e-07, -6.528168245674841e-07, -6.536797590709065e-07,
+        -6.545118379169698e-07, -6.553131531308811e-07, -6.560837977704205e-07, -6.5682386591815e-07,
+        -6.575334526736344e-07, -6.582126541455284e-07, -6.588615674437405e-07, -6.594802906715733e-07,
+        -6.60068922917753e-07, -6.606275642485439e-07, -6.61156315699807e-07, -6.616552792689896e-07,
+        -6.621245579071653e-07, -6.625642555110558e-07, -6.629744769149446e-07, -6.633553278827069e-07,
+        -6.637069150997037e-07, -6.640293461647229e-07, -6.643227295818859e-07, -6.645871747525741e-07,
+        -6.648227919672475e-07, -6.650296923973738e-07, -6.652079880872329e-07, -6.653577919457653e-07,
+        -6.654792177383914e-07, -6.655723800788414e-07, -6.656373944209342e-07, -6.65674377050345e-07,
+        -6.656834450763978e-07, -6.656647164238304e-07, -6.656183098245145e-07, -6.655443448092294e-07,
+        -6.654429416993693e-07, -6.653142215986494e-07, -6.651583063848332e-07, -6.649753187014128e-07,
+        -6.647653819493348e-07, -6.645286202786438e-07, -6.642651585801598e-07, -6.63975122477159e-07,
+        -6.636586383170249e-07, -6.633158331628919e-07, -6.62946834785322e-07, -6.625517716539107e-07,
+        -6.621307729289247e-07, -6.616839684529443e-07, -6.612114887424755e-07, -6.60713464979591e-07,
+        -6.601900290035069e-07, -6.596413133022497e-07, -6.590674510042068e-07, -6.584685758697704e-07,
+        -6.578448222829256e-07, -6.571963252428704e-07, -6.565232203555872e-07, -6.558256438254704e-07,
+        -6.551037324469108e-07, -6.543576235958848e-07, -6.535874552215767e-07, -6.527933658379521e-07,
+        -6.51975494515367e-07, -6.511339808721749e-07, -6.50268965066323e-07, -6.493805877869431e-07,
+        -6.484689902459815e-07, -6.475343141697855e-07, -6.465767017907243e-07, -6.455962958388107e-07,
+        -6.44593239533301e-07, -6.435676765743411e-07, -6.425197511345767e-07, -6.414496078507878e-07,
+        -6.403573918155294e-07, -6.392432485687807e-07, -6.381073240895786e-07, -6.369497647876883e-07,
+        -6.357707174952635e-07, -6.345703294585043e-07, -6.333487483293439e-07, -6.321061221571346e-07,
+        -6.308425993803343e-07, -6.2955832881821e-07, -6.282534596625594e-07, -6.269281414694097e-07,
+        -6.255825241507613e-07, -6.242167579663261e-07, -6.228309935152735e-07, -6.214253817279935e-07,
+        -6.200000738578616e-07, -6.185552214730401e-07, -6.17090976448248e-07, -6.156074909565836e-07,
+        -6.141049174613393e-07, -6.125834087078334e-07, -6.110431177152567e-07, -6.094841977685254e-07,
+        -6.079068024101688e-07, -6.063110854321932e-07, -6.046972008679991e-07, -6.030653029842919e-07,
+        -6.014155462730126e-07, -5.997480854432827e-07, -5.980630754133756e-07, -5.963606713026772e-07,
+        -5.946410284236963e-07, -5.929043022740693e-07, -5.911506485285905e-07, -5.893802230312537e-07,
+        -5.875931817873216e-07, -5.857896809554094e-07, -5.839698768395749e-07, -5.821339258814452e-07,
+        -5.802819846523549e-07, -5.784142098454931e-07, -5.765307582680966e-07, -5.746317868336248e-07,
+        -5.727174525540066e-07, -5.707879125318484e-07, -5.688433239527014e-07, -5.668838440773494e-07,
+        -5.649096302341043e-07, -5.629208398111154e-07, -5.609176302487439e-07, -5.58900159031902e-07,
+        -5.568685836824453e-07, -5.548230617516007e-07, -5.527637508123921e-07, -5.506908084520898e-07,
+        -5.486043922647122e-07, -5.465046598435189e-07, -5.443917687735453e-07, -5.422658766241581e-07,
+        -5.401271409416394e-07, -5.379757192417756e-07, -5.35811769002517e-07, -5.336354476565977e-07,
+        -5.314469125842606e-07, -5.292463211059293e-07, -5.270338304749645e-07, -5.248095978704105e-07,
+        -5.225737803897914e-07, -5.203265350419327e-07, -5.180680187398061e-07, -5.157983882933833e-07,
+        -5.135178004025555e-07, -5.112264116500417e-07, -5.089243784943541e-07, -5.066118572627941e-07,
+        -5.042890041444466e-07, -5.019559751832465e-07, -4.99612926271018e-07, -4.972600131405971e-07,
+        -4.948973913589726e-07, -4.925252163204335e-07, -4.90143643239765e-07, -4.877528271454765e-07,
+        -4.853529228730507e-07, -4.829440850582202e-07, -4.805264681303102e-07, -4.78100226305566e-07,
+        -4.7566551358054325e-07, -4.7322248372549553e-07, -4.707712902778707e-07, -4.6831208653572233e-07,
+        -4.658450255512726e-07, -4.6337026012442786e-07, -4.608879427963659e-07, -4.5839822584311944e-07,
+        -4.5590126126925877e-07, -4.533972008015285e-07, -4.5088619588258466e-07, -4.483683976647297e-07,
+        -4.458439570036682e-07, -4.433130244523582e-07, -4.407757502548381e-07, -4.3823228434008627e-07,
+        -4.3568277631598745e-07, -4.331273754632488e-07, -4.3056623072937764e-07, -4.279994907227415e-07,
+        -4.254273037066072e-07, -4.2284981759321055e-07, -4.202671799379472e-07, -4.1767953793346597e-07,
+        -4.1508703840393317e-07, -4.124898277992401e-07, -4.098880521892947e-07, -4.072818572583053e-07,
+        -4.04671388299179e-07, -4.0205679020786203e-07, -3.994382074777996e-07, -3.968157841943798e-07,
+        -3.941896640294461e-07, -3.9155999023581224e-07, -3.88926905641854e-07, -3.862905526461347e-07,
+        -3.836510732120467e-07, -3.810086088624934e-07, -3.783633006746364e-07, -3.7571528927463835e-07,
+        -3.730647148324843e-07, -3.704117170568417e-07, -3.6775643518993125e-07, -3.6509900800244386e-07,
+        -3.624395737885219e-07, -3.597782703607358e-07, -3.5711523504514186e-07, -3.5445060467638166e-07,
+        -3.517845155927761e-07, -3.4911710363151406e-07, -3.4644850412381473e-07, -3.4377885189019556e-07,
+        -3.411082812357694e-07, -3.3843692594554163e-07, -3.357649192797818e-07, -3.3309239396943336e-07,
+        -3.304194822115423e-07, -3.277463156647442e-07, -3.2507302544481787e-07, -3.223997421202231e-07,
+        -3.1972659570772663e-07, -3.170537156680142e-07, -3.1438123090145005e-07, -3.117092697437258e-07,
+        -3.090379599617034e-07, -3.0636742874919196e-07, -3.0369780272282194e-07, -3.0102920791790687e-07,
+        -2.9836176978444223e-07, -2.956956131830242e-07, -2.9303086238092197e-07, -2.90367641048124e-07,
+        -2.87706072253416e-07, -2.8504627846058287e-07, -2.8238838152457246e-07, -2.797325026877001e-07,
+        -2.7707876257598484e-07, -2.74427281195428e-07, -2.7177817792835353e-07, -2.6913157152986226e-07,
+        -2.664875801242489e-07, -2.638463212014596e-07, -2.6120791161369224e-07, -2.585724675718839e-07,
+        -2.5594010464239673e-07, -2.5331093774363605e-07, -2.506850811427684e-07, -2.480626484524263e-07,
+        -2.4544375262755666e-07, -2.428285059621802e-07, -2.402170200863171e-07, -2.3760940596286918e-07,
+        -2.3500577388459275e-07, -2.3240623347106916e-07, -2.2981089366576499e-07, -2.2721986273313298e-07,
+        -2.2463324825571781e-07, -2.2205115713132594e-07, -2.1947369557025345e-07, -2.1690096909251268e-07,
+        -2.1433308252514939e-07, -2.1177013999960115e-07, -2.0921224494906002e-07, -2.0665950010590894e-07,
+        -2.0411200749916067e-07, -2.0156986845203498e-07, -1.9903318357943907e-07, -1.9650205278563235e-07,
+        -1.9397657526182864e-07, -1.9145684948390534e-07, -1.889429732100841e-07, -1.8643504347877287e-07,
+        -1.8393315660630133e-07, -1.814374081848429e-07, -1.789478930802844e-07, -1.7646470543013755e-07,
+        -1.739879386415845e-07, -1.7151768538946798e-07, -1.6905403761433714e-07, -1.665970865206144e-07,
+        -1.641469225747214e-07, -1.6170363550324578e-07, -1.5926731429124216e-07, -1.568380471804778e-07,
+        -1.5441592166773304e-07, -1.520010245032329e-07, -1.495934416889795e-07, -1.4719325847726966e-07,
+        -1.4480055936914867e-07, -1.4241542811296903e-07, -1.400379477029233e-07, -1.3766820037773394e-07,
+        -1.3530626761923834e-07, -1.3295223015115763e-07, -1.3060616793780488e-07, -1.2826816018288918e-07,
+        -1.2593828532832045e-07, -1.236166210530928e-07, -1.2130324427221907e-07, -1.1899823113565515e-07,
+        -1.1670165702730457e-07, -1.1441359656406314e-07, -1.1213412359487228e-07, -1.098633111998511e-07,
+        -1.0760123168947905e-07, -1.0534795660376794e-07, -1.0310355671152246e-07, -1.0086810200959594e-07,
+        -9.864166172223154e-08, -9.642430430044874e-08, -9.421609742141591e-08, -9.201710798790867e-08,
+        -8.982740212780445e-08, -8.764704519358142e-08, -8.54761017619039e-08, -8.331463563324713e-08,
+        -8.11627098315193e-08, -7.902038660375402e-08, -7.688772741984876e-08, -7.476479297230374e-08,
+        -7.265164317604658e-08, -7.054833716829561e-08, -6.845493330842019e-08, -6.637148917788015e-08,
+        -6.429806158015577e-08, -6.223470654081329e-08, -6.018147930747473e-08, -5.8138434349961794e-08,
+        -5.610562536037877e-08, -5.4083105253290524e-08, -5.207092616588071e-08, -5.0069139458250996e-08,
+        -4.8077795713622717e-08, -4.609694473871046e-08, -4.412663556404464e-08, -4.216691644433219e-08,
+        -4.021783485893575e-08, -3.8279437512307574e-08, -3.635177033446495e-08, -3.4434878481579634e-08,
+        -3.252880633652612e-08, -3.0633597509467066e-08, -2.8749294838554997e-08, -2.6875940390589867e-08,
+        -2.5013575461716387e-08, -2.3162240578242407e-08, -2.132197549736501e-08, -1.9492819208061514e-08,
+        -1.767480993192208e-08, -1.5867985124070245e-08, -1.4072381474066895e-08, -1.2288034906944329e-08,
+        -1.0514980584148328e-08, -8.753252904642082e-09, -7.002885505952707e-09, -5.2639112653029835e-09,
+        -3.536362300736568e-09, 0.0,
+    ],
+};
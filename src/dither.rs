@@ -0,0 +1,256 @@
+use crate::sample::Sample;
+
+
+/// The kind of dither applied when converting samples to an integer
+/// format.
+///
+/// Without dither, samples are merely rounded to the nearest integer
+/// value, which for low level signals causes distortion correlated with
+/// the signal. Dither adds a small amount of noise before quantization,
+/// decorrelating the error from the signal at the cost of a slightly
+/// raised noise floor.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Dither {
+    /// No dither; samples are rounded and clipped.
+    #[default]
+    None,
+    /// Noise with a rectangular probability density function and a peak
+    /// amplitude of half a quantization step.
+    Rectangular,
+    /// Noise with a triangular probability density function (TPDF) and
+    /// a peak amplitude of one quantization step.
+    Triangular,
+    /// Triangular dither combined with first order error feedback,
+    /// shifting the quantization noise towards higher frequencies.
+    NoiseShaped,
+}
+
+
+/// A small, deterministic pseudo random number generator based on
+/// `SplitMix64`.
+#[derive(Clone, Debug)]
+struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Generate the next 64 bit value.
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Generate a value uniformly distributed in `[-0.5, 0.5)`.
+    fn next_f32(&mut self) -> f32 {
+        // The upper 24 bits fit into the mantissa of an `f32` exactly.
+        (self.next_u64() >> 40) as f32 / (1u32 << 24) as f32 - 0.5
+    }
+}
+
+
+/// The state required for dithering samples.
+#[derive(Clone, Debug)]
+pub(crate) struct Ditherer {
+    dither: Dither,
+    seed: u64,
+    rng: Rng,
+    /// The quantization error of the last sample of each channel, in
+    /// quantization steps.
+    errors: Box<[f64]>,
+}
+
+impl Ditherer {
+    pub(crate) fn new(dither: Dither, seed: u64, channels: u8) -> Self {
+        Self {
+            dither,
+            seed,
+            rng: Rng::new(seed),
+            errors: vec![0.0; usize::from(channels)].into_boxed_slice(),
+        }
+    }
+
+    /// Reset the state, so that the same noise sequence gets generated
+    /// again.
+    pub(crate) fn reset(&mut self) {
+        self.rng = Rng::new(self.seed);
+        let () = self.errors.fill(0.0);
+    }
+
+    /// Dither and quantize the interleaved `samples` in place to
+    /// multiples of `step`. Without dither, samples are left untouched.
+    ///
+    /// The result is clipped to the range `[-1.0, 1.0 - step]`, so
+    /// that subsequent conversion to an integer format is exact.
+    ///
+    /// An `f32` can only represent multiples of steps of at least
+    /// [`f32::EPSILON`] exactly. Finer steps, as used by 32 bit integer
+    /// formats, are below the precision of the conversion itself, and
+    /// samples are left untouched as well.
+    pub(crate) fn apply(&mut self, samples: &mut [f32], step: f32) {
+        if self.dither == Dither::None || step < f32::EPSILON {
+            return
+        }
+
+        let channels = self.errors.len();
+        let step = f64::from(step);
+        let max = step.recip();
+        for frame in samples.chunks_exact_mut(channels) {
+            for (sample, error) in frame.iter_mut().zip(self.errors.iter_mut()) {
+                let value = f64::from(*sample) * max;
+                let (value, noise) = match self.dither {
+                    Dither::None => (value, 0.0),
+                    Dither::Rectangular => (value, self.rng.next_f32()),
+                    Dither::Triangular => (value, self.rng.next_f32() + self.rng.next_f32()),
+                    Dither::NoiseShaped => {
+                        (value - *error, self.rng.next_f32() + self.rng.next_f32())
+                    },
+                };
+
+                let quantized = (value + f64::from(noise)).round().clamp(-max, max - 1.0);
+                *error = quantized - value;
+                // `quantized` has at most 24 significant bits, so the
+                // result is exact.
+                *sample = (quantized * step) as f32;
+            }
+        }
+    }
+
+    /// Dither `samples` in preparation of their conversion to `S`, if
+    /// it is an integer format.
+    pub(crate) fn apply_for<S>(&mut self, samples: &mut [f32])
+    where
+        S: Sample,
+    {
+        if let Some(step) = S::quantization_step() {
+            let () = self.apply(samples, step);
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+
+    /// Make sure that the random number generator is deterministic and
+    /// produces values in the expected range.
+    #[test]
+    fn random_numbers() {
+        let mut rng1 = Rng::new(42);
+        let mut rng2 = Rng::new(42);
+        let mut rng3 = Rng::new(43);
+
+        let values = (0..10_000).map(|_| rng1.next_f32()).collect::<Vec<_>>();
+        assert!(values.iter().all(|x| (-0.5..0.5).contains(x)));
+        assert!((0..10_000).all(|i| rng2.next_f32() == values[i]));
+        assert!((0..10_000).any(|i| rng3.next_f32() != values[i]));
+
+        let mean = values.iter().sum::<f32>() / values.len() as f32;
+        assert!(mean.abs() < 0.01, "{mean}");
+    }
+
+    /// Check that dither preserves signals below a quantization step on
+    /// average.
+    #[test]
+    fn low_level_signal() {
+        const STEP: f32 = 1.0 / 32768.0;
+
+        let input = vec![0.3 * STEP; 20_000];
+
+        let mut ditherer = Ditherer::new(Dither::None, 0, 1);
+        let mut output = input.clone();
+        let () = ditherer.apply(&mut output, STEP);
+        assert_eq!(output, input);
+
+        for dither in [Dither::Rectangular, Dither::Triangular, Dither::NoiseShaped] {
+            let mut ditherer = Ditherer::new(dither, 1, 2);
+            let mut output = input.clone();
+            let () = ditherer.apply(&mut output, STEP);
+            assert!(output
+                .iter()
+                .all(|x| (*x / STEP).fract() == 0.0 && x.abs() <= 4.0 * STEP));
+
+            let mean = output.iter().sum::<f32>() / output.len() as f32 / STEP;
+            assert!((mean - 0.3).abs() < 0.02, "{dither:?}: {mean}");
+
+            // Resetting reproduces the same output.
+            let () = ditherer.reset();
+            let mut again = input.clone();
+            let () = ditherer.apply(&mut again, STEP);
+            assert_eq!(again, output);
+        }
+    }
+
+    /// Check that noise shaping keeps the accumulated quantization
+    /// error bounded, i.e., pushes it towards high frequencies.
+    #[test]
+    fn noise_shaping() {
+        const STEP: f32 = 1.0 / 32768.0;
+
+        let input = (0..50_000)
+            .map(|i| (i as f32 / 50.0).sin() * 10.0 * STEP)
+            .collect::<Vec<f32>>();
+
+        let accumulated = |dither| {
+            let mut ditherer = Ditherer::new(dither, 7, 1);
+            let mut output = input.clone();
+            let () = ditherer.apply(&mut output, STEP);
+            output
+                .iter()
+                .zip(&input)
+                .map(|(output, input)| (output - input) / STEP)
+                .fold((0.0f32, 0.0f32), |(sum, max), error| {
+                    let sum = sum + error;
+                    (sum, max.max(sum.abs()))
+                })
+                .1
+        };
+
+        assert!(accumulated(Dither::NoiseShaped) < 4.0);
+        assert!(accumulated(Dither::Triangular) > 10.0);
+    }
+
+    /// Check that quantized samples are clipped to the valid range.
+    #[test]
+    fn clipping() {
+        const STEP: f32 = 1.0 / 32768.0;
+
+        let mut ditherer = Ditherer::new(Dither::Triangular, 0, 1);
+        let mut samples = [2.0f32, -2.0, 1.0, -1.0];
+        let () = ditherer.apply(&mut samples, STEP);
+        assert!(samples.iter().all(|x| (-1.0..=1.0 - STEP).contains(x)));
+
+        let mut samples = [1.0f32; 64];
+        let mut output = [0i16; 64];
+        let () = ditherer.apply_for::<i16>(&mut samples);
+        let () = i16::from_float(&samples, &mut output);
+        assert!(output.iter().all(|x| *x == i16::MAX));
+    }
+
+    /// Make sure that samples destined for formats finer than what an
+    /// `f32` can represent are left untouched.
+    #[test]
+    fn wide_formats() {
+        let input = (0..1000)
+            .map(|i| (i as f32 / 10.0).sin() * 0.999)
+            .collect::<Vec<f32>>();
+
+        for dither in [Dither::Rectangular, Dither::Triangular, Dither::NoiseShaped] {
+            let mut ditherer = Ditherer::new(dither, 5, 1);
+            let mut samples = input.clone();
+            let () = ditherer.apply_for::<i32>(&mut samples);
+            assert_eq!(samples, input, "{dither:?}");
+
+            let () = ditherer.apply_for::<f32>(&mut samples);
+            assert_eq!(samples, input, "{dither:?}");
+        }
+    }
+}
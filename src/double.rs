@@ -0,0 +1,282 @@
+use crate::coeffs;
+use crate::error::Error;
+use crate::error::ErrorKind;
+use crate::resample_type::ResampleType;
+use crate::resampler::Processed;
+use crate::sinc::SincConverter;
+
+
+/// The smallest ratio difference `libsamplerate` considers a change.
+const MIN_RATIO_DIFF: f64 = 1e-20;
+/// The smallest ratio `libsamplerate` supports.
+const MIN_RATIO: f64 = 1.0 / 256.0;
+
+
+/// Calculate the positive fractional part of `x`, mirroring
+/// `libsamplerate`'s `fmod_one`.
+#[inline]
+pub(crate) fn fmod_one(x: f64) -> f64 {
+    let rem = x - x.round_ties_even();
+    if rem < 0.0 {
+        rem + 1.0
+    } else {
+        rem
+    }
+}
+
+
+/// The interpolation scheme of an [`Interpolator`].
+#[derive(Clone, Copy, Debug)]
+enum Interpolation {
+    ZeroOrderHold,
+    Linear,
+}
+
+
+/// A double precision port of `libsamplerate`'s zero order hold and
+/// linear converters.
+#[derive(Clone, Debug)]
+pub(crate) struct Interpolator {
+    interpolation: Interpolation,
+    channels: usize,
+    /// The ratio used at the end of the last block, or `0.0` if none
+    /// has been set yet.
+    last_ratio: f64,
+    /// The fractional position into the input at which to continue.
+    last_position: f64,
+    /// The last input frame consumed.
+    last_value: Box<[f64]>,
+    /// Whether `last_value` has been initialized.
+    dirty: bool,
+}
+
+impl Interpolator {
+    fn new(interpolation: Interpolation, channels: u8) -> Self {
+        let channels = usize::from(channels);
+
+        Self {
+            interpolation,
+            channels,
+            last_ratio: 0.0,
+            last_position: 0.0,
+            last_value: vec![0.0; channels].into_boxed_slice(),
+            dirty: false,
+        }
+    }
+
+    /// Reset the converter's state.
+    fn reset(&mut self) {
+        self.last_ratio = 0.0;
+        self.last_position = 0.0;
+        let () = self.last_value.fill(0.0);
+        self.dirty = false;
+    }
+
+    /// Calculate a single output sample for channel `ch` from the
+    /// frames `prev` and `next`.
+    #[inline]
+    fn interpolate(&self, prev: &[f64], next: &[f64], ch: usize, position: f64) -> f64 {
+        match self.interpolation {
+            Interpolation::ZeroOrderHold => prev[ch],
+            Interpolation::Linear => prev[ch] + position * (next[ch] - prev[ch]),
+        }
+    }
+
+    /// Convert frame aligned `input` into frame aligned `output`,
+    /// transitioning to `ratio` over the course of the block.
+    fn process(&mut self, input: &[f64], output: &mut [f64], ratio: f64) -> Processed {
+        let channels = self.channels;
+        if self.last_ratio < MIN_RATIO {
+            self.last_ratio = ratio;
+        }
+
+        if input.is_empty() {
+            return Processed::default()
+        }
+
+        if !self.dirty {
+            let () = self.last_value.copy_from_slice(&input[..channels]);
+            self.dirty = true;
+        }
+
+        let in_count = input.len();
+        let out_count = output.len();
+        let start_ratio = self.last_ratio;
+        let varying = (start_ratio - ratio).abs() > MIN_RATIO_DIFF;
+        let ratio_at = |out_gen: usize| {
+            start_ratio + out_gen as f64 * (ratio - start_ratio) / out_count as f64
+        };
+
+        let mut src_ratio = start_ratio;
+        let mut in_used = 0;
+        let mut out_gen = 0;
+        let mut position = self.last_position;
+
+        // Generate output between the last frame of the previous block
+        // and the first one of this block.
+        while position < 1.0 && out_gen < out_count {
+            if (channels as f64) * position >= in_count as f64 {
+                break
+            }
+            if varying {
+                src_ratio = ratio_at(out_gen);
+            }
+
+            for ch in 0..channels {
+                output[out_gen] = self.interpolate(&self.last_value, input, ch, position);
+                out_gen += 1;
+            }
+            position += src_ratio.recip();
+        }
+
+        let rem = fmod_one(position);
+        in_used += channels * (position - rem).round() as usize;
+        position = rem;
+
+        // Generate output between the frames of this block. The zero
+        // order hold converter only ever accesses the frame before the
+        // current position.
+        let limit = |in_used: usize, position: f64| {
+            let pos = in_used as f64 + channels as f64 * position;
+            match self.interpolation {
+                Interpolation::ZeroOrderHold => pos <= in_count as f64,
+                Interpolation::Linear => pos < in_count as f64,
+            }
+        };
+
+        while out_gen < out_count && limit(in_used, position) {
+            if varying {
+                src_ratio = ratio_at(out_gen);
+            }
+
+            let prev = &input[in_used - channels..in_used];
+            let next = input.get(in_used..).unwrap_or(&[]);
+            for ch in 0..channels {
+                output[out_gen] = self.interpolate(prev, next, ch, position);
+                out_gen += 1;
+            }
+
+            position += src_ratio.recip();
+            let rem = fmod_one(position);
+            in_used += channels * (position - rem).round() as usize;
+            position = rem;
+        }
+
+        if in_used > in_count {
+            position += ((in_used - in_count) / channels) as f64;
+            in_used = in_count;
+        }
+        self.last_position = position;
+
+        if in_used > 0 {
+            let () = self
+                .last_value
+                .copy_from_slice(&input[in_used - channels..in_used]);
+        }
+        self.last_ratio = src_ratio;
+
+        Processed {
+            read: in_used,
+            written: out_gen,
+        }
+    }
+}
+
+
+/// A double precision samplerate converter.
+///
+/// Input and output samples as well as all intermediate values are
+/// kept as `f64`. Behavior otherwise matches that of the respective
+/// `libsamplerate` converter, including the transition between ratios
+/// over the course of a block.
+#[derive(Clone, Debug)]
+pub(crate) enum DoubleConverter {
+    Interpolator(Interpolator),
+    Sinc(SincConverter),
+}
+
+impl DoubleConverter {
+    /// Create a new converter of the given type.
+    ///
+    /// Converters not [supporting][ResampleType::supports_f64] double
+    /// precision are reported as an error of kind
+    /// [`ErrorKind::BadConverter`].
+    pub(crate) fn new(converter_type: ResampleType, channels: u8) -> Result<Self, Error> {
+        let slf = match converter_type {
+            ResampleType::ZeroOrderHold => {
+                Self::Interpolator(Interpolator::new(Interpolation::ZeroOrderHold, channels))
+            },
+            ResampleType::Linear => {
+                Self::Interpolator(Interpolator::new(Interpolation::Linear, channels))
+            },
+            ResampleType::SincMediumQuality => {
+                Self::Sinc(SincConverter::new(&coeffs::MEDIUM_QUALITY, channels))
+            },
+            ResampleType::SincFastest => Self::Sinc(SincConverter::new(&coeffs::FASTEST, channels)),
+            ResampleType::SincBestQuality => return Err(Error::from(ErrorKind::BadConverter)),
+        };
+        Ok(slf)
+    }
+
+    /// Set the ratio to use from now on, without a gradual transition.
+    #[inline]
+    pub(crate) fn set_ratio(&mut self, ratio: f64) {
+        match self {
+            Self::Interpolator(interpolator) => interpolator.last_ratio = ratio,
+            Self::Sinc(sinc) => sinc.set_ratio(ratio),
+        }
+    }
+
+    /// Reset the converter's state.
+    pub(crate) fn reset(&mut self) {
+        match self {
+            Self::Interpolator(interpolator) => interpolator.reset(),
+            Self::Sinc(sinc) => sinc.reset(),
+        }
+    }
+
+    /// Convert frame aligned `input` into frame aligned `output`,
+    /// transitioning to `ratio` over the course of the block.
+    ///
+    /// `end_of_input` indicates that `input` is the last block of
+    /// input, allowing the converter to flush buffered data.
+    pub(crate) fn process(
+        &mut self,
+        input: &[f64],
+        output: &mut [f64],
+        ratio: f64,
+        end_of_input: bool,
+    ) -> Result<Processed, Error> {
+        match self {
+            Self::Interpolator(interpolator) => Ok(interpolator.process(input, output, ratio)),
+            Self::Sinc(sinc) => sinc.process(input, output, ratio, end_of_input),
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+
+    /// Check that `fmod_one` behaves like `libsamplerate`'s version.
+    #[test]
+    fn fractional_part() {
+        assert_eq!(fmod_one(0.0), 0.0);
+        assert_eq!(fmod_one(0.25), 0.25);
+        assert_eq!(fmod_one(0.5), 0.5);
+        assert_eq!(fmod_one(1.5), 0.5);
+        assert_eq!(fmod_one(2.75), 0.75);
+    }
+
+    /// Make sure that the best quality sinc converter is rejected.
+    #[test]
+    fn sinc_unsupported() {
+        let err = DoubleConverter::new(ResampleType::SincBestQuality, 1).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::BadConverter);
+
+        let _converter = DoubleConverter::new(ResampleType::SincMediumQuality, 1).unwrap();
+        let _converter = DoubleConverter::new(ResampleType::SincFastest, 1).unwrap();
+    }
+}
@@ -3,6 +3,7 @@ use std::ffi::CStr;
 use std::fmt::Display;
 use std::fmt::Formatter;
 use std::fmt::Result as FmtResult;
+use std::io::Error as IoError;
 
 use libsamplerate_rs::src_strerror;
 
@@ -35,6 +36,23 @@ pub enum ErrorKind {
     SincPrepareDataBadLen = 21,
     BadInternalState = 22,
     MaxError = 23,
+    // The following errors are specific to this crate and not reported
+    // by `libsamplerate` itself.
+    /// The input buffer does not contain a whole number of frames.
+    InputNotFrameAligned = 256,
+    /// The output buffer does not provide space for a whole number of
+    /// frames.
+    OutputNotFrameAligned = 257,
+    /// The number of planar buffers does not match the channel count.
+    ChannelCountMismatch = 258,
+    /// A ratio schedule is empty or its breakpoints are not strictly
+    /// ordered by frame.
+    InvalidSchedule = 259,
+    /// The output buffer is too small to hold the result of the
+    /// conversion.
+    OutputBufferTooSmall = 260,
+    /// A controller gain or limit is out of range.
+    InvalidControllerParameter = 261,
 }
 
 impl ErrorKind {
@@ -74,6 +92,12 @@ impl ErrorKind {
     pub fn description(&self) -> &'static str {
         match self {
             Self::Unknown => "Unkown error.",
+            Self::InputNotFrameAligned => "Input length is not a multiple of the channel count.",
+            Self::OutputNotFrameAligned => "Output length is not a multiple of the channel count.",
+            Self::ChannelCountMismatch => "Number of buffers does not match the channel count.",
+            Self::InvalidSchedule => "Ratio schedule is empty or not ordered by frame.",
+            Self::OutputBufferTooSmall => "Output buffer is too small for the converted data.",
+            Self::InvalidControllerParameter => "Controller gain or limit is out of range.",
             _ => {
                 // SAFETY: `src_strerror` is always safe to call.
                 let ptr = unsafe { src_strerror(*self as i32) };
@@ -129,6 +153,13 @@ impl Display for Error {
 
 impl StdError for Error {}
 
+impl From<Error> for IoError {
+    #[inline]
+    fn from(error: Error) -> Self {
+        Self::other(error)
+    }
+}
+
 
 #[cfg(test)]
 mod tests {
@@ -167,6 +198,7 @@ mod tests {
         assert_eq!(ErrorKind::from_int(22), Some(ErrorKind::BadInternalState));
         assert_eq!(ErrorKind::from_int(23), Some(ErrorKind::MaxError));
         assert_eq!(ErrorKind::from_int(24), Some(ErrorKind::Unknown));
+        assert_eq!(ErrorKind::from_int(256), Some(ErrorKind::Unknown));
     }
 
     #[test]
@@ -261,5 +293,29 @@ mod tests {
             "Placeholder. No error defined for this error number."
         );
         assert_eq!(ErrorKind::Unknown.description(), "Unkown error.");
+        assert_eq!(
+            ErrorKind::InputNotFrameAligned.description(),
+            "Input length is not a multiple of the channel count."
+        );
+        assert_eq!(
+            ErrorKind::OutputNotFrameAligned.description(),
+            "Output length is not a multiple of the channel count."
+        );
+        assert_eq!(
+            ErrorKind::ChannelCountMismatch.description(),
+            "Number of buffers does not match the channel count."
+        );
+        assert_eq!(
+            ErrorKind::InvalidSchedule.description(),
+            "Ratio schedule is empty or not ordered by frame."
+        );
+        assert_eq!(
+            ErrorKind::OutputBufferTooSmall.description(),
+            "Output buffer is too small for the converted data."
+        );
+        assert_eq!(
+            ErrorKind::InvalidControllerParameter.description(),
+            "Controller gain or limit is out of range."
+        );
     }
 }
@@ -0,0 +1,638 @@
+use std::io::Error as IoError;
+use std::io::ErrorKind as IoErrorKind;
+use std::io::Read;
+use std::io::Result as IoResult;
+use std::io::Write;
+
+use crate::error::Error;
+use crate::resampler::Resampler;
+use crate::sample::Sample;
+
+
+/// The number of input frames converted in one go.
+const BLOCK_FRAMES: usize = 1024;
+/// The number of samples decoded or encoded in one go.
+const CHUNK_SAMPLES: usize = 256;
+
+
+/// The encoding of samples in a raw PCM byte stream.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SampleEncoding {
+    /// 32 bit floating point samples in little endian byte order.
+    F32Le,
+    /// 32 bit floating point samples in big endian byte order.
+    F32Be,
+    /// Signed 16 bit integer samples in little endian byte order.
+    S16Le,
+    /// Signed 16 bit integer samples in big endian byte order.
+    S16Be,
+    /// Signed 32 bit integer samples in little endian byte order.
+    S32Le,
+    /// Signed 32 bit integer samples in big endian byte order.
+    S32Be,
+}
+
+impl SampleEncoding {
+    /// Retrieve the size of a single sample, in bytes.
+    pub fn sample_size(&self) -> usize {
+        match self {
+            Self::F32Le | Self::F32Be | Self::S32Le | Self::S32Be => 4,
+            Self::S16Le | Self::S16Be => 2,
+        }
+    }
+
+    /// Retrieve the quantization step of the encoding, if it is an
+    /// integer one.
+    fn quantization_step(&self) -> Option<f32> {
+        match self {
+            Self::F32Le | Self::F32Be => f32::quantization_step(),
+            Self::S16Le | Self::S16Be => i16::quantization_step(),
+            Self::S32Le | Self::S32Be => i32::quantization_step(),
+        }
+    }
+
+    /// Decode the samples in `bytes` into `samples`.
+    fn decode(&self, bytes: &[u8], samples: &mut [f32]) {
+        match self {
+            Self::F32Le => decode_with(bytes, samples, f32::from_le_bytes),
+            Self::F32Be => decode_with(bytes, samples, f32::from_be_bytes),
+            Self::S16Le => decode_with(bytes, samples, i16::from_le_bytes),
+            Self::S16Be => decode_with(bytes, samples, i16::from_be_bytes),
+            Self::S32Le => decode_with(bytes, samples, i32::from_le_bytes),
+            Self::S32Be => decode_with(bytes, samples, i32::from_be_bytes),
+        }
+    }
+
+    /// Encode `samples`, appending them to `bytes`.
+    fn encode(&self, samples: &[f32], bytes: &mut Vec<u8>) {
+        match self {
+            Self::F32Le => encode_with(samples, bytes, f32::to_le_bytes),
+            Self::F32Be => encode_with(samples, bytes, f32::to_be_bytes),
+            Self::S16Le => encode_with(samples, bytes, i16::to_le_bytes),
+            Self::S16Be => encode_with(samples, bytes, i16::to_be_bytes),
+            Self::S32Le => encode_with(samples, bytes, i32::to_le_bytes),
+            Self::S32Be => encode_with(samples, bytes, i32::to_be_bytes),
+        }
+    }
+}
+
+fn decode_with<S, const N: usize>(bytes: &[u8], samples: &mut [f32], from_bytes: fn([u8; N]) -> S)
+where
+    S: Sample,
+{
+    let (bytes, _rest) = bytes.as_chunks::<N>();
+    debug_assert_eq!(bytes.len(), samples.len());
+
+    let mut buffer = [S::default(); CHUNK_SAMPLES];
+    for (bytes, samples) in bytes
+        .chunks(CHUNK_SAMPLES)
+        .zip(samples.chunks_mut(CHUNK_SAMPLES))
+    {
+        let buffer = &mut buffer[..bytes.len()];
+        let () = buffer
+            .iter_mut()
+            .zip(bytes)
+            .for_each(|(sample, bytes)| *sample = from_bytes(*bytes));
+        let () = S::to_float(buffer, samples);
+    }
+}
+
+fn encode_with<S, const N: usize>(samples: &[f32], bytes: &mut Vec<u8>, to_bytes: fn(S) -> [u8; N])
+where
+    S: Sample,
+{
+    let mut buffer = [S::default(); CHUNK_SAMPLES];
+    for samples in samples.chunks(CHUNK_SAMPLES) {
+        let buffer = &mut buffer[..samples.len()];
+        let () = S::from_float(samples, buffer);
+        let () = buffer
+            .iter()
+            .for_each(|sample| bytes.extend_from_slice(&to_bytes(*sample)));
+    }
+}
+
+
+/// The state shared between [`ResampleReader`] and
+/// [`ResampleWriter`].
+#[derive(Debug)]
+struct Core {
+    resampler: Resampler,
+    encoding: SampleEncoding,
+    /// Raw input bytes not yet decoded.
+    raw: Vec<u8>,
+    /// Decoded input samples not yet consumed by the converter.
+    input: Vec<f32>,
+    /// Buffer for converted samples.
+    output: Vec<f32>,
+    /// Encoded output bytes not yet handed out.
+    encoded: Vec<u8>,
+}
+
+impl Core {
+    fn new(resampler: Resampler, encoding: SampleEncoding) -> Self {
+        let in_channels = usize::from(resampler.input_channels());
+        let out_channels = usize::from(resampler.output_channels());
+        let output_frames = resampler.max_output_frames(BLOCK_FRAMES);
+
+        Self {
+            raw: Vec::with_capacity(BLOCK_FRAMES * in_channels * encoding.sample_size()),
+            input: Vec::with_capacity(BLOCK_FRAMES * in_channels),
+            output: vec![0.0; output_frames * out_channels],
+            encoded: Vec::new(),
+            resampler,
+            encoding,
+        }
+    }
+
+    /// The size of an input frame, in bytes.
+    fn frame_size(&self) -> usize {
+        usize::from(self.resampler.input_channels()) * self.encoding.sample_size()
+    }
+
+    /// The number of raw bytes making up one block of input.
+    fn block_size(&self) -> usize {
+        BLOCK_FRAMES * self.frame_size()
+    }
+
+    /// Decode all whole frames of raw input.
+    fn decode(&mut self) {
+        let frame_size = self.frame_size();
+        let len = self.raw.len() - self.raw.len() % frame_size;
+        let count = len / self.encoding.sample_size();
+
+        let start = self.input.len();
+        let () = self.input.resize(start + count, 0.0);
+        let () = self
+            .encoding
+            .decode(&self.raw[..len], &mut self.input[start..]);
+        let _drained = self.raw.drain(..len);
+    }
+
+    /// Convert all decoded input, encoding the output.
+    fn convert(&mut self, finalize: bool) -> Result<(), Error> {
+        loop {
+            let processed = if finalize {
+                self.resampler.finalize(&self.input, &mut self.output)?
+            } else {
+                self.resampler.process(&self.input, &mut self.output)?
+            };
+
+            let () = drop(self.input.drain(..processed.read));
+            let output = &mut self.output[..processed.written];
+            if let (Some(ditherer), Some(step)) = (
+                self.resampler.ditherer_mut(),
+                self.encoding.quantization_step(),
+            ) {
+                let () = ditherer.apply(output, step);
+            }
+            let () = self.encoding.encode(output, &mut self.encoded);
+
+            if finalize {
+                if processed.written == 0 {
+                    break Ok(())
+                }
+            } else if self.input.is_empty() || (processed.read == 0 && processed.written == 0) {
+                break Ok(())
+            }
+        }
+    }
+}
+
+
+/// The error reported when a stream ends in the middle of a frame.
+fn incomplete_frame() -> IoError {
+    IoError::new(
+        IoErrorKind::UnexpectedEof,
+        "stream ended in the middle of a frame",
+    )
+}
+
+
+/// A reader resampling the raw PCM data read from an inner reader.
+///
+/// Input samples are read in the provided encoding and resampled
+/// output is produced in the same encoding. Once the inner reader
+/// reports the end of the stream, the conversion is finalized. Integer
+/// output is dithered as configured on the resampler (see
+/// [`Resampler::set_dither`]).
+///
+/// # Example
+///
+/// ```
+/// use std::io::Read as _;
+/// use resample::{Resampler, ResampleReader, ResampleType, SampleEncoding};
+///
+/// // One second of 16 bit stereo silence in 44100Hz.
+/// let input = vec![0u8; 44100 * 2 * 2];
+/// let resampler = Resampler::new(ResampleType::SincFastest, 2, 44100, 48000).unwrap();
+/// let mut reader = ResampleReader::new(input.as_slice(), resampler, SampleEncoding::S16Le);
+///
+/// let mut output = Vec::new();
+/// let _count = reader.read_to_end(&mut output).unwrap();
+/// assert!((48000..=48001).contains(&(output.len() / (2 * 2))));
+/// ```
+#[derive(Debug)]
+pub struct ResampleReader<R> {
+    inner: R,
+    core: Core,
+    /// The offset of the next byte in `core.encoded` to hand out.
+    offset: usize,
+    /// Whether the inner reader reached the end of the stream.
+    eof: bool,
+    /// Whether the conversion has been finalized completely.
+    done: bool,
+}
+
+impl<R> ResampleReader<R>
+where
+    R: Read,
+{
+    /// Create a new reader resampling the data read from `inner`,
+    /// which is expected to be encoded as described by `encoding`,
+    /// using the given `resampler`.
+    pub fn new(inner: R, resampler: Resampler, encoding: SampleEncoding) -> Self {
+        Self {
+            inner,
+            core: Core::new(resampler, encoding),
+            offset: 0,
+            eof: false,
+            done: false,
+        }
+    }
+
+    /// Retrieve a reference to the inner reader.
+    #[inline]
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Retrieve a mutable reference to the inner reader.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Destroy the reader, returning the inner reader.
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Read the next block of input and convert it.
+    fn refill(&mut self) -> IoResult<()> {
+        let () = self.core.encoded.clear();
+        self.offset = 0;
+
+        if !self.eof {
+            let len = self.core.raw.len();
+            let () = self.core.raw.resize(self.core.block_size(), 0);
+            let result = self.inner.read(&mut self.core.raw[len..]);
+            match result {
+                Ok(0) => {
+                    let () = self.core.raw.truncate(len);
+                    self.eof = true;
+                },
+                Ok(count) => {
+                    let () = self.core.raw.truncate(len + count);
+                },
+                Err(err) => {
+                    let () = self.core.raw.truncate(len);
+                    return Err(err)
+                },
+            }
+        }
+
+        let () = self.core.decode();
+        if self.eof && !self.core.raw.is_empty() {
+            // Discard the incomplete frame, so that we can continue
+            // with finalization on the next call.
+            let () = self.core.raw.clear();
+            return Err(incomplete_frame())
+        }
+
+        let () = self.core.convert(self.eof)?;
+        self.done = self.eof && self.core.encoded.is_empty();
+        Ok(())
+    }
+}
+
+impl<R> Read for ResampleReader<R>
+where
+    R: Read,
+{
+    fn read(&mut self, buf: &mut [u8]) -> IoResult<usize> {
+        if buf.is_empty() {
+            return Ok(0)
+        }
+
+        loop {
+            let available = &self.core.encoded[self.offset..];
+            if !available.is_empty() {
+                let count = available.len().min(buf.len());
+                let () = buf[..count].copy_from_slice(&available[..count]);
+                self.offset += count;
+                break Ok(count)
+            }
+
+            if self.done {
+                break Ok(0)
+            }
+
+            let () = self.refill()?;
+        }
+    }
+}
+
+
+/// A writer resampling raw PCM data before writing it to an inner
+/// writer.
+///
+/// Input samples are expected in the provided encoding and resampled
+/// output is written in the same encoding. Converted data is buffered
+/// internally until it can be written out. Integer output is dithered
+/// as configured on the resampler (see [`Resampler::set_dither`]).
+///
+/// Flushing the writer finalizes the conversion, i.e., it marks the end
+/// of the stream and writes out all pending output. Data written
+/// afterwards starts a new stream. Use [`finish`][Self::finish] to
+/// finalize the conversion and retrieve the inner writer. Dropping the
+/// writer without either flushing or finishing it discards pending
+/// output.
+///
+/// # Example
+///
+/// ```
+/// use std::io::Write as _;
+/// use resample::{Resampler, ResampleType, ResampleWriter, SampleEncoding};
+///
+/// // One second of 32 bit floating point mono silence in 48000Hz.
+/// let input = vec![0u8; 48000 * 4];
+/// let resampler = Resampler::new(ResampleType::SincFastest, 1, 48000, 44100).unwrap();
+/// let mut writer = ResampleWriter::new(Vec::new(), resampler, SampleEncoding::F32Le);
+///
+/// let () = writer.write_all(&input).unwrap();
+/// let output = writer.finish().unwrap();
+/// assert!((44099..=44100).contains(&(output.len() / 4)));
+/// ```
+#[derive(Debug)]
+pub struct ResampleWriter<W>
+where
+    W: Write,
+{
+    inner: W,
+    core: Core,
+}
+
+impl<W> ResampleWriter<W>
+where
+    W: Write,
+{
+    /// Create a new writer resampling data encoded as described by
+    /// `encoding` using the given `resampler`, before writing it to
+    /// `inner`.
+    pub fn new(inner: W, resampler: Resampler, encoding: SampleEncoding) -> Self {
+        Self {
+            inner,
+            core: Core::new(resampler, encoding),
+        }
+    }
+
+    /// Retrieve a reference to the inner writer.
+    #[inline]
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Retrieve a mutable reference to the inner writer.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Write out pending encoded output.
+    fn write_pending(&mut self) -> IoResult<()> {
+        let mut offset = 0;
+        let result = loop {
+            if offset >= self.core.encoded.len() {
+                break Ok(())
+            }
+
+            match self.inner.write(&self.core.encoded[offset..]) {
+                Ok(0) => break Err(IoError::from(IoErrorKind::WriteZero)),
+                Ok(count) => offset += count,
+                Err(err) if err.kind() == IoErrorKind::Interrupted => (),
+                Err(err) => break Err(err),
+            }
+        };
+
+        let _drained = self.core.encoded.drain(..offset);
+        result
+    }
+
+    /// Finalize the conversion, writing out all pending output.
+    fn finalize(&mut self) -> IoResult<()> {
+        let () = self.write_pending()?;
+        if !self.core.raw.is_empty() {
+            return Err(incomplete_frame())
+        }
+
+        let () = self.core.convert(true)?;
+        let () = self.core.resampler.reset()?;
+        let () = self.write_pending()?;
+        Ok(())
+    }
+
+    /// Finalize the conversion and destroy the writer, returning the
+    /// inner writer.
+    pub fn finish(mut self) -> IoResult<W> {
+        let () = self.finalize()?;
+        let () = self.inner.flush()?;
+        Ok(self.inner)
+    }
+}
+
+impl<W> Write for ResampleWriter<W>
+where
+    W: Write,
+{
+    fn write(&mut self, buf: &[u8]) -> IoResult<usize> {
+        // Make sure to not accumulate output in case the inner writer
+        // is not able to keep up.
+        let () = self.write_pending()?;
+
+        let count = buf.len().min(self.core.block_size());
+        let () = self.core.raw.extend_from_slice(&buf[..count]);
+        let () = self.core.decode();
+        let () = self.core.convert(false)?;
+
+        // The input has been consumed at this point, so errors are
+        // reported by the next operation, when writing is retried.
+        let _result = self.write_pending();
+        Ok(count)
+    }
+
+    fn flush(&mut self) -> IoResult<()> {
+        let () = self.finalize()?;
+        self.inner.flush()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::f32::consts::PI;
+
+    use crate::convert;
+    use crate::dither::Dither;
+    use crate::resample_type::ResampleType;
+
+
+    /// Generate `frames` frames of a stereo signal.
+    fn signal(frames: usize) -> Vec<f32> {
+        let freq = PI * 880f32 / 44100f32;
+        (0..frames)
+            .flat_map(|i| {
+                [
+                    0.5 * (freq * i as f32).sin(),
+                    0.25 * (freq * i as f32).cos(),
+                ]
+            })
+            .collect()
+    }
+
+    /// Check that samples survive an encoding and decoding round trip.
+    #[test]
+    fn encoding_round_trip() {
+        let samples = [0.0f32, 0.5, -0.5, -1.0];
+
+        for encoding in [
+            SampleEncoding::F32Le,
+            SampleEncoding::F32Be,
+            SampleEncoding::S16Le,
+            SampleEncoding::S16Be,
+            SampleEncoding::S32Le,
+            SampleEncoding::S32Be,
+        ] {
+            let mut bytes = Vec::new();
+            let () = encoding.encode(&samples, &mut bytes);
+            assert_eq!(bytes.len(), samples.len() * encoding.sample_size());
+
+            let mut decoded = [1.0; 4];
+            let () = encoding.decode(&bytes, &mut decoded);
+            assert_eq!(decoded, samples, "{encoding:?}");
+        }
+
+        let mut bytes = Vec::new();
+        let () = SampleEncoding::S16Be.encode(&[0.5], &mut bytes);
+        assert_eq!(bytes, [0x40, 0x00]);
+    }
+
+    /// Check that reading resampled data produces the same output as
+    /// converting everything in one go.
+    #[test]
+    fn reader_conversion() {
+        let input = signal(10_000);
+        let reference = convert(ResampleType::Linear, 2, 44100, 48000, &input).unwrap();
+
+        let mut bytes = Vec::new();
+        let () = SampleEncoding::F32Be.encode(&input, &mut bytes);
+
+        let resampler = Resampler::new(ResampleType::Linear, 2, 44100, 48000).unwrap();
+        let mut reader = ResampleReader::new(bytes.as_slice(), resampler, SampleEncoding::F32Be);
+        let mut output = Vec::new();
+        let mut buffer = [0u8; 13];
+        loop {
+            let count = reader.read(&mut buffer).unwrap();
+            if count == 0 {
+                break
+            }
+            let () = output.extend_from_slice(&buffer[..count]);
+        }
+
+        let mut resampled = vec![0.0; output.len() / 4];
+        let () = SampleEncoding::F32Be.decode(&output, &mut resampled);
+        assert_eq!(resampled, reference);
+    }
+
+    /// Check that writing resampled data produces the same output as
+    /// converting everything in one go.
+    #[test]
+    fn writer_conversion() {
+        let input = signal(10_000);
+        let reference = convert(ResampleType::SincFastest, 2, 44100, 22050, &input).unwrap();
+
+        let mut bytes = Vec::new();
+        let () = SampleEncoding::S32Le.encode(&input, &mut bytes);
+
+        let resampler = Resampler::new(ResampleType::SincFastest, 2, 44100, 22050).unwrap();
+        let mut writer = ResampleWriter::new(Vec::new(), resampler, SampleEncoding::S32Le);
+        // Write in chunks not aligned to frame boundaries.
+        for chunk in bytes.chunks(1001) {
+            let () = writer.write_all(chunk).unwrap();
+        }
+        let output = writer.finish().unwrap();
+
+        let mut resampled = vec![0.0; output.len() / 4];
+        let () = SampleEncoding::S32Le.decode(&output, &mut resampled);
+        assert_eq!(resampled.len(), reference.len());
+
+        let max_diff = resampled
+            .iter()
+            .zip(&reference)
+            .fold(0f32, |max, (a, b)| max.max((a - b).abs()));
+        assert!(max_diff < 1e-6, "{max_diff}");
+    }
+
+    /// Check that integer output is dithered as configured on the
+    /// resampler.
+    #[test]
+    fn dithering() {
+        let input = signal(10_000)
+            .into_iter()
+            .map(|sample| (sample * 32768.0) as i16)
+            .collect::<Vec<_>>();
+        let builder = Resampler::builder(ResampleType::Linear)
+            .channels(2)
+            .rates(44100, 48000)
+            .dither(Dither::NoiseShaped, 11);
+        let reference = builder.convert(&input).unwrap();
+        let plain = convert(ResampleType::Linear, 2, 44100, 48000, &input).unwrap();
+        assert_ne!(reference, plain);
+
+        let mut bytes = Vec::new();
+        let () = input
+            .iter()
+            .for_each(|sample| bytes.extend_from_slice(&sample.to_le_bytes()));
+
+        let resampler = builder.build().unwrap();
+        let mut reader = ResampleReader::new(bytes.as_slice(), resampler, SampleEncoding::S16Le);
+        let mut output = Vec::new();
+        let _count = reader.read_to_end(&mut output).unwrap();
+        let resampled = output
+            .as_chunks::<2>()
+            .0
+            .iter()
+            .map(|bytes| i16::from_le_bytes(*bytes))
+            .collect::<Vec<_>>();
+        assert_eq!(resampled, reference);
+    }
+
+    /// Make sure that we report streams ending in the middle of a frame.
+    #[test]
+    fn incomplete_frames() {
+        let bytes = [0u8; 6];
+        let resampler = Resampler::new(ResampleType::Linear, 2, 1, 2).unwrap();
+        let mut reader = ResampleReader::new(bytes.as_slice(), resampler, SampleEncoding::S16Le);
+        let mut output = Vec::new();
+        let err = reader.read_to_end(&mut output).unwrap_err();
+        assert_eq!(err.kind(), IoErrorKind::UnexpectedEof);
+
+        let resampler = Resampler::new(ResampleType::Linear, 2, 1, 2).unwrap();
+        let mut writer = ResampleWriter::new(Vec::new(), resampler, SampleEncoding::S16Le);
+        let () = writer.write_all(&bytes).unwrap();
+        let err = writer.flush().unwrap_err();
+        assert_eq!(err.kind(), IoErrorKind::UnexpectedEof);
+    }
+}
@@ -0,0 +1,357 @@
+use std::iter::Flatten;
+
+use crate::error::Error;
+use crate::error::ErrorKind;
+use crate::resample_type::ResampleType;
+use crate::resampler::Resampler;
+
+
+/// The number of input frames converted in one go.
+const BLOCK_FRAMES: usize = 1024;
+
+
+/// An iterator adapter resampling a stream of interleaved samples.
+///
+/// Input is pulled from the wrapped iterator in blocks, converted, and
+/// buffered internally. Once the source runs out, the conversion is
+/// finalized. Should the source end in the middle of a frame, the
+/// incomplete frame is dropped.
+///
+/// Objects of this type are usually created via
+/// [`ResampleExt::resample`].
+///
+/// # Example
+///
+/// ```
+/// # use std::f32::consts::PI;
+/// use resample::{ResampleExt as _, ResampleType};
+///
+/// // Generate a 880Hz sine wave for 1 second in 44100Hz with one channel.
+/// let freq = PI * 880_f32 / 44100_f32;
+/// let samples = (0..44100).map(|i| (freq * i as f32).sin());
+///
+/// let resampled = samples
+///     .resample(ResampleType::SincFastest, 1, 44100, 48000)
+///     .unwrap();
+/// assert_eq!(resampled.count(), 48000);
+/// ```
+#[derive(Debug)]
+pub struct ResampleIter<I> {
+    /// The source of input samples.
+    source: I,
+    /// The converter used.
+    resampler: Resampler,
+    /// Input samples not yet consumed by the converter.
+    input: Vec<f32>,
+    /// Converted samples.
+    output: Vec<f32>,
+    /// The index of the next sample in `output` to hand out.
+    offset: usize,
+    /// The number of valid samples in `output`.
+    len: usize,
+    /// Whether `source` has run out of samples.
+    exhausted: bool,
+    /// Whether the conversion has been finalized completely.
+    done: bool,
+    /// The error that terminated the conversion, if any.
+    error: Option<Error>,
+}
+
+impl<I> ResampleIter<I>
+where
+    I: Iterator<Item = f32>,
+{
+    /// Create a new iterator converting the samples provided by `source`
+    /// using the given `resampler`.
+    pub fn new(source: I, resampler: Resampler) -> Self {
+        let in_channels = usize::from(resampler.input_channels());
+        let out_channels = usize::from(resampler.output_channels());
+        let output_frames = resampler.max_output_frames(BLOCK_FRAMES);
+
+        Self {
+            source,
+            resampler,
+            input: Vec::with_capacity(BLOCK_FRAMES * in_channels),
+            output: vec![0.0; output_frames * out_channels],
+            offset: 0,
+            len: 0,
+            exhausted: false,
+            done: false,
+            error: None,
+        }
+    }
+
+    /// Retrieve the error that terminated the conversion prematurely,
+    /// if any.
+    ///
+    /// Errors can only be caused by internal failures of the converter,
+    /// but as iteration can't report them directly, they are made
+    /// available here.
+    pub fn error(&self) -> Option<&Error> {
+        self.error.as_ref()
+    }
+
+    /// Convert the next block of input.
+    fn refill(&mut self) {
+        let channels = usize::from(self.resampler.input_channels());
+
+        if !self.exhausted {
+            let block = BLOCK_FRAMES * channels;
+            let count = block - self.input.len();
+            let () = self.input.extend(self.source.by_ref().take(count));
+
+            if self.input.len() < block {
+                self.exhausted = true;
+                let () = self
+                    .input
+                    .truncate(self.input.len() - self.input.len() % channels);
+            }
+        }
+
+        let result = if self.exhausted {
+            self.resampler.finalize(&self.input, &mut self.output)
+        } else {
+            self.resampler.process(&self.input, &mut self.output)
+        };
+
+        match result {
+            Ok(processed) => {
+                let _drained = self.input.drain(..processed.read);
+                self.offset = 0;
+                self.len = processed.written;
+                self.done = self.exhausted && processed.written == 0;
+            },
+            Err(err) => {
+                self.error = Some(err);
+                self.done = true;
+            },
+        }
+    }
+}
+
+impl<I> Iterator for ResampleIter<I>
+where
+    I: Iterator<Item = f32>,
+{
+    type Item = f32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.offset < self.len {
+                let sample = self.output[self.offset];
+                self.offset += 1;
+                break Some(sample)
+            }
+
+            if self.done {
+                break None
+            }
+
+            let () = self.refill();
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len - self.offset, None)
+    }
+}
+
+
+/// An iterator adapter resampling a stream of frames.
+///
+/// This type is the frame-wise equivalent of [`ResampleIter`], with
+/// each item holding one sample for each of the `N` channels.
+///
+/// Objects of this type are usually created via
+/// [`ResampleExt::resample_frames`].
+#[derive(Debug)]
+pub struct ResampleFrames<I, const N: usize>
+where
+    I: Iterator<Item = [f32; N]>,
+{
+    inner: ResampleIter<Flatten<I>>,
+}
+
+impl<I, const N: usize> ResampleFrames<I, N>
+where
+    I: Iterator<Item = [f32; N]>,
+{
+    /// Create a new iterator converting the frames provided by `source`
+    /// using the given `resampler`.
+    ///
+    /// The `resampler` has to be configured for `N` channels, otherwise
+    /// an error of kind [`ErrorKind::ChannelCountMismatch`] is
+    /// reported.
+    pub fn new(source: I, resampler: Resampler) -> Result<Self, Error> {
+        if usize::from(resampler.input_channels()) != N
+            || usize::from(resampler.output_channels()) != N
+        {
+            return Err(Error::from(ErrorKind::ChannelCountMismatch))
+        }
+
+        let slf = Self {
+            inner: ResampleIter::new(source.flatten(), resampler),
+        };
+        Ok(slf)
+    }
+
+    /// Retrieve the error that terminated the conversion prematurely,
+    /// if any.
+    ///
+    /// See [`ResampleIter::error`] for details.
+    pub fn error(&self) -> Option<&Error> {
+        self.inner.error()
+    }
+}
+
+impl<I, const N: usize> Iterator for ResampleFrames<I, N>
+where
+    I: Iterator<Item = [f32; N]>,
+{
+    type Item = [f32; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut frame = [0.0; N];
+        for sample in &mut frame {
+            // The converter only ever produces whole frames.
+            *sample = self.inner.next()?;
+        }
+        Some(frame)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, _upper) = self.inner.size_hint();
+        (lower / N, None)
+    }
+}
+
+
+/// An extension trait for resampling iterators of samples and frames.
+pub trait ResampleExt: Iterator {
+    /// Resample the interleaved samples of this iterator from
+    /// `from_rate` to `to_rate`, assuming the given channel count.
+    ///
+    /// See [`ResampleIter`] for details.
+    fn resample(
+        self,
+        converter_type: ResampleType,
+        channels: u8,
+        from_rate: u32,
+        to_rate: u32,
+    ) -> Result<ResampleIter<Self>, Error>
+    where
+        Self: Sized + Iterator<Item = f32>,
+    {
+        let resampler = Resampler::new(converter_type, channels, from_rate, to_rate)?;
+        Ok(ResampleIter::new(self, resampler))
+    }
+
+    /// Resample the frames of this iterator from `from_rate` to
+    /// `to_rate`.
+    ///
+    /// See [`ResampleFrames`] for details.
+    fn resample_frames<const N: usize>(
+        self,
+        converter_type: ResampleType,
+        from_rate: u32,
+        to_rate: u32,
+    ) -> Result<ResampleFrames<Self, N>, Error>
+    where
+        Self: Sized + Iterator<Item = [f32; N]>,
+    {
+        let channels = u8::try_from(N).map_err(|_err| Error::from(ErrorKind::BadChannelCount))?;
+        let resampler = Resampler::new(converter_type, channels, from_rate, to_rate)?;
+        ResampleFrames::new(self, resampler)
+    }
+}
+
+impl<I> ResampleExt for I where I: Iterator {}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::f32::consts::PI;
+
+    use crate::convert;
+
+
+    /// Generate `frames` frames of a stereo signal.
+    fn signal(frames: usize) -> Vec<f32> {
+        let freq = PI * 880f32 / 44100f32;
+        (0..frames)
+            .flat_map(|i| [(freq * i as f32).sin(), (freq * i as f32).cos()])
+            .collect()
+    }
+
+    /// Check that resampling an iterator produces the same output as
+    /// converting everything in one go.
+    #[test]
+    fn iter_conversion() {
+        let input = signal(10_000);
+
+        for type_ in [
+            ResampleType::SincFastest,
+            ResampleType::ZeroOrderHold,
+            ResampleType::Linear,
+        ] {
+            let reference = convert(type_, 2, 44100, 48000, &input).unwrap();
+            let resampled = input
+                .iter()
+                .copied()
+                .resample(type_, 2, 44100, 48000)
+                .unwrap()
+                .collect::<Vec<_>>();
+            assert_eq!(resampled.len(), reference.len(), "{type_:?}");
+
+            let max_diff = resampled
+                .iter()
+                .zip(&reference)
+                .fold(0f32, |max, (a, b)| max.max((a - b).abs()));
+            assert!(max_diff < 1e-5, "{type_:?}: {max_diff}");
+        }
+    }
+
+    /// Check that the frame-wise adapter produces the same output as
+    /// the sample-wise one.
+    #[test]
+    fn frame_conversion() {
+        let input = signal(5_000);
+        let samples = input
+            .iter()
+            .copied()
+            .resample(ResampleType::Linear, 2, 48000, 16000)
+            .unwrap()
+            .collect::<Vec<_>>();
+        let frames = input
+            .chunks_exact(2)
+            .map(|frame| [frame[0], frame[1]])
+            .resample_frames(ResampleType::Linear, 48000, 16000)
+            .unwrap()
+            .collect::<Vec<_>>();
+        assert_eq!(frames.concat(), samples);
+    }
+
+    /// Make sure that an incomplete trailing frame is dropped.
+    #[test]
+    fn incomplete_frame() {
+        let resampled = [0.25f32; 5]
+            .into_iter()
+            .resample(ResampleType::ZeroOrderHold, 2, 1, 1)
+            .unwrap()
+            .collect::<Vec<_>>();
+        assert_eq!(resampled.len() % 2, 0);
+        assert!(!resampled.is_empty());
+        assert!(resampled.iter().all(|sample| *sample == 0.25));
+    }
+
+    /// Check that we detect mismatching channel counts for frame-wise
+    /// conversion.
+    #[test]
+    fn frame_channel_mismatch() {
+        let resampler = Resampler::new(ResampleType::Linear, 1, 1, 2).unwrap();
+        let err = ResampleFrames::new([[0.0f32; 2]].into_iter(), resampler).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ChannelCountMismatch);
+    }
+}
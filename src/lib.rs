@@ -1,19 +1,93 @@
 //! A library for sample rate conversion of audio.
+//!
+//! The central type is the [`Resampler`], which converts audio in
+//! blocks and is best configured via [`Resampler::builder`]:
+//!
+//! ```
+//! use resample::{OutputLength, Resampler, ResampleType};
+//!
+//! let mut resampler = Resampler::builder(ResampleType::SincFastest)
+//!     .channels(2)
+//!     .rates(44100, 48000)
+//!     .output_length(OutputLength::Exact)
+//!     .build()
+//!     .unwrap();
+//!
+//! let input = [0.0f32; 2 * 4410];
+//! let mut output = [0.0f32; 2 * 4800];
+//! let processed = resampler.finalize(&input, &mut output).unwrap();
+//! assert_eq!(processed.written, output.len());
+//! ```
+//!
+//! For converting a chunk of audio that is available in its entirety,
+//! the [`convert`] family of functions provides shortcuts for common
+//! configurations, while [`ResamplerBuilder::convert`] supports all
+//! options the builder offers.
 
 #![cfg_attr(feature = "nightly", feature(test))]
 
 #[cfg(all(test, feature = "nightly"))]
 extern crate test;
 
+mod adaptive;
+mod builder;
+mod callback;
+mod cascade;
+mod coeffs;
+mod dither;
+mod double;
 mod error;
+mod io;
+mod iter;
+mod mix;
+#[cfg(feature = "parallel")]
+mod parallel;
+mod realtime;
 mod resample_type;
 mod resampler;
+mod sample;
+mod schedule;
+mod sinc;
+#[cfg(feature = "futures")]
+mod stream;
 
+pub use crate::adaptive::AdaptiveResampler;
+pub use crate::builder::ResamplerBuilder;
+pub use crate::callback::CallbackResampler;
+pub use crate::cascade::CascadedResampler;
+pub use crate::dither::Dither;
 pub use crate::error::Error;
 pub use crate::error::ErrorKind;
+pub use crate::io::ResampleReader;
+pub use crate::io::ResampleWriter;
+pub use crate::io::SampleEncoding;
+pub use crate::iter::ResampleExt;
+pub use crate::iter::ResampleFrames;
+pub use crate::iter::ResampleIter;
+pub use crate::mix::ChannelMix;
+#[cfg(feature = "parallel")]
+pub use crate::parallel::convert_parallel;
+#[cfg(feature = "parallel")]
+pub use crate::parallel::ParallelResampler;
+pub use crate::realtime::RealtimeResampler;
 pub use crate::resample_type::ResampleType;
+pub use crate::resampler::Latency;
+pub use crate::resampler::OutputLength;
 pub use crate::resampler::Processed;
 pub use crate::resampler::Resampler;
+pub use crate::sample::Sample;
+pub use crate::schedule::convert_with_schedule;
+pub use crate::schedule::RatioInterpolation;
+#[cfg(feature = "futures")]
+pub use crate::stream::ResampleSink;
+#[cfg(feature = "futures")]
+pub use crate::stream::ResampleStream;
+
+use crate::resampler::check_channels;
+use crate::resampler::check_frame_aligned;
+use crate::resampler::check_ratio;
+use crate::resampler::expected_rate_frames;
+use crate::resampler::tail_frames;
 
 
 /// Perform a simple samplerate conversion of a large chunk of audio.
@@ -21,7 +95,9 @@ pub use crate::resampler::Resampler;
 /// This function is not suitable for streamed audio. Use the
 /// [`Resampler`] type in such a context.
 ///
-/// The length of `input` must be `input_frames * channels`.
+/// The length of `input` must be `input_frames * channels`, otherwise
+/// an error of kind [`ErrorKind::InputNotFrameAligned`] is reported.
+/// Samples can be provided in any of the supported [`Sample`] formats.
 ///
 /// # Example
 ///
@@ -38,23 +114,241 @@ pub use crate::resampler::Resampler;
 /// let resampled = convert(type_, 1, 44100, 48000, &input).unwrap();
 /// assert_eq!(resampled.len(), 48000);
 /// ```
-pub fn convert(
+pub fn convert<S>(
+    type_: ResampleType,
+    channels: u8,
+    from_rate: u32,
+    to_rate: u32,
+    input: &[S],
+) -> Result<Vec<S>, Error>
+where
+    S: Sample,
+{
+    convert_with_length(
+        type_,
+        channels,
+        from_rate,
+        to_rate,
+        input,
+        OutputLength::Natural,
+    )
+}
+
+
+/// Perform a simple samplerate conversion of a large chunk of audio,
+/// with the output length determined by `length`.
+///
+/// This function behaves like [`convert`], except that with a policy
+/// other than [`OutputLength::Natural`] the result is guaranteed to
+/// contain exactly `ceil(input_frames * to_rate / from_rate)` frames.
+///
+/// # Example
+///
+/// ```
+/// use resample::{convert_with_length, OutputLength, ResampleType};
+///
+/// let input = vec![0.5f32; 1000];
+/// let resampled = convert_with_length(
+///     ResampleType::Linear,
+///     1,
+///     44100,
+///     48000,
+///     &input,
+///     OutputLength::ExactAlignedToDelay,
+/// )
+/// .unwrap();
+/// assert_eq!(resampled.len(), 1089);
+/// ```
+pub fn convert_with_length<S>(
     type_: ResampleType,
     channels: u8,
     from_rate: u32,
     to_rate: u32,
-    input: &[f32],
-) -> Result<Vec<f32>, Error> {
-    let input_len = input.len();
-    let input_frames = input_len / usize::from(channels);
-    let output_frames = (input_frames * to_rate as usize).div_ceil(from_rate as usize);
-    let mut output = vec![0.0; output_frames * usize::from(channels)];
-    let mut resampler = Resampler::new(type_, channels, from_rate, to_rate)?;
+    input: &[S],
+    length: OutputLength,
+) -> Result<Vec<S>, Error>
+where
+    S: Sample,
+{
+    let resampler = Resampler::builder(type_)
+        .channels(channels)
+        .rates(from_rate, to_rate)
+        .output_length(length)
+        .build()?;
+    let () = check_frame_aligned(input.len(), channels, ErrorKind::InputNotFrameAligned)?;
+
+    let input_frames = input.len() / usize::from(channels);
+    let output_frames = expected_rate_frames(input_frames, from_rate, to_rate);
+    convert_impl(resampler, input, output_frames)
+}
+
 
+/// Perform a simple samplerate conversion of a large chunk of audio,
+/// using an arbitrary conversion ratio.
+///
+/// The ratio is defined as output samplerate divided by input
+/// samplerate. Other than that, this function behaves like [`convert`].
+///
+/// # Example
+///
+/// ```
+/// use resample::{convert_ratio, ResampleType};
+///
+/// // Speed up playback ever so slightly.
+/// let input = vec![0.0f32; 100_000];
+/// let resampled = convert_ratio(ResampleType::Linear, 1, 0.999, &input).unwrap();
+/// assert!(resampled.len().abs_diff(99_900) <= 1);
+/// ```
+pub fn convert_ratio<S>(
+    type_: ResampleType,
+    channels: u8,
+    ratio: f64,
+    input: &[S],
+) -> Result<Vec<S>, Error>
+where
+    S: Sample,
+{
+    let resampler = Resampler::builder(type_)
+        .channels(channels)
+        .ratio(ratio)
+        .build()?;
+    let () = check_frame_aligned(input.len(), channels, ErrorKind::InputNotFrameAligned)?;
+
+    let input_frames = input.len() / usize::from(channels);
+    let output_frames = (input_frames as f64 * ratio).ceil() as usize;
+    convert_impl(resampler, input, output_frames)
+}
+
+
+/// Calculate an upper bound on the number of samples [`convert`] and
+/// [`convert_into`] produce for `input_len` samples of input.
+///
+/// A buffer of this size is guaranteed to be large enough for
+/// [`convert_into`] to succeed, and the output produced into it is
+/// identical to that of [`convert`].
+///
+/// Errors are reported for the same invalid arguments [`convert`]
+/// rejects.
+pub fn max_convert_len(
+    type_: ResampleType,
+    channels: u8,
+    from_rate: u32,
+    to_rate: u32,
+    input_len: usize,
+) -> Result<usize, Error> {
+    let () = check_channels(channels)?;
+    let ratio = f64::from(to_rate) / f64::from(from_rate);
+    let () = check_ratio(ratio)?;
+    let () = check_frame_aligned(input_len, channels, ErrorKind::InputNotFrameAligned)?;
+
+    let channels = usize::from(channels);
+    let input_frames = input_len / channels;
+    let output_frames = expected_rate_frames(input_frames, from_rate, to_rate);
+    Ok(output_frames
+        .saturating_add(tail_frames(type_, ratio))
+        .saturating_mul(channels))
+}
+
+
+/// Perform a simple samplerate conversion of a large chunk of audio,
+/// writing the result into a caller provided buffer.
+///
+/// This function behaves like [`convert`], but does not allocate any
+/// memory for the output. The number of samples written to `output` is
+/// returned. Use [`max_convert_len`] to size the buffer. If `output` is
+/// too small to hold the result or does not provide space for at least
+/// the `ceil(input_frames * to_rate / from_rate)` frames the conversion
+/// is expected to produce, an error of kind
+/// [`ErrorKind::OutputBufferTooSmall`] is reported.
+///
+/// # Example
+///
+/// ```
+/// use resample::{convert_into, max_convert_len, ResampleType};
+///
+/// let input = vec![0.0f32; 2 * 4410];
+/// let len = max_convert_len(ResampleType::SincFastest, 2, 44100, 48000, input.len()).unwrap();
+/// let mut output = vec![0.0f32; len];
+///
+/// let written =
+///     convert_into(ResampleType::SincFastest, 2, 44100, 48000, &input, &mut output).unwrap();
+/// assert_eq!(written, 2 * 4800);
+/// ```
+pub fn convert_into<S>(
+    type_: ResampleType,
+    channels: u8,
+    from_rate: u32,
+    to_rate: u32,
+    input: &[S],
+    output: &mut [S],
+) -> Result<usize, Error>
+where
+    S: Sample,
+{
+    let mut resampler = Resampler::builder(type_)
+        .channels(channels)
+        .rates(from_rate, to_rate)
+        .build()?;
+    let () = check_frame_aligned(input.len(), channels, ErrorKind::InputNotFrameAligned)?;
+
+    let input_frames = input.len() / usize::from(channels);
+    let output_frames = expected_rate_frames(input_frames, from_rate, to_rate);
+    convert_into_impl(&mut resampler, input, output, output_frames)
+}
+
+
+/// Convert `input` in its entirety using `resampler`, expecting
+/// roughly `output_frames` frames of output.
+fn convert_impl<S>(
+    mut resampler: Resampler,
+    input: &[S],
+    output_frames: usize,
+) -> Result<Vec<S>, Error>
+where
+    S: Sample,
+{
+    let channels = usize::from(resampler.output_channels());
+    let len = (output_frames + resampler.tail_frames()) * channels;
+    let mut output = vec![S::default(); len];
+
+    let written = convert_into_impl(&mut resampler, input, &mut output, output_frames)?;
+    let () = output.truncate(written);
+    Ok(output)
+}
+
+
+/// Convert `input` in its entirety using `resampler`, writing the
+/// result to `output` and expecting roughly `output_frames` frames of
+/// output.
+fn convert_into_impl<S>(
+    resampler: &mut Resampler,
+    input: &[S],
+    output: &mut [S],
+    output_frames: usize,
+) -> Result<usize, Error>
+where
+    S: Sample,
+{
+    let channels = usize::from(resampler.output_channels());
+    let capacity = output.len() / channels;
+    // Sinc converters consume all input before flushing their output,
+    // so we could not tell whether anything got cut off if the buffer
+    // were smaller than the expected output.
+    if capacity < output_frames {
+        return Err(Error::from(ErrorKind::OutputBufferTooSmall))
+    }
+
+    let max_frames = (output_frames + resampler.tail_frames()).min(capacity);
+    // Sinc converters fill whatever output buffer they are provided
+    // with when flushing, so initially we restrict them to the expected
+    // output length. Only if that is not enough to consume all input do
+    // we make use of the remaining space.
+    let mut limit = output_frames;
     let mut total = Processed::default();
+
     loop {
         let in_buf = &input[total.read..];
-        let out_buf = &mut output[total.written..];
+        let out_buf = &mut output[total.written..limit * channels];
 
         let processed = resampler.finalize(in_buf, out_buf)?;
 
@@ -62,19 +356,14 @@ pub fn convert(
         total.written += processed.written;
 
         if total.read >= input.len() {
-            break
+            break Ok(total.written)
         }
 
-        // We haven't quite managed to process everything, due to output
-        // buffer size constraints. Allocate a few more bytes and
-        // continue.
-        let () = output.resize(output.len() + 64 * usize::from(channels), 0.0);
+        if processed == Processed::default() && limit == max_frames {
+            break Err(Error::from(ErrorKind::OutputBufferTooSmall))
+        }
+        limit = max_frames;
     }
-
-    debug_assert_eq!(total.read, input.len());
-
-    let () = output.resize(total.written, 0.0);
-    Ok(output)
 }
 
 
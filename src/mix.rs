@@ -0,0 +1,285 @@
+use std::f32::consts::FRAC_1_SQRT_2;
+use std::ops::Add;
+use std::ops::Mul;
+
+use crate::error::Error;
+use crate::error::ErrorKind;
+use crate::resampler::check_channels;
+use crate::resampler::check_frame_aligned;
+use crate::resampler::Processed;
+
+
+/// A sample type that channels can be mixed in.
+pub(crate) trait Mixable:
+    Copy + Default + From<f32> + Add<Output = Self> + Mul<Output = Self>
+{
+    /// Retrieve the mixing buffer for this sample type.
+    fn buffer(buffers: &mut Buffers) -> &mut Vec<Self>;
+}
+
+impl Mixable for f32 {
+    #[inline]
+    fn buffer(buffers: &mut Buffers) -> &mut Vec<Self> {
+        &mut buffers.single
+    }
+}
+
+impl Mixable for f64 {
+    #[inline]
+    fn buffer(buffers: &mut Buffers) -> &mut Vec<Self> {
+        &mut buffers.double
+    }
+}
+
+
+/// Buffers holding mixed samples.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Buffers {
+    /// Buffer for single precision samples.
+    single: Vec<f32>,
+    /// Buffer for double precision samples.
+    double: Vec<f64>,
+}
+
+
+/// A matrix describing how to mix a number of input channels into a
+/// number of output channels.
+///
+/// Each output channel is a weighted sum of all input channels. The
+/// matrix is stored row by row, with one row per output channel holding
+/// one coefficient per input channel.
+///
+/// # Example
+///
+/// ```
+/// use resample::ChannelMix;
+///
+/// // Mix three channels into two, dropping the center channel.
+/// let mix = ChannelMix::new(3, 2, &[
+///     1.0, 0.0, 0.0,
+///     0.0, 1.0, 0.0,
+/// ])
+/// .unwrap();
+/// assert_eq!(mix.inputs(), 3);
+/// assert_eq!(mix.outputs(), 2);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChannelMix {
+    inputs: u8,
+    outputs: u8,
+    coefficients: Box<[f32]>,
+}
+
+impl ChannelMix {
+    /// Create a new channel mix from the given matrix of coefficients.
+    ///
+    /// `coefficients` has to contain `inputs * outputs` values,
+    /// otherwise an error of kind [`ErrorKind::ChannelCountMismatch`] is
+    /// reported.
+    pub fn new(inputs: u8, outputs: u8, coefficients: &[f32]) -> Result<Self, Error> {
+        let () = check_channels(inputs)?;
+        let () = check_channels(outputs)?;
+
+        if coefficients.len() != usize::from(inputs) * usize::from(outputs) {
+            return Err(Error::from(ErrorKind::ChannelCountMismatch))
+        }
+
+        let slf = Self {
+            inputs,
+            outputs,
+            coefficients: coefficients.into(),
+        };
+        Ok(slf)
+    }
+
+    /// Create a channel mix duplicating a mono channel into stereo.
+    pub fn mono_to_stereo() -> Self {
+        Self {
+            inputs: 1,
+            outputs: 2,
+            coefficients: [1.0, 1.0].into(),
+        }
+    }
+
+    /// Create a channel mix averaging stereo channels into mono.
+    pub fn stereo_to_mono() -> Self {
+        Self {
+            inputs: 2,
+            outputs: 1,
+            coefficients: [0.5, 0.5].into(),
+        }
+    }
+
+    /// Create a channel mix downmixing 5.1 surround to stereo, using
+    /// the coefficients recommended by ITU-R BS.775.
+    ///
+    /// Input channels are expected in the order front left, front
+    /// right, center, low frequency effects, surround left, and
+    /// surround right. The low frequency effects channel is dropped.
+    /// Note that no normalization is performed, so loud input may
+    /// exceed the `[-1.0, 1.0]` range.
+    pub fn surround_to_stereo() -> Self {
+        #[rustfmt::skip]
+        let coefficients = [
+            1.0, 0.0, FRAC_1_SQRT_2, 0.0, FRAC_1_SQRT_2, 0.0,
+            0.0, 1.0, FRAC_1_SQRT_2, 0.0, 0.0, FRAC_1_SQRT_2,
+        ];
+
+        Self {
+            inputs: 6,
+            outputs: 2,
+            coefficients: coefficients.into(),
+        }
+    }
+
+    /// Retrieve the number of input channels.
+    #[inline]
+    pub fn inputs(&self) -> u8 {
+        self.inputs
+    }
+
+    /// Retrieve the number of output channels.
+    #[inline]
+    pub fn outputs(&self) -> u8 {
+        self.outputs
+    }
+
+    /// Mix the frames in `input` into `output`.
+    ///
+    /// `input` is expected to be frame aligned and `output` to provide
+    /// space for as many frames.
+    pub(crate) fn apply<T>(&self, input: &[T], output: &mut [T])
+    where
+        T: Mixable,
+    {
+        let inputs = usize::from(self.inputs);
+        let outputs = usize::from(self.outputs);
+
+        for (input, output) in input
+            .chunks_exact(inputs)
+            .zip(output.chunks_exact_mut(outputs))
+        {
+            for (output, row) in output
+                .iter_mut()
+                .zip(self.coefficients.chunks_exact(inputs))
+            {
+                *output = input
+                    .iter()
+                    .zip(row)
+                    .fold(T::default(), |sum, (sample, coefficient)| {
+                        sum + *sample * T::from(*coefficient)
+                    });
+            }
+        }
+    }
+}
+
+
+/// A channel mix along with the buffers required for applying it.
+#[derive(Clone, Debug)]
+pub(crate) struct Mix {
+    matrix: ChannelMix,
+    buffers: Buffers,
+}
+
+impl Mix {
+    pub(crate) fn new(matrix: ChannelMix) -> Self {
+        Self {
+            matrix,
+            buffers: Buffers::default(),
+        }
+    }
+
+    /// Retrieve the channel mix.
+    #[inline]
+    pub(crate) fn matrix(&self) -> &ChannelMix {
+        &self.matrix
+    }
+
+    /// Run the conversion `f` on `input` and `output`, mixing channels
+    /// either before or after, depending on which side has fewer
+    /// channels.
+    ///
+    /// The returned counts refer to `input` and `output`.
+    pub(crate) fn run<C, T, F>(
+        &mut self,
+        ctx: &mut C,
+        input: &[T],
+        output: &mut [T],
+        f: F,
+    ) -> Result<Processed, Error>
+    where
+        T: Mixable,
+        F: FnOnce(&mut C, &[T], &mut [T]) -> Result<Processed, Error>,
+    {
+        let inputs = self.matrix.inputs;
+        let outputs = self.matrix.outputs;
+        let matrix = &self.matrix;
+        let buffer = T::buffer(&mut self.buffers);
+
+        if inputs >= outputs {
+            let () = check_frame_aligned(input.len(), inputs, ErrorKind::InputNotFrameAligned)?;
+            let frames = input.len() / usize::from(inputs);
+            let () = buffer.clear();
+            let () = buffer.resize(frames * usize::from(outputs), T::default());
+            let () = matrix.apply(input, buffer);
+
+            let processed = f(ctx, buffer, output)?;
+            let processed = Processed {
+                read: processed.read / usize::from(outputs) * usize::from(inputs),
+                written: processed.written,
+            };
+            Ok(processed)
+        } else {
+            let () = check_frame_aligned(output.len(), outputs, ErrorKind::OutputNotFrameAligned)?;
+            let frames = output.len() / usize::from(outputs);
+            let () = buffer.clear();
+            let () = buffer.resize(frames * usize::from(inputs), T::default());
+
+            let processed = f(ctx, input, buffer)?;
+            let written = processed.written / usize::from(inputs) * usize::from(outputs);
+            let () = matrix.apply(&buffer[..processed.written], &mut output[..written]);
+            let processed = Processed {
+                read: processed.read,
+                written,
+            };
+            Ok(processed)
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+
+    /// Check that channel mixes are applied as expected.
+    #[test]
+    fn mixing() {
+        let mix = ChannelMix::stereo_to_mono();
+        let mut output = [0.0f32; 2];
+        let () = mix.apply(&[1.0f32, 0.0, 0.5, 0.5], &mut output);
+        assert_eq!(output, [0.5, 0.5]);
+
+        let mix = ChannelMix::mono_to_stereo();
+        let mut output = [0.0f64; 4];
+        let () = mix.apply(&[0.25f64, -1.0], &mut output);
+        assert_eq!(output, [0.25, 0.25, -1.0, -1.0]);
+
+        let mix = ChannelMix::surround_to_stereo();
+        let mut output = [0.0f32; 2];
+        let () = mix.apply(&[0.1f32, 0.2, 0.0, 1.0, 0.0, 0.0], &mut output);
+        assert_eq!(output, [0.1, 0.2]);
+    }
+
+    /// Make sure that invalid matrices are rejected.
+    #[test]
+    fn invalid_mix() {
+        let err = ChannelMix::new(2, 1, &[1.0]).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ChannelCountMismatch);
+
+        let err = ChannelMix::new(0, 1, &[]).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::BadChannelCount);
+    }
+}
@@ -0,0 +1,712 @@
+use std::mem::take;
+
+use rayon::current_num_threads;
+use rayon::iter::IntoParallelIterator as _;
+use rayon::iter::IntoParallelRefMutIterator as _;
+use rayon::iter::ParallelIterator as _;
+
+use crate::convert;
+use crate::dither::Dither;
+use crate::dither::Ditherer;
+use crate::error::Error;
+use crate::error::ErrorKind;
+use crate::resample_type::ResampleType;
+use crate::resampler::check_channels;
+use crate::resampler::check_frame_aligned;
+use crate::resampler::Latency;
+use crate::resampler::Processed;
+use crate::resampler::Resampler;
+use crate::sample::Sample;
+
+
+/// The number of input frames segments overlap by, in addition to the
+/// converter's filter length.
+const SEGMENT_MARGIN: usize = 16;
+
+
+/// Calculate the greatest common divisor of `a` and `b`.
+fn gcd(mut a: usize, mut b: usize) -> usize {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+
+/// Calculate the number of output frames corresponding to `frames`
+/// input frames, rounding down.
+fn output_frames(frames: usize, from_rate: u32, to_rate: u32) -> usize {
+    let frames = frames as u64 * u64::from(to_rate) / u64::from(from_rate);
+    // The result never exceeds the length of an output buffer, so it
+    // fits into `usize`.
+    frames as usize
+}
+
+
+/// Perform a samplerate conversion of a large chunk of audio, spreading
+/// the work across the global [`rayon`] thread pool.
+///
+/// The input is split into one segment per thread, each of which is
+/// converted independently. Segments are extended on both sides by
+/// enough input to cover the converter's filter and start at input
+/// frames that map to whole output frames, so that the stitched
+/// together result matches that of [`convert`]. Because every segment
+/// starts from a fresh converter, minor differences in rounding remain.
+///
+/// See [`convert`] for details on the arguments.
+///
+/// # Example
+///
+/// ```
+/// use resample::{convert_parallel, ResampleType};
+///
+/// let input = vec![0.0f32; 2 * 441_000];
+/// let resampled =
+///     convert_parallel(ResampleType::SincFastest, 2, 44100, 48000, &input).unwrap();
+/// assert_eq!(resampled.len(), 2 * 480_000);
+/// ```
+pub fn convert_parallel<S>(
+    type_: ResampleType,
+    channels: u8,
+    from_rate: u32,
+    to_rate: u32,
+    input: &[S],
+) -> Result<Vec<S>, Error>
+where
+    S: Sample + Send + Sync,
+{
+    convert_segmented(
+        type_,
+        channels,
+        from_rate,
+        to_rate,
+        input,
+        current_num_threads(),
+    )
+}
+
+
+/// Convert `input` in up to `segments` independent segments.
+fn convert_segmented<S>(
+    type_: ResampleType,
+    channels: u8,
+    from_rate: u32,
+    to_rate: u32,
+    input: &[S],
+    segments: usize,
+) -> Result<Vec<S>, Error>
+where
+    S: Sample + Send + Sync,
+{
+    let () = check_channels(channels)?;
+    let () = check_frame_aligned(input.len(), channels, ErrorKind::InputNotFrameAligned)?;
+    if from_rate == 0 {
+        return Err(Error::from(ErrorKind::BadSrcRatio))
+    }
+
+    let from = from_rate as usize;
+    let to = to_rate as usize;
+    let frame_len = usize::from(channels);
+    let frames = input.len() / frame_len;
+    // Segments have to start at multiples of `step` input frames, as
+    // only those correspond to whole output frames.
+    let step = from / gcd(from, to);
+    let ratio = f64::from(to_rate) / f64::from(from_rate);
+    let overlap = 2 * type_.half_filter_frames(ratio) + SEGMENT_MARGIN;
+    let lead = overlap.next_multiple_of(step);
+    let length = frames
+        .div_ceil(segments.max(1))
+        .max(4 * overlap)
+        .next_multiple_of(step);
+    let count = frames.div_ceil(length).max(1);
+
+    let outputs = (0..count)
+        .into_par_iter()
+        .map(|segment| {
+            let start = segment * length;
+            let end = (start + length).min(frames);
+            let first = start.saturating_sub(lead);
+            let last = (end + overlap).min(frames);
+
+            let output = convert(
+                type_,
+                channels,
+                from_rate,
+                to_rate,
+                &input[first * frame_len..last * frame_len],
+            )?;
+
+            // `start - first` is a multiple of `step`, so the division
+            // is exact.
+            let skip = output_frames(start - first, from_rate, to_rate) * frame_len;
+            let output = if end == frames {
+                output[skip..].to_vec()
+            } else {
+                let take = output_frames(end - first, from_rate, to_rate) * frame_len;
+                output[skip..take].to_vec()
+            };
+            Ok(output)
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    Ok(outputs.concat())
+}
+
+
+/// A group of adjacent channels converted independently of all others.
+#[derive(Debug)]
+struct Group {
+    resampler: Resampler,
+    /// The index of the group's first channel.
+    offset: usize,
+    /// The group's share of the input, interleaved.
+    input: Vec<f32>,
+    /// The group's share of the output, interleaved.
+    output: Vec<f32>,
+}
+
+impl Group {
+    /// Convert the group's channels of `input`, which holds `channels`
+    /// channels, into the group's output buffer, which is sized for
+    /// `output_frames` frames.
+    fn process(
+        &mut self,
+        input: &[f32],
+        channels: usize,
+        output_frames: usize,
+        finalize: bool,
+    ) -> Result<Processed, Error> {
+        let group_channels = usize::from(self.resampler.channels());
+        let range = self.offset..self.offset + group_channels;
+
+        let () = self.input.clear();
+        let () = input
+            .chunks_exact(channels)
+            .for_each(|frame| self.input.extend_from_slice(&frame[range.clone()]));
+        let () = self.output.clear();
+        let () = self.output.resize(output_frames * group_channels, 0.0);
+
+        let processed = if finalize {
+            self.resampler.finalize(&self.input, &mut self.output)?
+        } else {
+            self.resampler.process(&self.input, &mut self.output)?
+        };
+
+        let processed = Processed {
+            read: processed.read / group_channels,
+            written: processed.written / group_channels,
+        };
+        Ok(processed)
+    }
+}
+
+
+/// A samplerate converter distributing channels across a thread pool.
+///
+/// Material with large channel counts, such as higher order ambisonics
+/// or recordings from microphone arrays, quickly becomes too expensive
+/// to convert on a single thread. A `ParallelResampler` splits the
+/// channels into groups of adjacent channels, each of which is
+/// converted by a dedicated `libsamplerate` converter on the global
+/// [`rayon`] thread pool. Because `libsamplerate` converts channels
+/// independently of one another, the output is identical to that of a
+/// single [`Resampler`] working on all channels.
+///
+/// Input and output are interleaved, just as with [`Resampler`].
+///
+/// # Example
+///
+/// ```
+/// use resample::{ParallelResampler, ResampleType};
+///
+/// // Third order ambisonics, i.e., 16 channels.
+/// let mut resampler =
+///     ParallelResampler::with_groups(ResampleType::SincFastest, 16, 48000, 44100, 4).unwrap();
+/// assert_eq!(resampler.groups(), 4);
+///
+/// let input = [0.0f32; 16 * 480];
+/// let mut output = [0.0f32; 16 * 1024];
+/// let processed = resampler.process(&input, &mut output).unwrap();
+/// assert_eq!(processed.read, input.len());
+/// ```
+#[derive(Debug)]
+pub struct ParallelResampler {
+    groups: Vec<Group>,
+    channels: u8,
+    /// The dither applied to integer output, if any.
+    dither: Option<Ditherer>,
+    /// Buffers for converting non-`f32` samples.
+    scratch: (Vec<f32>, Vec<f32>),
+}
+
+impl ParallelResampler {
+    /// Create a new parallel samplerate converter assuming the given
+    /// channel count and sample rates, with one channel group per
+    /// thread of the global thread pool.
+    pub fn new(
+        converter_type: ResampleType,
+        channels: u8,
+        from_rate: u32,
+        to_rate: u32,
+    ) -> Result<Self, Error> {
+        Self::with_groups(
+            converter_type,
+            channels,
+            from_rate,
+            to_rate,
+            current_num_threads(),
+        )
+    }
+
+    /// Create a new parallel samplerate converter assuming the given
+    /// channel count and sample rates, splitting channels into `groups`
+    /// groups of roughly equal size.
+    ///
+    /// The number of groups is capped at the number of channels. A
+    /// value of `0` is treated as `1`.
+    pub fn with_groups(
+        converter_type: ResampleType,
+        channels: u8,
+        from_rate: u32,
+        to_rate: u32,
+        groups: usize,
+    ) -> Result<Self, Error> {
+        let () = check_channels(channels)?;
+
+        let count = groups.clamp(1, usize::from(channels));
+        let size = usize::from(channels) / count;
+        let remainder = usize::from(channels) % count;
+
+        let mut offset = 0;
+        let groups = (0..count)
+            .map(|idx| {
+                // The first `remainder` groups take one extra channel
+                // each.
+                let group_channels = size + usize::from(idx < remainder);
+                // `group_channels` is at most `channels`, so the
+                // conversion can't fail.
+                let resampler =
+                    Resampler::new(converter_type, group_channels as u8, from_rate, to_rate)?;
+                let group = Group {
+                    resampler,
+                    offset,
+                    input: Vec::new(),
+                    output: Vec::new(),
+                };
+                offset += group_channels;
+                Ok(group)
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        let slf = Self {
+            groups,
+            channels,
+            dither: None,
+            scratch: (Vec::new(), Vec::new()),
+        };
+        Ok(slf)
+    }
+
+    /// Retrieve the number of channel groups converted in parallel.
+    #[inline]
+    pub fn groups(&self) -> usize {
+        self.groups.len()
+    }
+
+    /// Retrieve the number of channels the converter works with.
+    #[inline]
+    pub fn channels(&self) -> u8 {
+        self.channels
+    }
+
+    /// Retrieve the converter's latency.
+    ///
+    /// See [`Resampler::latency`] for details.
+    pub fn latency(&self) -> Latency {
+        // All groups share the same configuration, so any of them is
+        // representative. `with_groups` creates at least one group, so
+        // indexing is safe.
+        self.groups[0].resampler.latency()
+    }
+
+    /// Convert all channel groups in parallel and interleave their
+    /// output.
+    fn process_impl(
+        &mut self,
+        input: &[f32],
+        output: &mut [f32],
+        finalize: bool,
+    ) -> Result<Processed, Error> {
+        let () = check_frame_aligned(input.len(), self.channels, ErrorKind::InputNotFrameAligned)?;
+        let () = check_frame_aligned(
+            output.len(),
+            self.channels,
+            ErrorKind::OutputNotFrameAligned,
+        )?;
+
+        let channels = usize::from(self.channels);
+        let output_frames = output.len() / channels;
+        let results = self
+            .groups
+            .par_iter_mut()
+            .map(|group| group.process(input, channels, output_frames, finalize))
+            .collect::<Vec<_>>();
+
+        if let Some(err) = results
+            .iter()
+            .find_map(|result| result.as_ref().err().copied())
+        {
+            // Groups that succeeded have advanced while others may not
+            // have. Reset all of them so that they stay in sync.
+            let () = self.reset()?;
+            return Err(err)
+        }
+
+        let mut frames = None;
+        for result in results {
+            // We checked for errors above.
+            let processed = result.unwrap();
+            // All groups are configured identically and see the same
+            // number of frames, so they make the same progress.
+            debug_assert!(frames.as_ref().is_none_or(|frames| *frames == processed));
+            frames = Some(processed);
+        }
+        let frames = frames.unwrap_or_default();
+
+        for group in &self.groups {
+            let group_channels = usize::from(group.resampler.channels());
+            let () = output
+                .chunks_exact_mut(channels)
+                .zip(group.output.chunks_exact(group_channels))
+                .take(frames.written)
+                .for_each(|(frame, samples)| {
+                    frame[group.offset..group.offset + group_channels].copy_from_slice(samples)
+                });
+        }
+
+        let processed = Processed {
+            read: frames.read * channels,
+            written: frames.written * channels,
+        };
+        Ok(processed)
+    }
+
+    /// Invoke `f` with `input` and `output` represented as `f32`
+    /// samples, converting them via scratch buffers if necessary.
+    fn with_float<S, F>(&mut self, input: &[S], output: &mut [S], f: F) -> Result<Processed, Error>
+    where
+        S: Sample,
+        F: FnOnce(&mut Self, &[f32], &mut [f32]) -> Result<Processed, Error>,
+    {
+        if let (Some(input), Some(output)) = (S::as_float(input), S::as_float_mut(output)) {
+            return f(self, input, output)
+        }
+
+        let (mut in_buf, mut out_buf) = take(&mut self.scratch);
+        let () = in_buf.clear();
+        let () = in_buf.resize(input.len(), 0.0);
+        let () = S::to_float(input, &mut in_buf);
+        let () = out_buf.clear();
+        let () = out_buf.resize(output.len(), 0.0);
+
+        let result = f(self, &in_buf, &mut out_buf);
+        if let Ok(processed) = &result {
+            let written = processed.written;
+            if let Some(ditherer) = &mut self.dither {
+                let () = ditherer.apply_for::<S>(&mut out_buf[..written]);
+            }
+            let () = S::from_float(&out_buf[..written], &mut output[..written]);
+        }
+
+        self.scratch = (in_buf, out_buf);
+        result
+    }
+
+    /// Set the dither to apply when producing integer output.
+    ///
+    /// See [`Resampler::set_dither`] for details.
+    pub fn set_dither(&mut self, dither: Dither, seed: u64) {
+        self.dither = (dither != Dither::None).then(|| Ditherer::new(dither, seed, self.channels));
+    }
+
+    /// Perform a samplerate conversion on a block of data.
+    ///
+    /// Should the conversion of any channel group fail, all groups are
+    /// reset, discarding any input buffered so far, to keep them in
+    /// sync.
+    ///
+    /// See [`Resampler::process`] for details.
+    pub fn process<S>(&mut self, input: &[S], output: &mut [S]) -> Result<Processed, Error>
+    where
+        S: Sample,
+    {
+        self.with_float(input, output, |slf, input, output| {
+            slf.process_impl(input, output, false)
+        })
+    }
+
+    /// Perform a samplerate conversion on last block of given input
+    /// data (which may be empty).
+    ///
+    /// Errors are handled as by [`ParallelResampler::process`]. See
+    /// [`Resampler::finalize`] for details.
+    pub fn finalize<S>(&mut self, input: &[S], output: &mut [S]) -> Result<Processed, Error>
+    where
+        S: Sample,
+    {
+        self.with_float(input, output, |slf, input, output| {
+            slf.process_impl(input, output, true)
+        })
+    }
+
+    /// Reset the internal state of all converters.
+    pub fn reset(&mut self) -> Result<(), Error> {
+        let () = self
+            .groups
+            .iter_mut()
+            .try_for_each(|group| group.resampler.reset())?;
+        if let Some(ditherer) = &mut self.dither {
+            let () = ditherer.reset();
+        }
+        Ok(())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::ChannelMix;
+
+
+    /// Check that channels are split into the expected groups.
+    #[test]
+    fn grouping() {
+        let resampler =
+            ParallelResampler::with_groups(ResampleType::Linear, 10, 44100, 48000, 4).unwrap();
+        let groups = resampler
+            .groups
+            .iter()
+            .map(|group| (group.offset, group.resampler.channels()))
+            .collect::<Vec<_>>();
+        assert_eq!(groups, [(0, 3), (3, 3), (6, 2), (8, 2)]);
+
+        let resampler =
+            ParallelResampler::with_groups(ResampleType::Linear, 2, 44100, 48000, 0).unwrap();
+        assert_eq!(resampler.groups(), 1);
+
+        let resampler =
+            ParallelResampler::with_groups(ResampleType::Linear, 2, 44100, 48000, 16).unwrap();
+        assert_eq!(resampler.groups(), 2);
+
+        let err = ParallelResampler::new(ResampleType::Linear, 0, 44100, 48000).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::BadChannelCount);
+    }
+
+    /// Check that parallel conversion produces the exact same output as
+    /// conversion on a single thread.
+    #[test]
+    fn serial_equivalence() {
+        const CHANNELS: u8 = 32;
+
+        let channels = usize::from(CHANNELS);
+        let input = (0..4800)
+            .flat_map(|i| (0..channels).map(move |ch| ((i * (ch + 1)) as f32 / 100.0).sin() * 0.5))
+            .collect::<Vec<f32>>();
+
+        for type_ in [
+            ResampleType::SincBestQuality,
+            ResampleType::SincMediumQuality,
+            ResampleType::SincFastest,
+            ResampleType::ZeroOrderHold,
+            ResampleType::Linear,
+        ] {
+            let mut serial = Resampler::new(type_, CHANNELS, 48000, 44100).unwrap();
+            let mut parallel =
+                ParallelResampler::with_groups(type_, CHANNELS, 48000, 44100, 5).unwrap();
+
+            let mut expected = vec![0.0f32; channels * 512];
+            let mut output = vec![0.0f32; channels * 512];
+            let mut rest = input.as_slice();
+            loop {
+                let processed = serial.finalize(rest, &mut expected).unwrap();
+                let parallel_processed = parallel.finalize(rest, &mut output).unwrap();
+                assert_eq!(processed, parallel_processed, "{type_:?}");
+                assert_eq!(
+                    output[..processed.written],
+                    expected[..processed.written],
+                    "{type_:?}"
+                );
+
+                rest = &rest[processed.read..];
+                if processed.written == 0 && rest.is_empty() {
+                    break
+                }
+            }
+        }
+    }
+
+    /// Generate a stereo test signal for segmented conversion.
+    fn segment_signal() -> Vec<f32> {
+        (0..50_000)
+            .flat_map(|i| {
+                let x = i as f32 / 20.0;
+                [x.sin() * 0.5, (x * 0.3).cos() * 0.5]
+            })
+            .collect()
+    }
+
+    /// Check that we calculate output frame counts correctly, even if
+    /// intermediate products exceed 32 bit.
+    #[test]
+    fn output_frame_count() {
+        assert_eq!(output_frames(0, 44100, 48000), 0);
+        assert_eq!(output_frames(44100, 44100, 48000), 48000);
+        assert_eq!(output_frames(100_000_000, 44100, 48000), 108_843_537);
+        assert_eq!(output_frames(100_000_000, 48000, 16000), 33_333_333);
+    }
+
+    /// Check that segmented conversion exactly matches serial conversion
+    /// for integer ratios.
+    #[test]
+    fn segmented_equivalence_integer() {
+        let input = segment_signal();
+
+        for type_ in [
+            ResampleType::SincBestQuality,
+            ResampleType::SincMediumQuality,
+            ResampleType::SincFastest,
+            ResampleType::ZeroOrderHold,
+            ResampleType::Linear,
+        ] {
+            for (from_rate, to_rate) in [(44100, 88200), (48000, 16000)] {
+                let expected = convert(type_, 2, from_rate, to_rate, &input).unwrap();
+                let output = convert_segmented(type_, 2, from_rate, to_rate, &input, 7).unwrap();
+                assert_eq!(output, expected, "{type_:?}: {from_rate} -> {to_rate}");
+            }
+        }
+    }
+
+    /// Check that segmented conversion matches serial conversion.
+    #[test]
+    fn segmented_equivalence() {
+        let input = segment_signal();
+
+        for type_ in [
+            ResampleType::SincBestQuality,
+            ResampleType::SincMediumQuality,
+            ResampleType::SincFastest,
+            ResampleType::ZeroOrderHold,
+            ResampleType::Linear,
+        ] {
+            let expected = convert(type_, 2, 44100, 48000, &input).unwrap();
+            let output = convert_segmented(type_, 2, 44100, 48000, &input, 7).unwrap();
+            assert_eq!(output.len(), expected.len(), "{type_:?}");
+
+            let max_diff = output
+                .iter()
+                .zip(&expected)
+                .fold(0f32, |max, (a, b)| max.max((a - b).abs()));
+            assert!(max_diff < 1e-6, "{type_:?}: {max_diff}");
+        }
+
+        let output = convert_parallel(ResampleType::Linear, 2, 1, 2, &[0i16; 0]).unwrap();
+        assert!(output.is_empty());
+
+        let err = convert_parallel(ResampleType::Linear, 2, 1, 2, &[0i16; 3]).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InputNotFrameAligned);
+    }
+
+    /// Make sure that all channel groups are reset when the conversion
+    /// of one of them fails.
+    #[test]
+    fn group_failure() {
+        let input = (0..4 * 999)
+            .map(|i| (i as f32 / 50.0).sin() * 0.5)
+            .collect::<Vec<f32>>();
+        let mut output = [0.0f32; 4 * 2048];
+
+        let mut resampler =
+            ParallelResampler::with_groups(ResampleType::SincFastest, 4, 44100, 48000, 2).unwrap();
+        // Have the second group expect two input channels for each one
+        // it is fed, so that it rejects the odd number of frames.
+        resampler.groups[1].resampler = Resampler::with_channel_mix(
+            ResampleType::SincFastest,
+            ChannelMix::stereo_to_mono(),
+            44100,
+            48000,
+        )
+        .unwrap();
+
+        let err = resampler.process(&input, &mut output).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InputNotFrameAligned);
+
+        // The first group succeeded, but must have been reset
+        // nevertheless.
+        let group_input = input
+            .chunks_exact(4)
+            .flat_map(|frame| [frame[0], frame[1]])
+            .collect::<Vec<f32>>();
+        let mut fresh = Resampler::new(ResampleType::SincFastest, 2, 44100, 48000).unwrap();
+        let mut expected = [0.0f32; 2 * 2048];
+        let mut group_output = [0.0f32; 2 * 2048];
+        let processed = resampler.groups[0]
+            .resampler
+            .process(&group_input, &mut group_output)
+            .unwrap();
+        let fresh_processed = fresh.process(&group_input, &mut expected).unwrap();
+        assert_eq!(processed, fresh_processed);
+        assert_eq!(
+            group_output[..processed.written],
+            expected[..processed.written]
+        );
+    }
+
+    /// Check that integer output is dithered just like by a single
+    /// converter working on all channels.
+    #[test]
+    fn dithering() {
+        let input = (0..4 * 2000)
+            .map(|i| ((i as f32 / 50.0).sin() * 8.0) as i16)
+            .collect::<Vec<_>>();
+        let mut output = vec![0i16; 4 * 4096];
+        let mut expected = vec![0i16; 4 * 4096];
+
+        let mut serial = Resampler::new(ResampleType::SincFastest, 4, 44100, 48000).unwrap();
+        let () = serial.set_dither(Dither::Triangular, 9);
+        let mut parallel =
+            ParallelResampler::with_groups(ResampleType::SincFastest, 4, 44100, 48000, 2).unwrap();
+        let () = parallel.set_dither(Dither::Triangular, 9);
+
+        for _ in 0..2 {
+            let processed = parallel.finalize(&input, &mut output).unwrap();
+            let serial_processed = serial.finalize(&input, &mut expected).unwrap();
+            assert_eq!(processed, serial_processed);
+            assert_eq!(output, expected);
+
+            // Resetting restarts the noise sequence.
+            let () = parallel.reset().unwrap();
+            let () = serial.reset().unwrap();
+        }
+    }
+
+    /// Check that non-`f32` samples are supported.
+    #[test]
+    fn integer_samples() {
+        let input = [i16::MAX / 2; 4 * 100];
+        let mut output = [0i16; 4 * 300];
+        let mut resampler =
+            ParallelResampler::with_groups(ResampleType::ZeroOrderHold, 4, 1, 2, 2).unwrap();
+        let processed = resampler.finalize(&input, &mut output).unwrap();
+        assert_eq!(processed.read, input.len());
+        assert!(processed.written >= 2 * input.len());
+        assert!(output[..processed.written]
+            .iter()
+            .all(|sample| *sample == i16::MAX / 2));
+
+        let () = resampler.reset().unwrap();
+        let err = resampler.process(&input[..3], &mut output).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InputNotFrameAligned);
+    }
+}
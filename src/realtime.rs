@@ -0,0 +1,434 @@
+use crate::dither::Dither;
+use crate::error::Error;
+use crate::error::ErrorKind;
+use crate::resample_type::ResampleType;
+use crate::resampler::check_frame_aligned;
+use crate::resampler::planar_frames;
+use crate::resampler::Processed;
+use crate::resampler::Resampler;
+use crate::sample::Sample;
+
+
+/// A first-in-first-out queue of samples backed by a fixed size
+/// buffer.
+#[derive(Debug)]
+struct Fifo {
+    buffer: Box<[f32]>,
+    /// The index of the first queued sample.
+    start: usize,
+    /// The index one past the last queued sample.
+    end: usize,
+}
+
+impl Fifo {
+    fn new(len: usize) -> Self {
+        Self {
+            buffer: vec![0.0; len].into_boxed_slice(),
+            start: 0,
+            end: 0,
+        }
+    }
+
+    /// Retrieve the queued samples.
+    #[inline]
+    fn data(&self) -> &[f32] {
+        &self.buffer[self.start..self.end]
+    }
+
+    /// Retrieve the queued samples, mutably.
+    #[inline]
+    fn data_mut(&mut self) -> &mut [f32] {
+        &mut self.buffer[self.start..self.end]
+    }
+
+    /// Remove `count` samples from the front of the queue.
+    #[inline]
+    fn consume(&mut self, count: usize) {
+        debug_assert!(count <= self.end - self.start);
+        self.start += count;
+
+        if self.start == self.end {
+            self.start = 0;
+            self.end = 0;
+        }
+    }
+
+    /// Retrieve the free space at the back of the queue, moving the
+    /// queued samples to the front of the buffer first.
+    fn space(&mut self) -> &mut [f32] {
+        if self.start > 0 {
+            let () = self.buffer.copy_within(self.start..self.end, 0);
+            self.end -= self.start;
+            self.start = 0;
+        }
+        &mut self.buffer[self.end..]
+    }
+
+    /// Add `count` samples previously written to the free space to the
+    /// queue.
+    #[inline]
+    fn commit(&mut self, count: usize) {
+        debug_assert!(count <= self.buffer.len() - self.end);
+        self.end += count;
+    }
+
+    /// Remove all queued samples.
+    #[inline]
+    fn clear(&mut self) {
+        self.start = 0;
+        self.end = 0;
+    }
+}
+
+
+/// A samplerate converter suitable for use in real time contexts, such
+/// as audio device callbacks.
+///
+/// All buffers are allocated up front, when the object is created.
+/// Afterwards, [`process`][Self::process] and
+/// [`process_planar`][Self::process_planar] neither allocate memory nor
+/// acquire locks, irrespective of the sample format used. Rather than
+/// panicking, misuse is reported as an error.
+///
+/// Input and output are passed through internal queues, so blocks of
+/// arbitrary size (up to the maximum provided at construction time) can
+/// be used. Input that does not fit into the input queue is not
+/// consumed and output that does not fit into the provided buffer is
+/// retained for the next call.
+///
+/// # Example
+///
+/// ```
+/// use resample::{RealtimeResampler, ResampleType};
+///
+/// let mut resampler =
+///     RealtimeResampler::new(ResampleType::SincFastest, 2, 44100, 48000, 512).unwrap();
+///
+/// // Blocks of varying size, as they may be handed out by an audio
+/// // device.
+/// let input = [0i16; 2 * 441];
+/// let mut output = [0i16; 2 * 480];
+/// for _ in 0..10 {
+///     let processed = resampler.process(&input, &mut output).unwrap();
+///     assert_eq!(processed.read, input.len());
+///     assert!(processed.written <= output.len());
+/// }
+/// ```
+#[derive(Debug)]
+pub struct RealtimeResampler {
+    resampler: Resampler,
+    /// Input not yet consumed by the converter.
+    input: Fifo,
+    /// Output not yet handed out.
+    output: Fifo,
+}
+
+impl RealtimeResampler {
+    /// Create a new real time samplerate converter assuming the given
+    /// channel count and sample rates.
+    ///
+    /// `max_frames` is the maximum number of frames per block the
+    /// converter is expected to be used with. It determines the size of
+    /// the internal queues.
+    pub fn new(
+        converter_type: ResampleType,
+        channels: u8,
+        from_rate: u32,
+        to_rate: u32,
+        max_frames: usize,
+    ) -> Result<Self, Error> {
+        let resampler = Resampler::new(converter_type, channels, from_rate, to_rate)?;
+        let channels = usize::from(channels);
+        let in_len = max_frames * channels;
+        let out_len = resampler.max_output_frames(max_frames) * channels;
+
+        let slf = Self {
+            resampler,
+            input: Fifo::new(in_len),
+            output: Fifo::new(out_len),
+        };
+        Ok(slf)
+    }
+
+    /// Feed queued input to the converter.
+    fn convert(&mut self) -> Result<(), Error> {
+        loop {
+            // NB: We invoke the converter even without queued input, as
+            //     it may have buffered input internally that it has not
+            //     been able to produce output for yet.
+            let processed = self
+                .resampler
+                .process(self.input.data(), self.output.space())?;
+            let () = self.input.consume(processed.read);
+            let () = self.output.commit(processed.written);
+
+            if processed.read == 0 && processed.written == 0 {
+                break Ok(())
+            }
+        }
+    }
+
+    /// Perform a samplerate conversion on a block of data.
+    ///
+    /// If the number of channels used was not `1` (Mono), the samples
+    /// are expected to be stored interleaved. Both `input` and `output`
+    /// need to hold a whole number of frames.
+    ///
+    /// The returned [`Processed`] object reports the number of samples
+    /// taken from `input` and the number of samples written to
+    /// `output`.
+    pub fn process<S>(&mut self, input: &[S], output: &mut [S]) -> Result<Processed, Error>
+    where
+        S: Sample,
+    {
+        let channels = self.resampler.channels();
+        let () = check_frame_aligned(input.len(), channels, ErrorKind::InputNotFrameAligned)?;
+        let () = check_frame_aligned(output.len(), channels, ErrorKind::OutputNotFrameAligned)?;
+
+        let space = self.input.space();
+        // All queue sizes and offsets are multiples of the channel
+        // count, so this count is frame aligned.
+        let read = input.len().min(space.len());
+        let () = S::to_float(&input[..read], &mut space[..read]);
+        let () = self.input.commit(read);
+
+        let () = self.convert()?;
+
+        let data = self.output.data_mut();
+        let written = output.len().min(data.len());
+        if let Some(ditherer) = self.resampler.ditherer_mut() {
+            let () = ditherer.apply_for::<S>(&mut data[..written]);
+        }
+        let () = S::from_float(&data[..written], &mut output[..written]);
+        let () = self.output.consume(written);
+
+        Ok(Processed { read, written })
+    }
+
+    /// Perform a samplerate conversion on a block of planar data.
+    ///
+    /// `input` and `output` are expected to contain one buffer per
+    /// channel, with all buffers of each being of equal length.
+    /// Contrary to [`process`][Self::process], the returned
+    /// [`Processed`] object reports counts in frames, i.e., per
+    /// channel.
+    pub fn process_planar(
+        &mut self,
+        input: &[&[f32]],
+        output: &mut [&mut [f32]],
+    ) -> Result<Processed, Error> {
+        let channels = self.resampler.channels();
+        let in_frames = planar_frames(input, channels, ErrorKind::InputNotFrameAligned)?;
+        let out_frames = planar_frames(output, channels, ErrorKind::OutputNotFrameAligned)?;
+        let channels = usize::from(channels);
+
+        let space = self.input.space();
+        let read = in_frames.min(space.len() / channels);
+        for (channel, input) in input.iter().enumerate() {
+            let samples = space.iter_mut().skip(channel).step_by(channels);
+            let () = samples
+                .zip(&input[..read])
+                .for_each(|(sample, input)| *sample = *input);
+        }
+        let () = self.input.commit(read * channels);
+
+        let () = self.convert()?;
+
+        let data = self.output.data();
+        let written = out_frames.min(data.len() / channels);
+        for (channel, output) in output.iter_mut().enumerate() {
+            let samples = data.iter().skip(channel).step_by(channels);
+            let () = output[..written]
+                .iter_mut()
+                .zip(samples)
+                .for_each(|(output, sample)| *output = *sample);
+        }
+        let () = self.output.consume(written * channels);
+
+        Ok(Processed { read, written })
+    }
+
+    /// Reset the internal converter's state, discarding all queued
+    /// input and output.
+    pub fn reset(&mut self) -> Result<(), Error> {
+        let () = self.resampler.reset()?;
+        let () = self.input.clear();
+        let () = self.output.clear();
+        Ok(())
+    }
+
+    /// Set the conversion ratio to use from now on, without a gradual
+    /// transition from the previous one.
+    ///
+    /// The ratio is defined as output samplerate divided by input
+    /// samplerate. Note that the size of the output queue is determined
+    /// based on the ratio in use at construction time. A larger ratio
+    /// may result in less input being consumed per call.
+    pub fn set_ratio(&mut self, ratio: f64) -> Result<(), Error> {
+        self.resampler.set_ratio(ratio)
+    }
+
+    /// Set the dither to apply when producing integer output.
+    ///
+    /// See [`Resampler::set_dither`] for details. Contrary to the
+    /// conversion itself, this method allocates and should be invoked
+    /// before the converter is used in a real time context.
+    pub fn set_dither(&mut self, dither: Dither, seed: u64) {
+        self.resampler.set_dither(dither, seed)
+    }
+
+    /// Retrieve the number of channels the converter works with.
+    #[inline]
+    pub fn channels(&self) -> u8 {
+        self.resampler.channels()
+    }
+
+    /// Retrieve the number of output samples queued up and ready to be
+    /// retrieved.
+    #[inline]
+    pub fn queued_output(&self) -> usize {
+        self.output.data().len()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::f32::consts::PI;
+
+    use crate::convert;
+
+
+    /// Check that the queue handles interleaved pushing and popping
+    /// correctly.
+    #[test]
+    fn fifo() {
+        let mut fifo = Fifo::new(4);
+        let () = fifo.space()[..3].copy_from_slice(&[1.0, 2.0, 3.0]);
+        let () = fifo.commit(3);
+        let () = fifo.consume(2);
+        assert_eq!(fifo.data(), [3.0]);
+
+        let space = fifo.space();
+        assert_eq!(space.len(), 3);
+        let () = space.copy_from_slice(&[4.0, 5.0, 6.0]);
+        let () = fifo.commit(3);
+        assert_eq!(fifo.data(), [3.0, 4.0, 5.0, 6.0]);
+        assert!(fifo.space().is_empty());
+    }
+
+    /// Check that real time conversion with varying block sizes
+    /// produces the same output as conversion in one go.
+    #[test]
+    fn realtime_conversion() {
+        let freq = PI * 880f32 / 44100f32;
+        let input = (0..20_000)
+            .map(|i| (freq * i as f32).sin())
+            .collect::<Vec<f32>>();
+
+        for type_ in [
+            ResampleType::SincFastest,
+            ResampleType::ZeroOrderHold,
+            ResampleType::Linear,
+        ] {
+            let reference = convert(type_, 1, 44100, 48000, &input).unwrap();
+            let mut resampler = RealtimeResampler::new(type_, 1, 44100, 48000, 512).unwrap();
+
+            let mut output = Vec::new();
+            let mut buffer = [0.0f32; 512];
+            let mut remaining = input.as_slice();
+            let mut sizes = [17, 512, 300, 1, 64].into_iter().cycle();
+            while !remaining.is_empty() {
+                let size = sizes.next().unwrap();
+                let block = &remaining[..size.min(remaining.len())];
+                let processed = resampler.process(block, &mut buffer[..size]).unwrap();
+                let () = output.extend_from_slice(&buffer[..processed.written]);
+                remaining = &remaining[processed.read..];
+            }
+
+            loop {
+                let processed = resampler.process(&[], &mut buffer).unwrap();
+                if processed.written == 0 {
+                    break
+                }
+                let () = output.extend_from_slice(&buffer[..processed.written]);
+            }
+
+            // Without finalization we lack the tail, but everything up to
+            // that point should match.
+            assert!(output.len() + 64 > reference.len(), "{type_:?}");
+            let max_diff = output
+                .iter()
+                .zip(&reference)
+                .fold(0f32, |max, (a, b)| max.max((a - b).abs()));
+            assert!(max_diff < 1e-5, "{type_:?}: {max_diff}");
+        }
+    }
+
+    /// Check that planar processing works as expected.
+    #[test]
+    fn realtime_planar() {
+        let mut resampler =
+            RealtimeResampler::new(ResampleType::ZeroOrderHold, 2, 1, 2, 16).unwrap();
+        let left = [1.0f32; 8];
+        let right = [-1.0f32; 8];
+        let mut out_left = [0.0f32; 16];
+        let mut out_right = [0.0f32; 16];
+
+        let processed = resampler
+            .process_planar(&[&left, &right], &mut [&mut out_left, &mut out_right])
+            .unwrap();
+        assert_eq!(processed.read, 8);
+        assert!(processed.written > 0);
+        assert!(out_left[..processed.written].iter().all(|x| *x == 1.0));
+        assert!(out_right[..processed.written].iter().all(|x| *x == -1.0));
+
+        let err = resampler
+            .process_planar(&[&left], &mut [&mut out_left, &mut out_right])
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ChannelCountMismatch);
+    }
+
+    /// Check that integer output gets dithered as configured.
+    #[test]
+    fn realtime_dithering() {
+        // A square wave of one quantization step, which linear
+        // interpolation turns into a signal mostly in between steps.
+        let input = (0..20_000)
+            .map(|i| i16::from(i % 2 == 0))
+            .collect::<Vec<_>>();
+        let convert = |dither| {
+            let mut resampler = RealtimeResampler::new(ResampleType::Linear, 1, 3, 4, 256).unwrap();
+            let () = resampler.set_dither(dither, 1);
+            let mut output = Vec::new();
+            let mut buffer = [0i16; 256];
+            let mut remaining = input.as_slice();
+            while !remaining.is_empty() {
+                let block = &remaining[..199.min(remaining.len())];
+                let processed = resampler.process(block, &mut buffer).unwrap();
+                let () = output.extend_from_slice(&buffer[..processed.written]);
+                remaining = &remaining[processed.read..];
+            }
+            output.iter().map(|x| f64::from(*x)).sum::<f64>() / output.len() as f64
+        };
+
+        let plain = convert(Dither::None);
+        let dithered = convert(Dither::Triangular);
+        assert!((dithered - 0.5).abs() < 0.02, "{dithered}");
+        assert!((dithered - 0.5).abs() < (plain - 0.5).abs());
+    }
+
+    /// Make sure that input exceeding the queue's capacity is not
+    /// consumed.
+    #[test]
+    fn realtime_backpressure() {
+        let mut resampler = RealtimeResampler::new(ResampleType::Linear, 1, 1, 2, 16).unwrap();
+        let input = [0.5f32; 64];
+        let processed = resampler.process(&input, &mut []).unwrap();
+        assert!(processed.read < input.len());
+        assert_eq!(processed.written, 0);
+        assert!(resampler.queued_output() > 0);
+    }
+}
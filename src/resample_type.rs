@@ -48,6 +48,44 @@ impl ResampleType {
         // SAFETY: `ptr` is not NULL and guaranteed to be valid.
         unsafe { CStr::from_ptr(ptr) }.to_str().unwrap()
     }
+
+    /// Check whether the converter supports the double precision
+    /// processing path, i.e., [`Resampler::process_f64`] and friends.
+    ///
+    /// The filter coefficients of the best quality sinc converter are
+    /// not available in double precision, so it is the only one not
+    /// supporting it.
+    ///
+    /// [`Resampler::process_f64`]: crate::Resampler::process_f64
+    pub fn supports_f64(&self) -> bool {
+        match self {
+            Self::SincMediumQuality | Self::SincFastest | Self::ZeroOrderHold | Self::Linear => {
+                true
+            },
+            Self::SincBestQuality => false,
+        }
+    }
+
+    /// Retrieve the maximum number of input frames the converter
+    /// considers on either side of the current position, when
+    /// converting with the given ratio.
+    pub(crate) fn half_filter_frames(&self, ratio: f64) -> usize {
+        // The number of filter coefficients and the increment at which
+        // they are stepped through, as defined by the respective sinc
+        // coefficient tables.
+        let (coeffs, increment) = match self {
+            Self::SincBestQuality => (340239, 2381),
+            Self::SincMediumQuality => (22438, 491),
+            Self::SincFastest => (2464, 128),
+            Self::ZeroOrderHold | Self::Linear => return 0,
+        };
+
+        // This logic mirrors the calculation of the half filter length
+        // in `libsamplerate`'s sinc converter, which widens the filter
+        // when downsampling.
+        let count = f64::from(coeffs) / f64::from(increment) / ratio.min(1.0);
+        count.round_ties_even() as usize + 1
+    }
 }
 
 
@@ -74,6 +112,27 @@ mod tests {
         assert_eq!(ResampleType::Linear.name(), "Linear Interpolator");
     }
 
+    /// Check which converters support double precision processing.
+    #[test]
+    fn supports_f64() {
+        assert!(!ResampleType::SincBestQuality.supports_f64());
+        assert!(ResampleType::SincMediumQuality.supports_f64());
+        assert!(ResampleType::SincFastest.supports_f64());
+        assert!(ResampleType::ZeroOrderHold.supports_f64());
+        assert!(ResampleType::Linear.supports_f64());
+    }
+
+    /// Check that the half filter length is calculated as expected.
+    #[test]
+    fn half_filter_frames() {
+        assert_eq!(ResampleType::SincBestQuality.half_filter_frames(1.0), 144);
+        assert_eq!(ResampleType::SincMediumQuality.half_filter_frames(2.0), 47);
+        assert_eq!(ResampleType::SincFastest.half_filter_frames(1.0), 20);
+        assert_eq!(ResampleType::SincFastest.half_filter_frames(0.5), 39);
+        assert_eq!(ResampleType::ZeroOrderHold.half_filter_frames(0.5), 0);
+        assert_eq!(ResampleType::Linear.half_filter_frames(8.0), 0);
+    }
+
     #[test]
     fn description() {
         assert_eq!(
@@ -1,18 +1,34 @@
+use std::ffi::c_long;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
+use std::mem::take;
+
+use libsamplerate_rs::src_clone;
 use libsamplerate_rs::src_delete;
 use libsamplerate_rs::src_is_valid_ratio;
 use libsamplerate_rs::src_new;
 use libsamplerate_rs::src_process;
 use libsamplerate_rs::src_reset;
+use libsamplerate_rs::src_set_ratio;
 use libsamplerate_rs::SRC_DATA;
 use libsamplerate_rs::SRC_STATE;
 
+use crate::builder::ResamplerBuilder;
+use crate::dither::Dither;
+use crate::dither::Ditherer;
+use crate::double::DoubleConverter;
 use crate::error::Error;
 use crate::error::ErrorKind;
+use crate::mix::ChannelMix;
+use crate::mix::Mix;
+use crate::mix::Mixable;
 use crate::resample_type::ResampleType;
+use crate::sample::Sample;
 
 
 /// A type representing the result of a samplerate conversion.
-#[derive(Debug, Default)]
+#[derive(Debug, Default, PartialEq, Eq)]
 pub struct Processed {
     /// The number of input samples read.
     pub read: usize,
@@ -21,10 +37,246 @@ pub struct Processed {
 }
 
 
+/// The policy determining the overall number of frames a conversion
+/// produces.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OutputLength {
+    /// Produce as many frames as the converter naturally does. For the
+    /// zero order hold and linear converters, that may be a few frames
+    /// more or less than the input length corresponds to.
+    #[default]
+    Natural,
+    /// Produce exactly `ceil(input_frames * ratio)` frames, dropping
+    /// excess output and padding missing output with silence.
+    Exact,
+    /// Produce exactly `ceil(input_frames * ratio)` frames, like
+    /// [`Exact`][Self::Exact], with the output aligned to the input as
+    /// per [`Resampler::set_delay_compensation`].
+    ExactAlignedToDelay,
+}
+
+
+/// The latency introduced by a samplerate converter.
+///
+/// The latency describes the amount of input a converter holds back
+/// before the corresponding output gets produced, in terms of both
+/// input and (approximate) output frames.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Latency {
+    /// The latency in input frames.
+    pub input_frames: usize,
+    /// The latency in output frames.
+    pub output_frames: usize,
+}
+
+
+/// Check that the given conversion ratio is supported by
+/// `libsamplerate`.
+pub(crate) fn check_ratio(ratio: f64) -> Result<(), Error> {
+    // NB: `libsamplerate` does not catch NaN ratios, so we do.
+    // SAFETY: `src_is_valid_ratio` is always safe to call.
+    if ratio.is_nan() || unsafe { src_is_valid_ratio(ratio) } == 0 {
+        return Err(Error::from(ErrorKind::BadSrcRatio))
+    }
+    Ok(())
+}
+
+
+/// Calculate the maximum number of frames a converter of type
+/// `converter_type` may produce when flushing, in addition to the
+/// output corresponding to its input, at the given ratio.
+pub(crate) fn tail_frames(converter_type: ResampleType, ratio: f64) -> usize {
+    let half_filter = converter_type.half_filter_frames(ratio);
+    (half_filter as f64 * ratio).ceil() as usize + 1
+}
+
+
+/// Calculate the number of output frames `input_frames` frames of
+/// input correspond to at the given ratio, rounding up.
+pub(crate) fn expected_frames(input_frames: usize, ratio: f64) -> usize {
+    (input_frames as f64 * ratio - LENGTH_TOLERANCE)
+        .ceil()
+        .max(0.0) as usize
+}
+
+
+/// Calculate the number of output frames `input_frames` frames of
+/// input correspond to when converting from `from_rate` to `to_rate`,
+/// rounding up.
+pub(crate) fn expected_rate_frames(input_frames: usize, from_rate: u32, to_rate: u32) -> usize {
+    // NB: The product may not fit into a 32 bit `usize`.
+    let frames = (input_frames as u64 * u64::from(to_rate)).div_ceil(u64::from(from_rate));
+    usize::try_from(frames).unwrap_or(usize::MAX)
+}
+
+
+/// Check that the given channel count is valid.
+pub(crate) fn check_channels(channels: u8) -> Result<(), Error> {
+    // NB: Not all of `libsamplerate`'s converters check the channel
+    //     count themselves.
+    if channels == 0 {
+        return Err(Error::from(ErrorKind::BadChannelCount))
+    }
+    Ok(())
+}
+
+/// Check that a buffer of `len` samples contains a whole number of
+/// frames, reporting `kind` if it does not.
+pub(crate) fn check_frame_aligned(len: usize, channels: u8, kind: ErrorKind) -> Result<(), Error> {
+    if !len.is_multiple_of(usize::from(channels)) {
+        return Err(Error::from(kind))
+    }
+    Ok(())
+}
+
+/// Check that `buffers` contains one buffer per channel, with all of
+/// them being of equal length, reporting `kind` if they are not.
+///
+/// On success, the number of frames is returned.
+pub(crate) fn planar_frames<T>(buffers: &[T], channels: u8, kind: ErrorKind) -> Result<usize, Error>
+where
+    T: AsRef<[f32]>,
+{
+    if buffers.len() != usize::from(channels) {
+        return Err(Error::from(ErrorKind::ChannelCountMismatch))
+    }
+
+    let frames = buffers
+        .first()
+        .map(|buffer| buffer.as_ref().len())
+        .unwrap_or(0);
+    if buffers.iter().any(|buffer| buffer.as_ref().len() != frames) {
+        return Err(Error::from(kind))
+    }
+    Ok(frames)
+}
+
+/// Convert a frame count into the representation used by
+/// `libsamplerate`.
+///
+/// Counts exceeding the representable range are clamped, which is
+/// fine, because `libsamplerate` reports how much it actually
+/// processed.
+#[inline]
+fn to_src_frames(frames: usize) -> c_long {
+    c_long::try_from(frames).unwrap_or(c_long::MAX)
+}
+
+/// Convert a frame count reported by `libsamplerate` into a `usize`.
+#[inline]
+fn from_src_frames(frames: c_long) -> usize {
+    // `libsamplerate` never reports negative counts.
+    usize::try_from(frames).unwrap_or(0)
+}
+
+
+/// Scratch buffers used for converting samples to and from `f32`.
+#[derive(Default)]
+struct Scratch {
+    input: Vec<f32>,
+    output: Vec<f32>,
+}
+
+impl Debug for Scratch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("Scratch").finish_non_exhaustive()
+    }
+}
+
+
+/// The number of output frames by which the expected output length may
+/// be off due to rounding errors.
+const LENGTH_TOLERANCE: f64 = 1e-6;
+
+
+/// Bookkeeping of the output produced relative to the input consumed
+/// since the converter got created or reset.
+#[derive(Clone, Debug, Default)]
+struct Accounting {
+    /// The number of output frames the input consumed so far
+    /// corresponds to.
+    expected: f64,
+    /// The number of output frames produced so far.
+    produced: usize,
+}
+
+impl Accounting {
+    /// The number of output frames to produce overall for the input
+    /// consumed so far.
+    #[inline]
+    fn target(&self) -> usize {
+        (self.expected - LENGTH_TOLERANCE).ceil().max(0.0) as usize
+    }
+}
+
+
+/// State required for compensating the delay of the zero order hold
+/// and linear converters.
+#[derive(Clone, Debug)]
+struct Compensation {
+    /// Whether no input has been processed since the converter got
+    /// created or reset.
+    fresh: bool,
+    /// The first input frame, which is withheld from the converter.
+    first: Vec<f32>,
+    /// The second input frame, i.e., the first one the converter got
+    /// to see.
+    second: Vec<f32>,
+    /// Two copies of the last input frame consumed, used for flushing
+    /// the converter.
+    padding: Vec<f32>,
+}
+
+impl Compensation {
+    fn new(channels: u8) -> Self {
+        let channels = usize::from(channels);
+        Self {
+            fresh: true,
+            first: Vec::with_capacity(channels),
+            second: Vec::with_capacity(channels),
+            padding: Vec::with_capacity(2 * channels),
+        }
+    }
+
+    /// Fix up the output located in between the first and the second
+    /// input frame.
+    ///
+    /// `output` holds output frames starting at frame `start` of the
+    /// overall output.
+    fn fix_up(&self, type_: ResampleType, output: &mut [f32], start: usize, ratio: f64) {
+        if self.second.is_empty() {
+            return
+        }
+
+        let channels = self.first.len();
+        for (idx, frame) in output.chunks_exact_mut(channels).enumerate() {
+            let position = (start + idx) as f64 / ratio;
+            if position >= 1.0 {
+                break
+            }
+
+            for ((sample, first), second) in frame.iter_mut().zip(&self.first).zip(&self.second) {
+                *sample = match type_ {
+                    ResampleType::Linear => {
+                        (f64::from(*first) + position * f64::from(second - first)) as f32
+                    },
+                    _ => *first,
+                };
+            }
+        }
+    }
+}
+
+
 /// A samplerate converter.
 ///
 /// This is a wrapper around `libsamplerate`'s `SRC_STATE`.
 ///
+/// # Thread Safety
+/// A `Resampler` exclusively owns its converter state and can be moved
+/// to other threads freely. It is also [`Sync`], as operations that
+/// modify the state all require a mutable reference.
+///
 /// # Example
 ///
 /// ```
@@ -37,7 +289,11 @@ pub struct Processed {
 /// let mut output = vec![0.0; 48000];
 ///
 /// // Instantiate a new resampler.
-/// let mut resampler = Resampler::new(ResampleType::SincBestQuality, 1, 44100, 48000).unwrap();
+/// let mut resampler = Resampler::builder(ResampleType::SincBestQuality)
+///     .channels(1)
+///     .rates(44100, 48000)
+///     .build()
+///     .unwrap();
 ///
 /// // Resample the input from 44100Hz to 48000Hz.
 /// let processed = resampler.finalize(&input, &mut output).unwrap();
@@ -47,25 +303,73 @@ pub struct Processed {
 #[derive(Debug)]
 pub struct Resampler {
     state: *mut SRC_STATE,
+    converter_type: ResampleType,
     channels: u8,
     ratio: f64,
+    /// State for delay compensation, if enabled and necessary.
+    compensation: Option<Compensation>,
+    /// The policy for the overall output length.
+    output_length: OutputLength,
+    /// Bookkeeping of input consumed and output produced.
+    accounting: Accounting,
+    /// The double precision converter, created on first use.
+    double: Option<DoubleConverter>,
+    /// The channel mix applied around the conversion, if any.
+    mix: Option<Mix>,
+    /// The dither applied to integer output, if any.
+    dither: Option<Ditherer>,
+    /// Buffers for converting non-`f32` samples.
+    scratch: Scratch,
 }
 
 impl Resampler {
+    /// Create a [`ResamplerBuilder`] for configuring a converter of
+    /// type `converter_type`.
+    ///
+    /// This is the most flexible way of creating a `Resampler`, with
+    /// all options validated together.
+    #[inline]
+    pub fn builder(converter_type: ResampleType) -> ResamplerBuilder {
+        ResamplerBuilder::new(converter_type)
+    }
+
     /// Create a new samplerate converter assuming the given channel
     /// count and sample rates.
+    ///
+    /// This is a shortcut for configuring channels and rates via
+    /// [`Resampler::builder`].
     pub fn new(
         converter_type: ResampleType,
         channels: u8,
         from_rate: u32,
         to_rate: u32,
     ) -> Result<Self, Error> {
-        // Make sure that the provided ratio is supported by `libsamplerate`.
         let ratio = to_rate as f64 / from_rate as f64;
-        // SAFETY: `src_is_valid_ratio` is always safe to call.
-        if unsafe { src_is_valid_ratio(ratio) } == 0 {
-            return Err(Error::from(ErrorKind::BadSrcRatio));
-        }
+        Self::with_ratio(converter_type, channels, ratio)
+    }
+
+    /// Create a new samplerate converter assuming the given channel
+    /// count and conversion ratio.
+    ///
+    /// The ratio is defined as output samplerate divided by input
+    /// samplerate. Contrary to [`new`][Self::new], it does not have to
+    /// originate from integer sample rates, making it possible to, for
+    /// example, correct for clock drift.
+    ///
+    /// ```
+    /// use resample::{Resampler, ResampleType};
+    ///
+    /// let resampler = Resampler::with_ratio(ResampleType::Linear, 2, 1.000137).unwrap();
+    /// assert_eq!(resampler.ratio(), 1.000137);
+    /// ```
+    pub fn with_ratio(
+        converter_type: ResampleType,
+        channels: u8,
+        ratio: f64,
+    ) -> Result<Self, Error> {
+        // Make sure that the provided ratio is supported by `libsamplerate`.
+        let () = check_ratio(ratio)?;
+        let () = check_channels(channels)?;
         // Construct the `SRC_STATE` struct and check if that worked.
         let mut error = 0i32;
         // SAFETY: `error` is a valid pointer coming from a reference.
@@ -74,27 +378,84 @@ impl Resampler {
 
         let slf = Self {
             state,
+            converter_type,
             ratio,
             channels,
+            compensation: None,
+            output_length: OutputLength::Natural,
+            accounting: Accounting::default(),
+            double: None,
+            mix: None,
+            dither: None,
+            scratch: Scratch::default(),
         };
         Ok(slf)
     }
 
-    fn process_impl(
+    /// Create a new samplerate converter mixing channels as per `mix`
+    /// in the process.
+    ///
+    /// Input is expected to have [`ChannelMix::inputs`] channels and
+    /// output is produced with [`ChannelMix::outputs`] channels. Mixing
+    /// happens before the conversion when downmixing and after it
+    /// otherwise, so that the converter always works on the smaller
+    /// number of channels.
+    ///
+    /// ```
+    /// use resample::{ChannelMix, Resampler, ResampleType};
+    ///
+    /// let mix = ChannelMix::surround_to_stereo();
+    /// let mut resampler =
+    ///     Resampler::with_channel_mix(ResampleType::SincFastest, mix, 48000, 44100).unwrap();
+    /// assert_eq!(resampler.channels(), 2);
+    ///
+    /// let input = [0.0f32; 6 * 480];
+    /// let mut output = [0.0f32; 2 * 441];
+    /// let processed = resampler.process(&input, &mut output).unwrap();
+    /// assert_eq!(processed.read, input.len());
+    /// ```
+    pub fn with_channel_mix(
+        converter_type: ResampleType,
+        mix: ChannelMix,
+        from_rate: u32,
+        to_rate: u32,
+    ) -> Result<Self, Error> {
+        let channels = mix.inputs().min(mix.outputs());
+        let mut slf = Self::new(converter_type, channels, from_rate, to_rate)?;
+        let () = slf.set_channel_mix(mix);
+        Ok(slf)
+    }
+
+    /// Set the channel mix to apply around the conversion.
+    ///
+    /// The converter is expected to work on the smaller of the mix's
+    /// input and output channel counts.
+    pub(crate) fn set_channel_mix(&mut self, mix: ChannelMix) {
+        debug_assert_eq!(self.channels, mix.inputs().min(mix.outputs()));
+        self.mix = Some(Mix::new(mix));
+    }
+
+    fn process_raw(
         &mut self,
         input: &[f32],
         output: &mut [f32],
+        ratio: f64,
         end_of_input: bool,
     ) -> Result<Processed, Error> {
-        let channels = usize::from(self.channels);
-        debug_assert_eq!(input.len() % channels, 0);
+        let () = check_frame_aligned(input.len(), self.channels, ErrorKind::InputNotFrameAligned)?;
+        let () = check_frame_aligned(
+            output.len(),
+            self.channels,
+            ErrorKind::OutputNotFrameAligned,
+        )?;
 
+        let channels = usize::from(self.channels);
         let mut src = SRC_DATA {
             data_in: input.as_ptr(),
             data_out: output.as_mut_ptr(),
-            input_frames: (input.len() / channels).try_into().unwrap(),
-            output_frames: (output.len() / channels).try_into().unwrap(),
-            src_ratio: self.ratio,
+            input_frames: to_src_frames(input.len() / channels),
+            output_frames: to_src_frames(output.len() / channels),
+            src_ratio: ratio,
             end_of_input: if end_of_input { 1 } else { 0 },
             input_frames_used: 0,
             output_frames_gen: 0,
@@ -105,63 +466,814 @@ impl Resampler {
         //          originating from a reference.
         let error = unsafe { src_process(self.state, &raw mut src) };
         let () = Error::check_int(error)?;
+        // `libsamplerate` remembers the last ratio used and smoothly
+        // transitions from it on subsequent calls, so we do the same.
+        self.ratio = ratio;
+
+        let processed = Processed {
+            read: from_src_frames(src.input_frames_used) * channels,
+            written: from_src_frames(src.output_frames_gen) * channels,
+        };
+        Ok(processed)
+    }
+
+    fn process_impl(
+        &mut self,
+        input: &[f32],
+        output: &mut [f32],
+        ratio: f64,
+        end_of_input: bool,
+    ) -> Result<Processed, Error> {
+        let channels = usize::from(self.channels);
+        let all_input = input;
+        let mut input = input;
+        let mut held = 0;
+
+        if let Some(compensation) = &mut self.compensation {
+            if compensation.fresh && !input.is_empty() {
+                // Both the zero order hold and the linear converter
+                // start out by repeating the first input frame they
+                // see, which delays their output by one input frame.
+                // We withhold the first frame and produce the output
+                // located before the second one ourselves instead.
+                let () = compensation.first.extend_from_slice(&input[..channels]);
+                compensation.fresh = false;
+                input = &input[channels..];
+                held = channels;
+            }
+            if compensation.second.is_empty() && !input.is_empty() {
+                let () = compensation.second.extend_from_slice(&input[..channels]);
+            }
+        }
+
+        let mut processed = self.process_raw(input, output, ratio, end_of_input)?;
+        processed.read += held;
+
+        if let Some(compensation) = &mut self.compensation {
+            let () = compensation.fix_up(
+                self.converter_type,
+                &mut output[..processed.written],
+                self.accounting.produced,
+                ratio,
+            );
+
+            if processed.read > 0 {
+                let frame = &all_input[processed.read - channels..processed.read];
+                let () = compensation.padding.clear();
+                let () = compensation.padding.extend_from_slice(frame);
+                let () = compensation.padding.extend_from_slice(frame);
+            }
+        }
+        self.accounting.expected += (processed.read / channels) as f64 * ratio;
+        self.accounting.produced += processed.written / channels;
+        Ok(processed)
+    }
+
+    /// Finalize the conversion with delay compensation active.
+    fn finalize_compensated(
+        &mut self,
+        input: &[f32],
+        output: &mut [f32],
+    ) -> Result<Processed, Error> {
+        let mut total = self.process_impl(input, output, self.ratio, false)?;
+        if total.read < input.len() {
+            // The output buffer is full.
+            return Ok(total)
+        }
+
+        // Neither the zero order hold nor the linear converter ever
+        // process the last input frame. Feed a copy of it to flush
+        // them, cutting off the output at the length corresponding to
+        // the actual input.
+        let channels = usize::from(self.channels);
+        let remaining = self
+            .accounting
+            .target()
+            .saturating_sub(self.accounting.produced);
+        // Can't fail, because we are only called with compensation
+        // enabled.
+        let compensation = self.compensation.as_mut().unwrap();
+        let len = (remaining * channels).min(output.len() - total.written);
+        if len == 0 || compensation.padding.is_empty() {
+            return Ok(total)
+        }
+
+        let padding = take(&mut compensation.padding);
+        let output = &mut output[total.written..total.written + len];
+        let result = self.process_raw(&padding, output, self.ratio, true);
+        // Can't fail, because we checked above.
+        let compensation = self.compensation.as_mut().unwrap();
+        compensation.padding = padding;
+
+        let processed = result?;
+        self.accounting.produced += processed.written / channels;
+        total.written += processed.written;
+        Ok(total)
+    }
+
+    /// Run `f` with channels mixed as configured.
+    fn with_mix<T, F>(&mut self, input: &[T], output: &mut [T], f: F) -> Result<Processed, Error>
+    where
+        T: Mixable,
+        F: FnOnce(&mut Self, &[T], &mut [T]) -> Result<Processed, Error>,
+    {
+        let Some(mut mix) = self.mix.take() else {
+            return f(self, input, output)
+        };
+
+        let result = mix.run(self, input, output, f);
+        self.mix = Some(mix);
+        result
+    }
+
+    /// Invoke `f` with `input` and `output` represented as `f32`
+    /// samples, converting them via scratch buffers if necessary.
+    fn with_float<S, F>(&mut self, input: &[S], output: &mut [S], f: F) -> Result<Processed, Error>
+    where
+        S: Sample,
+        F: FnOnce(&mut Self, &[f32], &mut [f32]) -> Result<Processed, Error>,
+    {
+        if let (Some(input), Some(output)) = (S::as_float(input), S::as_float_mut(output)) {
+            return self.with_mix(input, output, f)
+        }
+
+        let mut scratch = take(&mut self.scratch);
+        let () = scratch.input.clear();
+        let () = scratch.input.resize(input.len(), 0.0);
+        let () = S::to_float(input, &mut scratch.input);
+        let () = scratch.output.clear();
+        let () = scratch.output.resize(output.len(), 0.0);
+
+        let result = self.with_mix(&scratch.input, &mut scratch.output, f);
+        if let Ok(processed) = &result {
+            let written = processed.written;
+            if let Some(ditherer) = &mut self.dither {
+                let () = ditherer.apply_for::<S>(&mut scratch.output[..written]);
+            }
+            let () = S::from_float(&scratch.output[..written], &mut output[..written]);
+        }
+
+        self.scratch = scratch;
+        result
+    }
+
+    /// Drop output in excess of what the consumed input corresponds to
+    /// or pad missing output with silence, as per the output length
+    /// policy.
+    fn enforce_length(
+        &mut self,
+        input: &[f32],
+        output: &mut [f32],
+        mut processed: Processed,
+    ) -> Processed {
+        let channels = usize::from(self.channels);
+        let target = self.accounting.target();
+        let produced = self.accounting.produced;
+
+        if produced > target {
+            // Output already handed out can't be taken back, so we can
+            // only drop what got produced just now.
+            let excess = ((produced - target) * channels).min(processed.written);
+            processed.written -= excess;
+            self.accounting.produced -= excess / channels;
+        } else if processed.read == input.len() {
+            // All input has been consumed and the converter is flushed
+            // to the extent that the output buffer permits.
+            let missing = (target - produced) * channels;
+            let len = missing.min(output.len() - processed.written);
+            let () = output[processed.written..processed.written + len].fill(0.0);
+            processed.written += len;
+            self.accounting.produced += len / channels;
+        }
+        processed
+    }
+
+    fn finalize_impl(&mut self, input: &[f32], output: &mut [f32]) -> Result<Processed, Error> {
+        let processed = if self.compensation.is_some() {
+            self.finalize_compensated(input, output)?
+        } else {
+            self.finalize_natural(input, output)?
+        };
+
+        let processed = match self.output_length {
+            OutputLength::Natural => processed,
+            OutputLength::Exact | OutputLength::ExactAlignedToDelay => {
+                self.enforce_length(input, output, processed)
+            },
+        };
+        Ok(processed)
+    }
+
+    /// Finalize the conversion without any adjustments.
+    fn finalize_natural(&mut self, input: &[f32], output: &mut [f32]) -> Result<Processed, Error> {
+        let mut total = Processed::default();
+
+        loop {
+            let in_buf = &input[total.read..];
+            let out_buf = &mut output[total.written..];
+
+            let processed = self.process_impl(in_buf, out_buf, self.ratio, true)?;
+
+            total.read += processed.read;
+            total.written += processed.written;
+
+            if processed.written == 0 {
+                break Ok(total)
+            }
+        }
+    }
+
+    /// Perform a samplerate conversion on a block of data.
+    ///
+    /// If the number of channels used was not `1` (Mono), the samples
+    /// are expected to be stored interleaved. Both `input` and `output`
+    /// need to hold a whole number of frames.
+    ///
+    /// Samples can be provided in any of the supported [`Sample`]
+    /// formats. Formats other than `f32` are converted internally.
+    ///
+    /// # Notes
+    /// Even if all input samples are cleanly processed with this
+    /// method, you will still need to [`finalize`][Self::finalize] the
+    /// conversion.
+    pub fn process<S>(&mut self, input: &[S], output: &mut [S]) -> Result<Processed, Error>
+    where
+        S: Sample,
+    {
+        self.with_float(input, output, |slf, input, output| {
+            slf.process_impl(input, output, slf.ratio, false)
+        })
+    }
+
+    /// Perform a samplerate conversion on a block of data, gliding
+    /// from the current conversion ratio to `ratio`.
+    ///
+    /// The ratio is defined as output samplerate divided by input
+    /// samplerate. The transition happens linearly over the course of
+    /// the provided output buffer, which should hence be sized to the
+    /// expected output length. Subsequent calls to
+    /// [`process`][Self::process] will continue to use `ratio`.
+    ///
+    /// Use [`set_ratio`][Self::set_ratio] instead if an instant change
+    /// in ratio is desired.
+    pub fn process_with_ratio<S>(
+        &mut self,
+        input: &[S],
+        output: &mut [S],
+        ratio: f64,
+    ) -> Result<Processed, Error>
+    where
+        S: Sample,
+    {
+        let () = check_ratio(ratio)?;
+        self.with_float(input, output, |slf, input, output| {
+            slf.process_impl(input, output, ratio, false)
+        })
+    }
+
+    /// Perform a samplerate conversion on last block of given input
+    /// data (which may be empty).
+    ///
+    /// If the number of channels used was not `1` (Mono), the samples
+    /// are expected to be stored interleaved. Both `input` and `output`
+    /// need to hold a whole number of frames.
+    ///
+    /// If the returned `Processed::written` value equals the size of
+    /// the output buffer this way indicate that more data is available
+    /// for consumption and that the method should be invoked again with
+    /// potentially remaining input.
+    pub fn finalize<S>(&mut self, input: &[S], output: &mut [S]) -> Result<Processed, Error>
+    where
+        S: Sample,
+    {
+        self.with_float(input, output, Self::finalize_impl)
+    }
+
+    /// Perform a samplerate conversion on the last block of given
+    /// input data, gliding from the current conversion ratio to
+    /// `ratio`.
+    ///
+    /// This method behaves like [`finalize`][Self::finalize] in all
+    /// other respects.
+    pub(crate) fn finalize_with_ratio(
+        &mut self,
+        input: &[f32],
+        output: &mut [f32],
+        ratio: f64,
+    ) -> Result<Processed, Error> {
+        let () = check_ratio(ratio)?;
+        // `libsamplerate` transitions from the ratio last used to the
+        // one we pass in.
+        self.ratio = ratio;
+        self.with_float(input, output, Self::finalize_impl)
+    }
+
+    /// Invoke `f` with planar `input` and `output` interleaved into
+    /// scratch buffers.
+    ///
+    /// The [`Processed`] result reported is counted in frames.
+    fn with_planar<F>(
+        &mut self,
+        input: &[&[f32]],
+        output: &mut [&mut [f32]],
+        f: F,
+    ) -> Result<Processed, Error>
+    where
+        F: FnOnce(&mut Self, &[f32], &mut [f32]) -> Result<Processed, Error>,
+    {
+        let in_channels = self.input_channels();
+        let out_channels = self.output_channels();
+        let in_frames = planar_frames(input, in_channels, ErrorKind::InputNotFrameAligned)?;
+        let out_frames = planar_frames(output, out_channels, ErrorKind::OutputNotFrameAligned)?;
+        let in_channels = usize::from(in_channels);
+        let out_channels = usize::from(out_channels);
+
+        let mut scratch = take(&mut self.scratch);
+        let () = scratch.input.clear();
+        let () = scratch.input.resize(in_frames * in_channels, 0.0);
+        for (channel, input) in input.iter().enumerate() {
+            let samples = scratch.input.iter_mut().skip(channel).step_by(in_channels);
+            let () = samples
+                .zip(input.iter())
+                .for_each(|(sample, input)| *sample = *input);
+        }
+        let () = scratch.output.clear();
+        let () = scratch.output.resize(out_frames * out_channels, 0.0);
+
+        let result = self.with_mix(&scratch.input, &mut scratch.output, f);
+        let result = result.map(|processed| {
+            let written = &scratch.output[..processed.written];
+            for (channel, output) in output.iter_mut().enumerate() {
+                let samples = written.iter().skip(channel).step_by(out_channels);
+                let () = output
+                    .iter_mut()
+                    .zip(samples)
+                    .for_each(|(output, sample)| *output = *sample);
+            }
+
+            Processed {
+                read: processed.read / in_channels,
+                written: processed.written / out_channels,
+            }
+        });
+
+        self.scratch = scratch;
+        result
+    }
+
+    /// Perform a samplerate conversion on a block of planar data.
+    ///
+    /// `input` and `output` are expected to contain one buffer per
+    /// channel, with all buffers of each being of equal length.
+    /// Contrary to [`process`][Self::process], the returned
+    /// [`Processed`] object reports counts in frames, i.e., per
+    /// channel.
+    ///
+    /// # Notes
+    /// Even if all input samples are cleanly processed with this
+    /// method, you will still need to
+    /// [`finalize_planar`][Self::finalize_planar] the conversion.
+    pub fn process_planar(
+        &mut self,
+        input: &[&[f32]],
+        output: &mut [&mut [f32]],
+    ) -> Result<Processed, Error> {
+        self.with_planar(input, output, |slf, input, output| {
+            slf.process_impl(input, output, slf.ratio, false)
+        })
+    }
+
+    /// Perform a samplerate conversion on the last block of given
+    /// planar input data (which may be empty).
+    ///
+    /// `input` and `output` are expected to contain one buffer per
+    /// channel, with all buffers of each being of equal length.
+    /// Contrary to [`finalize`][Self::finalize], the returned
+    /// [`Processed`] object reports counts in frames, i.e., per
+    /// channel.
+    ///
+    /// If the returned `Processed::written` value equals the length of
+    /// the output buffers this may indicate that more data is available
+    /// for consumption and that the method should be invoked again with
+    /// potentially remaining input.
+    pub fn finalize_planar(
+        &mut self,
+        input: &[&[f32]],
+        output: &mut [&mut [f32]],
+    ) -> Result<Processed, Error> {
+        self.with_planar(input, output, Self::finalize_impl)
+    }
+
+    fn process_f64_impl(
+        &mut self,
+        input: &[f64],
+        output: &mut [f64],
+        ratio: f64,
+        end_of_input: bool,
+    ) -> Result<Processed, Error> {
+        self.with_mix(input, output, |slf, input, output| {
+            slf.process_f64_raw(input, output, ratio, end_of_input)
+        })
+    }
+
+    fn process_f64_raw(
+        &mut self,
+        input: &[f64],
+        output: &mut [f64],
+        ratio: f64,
+        end_of_input: bool,
+    ) -> Result<Processed, Error> {
+        let () = check_frame_aligned(input.len(), self.channels, ErrorKind::InputNotFrameAligned)?;
+        let () = check_frame_aligned(
+            output.len(),
+            self.channels,
+            ErrorKind::OutputNotFrameAligned,
+        )?;
+
+        let double = match &mut self.double {
+            Some(double) => double,
+            double @ None => {
+                double.insert(DoubleConverter::new(self.converter_type, self.channels)?)
+            },
+        };
+        let processed = double.process(input, output, ratio, end_of_input)?;
+        self.ratio = ratio;
+        Ok(processed)
+    }
+
+    /// Perform a samplerate conversion on a block of `f64` data.
+    ///
+    /// Contrary to [`process`][Self::process], samples are never
+    /// converted to `f32` and all calculations happen in double
+    /// precision. Only converters for which
+    /// [`ResampleType::supports_f64`] holds provide this path, all
+    /// others report an error of kind [`ErrorKind::BadConverter`].
+    /// Delay compensation does not apply.
+    ///
+    /// The `f64` path keeps its own conversion history. It should not
+    /// be mixed with the `f32` one without a [`reset`][Self::reset] in
+    /// between.
+    pub fn process_f64(&mut self, input: &[f64], output: &mut [f64]) -> Result<Processed, Error> {
+        self.process_f64_impl(input, output, self.ratio, false)
+    }
+
+    /// Perform a samplerate conversion on a block of `f64` data,
+    /// gliding from the current conversion ratio to `ratio`.
+    ///
+    /// See [`process_with_ratio`][Self::process_with_ratio] and
+    /// [`process_f64`][Self::process_f64] for details.
+    pub fn process_with_ratio_f64(
+        &mut self,
+        input: &[f64],
+        output: &mut [f64],
+        ratio: f64,
+    ) -> Result<Processed, Error> {
+        let () = check_ratio(ratio)?;
+        self.process_f64_impl(input, output, ratio, false)
+    }
+
+    /// Perform a samplerate conversion on the last block of `f64` data
+    /// (which may be empty).
+    ///
+    /// See [`finalize`][Self::finalize] and
+    /// [`process_f64`][Self::process_f64] for details.
+    pub fn finalize_f64(&mut self, input: &[f64], output: &mut [f64]) -> Result<Processed, Error> {
+        let mut total = Processed::default();
+
+        loop {
+            let in_buf = &input[total.read..];
+            let out_buf = &mut output[total.written..];
+
+            let processed = self.process_f64_impl(in_buf, out_buf, self.ratio, true)?;
+
+            total.read += processed.read;
+            total.written += processed.written;
+
+            if processed.written == 0 {
+                break Ok(total)
+            }
+        }
+    }
+
+    /// Convert as much of `input` as the converter accepts, appending
+    /// all output to `output`.
+    ///
+    /// If `finalize` is `true`, the conversion is finalized completely.
+    /// The function returns the number of input samples consumed.
+    pub(crate) fn process_into_vec(
+        &mut self,
+        input: &[f32],
+        output: &mut Vec<f32>,
+        finalize: bool,
+    ) -> Result<usize, Error> {
+        let in_channels = usize::from(self.input_channels());
+        let channels = usize::from(self.output_channels());
+        let mut total = Processed {
+            read: 0,
+            written: output.len(),
+        };
+        let len = output.len() + self.max_output_frames(input.len() / in_channels) * channels;
+        let () = output.resize(len, 0.0);
+
+        let result = loop {
+            if total.written == output.len() {
+                let len = output.len() + self.tail_frames() * channels;
+                let () = output.resize(len, 0.0);
+            }
+
+            let in_buf = &input[total.read..];
+            let out_buf = &mut output[total.written..];
+            let result = if finalize {
+                self.finalize(in_buf, out_buf)
+            } else {
+                self.process(in_buf, out_buf)
+            };
+            let processed = match result {
+                Ok(processed) => processed,
+                Err(err) => break Err(err),
+            };
+
+            total.read += processed.read;
+            total.written += processed.written;
+
+            if finalize {
+                if processed.written == 0 {
+                    break Ok(total.read)
+                }
+            } else if total.read == input.len() || (processed.read == 0 && processed.written == 0) {
+                break Ok(total.read)
+            }
+        };
+
+        let () = output.truncate(total.written);
+        result
+    }
+
+    /// Reset the internal converter's state.
+    pub fn reset(&mut self) -> Result<(), Error> {
+        // SAFETY: `state` is valid and guaranteed to be coming from a
+        //          previous `src_new` call.
+        let error = unsafe { src_reset(self.state) };
+        let () = Error::check_int(error)?;
+
+        if self.compensation.is_some() {
+            self.compensation = Some(Compensation::new(self.channels));
+        }
+        self.accounting = Accounting::default();
+        if let Some(double) = &mut self.double {
+            let () = double.reset();
+        }
+        if let Some(ditherer) = &mut self.dither {
+            let () = ditherer.reset();
+        }
+        Ok(())
+    }
+
+    /// Retrieve the number of channels the converter works with.
+    ///
+    /// If a [`ChannelMix`] is in use, this is the smaller of the input
+    /// and output channel counts.
+    #[inline]
+    pub fn channels(&self) -> u8 {
+        self.channels
+    }
+
+    /// Retrieve the number of channels input is expected to have.
+    #[inline]
+    pub fn input_channels(&self) -> u8 {
+        self.mix
+            .as_ref()
+            .map(|mix| mix.matrix().inputs())
+            .unwrap_or(self.channels)
+    }
+
+    /// Retrieve the number of channels output is produced with.
+    #[inline]
+    pub fn output_channels(&self) -> u8 {
+        self.mix
+            .as_ref()
+            .map(|mix| mix.matrix().outputs())
+            .unwrap_or(self.channels)
+    }
+
+    /// Retrieve the conversion ratio currently in use.
+    ///
+    /// The ratio is defined as output samplerate divided by input
+    /// samplerate. It reflects the most recent ratio set or used for
+    /// processing.
+    #[inline]
+    pub fn ratio(&self) -> f64 {
+        self.ratio
+    }
 
-        let processed = Processed {
-            read: usize::try_from(src.input_frames_used).unwrap() * channels,
-            written: usize::try_from(src.output_frames_gen).unwrap() * channels,
+    /// Retrieve the latency of the converter at the current ratio.
+    ///
+    /// The sinc based converters produce output that is aligned with
+    /// the input, but they need to buffer half of their filter's worth
+    /// of input before producing the corresponding output. The zero
+    /// order hold and linear converters delay their output by one input
+    /// frame, unless [delay
+    /// compensation][Self::set_delay_compensation] is enabled.
+    pub fn latency(&self) -> Latency {
+        let input_frames = match self.converter_type {
+            ResampleType::ZeroOrderHold | ResampleType::Linear => 1,
+            _ => self.converter_type.half_filter_frames(self.ratio),
         };
-        Ok(processed)
+
+        Latency {
+            input_frames,
+            output_frames: (input_frames as f64 * self.ratio).ceil() as usize,
+        }
     }
 
-    /// Perform a samplerate conversion on a block of data.
+    /// Enable or disable compensation for the delay introduced by the
+    /// converter.
     ///
-    /// If the number of channels used was not `1` (Mono), the samples
-    /// are expected to be stored interleaved.
+    /// With compensation enabled, output frame `n` corresponds to input
+    /// frame `n / ratio`, i.e., impulses and transients in the output
+    /// line up with the input. Furthermore, [`finalize`][Self::finalize]
+    /// flushes the converter such that the total output length
+    /// corresponds to the input length. For changing ratios the
+    /// latter is approximate.
     ///
-    /// # Notes
-    /// Even if all input samples are cleanly processed with this
-    /// method, you will still need to [`finalize`][Self::finalize] the
-    /// conversion.
-    pub fn process(&mut self, input: &[f32], output: &mut [f32]) -> Result<Processed, Error> {
-        self.process_impl(input, output, false)
+    /// The output of the sinc based converters is already aligned,
+    /// so for them compensation has no effect. Because it affects the
+    /// start of the conversion, changing the setting also
+    /// [resets][Self::reset] the converter.
+    pub fn set_delay_compensation(&mut self, enable: bool) -> Result<(), Error> {
+        let () = self.reset()?;
+
+        let delayed = matches!(
+            self.converter_type,
+            ResampleType::ZeroOrderHold | ResampleType::Linear
+        );
+        self.compensation = (enable && delayed).then(|| Compensation::new(self.channels));
+        Ok(())
     }
 
-    /// Perform a samplerate conversion on last block of given input
-    /// data (which may be empty).
+    /// Set the policy determining the overall number of frames the
+    /// conversion produces.
     ///
-    /// If the number of channels used was not `1` (Mono), the samples
-    /// are expected to be stored interleaved.
+    /// With a policy other than [`OutputLength::Natural`],
+    /// [`finalize`][Self::finalize] drops output beyond
+    /// `ceil(input_frames * ratio)` frames, where `input_frames` is the
+    /// number of input frames consumed since the converter got created
+    /// or reset, and pads missing output with silence. Output already
+    /// returned by [`process`][Self::process] can't be dropped, though,
+    /// so the final block of input should be passed to `finalize`. For
+    /// changing ratios the length is approximate. The double precision
+    /// methods are not affected.
     ///
-    /// If the returned `Processed::written` value equals the size of
-    /// the output buffer this way indicate that more data is available
-    /// for consumption and that the method should be invoked again with
-    /// potentially remaining input.
-    pub fn finalize(&mut self, input: &[f32], output: &mut [f32]) -> Result<Processed, Error> {
-        let mut total = Processed::default();
-
-        loop {
-            let in_buf = &input[total.read..];
-            let out_buf = &mut output[total.written..];
+    /// [`OutputLength::ExactAlignedToDelay`] enables [delay
+    /// compensation][Self::set_delay_compensation], while switching
+    /// from it to another policy disables it again. Both cases reset
+    /// the converter.
+    pub fn set_output_length(&mut self, length: OutputLength) -> Result<(), Error> {
+        let aligned = length == OutputLength::ExactAlignedToDelay;
+        if aligned || self.output_length == OutputLength::ExactAlignedToDelay {
+            let () = self.set_delay_compensation(aligned)?;
+        }
+        self.output_length = length;
+        Ok(())
+    }
 
-            let processed = self.process_impl(in_buf, out_buf, true)?;
+    /// Retrieve the policy determining the overall number of frames
+    /// the conversion produces.
+    #[inline]
+    pub fn output_length(&self) -> OutputLength {
+        self.output_length
+    }
 
-            total.read += processed.read;
-            total.written += processed.written;
+    /// Set the dither to apply when producing integer output.
+    ///
+    /// Dither only affects output in an integer [`Sample`] format of up
+    /// to 16 bit. 32 bit output is finer than the precision of the
+    /// conversion and is not dithered. The noise is generated by a
+    /// pseudo random number generator initialized with `seed`, making
+    /// the output deterministic. [Resetting][Self::reset] the converter
+    /// restarts the noise sequence.
+    ///
+    /// The dither also applies to integer encodings produced by
+    /// [`ResampleReader`][crate::ResampleReader] and
+    /// [`ResampleWriter`][crate::ResampleWriter] wrapping the
+    /// converter.
+    pub fn set_dither(&mut self, dither: Dither, seed: u64) {
+        self.dither =
+            (dither != Dither::None).then(|| Ditherer::new(dither, seed, self.output_channels()));
+    }
 
-            if processed.written == 0 {
-                break Ok(total)
-            }
-        }
+    /// Retrieve the state for dithering integer output, if dither is
+    /// enabled.
+    #[inline]
+    pub(crate) fn ditherer_mut(&mut self) -> Option<&mut Ditherer> {
+        self.dither.as_mut()
     }
 
-    /// Reset the internal converter's state.
-    pub fn reset(&mut self) -> Result<(), Error> {
+    /// Set the conversion ratio to use from now on, without a gradual
+    /// transition from the previous one.
+    ///
+    /// The ratio is defined as output samplerate divided by input
+    /// samplerate.
+    pub fn set_ratio(&mut self, ratio: f64) -> Result<(), Error> {
+        let () = check_ratio(ratio)?;
         // SAFETY: `state` is valid and guaranteed to be coming from a
         //          previous `src_new` call.
-        let error = unsafe { src_reset(self.state) };
+        let error = unsafe { src_set_ratio(self.state, ratio) };
         let () = Error::check_int(error)?;
+        if let Some(double) = &mut self.double {
+            let () = double.set_ratio(ratio);
+        }
+        self.ratio = ratio;
         Ok(())
     }
+
+    /// Calculate the maximum number of frames a single call to
+    /// [`process`][Self::process] can produce for `input_frames` frames
+    /// of input, at the current ratio.
+    ///
+    /// An output buffer of this size is guaranteed to never be the
+    /// limiting factor in the conversion of the given input.
+    pub fn max_output_frames(&self, input_frames: usize) -> usize {
+        (input_frames as f64 * self.ratio).ceil() as usize + self.tail_frames()
+    }
+
+    /// Calculate the number of input frames that is guaranteed to
+    /// produce at least `output_frames` frames of output, at the
+    /// current ratio.
+    ///
+    /// The result accounts for the input the converter needs to buffer
+    /// before producing output, and so holds irrespective of the
+    /// converter's current state.
+    pub fn input_frames_needed(&self, output_frames: usize) -> usize {
+        (output_frames as f64 / self.ratio).ceil() as usize
+            + self.converter_type.half_filter_frames(self.ratio)
+            + 1
+    }
+
+    /// Calculate the maximum number of frames a call to
+    /// [`finalize`][Self::finalize] may produce in addition to the
+    /// output corresponding to the input provided to it, at the current
+    /// ratio.
+    pub fn tail_frames(&self) -> usize {
+        tail_frames(self.converter_type, self.ratio)
+    }
+
+    /// Create a copy of this converter, including all of its internal
+    /// state.
+    ///
+    /// The copy continues the conversion from exactly the point where
+    /// the original is at, without having to re-feed any previous
+    /// input. Contrary to [`Clone::clone`], this method reports
+    /// allocation failures as errors.
+    pub fn try_clone(&self) -> Result<Self, Error> {
+        let mut error = 0i32;
+        // SAFETY: `state` is valid and guaranteed to be coming from a
+        //          previous `src_new` call and `error` is a valid
+        //          pointer coming from a reference.
+        let state = unsafe { src_clone(self.state, &raw mut error) };
+        let () = Error::check_int(error)?;
+
+        let slf = Self {
+            state,
+            converter_type: self.converter_type,
+            channels: self.channels,
+            ratio: self.ratio,
+            compensation: self.compensation.clone(),
+            output_length: self.output_length,
+            accounting: self.accounting.clone(),
+            double: self.double.clone(),
+            mix: self.mix.clone(),
+            dither: self.dither.clone(),
+            scratch: Scratch::default(),
+        };
+        Ok(slf)
+    }
+}
+
+// SAFETY: The `SRC_STATE` object is exclusively owned by the
+//         `Resampler` and only ever accessed through it. None of the
+//         converters use thread-local storage and the only global
+//         state they reference are their immutable method tables and
+//         filter coefficients. Hence, using the state from a thread
+//         other than the one that created it is fine.
+unsafe impl Send for Resampler {}
+
+// SAFETY: All methods modifying the `SRC_STATE` object require a
+//         mutable reference. The only operation using it through a
+//         shared reference, `try_clone`, merely reads it.
+unsafe impl Sync for Resampler {}
+
+impl Clone for Resampler {
+    /// Create a copy of this converter, including all of its internal
+    /// state.
+    ///
+    /// # Panics
+    /// Panics if the internal state could not be copied, which only
+    /// happens if memory allocation fails. Use
+    /// [`Resampler::try_clone`] to handle this case gracefully.
+    fn clone(&self) -> Self {
+        self.try_clone().expect("failed to clone resampler state")
+    }
 }
 
 impl Drop for Resampler {
@@ -178,6 +1290,13 @@ mod tests {
     use super::*;
 
     use std::f32::consts::PI;
+    use std::thread;
+
+    use crate::convert;
+    use crate::convert_into;
+    use crate::convert_ratio;
+    use crate::convert_with_length;
+    use crate::max_convert_len;
 
 
     #[test]
@@ -303,4 +1422,924 @@ mod tests {
 
         assert_eq!(output, reference);
     }
+
+    /// Check that a cloned `Resampler` continues where the original
+    /// left off, independently of it.
+    #[test]
+    fn cloning() {
+        let freq = PI * 880f32 / 44100f32;
+        let input = (0..2 * 4410)
+            .map(|i| (freq * (i / 2) as f32).sin())
+            .collect::<Vec<f32>>();
+        let head = &input[..input.len() / 2];
+
+        for type_ in [
+            ResampleType::SincFastest,
+            ResampleType::ZeroOrderHold,
+            ResampleType::Linear,
+        ] {
+            let mut resampler = Resampler::new(type_, 2, 44100, 48000).unwrap();
+            let mut output = vec![0.0; input.len() * 2];
+            let processed = resampler.process(head, &mut output).unwrap();
+            let tail = &input[processed.read..];
+
+            let mut clone = resampler.clone();
+            assert_eq!(clone.channels, resampler.channels);
+            assert_eq!(clone.ratio, resampler.ratio);
+
+            let mut expected = vec![0.0; input.len() * 2];
+            let processed = resampler.finalize(tail, &mut expected).unwrap();
+            let () = expected.truncate(processed.written);
+
+            // Mess with the original's state to make sure that the
+            // clone is unaffected.
+            let () = resampler.reset().unwrap();
+            let _processed = resampler.process(head, &mut output).unwrap();
+
+            let processed = clone.finalize(tail, &mut output).unwrap();
+            assert_eq!(&output[..processed.written], expected.as_slice());
+        }
+    }
+
+    /// Check that a `Resampler` can be sent to and shared with other
+    /// threads.
+    #[test]
+    fn send_sync() {
+        fn test<T>()
+        where
+            T: Send + Sync,
+        {
+        }
+
+        let () = test::<Resampler>();
+
+        let mut resampler = Resampler::new(ResampleType::SincFastest, 1, 44100, 48000).unwrap();
+        let handle = thread::spawn(move || {
+            let mut output = vec![0.0; 2048];
+            let processed = resampler.finalize(&[0.5f32; 1024], &mut output).unwrap();
+            processed.written
+        });
+        let written = handle.join().unwrap();
+        assert!(written > 1024, "{written}");
+    }
+
+    /// Check that integer samples are converted the same way as
+    /// floating point ones.
+    #[test]
+    fn integer_samples() {
+        let freq = PI * 880f32 / 44100f32;
+        let input = (0..4410)
+            .map(|i| (freq * i as f32).sin() * 0.5)
+            .collect::<Vec<f32>>();
+        let mut short_input = vec![0i16; input.len()];
+        let () = i16::from_float(&input, &mut short_input);
+
+        let mut resampler = Resampler::new(ResampleType::SincFastest, 1, 44100, 48000).unwrap();
+        let mut output = vec![0i16; 6000];
+        let processed = resampler.finalize(&short_input, &mut output).unwrap();
+        assert_eq!(processed.read, input.len());
+
+        let mut resampler = Resampler::new(ResampleType::SincFastest, 1, 44100, 48000).unwrap();
+        let mut expected = vec![0f32; 6000];
+        let reference = resampler.finalize(&input, &mut expected).unwrap();
+        assert_eq!(processed.written, reference.written);
+
+        let () =
+            output
+                .iter()
+                .zip(&expected[..processed.written])
+                .for_each(|(actual, expected)| {
+                    let expected = *expected * 32768.0;
+                    assert!((f32::from(*actual) - expected).abs() <= 2.0);
+                });
+    }
+
+    /// Check that planar data is converted the same way as interleaved
+    /// data.
+    #[test]
+    fn planar_conversion() {
+        let channels = 3;
+        let freq = PI * 880f32 / 44100f32;
+        let planar = (0..channels)
+            .map(|channel| {
+                (0..4410)
+                    .map(|i| (freq * i as f32 + channel as f32).sin())
+                    .collect::<Vec<f32>>()
+            })
+            .collect::<Vec<_>>();
+        let interleaved = (0..4410 * channels)
+            .map(|i| planar[i % channels][i / channels])
+            .collect::<Vec<f32>>();
+
+        let mut resampler =
+            Resampler::new(ResampleType::SincFastest, channels as u8, 44100, 48000).unwrap();
+        let mut expected = vec![0.0; 5000 * channels];
+        let reference = resampler.finalize(&interleaved, &mut expected).unwrap();
+
+        let mut resampler =
+            Resampler::new(ResampleType::SincFastest, channels as u8, 44100, 48000).unwrap();
+        let mut output = vec![vec![0.0; 5000]; channels];
+        let input = planar.iter().map(Vec::as_slice).collect::<Vec<_>>();
+        let mut out_bufs = output.iter_mut().map(Vec::as_mut_slice).collect::<Vec<_>>();
+        let processed = resampler.finalize_planar(&input, &mut out_bufs).unwrap();
+        assert_eq!(processed.read, 4410);
+        assert_eq!(processed.written * channels, reference.written);
+
+        for (i, expected) in expected[..reference.written].iter().enumerate() {
+            assert_eq!(output[i % channels][i / channels], *expected);
+        }
+    }
+
+    /// Make sure that buffers not holding a whole number of frames are
+    /// rejected.
+    #[test]
+    fn frame_alignment() {
+        let mut resampler = Resampler::new(ResampleType::Linear, 2, 44100, 48000).unwrap();
+        let mut output = [0.0f32; 16];
+
+        let err = resampler.process(&[0.0; 7], &mut output).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InputNotFrameAligned);
+        let err = resampler.finalize(&[0.0; 7], &mut output).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InputNotFrameAligned);
+        let err = resampler.process(&[0.0; 8], &mut output[..15]).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::OutputNotFrameAligned);
+        let err = resampler.finalize(&[0i16; 8], &mut [0i16; 15]).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::OutputNotFrameAligned);
+
+        let left = [0.0; 8];
+        let right = [0.0; 7];
+        let mut out_left = [0.0; 8];
+        let mut out_right = [0.0; 8];
+        let err = resampler
+            .process_planar(&[&left], &mut [&mut out_left, &mut out_right])
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ChannelCountMismatch);
+        let err = resampler
+            .process_planar(&[&left, &right], &mut [&mut out_left, &mut out_right])
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InputNotFrameAligned);
+        let err = resampler
+            .finalize_planar(&[&left, &left], &mut [&mut out_left, &mut out_right[..7]])
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::OutputNotFrameAligned);
+    }
+
+    /// Check that our buffer sizing helpers report sufficient sizes.
+    #[test]
+    fn buffer_sizing() {
+        let mut seed = 0x2545_f491_4f6c_dd1d_u64;
+        let mut random = move |max: usize| {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            (seed % max as u64) as usize + 1
+        };
+
+        for type_ in [
+            ResampleType::SincMediumQuality,
+            ResampleType::SincFastest,
+            ResampleType::ZeroOrderHold,
+            ResampleType::Linear,
+        ] {
+            for ratio in [1.0 / 64.0, 0.3, 1.0, 48000.0 / 44100.0, 7.3, 256.0] {
+                let mut resampler = Resampler::new(type_, 2, 1, 1).unwrap();
+                let () = resampler.set_ratio(ratio).unwrap();
+
+                // Starting from a pristine state, we should get at least
+                // as much output as requested.
+                let frames = resampler.input_frames_needed(100);
+                let mut input = &vec![0.25f32; frames * 2][..];
+                let mut output = vec![0.0; 2 * resampler.max_output_frames(frames) * 2];
+                let mut written = 0;
+                while !input.is_empty() {
+                    let processed = resampler.process(input, &mut output).unwrap();
+                    written += processed.written;
+                    input = &input[processed.read..];
+                }
+                assert!(written >= 200, "{type_:?} {ratio}: {written}");
+                assert!(written <= resampler.max_output_frames(frames) * 2);
+
+                // Use output buffers larger than necessary, to check that
+                // the reported bounds actually hold.
+                for _ in 0..20 {
+                    let frames = random(500);
+                    let max_frames = resampler.max_output_frames(frames);
+                    let input = vec![0.25f32; frames * 2];
+                    let mut output = vec![0.0; 2 * max_frames * 2];
+                    let processed = resampler.process(&input, &mut output).unwrap();
+                    assert_eq!(processed.read, input.len());
+                    assert!(processed.written <= max_frames * 2);
+                }
+
+                let tail_frames = resampler.tail_frames();
+                let mut output = vec![0.0; 2 * tail_frames * 2];
+                let processed = resampler.finalize(&[0.0f32; 0], &mut output).unwrap();
+                assert!(processed.written <= tail_frames * 2);
+            }
+        }
+    }
+
+    /// Make sure that invalid ratios are rejected.
+    #[test]
+    fn invalid_ratio() {
+        let mut resampler = Resampler::new(ResampleType::Linear, 1, 44100, 48000).unwrap();
+        for ratio in [0.0, 1.0 / 512.0, 257.0, f64::NAN, f64::INFINITY] {
+            let err = resampler.set_ratio(ratio).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::BadSrcRatio);
+
+            let mut output = [0.0f32; 16];
+            let err = resampler
+                .process_with_ratio(&[0.0f32; 8], &mut output, ratio)
+                .unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::BadSrcRatio);
+
+            let err = Resampler::with_ratio(ResampleType::Linear, 1, ratio).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::BadSrcRatio);
+        }
+        assert_eq!(resampler.ratio(), 48000.0 / 44100.0);
+    }
+
+    /// Check that the double precision path matches the single
+    /// precision one, while retaining its precision.
+    #[test]
+    fn double_precision() {
+        let input = (0..10_000)
+            .flat_map(|i| {
+                let x = f64::from(i) / 64.0;
+                [x.sin(), x.cos()]
+            })
+            .collect::<Vec<f64>>();
+        let input32 = input.iter().map(|x| *x as f32).collect::<Vec<_>>();
+
+        for type_ in [ResampleType::ZeroOrderHold, ResampleType::Linear] {
+            let mut resampler = Resampler::new(type_, 2, 44100, 48000).unwrap();
+            let mut resampler32 = Resampler::new(type_, 2, 44100, 48000).unwrap();
+            let mut output = vec![0.0f64; 2 * 16_000];
+            let mut output32 = vec![0.0f32; 2 * 16_000];
+            let mut written = 0;
+
+            // Process in blocks of varying size, changing the ratio
+            // along the way.
+            for (i, (chunk, chunk32)) in input
+                .chunks(2 * 997)
+                .zip(input32.chunks(2 * 997))
+                .enumerate()
+            {
+                let ratio = 1.0 + i as f64 / 20.0;
+                let processed = resampler
+                    .process_with_ratio_f64(chunk, &mut output[written..], ratio)
+                    .unwrap();
+                let processed32 = resampler32
+                    .process_with_ratio(chunk32, &mut output32[written..], ratio)
+                    .unwrap();
+                assert_eq!(processed.read, chunk.len(), "{type_:?}");
+                assert_eq!(processed.read, processed32.read, "{type_:?}");
+                assert_eq!(processed.written, processed32.written, "{type_:?}");
+                written += processed.written;
+            }
+
+            let processed = resampler.finalize_f64(&[], &mut output[written..]).unwrap();
+            assert_eq!(processed.written, 0);
+
+            let max_diff = output[..written]
+                .iter()
+                .zip(&output32)
+                .fold(0f64, |max, (a, b)| max.max((a - f64::from(*b)).abs()));
+            assert!(max_diff < 1e-6, "{type_:?}: {max_diff}");
+        }
+
+        // Double precision input retains its precision.
+        let mut resampler = Resampler::new(ResampleType::Linear, 1, 1, 2).unwrap();
+        let input = [1.0, 1.0 + 1e-12, 1.0 + 2e-12, 1.0 + 3e-12];
+        let mut output = [0.0f64; 8];
+        let processed = resampler.finalize_f64(&input, &mut output).unwrap();
+        assert!(processed.written > 2);
+        let output = &output[..processed.written];
+        assert!(output.windows(2).all(|pair| pair[0] <= pair[1]));
+        assert!(output.iter().any(|x| *x != 1.0 && *x - 1.0 < 1e-11));
+
+        let mut resampler = Resampler::new(ResampleType::SincBestQuality, 1, 1, 2).unwrap();
+        let err = resampler.process_f64(&input, &mut [0.0; 8]).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::BadConverter);
+    }
+
+    /// Check that the double precision path of every converter type
+    /// matches the single precision one within tolerance, or is
+    /// reported as unsupported.
+    #[test]
+    fn double_precision_every_type() {
+        let types = [
+            ResampleType::SincBestQuality,
+            ResampleType::SincMediumQuality,
+            ResampleType::SincFastest,
+            ResampleType::ZeroOrderHold,
+            ResampleType::Linear,
+        ];
+        let input = (0..2 * 4_000)
+            .map(|i| ((i / 2) as f64 / (16.0 + (i % 2) as f64)).sin() * 0.9)
+            .collect::<Vec<f64>>();
+        let input32 = input.iter().map(|x| *x as f32).collect::<Vec<_>>();
+
+        for type_ in types {
+            let tolerance = match type_ {
+                ResampleType::ZeroOrderHold | ResampleType::Linear => 1e-6,
+                ResampleType::SincMediumQuality | ResampleType::SincFastest => 1e-5,
+                ResampleType::SincBestQuality => {
+                    assert!(!type_.supports_f64());
+                    let mut resampler = Resampler::new(type_, 2, 44100, 48000).unwrap();
+                    let err = resampler.process_f64(&input, &mut [0.0; 8]).unwrap_err();
+                    assert_eq!(err.kind(), ErrorKind::BadConverter);
+                    continue
+                },
+            };
+            assert!(type_.supports_f64(), "{type_:?}");
+
+            let mut resampler = Resampler::new(type_, 2, 44100, 48000).unwrap();
+            let mut resampler32 = Resampler::new(type_, 2, 44100, 48000).unwrap();
+            let mut output = vec![0.0f64; 2 * 5_000];
+            let mut output32 = vec![0.0f32; 2 * 5_000];
+
+            let processed = resampler.finalize_f64(&input, &mut output).unwrap();
+            let processed32 = resampler32.finalize(&input32, &mut output32).unwrap();
+            assert_eq!(processed, processed32, "{type_:?}");
+
+            let max_diff = output[..processed.written]
+                .iter()
+                .zip(&output32)
+                .fold(0f64, |max, (a, b)| max.max((a - f64::from(*b)).abs()));
+            assert!(max_diff < tolerance, "{type_:?}: {max_diff}");
+        }
+    }
+
+    /// Check that the double precision sinc converters match their
+    /// single precision counterparts, for all channel counts
+    /// `libsamplerate` has dedicated code paths for.
+    #[test]
+    fn double_precision_sinc() {
+        for type_ in [ResampleType::SincFastest, ResampleType::SincMediumQuality] {
+            for channels in [1u8, 2, 3, 4, 6] {
+                let frame_len = usize::from(channels);
+                let input = (0..5_000 * frame_len)
+                    .map(|i| {
+                        let x = (i / frame_len) as f64 / (16.0 + (i % frame_len) as f64);
+                        x.sin() * 0.9
+                    })
+                    .collect::<Vec<f64>>();
+                let input32 = input.iter().map(|x| *x as f32).collect::<Vec<_>>();
+
+                let mut resampler = Resampler::new(type_, channels, 48000, 44100).unwrap();
+                let mut resampler32 = Resampler::new(type_, channels, 48000, 44100).unwrap();
+                let mut output = vec![0.0f64; 12_000 * frame_len];
+                let mut output32 = vec![0.0f32; 12_000 * frame_len];
+                let mut written = 0;
+
+                // Process in blocks of varying size, changing the ratio
+                // along the way.
+                for (i, (chunk, chunk32)) in input
+                    .chunks(frame_len * 997)
+                    .zip(input32.chunks(frame_len * 997))
+                    .enumerate()
+                {
+                    let ratio = 0.9 + i as f64 / 5.0;
+                    let processed = resampler
+                        .process_with_ratio_f64(chunk, &mut output[written..], ratio)
+                        .unwrap();
+                    let processed32 = resampler32
+                        .process_with_ratio(chunk32, &mut output32[written..], ratio)
+                        .unwrap();
+                    assert_eq!(processed, processed32, "{type_:?}/{channels}");
+                    written += processed.written;
+                }
+
+                let processed = resampler.finalize_f64(&[], &mut output[written..]).unwrap();
+                let processed32 = resampler32
+                    .finalize(&[0.0f32; 0], &mut output32[written..])
+                    .unwrap();
+                assert_eq!(processed, processed32, "{type_:?}/{channels}");
+                assert_ne!(processed.written, 0);
+                written += processed.written;
+
+                let max_diff = output[..written]
+                    .iter()
+                    .zip(&output32)
+                    .fold(0f64, |max, (a, b)| max.max((a - f64::from(*b)).abs()));
+                assert!(max_diff < 1e-5, "{type_:?}/{channels}: {max_diff}");
+            }
+        }
+
+        // Double precision input retains its precision, i.e., is not
+        // rounded to single precision along the way.
+        let input = (0..1000)
+            .map(|i| (f64::from(i) / 10.0).sin() / 3.0)
+            .collect::<Vec<f64>>();
+        let rounded = input
+            .iter()
+            .map(|x| f64::from(*x as f32))
+            .collect::<Vec<f64>>();
+        assert_ne!(input, rounded);
+
+        let convert = |input: &[f64]| {
+            let mut resampler = Resampler::new(ResampleType::SincFastest, 1, 1, 2).unwrap();
+            let mut output = vec![0.0f64; 2100];
+            let processed = resampler.finalize_f64(input, &mut output).unwrap();
+            let () = output.truncate(processed.written);
+            output
+        };
+        let output = convert(&input);
+        let output_rounded = convert(&rounded);
+        let max_diff = output
+            .iter()
+            .zip(&output_rounded)
+            .fold(0f64, |max, (a, b)| max.max((a - b).abs()));
+        assert!(max_diff > 0.0 && max_diff < 1e-7, "{max_diff}");
+    }
+
+    /// Check that we can create a converter from an arbitrary ratio and
+    /// read it back.
+    #[test]
+    fn arbitrary_ratio() {
+        let mut resampler = Resampler::with_ratio(ResampleType::Linear, 1, 1.000137).unwrap();
+        assert_eq!(resampler.ratio(), 1.000137);
+
+        let input = vec![0.5f32; 100_000];
+        let mut output = vec![0.0f32; resampler.max_output_frames(input.len())];
+        let processed = resampler.finalize(&input, &mut output).unwrap();
+        assert_eq!(processed.read, input.len());
+        assert!(
+            processed.written.abs_diff(100_014) <= 1,
+            "{}",
+            processed.written
+        );
+
+        let () = resampler.set_ratio(0.5).unwrap();
+        assert_eq!(resampler.ratio(), 0.5);
+        let _processed = resampler
+            .process_with_ratio(&input, &mut output, 2.0)
+            .unwrap();
+        assert_eq!(resampler.ratio(), 2.0);
+
+        let reference = convert(ResampleType::Linear, 1, 44100, 48000, &input).unwrap();
+        let output = convert_ratio(ResampleType::Linear, 1, 48000.0 / 44100.0, &input).unwrap();
+        // The expected output length is calculated using floating point
+        // arithmetic and so may exceed the reference by a frame.
+        assert!(output.len().abs_diff(reference.len()) <= 1);
+        assert_eq!(output[..reference.len()], reference);
+    }
+
+    /// Check that converting into a caller provided buffer matches
+    /// regular conversion.
+    #[test]
+    fn conversion_into_buffer() {
+        /// Convert `input` the way `convert` used to, by growing the
+        /// output buffer until all input is consumed.
+        fn convert_growing(
+            type_: ResampleType,
+            channels: u8,
+            from_rate: u32,
+            to_rate: u32,
+            input: &[f32],
+        ) -> Vec<f32> {
+            let frames = input.len() / usize::from(channels);
+            let output_frames = (frames * to_rate as usize).div_ceil(from_rate as usize);
+            let mut output = vec![0.0; output_frames * usize::from(channels)];
+            let mut resampler = Resampler::new(type_, channels, from_rate, to_rate).unwrap();
+            let mut total = Processed::default();
+
+            loop {
+                let processed = resampler
+                    .finalize(&input[total.read..], &mut output[total.written..])
+                    .unwrap();
+                total.read += processed.read;
+                total.written += processed.written;
+
+                if total.read >= input.len() {
+                    break
+                }
+                let () = output.resize(output.len() + 64 * usize::from(channels), 0.0);
+            }
+
+            let () = output.truncate(total.written);
+            output
+        }
+
+        let input = (0..2 * 1000)
+            .map(|i| (i as f32 / 16.0).sin())
+            .collect::<Vec<f32>>();
+
+        for type_ in [
+            ResampleType::SincMediumQuality,
+            ResampleType::SincFastest,
+            ResampleType::ZeroOrderHold,
+            ResampleType::Linear,
+        ] {
+            for (from_rate, to_rate) in [(44100, 48000), (48000, 8000), (1, 3)] {
+                let expected = convert_growing(type_, 2, from_rate, to_rate, &input);
+                let len = max_convert_len(type_, 2, from_rate, to_rate, input.len()).unwrap();
+
+                let mut output = vec![0.0f32; len];
+                let written =
+                    convert_into(type_, 2, from_rate, to_rate, &input, &mut output).unwrap();
+                assert!(written <= len, "{type_:?}");
+                assert_eq!(written, expected.len(), "{type_:?}");
+                assert_eq!(output[..written], expected, "{type_:?}");
+
+                // A buffer one frame short of the result is rejected.
+                let mut output = vec![0.0f32; written - 2];
+                let err =
+                    convert_into(type_, 2, from_rate, to_rate, &input, &mut output).unwrap_err();
+                assert_eq!(err.kind(), ErrorKind::OutputBufferTooSmall, "{type_:?}");
+            }
+        }
+
+        let written = convert_into(ResampleType::Linear, 1, 1, 2, &[0i16; 0], &mut []).unwrap();
+        assert_eq!(written, 0);
+
+        // The number of output frames is computed without overflowing
+        // on 32 bit targets.
+        let len = max_convert_len(ResampleType::Linear, 2, 44100, 48000, 2 * 100_000_000).unwrap();
+        let tail = tail_frames(ResampleType::Linear, 48000.0 / 44100.0);
+        assert_eq!(len, 2 * (108_843_538 + tail));
+
+        let err = max_convert_len(ResampleType::Linear, 2, 0, 2, 4).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::BadSrcRatio);
+        let err = max_convert_len(ResampleType::Linear, 2, 1, 2, 3).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InputNotFrameAligned);
+    }
+
+    /// Check that the output length policy is honored.
+    #[test]
+    fn output_length() {
+        let types = [
+            ResampleType::SincMediumQuality,
+            ResampleType::SincFastest,
+            ResampleType::ZeroOrderHold,
+            ResampleType::Linear,
+        ];
+        let lengths = [OutputLength::Exact, OutputLength::ExactAlignedToDelay];
+
+        for type_ in types {
+            for length in lengths {
+                for (from_rate, to_rate) in [(44100, 48000), (48000, 44100), (8000, 44100)] {
+                    for frames in [0, 1, 7, 441, 1000, 4410] {
+                        let input = (0..2 * frames)
+                            .map(|i| (i as f32 / 10.0).sin())
+                            .collect::<Vec<f32>>();
+                        let output =
+                            convert_with_length(type_, 2, from_rate, to_rate, &input, length)
+                                .unwrap();
+                        let expected = (frames * to_rate as usize).div_ceil(from_rate as usize);
+                        assert_eq!(
+                            output.len(),
+                            2 * expected,
+                            "{type_:?} {length:?}: {frames} @ {from_rate} -> {to_rate}"
+                        );
+                    }
+                }
+            }
+        }
+
+        // Streaming conversion is cut off or padded upon finalization.
+        let input = vec![0.25f32; 10_000];
+        for type_ in types {
+            let mut resampler = Resampler::new(type_, 1, 44100, 48000).unwrap();
+            let () = resampler.set_output_length(OutputLength::Exact).unwrap();
+            assert_eq!(resampler.output_length(), OutputLength::Exact);
+
+            let mut output = vec![0.0f32; 20_000];
+            let mut written = 0;
+            for chunk in input[..9000].chunks(1000) {
+                let processed = resampler.process(chunk, &mut output[written..]).unwrap();
+                assert_eq!(processed.read, chunk.len());
+                written += processed.written;
+            }
+
+            let mut rest = &input[9000..];
+            loop {
+                let processed = resampler.finalize(rest, &mut output[written..]).unwrap();
+                rest = &rest[processed.read..];
+                written += processed.written;
+                if processed.written == 0 && rest.is_empty() {
+                    break
+                }
+            }
+            assert_eq!(written, 10_885, "{type_:?}");
+
+            let () = resampler.reset().unwrap();
+            let processed = resampler.finalize(&input, &mut output).unwrap();
+            assert_eq!(processed.written, 10_885, "{type_:?}");
+        }
+    }
+
+    /// Check that integer output gets dithered as configured.
+    #[test]
+    fn dithering() {
+        // A square wave of one quantization step, which linear
+        // interpolation turns into a signal mostly in between steps.
+        let input = (0..20_000)
+            .map(|i| i16::from(i % 2 == 0))
+            .collect::<Vec<_>>();
+        let mean = |output: &[i16]| {
+            output.iter().map(|x| f64::from(*x)).sum::<f64>() / output.len() as f64
+        };
+
+        let output = convert(ResampleType::Linear, 1, 3, 4, &input).unwrap();
+        let plain = mean(&output);
+
+        let builder = Resampler::builder(ResampleType::Linear)
+            .channels(1)
+            .rates(3, 4);
+        for dither in [Dither::Rectangular, Dither::Triangular, Dither::NoiseShaped] {
+            let output = builder.clone().dither(dither, 1).convert(&input).unwrap();
+            let dithered = mean(&output);
+            assert!((dithered - 0.5).abs() < 0.02, "{dither:?}: {dithered}");
+            assert!((dithered - 0.5).abs() < (plain - 0.5).abs());
+
+            let again = builder.clone().dither(dither, 1).convert(&input).unwrap();
+            assert_eq!(again, output);
+            let other = builder.clone().dither(dither, 2).convert(&input).unwrap();
+            assert_ne!(other, output);
+
+            // Dither combines with other options.
+            let output = builder
+                .clone()
+                .ratio(1.37)
+                .output_length(OutputLength::ExactAlignedToDelay)
+                .dither(dither, 1)
+                .convert(&input)
+                .unwrap();
+            assert_eq!(output.len(), 27_400);
+            let dithered = mean(&output);
+            assert!((dithered - 0.5).abs() < 0.02, "{dither:?}: {dithered}");
+        }
+
+        // 32 bit output is not affected.
+        let input = input
+            .iter()
+            .map(|x| i32::from(*x) << 16)
+            .collect::<Vec<_>>();
+        let expected = builder.convert(&input).unwrap();
+        let output = builder
+            .clone()
+            .dither(Dither::Triangular, 1)
+            .convert(&input)
+            .unwrap();
+        assert_eq!(output, expected);
+        let input = (0..20_000)
+            .map(|i| i16::from(i % 2 == 0))
+            .collect::<Vec<_>>();
+
+        // Resetting the converter restarts the noise sequence, while
+        // floating point output is unaffected.
+        let mut resampler = Resampler::new(ResampleType::Linear, 1, 3, 4).unwrap();
+        let () = resampler.set_dither(Dither::Triangular, 3);
+        let mut output = vec![0i16; 30_000];
+        let processed = resampler.finalize(&input, &mut output).unwrap();
+        let () = resampler.reset().unwrap();
+        let mut again = vec![0i16; 30_000];
+        let processed_again = resampler.finalize(&input, &mut again).unwrap();
+        assert_eq!(processed, processed_again);
+        assert_eq!(output, again);
+
+        let input = vec![0.25f32; 1000];
+        let mut output = vec![0.0f32; 2000];
+        let () = resampler.reset().unwrap();
+        let processed = resampler.finalize(&input, &mut output).unwrap();
+        assert!(output[..processed.written].iter().all(|x| *x == 0.25));
+    }
+
+    /// Check that we can change the conversion ratio on the fly.
+    #[test]
+    fn ratio_changes() {
+        let input = vec![0.5; 1000];
+        let mut output = vec![0.0; 4000];
+
+        for type_ in [
+            ResampleType::SincFastest,
+            ResampleType::ZeroOrderHold,
+            ResampleType::Linear,
+        ] {
+            let mut resampler = Resampler::new(type_, 1, 1, 1).unwrap();
+            let () = resampler.set_ratio(2.0).unwrap();
+            let processed = resampler.finalize(&input, &mut output).unwrap();
+            assert_eq!(processed.read, input.len());
+            assert!(
+                (1990..=2010).contains(&processed.written),
+                "{}",
+                processed.written
+            );
+
+            // Glide from a ratio of 1.0 to one of 3.0, which should
+            // produce roughly twice the input.
+            let mut resampler = Resampler::new(type_, 1, 1, 1).unwrap();
+            let processed = resampler
+                .process_with_ratio(&input, &mut output[..2000], 3.0)
+                .unwrap();
+            assert_eq!(resampler.ratio, 3.0);
+            assert!(
+                (1800..=2000).contains(&processed.written),
+                "{}",
+                processed.written
+            );
+        }
+    }
+
+    /// Check that we report sensible latencies.
+    #[test]
+    fn latency() {
+        let resampler = Resampler::new(ResampleType::Linear, 1, 44100, 88200).unwrap();
+        let latency = resampler.latency();
+        assert_eq!(
+            latency,
+            Latency {
+                input_frames: 1,
+                output_frames: 2,
+            }
+        );
+
+        let resampler = Resampler::new(ResampleType::SincFastest, 2, 48000, 24000).unwrap();
+        let latency = resampler.latency();
+        assert_eq!(latency.input_frames, 39);
+        assert_eq!(latency.output_frames, 20);
+    }
+
+    /// Check that with delay compensation enabled, an impulse in the
+    /// input lines up with the one in the output and that the output
+    /// length matches the input length.
+    #[test]
+    fn delay_compensation() {
+        let mut input = vec![0.0f32; 2000];
+        input[1000] = 1.0;
+
+        for type_ in [
+            ResampleType::SincFastest,
+            ResampleType::ZeroOrderHold,
+            ResampleType::Linear,
+        ] {
+            for (ratio, chunk) in [(2.0, 2000), (2.0, 7), (0.5, 2000), (0.5, 13)] {
+                let mut resampler = Resampler::new(type_, 1, 1, 1).unwrap();
+                let () = resampler.set_ratio(ratio).unwrap();
+                let () = resampler.set_delay_compensation(true).unwrap();
+
+                let mut output = Vec::new();
+                let mut buffer = vec![0.0f32; 4 * chunk];
+                for chunk in input.chunks(chunk) {
+                    let processed = resampler.process(chunk, &mut buffer).unwrap();
+                    assert_eq!(processed.read, chunk.len());
+                    let () = output.extend_from_slice(&buffer[..processed.written]);
+                }
+                loop {
+                    let processed = resampler.finalize(&[], &mut buffer[..3]).unwrap();
+                    let () = output.extend_from_slice(&buffer[..processed.written]);
+                    if processed.written == 0 {
+                        break
+                    }
+                }
+
+                let peak = output
+                    .iter()
+                    .enumerate()
+                    .fold(
+                        (0, 0.0),
+                        |(idx, max), (i, x)| {
+                            if *x > max {
+                                (i, *x)
+                            } else {
+                                (idx, max)
+                            }
+                        },
+                    )
+                    .0;
+                assert_eq!(peak, (1000.0 * ratio) as usize, "{type_:?} @ {ratio}");
+
+                if type_ != ResampleType::SincFastest {
+                    assert_eq!(
+                        output.len(),
+                        (2000.0 * ratio) as usize,
+                        "{type_:?} @ {ratio}"
+                    );
+                }
+            }
+        }
+    }
+
+    /// Check that feeding input one frame at a time produces the same
+    /// output as feeding it in one go.
+    #[test]
+    fn single_frames() {
+        let input = (0..256)
+            .map(|i| (i as f32 / 16.0).sin())
+            .collect::<Vec<_>>();
+
+        for type_ in [ResampleType::ZeroOrderHold, ResampleType::Linear] {
+            for ratio in [0.3, 1.0, 2.5] {
+                let mut resampler = Resampler::new(type_, 1, 1, 1).unwrap();
+                let () = resampler.set_ratio(ratio).unwrap();
+                let mut reference = vec![0.0f32; 1024];
+                let processed = resampler.process(&input, &mut reference).unwrap();
+                let () = reference.truncate(processed.written);
+
+                let mut resampler = Resampler::new(type_, 1, 1, 1).unwrap();
+                let () = resampler.set_ratio(ratio).unwrap();
+                let mut output = Vec::new();
+                let mut buffer = [0.0f32; 8];
+                for sample in &input {
+                    // Use a dedicated buffer, to not have the converter
+                    // read neighboring samples by accident.
+                    let frame = [*sample];
+                    let processed = resampler.process(&frame, &mut buffer).unwrap();
+                    assert_eq!(processed.read, 1);
+                    let () = output.extend_from_slice(&buffer[..processed.written]);
+                }
+
+                let len = output.len().min(reference.len());
+                assert!(len + 3 > reference.len(), "{type_:?} @ {ratio}");
+                assert_eq!(output[..len], reference[..len], "{type_:?} @ {ratio}");
+            }
+        }
+    }
+
+    /// Check that channels are mixed as part of the conversion.
+    #[test]
+    fn channel_mix() {
+        let frames = 4096;
+        let surround = (0..frames)
+            .flat_map(|i| {
+                let x = i as f32 / 32.0;
+                [x.sin(), x.cos(), 0.5 * x.sin(), 1.0, -0.25, 0.25]
+            })
+            .collect::<Vec<f32>>();
+        let mix = ChannelMix::surround_to_stereo();
+        let mut stereo = vec![0.0f32; 2 * frames];
+        let () = mix.apply(&surround, &mut stereo);
+
+        // Downmixing happens before the conversion.
+        let mut resampler =
+            Resampler::with_channel_mix(ResampleType::Linear, mix, 44100, 48000).unwrap();
+        assert_eq!(resampler.channels(), 2);
+        assert_eq!(resampler.input_channels(), 6);
+        assert_eq!(resampler.output_channels(), 2);
+
+        let mut output = vec![0.0f32; 2 * 5000];
+        let processed = resampler.finalize(&surround, &mut output).unwrap();
+        assert_eq!(processed.read, surround.len());
+
+        let mut reference = Resampler::new(ResampleType::Linear, 2, 44100, 48000).unwrap();
+        let mut expected = vec![0.0f32; 2 * 5000];
+        let processed_ref = reference.finalize(&stereo, &mut expected).unwrap();
+        assert_eq!(processed.written, processed_ref.written);
+        assert_eq!(output, expected);
+
+        // Upmixing happens after the conversion.
+        let mono = (0..frames)
+            .map(|i| (i as f32 / 32.0).sin())
+            .collect::<Vec<f32>>();
+        let mut resampler = Resampler::with_channel_mix(
+            ResampleType::Linear,
+            ChannelMix::mono_to_stereo(),
+            44100,
+            48000,
+        )
+        .unwrap();
+        assert_eq!(resampler.channels(), 1);
+
+        let mut output = vec![0.0f32; 2 * 5000];
+        let processed = resampler.finalize(&mono, &mut output).unwrap();
+        assert_eq!(processed.read, mono.len());
+
+        let mut reference = Resampler::new(ResampleType::Linear, 1, 44100, 48000).unwrap();
+        let mut expected = vec![0.0f32; 5000];
+        let processed_ref = reference.finalize(&mono, &mut expected).unwrap();
+        assert_eq!(processed.written, 2 * processed_ref.written);
+        assert!(output[..processed.written]
+            .chunks_exact(2)
+            .zip(&expected)
+            .all(|(frame, sample)| frame == [*sample, *sample]));
+    }
+
+    /// Check that planar data can be mixed as well.
+    #[test]
+    fn planar_channel_mix() {
+        let left = [0.25f32; 512];
+        let right = [0.75f32; 512];
+        let mut mono = [0.0f32; 1024];
+        let mut resampler = Resampler::with_channel_mix(
+            ResampleType::ZeroOrderHold,
+            ChannelMix::stereo_to_mono(),
+            1,
+            2,
+        )
+        .unwrap();
+
+        let processed = resampler
+            .finalize_planar(&[&left, &right], &mut [&mut mono])
+            .unwrap();
+        assert_eq!(processed.read, 512);
+        assert_eq!(processed.written, 1024);
+        assert!(mono.iter().all(|x| *x == 0.5));
+
+        let err = resampler
+            .process_planar(&[&left], &mut [&mut mono])
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ChannelCountMismatch);
+    }
 }
@@ -0,0 +1,227 @@
+use std::ffi::c_int;
+
+use libsamplerate_rs::src_float_to_int_array;
+use libsamplerate_rs::src_float_to_short_array;
+use libsamplerate_rs::src_int_to_float_array;
+use libsamplerate_rs::src_short_to_float_array;
+
+
+/// The maximum number of samples `libsamplerate`'s conversion
+/// functions can handle in one go.
+const MAX_CHUNK: usize = c_int::MAX as usize;
+
+
+/// Retrieve the length of a chunk of at most [`MAX_CHUNK`] samples as
+/// a `c_int`.
+#[inline]
+fn chunk_len<T>(chunk: &[T]) -> c_int {
+    debug_assert!(chunk.len() <= MAX_CHUNK);
+    chunk.len() as c_int
+}
+
+
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for f32 {}
+    impl Sealed for i16 {}
+    impl Sealed for i32 {}
+}
+
+
+/// A trait representing a sample format that can be resampled.
+///
+/// `libsamplerate` itself works on `f32` samples, in the range
+/// `[-1.0, 1.0]`. Samples of other formats get converted to and from
+/// this representation transparently. Integer samples are scaled to
+/// their full range and clipped when converted back.
+///
+/// This trait is sealed and cannot be implemented outside of this
+/// crate.
+pub trait Sample: Copy + Default + private::Sealed {
+    /// Convert a slice of samples of this type into `f32` samples.
+    ///
+    /// `input` and `output` are expected to be of the same length.
+    fn to_float(input: &[Self], output: &mut [f32]);
+
+    /// Convert a slice of `f32` samples into samples of this type,
+    /// clipping them as necessary.
+    ///
+    /// `input` and `output` are expected to be of the same length.
+    fn from_float(input: &[f32], output: &mut [Self]);
+
+    /// Retrieve the provided samples as `f32` samples, if they are
+    /// already of this type.
+    fn as_float(samples: &[Self]) -> Option<&[f32]>;
+
+    /// Retrieve the provided samples as mutable `f32` samples, if they
+    /// are already of this type.
+    fn as_float_mut(samples: &mut [Self]) -> Option<&mut [f32]>;
+
+    /// Retrieve the distance between two adjacent values of this type,
+    /// in terms of the `f32` representation, or `None` if conversion
+    /// from `f32` does not quantize samples.
+    fn quantization_step() -> Option<f32>;
+}
+
+impl Sample for f32 {
+    #[inline]
+    fn to_float(input: &[Self], output: &mut [f32]) {
+        let () = output.copy_from_slice(input);
+    }
+
+    #[inline]
+    fn from_float(input: &[f32], output: &mut [Self]) {
+        let () = output.copy_from_slice(input);
+    }
+
+    #[inline]
+    fn as_float(samples: &[Self]) -> Option<&[f32]> {
+        Some(samples)
+    }
+
+    #[inline]
+    fn as_float_mut(samples: &mut [Self]) -> Option<&mut [f32]> {
+        Some(samples)
+    }
+
+    #[inline]
+    fn quantization_step() -> Option<f32> {
+        None
+    }
+}
+
+impl Sample for i16 {
+    fn to_float(input: &[Self], output: &mut [f32]) {
+        debug_assert_eq!(input.len(), output.len());
+
+        for (input, output) in input.chunks(MAX_CHUNK).zip(output.chunks_mut(MAX_CHUNK)) {
+            // SAFETY: Both pointers are valid for `input.len()`
+            //         samples.
+            let () = unsafe {
+                src_short_to_float_array(input.as_ptr(), output.as_mut_ptr(), chunk_len(input))
+            };
+        }
+    }
+
+    fn from_float(input: &[f32], output: &mut [Self]) {
+        debug_assert_eq!(input.len(), output.len());
+
+        for (input, output) in input.chunks(MAX_CHUNK).zip(output.chunks_mut(MAX_CHUNK)) {
+            // SAFETY: Both pointers are valid for `input.len()`
+            //         samples.
+            let () = unsafe {
+                src_float_to_short_array(input.as_ptr(), output.as_mut_ptr(), chunk_len(input))
+            };
+        }
+    }
+
+    #[inline]
+    fn as_float(_samples: &[Self]) -> Option<&[f32]> {
+        None
+    }
+
+    #[inline]
+    fn as_float_mut(_samples: &mut [Self]) -> Option<&mut [f32]> {
+        None
+    }
+
+    #[inline]
+    fn quantization_step() -> Option<f32> {
+        Some(1.0 / 32768.0)
+    }
+}
+
+impl Sample for i32 {
+    fn to_float(input: &[Self], output: &mut [f32]) {
+        debug_assert_eq!(input.len(), output.len());
+
+        for (input, output) in input.chunks(MAX_CHUNK).zip(output.chunks_mut(MAX_CHUNK)) {
+            // SAFETY: Both pointers are valid for `input.len()`
+            //         samples.
+            let () = unsafe {
+                src_int_to_float_array(input.as_ptr(), output.as_mut_ptr(), chunk_len(input))
+            };
+        }
+    }
+
+    fn from_float(input: &[f32], output: &mut [Self]) {
+        debug_assert_eq!(input.len(), output.len());
+
+        for (input, output) in input.chunks(MAX_CHUNK).zip(output.chunks_mut(MAX_CHUNK)) {
+            // SAFETY: Both pointers are valid for `input.len()`
+            //         samples.
+            let () = unsafe {
+                src_float_to_int_array(input.as_ptr(), output.as_mut_ptr(), chunk_len(input))
+            };
+        }
+    }
+
+    #[inline]
+    fn as_float(_samples: &[Self]) -> Option<&[f32]> {
+        None
+    }
+
+    #[inline]
+    fn as_float_mut(_samples: &mut [Self]) -> Option<&mut [f32]> {
+        None
+    }
+
+    #[inline]
+    fn quantization_step() -> Option<f32> {
+        Some(1.0 / 2147483648.0)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+
+    /// Check that we can convert 16 bit integer samples to and from
+    /// floating point ones.
+    #[test]
+    fn short_conversion() {
+        let input = [0i16, 1, -1, 16384, -16384, i16::MAX, i16::MIN];
+        let mut float = [0.0; 7];
+        let () = i16::to_float(&input, &mut float);
+        assert_eq!(float[0], 0.0);
+        assert_eq!(float[3], 0.5);
+        assert_eq!(float[4], -0.5);
+        assert_eq!(float[6], -1.0);
+
+        let mut output = [0i16; 7];
+        let () = i16::from_float(&float, &mut output);
+        assert_eq!(output, input);
+    }
+
+    /// Check that we can convert 32 bit integer samples to and from
+    /// floating point ones.
+    #[test]
+    fn int_conversion() {
+        let input = [0i32, 1 << 30, -(1 << 30), i32::MIN];
+        let mut float = [0.0; 4];
+        let () = i32::to_float(&input, &mut float);
+        assert_eq!(float, [0.0, 0.5, -0.5, -1.0]);
+
+        let mut output = [0i32; 4];
+        let () = i32::from_float(&float, &mut output);
+        assert_eq!(output, input);
+    }
+
+    /// Make sure that out-of-range values are clipped when converting
+    /// to integers.
+    #[test]
+    fn clipping() {
+        let input = [1.5f32, -1.5, 1.0, -1.0];
+
+        let mut output = [0i16; 4];
+        let () = i16::from_float(&input, &mut output);
+        assert_eq!(output, [i16::MAX, i16::MIN, i16::MAX, i16::MIN]);
+
+        let mut output = [0i32; 4];
+        let () = i32::from_float(&input, &mut output);
+        assert_eq!(output, [i32::MAX, i32::MIN, i32::MAX, i32::MIN]);
+    }
+}
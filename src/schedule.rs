@@ -0,0 +1,387 @@
+use std::borrow::Cow;
+
+use crate::error::Error;
+use crate::error::ErrorKind;
+use crate::resample_type::ResampleType;
+use crate::resampler::check_frame_aligned;
+use crate::resampler::check_ratio;
+use crate::resampler::Resampler;
+use crate::sample::Sample;
+
+
+/// The maximum number of frames converted with a single ratio when
+/// interpolating linearly.
+const BLOCK_FRAMES: usize = 64;
+
+
+/// The way the conversion ratio transitions between the breakpoints of
+/// a schedule.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RatioInterpolation {
+    /// The ratio of a breakpoint applies unchanged until the next one.
+    #[default]
+    Stepped,
+    /// The ratio changes linearly from one breakpoint to the next.
+    Linear,
+}
+
+
+/// Check that `schedule` is non-empty, strictly ordered by frame, and
+/// only contains supported ratios.
+fn check_schedule(schedule: &[(usize, f64)]) -> Result<(), Error> {
+    if schedule.is_empty() || schedule.windows(2).any(|pair| pair[0].0 >= pair[1].0) {
+        return Err(Error::from(ErrorKind::InvalidSchedule))
+    }
+
+    schedule
+        .iter()
+        .try_for_each(|(_frame, ratio)| check_ratio(*ratio))
+}
+
+
+/// Determine the ratio in effect at input frame `frame`.
+fn ratio_at(schedule: &[(usize, f64)], frame: usize, interpolation: RatioInterpolation) -> f64 {
+    let idx = schedule.partition_point(|(start, _ratio)| *start <= frame);
+    match (idx, schedule.get(idx)) {
+        (0, _) => schedule[0].1,
+        (_, None) => schedule[idx - 1].1,
+        (_, Some(next)) => {
+            let prev = schedule[idx - 1];
+            match interpolation {
+                RatioInterpolation::Stepped => prev.1,
+                RatioInterpolation::Linear => {
+                    let progress = (frame - prev.0) as f64 / (next.0 - prev.0) as f64;
+                    prev.1 + (next.1 - prev.1) * progress
+                },
+            }
+        },
+    }
+}
+
+
+/// Convert `input` in its entirety, appending the output to `output`.
+///
+/// If `ratio` is provided, the conversion ratio transitions towards it
+/// over the course of the block. Otherwise the ratio currently set is
+/// used.
+///
+/// When finalizing, the converter is flushed after all of `input` got
+/// consumed, with `ratio` being the one reached at the end of the
+/// flush.
+fn convert_block(
+    resampler: &mut Resampler,
+    input: &[f32],
+    output: &mut Vec<f32>,
+    ratio: Option<f64>,
+    finalize: bool,
+) -> Result<(), Error> {
+    let channels = usize::from(resampler.channels());
+    let mut read = 0;
+    let mut written = output.len();
+    // The ratio glides linearly over the course of the output buffer,
+    // so the buffer is sized to the output expected at the average
+    // ratio. Leftover input is processed at the target ratio.
+    let mut block_ratio =
+        ratio.map_or(resampler.ratio(), |ratio| (resampler.ratio() + ratio) / 2.0);
+
+    loop {
+        if written == output.len() {
+            let frames = (input.len() - read) / channels;
+            let frames = if finalize {
+                resampler.max_output_frames(frames)
+            } else {
+                ((frames as f64 * block_ratio).ceil() as usize).max(1)
+            };
+            let () = output.resize(output.len() + frames * channels, 0.0);
+            block_ratio = resampler.ratio();
+        }
+
+        let in_buf = &input[read..];
+        let out_buf = &mut output[written..];
+        let processed = if finalize {
+            if let Some(ratio) = ratio {
+                resampler.finalize_with_ratio(in_buf, out_buf, ratio)?
+            } else {
+                resampler.finalize(in_buf, out_buf)?
+            }
+        } else if let Some(ratio) = ratio {
+            resampler.process_with_ratio(in_buf, out_buf, ratio)?
+        } else {
+            resampler.process(in_buf, out_buf)?
+        };
+        read += processed.read;
+        written += processed.written;
+
+        let done = if finalize {
+            processed.written == 0
+        } else {
+            read == input.len()
+        };
+
+        if done {
+            let () = output.truncate(written);
+            break Ok(())
+        }
+    }
+}
+
+
+/// Perform a samplerate conversion of a large chunk of audio with the
+/// conversion ratio following a schedule.
+///
+/// `schedule` is a list of `(frame, ratio)` breakpoints, strictly
+/// ordered by input frame, with at least one entry. The ratio of the
+/// first breakpoint applies from the start of the input and that of
+/// the last one until its end. In between, the ratio either steps from
+/// one breakpoint's value to the next or transitions linearly, as per
+/// `interpolation`. As usual, a ratio is defined as output samplerate
+/// divided by input samplerate, so a ratio larger than `1.0` slows
+/// playback down.
+///
+/// An invalid schedule is reported as an error of kind
+/// [`ErrorKind::InvalidSchedule`], while unsupported ratios result in
+/// an [`ErrorKind::BadSrcRatio`] error.
+///
+/// Integer output is not dithered. Convert to `f32` samples and drive a
+/// [`Resampler`] with [dither][Resampler::set_dither] enabled via
+/// [`Resampler::process_with_ratio`] if dither is required.
+///
+/// # Example
+///
+/// ```
+/// use resample::{convert_with_schedule, RatioInterpolation, ResampleType};
+///
+/// // A tape stop: slow down to a quarter of the original speed over the
+/// // course of a second of 44.1kHz input.
+/// let input = vec![0.0f32; 44100];
+/// let schedule = [(0, 1.0), (44100, 4.0)];
+/// let output = convert_with_schedule(
+///     ResampleType::SincFastest,
+///     1,
+///     &input,
+///     &schedule,
+///     RatioInterpolation::Linear,
+/// )
+/// .unwrap();
+/// assert!(output.len().abs_diff(44100 * 5 / 2) <= 4);
+/// ```
+pub fn convert_with_schedule<S>(
+    type_: ResampleType,
+    channels: u8,
+    input: &[S],
+    schedule: &[(usize, f64)],
+    interpolation: RatioInterpolation,
+) -> Result<Vec<S>, Error>
+where
+    S: Sample,
+{
+    let () = check_schedule(schedule)?;
+    let mut resampler = Resampler::with_ratio(type_, channels, schedule[0].1)?;
+    let () = check_frame_aligned(input.len(), channels, ErrorKind::InputNotFrameAligned)?;
+
+    let input = if let Some(input) = S::as_float(input) {
+        Cow::Borrowed(input)
+    } else {
+        let mut buffer = vec![0.0; input.len()];
+        let () = S::to_float(input, &mut buffer);
+        Cow::Owned(buffer)
+    };
+
+    let channels = usize::from(channels);
+    let frames = input.len() / channels;
+    let mut output = Vec::new();
+    let mut start = 0;
+
+    loop {
+        // Blocks never straddle a breakpoint, so that each one is hit
+        // exactly.
+        let next = schedule
+            .iter()
+            .map(|(frame, _ratio)| *frame)
+            .find(|frame| *frame > start)
+            .unwrap_or(usize::MAX);
+        let end = match interpolation {
+            RatioInterpolation::Stepped => next,
+            RatioInterpolation::Linear => next.min(start + BLOCK_FRAMES),
+        };
+        let end = end.min(frames);
+        let block = &input[start * channels..end * channels];
+
+        let ratio = match interpolation {
+            RatioInterpolation::Stepped => {
+                let ratio = ratio_at(schedule, start, interpolation);
+                if ratio != resampler.ratio() {
+                    let () = resampler.set_ratio(ratio)?;
+                }
+                None
+            },
+            // The converter's output lags behind its input, so the
+            // ratio is picked for the input the output corresponds to.
+            RatioInterpolation::Linear => {
+                let frame = end.saturating_sub(resampler.latency().input_frames);
+                Some(ratio_at(schedule, frame, interpolation))
+            },
+        };
+
+        let () = convert_block(&mut resampler, block, &mut output, ratio, false)?;
+
+        if end == frames {
+            // Flushing the converter produces the output for the
+            // remaining input, reaching the ratio in effect at its end.
+            let ratio = ratio.map(|_ratio| ratio_at(schedule, frames, interpolation));
+            let () = convert_block(&mut resampler, &[], &mut output, ratio, true)?;
+            break
+        }
+        start = end;
+    }
+
+    let mut result = vec![S::default(); output.len()];
+    let () = S::from_float(&output, &mut result);
+    Ok(result)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::convert_ratio;
+
+
+    /// Check that we determine the ratio at a given frame correctly.
+    #[test]
+    fn ratio_lookup() {
+        let schedule = [(10, 1.0), (20, 2.0), (40, 0.5)];
+        let stepped = |frame| ratio_at(&schedule, frame, RatioInterpolation::Stepped);
+        let linear = |frame| ratio_at(&schedule, frame, RatioInterpolation::Linear);
+
+        assert_eq!(stepped(0), 1.0);
+        assert_eq!(stepped(19), 1.0);
+        assert_eq!(stepped(20), 2.0);
+        assert_eq!(stepped(1000), 0.5);
+
+        assert_eq!(linear(0), 1.0);
+        assert_eq!(linear(15), 1.5);
+        assert_eq!(linear(20), 2.0);
+        assert_eq!(linear(30), 1.25);
+        assert_eq!(linear(1000), 0.5);
+    }
+
+    /// Check that a schedule with a single breakpoint behaves like a
+    /// conversion with a constant ratio.
+    #[test]
+    fn constant_schedule() {
+        let input = (0..5000)
+            .map(|i| (i as f32 / 16.0).sin())
+            .collect::<Vec<_>>();
+        let reference = convert_ratio(ResampleType::Linear, 1, 1.5, &input).unwrap();
+
+        for interpolation in [RatioInterpolation::Stepped, RatioInterpolation::Linear] {
+            let output =
+                convert_with_schedule(ResampleType::Linear, 1, &input, &[(0, 1.5)], interpolation)
+                    .unwrap();
+            assert_eq!(output, reference, "{interpolation:?}");
+        }
+    }
+
+    /// Check that ratios change at the expected frames.
+    #[test]
+    fn scheduled_ratios() {
+        let input = vec![0.5f32; 2 * 2000];
+
+        // 1000 frames at a ratio of 1.0 followed by 1000 at 2.0.
+        let schedule = [(0, 1.0), (1000, 2.0)];
+        let output = convert_with_schedule(
+            ResampleType::ZeroOrderHold,
+            2,
+            &input,
+            &schedule,
+            RatioInterpolation::Stepped,
+        )
+        .unwrap();
+        assert!(output.len().abs_diff(2 * 3000) <= 2 * 2, "{}", output.len());
+
+        // An average ratio of 2.0 over the first 1000 frames followed
+        // by 1000 at 3.0.
+        let schedule = [(0, 1.0), (1000, 3.0)];
+        let output = convert_with_schedule(
+            ResampleType::Linear,
+            2,
+            &input,
+            &schedule,
+            RatioInterpolation::Linear,
+        )
+        .unwrap();
+        assert!(output.len().abs_diff(2 * 5000) <= 2 * 2, "{}", output.len());
+
+        let output = convert_with_schedule(
+            ResampleType::Linear,
+            2,
+            &[0i16; 0],
+            &schedule,
+            RatioInterpolation::Linear,
+        )
+        .unwrap();
+        assert!(output.is_empty());
+    }
+
+    /// Check that the output length up to and past a breakpoint
+    /// tracks a linearly interpolated ratio.
+    #[test]
+    fn interpolated_length() {
+        let types = [
+            ResampleType::SincMediumQuality,
+            ResampleType::SincFastest,
+            ResampleType::ZeroOrderHold,
+            ResampleType::Linear,
+        ];
+        let schedules = [
+            [(0, 1.0), (1000, 3.0)],
+            [(0, 3.0), (1000, 1.0)],
+            [(0, 1.5), (1000, 1.0)],
+        ];
+
+        for type_ in types {
+            for schedule in schedules {
+                let ramp = 1000.0 * (schedule[0].1 + schedule[1].1) / 2.0;
+                for (frames, expected) in [(1000, ramp), (3000, ramp + 2000.0 * schedule[1].1)] {
+                    let input = vec![0.5f32; frames];
+                    let output = convert_with_schedule(
+                        type_,
+                        1,
+                        &input,
+                        &schedule,
+                        RatioInterpolation::Linear,
+                    )
+                    .unwrap();
+                    assert!(
+                        output.len().abs_diff(expected as usize) <= 4,
+                        "{type_:?} {schedule:?} {frames}: {}",
+                        output.len()
+                    );
+                }
+            }
+        }
+    }
+
+    /// Make sure that invalid schedules are rejected.
+    #[test]
+    fn invalid_schedule() {
+        let convert = |schedule: &[(usize, f64)]| {
+            convert_with_schedule(
+                ResampleType::Linear,
+                1,
+                &[0.0f32; 16],
+                schedule,
+                RatioInterpolation::Stepped,
+            )
+            .unwrap_err()
+            .kind()
+        };
+
+        assert_eq!(convert(&[]), ErrorKind::InvalidSchedule);
+        assert_eq!(convert(&[(5, 1.0), (5, 2.0)]), ErrorKind::InvalidSchedule);
+        assert_eq!(convert(&[(5, 1.0), (3, 2.0)]), ErrorKind::InvalidSchedule);
+        assert_eq!(convert(&[(0, 1.0), (3, 0.0)]), ErrorKind::BadSrcRatio);
+    }
+}
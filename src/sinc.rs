@@ -0,0 +1,377 @@
+use std::mem::take;
+
+use crate::coeffs::Coefficients;
+use crate::double::fmod_one;
+use crate::error::Error;
+use crate::error::ErrorKind;
+use crate::resampler::Processed;
+
+
+/// The number of fractional bits of the fixed point filter index.
+const SHIFT_BITS: u32 = 12;
+/// The fixed point representation of `1.0`.
+const FP_ONE: f64 = (1 << SHIFT_BITS) as f64;
+/// The largest ratio `libsamplerate` supports.
+const MAX_RATIO: f64 = 256.0;
+/// The smallest ratio difference the sinc converters consider a change.
+const MIN_RATIO_DIFF: f64 = 1e-10;
+
+
+#[inline]
+fn double_to_fp(x: f64) -> i32 {
+    (x * FP_ONE).round_ties_even() as i32
+}
+
+#[inline]
+fn int_to_fp(x: i32) -> i32 {
+    x << SHIFT_BITS
+}
+
+#[inline]
+fn fp_to_int(x: i32) -> i32 {
+    x >> SHIFT_BITS
+}
+
+#[inline]
+fn fp_to_double(x: i32) -> f64 {
+    f64::from(x & ((1 << SHIFT_BITS) - 1)) / FP_ONE
+}
+
+
+/// A double precision port of `libsamplerate`'s sinc converters.
+///
+/// Input samples are buffered as `f64` and filter coefficients are
+/// kept in double precision, with all accumulation happening in `f64`
+/// as well. The processing logic otherwise mirrors that of
+/// `libsamplerate`, including buffer management and the transition
+/// between ratios over the course of a block. The variants
+/// `libsamplerate` provides for specific channel counts only differ in
+/// loop unrolling and are covered by a single implementation.
+#[derive(Clone, Debug)]
+pub(crate) struct SincConverter {
+    coeffs: &'static [f64],
+    /// The index of the last coefficient the filter may access.
+    coeff_half_len: i32,
+    /// The number of coefficients between two adjacent input frames.
+    index_inc: i32,
+    channels: usize,
+    /// The ratio used at the end of the last block, or `0.0` if none
+    /// has been set yet.
+    last_ratio: f64,
+    /// The fractional position into the input at which to continue.
+    last_position: f64,
+    /// The ring buffer of input samples.
+    buffer: Box<[f64]>,
+    /// The length of the usable part of `buffer`.
+    b_len: usize,
+    /// The index of the current frame in `buffer`.
+    b_current: usize,
+    /// The index one past the last sample in `buffer`.
+    b_end: usize,
+    /// The end of the actual input, once it is known.
+    b_real_end: Option<usize>,
+    /// Per channel accumulators for the left half of the filter.
+    left: Box<[f64]>,
+    /// Per channel accumulators for the right half of the filter.
+    right: Box<[f64]>,
+}
+
+impl SincConverter {
+    /// Create a new converter using the given filter coefficients.
+    pub(crate) fn new(coeffs: &'static Coefficients, channels: u8) -> Self {
+        let channels = usize::from(channels);
+        let coeff_half_len = coeffs.coeffs.len() as i32 - 2;
+        let index_inc = coeffs.increment;
+
+        let b_len = 3
+            * ((f64::from(coeff_half_len) + 2.0) / f64::from(index_inc) * MAX_RATIO + 1.0)
+                .round_ties_even() as usize;
+        // There is a `<=` check against the samples in hand, requiring a
+        // buffer bigger than the calculation above.
+        let b_len = b_len.max(4096) * channels + 1;
+
+        Self {
+            coeffs: coeffs.coeffs,
+            coeff_half_len,
+            index_inc,
+            channels,
+            last_ratio: 0.0,
+            last_position: 0.0,
+            buffer: vec![0.0; b_len + channels].into_boxed_slice(),
+            b_len,
+            b_current: 0,
+            b_end: 0,
+            b_real_end: None,
+            left: vec![0.0; channels].into_boxed_slice(),
+            right: vec![0.0; channels].into_boxed_slice(),
+        }
+    }
+
+    /// Set the ratio to use from now on, without a gradual transition.
+    #[inline]
+    pub(crate) fn set_ratio(&mut self, ratio: f64) {
+        self.last_ratio = ratio;
+    }
+
+    /// Reset the converter's state.
+    pub(crate) fn reset(&mut self) {
+        self.last_ratio = 0.0;
+        self.last_position = 0.0;
+        self.b_current = 0;
+        self.b_end = 0;
+        self.b_real_end = None;
+        let () = self.buffer.fill(0.0);
+    }
+
+    /// Calculate the interpolated coefficient at the fixed point
+    /// `filter_index`.
+    #[inline]
+    fn coeff(&self, filter_index: i32) -> f64 {
+        let fraction = fp_to_double(filter_index);
+        let index = fp_to_int(filter_index) as usize;
+        self.coeffs[index] + fraction * (self.coeffs[index + 1] - self.coeffs[index])
+    }
+
+    /// Calculate a single output frame, centered at the current buffer
+    /// position.
+    fn calc_output(
+        &mut self,
+        increment: i32,
+        start_filter_index: i32,
+        scale: f64,
+        output: &mut [f64],
+    ) {
+        let channels = self.channels;
+        let max_filter_index = int_to_fp(self.coeff_half_len);
+
+        // First apply the left half of the filter.
+        let mut filter_index = start_filter_index;
+        let coeff_count = (max_filter_index - filter_index) / increment;
+        filter_index += coeff_count * increment;
+        let mut data_index = self.b_current as isize - (channels * coeff_count as usize) as isize;
+
+        if data_index < 0 {
+            // Avoid reading before the start of the buffer.
+            let steps = (-data_index as usize).div_ceil(channels);
+            filter_index -= increment * steps as i32;
+            data_index += (steps * channels) as isize;
+        }
+
+        let mut left = take(&mut self.left);
+        let () = left.fill(0.0);
+        let mut data_index = data_index as usize;
+        while filter_index >= 0 {
+            let coeff = self.coeff(filter_index);
+            let frame = &self.buffer[data_index..data_index + channels];
+            let () = left
+                .iter_mut()
+                .zip(frame)
+                .for_each(|(left, sample)| *left += coeff * sample);
+
+            filter_index -= increment;
+            data_index += channels;
+        }
+
+        // Now apply the right half of the filter.
+        let mut filter_index = increment - start_filter_index;
+        let coeff_count = (max_filter_index - filter_index) / increment;
+        filter_index += coeff_count * increment;
+        let mut data_index = self.b_current + channels * (1 + coeff_count as usize);
+
+        let mut right = take(&mut self.right);
+        let () = right.fill(0.0);
+        loop {
+            let coeff = self.coeff(filter_index);
+            let frame = &self.buffer[data_index..data_index + channels];
+            let () = right
+                .iter_mut()
+                .zip(frame)
+                .for_each(|(right, sample)| *right += coeff * sample);
+
+            filter_index -= increment;
+            data_index -= channels;
+            if filter_index <= 0 {
+                break
+            }
+        }
+
+        let () = output
+            .iter_mut()
+            .zip(left.iter().zip(right.iter()))
+            .for_each(|(output, (left, right))| *output = scale * (left + right));
+
+        self.left = left;
+        self.right = right;
+    }
+
+    /// Move input into the buffer, making sure that enough input is
+    /// present around the current position to apply the filter.
+    fn prepare_data(
+        &mut self,
+        input: &[f64],
+        in_used: &mut usize,
+        half_filter_chan_len: usize,
+        end_of_input: bool,
+    ) -> Result<(), Error> {
+        if self.b_real_end.is_some() {
+            // We should be terminating. Just return.
+            return Ok(())
+        }
+
+        let channels = self.channels;
+        let len = if self.b_current == 0 {
+            // Initial state. Set up zeros at the start of the buffer and
+            // then load new data after that.
+            self.b_current = half_filter_chan_len;
+            self.b_end = half_filter_chan_len;
+            self.b_len.saturating_sub(2 * half_filter_chan_len)
+        } else if self.b_end + half_filter_chan_len + channels < self.b_len {
+            // Load data at current end position.
+            self.b_len
+                .saturating_sub(self.b_current + half_filter_chan_len)
+        } else {
+            // Move data at end of buffer back to the start of the buffer.
+            let () = self
+                .buffer
+                .copy_within(self.b_current - half_filter_chan_len..self.b_end, 0);
+            self.b_end = self.b_end + half_filter_chan_len - self.b_current;
+            self.b_current = half_filter_chan_len;
+
+            // Now load data at current end of buffer.
+            self.b_len
+                .saturating_sub(self.b_current + half_filter_chan_len)
+        };
+
+        let len = len.min(input.len() - *in_used);
+        let len = len - len % channels;
+        if self.b_end + len > self.b_len {
+            return Err(Error::from(ErrorKind::SincPrepareDataBadLen))
+        }
+
+        let () = self.buffer[self.b_end..self.b_end + len]
+            .copy_from_slice(&input[*in_used..*in_used + len]);
+        self.b_end += len;
+        *in_used += len;
+
+        if *in_used == input.len()
+            && self.b_end - self.b_current < 2 * half_filter_chan_len
+            && end_of_input
+        {
+            // All data in the current buffer has been consumed and this
+            // is the last buffer.
+            if self.b_len - self.b_end < half_filter_chan_len + 5 {
+                // If necessary, move data down to the start of the
+                // buffer.
+                let () = self
+                    .buffer
+                    .copy_within(self.b_current - half_filter_chan_len..self.b_end, 0);
+                self.b_end = self.b_end + half_filter_chan_len - self.b_current;
+                self.b_current = half_filter_chan_len;
+            }
+
+            self.b_real_end = Some(self.b_end);
+            let len = (half_filter_chan_len + 5).min(self.b_len - self.b_end);
+            let () = self.buffer[self.b_end..self.b_end + len].fill(0.0);
+            self.b_end += len;
+        }
+        Ok(())
+    }
+
+    /// Advance the current buffer position by the whole frames
+    /// contained in `input_index`, returning the remaining fraction.
+    #[inline]
+    fn advance(&mut self, input_index: f64) -> f64 {
+        let rem = fmod_one(input_index);
+        let frames = (input_index - rem).round_ties_even() as usize;
+        self.b_current = (self.b_current + self.channels * frames) % self.b_len;
+        rem
+    }
+
+    /// Convert frame aligned `input` into frame aligned `output`,
+    /// transitioning to `ratio` over the course of the block.
+    pub(crate) fn process(
+        &mut self,
+        input: &[f64],
+        output: &mut [f64],
+        ratio: f64,
+        end_of_input: bool,
+    ) -> Result<Processed, Error> {
+        let channels = self.channels;
+        if self.last_ratio < MAX_RATIO.recip() {
+            self.last_ratio = ratio;
+        }
+
+        let out_count = output.len();
+        let start_ratio = self.last_ratio;
+        let mut src_ratio = start_ratio;
+        let mut in_used = 0;
+        let mut out_gen = 0;
+
+        // Check the sample rate ratio with respect to the buffer
+        // length, determining the maximum number of coefficients on
+        // either side of the center point.
+        let mut count = (f64::from(self.coeff_half_len) + 2.0) / f64::from(self.index_inc);
+        if start_ratio.min(ratio) < 1.0 {
+            count /= start_ratio.min(ratio);
+        }
+        let half_filter_chan_len = channels * (count.round_ties_even() as usize + 1);
+
+        let mut input_index = self.advance(self.last_position);
+        let terminate = src_ratio.recip() + 1e-20;
+
+        while out_gen < out_count {
+            // Need to reload buffer?
+            let samples_in_hand = (self.b_end + self.b_len - self.b_current) % self.b_len;
+            if samples_in_hand <= half_filter_chan_len {
+                let () =
+                    self.prepare_data(input, &mut in_used, half_filter_chan_len, end_of_input)?;
+
+                let samples_in_hand = (self.b_end + self.b_len - self.b_current) % self.b_len;
+                if samples_in_hand <= half_filter_chan_len {
+                    break
+                }
+            }
+
+            // This is the termination condition. The mono converter
+            // differs from all others in treating reaching the end as
+            // not yet terminated.
+            if let Some(real_end) = self.b_real_end {
+                let position = self.b_current as f64 + input_index + terminate;
+                let real_end = real_end as f64;
+                if position > real_end || (channels > 1 && position == real_end) {
+                    break
+                }
+            }
+
+            if out_count > 0 && (start_ratio - ratio).abs() > MIN_RATIO_DIFF {
+                src_ratio = start_ratio + out_gen as f64 * (ratio - start_ratio) / out_count as f64;
+            }
+
+            let float_increment = f64::from(self.index_inc) * src_ratio.min(1.0);
+            let increment = double_to_fp(float_increment);
+            let start_filter_index = double_to_fp(input_index * float_increment);
+            let scale = float_increment / f64::from(self.index_inc);
+
+            let () = self.calc_output(
+                increment,
+                start_filter_index,
+                scale,
+                &mut output[out_gen..out_gen + channels],
+            );
+            out_gen += channels;
+
+            // Figure out the next index.
+            input_index = self.advance(input_index + src_ratio.recip());
+        }
+
+        self.last_position = input_index;
+        // Save current ratio rather then target ratio.
+        self.last_ratio = src_ratio;
+
+        let processed = Processed {
+            read: in_used,
+            written: out_gen,
+        };
+        Ok(processed)
+    }
+}
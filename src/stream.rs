@@ -0,0 +1,393 @@
+use std::pin::Pin;
+use std::task::ready;
+use std::task::Context;
+use std::task::Poll;
+
+use futures_core::Stream;
+use futures_sink::Sink;
+
+use crate::error::Error;
+use crate::resampler::Processed;
+use crate::resampler::Resampler;
+
+
+/// A helper for converting blocks of samples.
+#[derive(Debug)]
+struct BlockConverter {
+    resampler: Resampler,
+    /// Input samples not consumed by a previous conversion.
+    pending: Vec<f32>,
+}
+
+impl BlockConverter {
+    fn new(resampler: Resampler) -> Self {
+        Self {
+            resampler,
+            pending: Vec::new(),
+        }
+    }
+
+    /// Convert a block of input, finalizing the conversion if
+    /// `finalize` is `true`.
+    fn convert(&mut self, block: &[f32], finalize: bool) -> Result<Vec<f32>, Error> {
+        let input = if self.pending.is_empty() {
+            block
+        } else {
+            let () = self.pending.extend_from_slice(block);
+            &self.pending
+        };
+
+        let channels = usize::from(self.resampler.channels());
+        let frames = input.len() / channels;
+        let mut output = vec![0.0; self.resampler.max_output_frames(frames) * channels];
+        let mut total = Processed::default();
+
+        loop {
+            if total.written == output.len() {
+                let len = output.len() + self.resampler.tail_frames() * channels;
+                let () = output.resize(len, 0.0);
+            }
+
+            let in_buf = &input[total.read..];
+            let out_buf = &mut output[total.written..];
+            let processed = if finalize {
+                self.resampler.finalize(in_buf, out_buf)?
+            } else {
+                self.resampler.process(in_buf, out_buf)?
+            };
+
+            total.read += processed.read;
+            total.written += processed.written;
+
+            if finalize {
+                if processed.written == 0 {
+                    break
+                }
+            } else if total.read == input.len() || (processed.read == 0 && processed.written == 0) {
+                break
+            }
+        }
+
+        let () = output.truncate(total.written);
+        // Hold on to input the converter did not accept, so that we can
+        // feed it again with the next block.
+        let pending = input[total.read..].to_vec();
+        self.pending = pending;
+        Ok(output)
+    }
+}
+
+
+/// A stream adapter resampling blocks of interleaved samples.
+///
+/// Each block yielded by the wrapped stream is converted via
+/// [`Resampler::process`] and the resampled output is yielded as a
+/// block in turn. Blocks for which the converter did not produce any
+/// output are skipped. Once the wrapped stream ends, the conversion is
+/// finalized. An error ends the stream.
+///
+/// The wrapped stream has to be [`Unpin`]. Use [`Box::pin`] for
+/// streams that are not.
+///
+/// # Example
+///
+/// ```
+/// use futures::executor::block_on;
+/// use futures::stream::iter;
+/// use futures::StreamExt as _;
+/// use resample::{Resampler, ResampleStream, ResampleType};
+///
+/// let blocks = iter(vec![vec![0.0f32; 441]; 100]);
+/// let resampler = Resampler::new(ResampleType::SincFastest, 1, 44100, 48000).unwrap();
+/// let stream = ResampleStream::new(blocks, resampler);
+///
+/// let output = block_on(stream.collect::<Vec<_>>());
+/// let samples = output.into_iter().map(Result::unwrap).flatten().count();
+/// assert!((48000..=48001).contains(&samples));
+/// ```
+#[derive(Debug)]
+pub struct ResampleStream<S> {
+    stream: S,
+    converter: BlockConverter,
+    /// Whether the stream has ended.
+    done: bool,
+}
+
+impl<S> ResampleStream<S> {
+    /// Create a new stream resampling the blocks yielded by `stream`
+    /// using the given `resampler`.
+    pub fn new(stream: S, resampler: Resampler) -> Self {
+        Self {
+            stream,
+            converter: BlockConverter::new(resampler),
+            done: false,
+        }
+    }
+
+    /// Retrieve a reference to the wrapped stream.
+    #[inline]
+    pub fn get_ref(&self) -> &S {
+        &self.stream
+    }
+
+    /// Retrieve a mutable reference to the wrapped stream.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut S {
+        &mut self.stream
+    }
+
+    /// Destroy the adapter, returning the wrapped stream.
+    #[inline]
+    pub fn into_inner(self) -> S {
+        self.stream
+    }
+}
+
+impl<S, B> Stream for ResampleStream<S>
+where
+    S: Stream<Item = B> + Unpin,
+    B: AsRef<[f32]>,
+{
+    type Item = Result<Vec<f32>, Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let slf = self.get_mut();
+
+        loop {
+            if slf.done {
+                break Poll::Ready(None)
+            }
+
+            let block = ready!(Pin::new(&mut slf.stream).poll_next(cx));
+            let finalize = block.is_none();
+            let block = block.as_ref().map(AsRef::as_ref).unwrap_or(&[]);
+            let result = slf.converter.convert(block, finalize);
+            slf.done = finalize || result.is_err();
+
+            match result {
+                Ok(output) if output.is_empty() => continue,
+                result => break Poll::Ready(Some(result)),
+            }
+        }
+    }
+}
+
+
+/// A sink adapter resampling blocks of interleaved samples before
+/// passing them on to a wrapped sink.
+///
+/// Each block sent is converted via [`Resampler::process`] and the
+/// resampled output is forwarded as a block, unless it is empty.
+/// Closing the sink finalizes the conversion and forwards the remaining
+/// output, before closing the wrapped sink.
+///
+/// The wrapped sink has to be [`Unpin`] and its error type has to be
+/// constructible from [`Error`].
+#[derive(Debug)]
+pub struct ResampleSink<Si> {
+    sink: Si,
+    converter: BlockConverter,
+    /// Converted output not yet forwarded to `sink`.
+    buffered: Option<Vec<f32>>,
+    /// Whether the conversion has been finalized.
+    finalized: bool,
+}
+
+impl<Si> ResampleSink<Si> {
+    /// Create a new sink resampling blocks using the given
+    /// `resampler`, before forwarding them to `sink`.
+    pub fn new(sink: Si, resampler: Resampler) -> Self {
+        Self {
+            sink,
+            converter: BlockConverter::new(resampler),
+            buffered: None,
+            finalized: false,
+        }
+    }
+
+    /// Retrieve a reference to the wrapped sink.
+    #[inline]
+    pub fn get_ref(&self) -> &Si {
+        &self.sink
+    }
+
+    /// Retrieve a mutable reference to the wrapped sink.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut Si {
+        &mut self.sink
+    }
+
+    /// Destroy the adapter, returning the wrapped sink.
+    #[inline]
+    pub fn into_inner(self) -> Si {
+        self.sink
+    }
+}
+
+impl<Si> ResampleSink<Si>
+where
+    Si: Sink<Vec<f32>> + Unpin,
+{
+    /// Forward buffered output to the wrapped sink.
+    fn poll_forward(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Si::Error>> {
+        if self.buffered.is_some() {
+            let () = ready!(Pin::new(&mut self.sink).poll_ready(cx))?;
+            // Can't fail, because we checked above.
+            let block = self.buffered.take().unwrap();
+            let () = Pin::new(&mut self.sink).start_send(block)?;
+        }
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<Si, B> Sink<B> for ResampleSink<Si>
+where
+    Si: Sink<Vec<f32>> + Unpin,
+    Si::Error: From<Error>,
+    B: AsRef<[f32]>,
+{
+    type Error = Si::Error;
+
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.get_mut().poll_forward(cx)
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: B) -> Result<(), Self::Error> {
+        let slf = self.get_mut();
+        debug_assert!(slf.buffered.is_none());
+
+        let output = slf.converter.convert(item.as_ref(), false)?;
+        if !output.is_empty() {
+            slf.buffered = Some(output);
+        }
+        Ok(())
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let slf = self.get_mut();
+        let () = ready!(slf.poll_forward(cx))?;
+        Pin::new(&mut slf.sink).poll_flush(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let slf = self.get_mut();
+        let () = ready!(slf.poll_forward(cx))?;
+
+        if !slf.finalized {
+            let output = slf.converter.convert(&[], true)?;
+            slf.finalized = true;
+
+            if !output.is_empty() {
+                slf.buffered = Some(output);
+                let () = ready!(slf.poll_forward(cx))?;
+            }
+        }
+        Pin::new(&mut slf.sink).poll_close(cx)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::f32::consts::PI;
+    use std::io::Error as IoError;
+
+    use futures::executor::block_on;
+    use futures::stream::iter;
+    use futures::SinkExt;
+    use futures::StreamExt as _;
+
+    use crate::convert;
+    use crate::error::ErrorKind;
+    use crate::resample_type::ResampleType;
+
+
+    /// Generate `frames` frames of a stereo signal.
+    fn signal(frames: usize) -> Vec<f32> {
+        let freq = PI * 880f32 / 44100f32;
+        (0..frames)
+            .flat_map(|i| [(freq * i as f32).sin(), (freq * i as f32).cos()])
+            .collect()
+    }
+
+    /// Check that resampling a stream produces the same output as
+    /// converting everything in one go.
+    #[test]
+    fn stream_conversion() {
+        let input = signal(10_000);
+        let reference = convert(ResampleType::SincFastest, 2, 44100, 48000, &input).unwrap();
+
+        let resampler = Resampler::new(ResampleType::SincFastest, 2, 44100, 48000).unwrap();
+        let stream = ResampleStream::new(iter(input.chunks(2 * 123)), resampler);
+        let output = block_on(stream.collect::<Vec<_>>())
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap()
+            .concat();
+        assert_eq!(output.len(), reference.len());
+
+        let max_diff = output
+            .iter()
+            .zip(&reference)
+            .fold(0f32, |max, (a, b)| max.max((a - b).abs()));
+        assert!(max_diff < 1e-5, "{max_diff}");
+    }
+
+    /// Check that errors are reported by the stream, which ends
+    /// afterwards.
+    #[test]
+    fn stream_error() {
+        let resampler = Resampler::new(ResampleType::Linear, 2, 1, 2).unwrap();
+        let blocks = iter(vec![vec![0.0f32; 4], vec![0.0f32; 3], vec![0.0f32; 4]]);
+        let mut stream = ResampleStream::new(blocks, resampler);
+
+        let err = block_on(async {
+            loop {
+                match stream.next().await.unwrap() {
+                    Ok(_output) => continue,
+                    Err(err) => break err,
+                }
+            }
+        });
+        assert_eq!(err.kind(), ErrorKind::InputNotFrameAligned);
+        assert!(block_on(stream.next()).is_none());
+    }
+
+    /// Check that resampling via a sink produces the same output as
+    /// converting everything in one go.
+    #[test]
+    fn sink_conversion() {
+        let input = signal(10_000);
+        let reference = convert(ResampleType::Linear, 2, 44100, 16000, &input).unwrap();
+
+        let resampler = Resampler::new(ResampleType::Linear, 2, 44100, 16000).unwrap();
+        let sink = Vec::<Vec<f32>>::new().sink_map_err(|never| -> IoError { match never {} });
+        let mut sink = ResampleSink::new(sink, resampler);
+
+        let () = block_on(async {
+            for block in input.chunks(2 * 321) {
+                let () = sink.send(block).await.unwrap();
+            }
+            let () = SinkExt::<&[f32]>::close(&mut sink).await.unwrap();
+        });
+
+        let output = sink.get_ref().get_ref().concat();
+        assert_eq!(output, reference);
+    }
+
+    /// Check that the adapters can be sent to other threads.
+    #[test]
+    fn send() {
+        fn test<T>(_: &T)
+        where
+            T: Send,
+        {
+        }
+
+        let resampler = Resampler::new(ResampleType::Linear, 1, 1, 2).unwrap();
+        let stream = ResampleStream::new(iter(Vec::<Vec<f32>>::new()), resampler);
+        let () = test(&stream);
+    }
+}
@@ -0,0 +1,157 @@
+//! Tests for making sure that `RealtimeResampler` does not allocate
+//! once set up.
+//!
+//! These tests live in their own binary, because they install a custom
+//! global allocator.
+
+use std::alloc::GlobalAlloc;
+use std::alloc::Layout;
+use std::alloc::System;
+use std::cell::Cell;
+use std::hint::black_box;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+
+use resample::RealtimeResampler;
+use resample::ResampleType;
+
+
+thread_local! {
+    /// Whether allocations on the current thread are to be counted.
+    static ARMED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// The number of allocations performed while armed.
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+
+/// An allocator counting the allocations happening on a thread while
+/// armed.
+struct CountingAllocator;
+
+impl CountingAllocator {
+    fn count(&self) {
+        if ARMED.with(Cell::get) {
+            let _count = ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+}
+
+// SAFETY: We forward all requests to the system allocator.
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let () = self.count();
+        // SAFETY: The caller upholds the contract of `GlobalAlloc`.
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        // SAFETY: The caller upholds the contract of `GlobalAlloc`.
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let () = self.count();
+        // SAFETY: The caller upholds the contract of `GlobalAlloc`.
+        unsafe { System.alloc_zeroed(layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let () = self.count();
+        // SAFETY: The caller upholds the contract of `GlobalAlloc`.
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+
+/// Run `f`, returning the number of allocations it performed.
+fn allocations<F>(f: F) -> usize
+where
+    F: FnOnce(),
+{
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    let () = ARMED.with(|armed| armed.set(true));
+    let () = f();
+    let () = ARMED.with(|armed| armed.set(false));
+    ALLOCATIONS.load(Ordering::Relaxed) - before
+}
+
+
+/// Check that the allocation counting works in the first place.
+#[test]
+fn counting() {
+    let count = allocations(|| {
+        let vec = vec![0u8; 16];
+        let _vec = black_box(vec);
+    });
+    assert_eq!(count, 1);
+}
+
+/// Make sure that `RealtimeResampler` does not allocate after setup,
+/// for all converter types, supported sample formats, and block sizes.
+#[test]
+fn no_allocations() {
+    for type_ in [
+        ResampleType::SincBestQuality,
+        ResampleType::SincMediumQuality,
+        ResampleType::SincFastest,
+        ResampleType::ZeroOrderHold,
+        ResampleType::Linear,
+    ] {
+        let mut resampler = RealtimeResampler::new(type_, 2, 44100, 48000, 512).unwrap();
+        let input_f32 = vec![0.25f32; 2 * 512];
+        let input_i16 = vec![1024i16; 2 * 512];
+        let input_i32 = vec![1 << 24; 2 * 512];
+        let left = vec![0.5f32; 512];
+        let right = vec![-0.5f32; 512];
+        let mut output_f32 = vec![0.0f32; 2 * 512];
+        let mut output_i16 = vec![0i16; 2 * 512];
+        let mut output_i32 = vec![0i32; 2 * 512];
+        let mut out_left = vec![0.0f32; 512];
+        let mut out_right = vec![0.0f32; 512];
+
+        let count = allocations(|| {
+            for (i, frames) in [1, 7, 512, 64, 3, 480, 441, 0]
+                .into_iter()
+                .cycle()
+                .take(200)
+                .enumerate()
+            {
+                let samples = 2 * frames;
+                let () = match i % 4 {
+                    0 => {
+                        let _processed = resampler
+                            .process(&input_f32[..samples], &mut output_f32[..samples])
+                            .unwrap();
+                    },
+                    1 => {
+                        let _processed = resampler
+                            .process(&input_i16[..samples], &mut output_i16[..samples])
+                            .unwrap();
+                    },
+                    2 => {
+                        let _processed = resampler
+                            .process(&input_i32[..samples], &mut output_i32[..samples])
+                            .unwrap();
+                    },
+                    _ => {
+                        let _processed = resampler
+                            .process_planar(
+                                &[&left[..frames], &right[..frames]],
+                                &mut [&mut out_left[..frames], &mut out_right[..frames]],
+                            )
+                            .unwrap();
+                    },
+                };
+            }
+
+            let () = resampler.set_ratio(0.5).unwrap();
+            let _processed = resampler.process(&input_f32, &mut output_f32).unwrap();
+            let () = resampler.reset().unwrap();
+        });
+        assert_eq!(count, 0, "{type_:?}");
+    }
+}
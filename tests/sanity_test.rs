@@ -6,7 +6,9 @@ use std::f64::consts::PI;
 use rstest::rstest;
 
 use resample::convert;
+use resample::ErrorKind;
 use resample::ResampleType;
+use resample::Resampler;
 
 
 #[rustfmt::skip]
@@ -97,3 +99,68 @@ fn simple_resample(
       assert!(up_data.len() <= upper, "{} | {upper}", up_data.len());
     }
 }
+
+
+/// Check that integer samples can be converted just like floating
+/// point ones.
+#[test]
+fn integer_resample() {
+    let n = 4410;
+    let data = (0..n)
+        .map(|i| (2.0 * PI * 440.0 * (i as f64 / 44100.0)).sin() * 0.5)
+        .collect::<Vec<f64>>();
+    let float_data = data.iter().map(|x| *x as f32).collect::<Vec<f32>>();
+    let int_data = data
+        .iter()
+        .map(|x| (x * f64::from(i32::MAX)) as i32)
+        .collect::<Vec<i32>>();
+
+    let type_ = ResampleType::SincMediumQuality;
+    let float_result = convert(type_, 1, 44100, 48000, &float_data).unwrap();
+    let int_result = convert(type_, 1, 44100, 48000, &int_data).unwrap();
+    assert_eq!(int_result.len(), float_result.len());
+
+    let max_diff = int_result
+        .iter()
+        .zip(float_result.iter())
+        .map(|(a, b)| (f64::from(*a) / f64::from(i32::MAX) - f64::from(*b)).abs())
+        .fold(0.0, f64::max);
+    assert!(max_diff < 1e-6, "{max_diff}");
+}
+
+
+/// Make sure that `convert` rejects input not consisting of whole
+/// frames.
+#[test]
+fn unaligned_input() {
+    let err = convert(ResampleType::Linear, 2, 44100, 48000, &[0.0f32; 7]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InputNotFrameAligned);
+
+    let err = convert(ResampleType::Linear, 0, 44100, 48000, &[0.0f32; 8]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::BadChannelCount);
+}
+
+
+/// Make sure that the linear converter does not read before the start
+/// of the input when provided with a single frame.
+#[test]
+fn linear_single_frame() {
+    for channels in [1u8, 2] {
+        let frame_len = usize::from(channels);
+        // Only the last frame is part of the input; the ones before
+        // merely make any out of bounds access visible in the output.
+        let mut buffer = vec![100.0f32; 2 * frame_len];
+        let () = buffer.extend(vec![0.5; frame_len]);
+        let input = &buffer[2 * frame_len..];
+
+        let mut resampler = Resampler::new(ResampleType::Linear, channels, 1, 2).unwrap();
+        let mut output = [0.0f32; 8];
+        let processed = resampler.process(input, &mut output).unwrap();
+        assert_eq!(processed.read, input.len());
+        assert!(
+            output[..processed.written].iter().all(|x| *x == 0.5),
+            "{:?}",
+            &output[..processed.written]
+        );
+    }
+}